@@ -37,6 +37,7 @@ use core::fmt::Debug;
 use core::mem::MaybeUninit;
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
 use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
 pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
@@ -207,6 +208,19 @@ impl<K: Eq, V: Clone, Ptr: GenericPointer> MetaFlatMap<K, V, Ptr> {
     pub(crate) fn len_impl(&self) -> usize {
         self.map.len_impl()
     }
+
+    pub(crate) unsafe fn for_each_impl<F: FnMut(&K, &V) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.verify_init("for_each()");
+
+        for (_, entry) in self.map.iter_impl() {
+            if callback(&entry.id, &entry.value) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -392,6 +406,12 @@ impl<K: Eq, V: Clone> FlatMap<K, V> {
     pub fn len(&self) -> usize {
         self.len_impl()
     }
+
+    /// Calls the provided callback for every stored key-value pair until either all pairs
+    /// were visited or the callback returns [`CallbackProgression::Stop`].
+    pub fn for_each<F: FnMut(&K, &V) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.for_each_impl(callback) }
+    }
 }
 
 impl<K: Eq, V: Clone> RelocatableContainer for RelocatableFlatMap<K, V> {
@@ -631,6 +651,17 @@ impl<K: Eq, V: Clone> RelocatableFlatMap<K, V> {
     pub fn len(&self) -> usize {
         self.map.len()
     }
+
+    /// Calls the provided callback for every stored key-value pair until either all pairs
+    /// were visited or the callback returns [`CallbackProgression::Stop`].
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableFlatMap::init()`] must be called once before
+    ///
+    pub unsafe fn for_each<F: FnMut(&K, &V) -> CallbackProgression>(&self, callback: F) {
+        self.for_each_impl(callback)
+    }
 }
 
 /// A compile-time fixed-size, shared-memory compatible [`FixedSizeFlatMap`].
@@ -858,4 +889,10 @@ impl<K: Eq, V: Clone, const CAPACITY: usize> FixedSizeFlatMap<K, V, CAPACITY> {
     pub fn len(&self) -> usize {
         self.map.len()
     }
+
+    /// Calls the provided callback for every stored key-value pair until either all pairs
+    /// were visited or the callback returns [`CallbackProgression::Stop`].
+    pub fn for_each<F: FnMut(&K, &V) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.map.for_each(callback) }
+    }
 }
@@ -41,8 +41,11 @@ use core::{
     ops::{Deref, DerefMut},
 };
 
-use iceoryx2_bb_derive_macros::{PlacementDefault, ZeroCopySend};
+use iceoryx2_bb_derive_macros::PlacementDefault;
 use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary_traits::type_schema::{
+    FieldDescription, TypeSchemaKind, TypeSchemaVisitor,
+};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
@@ -53,7 +56,7 @@ use crate::string::{
 
 /// Variant of the [`String`] that has a compile-time fixed capacity and is
 /// shared-memory compatible.
-#[derive(PlacementDefault, ZeroCopySend, Clone, Copy)]
+#[derive(PlacementDefault, Clone, Copy)]
 #[repr(C)]
 pub struct StaticString<const CAPACITY: usize> {
     data: [MaybeUninit<u8>; CAPACITY],
@@ -61,6 +64,25 @@ pub struct StaticString<const CAPACITY: usize> {
     len: u64,
 }
 
+unsafe impl<const CAPACITY: usize> ZeroCopySend for StaticString<CAPACITY> {
+    fn describe_type_schema<V: TypeSchemaVisitor>(visitor: &mut V, name: &str, offset: usize) {
+        visitor.enter(&FieldDescription {
+            name,
+            kind: TypeSchemaKind::String,
+            offset,
+            size: core::mem::size_of::<Self>(),
+            capacity: CAPACITY,
+        });
+        <[MaybeUninit<u8>; CAPACITY]>::describe_type_schema(
+            visitor,
+            "data",
+            core::mem::offset_of!(Self, data),
+        );
+        u64::describe_type_schema(visitor, "len", core::mem::offset_of!(Self, len));
+        visitor.leave();
+    }
+}
+
 impl<const CAPACITY: usize> Serialize for StaticString<CAPACITY> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -34,7 +34,9 @@ use core::{
 };
 
 use iceoryx2_bb_elementary_traits::{
-    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+    placement_default::PlacementDefault,
+    type_schema::{FieldDescription, TypeSchemaKind, TypeSchemaVisitor},
+    zero_copy_send::ZeroCopySend,
 };
 use iceoryx2_bb_log::fail;
 use serde::{de::Visitor, Deserialize, Serialize};
@@ -76,7 +78,24 @@ impl<T: Debug, const CAPACITY: usize> Debug for StaticVec<T, CAPACITY> {
     }
 }
 
-unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticVec<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticVec<T, CAPACITY> {
+    fn describe_type_schema<V: TypeSchemaVisitor>(visitor: &mut V, name: &str, offset: usize) {
+        visitor.enter(&FieldDescription {
+            name,
+            kind: TypeSchemaKind::Vec,
+            offset,
+            size: core::mem::size_of::<Self>(),
+            capacity: CAPACITY,
+        });
+        <[MaybeUninit<T>; CAPACITY]>::describe_type_schema(
+            visitor,
+            "data",
+            core::mem::offset_of!(Self, data),
+        );
+        u64::describe_type_schema(visitor, "len", core::mem::offset_of!(Self, len));
+        visitor.leave();
+    }
+}
 
 impl<T, const CAPACITY: usize> Drop for StaticVec<T, CAPACITY> {
     fn drop(&mut self) {
@@ -190,6 +209,12 @@ impl<T: PartialEq, const CAPACITY: usize> PartialEq for StaticVec<T, CAPACITY> {
     }
 }
 
+impl<T: core::hash::Hash, const CAPACITY: usize> core::hash::Hash for StaticVec<T, CAPACITY> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
 impl<T: Clone, const CAPACITY: usize> TryFrom<&[T]> for StaticVec<T, CAPACITY> {
     type Error = VectorModificationError;
     fn try_from(value: &[T]) -> Result<Self, Self::Error> {
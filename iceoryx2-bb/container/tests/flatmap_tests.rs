@@ -12,6 +12,7 @@
 
 use iceoryx2_bb_container::flatmap::*;
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
@@ -208,6 +209,37 @@ mod flat_map {
         assert_that!(map.is_full(), eq true);
     }
 
+    #[test]
+    fn for_each_visits_all_key_value_pairs() {
+        let mut map = FixedSizeFlatMap::<u32, u32, CAPACITY>::new();
+        for i in 0..CAPACITY as u32 {
+            assert_that!(map.insert(i, 2 * i), is_ok);
+        }
+
+        let mut visited = [false; CAPACITY];
+        map.for_each(|key, value| {
+            assert_that!(*value, eq 2 * *key);
+            visited[*key as usize] = true;
+            CallbackProgression::Continue
+        });
+        assert_that!(visited.iter().all(|v| *v), eq true);
+    }
+
+    #[test]
+    fn for_each_stops_when_callback_requests_it() {
+        let mut map = FixedSizeFlatMap::<u32, u32, CAPACITY>::new();
+        for i in 0..CAPACITY as u32 {
+            assert_that!(map.insert(i, i), is_ok);
+        }
+
+        let mut counter = 0;
+        map.for_each(|_, _| {
+            counter += 1;
+            CallbackProgression::Stop
+        });
+        assert_that!(counter, eq 1);
+    }
+
     #[test]
     #[should_panic]
     fn double_init_call_causes_panic() {
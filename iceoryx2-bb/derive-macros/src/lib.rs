@@ -19,12 +19,14 @@
 extern crate alloc;
 extern crate proc_macro;
 
+mod rpc_service;
+
 use alloc::format;
 use alloc::vec::Vec;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, ItemTrait, LitStr};
 
 /// Implements the [`iceoryx2_bb_elementary_traits::placement_default::PlacementDefault`] trait when all
 /// fields of the struct implement it.
@@ -113,6 +115,10 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 /// the struct implement it and the struct is annotated with `repr(C)`. A type name can be optionally
 /// set with the helper attribute `type_name`.
 ///
+/// When a struct is annotated with the helper attribute `type_schema`, the name, offset and layout
+/// of every field is reported by
+/// [`iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend::describe_type_schema()`].
+///
 /// ```
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
 /// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -151,8 +157,17 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 /// needs_zero_copy_send_type(&shape2);
 /// needs_zero_copy_send_type(&shape3);
 /// assert_eq!(unsafe { Shape::type_name() }, "GeometricShape");
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[type_schema]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+///     history: [u16; 4],
+/// }
 /// ```
-#[proc_macro_derive(ZeroCopySend, attributes(type_name))]
+#[proc_macro_derive(ZeroCopySend, attributes(type_name, type_schema))]
 pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let struct_name = &ast.ident;
@@ -199,6 +214,43 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
         panic!("`#[derive(ZeroCopySend)]` requires the type to be annotated with #[repr(C)]");
     }
 
+    // check for type_schema attribute
+    let has_type_schema = ast.attrs.iter().any(|a| a.path().is_ident("type_schema"));
+    let type_schema_impl = match ast.data {
+        Data::Struct(ref data_struct) if has_type_schema => {
+            let members = data_struct.fields.iter().enumerate().map(|(i, f)| {
+                let field_type = &f.ty;
+                let (member, member_name) = match &f.ident {
+                    Some(ident) => (quote! { #ident }, ident.to_string()),
+                    None => {
+                        let index = syn::Index::from(i);
+                        (quote! { #index }, i.to_string())
+                    }
+                };
+                quote! {
+                    <#field_type as ZeroCopySend>::describe_type_schema(
+                        visitor,
+                        #member_name,
+                        core::mem::offset_of!(Self, #member),
+                    );
+                }
+            });
+
+            quote! {
+                fn describe_type_schema<V: ::iceoryx2_bb_elementary_traits::type_schema::TypeSchemaVisitor>(visitor: &mut V, name: &str, offset: usize) {
+                    visitor.enter_struct(name, offset, core::mem::size_of::<Self>());
+                    #(#members)*
+                    visitor.leave();
+                }
+            }
+        }
+        Data::Enum(_) if has_type_schema => {
+            return quote! {compile_error!("The type_schema attribute can only be used for structs");}
+                .into();
+        }
+        _ => quote! {},
+    };
+
     // implement ZeroCopySend
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
@@ -328,11 +380,37 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     let expanded = quote! {
         unsafe impl #impl_generics ZeroCopySend for #struct_name #ty_generics #where_clause {
             #zero_copy_send_impl
+
+            #type_schema_impl
         }
     };
 
     TokenStream::from(expanded)
 }
 
+/// Generates a typed request-response service from a trait. Every method must take `&self`
+/// or `&mut self` and its arguments and return value must implement `ZeroCopySend`, [`Clone`]
+/// and [`Debug`](core::fmt::Debug). The interface version is mandatory and must be increased
+/// whenever the trait changes.
+///
+/// For a trait `Calculator` the request and response enums `CalculatorRequest` and
+/// `CalculatorResponse`, the interface definition `CalculatorService`, the client stub
+/// `CalculatorClient` and the server dispatcher trait `CalculatorDispatcher` are generated.
+/// The generated code only requires the `iceoryx2_bb_elementary_traits` crate and uses the
+/// traits of [`iceoryx2_bb_elementary_traits::rpc_interface`] to send and receive the method
+/// calls, see `iceoryx2::rpc` for a transport and an example.
+#[proc_macro_attribute]
+pub fn rpc_service(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut arguments = rpc_service::Arguments::new();
+    let parser = syn::meta::parser(|meta| arguments.parse(meta));
+    parse_macro_input!(attr with parser);
+    let item_trait = parse_macro_input!(item as ItemTrait);
+
+    match rpc_service::expand(arguments, item_trait) {
+        Ok(expanded) => expanded.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 #[cfg(doctest)]
 mod zero_copy_send_compile_tests;
@@ -0,0 +1,350 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, Ident, ItemTrait, LitInt, Pat, ReturnType, TraitItem, Type};
+
+struct Method {
+    ident: Ident,
+    variant: Ident,
+    docs: Vec<syn::Attribute>,
+    arguments: Vec<(Ident, Type)>,
+    return_type: Type,
+}
+
+pub(crate) struct Arguments {
+    version: Option<u32>,
+}
+
+impl Arguments {
+    pub(crate) fn new() -> Self {
+        Self { version: None }
+    }
+
+    pub(crate) fn parse(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("version") {
+            let version: LitInt = meta.value()?.parse()?;
+            self.version = Some(version.base10_parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported rpc_service argument, only \"version\" is supported"))
+        }
+    }
+}
+
+fn to_upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn parse_method(item: &TraitItem) -> syn::Result<Method> {
+    let method = match item {
+        TraitItem::Fn(method) => method,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                item,
+                "an rpc_service trait can only contain methods",
+            ))
+        }
+    };
+
+    let sig = &method.sig;
+    if !sig.generics.params.is_empty() || sig.asyncness.is_some() {
+        return Err(syn::Error::new_spanned(
+            sig,
+            "rpc_service methods cannot be generic or async",
+        ));
+    }
+
+    match sig.receiver() {
+        Some(receiver) if receiver.reference.is_some() => (),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "rpc_service methods must take &self or &mut self",
+            ))
+        }
+    }
+
+    let mut arguments = Vec::new();
+    for input in sig.inputs.iter() {
+        if let FnArg::Typed(argument) = input {
+            match &*argument.pat {
+                Pat::Ident(pat) => arguments.push((pat.ident.clone(), (*argument.ty).clone())),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        argument,
+                        "rpc_service method arguments must be plain identifiers",
+                    ))
+                }
+            }
+        }
+    }
+
+    let return_type = match &sig.output {
+        ReturnType::Default => syn::parse_quote! { () },
+        ReturnType::Type(_, ty) => (**ty).clone(),
+    };
+
+    Ok(Method {
+        ident: sig.ident.clone(),
+        variant: format_ident!("{}", to_upper_camel_case(&sig.ident.to_string())),
+        docs: method
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("doc"))
+            .cloned()
+            .collect(),
+        arguments,
+        return_type,
+    })
+}
+
+pub(crate) fn expand(arguments: Arguments, item_trait: ItemTrait) -> syn::Result<TokenStream> {
+    let version = match arguments.version {
+        Some(version) => version,
+        None => {
+            return Err(syn::Error::new_spanned(
+                &item_trait.ident,
+                "rpc_service requires an interface version, e.g. #[rpc_service(version = 1)]",
+            ))
+        }
+    };
+
+    if !item_trait.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item_trait.generics,
+            "an rpc_service trait cannot be generic",
+        ));
+    }
+
+    let methods = item_trait
+        .items
+        .iter()
+        .map(parse_method)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    if methods.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item_trait.ident,
+            "an rpc_service trait requires at least one method",
+        ));
+    }
+
+    let vis = &item_trait.vis;
+    let trait_ident = &item_trait.ident;
+    let request_ident = format_ident!("{}Request", trait_ident);
+    let response_ident = format_ident!("{}Response", trait_ident);
+    let service_ident = format_ident!("{}Service", trait_ident);
+    let client_ident = format_ident!("{}Client", trait_ident);
+    let dispatcher_ident = format_ident!("{}Dispatcher", trait_ident);
+
+    let request_doc = format!("The arguments of a [`{trait_ident}`] method call.");
+    let response_doc = format!("The return value of a [`{trait_ident}`] method call.");
+    let service_doc = format!("The interface definition of the [`{trait_ident}`] methods.");
+    let client_doc =
+        format!("Typed client stub that calls the [`{trait_ident}`] methods with an `RpcCaller`.");
+    let dispatcher_doc = format!(
+        "Dispatches the requests of an `RpcResponder` to a [`{trait_ident}`] implementation and sends the return values back."
+    );
+
+    let request_variants = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let arguments = m.arguments.iter().map(|(ident, ty)| quote! { #ident: #ty });
+        quote! { #variant { #(#arguments),* } }
+    });
+
+    let response_variants = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let return_type = &m.return_type;
+        quote! { #variant(#return_type) }
+    });
+
+    let request_checks = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let idents = m
+            .arguments
+            .iter()
+            .map(|(ident, _)| ident)
+            .collect::<Vec<_>>();
+        quote! {
+            Self::#variant { #(#idents),* } => {
+                #(::iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend::__is_zero_copy_send(#idents);)*
+            }
+        }
+    });
+
+    let response_checks = methods.iter().map(|m| {
+        let variant = &m.variant;
+        quote! {
+            Self::#variant(value) => ::iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend::__is_zero_copy_send(value),
+        }
+    });
+
+    let method_ids = methods.iter().enumerate().map(|(id, m)| {
+        let variant = &m.variant;
+        let id = id as u32;
+        quote! { #request_ident::#variant { .. } => #id, }
+    });
+
+    let client_methods = methods.iter().map(|m| {
+        let ident = &m.ident;
+        let variant = &m.variant;
+        let docs = &m.docs;
+        let return_type = &m.return_type;
+        let arguments = m.arguments.iter().map(|(ident, ty)| quote! { #ident: #ty });
+        let idents = m.arguments.iter().map(|(ident, _)| ident);
+        quote! {
+            #(#docs)*
+            pub fn #ident(
+                &self,
+                #(#arguments),*
+            ) -> Result<C::PendingCall<#return_type>, C::Error> {
+                self.caller.call(
+                    #request_ident::#variant { #(#idents),* },
+                    |response| {
+                        #[allow(unreachable_patterns)]
+                        match response {
+                            #response_ident::#variant(value) => Some(value),
+                            _ => None,
+                        }
+                    },
+                )
+            }
+        }
+    });
+
+    let dispatch_arms = methods.iter().map(|m| {
+        let ident = &m.ident;
+        let variant = &m.variant;
+        let idents = m
+            .arguments
+            .iter()
+            .map(|(ident, _)| ident)
+            .collect::<Vec<_>>();
+        quote! {
+            #request_ident::#variant { #(#idents),* } => {
+                #response_ident::#variant(handler.#ident(#(#idents.clone()),*))
+            }
+        }
+    });
+
+    Ok(quote! {
+        #item_trait
+
+        #[doc = #request_doc]
+        #[repr(C)]
+        #[derive(Debug, Clone)]
+        #vis enum #request_ident {
+            #(#request_variants,)*
+        }
+
+        unsafe impl ::iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend for #request_ident {
+            fn __is_zero_copy_send(&self) {
+                match self {
+                    #(#request_checks)*
+                }
+            }
+        }
+
+        #[doc = #response_doc]
+        #[repr(C)]
+        #[derive(Debug, Clone)]
+        #vis enum #response_ident {
+            #(#response_variants,)*
+        }
+
+        unsafe impl ::iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend for #response_ident {
+            fn __is_zero_copy_send(&self) {
+                match self {
+                    #(#response_checks)*
+                }
+            }
+        }
+
+        #[doc = #service_doc]
+        #[derive(Debug, Clone, Copy)]
+        #vis struct #service_ident;
+
+        impl ::iceoryx2_bb_elementary_traits::rpc_interface::RpcInterface for #service_ident {
+            type Request = #request_ident;
+            type Response = #response_ident;
+
+            const INTERFACE_NAME: &'static str =
+                concat!(module_path!(), "::", stringify!(#trait_ident));
+            const VERSION: u32 = #version;
+
+            fn method_id(request: &Self::Request) -> u32 {
+                match request {
+                    #(#method_ids)*
+                }
+            }
+        }
+
+        #[doc = #client_doc]
+        #[derive(Debug)]
+        #vis struct #client_ident<C> {
+            caller: C,
+        }
+
+        impl<C: ::iceoryx2_bb_elementary_traits::rpc_interface::RpcCaller<#service_ident>>
+            #client_ident<C>
+        {
+            /// Creates a new client stub that sends the method calls with the provided caller.
+            pub fn new(caller: C) -> Self {
+                Self { caller }
+            }
+
+            /// Returns the underlying caller.
+            pub fn caller(&self) -> &C {
+                &self.caller
+            }
+
+            #(#client_methods)*
+        }
+
+        #[doc = #dispatcher_doc]
+        #vis trait #dispatcher_ident {
+            /// The failure that can occur while the requests are dispatched.
+            type Error;
+
+            /// Receives all requests, calls the corresponding method of the handler and sends
+            /// its return value back. Returns the number of handled requests.
+            fn dispatch<H: #trait_ident + ?Sized>(&self, handler: &mut H) -> Result<usize, Self::Error>;
+        }
+
+        impl<R: ::iceoryx2_bb_elementary_traits::rpc_interface::RpcResponder<#service_ident>>
+            #dispatcher_ident for R
+        {
+            type Error = R::Error;
+
+            fn dispatch<H: #trait_ident + ?Sized>(&self, handler: &mut H) -> Result<usize, Self::Error> {
+                self.respond(|request| match request {
+                    #(#dispatch_arms)*
+                })
+            }
+        }
+    })
+}
@@ -346,3 +346,18 @@ fn zero_copy_send_derive_does_not_work_for_generic_enum_when_members_do_not_impl
 /// ```
 #[cfg(doctest)]
 fn zero_copy_send_derive_does_not_work_for_generic_enum_when_not_all_members_implement_it() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[type_schema]
+/// enum MyEnum {
+///     Variant1,
+///     Variant2(u64),
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_type_schema_does_not_work_for_enums() {}
@@ -13,9 +13,43 @@
 #[cfg(test)]
 mod zero_copy_send {
     use iceoryx2_bb_derive_macros::ZeroCopySend;
+    use iceoryx2_bb_elementary_traits::type_schema::{
+        FieldDescription, TypeSchemaKind, TypeSchemaVisitor,
+    };
     use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
     use iceoryx2_bb_testing::assert_that;
 
+    #[derive(Default)]
+    struct SchemaCollector {
+        depth: usize,
+        fields: Vec<(usize, String, TypeSchemaKind, usize, usize, usize)>,
+    }
+
+    impl TypeSchemaVisitor for SchemaCollector {
+        fn enter(&mut self, field: &FieldDescription<'_>) {
+            self.fields.push((
+                self.depth,
+                field.name.to_string(),
+                field.kind,
+                field.offset,
+                field.size,
+                field.capacity,
+            ));
+            self.depth += 1;
+        }
+
+        fn leave(&mut self) {
+            self.depth -= 1;
+        }
+    }
+
+    fn collect_schema<T: ZeroCopySend>() -> SchemaCollector {
+        let mut collector = SchemaCollector::default();
+        T::describe_type_schema(&mut collector, "root", 0);
+        assert_that!(collector.depth, eq 0);
+        collector
+    }
+
     fn is_zero_copy_send<T: ZeroCopySend>(_: &T) -> bool {
         true
     }
@@ -172,4 +206,61 @@ mod zero_copy_send {
         assert_that!(is_zero_copy_send(&sut_with_attr), eq true);
         assert_that!(unsafe { GenericUnnamedTestStructWithAttr::<i32, i32>::type_name() }, eq "Smeik");
     }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    #[type_schema]
+    struct SchemaTestStruct {
+        val1: u8,
+        val2: [u16; 3],
+        val3: Foo,
+        val4: SchemaTestTuple,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    #[type_schema]
+    struct SchemaTestTuple(f64, bool);
+
+    #[test]
+    fn zero_copy_send_without_type_schema_is_opaque() {
+        let sut = collect_schema::<NamedTestStruct>();
+
+        assert_that!(sut.fields, eq vec![(0, "root".to_string(), TypeSchemaKind::Opaque, 0, 16, 0)]);
+    }
+
+    #[test]
+    fn zero_copy_send_derive_describes_type_schema_of_named_struct() {
+        let sut = collect_schema::<SchemaTestStruct>();
+
+        assert_that!(sut.fields, eq vec![
+            (0, "root".to_string(), TypeSchemaKind::Struct, 0, 32, 0),
+            (1, "val1".to_string(), TypeSchemaKind::U8, 0, 1, 0),
+            (1, "val2".to_string(), TypeSchemaKind::Array, 2, 6, 3),
+            (2, "".to_string(), TypeSchemaKind::U16, 0, 2, 0),
+            (1, "val3".to_string(), TypeSchemaKind::Opaque, 8, 2, 0),
+            (1, "val4".to_string(), TypeSchemaKind::Struct, 16, 16, 0),
+            (2, "0".to_string(), TypeSchemaKind::F64, 0, 8, 0),
+            (2, "1".to_string(), TypeSchemaKind::Bool, 8, 1, 0),
+        ]);
+    }
+
+    #[test]
+    fn zero_copy_send_derive_describes_type_schema_of_generic_struct() {
+        #[repr(C)]
+        #[derive(ZeroCopySend)]
+        #[type_schema]
+        struct GenericSchemaTestStruct<T: ZeroCopySend> {
+            val1: T,
+            val2: u32,
+        }
+
+        let sut = collect_schema::<GenericSchemaTestStruct<i64>>();
+
+        assert_that!(sut.fields, eq vec![
+            (0, "root".to_string(), TypeSchemaKind::Struct, 0, 16, 0),
+            (1, "val1".to_string(), TypeSchemaKind::I64, 0, 8, 0),
+            (1, "val2".to_string(), TypeSchemaKind::U32, 8, 4, 0),
+        ]);
+    }
 }
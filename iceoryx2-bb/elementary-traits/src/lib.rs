@@ -24,4 +24,6 @@ pub mod owning_pointer;
 pub mod placement_default;
 pub mod pointer_trait;
 pub mod relocatable_container;
+pub mod rpc_interface;
+pub mod type_schema;
 pub mod zero_copy_send;
@@ -0,0 +1,71 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Defines the contract between the code that is generated by the `rpc_service` macro and the
+//! transport that delivers the method calls. The macro generates an [`RpcInterface`], a client
+//! stub that sends its calls with an [`RpcCaller`] and a server dispatcher that is implemented
+//! for every [`RpcResponder`].
+
+use core::fmt::Debug;
+
+use crate::zero_copy_send::ZeroCopySend;
+
+/// Extracts the return value of a specific method from a response.
+pub type RpcResponseExtractor<Response, T> = fn(&Response) -> Option<&T>;
+
+/// Describes the methods of a remote procedure call interface.
+pub trait RpcInterface: Sized {
+    /// The request payload, an enum that contains the arguments of every method.
+    type Request: Debug + ZeroCopySend;
+
+    /// The response payload, an enum that contains the return value of every method.
+    type Response: Debug + ZeroCopySend;
+
+    /// The unique name of the interface.
+    const INTERFACE_NAME: &'static str;
+
+    /// The version of the interface. Only interfaces with the same version are compatible.
+    const VERSION: u32;
+
+    /// Returns the id of the method that is called with the provided request.
+    fn method_id(request: &Self::Request) -> u32;
+}
+
+/// Sends the method calls of an [`RpcInterface`] client stub.
+pub trait RpcCaller<Interface: RpcInterface> {
+    /// The method call that waits for the return value of type `T`.
+    type PendingCall<T>;
+
+    /// The failure that can occur when a method call is sent.
+    type Error;
+
+    /// Sends the request of a method call. `extract` returns the return value of the called
+    /// method from the response.
+    fn call<T>(
+        &self,
+        request: Interface::Request,
+        extract: RpcResponseExtractor<Interface::Response, T>,
+    ) -> Result<Self::PendingCall<T>, Self::Error>;
+}
+
+/// Receives the method calls of an [`RpcInterface`] and sends the return values back.
+pub trait RpcResponder<Interface: RpcInterface> {
+    /// The failure that can occur while the method calls are handled.
+    type Error;
+
+    /// Receives all requests, calls `handle` for each of them and sends the returned value
+    /// back. Returns the number of handled requests.
+    fn respond<F: FnMut(&Interface::Request) -> Interface::Response>(
+        &self,
+        handle: F,
+    ) -> Result<usize, Self::Error>;
+}
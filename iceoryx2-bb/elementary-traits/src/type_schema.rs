@@ -0,0 +1,119 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Describes the memory layout of a [`ZeroCopySend`](crate::zero_copy_send::ZeroCopySend) type
+//! field by field so that the raw bytes of the type can be decoded by tools that do not know
+//! the type at compile time.
+//!
+//! The layout is reported to a [`TypeSchemaVisitor`] by
+//! [`ZeroCopySend::describe_type_schema()`](crate::zero_copy_send::ZeroCopySend::describe_type_schema()).
+//! Every field is entered with [`TypeSchemaVisitor::enter()`] and left with
+//! [`TypeSchemaVisitor::leave()`]. All fields that are entered in between are members of the
+//! field and their offsets are relative to the start of the field.
+
+/// Defines how the bytes of a field are interpreted.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypeSchemaKind {
+    /// The layout of the field is unknown, only its size is available.
+    #[default]
+    Opaque,
+    /// A [`bool`]
+    Bool,
+    /// A [`char`]
+    Char,
+    /// An [`u8`]
+    U8,
+    /// An [`u16`]
+    U16,
+    /// An [`u32`]
+    U32,
+    /// An [`u64`]
+    U64,
+    /// An [`u128`]
+    U128,
+    /// An [`usize`]
+    Usize,
+    /// An [`i8`]
+    I8,
+    /// An [`i16`]
+    I16,
+    /// An [`i32`]
+    I32,
+    /// An [`i64`]
+    I64,
+    /// An [`i128`]
+    I128,
+    /// An [`isize`]
+    Isize,
+    /// A [`f32`]
+    F32,
+    /// A [`f64`]
+    F64,
+    /// A struct whose fields are its members.
+    Struct,
+    /// A fixed-size array with `capacity` elements. It has exactly one member that describes the
+    /// element at offset 0.
+    Array,
+    /// A fixed-capacity string with a capacity of `capacity` bytes. It has the members `data`, an
+    /// [`TypeSchemaKind::Array`] of [`TypeSchemaKind::U8`], and `len`, the number of bytes in use.
+    String,
+    /// A fixed-capacity vector with a capacity of `capacity` elements. It has the members `data`, an
+    /// [`TypeSchemaKind::Array`] of the elements, and `len`, the number of elements in use.
+    Vec,
+}
+
+/// Describes a single field that is reported to a [`TypeSchemaVisitor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDescription<'a> {
+    /// The name of the field. Elements of an array have an empty name.
+    pub name: &'a str,
+    /// Defines how the bytes of the field are interpreted.
+    pub kind: TypeSchemaKind,
+    /// The offset in bytes relative to the start of the enclosing field.
+    pub offset: usize,
+    /// The size of the field in bytes.
+    pub size: usize,
+    /// The number of elements of a [`TypeSchemaKind::Array`] or the capacity of a
+    /// [`TypeSchemaKind::String`] or [`TypeSchemaKind::Vec`]. Otherwise it is 0.
+    pub capacity: usize,
+}
+
+/// Receives the layout of a type from
+/// [`ZeroCopySend::describe_type_schema()`](crate::zero_copy_send::ZeroCopySend::describe_type_schema()).
+pub trait TypeSchemaVisitor {
+    /// Enters a field. Every field that is entered before the corresponding
+    /// [`TypeSchemaVisitor::leave()`] call is a member of it.
+    fn enter(&mut self, field: &FieldDescription<'_>);
+
+    /// Leaves the field that was entered last.
+    fn leave(&mut self);
+
+    /// Reports a field without members.
+    fn visit(&mut self, field: &FieldDescription<'_>) {
+        self.enter(field);
+        self.leave();
+    }
+
+    /// Enters a [`TypeSchemaKind::Struct`]. Used by the `ZeroCopySend` derive macro.
+    fn enter_struct(&mut self, name: &str, offset: usize, size: usize) {
+        self.enter(&FieldDescription {
+            name,
+            kind: TypeSchemaKind::Struct,
+            offset,
+            size,
+            capacity: 0,
+        });
+    }
+}
+
+unsafe impl crate::zero_copy_send::ZeroCopySend for TypeSchemaKind {}
@@ -12,6 +12,8 @@
 
 use iceoryx2_pal_concurrency_sync::iox_atomic::*;
 
+use crate::type_schema::{FieldDescription, TypeSchemaKind, TypeSchemaVisitor};
+
 /// Marks types that can be sent to another process in a zero-copy manner, i.e. the types can be
 /// safely used from within different process address spaces and can be uniquely identified by their
 /// [`ZeroCopySend::type_name()`] in an inter-process communication context.
@@ -43,31 +45,59 @@ pub unsafe trait ZeroCopySend {
         core::any::type_name::<Self>()
     }
 
+    /// Describes the memory layout of the type as field `name` at `offset` to the
+    /// [`TypeSchemaVisitor`]. By default the type is described as [`TypeSchemaKind::Opaque`].
+    /// The `ZeroCopySend` derive macro describes every field of a struct when it is annotated
+    /// with `#[type_schema]`.
+    fn describe_type_schema<V: TypeSchemaVisitor>(visitor: &mut V, name: &str, offset: usize)
+    where
+        Self: Sized,
+    {
+        visitor.visit(&FieldDescription {
+            name,
+            kind: TypeSchemaKind::Opaque,
+            offset,
+            size: core::mem::size_of::<Self>(),
+            capacity: 0,
+        });
+    }
+
     #[doc(hidden)]
     /// used as dummy call in the derive macro to ensure at compile-time that all fields of
     /// a struct implement ZeroCopySend
     fn __is_zero_copy_send(&self) {}
 }
 
-unsafe impl ZeroCopySend for usize {}
-unsafe impl ZeroCopySend for u8 {}
-unsafe impl ZeroCopySend for u16 {}
-unsafe impl ZeroCopySend for u32 {}
-unsafe impl ZeroCopySend for u64 {}
-unsafe impl ZeroCopySend for u128 {}
-
-unsafe impl ZeroCopySend for isize {}
-unsafe impl ZeroCopySend for i8 {}
-unsafe impl ZeroCopySend for i16 {}
-unsafe impl ZeroCopySend for i32 {}
-unsafe impl ZeroCopySend for i64 {}
-unsafe impl ZeroCopySend for i128 {}
+macro_rules! impl_zero_copy_send_for_primitive {
+    ($($t:ty => $kind:ident),*) => {
+        $(
+            unsafe impl ZeroCopySend for $t {
+                fn describe_type_schema<V: TypeSchemaVisitor>(
+                    visitor: &mut V,
+                    name: &str,
+                    offset: usize,
+                ) {
+                    visitor.visit(&FieldDescription {
+                        name,
+                        kind: TypeSchemaKind::$kind,
+                        offset,
+                        size: core::mem::size_of::<Self>(),
+                        capacity: 0,
+                    });
+                }
+            }
+        )*
+    };
+}
 
-unsafe impl ZeroCopySend for f32 {}
-unsafe impl ZeroCopySend for f64 {}
+impl_zero_copy_send_for_primitive!(
+    usize => Usize, u8 => U8, u16 => U16, u32 => U32, u64 => U64, u128 => U128,
+    isize => Isize, i8 => I8, i16 => I16, i32 => I32, i64 => I64, i128 => I128,
+    f32 => F32, f64 => F64,
+    char => Char, bool => Bool
+);
 
-unsafe impl ZeroCopySend for char {}
-unsafe impl ZeroCopySend for bool {}
+unsafe impl ZeroCopySend for IoxAtomicBool {}
 
 unsafe impl ZeroCopySend for IoxAtomicUsize {}
 unsafe impl ZeroCopySend for IoxAtomicU8 {}
@@ -86,11 +116,32 @@ unsafe impl ZeroCopySend for () {}
 unsafe impl ZeroCopySend for core::time::Duration {}
 
 unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {}
-unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {}
+unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {
+    fn describe_type_schema<V: TypeSchemaVisitor>(visitor: &mut V, name: &str, offset: usize) {
+        visitor.enter(&FieldDescription {
+            name,
+            kind: TypeSchemaKind::Array,
+            offset,
+            size: core::mem::size_of::<Self>(),
+            capacity: N,
+        });
+        T::describe_type_schema(visitor, "", 0);
+        visitor.leave();
+    }
+}
 unsafe impl<T: ZeroCopySend> ZeroCopySend for Option<T> {}
 unsafe impl<T: ZeroCopySend, E: ZeroCopySend> ZeroCopySend for Result<T, E> {}
-unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {}
-unsafe impl<T: ZeroCopySend> ZeroCopySend for core::cell::UnsafeCell<T> {}
+// MaybeUninit and UnsafeCell have the same memory layout as T
+unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {
+    fn describe_type_schema<V: TypeSchemaVisitor>(visitor: &mut V, name: &str, offset: usize) {
+        T::describe_type_schema(visitor, name, offset);
+    }
+}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for core::cell::UnsafeCell<T> {
+    fn describe_type_schema<V: TypeSchemaVisitor>(visitor: &mut V, name: &str, offset: usize) {
+        T::describe_type_schema(visitor, name, offset);
+    }
+}
 
 unsafe impl<T1: ZeroCopySend, T2: ZeroCopySend> ZeroCopySend for (T1, T2) {}
 unsafe impl<T1: ZeroCopySend, T2: ZeroCopySend, T3: ZeroCopySend> ZeroCopySend for (T1, T2, T3) {}
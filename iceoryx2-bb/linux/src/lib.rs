@@ -15,3 +15,6 @@ pub mod epoll;
 
 #[cfg(target_os = "linux")]
 pub mod signalfd;
+
+#[cfg(target_os = "linux")]
+pub mod numa;
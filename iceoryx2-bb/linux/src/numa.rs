@@ -0,0 +1,326 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`NumaTopology`] provides the NUMA nodes of the system and the cpu cores that belong to
+//! them. The topology is acquired from `/sys/devices/system/node`. Memory can be bound to a
+//! [`NumaNode`] with [`NumaNode::bind_memory()`] so that its pages are allocated on, or moved
+//! to, the memory of that node.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_linux::numa::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let topology = NumaTopology::new()?;
+//!
+//! println!("number of numa nodes: {}", topology.number_of_nodes());
+//! for node in topology.nodes() {
+//!     println!("numa node {} has the cpu cores {:?}", node.id(),
+//!         node.cpus().iter().collect::<Vec<_>>());
+//! }
+//!
+//! if let Some(node) = topology.node_of_cpu(0) {
+//!     println!("cpu core 0 belongs to numa node {}", node.id());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{
+    cpu_set::CpuSet,
+    file::{AccessMode, FileBuilder, FileOpenError, FileReadError},
+};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_os_api::linux;
+use iceoryx2_pal_posix::posix::{self};
+
+const NODE_DIRECTORY: &str = "/sys/devices/system/node";
+
+/// The maximum number of NUMA nodes that are supported by [`NumaNode::bind_memory()`].
+pub const MAX_NUMBER_OF_NUMA_NODES: usize = 1024;
+
+const BITS_PER_MASK_ENTRY: usize = core::mem::size_of::<posix::ulong>() * 8;
+
+/// Errors that can occur when the [`NumaTopology`] is acquired with [`NumaTopology::new()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum NumaTopologyError {
+    /// The system does not provide the NUMA topology, either because the kernel was compiled
+    /// without NUMA support or because `/sys` is not mounted.
+    NotSupported,
+    /// The process does not have the permission to read the NUMA topology.
+    InsufficientPermissions,
+    /// Insufficient memory to read the NUMA topology.
+    InsufficientMemory,
+    /// The process or system wide file handle limit has been reached.
+    FileHandleLimitReached,
+    /// [`FetchableSignal::Interrupt`](iceoryx2_bb_posix::signal::FetchableSignal::Interrupt)
+    /// was received (SIGINT).
+    Interrupt,
+    /// The content of a sysfs file could not be read.
+    SysfsFileReadFailure,
+    /// A sysfs file does not contain a valid list of numbers.
+    InvalidSysfsFileContent,
+    /// An undocumented error occurred.
+    UnknownError,
+}
+
+impl core::fmt::Display for NumaTopologyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NumaTopologyError::{self:?}")
+    }
+}
+
+impl core::error::Error for NumaTopologyError {}
+
+/// Errors that can be returned by [`NumaNode::bind_memory()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum NumaBindError {
+    /// The memory range is not page aligned, not mapped or the node id exceeds
+    /// [`MAX_NUMBER_OF_NUMA_NODES`].
+    InvalidArguments,
+    /// The process does not have the permission to move the pages of the memory range.
+    InsufficientPermissions,
+    /// The system has not enough memory to bind the memory range.
+    InsufficientMemory,
+    /// Some of the pages of the memory range could not be moved to the [`NumaNode`].
+    UnableToMovePages,
+    /// An error occurred that was not described in the linux man-page.
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for NumaBindError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NumaBindError::{self:?}")
+    }
+}
+
+impl core::error::Error for NumaBindError {}
+
+/// A NUMA node of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumaNode {
+    id: usize,
+    cpus: CpuSet,
+}
+
+impl NumaNode {
+    /// Returns the id of the [`NumaNode`].
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the cpu cores that belong to the [`NumaNode`].
+    pub fn cpus(&self) -> &CpuSet {
+        &self.cpus
+    }
+
+    /// Binds the memory range `[start, start + len)` to the [`NumaNode`]. Pages that are
+    /// already allocated on another node are moved to the [`NumaNode`] and all future
+    /// allocations in this range are served from its memory.
+    ///
+    /// # Safety
+    ///
+    /// * `start` must be page aligned
+    /// * `[start, start + len)` must be a memory range that is mapped into the process
+    pub unsafe fn bind_memory(&self, start: *mut u8, len: usize) -> Result<(), NumaBindError> {
+        let msg = "Unable to bind memory to numa node";
+        if MAX_NUMBER_OF_NUMA_NODES <= self.id {
+            fail!(from self, with NumaBindError::InvalidArguments,
+                "{msg} {} since the node id exceeds the maximum supported node id of {}.",
+                self.id, MAX_NUMBER_OF_NUMA_NODES - 1);
+        }
+
+        let mut node_mask = [0 as posix::ulong; MAX_NUMBER_OF_NUMA_NODES / BITS_PER_MASK_ENTRY];
+        node_mask[self.id / BITS_PER_MASK_ENTRY] |= 1 << (self.id % BITS_PER_MASK_ENTRY);
+
+        // the kernel considers only maxnode - 1 bits of the mask
+        if linux::mbind(
+            start as *mut posix::void,
+            len as _,
+            linux::MPOL_BIND,
+            node_mask.as_ptr(),
+            (MAX_NUMBER_OF_NUMA_NODES + 1) as _,
+            linux::MPOL_MF_MOVE | linux::MPOL_MF_STRICT,
+        ) == 0
+        {
+            return Ok(());
+        }
+
+        match posix::Errno::get() {
+            posix::Errno::EINVAL | posix::Errno::EFAULT => {
+                fail!(from self, with NumaBindError::InvalidArguments,
+                    "{msg} {} since the memory range {start:?} with {len} bytes is not page aligned or not mapped.",
+                    self.id);
+            }
+            posix::Errno::EPERM => {
+                fail!(from self, with NumaBindError::InsufficientPermissions,
+                    "{msg} {} due to insufficient permissions to move the pages of the memory range.",
+                    self.id);
+            }
+            posix::Errno::ENOMEM => {
+                fail!(from self, with NumaBindError::InsufficientMemory,
+                    "{msg} {} due to insufficient memory.", self.id);
+            }
+            posix::Errno::EIO => {
+                fail!(from self, with NumaBindError::UnableToMovePages,
+                    "{msg} {} since some pages of the memory range could not be moved to the node.",
+                    self.id);
+            }
+            e => {
+                fail!(from self, with NumaBindError::UnknownError(e as i32),
+                    "{msg} {} since an unknown error occurred ({e:?}).", self.id);
+            }
+        }
+    }
+}
+
+/// The NUMA topology of the system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumaTopology {
+    nodes: Vec<NumaNode>,
+}
+
+impl NumaTopology {
+    /// Acquires the current NUMA topology of the system.
+    pub fn new() -> Result<Self, NumaTopologyError> {
+        let origin = "NumaTopology::new()";
+        let msg = "Unable to acquire the numa topology";
+
+        let online_nodes_file = format!("{NODE_DIRECTORY}/online");
+        let online_nodes = fail!(from origin,
+            when Self::read_list(&Self::read_sysfs_file(&online_nodes_file)?),
+            "{msg} since the online nodes could not be acquired.");
+
+        let mut nodes = Vec::with_capacity(online_nodes.len());
+        for id in online_nodes {
+            let cpu_list_file = format!("{NODE_DIRECTORY}/node{id}/cpulist");
+            let cpu_list = fail!(from origin,
+                when Self::read_list(&Self::read_sysfs_file(&cpu_list_file)?),
+                "{msg} since the cpu cores of numa node {id} could not be acquired.");
+
+            nodes.push(NumaNode {
+                id,
+                cpus: CpuSet::from_cpus(&cpu_list),
+            });
+        }
+
+        Ok(Self { nodes })
+    }
+
+    /// Returns the number of online NUMA nodes.
+    pub fn number_of_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns all online NUMA nodes ordered by their id.
+    pub fn nodes(&self) -> &[NumaNode] {
+        &self.nodes
+    }
+
+    /// Returns the [`NumaNode`] with the provided id. If the node does not exist or is
+    /// offline [`None`] is returned.
+    pub fn node(&self, id: usize) -> Option<&NumaNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Returns the [`NumaNode`] the cpu core belongs to. If the cpu core does not exist or
+    /// is offline [`None`] is returned.
+    pub fn node_of_cpu(&self, cpu_core_id: usize) -> Option<&NumaNode> {
+        self.nodes
+            .iter()
+            .find(|node| node.cpus.contains(cpu_core_id))
+    }
+
+    fn read_sysfs_file(file: &str) -> Result<String, NumaTopologyError> {
+        let origin = "NumaTopology::read_sysfs_file()";
+        let msg = "Unable to read";
+        let file_path = unsafe { FilePath::new_unchecked(file.as_bytes()) };
+        let sysfs_file = match FileBuilder::new(&file_path)
+            .has_ownership(false)
+            .open_existing(AccessMode::Read)
+        {
+            Ok(file) => file,
+            Err(FileOpenError::FileDoesNotExist) => {
+                fail!(from origin, with NumaTopologyError::NotSupported,
+                    "{msg} {file} since the file does not exist.");
+            }
+            Err(FileOpenError::InsufficientPermissions) => {
+                fail!(from origin, with NumaTopologyError::InsufficientPermissions,
+                    "{msg} {file} due to insufficient permissions.");
+            }
+            Err(FileOpenError::Interrupt) => {
+                fail!(from origin, with NumaTopologyError::Interrupt,
+                    "{msg} {file} since an interrupt signal was raised while opening the file.");
+            }
+            Err(FileOpenError::InsufficientMemory) => {
+                fail!(from origin, with NumaTopologyError::InsufficientMemory,
+                    "{msg} {file} due to insufficient memory.");
+            }
+            Err(FileOpenError::PerProcessFileHandleLimitReached)
+            | Err(FileOpenError::SystemWideFileHandleLimitReached) => {
+                fail!(from origin, with NumaTopologyError::FileHandleLimitReached,
+                    "{msg} {file} since the file handle limit was reached.");
+            }
+            Err(e) => {
+                fail!(from origin, with NumaTopologyError::UnknownError,
+                    "{msg} {file} due to an unknown error while opening the file ({e:?}).");
+            }
+        };
+
+        // sysfs files report a size of one page, therefore the content must be truncated
+        // to the bytes that were actually read
+        let mut content = String::new();
+        match sysfs_file.read_to_string(&mut content) {
+            Ok(bytes_read) => {
+                content.truncate(bytes_read as usize);
+                Ok(content)
+            }
+            Err(FileReadError::Interrupt) => {
+                fail!(from origin, with NumaTopologyError::Interrupt,
+                    "{msg} {file} since an interrupt signal was raised while reading the file.");
+            }
+            Err(e) => {
+                fail!(from origin, with NumaTopologyError::SysfsFileReadFailure,
+                    "{msg} {file} since the content could not be read ({e:?}).");
+            }
+        }
+    }
+
+    /// Parses the sysfs list format, e.g. `0-3,8,10-11`.
+    fn read_list(content: &str) -> Result<Vec<usize>, NumaTopologyError> {
+        let origin = "NumaTopology::read_list()";
+        let msg = "Unable to parse sysfs list";
+        let mut list = Vec::new();
+
+        for range in content.trim().split(',').filter(|r| !r.is_empty()) {
+            let (first, last) = range.split_once('-').unwrap_or((range, range));
+            match (first.parse::<usize>(), last.parse::<usize>()) {
+                (Ok(first), Ok(last)) if first <= last => list.extend(first..=last),
+                _ => {
+                    fail!(from origin, with NumaTopologyError::InvalidSysfsFileContent,
+                        "{msg} \"{content}\" since the entry \"{range}\" is not a valid number or range.");
+                }
+            }
+        }
+
+        Ok(list)
+    }
+}
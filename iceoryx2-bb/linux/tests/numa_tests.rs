@@ -0,0 +1,84 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+pub mod tests {
+    use std::alloc::Layout;
+
+    use iceoryx2_bb_linux::numa::*;
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn topology_contains_at_least_one_node() {
+        let sut = NumaTopology::new().unwrap();
+
+        assert_that!(sut.number_of_nodes(), ge 1);
+        assert_that!(sut.nodes(), len sut.number_of_nodes());
+    }
+
+    #[test]
+    fn every_node_can_be_acquired_by_id() {
+        let sut = NumaTopology::new().unwrap();
+
+        for node in sut.nodes() {
+            assert_that!(sut.node(node.id()), eq Some(node));
+        }
+        assert_that!(sut.node(MAX_NUMBER_OF_NUMA_NODES), is_none);
+    }
+
+    #[test]
+    fn cpu_core_zero_belongs_to_a_node() {
+        let sut = NumaTopology::new().unwrap();
+
+        let node = sut.node_of_cpu(0);
+        assert_that!(node, is_some);
+        assert_that!(node.unwrap().cpus().contains(0), eq true);
+    }
+
+    #[test]
+    fn non_existing_cpu_core_does_not_belong_to_a_node() {
+        let sut = NumaTopology::new().unwrap();
+
+        assert_that!(sut.node_of_cpu(usize::MAX), is_none);
+    }
+
+    #[test]
+    fn memory_can_be_bound_to_node_of_cpu_core_zero() {
+        let sut = NumaTopology::new().unwrap();
+        let node = sut.node_of_cpu(0).unwrap();
+
+        let page_size = SystemInfo::PageSize.value();
+        let layout = Layout::from_size_align(4 * page_size, page_size).unwrap();
+        let memory = unsafe { std::alloc::alloc_zeroed(layout) };
+
+        let result = unsafe { node.bind_memory(memory, layout.size()) };
+        unsafe { std::alloc::dealloc(memory, layout) };
+
+        assert_that!(result, is_ok);
+    }
+
+    #[test]
+    fn binding_unaligned_memory_fails() {
+        let sut = NumaTopology::new().unwrap();
+        let node = sut.node_of_cpu(0).unwrap();
+
+        let page_size = SystemInfo::PageSize.value();
+        let layout = Layout::from_size_align(2 * page_size, page_size).unwrap();
+        let memory = unsafe { std::alloc::alloc_zeroed(layout) };
+
+        let result = unsafe { node.bind_memory(memory.add(1), page_size) };
+        unsafe { std::alloc::dealloc(memory, layout) };
+
+        assert_that!(result.err(), eq Some(NumaBindError::InvalidArguments));
+    }
+}
@@ -176,10 +176,32 @@ impl UnrestrictedAtomicMgmt {
         value_alignment: usize,
         data_ptr: *const u8,
     ) {
+        unsafe { self.load_with_generation(value_ptr, value_size, value_alignment, data_ptr) };
+    }
+
+    /// Returns the generation of the currently stored value. It starts with 0 and is
+    /// incremented with every store operation. The counter wraps around on overflow.
+    pub fn generation(&self) -> u32 {
+        self.write_cell.load(Ordering::Acquire).wrapping_sub(1)
+    }
+
+    /// Loads the value like [`UnrestrictedAtomicMgmt::load()`] and returns the generation of
+    /// the loaded value.
+    ///
+    /// # Safety
+    ///
+    ///   * see Safety section of core::ptr::copy_nonoverlapping
+    pub unsafe fn load_with_generation(
+        &self,
+        value_ptr: *mut u8,
+        value_size: usize,
+        value_alignment: usize,
+        data_ptr: *const u8,
+    ) -> u32 {
         /////////////////////////
         // SYNC POINT - read
         /////////////////////////
-        let mut read_cell = self.write_cell.load(Ordering::Acquire) - 1;
+        let mut read_cell = self.write_cell.load(Ordering::Acquire).wrapping_sub(1);
 
         loop {
             unsafe {
@@ -205,11 +227,13 @@ impl UnrestrictedAtomicMgmt {
                 Ordering::Acquire,
             );
             if let Err(write_cell) = write_cell_result {
-                read_cell = write_cell - 1;
+                read_cell = write_cell.wrapping_sub(1);
             } else {
                 break;
             }
         }
+
+        read_cell
     }
 
     /// # Safety
@@ -324,18 +348,30 @@ impl<T: Copy> UnrestrictedAtomic<T> {
 
     /// Loads the underlying value and returns a copy of it.
     pub fn load(&self) -> T {
+        self.load_with_generation().0
+    }
+
+    /// Loads the underlying value and returns a copy of it together with its generation.
+    /// See [`UnrestrictedAtomic::generation()`].
+    pub fn load_with_generation(&self) -> (T, u32) {
         let mut return_value: MaybeUninit<T> = MaybeUninit::uninit();
         unsafe {
-            self.mgmt.load(
+            let generation = self.mgmt.load_with_generation(
                 return_value.as_mut_ptr().cast(),
                 core::mem::size_of::<T>(),
                 core::mem::align_of::<T>(),
                 self.data.as_ptr().cast(),
             );
-            return_value.assume_init()
+            (return_value.assume_init(), generation)
         }
     }
 
+    /// Returns the generation of the currently stored value. It starts with 0 and is
+    /// incremented with every store. The counter wraps around on overflow.
+    pub fn generation(&self) -> u32 {
+        self.mgmt.generation()
+    }
+
     #[doc(hidden)]
     pub fn __internal_get_mgmt(&self) -> &UnrestrictedAtomicMgmt {
         &self.mgmt
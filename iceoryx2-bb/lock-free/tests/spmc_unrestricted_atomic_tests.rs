@@ -80,6 +80,27 @@ fn spmc_unrestricted_atomic_load_store_works() {
     }
 }
 
+#[test]
+fn spmc_unrestricted_atomic_generation_is_incremented_with_every_store() {
+    let _test_lock = TEST_LOCK.lock().unwrap();
+    let sut = UnrestrictedAtomic::<u64>::new(0);
+    assert_that!(sut.generation(), eq 0);
+
+    let producer = sut.acquire_producer().unwrap();
+    for i in 1..=10u64 {
+        producer.store(i * 3);
+        assert_that!(sut.generation(), eq i as u32);
+        assert_that!(sut.load_with_generation(), eq(i * 3, i as u32));
+    }
+
+    unsafe {
+        producer.__internal_get_ptr_to_write_cell().write(42);
+        assert_that!(sut.generation(), eq 10);
+        producer.__internal_update_write_cell();
+    }
+    assert_that!(sut.load_with_generation(), eq(42, 11));
+}
+
 #[test]
 fn spmc_unrestricted_atomic_load_store_works_concurrently() {
     let _test_lock = TEST_LOCK.lock().unwrap();
@@ -0,0 +1,113 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`CpuSet`] is a fixed-size set of CPU core ids. The cores are enumerated from
+//! `0..number_of_cpu_cores-1` and the set can hold at most [`CpuSet::capacity()`] cores.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::cpu_set::*;
+//!
+//! let mut cpu_set = CpuSet::from_cpus(&[0, 4]);
+//! cpu_set.insert(5);
+//!
+//! assert!(cpu_set.contains(4));
+//! assert!(!cpu_set.contains(1));
+//!
+//! for cpu_core_id in cpu_set.iter() {
+//!     println!("cpu core {cpu_core_id} is in the set");
+//! }
+//! ```
+
+use iceoryx2_pal_posix::posix::CPU_SETSIZE;
+
+/// A fixed-size set of CPU core ids.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CpuSet {
+    bits: [u8; CPU_SETSIZE / 8],
+}
+
+impl core::fmt::Debug for CpuSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CpuSet ")?;
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Default for CpuSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CpuSet {
+    /// Creates a new empty [`CpuSet`].
+    pub const fn new() -> Self {
+        Self {
+            bits: [0; CPU_SETSIZE / 8],
+        }
+    }
+
+    /// Creates a new [`CpuSet`] that contains the provided cpu core ids. Ids that exceed
+    /// [`CpuSet::capacity()`] are ignored.
+    pub fn from_cpus(cpu_core_ids: &[usize]) -> Self {
+        let mut new_self = Self::new();
+        for cpu_core_id in cpu_core_ids {
+            new_self.insert(*cpu_core_id);
+        }
+        new_self
+    }
+
+    /// Returns the largest cpu core id + 1 that can be contained in the [`CpuSet`].
+    pub const fn capacity() -> usize {
+        CPU_SETSIZE
+    }
+
+    /// Adds a cpu core id to the set. Returns false when the id exceeds
+    /// [`CpuSet::capacity()`], otherwise true.
+    pub fn insert(&mut self, cpu_core_id: usize) -> bool {
+        if cpu_core_id >= CPU_SETSIZE {
+            return false;
+        }
+
+        self.bits[cpu_core_id / 8] |= 1 << (cpu_core_id % 8);
+        true
+    }
+
+    /// Removes a cpu core id from the set.
+    pub fn remove(&mut self, cpu_core_id: usize) {
+        if cpu_core_id < CPU_SETSIZE {
+            self.bits[cpu_core_id / 8] &= !(1 << (cpu_core_id % 8));
+        }
+    }
+
+    /// Returns true if the cpu core id is contained in the set, otherwise false.
+    pub fn contains(&self, cpu_core_id: usize) -> bool {
+        cpu_core_id < CPU_SETSIZE && self.bits[cpu_core_id / 8] & (1 << (cpu_core_id % 8)) != 0
+    }
+
+    /// Returns true if the set does not contain any cpu core id.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|b| *b == 0)
+    }
+
+    /// Returns the number of cpu core ids contained in the set.
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over all cpu core ids contained in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..CPU_SETSIZE).filter(|cpu_core_id| self.contains(*cpu_core_id))
+    }
+}
@@ -178,6 +178,35 @@ impl Gid {
         Self { gid: gid as _ }
     }
 
+    /// Returns the Gid of the group owning the process
+    pub fn from_self() -> Self {
+        Self::new_from_native(unsafe { posix::getgid() })
+    }
+
+    /// Returns true when the process is a member of the group, either since it is the group
+    /// owning the process or one of its supplementary groups
+    pub fn is_group_of_self(&self) -> bool {
+        if *self == Self::from_self() {
+            return true;
+        }
+
+        let number_of_groups = unsafe { posix::getgroups(0, core::ptr::null_mut()) };
+        if number_of_groups <= 0 {
+            return false;
+        }
+
+        let mut groups: Vec<posix::gid_t> = vec![0; number_of_groups as usize];
+        let number_of_groups = unsafe { posix::getgroups(number_of_groups, groups.as_mut_ptr()) };
+        if number_of_groups < 0 {
+            return false;
+        }
+
+        groups
+            .iter()
+            .take(number_of_groups as usize)
+            .any(|gid| Self::new_from_native(*gid) == *self)
+    }
+
     pub fn value(&self) -> u32 {
         self.gid
     }
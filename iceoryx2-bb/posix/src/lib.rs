@@ -41,6 +41,7 @@ pub mod adaptive_wait;
 pub mod barrier;
 pub mod clock;
 pub mod config;
+pub mod cpu_set;
 pub mod creation_mode;
 pub mod socket_pair;
 pub mod udp_socket;
@@ -66,6 +66,10 @@ pub struct MemoryLock {
     len: usize,
 }
 
+// the lock only stores the address range to unlock it, which can be done from every thread
+unsafe impl Send for MemoryLock {}
+unsafe impl Sync for MemoryLock {}
+
 impl MemoryLock {
     /// Locks a provided memory region. As soon as the memory lock goes out of scope the memory
     /// region is unlocked again.
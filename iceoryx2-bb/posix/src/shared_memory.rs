@@ -62,6 +62,26 @@
 //! // print the first byte of the shared memory
 //! println!("first byte: {}", shm.as_slice()[0]);
 //! ```
+//!
+//! ## Create shared memory that is backed by huge pages.
+//!
+//! When no huge pages are available the regular POSIX shared memory is used.
+//!
+//! ```
+//! use iceoryx2_bb_posix::shared_memory::*;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::*;
+//!
+//! let name = FileName::new(b"someHugePageShmName").unwrap();
+//! let shm = SharedMemoryBuilder::new(&name)
+//!                     .huge_pages(true)
+//!                     .creation_mode(CreationMode::PurgeAndCreate)
+//!                     .size(4 * 1024 * 1024)
+//!                     .create()
+//!                     .expect("failed to create shared memory");
+//!
+//! println!("is huge page backed: {}", shm.is_huge_page_backed());
+//! ```
 
 use core::ptr::NonNull;
 use core::sync::atomic::Ordering;
@@ -71,12 +91,12 @@ use alloc::vec::Vec;
 
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::{error, fail, fatal_panic, trace};
+use iceoryx2_bb_log::{debug, error, fail, fatal_panic, trace, warn};
 use iceoryx2_bb_system_types::file_name::*;
 use iceoryx2_bb_system_types::file_path::*;
 use iceoryx2_bb_system_types::path::*;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
-use iceoryx2_pal_configuration::PATH_SEPARATOR;
+use iceoryx2_pal_configuration::{HUGE_PAGE_DIRECTORY, PATH_SEPARATOR};
 use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
@@ -84,7 +104,8 @@ use iceoryx2_pal_posix::*;
 
 pub use crate::access_mode::AccessMode;
 pub use crate::creation_mode::CreationMode;
-use crate::file::{FileStatError, FileTruncateError};
+use crate::directory::Directory;
+use crate::file::{File, FileStatError, FileTruncateError};
 use crate::file_descriptor::*;
 use crate::handle_errno;
 use crate::memory_lock::{MemoryLock, MemoryLockCreationError};
@@ -137,6 +158,7 @@ pub struct SharedMemoryBuilder {
     access_mode: AccessMode,
     mapping_offset: isize,
     enforce_base_address: Option<u64>,
+    huge_pages: bool,
 }
 
 impl SharedMemoryBuilder {
@@ -152,6 +174,7 @@ impl SharedMemoryBuilder {
             zero_memory: true,
             mapping_offset: 0,
             enforce_base_address: None,
+            huge_pages: false,
         }
     }
 
@@ -176,6 +199,16 @@ impl SharedMemoryBuilder {
         self
     }
 
+    /// Backs the shared memory with huge pages of the hugetlbfs mounted at
+    /// [`HUGE_PAGE_DIRECTORY`] to reduce TLB misses when large segments are accessed. The size
+    /// is rounded up to a multiple of the huge page size. If no huge pages are available the
+    /// regular POSIX shared memory is created instead. When an existing shared memory is opened
+    /// the huge page backed one is preferred.
+    pub fn huge_pages(mut self, value: bool) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
     /// Opens an already existing shared memory.
     pub fn open_existing(
         mut self,
@@ -208,7 +241,7 @@ impl SharedMemoryBuilder {
 
     fn open(mut self) -> Result<SharedMemory, SharedMemoryCreationError> {
         let msg = "Unable to open shared memory";
-        let fd = SharedMemory::shm_open(&self.name, &self)?;
+        let (fd, is_huge_page_backed) = SharedMemory::open_file_descriptor(&self.name, &self)?;
 
         let actual_shm_size = fail!(from self, when fd.metadata(),
                 "{} since a failure occurred while acquiring the file attributes.", msg)
@@ -223,6 +256,7 @@ impl SharedMemoryBuilder {
             memory_lock: None,
             memory_mapping,
             mapping_offset: self.mapping_offset,
+            is_huge_page_backed,
         };
 
         trace!(from shm, "open");
@@ -282,68 +316,136 @@ impl SharedMemoryCreationBuilder {
                 "{msg} since a size of 0 is not supported for a shared memory object.");
         }
 
-        let shm_created;
-        let mut fd = match self
+        match self
             .config
             .creation_mode
             .expect("CreationMode must be set on creation")
         {
-            CreationMode::CreateExclusive => {
-                shm_created = true;
-                SharedMemory::shm_create(&self.config.name, &self.config)?
-            }
+            CreationMode::CreateExclusive => self.create_new(),
             CreationMode::PurgeAndCreate => {
-                shm_created = true;
                 fail!(from self.config, when SharedMemory::shm_unlink(&self.config.name),
                     "Failed to remove already existing shared memory.");
-                SharedMemory::shm_create(&self.config.name, &self.config)?
+                if self.config.huge_pages {
+                    fail!(from self.config, when SharedMemory::huge_page_unlink(&self.config.name),
+                        "Failed to remove already existing huge page backed shared memory.");
+                }
+                self.create_new()
             }
             CreationMode::OpenOrCreate => {
-                match SharedMemory::shm_open(&self.config.name, &self.config) {
-                    Ok(fd) => {
-                        shm_created = false;
-                        self.config.has_ownership = false;
-                        fd
-                    }
-                    Err(SharedMemoryCreationError::DoesNotExist) => {
-                        shm_created = true;
-                        match SharedMemory::shm_create(&self.config.name, &self.config) {
-                            Ok(fd) => fd,
-                            Err(SharedMemoryCreationError::AlreadyExist) => {
-                                SharedMemory::shm_open(&self.config.name, &self.config)?
-                            }
-                            Err(e) => return Err(e),
+                match SharedMemory::open_file_descriptor(&self.config.name, &self.config) {
+                    Ok((fd, is_huge_page_backed)) => self.map_existing(fd, is_huge_page_backed),
+                    Err(SharedMemoryCreationError::DoesNotExist) => match self.create_new() {
+                        Err(SharedMemoryCreationError::AlreadyExist) => {
+                            let (fd, is_huge_page_backed) = SharedMemory::open_file_descriptor(
+                                &self.config.name,
+                                &self.config,
+                            )?;
+                            self.map_existing(fd, is_huge_page_backed)
                         }
-                    }
-                    Err(v) => return Err(v),
+                        v => v,
+                    },
+                    Err(v) => Err(v),
                 }
             }
+        }
+    }
+
+    fn map_existing(
+        &mut self,
+        fd: FileDescriptor,
+        is_huge_page_backed: bool,
+    ) -> Result<SharedMemory, SharedMemoryCreationError> {
+        let msg = "Unable to create shared memory";
+        self.config.has_ownership = false;
+
+        let actual_shm_size = fail!(from self.config, when fd.metadata(),
+                "{} since a failure occurred while acquiring the file attributes.", msg)
+        .size();
+        if self.config.size > actual_shm_size as usize {
+            fail!(from self.config, with SharedMemoryCreationError::SizeDoesNotFit,
+                "{} since the actual size {} is not equal to the configured size {}.", msg, actual_shm_size, self.config.size);
+        }
+
+        self.config.size = actual_shm_size as _;
+        let memory_mapping = SharedMemoryBuilder::create_memory_mapping(fd, &self.config)?;
+
+        let shm = SharedMemory {
+            name: self.config.name,
+            has_ownership: IoxAtomicBool::new(self.config.has_ownership),
+            memory_lock: None,
+            memory_mapping,
+            mapping_offset: self.config.mapping_offset,
+            is_huge_page_backed,
         };
 
-        if !shm_created {
-            let actual_shm_size = fail!(from self.config, when fd.metadata(),
-                    "{} since a failure occurred while acquiring the file attributes.", msg)
-            .size();
-            if self.config.size > actual_shm_size as usize {
-                fail!(from self.config, with SharedMemoryCreationError::SizeDoesNotFit,
-                    "{} since the actual size {} is not equal to the configured size {}.", msg, actual_shm_size, self.config.size);
+        trace!(from shm, "open");
+        Ok(shm)
+    }
+
+    fn create_new(&mut self) -> Result<SharedMemory, SharedMemoryCreationError> {
+        if self.config.huge_pages {
+            if let Some(memory_mapping) = self.create_huge_page_mapping()? {
+                return self.initialize(memory_mapping, true);
+            }
+        }
+
+        let fd = SharedMemory::shm_create(&self.config.name, &self.config)?;
+        let memory_mapping = self.truncate_and_map(fd)?;
+        self.initialize(memory_mapping, false)
+    }
+
+    fn create_huge_page_mapping(
+        &mut self,
+    ) -> Result<Option<MemoryMapping>, SharedMemoryCreationError> {
+        let msg = "Unable to create huge page backed shared memory";
+        let fd = match SharedMemory::huge_page_create(&self.config.name, &self.config) {
+            Ok(fd) => fd,
+            Err(SharedMemoryCreationError::AlreadyExist) => {
+                fail!(from self.config, with SharedMemoryCreationError::AlreadyExist,
+                    "{} since it already exists.", msg);
+            }
+            Err(e) => {
+                debug!(from self.config,
+                    "{} ({:?}), falling back to POSIX shared memory.", msg, e);
+                return Ok(None);
             }
+        };
 
-            self.config.size = actual_shm_size as _;
-            let memory_mapping = SharedMemoryBuilder::create_memory_mapping(fd, &self.config)?;
+        if SharedMemory::does_exist(&self.config.name) {
+            let _ = SharedMemory::huge_page_unlink(&self.config.name);
+            fail!(from self.config, with SharedMemoryCreationError::AlreadyExist,
+                "{} since a POSIX shared memory with the same name already exists.", msg);
+        }
 
-            let shm = SharedMemory {
-                name: self.config.name,
-                has_ownership: IoxAtomicBool::new(self.config.has_ownership),
-                memory_lock: None,
-                memory_mapping,
-                mapping_offset: self.config.mapping_offset,
-            };
+        let huge_page_size = match fd.metadata() {
+            Ok(metadata) => (metadata.block_size() as usize).max(1),
+            Err(e) => {
+                let _ = SharedMemory::huge_page_unlink(&self.config.name);
+                debug!(from self.config,
+                    "{} since the huge page size could not be acquired ({:?}), falling back to POSIX shared memory.", msg, e);
+                return Ok(None);
+            }
+        };
 
-            trace!(from shm, "open");
-            return Ok(shm);
+        let requested_size = self.config.size;
+        self.config.size = requested_size.div_ceil(huge_page_size) * huge_page_size;
+        match self.truncate_and_map(fd) {
+            Ok(memory_mapping) => Ok(Some(memory_mapping)),
+            Err(e) => {
+                let _ = SharedMemory::huge_page_unlink(&self.config.name);
+                self.config.size = requested_size;
+                warn!(from self.config,
+                    "{} since the huge pages could not be mapped ({:?}), falling back to POSIX shared memory.", msg, e);
+                Ok(None)
+            }
         }
+    }
 
+    fn truncate_and_map(
+        &mut self,
+        mut fd: FileDescriptor,
+    ) -> Result<MemoryMapping, SharedMemoryCreationError> {
+        let msg = "Unable to create shared memory";
         fail!(from self.config, when fd.truncate(self.config.size), "{} since the shared memory truncation failed.", msg);
 
         let actual_shm_size = fail!(from self.config, when fd.metadata(),
@@ -355,14 +457,22 @@ impl SharedMemoryCreationBuilder {
         }
 
         self.config.size = actual_shm_size as _;
-        let memory_mapping = SharedMemoryBuilder::create_memory_mapping(fd, &self.config)?;
+        SharedMemoryBuilder::create_memory_mapping(fd, &self.config)
+    }
 
+    fn initialize(
+        &mut self,
+        memory_mapping: MemoryMapping,
+        is_huge_page_backed: bool,
+    ) -> Result<SharedMemory, SharedMemoryCreationError> {
+        let msg = "Unable to create shared memory";
         let mut shm = SharedMemory {
             name: self.config.name,
             has_ownership: IoxAtomicBool::new(self.config.has_ownership),
             memory_lock: None,
             memory_mapping,
             mapping_offset: self.config.mapping_offset,
+            is_huge_page_backed,
         };
 
         if self.config.is_memory_locked {
@@ -412,13 +522,14 @@ pub struct SharedMemory {
     memory_mapping: MemoryMapping,
     memory_lock: Option<MemoryLock>,
     mapping_offset: isize,
+    is_huge_page_backed: bool,
 }
 
 impl Drop for SharedMemory {
     fn drop(&mut self) {
         if self.has_ownership() {
             match self.set_permission(Permission::OWNER_ALL) {
-                Ok(()) => match Self::shm_unlink(&self.name) {
+                Ok(()) => match self.unlink() {
                     Ok(_) => {
                         trace!(from self, "delete");
                     }
@@ -449,6 +560,17 @@ impl SharedMemory {
         .is_some()
     }
 
+    /// Returns true if a huge page backed shared memory exists and is accessible, otherwise
+    /// false.
+    pub fn does_exist_in_huge_pages(name: &FileName) -> bool {
+        matches!(File::does_exist(&Self::huge_page_path(name)), Ok(true))
+    }
+
+    /// Returns true if the shared memory is backed by huge pages, otherwise false.
+    pub fn is_huge_page_backed(&self) -> bool {
+        self.is_huge_page_backed
+    }
+
     /// Returns the mapping offset used when the shared memory object was mapped into process space
     pub fn mapping_offset(&self) -> isize {
         self.mapping_offset
@@ -491,6 +613,31 @@ impl SharedMemory {
         }
     }
 
+    /// Removes a huge page backed shared memory file.
+    pub fn remove_from_huge_pages(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
+        match Self::huge_page_unlink(name) {
+            Ok(true) => {
+                trace!(from "SharedMemory::remove_from_huge_pages", "\"{}\"", name);
+                Ok(true)
+            }
+            Ok(false) => Ok(false),
+            Err(v) => Err(v),
+        }
+    }
+
+    /// Returns a list of all huge page backed shared memory objects
+    pub fn list_huge_pages() -> Vec<FileName> {
+        let directory = match Directory::new(&Self::huge_page_directory()) {
+            Ok(directory) => directory,
+            Err(_) => return vec![],
+        };
+
+        match directory.contents() {
+            Ok(entries) => entries.iter().map(|entry| *entry.name()).collect(),
+            Err(_) => vec![],
+        }
+    }
+
     /// Returns a list of all shared memory objects
     pub fn list() -> Vec<FileName> {
         let mut result = vec![];
@@ -538,6 +685,115 @@ impl SharedMemory {
         self.memory_mapping.as_mut_slice()
     }
 
+    fn unlink(&self) -> Result<bool, SharedMemoryRemoveError> {
+        if self.is_huge_page_backed {
+            Self::huge_page_unlink(&self.name)
+        } else {
+            Self::shm_unlink(&self.name)
+        }
+    }
+
+    fn open_file_descriptor(
+        name: &FileName,
+        config: &SharedMemoryBuilder,
+    ) -> Result<(FileDescriptor, bool), SharedMemoryCreationError> {
+        if config.huge_pages {
+            match Self::huge_page_open(name, config) {
+                Ok(fd) => return Ok((fd, true)),
+                Err(SharedMemoryCreationError::DoesNotExist) => (),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok((Self::shm_open(name, config)?, false))
+    }
+
+    fn huge_page_directory() -> Path {
+        // HUGE_PAGE_DIRECTORY is a valid path
+        unsafe { Path::new_unchecked(HUGE_PAGE_DIRECTORY) }
+    }
+
+    fn huge_page_path(name: &FileName) -> FilePath {
+        // the length of HUGE_PAGE_DIRECTORY is short enough to fit together with every file name
+        unsafe { FilePath::from_path_and_file_unchecked(&Self::huge_page_directory(), name) }
+    }
+
+    fn huge_page_create(
+        name: &FileName,
+        config: &SharedMemoryBuilder,
+    ) -> Result<FileDescriptor, SharedMemoryCreationError> {
+        let file_path = Self::huge_page_path(name);
+        let fd = FileDescriptor::new(unsafe {
+            posix::open_with_mode(
+                file_path.as_c_str(),
+                CreationMode::CreateExclusive.as_oflag() | config.access_mode.as_oflag(),
+                config.permission.as_mode(),
+            )
+        });
+
+        if let Some(v) = fd {
+            return Ok(v);
+        }
+
+        let msg = "Unable to create huge page backed shared memory";
+        handle_errno!(SharedMemoryCreationError, from config,
+            Errno::ENOENT => (DoesNotExist, "{} since the huge page file system is not available.", msg),
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EINVAL => (InvalidName, "{} since the provided name \"{}\" is invalid.", msg, name),
+            Errno::EEXIST => (AlreadyExist, "{} since it already exists.", msg),
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+            Errno::ENAMETOOLONG => (NameTooLong, "{} since the name exceeds the maximum supported length of {}.", msg, Limit::MaxFileNameLength.value() ),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    fn huge_page_open(
+        name: &FileName,
+        config: &SharedMemoryBuilder,
+    ) -> Result<FileDescriptor, SharedMemoryCreationError> {
+        let file_path = Self::huge_page_path(name);
+        let fd = FileDescriptor::new(unsafe {
+            posix::open(file_path.as_c_str(), config.access_mode.as_oflag())
+        });
+
+        if let Some(v) = fd {
+            return Ok(v);
+        }
+
+        let msg = "Unable to open huge page backed shared memory";
+        handle_errno!(SharedMemoryCreationError, from config,
+            Errno::ENOENT => (DoesNotExist, "{} since the shared memory does not exist.", msg),
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EINVAL => (InvalidName, "{} since the provided name \"{}\" is invalid.", msg, name),
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+            Errno::ENAMETOOLONG => (NameTooLong, "{} since the name exceeds the maximum supported length of {}.", msg, Limit::MaxFileNameLength.value() ),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    fn huge_page_unlink(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
+        let file_path = Self::huge_page_path(name);
+        if unsafe { posix::unlink(file_path.as_c_str()) } == 0 {
+            return Ok(true);
+        }
+
+        let msg = "Unable to remove huge page backed shared memory file";
+        let origin = "SharedMemory::huge_page_unlink()";
+        match posix::Errno::get() {
+            posix::Errno::EACCES | posix::Errno::EPERM => {
+                fail!(from origin, with SharedMemoryRemoveError::InsufficientPermissions,
+                    "{} \"{}\" due to insufficient permissions.", msg, name);
+            }
+            posix::Errno::ENOENT => Ok(false),
+            v => {
+                fail!(from origin, with SharedMemoryRemoveError::UnknownError(v as i32),
+                    "{} \"{}\" since an unknown error occurred ({}).", msg, name, v);
+            }
+        }
+    }
+
     fn shm_create(
         name: &FileName,
         config: &SharedMemoryBuilder,
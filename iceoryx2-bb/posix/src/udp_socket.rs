@@ -65,7 +65,7 @@ use iceoryx2_bb_system_types::ipv4_address::{self, Ipv4Address};
 use iceoryx2_bb_system_types::port::{self, Port};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 use iceoryx2_pal_posix::posix::{self, MemZeroedStruct};
-use iceoryx2_pal_posix::posix::{Errno, SockAddrIn};
+use iceoryx2_pal_posix::posix::{Errno, IpMreq, SockAddrIn};
 
 use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
 use crate::file_descriptor_set::{
@@ -89,6 +89,17 @@ pub enum UdpServerCreateError {
     UnknownError(i32),
 }
 
+/// Describes errors when configuring the multicast settings of an [`UdpServer`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UdpMulticastError {
+    InvalidMulticastAddress,
+    AddressNotAvailable,
+    NoSuchInterface,
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
 /// Describes errors when creating and [`UdpClient`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum UdpClientCreateError {
@@ -323,6 +334,7 @@ impl UdpClient {
 pub struct UdpServerBuilder {
     address: Ipv4Address,
     port: Port,
+    reuse_address: bool,
 }
 
 impl Default for UdpServerBuilder {
@@ -330,6 +342,7 @@ impl Default for UdpServerBuilder {
         Self {
             address: ipv4_address::UNSPECIFIED,
             port: port::UNSPECIFIED,
+            reuse_address: false,
         }
     }
 }
@@ -354,6 +367,14 @@ impl UdpServerBuilder {
         self
     }
 
+    /// Defines if the address/port can be shared with other sockets. Required when multiple
+    /// [`UdpServer`]s on the same host shall receive the messages of a multicast group.
+    /// If not set, it is disabled.
+    pub fn reuse_address(mut self, value: bool) -> Self {
+        self.reuse_address = value;
+        self
+    }
+
     /// Creates a socket that listens on the specified address/port.
     pub fn listen(self) -> Result<UdpServer, UdpServerCreateError> {
         let raw_fd = unsafe {
@@ -378,6 +399,26 @@ impl UdpServerBuilder {
             );
         }
 
+        if self.reuse_address {
+            let value: posix::int = 1;
+            if unsafe {
+                posix::setsockopt(
+                    raw_fd,
+                    posix::SOL_SOCKET,
+                    posix::SO_REUSEADDR,
+                    (&value as *const posix::int) as *const posix::void,
+                    core::mem::size_of::<posix::int>() as u32,
+                )
+            } == -1
+            {
+                let msg = "Unable to create UdpServer socket since the address could not be marked as reusable";
+                handle_errno!(UdpServerCreateError, from self,
+                    Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+                    v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+                );
+            }
+        }
+
         let server_address = create_sockaddr(self.address, self.port);
 
         let msg = "Unable to create and bind UdpServer socket";
@@ -508,6 +549,47 @@ impl UdpServer {
         }
     }
 
+    /// Joins the multicast group with the provided [`Ipv4Address`] on the network interface with
+    /// the provided [`Ipv4Address`]. Afterwards, the [`UdpServer`] receives all messages sent to
+    /// the multicast group on its [`Port`]. When the interface address is
+    /// [`ipv4_address::UNSPECIFIED`] the operating system chooses the interface.
+    pub fn join_multicast_group(
+        &self,
+        group: Ipv4Address,
+        interface: Ipv4Address,
+    ) -> Result<(), UdpMulticastError> {
+        let msg = format!("Unable to join multicast group {group} on interface {interface}");
+        if !group.is_multicast() {
+            fail!(from self, with UdpMulticastError::InvalidMulticastAddress,
+                "{} since the address is not a multicast address.", msg);
+        }
+
+        let mut request = posix::ip_mreq::new_zeroed();
+        request.set_imr_multiaddr(group.as_u32().to_be());
+        request.set_imr_interface(interface.as_u32().to_be());
+        self.socket
+            .set_ip_option(&msg, posix::IP_ADD_MEMBERSHIP, &request)
+    }
+
+    /// Defines the network interface via which the messages sent to a multicast group are
+    /// transmitted. When the interface address is [`ipv4_address::UNSPECIFIED`] the
+    /// operating system chooses the interface.
+    pub fn set_multicast_interface(&self, interface: Ipv4Address) -> Result<(), UdpMulticastError> {
+        let msg = format!("Unable to set the multicast interface to {interface}");
+        let address: u32 = interface.as_u32().to_be();
+        self.socket
+            .set_ip_option(&msg, posix::IP_MULTICAST_IF, &address)
+    }
+
+    /// Defines if messages sent to a multicast group are also delivered to the members of the
+    /// group on the same host. It is enabled by default.
+    pub fn set_multicast_loop(&self, value: bool) -> Result<(), UdpMulticastError> {
+        let msg = format!("Unable to set the multicast loop to {value}");
+        let value: posix::int = value as _;
+        self.socket
+            .set_ip_option(&msg, posix::IP_MULTICAST_LOOP, &value)
+    }
+
     /// Blocks until either a message was received. If no message was received
     /// the method returns [`None`] otherwise [`ReceiveDetails`] that contain the number of bytes
     /// received as well as the origin of the data.
@@ -582,6 +664,34 @@ impl UdpSocket {
         );
     }
 
+    fn set_ip_option<T>(
+        &self,
+        msg: &str,
+        option: posix::int,
+        value: &T,
+    ) -> Result<(), UdpMulticastError> {
+        if unsafe {
+            posix::setsockopt(
+                self.socket_fd.native_handle(),
+                posix::IPPROTO_IP,
+                option,
+                (value as *const T) as *const posix::void,
+                core::mem::size_of::<T>() as u32,
+            )
+        } == 0
+        {
+            return Ok(());
+        }
+
+        handle_errno!(UdpMulticastError, from self,
+            Errno::EADDRNOTAVAIL => (AddressNotAvailable, "{} since the address is not available.", msg),
+            Errno::ENODEV => (NoSuchInterface, "{} since the network interface does not exist.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
     fn set_non_blocking(&self, value: bool) -> Result<(), UdpReceiveError> {
         if self.is_non_blocking.load(Ordering::Relaxed) == value {
             return Ok(());
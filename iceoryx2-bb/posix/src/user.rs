@@ -185,6 +185,11 @@ impl Uid {
         Self { uid: uid as _ }
     }
 
+    /// Returns the Uid of the user owning the process
+    pub fn from_self() -> Self {
+        Self::new_from_native(unsafe { posix::getuid() })
+    }
+
     pub fn value(&self) -> u32 {
         self.uid
     }
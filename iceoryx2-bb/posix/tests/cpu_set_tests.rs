@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::cpu_set::*;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn cpu_set_new_is_empty() {
+    let sut = CpuSet::new();
+
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(sut.len(), eq 0);
+    assert_that!(sut.iter().next(), is_none);
+}
+
+#[test]
+fn cpu_set_insert_and_remove_works() {
+    let mut sut = CpuSet::new();
+
+    assert_that!(sut.insert(3), eq true);
+    assert_that!(sut.insert(9), eq true);
+    assert_that!(sut.contains(3), eq true);
+    assert_that!(sut.contains(9), eq true);
+    assert_that!(sut.contains(4), eq false);
+    assert_that!(sut.len(), eq 2);
+
+    sut.remove(3);
+    assert_that!(sut.contains(3), eq false);
+    assert_that!(sut.len(), eq 1);
+}
+
+#[test]
+fn cpu_set_insert_beyond_capacity_fails() {
+    let mut sut = CpuSet::new();
+
+    assert_that!(sut.insert(CpuSet::capacity()), eq false);
+    assert_that!(sut.contains(CpuSet::capacity()), eq false);
+    assert_that!(sut.insert(CpuSet::capacity() - 1), eq true);
+    assert_that!(sut.contains(CpuSet::capacity() - 1), eq true);
+}
+
+#[test]
+fn cpu_set_from_cpus_contains_all_cpus_in_ascending_order() {
+    let sut = CpuSet::from_cpus(&[17, 2, 8, 2]);
+
+    assert_that!(sut.iter().collect::<Vec<_>>(), eq vec![2, 8, 17]);
+}
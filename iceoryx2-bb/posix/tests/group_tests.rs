@@ -67,3 +67,10 @@ fn group_as_works() {
 
     assert_that!(root_1.details().unwrap().members().len(), ge 0);
 }
+
+#[test]
+fn group_of_process_is_group_of_self() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    assert_that!(Gid::from_self().is_group_of_self(), eq true);
+}
@@ -274,3 +274,63 @@ fn shared_memory_can_be_mapped_with_a_custom_offset() {
 
     assert_that!(sut.mapping_offset(), eq MAPPING_OFFSET);
 }
+
+#[test]
+fn shared_memory_with_huge_pages_create_and_open_works() {
+    let shm_name = generate_shm_name();
+    let mut sut_create = SharedMemoryBuilder::new(&shm_name)
+        .huge_pages(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .permission(Permission::OWNER_ALL)
+        .zero_memory(true)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .huge_pages(true)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    assert_that!(sut_create.size(), eq sut_open.size());
+    assert_that!(sut_create.size(), ge 1024);
+    assert_that!(sut_create.is_huge_page_backed(), eq sut_open.is_huge_page_backed());
+    assert_that!(
+        SharedMemory::does_exist_in_huge_pages(&shm_name),
+        eq sut_create.is_huge_page_backed()
+    );
+    assert_that!(
+        SharedMemory::does_exist(&shm_name),
+        eq !sut_create.is_huge_page_backed()
+    );
+
+    for e in sut_create.as_mut_slice().iter_mut() {
+        *e = 255;
+    }
+
+    for e in sut_open.as_slice().iter() {
+        assert_that!(*e, eq 255);
+    }
+}
+
+#[test]
+fn shared_memory_with_huge_pages_is_removed_when_it_goes_out_of_scope() {
+    let shm_name = generate_shm_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .huge_pages(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    if sut.is_huge_page_backed() {
+        assert_that!(SharedMemory::list_huge_pages(), contains shm_name);
+    } else {
+        assert_that!(SharedMemory::list(), contains shm_name);
+    }
+
+    drop(sut);
+
+    assert_that!(SharedMemory::does_exist_in_huge_pages(&shm_name), eq false);
+    assert_that!(SharedMemory::does_exist(&shm_name), eq false);
+}
@@ -405,3 +405,74 @@ fn udp_socket_server_timed_receive_from_does_block() {
         assert_that!(send_result, is_ok);
     });
 }
+
+#[test]
+fn udp_socket_server_with_same_port_and_reuse_address_works() {
+    let sut_server_1 = UdpServerBuilder::new()
+        .address(Ipv4Address::new(127, 0, 0, 1))
+        .reuse_address(true)
+        .listen()
+        .unwrap();
+
+    let sut_server_2 = UdpServerBuilder::new()
+        .address(Ipv4Address::new(127, 0, 0, 1))
+        .port(sut_server_1.port())
+        .reuse_address(true)
+        .listen();
+
+    assert_that!(sut_server_2, is_ok);
+}
+
+#[test]
+fn udp_socket_join_multicast_group_with_non_multicast_address_fails() {
+    let sut = UdpServerBuilder::new().listen().unwrap();
+
+    assert_that!(
+        sut.join_multicast_group(ipv4_address::LOCALHOST, ipv4_address::LOCALHOST),
+        eq Err(UdpMulticastError::InvalidMulticastAddress)
+    );
+}
+
+#[test]
+fn udp_socket_multicast_message_is_received_by_all_group_members() {
+    let group = Ipv4Address::new(239, 255, 117, 1);
+
+    let sut_member_1 = UdpServerBuilder::new()
+        .reuse_address(true)
+        .listen()
+        .unwrap();
+    let sut_member_2 = UdpServerBuilder::new()
+        .port(sut_member_1.port())
+        .reuse_address(true)
+        .listen()
+        .unwrap();
+    assert_that!(
+        sut_member_1.join_multicast_group(group, ipv4_address::LOCALHOST),
+        is_ok
+    );
+    assert_that!(
+        sut_member_2.join_multicast_group(group, ipv4_address::LOCALHOST),
+        is_ok
+    );
+
+    let sut_sender = UdpServerBuilder::new().listen().unwrap();
+    assert_that!(
+        sut_sender.set_multicast_interface(ipv4_address::LOCALHOST),
+        is_ok
+    );
+    assert_that!(sut_sender.set_multicast_loop(true), is_ok);
+
+    let send_buffer = [4u8, 8u8, 15u8, 16u8, 23u8, 42u8];
+    assert_that!(sut_sender.send_to(&send_buffer, group, sut_member_1.port()), eq Ok(send_buffer.len()));
+
+    for sut in [&sut_member_1, &sut_member_2] {
+        let mut recv_buffer = [0u8; 16];
+        let recv_details = sut
+            .timed_receive_from(&mut recv_buffer, TIMEOUT * 40)
+            .unwrap()
+            .unwrap();
+        assert_that!(recv_details.number_of_bytes, eq send_buffer.len());
+        assert_that!(recv_details.source_port, eq sut_sender.port());
+        assert_that!(recv_buffer[..send_buffer.len()], eq send_buffer);
+    }
+}
@@ -21,12 +21,14 @@ pub mod resizable_shared_memory_trait {
     use core::alloc::Layout;
 
     use iceoryx2_bb_conformance_test_macros::conformance_test;
-    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_posix::cpu_set::CpuSet;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_cal::named_concept::*;
     use iceoryx2_cal::resizable_shared_memory::*;
-    use iceoryx2_cal::shared_memory::SharedMemory;
+    use iceoryx2_cal::shared_memory::{MemoryLockPolicy, NumaPlacement, SharedMemory};
     use iceoryx2_cal::shm_allocator::{AllocationError, AllocationStrategy, ShmAllocationError};
     use iceoryx2_cal::testing::*;
+    use iceoryx2_pal_posix::posix::POSIX_SUPPORT_MEMORY_LOCK;
 
     use super::*;
 
@@ -769,6 +771,64 @@ pub mod resizable_shared_memory_trait {
         assert_that!(Sut::does_exist_cfg(&storage_name, &config), eq Ok(false));
     }
 
+    #[conformance_test]
+    pub fn allocate_more_than_hinted_with_required_memory_lock_policy_works<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+        let config = generate_isolated_config::<Sut>();
+        let storage_name = generate_name();
+
+        let sut_creator = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u64>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .memory_lock_policy(MemoryLockPolicy::Required)
+            .create()
+            .unwrap();
+
+        for n in 0..4 {
+            let chunk = sut_creator
+                .allocate(Layout::from_size_align(8 << n, 8).unwrap())
+                .unwrap();
+            unsafe { (chunk.data_ptr as *mut u64).write(n) };
+            assert_that!(unsafe { (chunk.data_ptr as *const u64).read() }, eq n);
+        }
+        assert_that!(sut_creator.number_of_active_segments(), eq 4);
+    }
+
+    #[conformance_test]
+    pub fn allocate_more_than_hinted_with_numa_placement_works<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let config = generate_isolated_config::<Sut>();
+        let storage_name = generate_name();
+
+        let sut_creator = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u64>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .numa_placement(NumaPlacement::NodeOfCpus(CpuSet::from_cpus(&[0])))
+            .create()
+            .unwrap();
+        let numa_node = sut_creator.numa_node();
+
+        for n in 0..4 {
+            let chunk = sut_creator
+                .allocate(Layout::from_size_align(8 << n, 8).unwrap())
+                .unwrap();
+            unsafe { (chunk.data_ptr as *mut u64).write(n) };
+            assert_that!(unsafe { (chunk.data_ptr as *const u64).read() }, eq n);
+        }
+        assert_that!(sut_creator.number_of_active_segments(), eq 4);
+        assert_that!(sut_creator.numa_node(), eq numa_node);
+    }
+
     #[conformance_test]
     pub fn when_max_number_of_reallocations_is_exceeded_another_allocation_fails<
         Shm: SharedMemory<DefaultAllocator>,
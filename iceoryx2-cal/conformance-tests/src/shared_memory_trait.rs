@@ -22,12 +22,14 @@ pub mod shared_memory_trait {
 
     use iceoryx2_bb_conformance_test_macros::conformance_test;
     use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_posix::cpu_set::CpuSet;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_cal::named_concept::*;
     use iceoryx2_cal::shared_memory::*;
     use iceoryx2_cal::shm_allocator::{ShmAllocationError, ShmAllocator};
     use iceoryx2_cal::testing::*;
+    use iceoryx2_pal_posix::posix::POSIX_SUPPORT_MEMORY_LOCK;
 
     use super::*;
 
@@ -400,4 +402,78 @@ pub mod shared_memory_trait {
         drop(sut);
         assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
     }
+
+    #[conformance_test]
+    pub fn memory_is_not_locked_by_default<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        assert_that!(sut.is_memory_locked(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn memory_is_locked_with_required_lock_policy<Sut: SharedMemory<DefaultAllocator>>() {
+        test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .memory_lock_policy(MemoryLockPolicy::Required)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).config(&config).open().unwrap();
+
+        assert_that!(sut.is_memory_locked(), eq true);
+        assert_that!(sut_open.is_memory_locked(), eq false);
+
+        let chunk = sut.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { *chunk.data_ptr = 0xaf };
+        assert_that!(unsafe { *chunk.data_ptr }, eq 0xaf);
+    }
+
+    #[conformance_test]
+    pub fn memory_is_not_bound_to_numa_node_by_default<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        assert_that!(sut.numa_node(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn memory_with_numa_placement_on_node_of_cpu_zero_works<
+        Sut: SharedMemory<DefaultAllocator>,
+    >() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        // on single node systems the placement is ignored and the memory is not bound
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .numa_placement(NumaPlacement::NodeOfCpus(CpuSet::from_cpus(&[0])))
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).config(&config).open().unwrap();
+
+        assert_that!(sut_open.numa_node(), is_none);
+
+        let chunk = sut.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { *chunk.data_ptr = 0xaf };
+        assert_that!(unsafe { *chunk.data_ptr }, eq 0xaf);
+    }
 }
@@ -781,6 +781,216 @@ pub mod zero_copy_connection_trait {
         }
     }
 
+    #[conformance_test]
+    pub fn receive_latest_from_empty_buffer_returns_none<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_receiver.receive_latest(id, |_| true), eq Ok(None));
+        assert_that!(sut_receiver.borrow_count(id), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_returns_newest_and_releases_older_samples<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 8;
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for n in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(n * SAMPLE_SIZE), SAMPLE_SIZE, id),
+                is_ok
+            );
+        }
+
+        let sample = sut_receiver.receive_latest(id, |_| true).unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(sample.unwrap().offset(), eq(BUFFER_SIZE - 1) * SAMPLE_SIZE);
+        assert_that!(sut_receiver.borrow_count(id), eq 1);
+        assert_that!(sut_receiver.has_data(id), eq false);
+        assert_that!(sut_receiver.receive(id).unwrap(), is_none);
+
+        for n in 0..BUFFER_SIZE - 1 {
+            let retrieval = sut_sender.reclaim(id).unwrap();
+            assert_that!(retrieval, is_some);
+            assert_that!(retrieval.unwrap().offset(), eq n * SAMPLE_SIZE);
+        }
+        assert_that!(sut_sender.reclaim(id).unwrap(), is_none);
+
+        assert_that!(sut_receiver.release(sample.unwrap(), id), is_ok);
+        assert_that!(sut_receiver.borrow_count(id), eq 0);
+        let retrieval = sut_sender.reclaim(id).unwrap();
+        assert_that!(
+            retrieval.unwrap().offset(),
+            eq(BUFFER_SIZE - 1) * SAMPLE_SIZE
+        );
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_returns_newest_accepted_and_releases_all_others<
+        Sut: ZeroCopyConnection,
+    >() {
+        const BUFFER_SIZE: usize = 8;
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for n in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(n * SAMPLE_SIZE), SAMPLE_SIZE, id),
+                is_ok
+            );
+        }
+
+        let mut checked_offsets = vec![];
+        let sample = sut_receiver
+            .receive_latest(id, |offset| {
+                checked_offsets.push(offset.offset());
+                (offset.offset() / SAMPLE_SIZE) % 2 == 0
+            })
+            .unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(sample.unwrap().offset(), eq(BUFFER_SIZE - 2) * SAMPLE_SIZE);
+        assert_that!(sut_receiver.borrow_count(id), eq 1);
+        assert_that!(sut_receiver.has_data(id), eq false);
+
+        let expected_offsets: Vec<usize> = (0..BUFFER_SIZE).map(|n| n * SAMPLE_SIZE).collect();
+        assert_that!(checked_offsets, eq expected_offsets);
+
+        let mut released_offsets = vec![];
+        while let Some(retrieval) = sut_sender.reclaim(id).unwrap() {
+            released_offsets.push(retrieval.offset());
+        }
+        released_offsets.sort();
+        let expected_offsets: Vec<usize> = (0..BUFFER_SIZE)
+            .filter(|n| *n != BUFFER_SIZE - 2)
+            .map(|n| n * SAMPLE_SIZE)
+            .collect();
+        assert_that!(released_offsets, eq expected_offsets);
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_returns_none_when_nothing_is_accepted<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 4;
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for n in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(n * SAMPLE_SIZE), SAMPLE_SIZE, id),
+                is_ok
+            );
+        }
+
+        assert_that!(sut_receiver.receive_latest(id, |_| false), eq Ok(None));
+        assert_that!(sut_receiver.borrow_count(id), eq 0);
+        assert_that!(sut_receiver.has_data(id), eq false);
+
+        for n in 0..BUFFER_SIZE {
+            let retrieval = sut_sender.reclaim(id).unwrap();
+            assert_that!(retrieval.unwrap().offset(), eq n * SAMPLE_SIZE);
+        }
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_cannot_borrow_more_samples_than_set_up<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 8;
+        const MAX_BORROW: usize = 1;
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples_per_channel(MAX_BORROW)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples_per_channel(MAX_BORROW)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for n in 0..3 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(n * SAMPLE_SIZE), SAMPLE_SIZE, id),
+                is_ok
+            );
+        }
+
+        let sample = sut_receiver.receive_latest(id, |_| true).unwrap().unwrap();
+
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(3 * SAMPLE_SIZE), SAMPLE_SIZE, id),
+            is_ok
+        );
+        assert_that!(
+            sut_receiver.receive_latest(id, |_| true).err(),
+            eq Some(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue)
+        );
+
+        assert_that!(sut_receiver.release(sample, id), is_ok);
+        let sample = sut_receiver.receive_latest(id, |_| true).unwrap();
+        assert_that!(sample.unwrap().offset(), eq 3 * SAMPLE_SIZE);
+    }
+
     #[conformance_test]
     pub fn blocking_send_blocks<Sut: ZeroCopyConnection>() {
         let id = ChannelId::new(0);
@@ -1933,4 +2143,102 @@ pub mod zero_copy_connection_trait {
 
         assert_that!(sut_receiver.channel_state(CHANNEL_ID).load(Ordering::Relaxed), eq 789);
     }
+
+    #[conformance_test]
+    pub fn channel_credits_are_set_to_default_value_on_creation<Sut: ZeroCopyConnection>() {
+        const NUMBER_OF_CHANNELS: usize = 7;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .number_of_channels(NUMBER_OF_CHANNELS)
+            .create_receiver()
+            .unwrap();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .config(&config)
+            .number_of_channels(NUMBER_OF_CHANNELS)
+            .create_sender()
+            .unwrap();
+
+        for id in 0..NUMBER_OF_CHANNELS {
+            assert_that!(sut_receiver.channel_credits(ChannelId::new(id)).load(Ordering::Relaxed), eq INITIAL_CHANNEL_CREDITS);
+            assert_that!(sut_sender.channel_credits(ChannelId::new(id)).load(Ordering::Relaxed), eq INITIAL_CHANNEL_CREDITS);
+        }
+    }
+
+    #[conformance_test]
+    pub fn changing_channel_credits_works<Sut: ZeroCopyConnection>() {
+        const CHANNEL_ID: ChannelId = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        sut_receiver
+            .channel_credits(CHANNEL_ID)
+            .store(12, Ordering::Relaxed);
+
+        let sut_sender = Sut::Builder::new(&name)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        assert_that!(sut_sender.channel_credits(CHANNEL_ID).load(Ordering::Relaxed), eq 12);
+        sut_sender
+            .channel_credits(CHANNEL_ID)
+            .fetch_sub(1, Ordering::Relaxed);
+
+        assert_that!(sut_receiver.channel_credits(CHANNEL_ID).load(Ordering::Relaxed), eq 11);
+        assert_that!(sut_receiver.channel_state(CHANNEL_ID).load(Ordering::Relaxed), eq INITIAL_CHANNEL_STATE);
+    }
+
+    #[conformance_test]
+    pub fn channel_wake_up_is_set_to_default_value_on_creation<Sut: ZeroCopyConnection>() {
+        const NUMBER_OF_CHANNELS: usize = 5;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .config(&config)
+            .number_of_channels(NUMBER_OF_CHANNELS)
+            .create_sender()
+            .unwrap();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .number_of_channels(NUMBER_OF_CHANNELS)
+            .create_receiver()
+            .unwrap();
+
+        for id in 0..NUMBER_OF_CHANNELS {
+            assert_that!(sut_receiver.channel_wake_up(ChannelId::new(id)).load(Ordering::Relaxed), eq INITIAL_CHANNEL_WAKE_UP);
+            assert_that!(sut_sender.channel_wake_up(ChannelId::new(id)).load(Ordering::Relaxed), eq INITIAL_CHANNEL_WAKE_UP);
+        }
+    }
+
+    #[conformance_test]
+    pub fn changing_channel_wake_up_works<Sut: ZeroCopyConnection>() {
+        const CHANNEL_ID: ChannelId = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        sut_receiver
+            .channel_wake_up(CHANNEL_ID)
+            .store(3, Ordering::Relaxed);
+
+        assert_that!(sut_sender.channel_wake_up(CHANNEL_ID).load(Ordering::Relaxed), eq 3);
+        assert_that!(sut_sender.channel_credits(CHANNEL_ID).load(Ordering::Relaxed), eq INITIAL_CHANNEL_CREDITS);
+    }
 }
@@ -13,6 +13,7 @@
 use iceoryx2_bb_testing::instantiate_conformance_tests_with_module;
 use iceoryx2_cal_conformance_tests::dynamic_storage_trait::TestData;
 
+use iceoryx2_cal::dynamic_storage::posix_shared_memory::HugePageStorage;
 use iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage as PosixStorage;
 use iceoryx2_cal::dynamic_storage::process_local::Storage as LocalStorage;
 
@@ -23,6 +24,13 @@ instantiate_conformance_tests_with_module!(
     super::PosixStorage<u64>
 );
 
+instantiate_conformance_tests_with_module!(
+    huge_page_shared_memory,
+    iceoryx2_cal_conformance_tests::dynamic_storage_trait,
+    super::HugePageStorage<super::TestData>,
+    super::HugePageStorage<u64>
+);
+
 instantiate_conformance_tests_with_module!(
     process_local,
     iceoryx2_cal_conformance_tests::dynamic_storage_trait,
@@ -41,3 +41,18 @@ mod process_local {
         super::ResizeableSharedMemory
     );
 }
+
+mod huge_page {
+    use super::*;
+    use iceoryx2_cal::resizable_shared_memory::huge_page::Memory as ResizableMemory;
+    use iceoryx2_cal::shared_memory::huge_page::Memory;
+
+    type SharedMemory = Memory<super::DefaultAllocator>;
+    type ResizeableSharedMemory = ResizableMemory<super::DefaultAllocator>;
+
+    instantiate_conformance_tests!(
+        iceoryx2_cal_conformance_tests::resizable_shared_memory_trait,
+        super::SharedMemory,
+        super::ResizeableSharedMemory
+    );
+}
@@ -39,6 +39,12 @@
 //! storage.get().store(456, Ordering::Relaxed);
 //!
 //! ```
+//!
+//! # Huge Pages
+//!
+//! [`HugePageStorage`] places the [`DynamicStorage`] into a [`SharedMemory`] that is backed by
+//! huge pages (see [`SharedMemoryBuilder::huge_pages()`]). When no huge pages are available, it
+//! falls back to the regular POSIX shared memory.
 pub use crate::dynamic_storage::*;
 pub use core::ops::Deref;
 
@@ -58,7 +64,9 @@ use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::warn;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::directory::*;
+use iceoryx2_bb_posix::file::FileSetOwnerError;
 use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_posix::ownership::Ownership;
 use iceoryx2_bb_posix::shared_memory::*;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
@@ -77,8 +85,14 @@ const FINAL_PERMISSIONS: Permission = Permission::OWNER_ALL;
 const FINAL_PERMISSIONS: Permission = Permission::ALL;
 
 /// The builder of [`Storage`].
+pub type Builder<'builder, T> = StorageBuilder<'builder, T, false>;
+
+/// The builder of [`HugePageStorage`].
+pub type HugePageBuilder<'builder, T> = StorageBuilder<'builder, T, true>;
+
+/// The generic builder of [`Storage`], use [`Builder`] or [`HugePageBuilder`] instead.
 #[derive(Debug)]
-pub struct Builder<'builder, T: Send + Sync + Debug> {
+pub struct StorageBuilder<'builder, T: Send + Sync + Debug, const HUGE_PAGES: bool> {
     storage_name: FileName,
     call_drop_on_destruction: bool,
     supplementary_size: usize,
@@ -94,6 +108,8 @@ pub struct Configuration<T: Send + Sync + Debug> {
     suffix: FileName,
     prefix: FileName,
     path: Path,
+    permission: Option<Permission>,
+    ownership: Option<Ownership>,
     _data: PhantomData<T>,
     type_name: String,
 }
@@ -104,6 +120,8 @@ impl<T: Send + Sync + Debug> Clone for Configuration<T> {
             suffix: self.suffix,
             prefix: self.prefix,
             path: self.path,
+            permission: self.permission,
+            ownership: self.ownership,
             _data: PhantomData,
             type_name: self.type_name.clone(),
         }
@@ -123,6 +141,8 @@ impl<T: Send + Sync + Debug> Default for Configuration<T> {
             path: Storage::<()>::default_path_hint(),
             suffix: Storage::<()>::default_suffix(),
             prefix: Storage::<()>::default_prefix(),
+            permission: None,
+            ownership: None,
             _data: PhantomData,
             type_name: core::any::type_name::<T>().to_string(),
         }
@@ -163,6 +183,24 @@ impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
         &self.path
     }
 
+    fn permission(mut self, value: Permission) -> Self {
+        self.permission = Some(value);
+        self
+    }
+
+    fn get_permission(&self) -> Option<Permission> {
+        self.permission
+    }
+
+    fn ownership(mut self, value: Ownership) -> Self {
+        self.ownership = Some(value);
+        self
+    }
+
+    fn get_ownership(&self) -> Option<Ownership> {
+        self.ownership
+    }
+
     fn path_for(&self, value: &FileName) -> iceoryx2_bb_system_types::file_path::FilePath {
         self.path_for_with_type(value)
     }
@@ -172,7 +210,9 @@ impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
     }
 }
 
-impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T> {
+impl<T: Send + Sync + Debug, const HUGE_PAGES: bool> NamedConceptBuilder<Storage<T, HUGE_PAGES>>
+    for StorageBuilder<'_, T, HUGE_PAGES>
+{
     fn new(storage_name: &FileName) -> Self {
         Self {
             call_drop_on_destruction: true,
@@ -192,8 +232,8 @@ impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T>
     }
 }
 
-impl<T: Send + Sync + Debug> Builder<'_, T> {
-    fn open_impl(&self) -> Result<Storage<T>, DynamicStorageOpenError> {
+impl<T: Send + Sync + Debug, const HUGE_PAGES: bool> StorageBuilder<'_, T, HUGE_PAGES> {
+    fn open_impl(&self) -> Result<Storage<T, HUGE_PAGES>, DynamicStorageOpenError> {
         let msg = "Failed to open posix_shared_memory::DynamicStorage";
 
         let full_name = self.config.path_for(&self.storage_name).file_name();
@@ -203,7 +243,10 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
 
         let mut elapsed_time = Duration::ZERO;
         let shm = loop {
-            match SharedMemoryBuilder::new(&full_name).open_existing(AccessMode::ReadWrite) {
+            match SharedMemoryBuilder::new(&full_name)
+                .huge_pages(HUGE_PAGES)
+                .open_existing(AccessMode::ReadWrite)
+            {
                 Ok(v) => break v,
                 Err(SharedMemoryCreationError::DoesNotExist) => {
                     fail!(from self, with DynamicStorageOpenError::DoesNotExist,
@@ -272,6 +315,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
 
         let full_name = self.config.path_for(&self.storage_name).file_name();
         let shm = match SharedMemoryBuilder::new(&full_name)
+            .huge_pages(HUGE_PAGES)
             .creation_mode(CreationMode::CreateExclusive)
             // posix shared memory is always aligned to the greatest possible value (PAGE_SIZE)
             // therefore we do not have to add additional alignment space for T
@@ -303,7 +347,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         &mut self,
         mut shm: SharedMemory,
         initial_value: T,
-    ) -> Result<Storage<T>, DynamicStorageCreateError> {
+    ) -> Result<Storage<T, HUGE_PAGES>, DynamicStorageCreateError> {
         let msg = "Failed to init dynamic_storage::PosixSharedMemory";
         let value = shm.base_address().as_ptr() as *mut Data<T>;
         let version_ptr = unsafe { core::ptr::addr_of_mut!((*value).version) };
@@ -344,7 +388,23 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         //////////////////////////////////////////
         unsafe { (*version_ptr).store(PackageVersion::get().to_u64(), Ordering::SeqCst) };
 
-        if let Err(e) = shm.set_permission(FINAL_PERMISSIONS) {
+        if let Some(ownership) = self.config.ownership {
+            if let Err(e) = shm.set_ownership(ownership) {
+                unsafe { core::ptr::drop_in_place(value) };
+                shm.acquire_ownership();
+                if e == FileSetOwnerError::InsufficientPermissions {
+                    fail!(from origin, with DynamicStorageCreateError::InsufficientPermissions,
+                        "{} due to insufficient permissions to change the ownership of the underlying shared memory to {}:{}.",
+                        msg, ownership.uid(), ownership.gid());
+                }
+                fail!(from origin, with DynamicStorageCreateError::InternalError,
+                    "{} since the ownership could not be applied to the underlying shared memory ({:?}).",
+                    msg, e);
+            }
+        }
+
+        let final_permission = self.config.permission.unwrap_or(FINAL_PERMISSIONS);
+        if let Err(e) = shm.set_permission(final_permission) {
             unsafe { core::ptr::drop_in_place(value) };
             shm.acquire_ownership();
             fail!(from origin, with DynamicStorageCreateError::InternalError,
@@ -360,8 +420,9 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
     }
 }
 
-impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storage<T>>
-    for Builder<'builder, T>
+impl<'builder, T: Send + Sync + Debug, const HUGE_PAGES: bool>
+    DynamicStorageBuilder<'builder, T, Storage<T, HUGE_PAGES>>
+    for StorageBuilder<'builder, T, HUGE_PAGES>
 {
     fn call_drop_on_destruction(mut self, value: bool) -> Self {
         self.call_drop_on_destruction = value;
@@ -391,19 +452,22 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
         self
     }
 
-    fn create(mut self, initial_value: T) -> Result<Storage<T>, DynamicStorageCreateError> {
+    fn create(
+        mut self,
+        initial_value: T,
+    ) -> Result<Storage<T, HUGE_PAGES>, DynamicStorageCreateError> {
         let shm = self.create_impl()?;
         self.init_impl(shm, initial_value)
     }
 
-    fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+    fn open(self) -> Result<Storage<T, HUGE_PAGES>, DynamicStorageOpenError> {
         self.open_impl()
     }
 
     fn open_or_create(
         mut self,
         initial_value: T,
-    ) -> Result<Storage<T>, DynamicStorageOpenOrCreateError> {
+    ) -> Result<Storage<T, HUGE_PAGES>, DynamicStorageOpenOrCreateError> {
         loop {
             match self.open_impl() {
                 Ok(storage) => return Ok(storage),
@@ -421,18 +485,22 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
 }
 
 /// Implements [`DynamicStorage`] for POSIX shared memory. It is built by
-/// [`Builder`].
+/// [`StorageBuilder`]. When `HUGE_PAGES` is set, the shared memory is backed by huge pages whenever
+/// they are available.
 #[derive(Debug)]
-pub struct Storage<T: Debug + Send + Sync> {
+pub struct Storage<T: Debug + Send + Sync, const HUGE_PAGES: bool = false> {
     shm: SharedMemory,
     name: FileName,
     _phantom_data: PhantomData<T>,
 }
 
-unsafe impl<T: Debug + Send + Sync> Send for Storage<T> {}
-unsafe impl<T: Debug + Send + Sync> Sync for Storage<T> {}
+/// A [`Storage`] that is backed by huge pages whenever they are available.
+pub type HugePageStorage<T> = Storage<T, true>;
+
+unsafe impl<T: Debug + Send + Sync, const HUGE_PAGES: bool> Send for Storage<T, HUGE_PAGES> {}
+unsafe impl<T: Debug + Send + Sync, const HUGE_PAGES: bool> Sync for Storage<T, HUGE_PAGES> {}
 
-impl<T: Debug + Send + Sync> Drop for Storage<T> {
+impl<T: Debug + Send + Sync, const HUGE_PAGES: bool> Drop for Storage<T, HUGE_PAGES> {
     fn drop(&mut self) {
         if self.shm.has_ownership() {
             let data = unsafe { &mut (*(self.shm.base_address().as_ptr() as *mut Data<T>)) };
@@ -444,13 +512,13 @@ impl<T: Debug + Send + Sync> Drop for Storage<T> {
     }
 }
 
-impl<T: Send + Sync + Debug> NamedConcept for Storage<T> {
+impl<T: Send + Sync + Debug, const HUGE_PAGES: bool> NamedConcept for Storage<T, HUGE_PAGES> {
     fn name(&self) -> &FileName {
         &self.name
     }
 }
 
-impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
+impl<T: Send + Sync + Debug, const HUGE_PAGES: bool> NamedConceptMgmt for Storage<T, HUGE_PAGES> {
     type Configuration = Configuration<T>;
 
     fn does_exist_cfg(
@@ -459,20 +527,26 @@ impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
     ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
         let full_name = cfg.path_for(name).file_name();
 
-        Ok(iceoryx2_bb_posix::shared_memory::SharedMemory::does_exist(
-            &full_name,
-        ))
+        Ok(
+            iceoryx2_bb_posix::shared_memory::SharedMemory::does_exist(&full_name)
+                || (HUGE_PAGES && SharedMemory::does_exist_in_huge_pages(&full_name)),
+        )
     }
 
     fn list_cfg(
         config: &Self::Configuration,
     ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
-        let entries = SharedMemory::list();
+        let mut entries = SharedMemory::list();
+        if HUGE_PAGES {
+            entries.extend(SharedMemory::list_huge_pages());
+        }
 
         let mut result = vec![];
         for entry in &entries {
             if let Some(entry_name) = config.extract_name_from_file(entry) {
-                result.push(entry_name);
+                if !result.contains(&entry_name) {
+                    result.push(entry_name);
+                }
             }
         }
 
@@ -487,7 +561,10 @@ impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
         let msg = "Unable to remove dynamic_storage::posix_shared_memory";
         let origin = "dynamic_storage::posix_shared_memory::Storage::remove_cfg()";
 
-        match Builder::<T>::new(name).config(cfg).open() {
+        match StorageBuilder::<T, HUGE_PAGES>::new(name)
+            .config(cfg)
+            .open()
+        {
             Ok(s) => {
                 s.acquire_ownership();
                 Ok(true)
@@ -498,7 +575,15 @@ impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
                     "Removing DynamicStorage in broken state ({:?}) will not call drop of the underlying data type {:?}.",
                     e, core::any::type_name::<T>());
 
-                match iceoryx2_bb_posix::shared_memory::SharedMemory::remove(&full_name) {
+                let removed_huge_page = match HUGE_PAGES {
+                    true => SharedMemory::remove_from_huge_pages(&full_name),
+                    false => Ok(false),
+                };
+
+                match removed_huge_page.and_then(|removed_huge_page| {
+                    Ok(iceoryx2_bb_posix::shared_memory::SharedMemory::remove(&full_name)?
+                        || removed_huge_page)
+                }) {
                     Ok(v) => Ok(v),
                     Err(
                         iceoryx2_bb_posix::shared_memory::SharedMemoryRemoveError::InsufficientPermissions,
@@ -522,8 +607,8 @@ impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
     }
 }
 
-impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
-    type Builder<'builder> = Builder<'builder, T>;
+impl<T: Send + Sync + Debug, const HUGE_PAGES: bool> DynamicStorage<T> for Storage<T, HUGE_PAGES> {
+    type Builder<'builder> = StorageBuilder<'builder, T, HUGE_PAGES>;
 
     fn does_support_persistency() -> bool {
         SharedMemory::does_support_persistency()
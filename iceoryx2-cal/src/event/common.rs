@@ -18,6 +18,7 @@ pub mod details {
 
     use iceoryx2_bb_log::{debug, fail};
     use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_posix::{ownership::Ownership, permission::Permission};
     use iceoryx2_bb_system_types::{file_name::FileName, path::Path};
     use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
@@ -55,6 +56,8 @@ pub mod details {
         suffix: FileName,
         prefix: FileName,
         path: Path,
+        permission: Option<Permission>,
+        ownership: Option<Ownership>,
         _tracker: PhantomData<Tracker>,
         _wait_mechanism: PhantomData<WaitMechanism>,
         _storage: PhantomData<Storage>,
@@ -71,6 +74,8 @@ pub mod details {
                 path: EventImpl::<Tracker, WaitMechanism, Storage>::default_path_hint(),
                 suffix: EventImpl::<Tracker, WaitMechanism, Storage>::default_suffix(),
                 prefix: EventImpl::<Tracker, WaitMechanism, Storage>::default_prefix(),
+                permission: None,
+                ownership: None,
                 _tracker: PhantomData,
                 _wait_mechanism: PhantomData,
                 _storage: PhantomData,
@@ -85,10 +90,20 @@ pub mod details {
         > Configuration<Tracker, WaitMechanism, Storage>
     {
         fn convert(&self) -> <Storage as NamedConceptMgmt>::Configuration {
-            <Storage as NamedConceptMgmt>::Configuration::default()
+            let mut config = <Storage as NamedConceptMgmt>::Configuration::default()
                 .prefix(&self.prefix)
                 .suffix(&self.suffix)
-                .path_hint(&self.path)
+                .path_hint(&self.path);
+
+            if let Some(permission) = self.permission {
+                config = config.permission(permission);
+            }
+
+            if let Some(ownership) = self.ownership {
+                config = config.ownership(ownership);
+            }
+
+            config
         }
     }
 
@@ -123,6 +138,8 @@ pub mod details {
                 suffix: self.suffix,
                 prefix: self.prefix,
                 path: self.path,
+                permission: self.permission,
+                ownership: self.ownership,
                 _tracker: PhantomData,
                 _wait_mechanism: PhantomData,
                 _storage: PhantomData,
@@ -162,6 +179,24 @@ pub mod details {
         fn get_path_hint(&self) -> &Path {
             &self.path
         }
+
+        fn permission(mut self, value: Permission) -> Self {
+            self.permission = Some(value);
+            self
+        }
+
+        fn get_permission(&self) -> Option<Permission> {
+            self.permission
+        }
+
+        fn ownership(mut self, value: Ownership) -> Self {
+            self.ownership = Some(value);
+            self
+        }
+
+        fn get_ownership(&self) -> Option<Ownership> {
+            self.ownership
+        }
     }
 
     #[derive(Debug)]
@@ -20,7 +20,7 @@ use crate::static_storage::file::NamedConceptConfiguration;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::{
     file_descriptor::FileDescriptorBased, file_descriptor_set::SynchronousMultiplexing,
-    unix_datagram_socket::*,
+    permission::Permission, unix_datagram_socket::*,
 };
 pub use iceoryx2_bb_system_types::file_name::FileName;
 
@@ -31,6 +31,7 @@ pub struct Configuration {
     suffix: FileName,
     prefix: FileName,
     path: Path,
+    permission: Option<Permission>,
 }
 
 impl Default for Configuration {
@@ -39,6 +40,7 @@ impl Default for Configuration {
             path: EventImpl::default_path_hint(),
             suffix: EventImpl::default_suffix(),
             prefix: EventImpl::default_prefix(),
+            permission: None,
         }
     }
 }
@@ -70,6 +72,15 @@ impl NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path
     }
+
+    fn permission(mut self, value: Permission) -> Self {
+        self.permission = Some(value);
+        self
+    }
+
+    fn get_permission(&self) -> Option<Permission> {
+        self.permission
+    }
 }
 
 impl From<Configuration> for crate::communication_channel::unix_datagram::Configuration {
@@ -363,10 +374,13 @@ impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
     fn create(self) -> Result<Listener, ListenerCreateError> {
         let msg = "Failed to create event::unix_datagram_socket::Listener";
         let full_name = self.config.path_for(&self.name);
-        match UnixDatagramReceiverBuilder::new(&full_name)
-            .creation_mode(CreationMode::CreateExclusive)
-            .create()
-        {
+        let mut builder = UnixDatagramReceiverBuilder::new(&full_name)
+            .creation_mode(CreationMode::CreateExclusive);
+        if let Some(permission) = self.config.permission {
+            builder = builder.permission(permission);
+        }
+
+        match builder.create() {
             Ok(r) => Ok(Listener {
                 receiver: r,
                 name: self.name,
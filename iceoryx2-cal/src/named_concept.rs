@@ -19,6 +19,8 @@ use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_posix::config::TEMP_DIRECTORY;
 use iceoryx2_bb_posix::directory::{Directory, DirectoryRemoveError};
+use iceoryx2_bb_posix::ownership::Ownership;
+use iceoryx2_bb_posix::permission::Permission;
 pub use iceoryx2_bb_system_types::file_name::FileName;
 pub use iceoryx2_bb_system_types::file_path::FilePath;
 pub use iceoryx2_bb_system_types::path::Path;
@@ -106,6 +108,31 @@ pub trait NamedConceptConfiguration: Default + Clone + Debug + Send {
     /// Returns the configurations path hint.
     fn get_path_hint(&self) -> &Path;
 
+    /// Defines the [`Permission`] the underlying resources shall have after they were created.
+    /// When it is not set the concept uses its own default. Concepts that use resources without
+    /// access rights, like process local constructs, ignore the value.
+    fn permission(self, _value: Permission) -> Self {
+        self
+    }
+
+    /// Returns the configured [`Permission`] or [`None`] when the concept default is used.
+    fn get_permission(&self) -> Option<Permission> {
+        None
+    }
+
+    /// Defines the [`Ownership`] the underlying resources shall have after they were created.
+    /// When it is not set the resources are owned by the creating process. Concepts that use
+    /// resources without an owner, like process local constructs, ignore the value.
+    fn ownership(self, _value: Ownership) -> Self {
+        self
+    }
+
+    /// Returns the configured [`Ownership`] or [`None`] when the resources are owned by the
+    /// creating process.
+    fn get_ownership(&self) -> Option<Ownership> {
+        None
+    }
+
     /// Returns the full path for a given value under the given configuration.
     fn path_for(&self, value: &FileName) -> FilePath {
         let mut path = *self.get_path_hint();
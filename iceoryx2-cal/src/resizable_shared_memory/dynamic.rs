@@ -32,7 +32,8 @@ use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
 
 use crate::shared_memory::{
-    AllocationStrategy, SegmentId, SharedMemoryForPoolAllocator, ShmPointer,
+    AllocationStrategy, MemoryLockPolicy, NumaPlacement, SegmentId, SharedMemoryForPoolAllocator,
+    ShmPointer,
 };
 use crate::shared_memory::{
     PointerOffset, SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError,
@@ -67,6 +68,8 @@ struct MemoryConfig<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     base_name: FileName,
     shm: Shm::Configuration,
     allocator_config_hint: Allocator::Configuration,
+    memory_lock_policy: MemoryLockPolicy,
+    numa_placement: NumaPlacement,
 }
 
 #[derive(Debug)]
@@ -205,6 +208,8 @@ where
                 base_name: *name,
                 allocator_config_hint: Allocator::Configuration::default(),
                 shm: Shm::Configuration::default(),
+                memory_lock_policy: MemoryLockPolicy::Disabled,
+                numa_placement: NumaPlacement::Disabled,
             },
             shared_state: SharedState {
                 allocation_strategy: AllocationStrategy::default(),
@@ -250,6 +255,16 @@ where
         self
     }
 
+    fn memory_lock_policy(mut self, value: MemoryLockPolicy) -> Self {
+        self.config.memory_lock_policy = value;
+        self
+    }
+
+    fn numa_placement(mut self, value: NumaPlacement) -> Self {
+        self.config.numa_placement = value;
+        self
+    }
+
     fn create(mut self) -> Result<DynamicMemory<Allocator, Shm>, SharedMemoryCreateError> {
         let msg = "Unable to create ResizableSharedMemory";
         let origin = format!("{self:?}");
@@ -577,6 +592,8 @@ where
         Self::segment_builder(&config.base_name, &config.shm, segment_id)
             .has_ownership(true)
             .size(payload_size)
+            .memory_lock_policy(config.memory_lock_policy)
+            .numa_placement(config.numa_placement)
             .create(&config.allocator_config_hint)
     }
 
@@ -733,6 +750,14 @@ where
         self.state().shared_memory_map.len()
     }
 
+    fn numa_node(&self) -> Option<usize> {
+        let state = self.state();
+        state
+            .shared_memory_map
+            .get(state.current_idx)
+            .and_then(|entry| entry.shm.numa_node())
+    }
+
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, ResizableShmAllocationError> {
         let msg = "Unable to allocate memory";
         let state = self.state_mut();
@@ -0,0 +1,19 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`ResizableSharedMemory`](crate::resizable_shared_memory::ResizableSharedMemory) whose
+//! segments are backed by huge pages, see [`crate::shared_memory::huge_page`].
+
+pub type Memory<Allocator> = crate::resizable_shared_memory::dynamic::DynamicMemory<
+    Allocator,
+    crate::shared_memory::huge_page::Memory<Allocator>,
+>;
@@ -87,6 +87,7 @@
 //! ```
 
 pub mod dynamic;
+pub mod huge_page;
 pub mod recommended;
 
 pub use crate::shm_allocator::{pool_allocator::PoolAllocator, AllocationStrategy};
@@ -99,7 +100,8 @@ use iceoryx2_bb_elementary::enum_gen;
 
 use crate::named_concept::*;
 use crate::shared_memory::{
-    SegmentId, SharedMemory, SharedMemoryCreateError, SharedMemoryOpenError, ShmPointer,
+    MemoryLockPolicy, NumaPlacement, SegmentId, SharedMemory, SharedMemoryCreateError,
+    SharedMemoryOpenError, ShmPointer,
 };
 use crate::shm_allocator::{PointerOffset, ShmAllocationError, ShmAllocator};
 
@@ -171,6 +173,14 @@ pub trait ResizableSharedMemoryBuilder<
     /// acquired.
     fn allocation_strategy(self, value: AllocationStrategy) -> Self;
 
+    /// Defines the [`MemoryLockPolicy`] that is applied to every [`SharedMemory`] segment that
+    /// is acquired. By default it is set to [`MemoryLockPolicy::Disabled`].
+    fn memory_lock_policy(self, value: MemoryLockPolicy) -> Self;
+
+    /// Defines the [`NumaPlacement`] that is applied to every [`SharedMemory`] segment that
+    /// is acquired. By default it is set to [`NumaPlacement::Disabled`].
+    fn numa_placement(self, value: NumaPlacement) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(self) -> Result<ResizableShm, SharedMemoryCreateError>;
 }
@@ -231,6 +241,10 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
     /// Returns the number of active [`SharedMemory`] segments.
     fn number_of_active_segments(&self) -> usize;
 
+    /// Returns the id of the NUMA node the current [`SharedMemory`] segment is bound to. See
+    /// [`SharedMemory::numa_node()`].
+    fn numa_node(&self) -> Option<usize>;
+
     /// Allocates a new piece of [`SharedMemory`] if the provided [`Layout`] exceeds the current
     /// supported [`Layout`], the memory would be out-of-memory or the number of chunks exceeds the
     /// current supported amount of chunks, a new [`SharedMemory`] segment will be created. If this
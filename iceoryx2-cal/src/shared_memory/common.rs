@@ -15,7 +15,10 @@ use core::mem::MaybeUninit;
 use core::{alloc::Layout, fmt::Debug};
 
 use iceoryx2_bb_elementary_traits::allocator::BaseAllocator;
-use iceoryx2_bb_log::{debug, fail};
+#[cfg(target_os = "linux")]
+use iceoryx2_bb_linux::numa::NumaTopology;
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::memory_lock::MemoryLock;
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
@@ -33,6 +36,7 @@ pub mod details {
     use alloc::vec::Vec;
 
     use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_posix::{ownership::Ownership, permission::Permission};
     use pool_allocator::PoolAllocator;
 
     use super::*;
@@ -124,6 +128,24 @@ pub mod details {
             self.dynamic_storage_config.get_path_hint()
         }
 
+        fn permission(mut self, value: Permission) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.permission(value);
+            self
+        }
+
+        fn get_permission(&self) -> Option<Permission> {
+            self.dynamic_storage_config.get_permission()
+        }
+
+        fn ownership(mut self, value: Ownership) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.ownership(value);
+            self
+        }
+
+        fn get_ownership(&self) -> Option<Ownership> {
+            self.dynamic_storage_config.get_ownership()
+        }
+
         fn path_for(&self, value: &FileName) -> FilePath {
             self.dynamic_storage_config.path_for(value)
         }
@@ -143,6 +165,8 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        memory_lock_policy: MemoryLockPolicy,
+        numa_placement: NumaPlacement,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -155,6 +179,8 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                memory_lock_policy: MemoryLockPolicy::Disabled,
+                numa_placement: NumaPlacement::Disabled,
             }
         }
 
@@ -198,6 +224,127 @@ pub mod details {
                 true
             }
         }
+
+        /// Returns the page aligned start address and the length of the memory range that
+        /// contains the payload. The page of the payload start address is always part of the
+        /// mapping.
+        fn payload_page_range(storage: &Storage, payload_start_address: usize) -> (usize, usize) {
+            let page_size = SystemInfo::PageSize.value();
+            let start = payload_start_address - payload_start_address % page_size;
+            let len = payload_start_address + storage.get().payload_size - start;
+            (start, len)
+        }
+
+        #[cfg(target_os = "linux")]
+        fn apply_numa_placement(
+            &self,
+            storage: &Storage,
+            payload_start_address: usize,
+        ) -> Result<Option<usize>, SharedMemoryCreateError> {
+            let msg = "Unable to create shared memory";
+            if self.numa_placement == NumaPlacement::Disabled {
+                return Ok(None);
+            }
+
+            let topology = match NumaTopology::new() {
+                Ok(topology) => topology,
+                Err(e) => {
+                    warn!(from self,
+                        "The numa placement {:?} is ignored since the numa topology could not be acquired ({:?}).",
+                        self.numa_placement, e);
+                    return Ok(None);
+                }
+            };
+
+            if topology.number_of_nodes() <= 1 {
+                warn!(from self,
+                    "The numa placement {:?} is ignored since the system has only one numa node.",
+                    self.numa_placement);
+                return Ok(None);
+            }
+
+            let node = match self.numa_placement {
+                NumaPlacement::Disabled => None,
+                NumaPlacement::Node(id) => topology.node(id),
+                NumaPlacement::NodeOfCpus(cpus) => {
+                    let node = cpus.iter().find_map(|cpu| topology.node_of_cpu(cpu));
+                    if let Some(node) = node {
+                        if cpus.iter().any(|cpu| !node.cpus().contains(cpu)) {
+                            warn!(from self,
+                                "The cpu cores of the numa placement {:?} belong to multiple numa nodes, the memory is bound to numa node {}.",
+                                self.numa_placement, node.id());
+                        }
+                    }
+                    node
+                }
+            };
+
+            let node = match node {
+                Some(node) => node,
+                None => {
+                    storage.acquire_ownership();
+                    fail!(from self, with SharedMemoryCreateError::UnableToApplyNumaPlacement,
+                        "{} since the numa placement {:?} does not refer to an online numa node.",
+                        msg, self.numa_placement);
+                }
+            };
+
+            // the placement must be applied before the memory is locked since locking faults
+            // all pages in
+            let (start, len) = Self::payload_page_range(storage, payload_start_address);
+            if let Err(e) = unsafe { node.bind_memory(start as *mut u8, len) } {
+                storage.acquire_ownership();
+                fail!(from self, with SharedMemoryCreateError::UnableToApplyNumaPlacement,
+                    "{} since the memory could not be bound to numa node {} ({:?}).",
+                    msg, node.id(), e);
+            }
+
+            Ok(Some(node.id()))
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        fn apply_numa_placement(
+            &self,
+            _storage: &Storage,
+            _payload_start_address: usize,
+        ) -> Result<Option<usize>, SharedMemoryCreateError> {
+            if self.numa_placement != NumaPlacement::Disabled {
+                warn!(from self,
+                    "The numa placement {:?} is ignored since it is not supported on this platform.",
+                    self.numa_placement);
+            }
+
+            Ok(None)
+        }
+
+        fn lock_memory(
+            &self,
+            storage: &Storage,
+            payload_start_address: usize,
+        ) -> Result<Option<MemoryLock>, SharedMemoryCreateError> {
+            if self.memory_lock_policy == MemoryLockPolicy::Disabled {
+                return Ok(None);
+            }
+
+            // mlock operates on whole pages
+            let (start, len) = Self::payload_page_range(storage, payload_start_address);
+
+            // locking the memory faults all pages in
+            match unsafe { MemoryLock::new(start as *const _, len) } {
+                Ok(memory_lock) => Ok(Some(memory_lock)),
+                Err(e) => {
+                    if self.memory_lock_policy == MemoryLockPolicy::Required {
+                        storage.acquire_ownership();
+                        fail!(from self, with SharedMemoryCreateError::UnableToLockMemory,
+                            "Unable to create shared memory since the memory could not be locked ({:?}).", e);
+                    }
+
+                    warn!(from self,
+                        "The shared memory could not be locked ({:?}), it is used without being locked.", e);
+                    Ok(None)
+                }
+            }
+        }
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -219,6 +366,16 @@ pub mod details {
             self
         }
 
+        fn memory_lock_policy(mut self, value: MemoryLockPolicy) -> Self {
+            self.memory_lock_policy = value;
+            self
+        }
+
+        fn numa_placement(mut self, value: NumaPlacement) -> Self {
+            self.numa_placement = value;
+            self
+        }
+
         fn create(
             self,
             allocator_config: &Allocator::Configuration,
@@ -265,8 +422,14 @@ pub mod details {
                 }
             };
 
+            let payload_start_address = get_payload_start_address(&storage);
+            let numa_node = self.apply_numa_placement(&storage, payload_start_address)?;
+            let memory_lock = self.lock_memory(&storage, payload_start_address)?;
+
             Ok(Memory::<Allocator, Storage> {
-                payload_start_address: get_payload_start_address(&storage),
+                payload_start_address,
+                memory_lock,
+                numa_node,
                 storage,
                 name: self.name,
                 _phantom: PhantomData,
@@ -316,6 +479,8 @@ pub mod details {
 
             Ok(Memory::<Allocator, Storage> {
                 payload_start_address: get_payload_start_address(&storage),
+                memory_lock: None,
+                numa_node: None,
                 name: self.name,
                 storage,
                 _phantom: PhantomData,
@@ -325,6 +490,9 @@ pub mod details {
 
     #[derive(Debug)]
     pub struct Memory<Allocator: ShmAllocator, Storage: DynamicStorage<AllocatorDetails<Allocator>>> {
+        // must be dropped before the storage unmaps the memory
+        memory_lock: Option<MemoryLock>,
+        numa_node: Option<usize>,
         storage: Storage,
         name: FileName,
         payload_start_address: usize,
@@ -405,6 +573,14 @@ pub mod details {
             Storage::does_support_persistency()
         }
 
+        fn is_memory_locked(&self) -> bool {
+            self.memory_lock.is_some()
+        }
+
+        fn numa_node(&self) -> Option<usize> {
+            self.numa_node
+        }
+
         fn has_ownership(&self) -> bool {
             self.storage.has_ownership()
         }
@@ -0,0 +1,25 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`SharedMemory`](crate::shared_memory::SharedMemory) that is backed by huge pages to reduce
+//! TLB misses when large payloads are accessed. On Linux the huge pages are provided by the
+//! hugetlbfs. When no huge pages are available, the regular POSIX shared memory is used.
+//!
+//! It can be used as `SharedMemory` of an `iceoryx2` service and, in combination with
+//! [`crate::resizable_shared_memory::huge_page::Memory`], as its `ResizableSharedMemory`.
+
+use super::common::details::AllocatorDetails;
+
+pub type Memory<Allocator> = crate::shared_memory::common::details::Memory<
+    Allocator,
+    crate::dynamic_storage::posix_shared_memory::HugePageStorage<AllocatorDetails<Allocator>>,
+>;
@@ -55,6 +55,7 @@
 //! ```
 
 pub mod common;
+pub mod huge_page;
 pub mod posix;
 pub mod process_local;
 pub mod recommended;
@@ -63,6 +64,7 @@ use core::{fmt::Debug, time::Duration};
 
 pub use crate::shm_allocator::*;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_bb_posix::cpu_set::CpuSet;
 use iceoryx2_bb_system_types::file_name::*;
 use pool_allocator::PoolAllocator;
 
@@ -72,6 +74,8 @@ pub enum SharedMemoryCreateError {
     AlreadyExists,
     SizeIsZero,
     InsufficientPermissions,
+    UnableToLockMemory,
+    UnableToApplyNumaPlacement,
     InternalError,
 }
 
@@ -104,6 +108,39 @@ impl core::fmt::Display for SharedMemoryOpenError {
 
 impl core::error::Error for SharedMemoryOpenError {}
 
+/// Defines if the payload memory of a newly created [`SharedMemory`] is locked into RAM, so that
+/// it is never swapped out, and how a failure to lock it is handled. Locking the memory also
+/// pre-faults all of its pages so that the first access does not cause a page fault.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MemoryLockPolicy {
+    /// The memory is not locked.
+    #[default]
+    Disabled,
+    /// The memory is locked. If this fails, a warning is emitted and the memory is used without
+    /// being locked.
+    BestEffort,
+    /// The memory is locked. If this fails, the creation fails with
+    /// [`SharedMemoryCreateError::UnableToLockMemory`].
+    Required,
+}
+
+/// Defines on which NUMA node the payload memory of a newly created [`SharedMemory`] is placed.
+/// On systems with only one NUMA node or without NUMA support the placement is ignored and a
+/// warning is emitted.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NumaPlacement {
+    /// The memory is placed by the operating system, usually on the NUMA node of the cpu core
+    /// that touches it first.
+    #[default]
+    Disabled,
+    /// The memory is bound to the NUMA node with the provided id.
+    Node(usize),
+    /// The memory is bound to the NUMA node the cpu cores belong to. If the cpu cores are spread
+    /// over multiple NUMA nodes, the node of the first cpu core is used.
+    NodeOfCpus(CpuSet),
+}
+
 /// Represents a pointer pointing to some [`SharedMemory`]. Consists of the actual data pointer and
 /// an [`PointerOffset`] which can be used in combination with a
 /// [`crate::zero_copy_connection::ZeroCopyConnection`]
@@ -141,6 +178,14 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Defines the [`MemoryLockPolicy`] of a newly created [`SharedMemory`]. By default it is
+    /// set to [`MemoryLockPolicy::Disabled`].
+    fn memory_lock_policy(self, value: MemoryLockPolicy) -> Self;
+
+    /// Defines the [`NumaPlacement`] of a newly created [`SharedMemory`]. By default it is
+    /// set to [`NumaPlacement::Disabled`].
+    fn numa_placement(self, value: NumaPlacement) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(
         self,
@@ -182,6 +227,15 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     ///  * the layout must be identical to the one used in [`SharedMemory::allocate()`]
     unsafe fn deallocate(&self, offset: PointerOffset, layout: core::alloc::Layout);
 
+    /// Returns true if the payload memory is locked into RAM, otherwise false. See
+    /// [`MemoryLockPolicy`].
+    fn is_memory_locked(&self) -> bool;
+
+    /// Returns the id of the NUMA node the memory was bound to with
+    /// [`SharedMemoryBuilder::numa_placement()`]. If the memory is not bound to a NUMA node or
+    /// was opened, [`None`] is returned.
+    fn numa_node(&self) -> Option<usize>;
+
     /// Returns if the [`SharedMemory`] supports persistency, meaning that the underlying OS
     /// resource remain even when every [`SharedMemory`] instance in every process was removed.
     fn does_support_persistency() -> bool;
@@ -57,17 +57,26 @@ use iceoryx2_bb_log::{fail, trace, warn};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::{
     directory::*, file::*, file_descriptor::FileDescriptorManagement, file_type::FileType,
+    ownership::Ownership,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
 const FINAL_PERMISSIONS: Permission = Permission::OWNER_READ;
 
+/// A static storage is finalized when the owner can only read it. As long as it is in the locked
+/// state the owner has write permissions.
+fn is_finalized(permission: Permission) -> bool {
+    permission.has(Permission::OWNER_READ) && !permission.has(Permission::OWNER_WRITE)
+}
+
 /// The custom configuration of the [`Storage`].
 #[derive(Clone, Debug)]
 pub struct Configuration {
     path: Path,
     suffix: FileName,
     prefix: FileName,
+    permission: Option<Permission>,
+    ownership: Option<Ownership>,
 }
 
 impl Default for Configuration {
@@ -76,7 +85,26 @@ impl Default for Configuration {
             path: Storage::default_path_hint(),
             suffix: Storage::default_suffix(),
             prefix: Storage::default_prefix(),
+            permission: None,
+            ownership: None,
+        }
+    }
+}
+
+impl Configuration {
+    /// The content of a static storage is immutable, therefore only the read permissions of the
+    /// configured [`Permission`] are applied.
+    fn final_permission(&self) -> Permission {
+        let mut permission = FINAL_PERMISSIONS;
+        if let Some(configured) = self.permission {
+            if configured.has(Permission::GROUP_READ) {
+                permission |= Permission::GROUP_READ;
+            }
+            if configured.has(Permission::OTHERS_READ) {
+                permission |= Permission::OTHERS_READ;
+            }
         }
+        permission
     }
 }
 
@@ -107,6 +135,24 @@ impl crate::named_concept::NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path
     }
+
+    fn permission(mut self, value: Permission) -> Self {
+        self.permission = Some(value);
+        self
+    }
+
+    fn get_permission(&self) -> Option<Permission> {
+        self.permission
+    }
+
+    fn ownership(mut self, value: Ownership) -> Self {
+        self.ownership = Some(value);
+        self
+    }
+
+    fn get_ownership(&self) -> Option<Ownership> {
+        self.ownership
+    }
 }
 
 impl crate::static_storage::StaticStorageConfiguration for Configuration {}
@@ -137,10 +183,11 @@ impl StaticStorageLocked<Storage> for Locked {
                 msg, contents.len(), bytes_written);
         }
 
-        fail!(from self, when self.static_storage.file.set_permission(FINAL_PERMISSIONS),
+        let final_permission = self.static_storage.config.final_permission();
+        fail!(from self, when self.static_storage.file.set_permission(final_permission),
                 map FileSetPermissionError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
                 unmatched StaticStorageUnlockError::InternalError,
-                "{} due to a failure while updating the permissions to {}.", msg, FINAL_PERMISSIONS);
+                "{} due to a failure while updating the permissions to {}.", msg, final_permission);
 
         self.static_storage.len = contents.len() as u64;
 
@@ -247,7 +294,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
             .iter()
             .filter(|entry| {
                 let metadata = entry.metadata();
-                metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS
+                metadata.file_type() == FileType::File && is_finalized(metadata.permission())
             })
             .filter_map(|entry| config.extract_name_from_file(entry.name()))
             .collect())
@@ -296,7 +343,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
         }
         let metadata = metadata.unwrap();
 
-        if metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS {
+        if metadata.file_type() == FileType::File && is_finalized(metadata.permission()) {
             return Ok(true);
         }
 
@@ -412,7 +459,8 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
             unmatched StaticStorageCreateError::Creation,
             "{} due to a failure while creating the underlying file.", msg);
 
-        Ok(Locked {
+        let ownership = self.config.ownership;
+        let mut locked = Locked {
             static_storage: Storage {
                 name: self.storage_name,
                 config: self.config,
@@ -420,7 +468,21 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
                 file,
                 len: 0,
             },
-        })
+        };
+
+        if let Some(ownership) = ownership {
+            // the storage is removed on failure when it has the ownership
+            locked.static_storage.acquire_ownership();
+            fail!(from locked, when locked.static_storage.file.set_ownership(ownership),
+                map FileSetOwnerError::InsufficientPermissions => StaticStorageCreateError::InsufficientPermissions,
+                unmatched StaticStorageCreateError::Creation,
+                "{} due to a failure while changing the ownership to {}:{}.", msg, ownership.uid(), ownership.gid());
+            if !self.has_ownership {
+                locked.static_storage.release_ownership();
+            }
+        }
+
+        Ok(locked)
     }
 
     fn open(self, timeout: Duration) -> Result<Storage, StaticStorageOpenError> {
@@ -444,7 +506,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
             when file.metadata(), with StaticStorageOpenError::Read,
             "{} due to a failure while reading the files metadata.", msg);
 
-            if metadata.permission() != FINAL_PERMISSIONS {
+            if !is_finalized(metadata.permission()) {
                 if elapsed_time > timeout {
                     fail!(from origin,
                         with StaticStorageOpenError::InitializationNotYetFinalized,
@@ -30,6 +30,7 @@ pub mod details {
     use iceoryx2_bb_log::{fail, fatal_panic};
     use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
     use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+    use iceoryx2_bb_posix::{ownership::Ownership, permission::Permission};
     use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
 
     pub use crate::zero_copy_connection::*;
@@ -100,6 +101,24 @@ pub mod details {
             self.dynamic_storage_config.get_path_hint()
         }
 
+        fn permission(mut self, value: Permission) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.permission(value);
+            self
+        }
+
+        fn get_permission(&self) -> Option<Permission> {
+            self.dynamic_storage_config.get_permission()
+        }
+
+        fn ownership(mut self, value: Ownership) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.ownership(value);
+            self
+        }
+
+        fn get_ownership(&self) -> Option<Ownership> {
+            self.dynamic_storage_config.get_ownership()
+        }
+
         fn path_for(&self, value: &FileName) -> FilePath {
             self.dynamic_storage_config.path_for(value)
         }
@@ -163,6 +182,8 @@ pub mod details {
         submission_queue: RelocatableSafelyOverflowingIndexQueue,
         completion_queue: RelocatableIndexQueue,
         state: IoxAtomicU64,
+        credits: IoxAtomicU64,
+        wake_up: IoxAtomicU64,
     }
 
     impl Channel {
@@ -175,6 +196,8 @@ pub mod details {
                     RelocatableIndexQueue::new_uninit(completion_queue_capacity)
                 },
                 state: IoxAtomicU64::new(INITIAL_CHANNEL_STATE),
+                credits: IoxAtomicU64::new(INITIAL_CHANNEL_CREDITS),
+                wake_up: IoxAtomicU64::new(INITIAL_CHANNEL_WAKE_UP),
             }
         }
 
@@ -657,6 +680,16 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
             &self.storage.get().channels[channel_id.value()].state
         }
+
+        fn channel_credits(&self, channel_id: ChannelId) -> &IoxAtomicU64 {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].credits
+        }
+
+        fn channel_wake_up(&self, channel_id: ChannelId) -> &IoxAtomicU64 {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].wake_up
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
@@ -893,6 +926,16 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
             &self.storage.get().channels[channel_id.value()].state
         }
+
+        fn channel_credits(&self, channel_id: ChannelId) -> &IoxAtomicU64 {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].credits
+        }
+
+        fn channel_wake_up(&self, channel_id: ChannelId) -> &IoxAtomicU64 {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].wake_up
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyReceiver for Receiver<Storage> {
@@ -928,6 +971,46 @@ pub mod details {
             }
         }
 
+        fn receive_latest<F: FnMut(PointerOffset) -> bool>(
+            &self,
+            channel_id: ChannelId,
+            mut is_accepted: F,
+        ) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+
+            if *self.borrow_counter(channel_id) >= self.storage.get().max_borrowed_samples {
+                fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
+                "Unable to receive the latest sample since already {} samples were borrowed and this would exceed the max borrow value of {}.",
+                    self.borrow_counter(channel_id), self.max_borrowed_samples());
+            }
+
+            let channel = &self.storage.get().channels[channel_id.value()];
+            let mut latest = None;
+            while let Some(v) = unsafe { channel.submission_queue.pop() } {
+                let discarded = match is_accepted(PointerOffset::from_value(v)) {
+                    true => latest.replace(v),
+                    false => Some(v),
+                };
+
+                if let Some(discarded) = discarded {
+                    // the completion queue has room for every sample that can be in flight,
+                    // therefore the discarded sample can be returned directly
+                    if !unsafe { channel.completion_queue.push(discarded) } {
+                        fatal_panic!(from self,
+                            "This should never happen! Unable to release a discarded sample since the retrieve buffer is full.");
+                    }
+                }
+            }
+
+            match latest {
+                None => Ok(None),
+                Some(v) => {
+                    *self.borrow_counter(channel_id) += 1;
+                    Ok(Some(PointerOffset::from_value(v)))
+                }
+            }
+        }
+
         fn borrow_count(&self, channel_id: ChannelId) -> usize {
             *self.borrow_counter(channel_id)
         }
@@ -144,6 +144,8 @@ pub const DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS: u8 = 1;
 pub const DEFAULT_NUMBER_OF_CHANNELS: usize = 1;
 pub const DEFAULT_NUMBER_OF_SAMPLES_PER_SEGMENT: usize = 8;
 pub const INITIAL_CHANNEL_STATE: u64 = 0;
+pub const INITIAL_CHANNEL_CREDITS: u64 = u64::MAX;
+pub const INITIAL_CHANNEL_WAKE_UP: u64 = 0;
 
 pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<C> {
     fn buffer_size(self, value: usize) -> Self;
@@ -172,6 +174,14 @@ pub trait ZeroCopyPortDetails {
     fn max_supported_shared_memory_segments(&self) -> u8;
     fn is_connected(&self) -> bool;
     fn channel_state(&self, channel_id: ChannelId) -> &IoxAtomicU64;
+    /// Returns the credits of the channel that are shared between sender and receiver. They
+    /// can be used to realize a flow control on top of the connection and are initialized with
+    /// [`INITIAL_CHANNEL_CREDITS`].
+    fn channel_credits(&self, channel_id: ChannelId) -> &IoxAtomicU64;
+    /// Returns the wake up state of the channel that is shared between sender and receiver. It
+    /// can be used by the receiver to announce that it waits for a notification whenever new
+    /// data arrives on the channel and is initialized with [`INITIAL_CHANNEL_WAKE_UP`].
+    fn channel_wake_up(&self, channel_id: ChannelId) -> &IoxAtomicU64;
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept + Send {
@@ -205,6 +215,15 @@ pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept + Send {
     fn has_data(&self, channel_id: ChannelId) -> bool;
     fn receive(&self, channel_id: ChannelId)
         -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
+    /// Receives the most recently sent [`PointerOffset`] of the channel that is accepted by
+    /// `is_accepted` and releases all other ones that are still in the buffer directly back to
+    /// the [`ZeroCopySender`] without borrowing them. `is_accepted` is called for every
+    /// [`PointerOffset`] in the buffer in the order they were sent.
+    fn receive_latest<F: FnMut(PointerOffset) -> bool>(
+        &self,
+        channel_id: ChannelId,
+        is_accepted: F,
+    ) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
     fn release(
         &self,
         ptr: PointerOffset,
@@ -311,6 +311,13 @@ pub struct SubscribeOptions {
         help = "Maximum number of messages to be received before the process stops."
     )]
     pub max_messages: Option<u64>,
+
+    #[clap(
+        short,
+        long,
+        help = "Prints the sequence number, the send timestamp and the latency of every message as well as the number of messages missed from its publisher."
+    )]
+    pub show_send_details: bool,
 }
 
 #[derive(Parser)]
@@ -13,15 +13,42 @@
 use crate::cli::{DataRepresentation, SubscribeOptions};
 use crate::helper_functions::{extract_pubsub_payload, get_pubsub_service_types};
 use anyhow::Result;
+use iceoryx2::port::subscriber::Subscriber;
 use iceoryx2::prelude::*;
+use iceoryx2::sample::Sample;
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_cli::Format;
 use iceoryx2_userland_record_and_replay::hex_conversion::bytes_to_hex_string;
 use std::time::Duration;
 use std::time::Instant;
 
+#[derive(serde::Serialize)]
+struct SendDetails {
+    sequence_number: u64,
+    send_timestamp_ns: u128,
+    latency_ns: u128,
+    missed_samples: u64,
+}
+
+impl SendDetails {
+    fn new(
+        sample: &Sample<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>,
+        subscriber: &Subscriber<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>,
+    ) -> Self {
+        let send_timestamp = sample.send_timestamp();
+        Self {
+            sequence_number: sample.sequence_number(),
+            send_timestamp_ns: send_timestamp.as_duration().as_nanos(),
+            latency_ns: send_timestamp.elapsed().unwrap_or_default().as_nanos(),
+            missed_samples: subscriber.missed_samples(&sample.origin()).unwrap_or(0),
+        }
+    }
+}
+
 #[derive(serde::Serialize)]
 struct Message {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    send_details: Option<SendDetails>,
     system_header_len: usize,
     system_header: String,
     user_header_len: usize,
@@ -31,12 +58,14 @@ struct Message {
 }
 
 fn print_hex_dump(
+    send_details: Option<SendDetails>,
     system_header: &[u8],
     user_header: &[u8],
     payload: &[u8],
     format: Format,
 ) -> Result<()> {
     let msg = Message {
+        send_details,
         system_header_len: system_header.len(),
         system_header: bytes_to_hex_string(system_header),
         user_header_len: user_header.len(),
@@ -56,12 +85,14 @@ fn print_hex_dump(
 }
 
 fn print_iox2_dump(
+    send_details: Option<SendDetails>,
     system_header: &[u8],
     user_header: &[u8],
     payload: &[u8],
     format: Format,
 ) -> Result<()> {
     let msg = Message {
+        send_details,
         system_header_len: system_header.len(),
         system_header: bytes_to_hex_string(system_header),
         user_header_len: user_header.len(),
@@ -106,13 +137,16 @@ pub fn subscribe(options: SubscribeOptions, format: Format) -> Result<()> {
         while let Some(sample) = unsafe { subscriber.receive_custom_payload()? } {
             let (system_header, user_header, payload) =
                 extract_pubsub_payload(&sample, &service_types.user_header);
+            let send_details = options
+                .show_send_details
+                .then(|| SendDetails::new(&sample, &subscriber));
 
             match options.data_representation {
                 DataRepresentation::Iox2Dump => {
-                    print_iox2_dump(system_header, user_header, payload, format)?;
+                    print_iox2_dump(send_details, system_header, user_header, payload, format)?;
                 }
                 DataRepresentation::HumanReadable => {
-                    print_hex_dump(system_header, user_header, payload, format)?;
+                    print_hex_dump(send_details, system_header, user_header, payload, format)?;
                 }
             }
 
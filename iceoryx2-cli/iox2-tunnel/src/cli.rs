@@ -39,6 +39,30 @@ pub struct Cli {
     )]
     pub discovery_service: Option<String>,
 
+    #[clap(
+        long,
+        value_name = "GLOB",
+        global = true,
+        help = "Only tunnel services whose name matches the glob pattern, can be provided multiple times"
+    )]
+    pub include: Vec<String>,
+
+    #[clap(
+        long,
+        value_name = "GLOB",
+        global = true,
+        help = "Do not tunnel services whose name matches the glob pattern, can be provided multiple times"
+    )]
+    pub exclude: Vec<String>,
+
+    #[clap(
+        long,
+        value_name = "PATH",
+        global = true,
+        help = "Path to a TOML file with include and exclude rules matching service names, messaging patterns and attributes"
+    )]
+    pub filter_config: Option<String>,
+
     #[clap(
         long,
         value_name = "RATE",
@@ -68,6 +92,41 @@ pub struct ZenohOptions {
     pub zenoh_config: Option<String>,
 }
 
+#[derive(Parser)]
+pub struct UdpOptions {
+    #[clap(
+        short = 'g',
+        long,
+        value_name = "ADDRESS",
+        help = "The multicast group on which services are announced [default: 239.255.42.2]"
+    )]
+    pub multicast_group: Option<String>,
+
+    #[clap(
+        short = 'p',
+        long,
+        value_name = "PORT",
+        help = "The port of the multicast group [default: 42424]"
+    )]
+    pub multicast_port: Option<u16>,
+
+    #[clap(
+        short,
+        long,
+        value_name = "ADDRESS",
+        help = "The address of the network interface to use, chosen by the operating system when not provided"
+    )]
+    pub interface: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        value_name = "BYTES",
+        help = "The maximum size of a datagram, larger messages are fragmented [default: 1472]"
+    )]
+    pub max_datagram_size: Option<usize>,
+}
+
 #[derive(Subcommand)]
 pub enum Transport {
     #[clap(
@@ -75,4 +134,9 @@ pub enum Transport {
         help_template = help_template(HelpOptions::DontPrintCommandSection)
     )]
     Zenoh(ZenohOptions),
+    #[clap(
+        about = "Use UDP multicast for discovery and UDP unicast as the transport",
+        help_template = help_template(HelpOptions::DontPrintCommandSection)
+    )]
+    Udp(UdpOptions),
 }
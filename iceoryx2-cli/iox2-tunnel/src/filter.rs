@@ -0,0 +1,106 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Context};
+use serde::Deserialize;
+
+use iceoryx2::service::attribute::{AttributeKey, AttributeValue, AttributeVerifier};
+use iceoryx2::service::messaging_pattern::MessagingPattern;
+use iceoryx2_tunnel::{ServiceFilter, ServiceRule};
+
+/// A rule in the filter config file, e.g.
+///
+/// ```toml
+/// [[exclude]]
+/// service_name = "camera/*/raw"
+/// messaging_pattern = "PublishSubscribe"
+/// attributes = { bandwidth = "high" }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleConfig {
+    service_name: Option<String>,
+    messaging_pattern: Option<MessagingPattern>,
+    #[serde(default)]
+    attributes: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FilterConfig {
+    #[serde(default)]
+    include: Vec<RuleConfig>,
+    #[serde(default)]
+    exclude: Vec<RuleConfig>,
+}
+
+impl RuleConfig {
+    fn to_rule(&self) -> anyhow::Result<ServiceRule> {
+        let mut rule = ServiceRule::new();
+        if let Some(service_name) = &self.service_name {
+            rule = rule.service_name(service_name);
+        }
+        if let Some(messaging_pattern) = self.messaging_pattern {
+            rule = rule.messaging_pattern(messaging_pattern);
+        }
+        if !self.attributes.is_empty() {
+            let mut verifier = AttributeVerifier::new();
+            for (key, value) in &self.attributes {
+                let key = AttributeKey::try_from(key.as_str())
+                    .map_err(|e| anyhow!("invalid attribute key '{key}': {e:?}"))?;
+                let value = AttributeValue::try_from(value.as_str())
+                    .map_err(|e| anyhow!("invalid attribute value '{value}': {e:?}"))?;
+                verifier = verifier
+                    .require(&key, &value)
+                    .map_err(|e| anyhow!("too many attributes in filter rule: {e:?}"))?;
+            }
+            rule = rule.attributes(&verifier);
+        }
+
+        Ok(rule)
+    }
+}
+
+/// Creates the [`ServiceFilter`] from the service name globs provided on the
+/// command line and the rules of an optional filter config file.
+pub fn create_service_filter(
+    include: &[String],
+    exclude: &[String],
+    filter_config: Option<&str>,
+) -> anyhow::Result<ServiceFilter> {
+    let mut filter = ServiceFilter::new();
+
+    if let Some(path) = filter_config {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read filter config file '{path}'"))?;
+        let config: FilterConfig = toml::from_str(&content)
+            .with_context(|| format!("failed to parse filter config file '{path}'"))?;
+
+        for rule in &config.include {
+            filter = filter.include(rule.to_rule()?);
+        }
+        for rule in &config.exclude {
+            filter = filter.exclude(rule.to_rule()?);
+        }
+    }
+
+    for service_name in include {
+        filter = filter.include(ServiceRule::new().service_name(service_name));
+    }
+    for service_name in exclude {
+        filter = filter.exclude(ServiceRule::new().service_name(service_name));
+    }
+
+    Ok(filter)
+}
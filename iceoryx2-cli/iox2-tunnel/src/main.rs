@@ -17,6 +17,8 @@ fn main() {
 
 #[cfg(not(target_os = "freebsd"))]
 mod cli;
+#[cfg(not(target_os = "freebsd"))]
+mod filter;
 
 #[cfg(not(target_os = "freebsd"))]
 mod supported_platform {
@@ -28,6 +30,9 @@ mod supported_platform {
     extern crate better_panic;
 
     use super::cli;
+    use super::filter;
+
+    use core::fmt::Debug;
 
     use clap::Parser;
     use cli::Cli;
@@ -41,7 +46,12 @@ mod supported_platform {
     use iceoryx2_bb_log::LogLevel;
 
     use iceoryx2_tunnel::Tunnel;
+    use iceoryx2_tunnel_backend::traits::Backend;
 
+    #[cfg(feature = "tunnel_udp")]
+    use iceoryx2_bb_system_types::{ipv4_address::Ipv4Address, port::Port};
+    #[cfg(feature = "tunnel_udp")]
+    use iceoryx2_tunnel_udp::UdpBackend;
     #[cfg(feature = "tunnel_zenoh")]
     use iceoryx2_tunnel_zenoh::ZenohBackend;
 
@@ -61,7 +71,7 @@ mod supported_platform {
 
         set_log_level_from_env_or(LogLevel::Warn);
 
-        let cli = match Cli::try_parse() {
+        let mut cli = match Cli::try_parse() {
             Ok(cli) => cli,
             Err(e) => {
                 // --help and --version is treated as a parse error by clap
@@ -72,15 +82,11 @@ mod supported_platform {
         };
 
         // TODO(#1102): Organize into separate modules per-transport
-        if let Some(transport) = cli.transport {
+        if let Some(transport) = cli.transport.take() {
             match transport {
                 Transport::Zenoh(zenoh_options) => {
                     #[cfg(feature = "tunnel_zenoh")]
                     {
-                        let tunnel_config = iceoryx2_tunnel::Config {
-                            discovery_service: cli.discovery_service,
-                        };
-                        let iceoryx_config = iceoryx2::config::Config::default();
                         let zenoh_config = match zenoh_options.zenoh_config {
                             Some(path) => zenoh::Config::from_file(&path).map_err(|e| {
                                 anyhow::anyhow!("failed to read zenoh config file '{path}': {e}")
@@ -88,49 +94,7 @@ mod supported_platform {
                             None => zenoh::Config::default(),
                         };
 
-                        let tunnel = Tunnel::<ipc::Service, ZenohBackend<ipc::Service>>::create(
-                            &tunnel_config,
-                            &iceoryx_config,
-                            &zenoh_config,
-                        );
-                        let mut tunnel = fail!(
-                            from "iox2 tunnel",
-                            when tunnel,
-                            "Failed to create Tunnel"
-                        );
-
-                        let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
-
-                        if cli.reactive {
-                            // TODO(functionality): Make tunnel (or its endpoints) attachable to waitset
-                            unimplemented!("Reactive mode is not yet supported.");
-                        } else {
-                            let rate = cli.poll.unwrap_or(100);
-                            info!(from "iox2 tunnel", "Polling rate {}ms", rate);
-
-                            let guard =
-                                waitset.attach_interval(core::time::Duration::from_millis(rate))?;
-                            let tick = WaitSetAttachmentId::from_guard(&guard);
-
-                            let on_event = |id: WaitSetAttachmentId<ipc::Service>| {
-                                if id == tick {
-                                    let _ = tunnel.discover().inspect_err(|e| {
-                                        warn!(
-                                            "Error encountered whilst discoverying services: {}",
-                                            e
-                                        );
-                                    });
-                                    let _ = tunnel.propagate().inspect_err(|e| {
-                                        warn!(
-                                            "Error encountered whilst propagating between hosts: {e}"
-                                        );
-                                    });
-                                }
-                                CallbackProgression::Continue
-                            };
-
-                            waitset.wait_and_process(on_event)?;
-                        }
+                        run::<ZenohBackend<ipc::Service>>(&cli, &zenoh_config)?;
                     }
                     #[cfg(not(feature = "tunnel_zenoh"))]
                     {
@@ -138,11 +102,97 @@ mod supported_platform {
                         return Ok(());
                     }
                 }
+                Transport::Udp(udp_options) => {
+                    #[cfg(feature = "tunnel_udp")]
+                    {
+                        let mut udp_config = iceoryx2_tunnel_udp::Config::default();
+                        if let Some(group) = udp_options.multicast_group {
+                            udp_config.multicast_group = Ipv4Address::try_from(group.as_str())
+                                .map_err(|e| {
+                                    anyhow::anyhow!("invalid multicast group '{group}': {e:?}")
+                                })?;
+                        }
+                        if let Some(port) = udp_options.multicast_port {
+                            udp_config.multicast_port = Port::new(port);
+                        }
+                        if let Some(interface) = udp_options.interface {
+                            udp_config.interface = Ipv4Address::try_from(interface.as_str())
+                                .map_err(|e| {
+                                    anyhow::anyhow!(
+                                        "invalid interface address '{interface}': {e:?}"
+                                    )
+                                })?;
+                        }
+                        if let Some(max_datagram_size) = udp_options.max_datagram_size {
+                            udp_config.max_datagram_size = max_datagram_size;
+                        }
+
+                        run::<UdpBackend<ipc::Service>>(&cli, &udp_config)?;
+                    }
+                    #[cfg(not(feature = "tunnel_udp"))]
+                    {
+                        println!("UDP transport is not available. Please rebuild with the 'tunnel_udp' feature enabled.");
+                        return Ok(());
+                    }
+                }
             }
         }
 
         Ok(())
     }
+
+    #[allow(dead_code)] // unused when no transport feature is enabled
+    fn run<B: Backend<ipc::Service> + Debug>(
+        cli: &Cli,
+        backend_config: &B::Config,
+    ) -> anyhow::Result<()> {
+        let tunnel_config = iceoryx2_tunnel::Config {
+            discovery_service: cli.discovery_service.clone(),
+            filter: filter::create_service_filter(
+                &cli.include,
+                &cli.exclude,
+                cli.filter_config.as_deref(),
+            )?,
+        };
+        let iceoryx_config = iceoryx2::config::Config::default();
+
+        let tunnel =
+            Tunnel::<ipc::Service, B>::create(&tunnel_config, &iceoryx_config, backend_config);
+        let mut tunnel = fail!(
+            from "iox2 tunnel",
+            when tunnel,
+            "Failed to create Tunnel"
+        );
+
+        let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+
+        if cli.reactive {
+            // TODO(functionality): Make tunnel (or its endpoints) attachable to waitset
+            unimplemented!("Reactive mode is not yet supported.");
+        } else {
+            let rate = cli.poll.unwrap_or(100);
+            info!(from "iox2 tunnel", "Polling rate {}ms", rate);
+
+            let guard = waitset.attach_interval(core::time::Duration::from_millis(rate))?;
+            let tick = WaitSetAttachmentId::from_guard(&guard);
+
+            let on_event = |id: WaitSetAttachmentId<ipc::Service>| {
+                if id == tick {
+                    let _ = tunnel.discover().inspect_err(|e| {
+                        warn!("Error encountered whilst discoverying services: {}", e);
+                    });
+                    let _ = tunnel.propagate().inspect_err(|e| {
+                        warn!("Error encountered whilst propagating between hosts: {e}");
+                    });
+                }
+                CallbackProgression::Continue
+            };
+
+            waitset.wait_and_process(on_event)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(not(target_os = "freebsd"))]
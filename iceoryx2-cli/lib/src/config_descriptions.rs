@@ -220,6 +220,12 @@ pub fn get_sections() -> Vec<CliSection> {
                     default_value: config.defaults.publish_subscribe.subscriber_expired_connection_buffer.to_string(),
                     description: "Expired connection buffer size of the subscriber. Connections to publishers are expired when the publisher disconnected from the service and the connection contains unconsumed samples.",
                 },
+                CliEntry {
+                    key: "defaults.publish-subscribe.publisher-memory-lock-policy",
+                    value_type: "`Disabled`|`BestEffort`|`Required`",
+                    default_value: format!("{:?}", config.defaults.publish_subscribe.publisher_memory_lock_policy),
+                    description: "Defines if the data segment of a publisher is locked into RAM and pre-faulted. With `BestEffort` a failure only emits a warning, with `Required` the publisher creation fails.",
+                },
             ],
         },
         CliSection {
@@ -374,6 +380,12 @@ pub fn get_sections() -> Vec<CliSection> {
                     default_value: config.defaults.request_response.server_expired_connection_buffer.to_string(),
                     description: "Expired connection buffer size of the server. Connections to clients are expired when the client disconnected from the service and the connection contains unconsumed active requests.",
                 },
+                CliEntry {
+                    key: "defaults.request-response.server-memory-lock-policy",
+                    value_type: "`Disabled`|`BestEffort`|`Required`",
+                    default_value: format!("{:?}", config.defaults.request_response.server_memory_lock_policy),
+                    description: "Defines if the data segment of a server is locked into RAM and pre-faulted. With `BestEffort` a failure only emits a warning, with `Required` the server creation fails.",
+                },
             ],
         },
         CliSection {
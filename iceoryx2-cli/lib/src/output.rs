@@ -16,6 +16,7 @@ use iceoryx2::node::NodeDetails as IceoryxNodeDetails;
 use iceoryx2::node::NodeId as IceoryxNodeId;
 use iceoryx2::node::NodeState as IceoryxNodeState;
 use iceoryx2::node::NodeView as IceoryxNodeView;
+use iceoryx2::port::DataSegmentPlacement as IceoryxDataSegmentPlacement;
 use iceoryx2::service::attribute::AttributeSet as IceoryxAttributeSet;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as IceoryxMessagingPattern;
 use iceoryx2::service::Service as IceoryxService;
@@ -58,6 +59,7 @@ pub struct ServiceDescription {
     pub attributes: IceoryxAttributeSet,
     pub pattern: IceoryxMessagingPattern,
     pub nodes: Option<NodeList>,
+    pub data_segments: Option<Vec<DataSegmentDescriptor>>,
 }
 
 impl<T> From<&IceoryxServiceDetails<T>> for ServiceDescription
@@ -73,10 +75,52 @@ where
             attributes: config.attributes().clone(),
             pattern: config.messaging_pattern().clone(),
             nodes: service.dynamic_details.as_ref().map(NodeList::from),
+            data_segments: service
+                .dynamic_details
+                .as_ref()
+                .map(DataSegmentDescriptor::list_from),
         }
     }
 }
 
+#[derive(serde::Serialize)]
+pub enum DataSegmentOwner {
+    Publisher,
+    Server,
+}
+
+#[derive(serde::Serialize)]
+pub struct DataSegmentDescriptor {
+    owner: DataSegmentOwner,
+    port_id: String,
+    node_id: NodeIdString,
+    numa_node: Option<u32>,
+}
+
+impl DataSegmentDescriptor {
+    fn list_from<T: IceoryxService>(details: &IceoryxServiceDynamicDetails<T>) -> Vec<Self> {
+        let numa_node = |placement| match placement {
+            IceoryxDataSegmentPlacement::Unbound => None,
+            IceoryxDataSegmentPlacement::NumaNode(id) => Some(id),
+        };
+
+        let publishers = details.publishers.iter().map(|publisher| Self {
+            owner: DataSegmentOwner::Publisher,
+            port_id: format!("{:032x}", publisher.publisher_id.value()),
+            node_id: NodeIdString::from(&publisher.node_id),
+            numa_node: numa_node(publisher.data_segment_placement),
+        });
+        let servers = details.servers.iter().map(|server| Self {
+            owner: DataSegmentOwner::Server,
+            port_id: format!("{:032x}", server.server_id.value()),
+            node_id: NodeIdString::from(&server.node_id),
+            numa_node: numa_node(server.data_segment_placement),
+        });
+
+        publishers.chain(servers).collect()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize)]
 pub struct NodeIdString(String);
 
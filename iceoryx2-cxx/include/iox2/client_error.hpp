@@ -29,6 +29,8 @@ enum class ClientCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
 };
 } // namespace iox2
 #endif
@@ -22,6 +22,10 @@ enum class EntryHandleMutError : uint8_t {
     EntryDoesNotExist,
     /// The [`EntryHandleMut`] already exists.
     HandleAlreadyExists,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+    /// The entry is owned by another [`Writer`].
+    EntryOwnedByAnotherWriter,
 };
 } // namespace iox2
 
@@ -336,6 +336,10 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::EventOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_event_open_or_create_error_e_O_ACCESS_DENIED:
+        return iox2::EventOpenOrCreateError::OpenAccessDenied;
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE:
+        return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedNotificationPayloadSize;
     case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_CREATED_EVENT:
         return iox2::EventOpenOrCreateError::OpenIncompatibleNotifierCreatedEvent;
     case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_DROPPED_EVENT:
@@ -394,6 +398,10 @@ from<iox2::EventOpenOrCreateError, iox2_event_open_or_create_error_e>(const iox2
         return iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::EventOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::EventOpenOrCreateError::OpenAccessDenied:
+        return iox2_event_open_or_create_error_e_O_ACCESS_DENIED;
+    case iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedNotificationPayloadSize:
+        return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE;
 
     case iox2::EventOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_event_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
@@ -450,6 +458,10 @@ constexpr auto from<int, iox2::EventOpenError>(const int value) noexcept -> iox2
         return iox2::EventOpenError::ExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::EventOpenError::IsMarkedForDestruction;
+    case iox2_event_open_or_create_error_e_O_ACCESS_DENIED:
+        return iox2::EventOpenError::AccessDenied;
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE:
+        return iox2::EventOpenError::DoesNotSupportRequestedNotificationPayloadSize;
     default:
         IOX_UNREACHABLE();
     }
@@ -485,6 +497,10 @@ constexpr auto from<iox2::EventOpenError, iox2_event_open_or_create_error_e>(con
         return iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::EventOpenError::IsMarkedForDestruction:
         return iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::EventOpenError::AccessDenied:
+        return iox2_event_open_or_create_error_e_O_ACCESS_DENIED;
+    case iox2::EventOpenError::DoesNotSupportRequestedNotificationPayloadSize:
+        return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE;
     default:
         IOX_UNREACHABLE();
     }
@@ -574,6 +590,10 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LIFESPAN:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleLifespan;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SUBSCRIBER_DEADLINE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSubscriberDeadline;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -584,6 +604,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_ACCESS_DENIED:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenAccessDenied;
 
     case iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState;
@@ -599,6 +621,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::CreateIsBeingCreatedByAnotherInstance;
     case iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION:
         return iox2::PublishSubscribeOpenOrCreateError::CreateHangsInCreation;
+    case iox2_pub_sub_open_or_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS:
+        return iox2::PublishSubscribeOpenOrCreateError::CreateTypeSchemaExceedsLimits;
     case iox2_pub_sub_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive;
     case iox2_pub_sub_open_or_create_error_e_SYSTEM_IN_FLUX:
@@ -636,6 +660,10 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LIFESPAN:
+        return iox2::PublishSubscribeOpenError::IncompatibleLifespan;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SUBSCRIBER_DEADLINE:
+        return iox2::PublishSubscribeOpenError::IncompatibleSubscriberDeadline;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenError::InsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -646,6 +674,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenError::IsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_ACCESS_DENIED:
+        return iox2::PublishSubscribeOpenError::AccessDenied;
     default:
         IOX_UNREACHABLE();
     }
@@ -679,6 +709,10 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenError::IncompatibleLifespan:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LIFESPAN;
+    case iox2::PublishSubscribeOpenError::IncompatibleSubscriberDeadline:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SUBSCRIBER_DEADLINE;
     case iox2::PublishSubscribeOpenError::InsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenError::ServiceInCorruptedState:
@@ -689,6 +723,8 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenError::IsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenError::AccessDenied:
+        return iox2_pub_sub_open_or_create_error_e_O_ACCESS_DENIED;
     default:
         IOX_UNREACHABLE();
     }
@@ -719,6 +755,8 @@ constexpr auto from<int, iox2::PublishSubscribeCreateError>(const int value) noe
         return iox2::PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance;
     case iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION:
         return iox2::PublishSubscribeCreateError::HangsInCreation;
+    case iox2_pub_sub_open_or_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS:
+        return iox2::PublishSubscribeCreateError::TypeSchemaExceedsLimits;
     default:
         IOX_UNREACHABLE();
     }
@@ -742,6 +780,8 @@ constexpr auto from<iox2::PublishSubscribeCreateError, iox2_pub_sub_open_or_crea
         return iox2_pub_sub_open_or_create_error_e_C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE;
     case iox2::PublishSubscribeCreateError::HangsInCreation:
         return iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION;
+    case iox2::PublishSubscribeCreateError::TypeSchemaExceedsLimits:
+        return iox2_pub_sub_open_or_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS;
     default:
         IOX_UNREACHABLE();
     }
@@ -781,6 +821,10 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleLifespan:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LIFESPAN;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSubscriberDeadline:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SUBSCRIBER_DEADLINE;
     case iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenOrCreateError::OpenServiceInCorruptedState:
@@ -791,6 +835,8 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenAccessDenied:
+        return iox2_pub_sub_open_or_create_error_e_O_ACCESS_DENIED;
     case iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
     case iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberBufferMustBeLargerThanHistorySize:
@@ -805,6 +851,8 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE;
     case iox2::PublishSubscribeOpenOrCreateError::CreateHangsInCreation:
         return iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION;
+    case iox2::PublishSubscribeOpenOrCreateError::CreateTypeSchemaExceedsLimits:
+        return iox2_pub_sub_open_or_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS;
     case iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive:
         return iox2_pub_sub_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE;
     default:
@@ -836,6 +884,8 @@ constexpr auto from<int, iox2::RequestResponseCreateError>(const int value) noex
         return iox2::RequestResponseCreateError::HangsInCreation;
     case iox2_request_response_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::RequestResponseCreateError::ServiceInCorruptedState;
+    case iox2_request_response_open_or_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS:
+        return iox2::RequestResponseCreateError::TypeSchemaExceedsLimits;
     default:
         IOX_UNREACHABLE();
     }
@@ -857,6 +907,8 @@ constexpr auto from<iox2::RequestResponseCreateError, iox2_request_response_open
         return iox2_request_response_open_or_create_error_e_C_HANGS_IN_CREATION;
     case iox2::RequestResponseCreateError::ServiceInCorruptedState:
         return iox2_request_response_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
+    case iox2::RequestResponseCreateError::TypeSchemaExceedsLimits:
+        return iox2_request_response_open_or_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS;
     default:
         IOX_UNREACHABLE();
     }
@@ -907,12 +959,18 @@ constexpr auto from<int, iox2::RequestResponseOpenError>(const int value) noexce
         return iox2::RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS:
         return iox2::RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_CANCELLATION:
+        return iox2::RequestResponseOpenError::IncompatibleRequestCancellation;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_DISPATCH_POLICY:
+        return iox2::RequestResponseOpenError::IncompatibleDispatchPolicy;
     case iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::RequestResponseOpenError::InsufficientPermissions;
     case iox2_request_response_open_or_create_error_e_O_INTERNAL_FAILURE:
         return iox2::RequestResponseOpenError::InternalFailure;
     case iox2_request_response_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::RequestResponseOpenError::IsMarkedForDestruction;
+    case iox2_request_response_open_or_create_error_e_O_ACCESS_DENIED:
+        return iox2::RequestResponseOpenError::AccessDenied;
     case iox2_request_response_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
         return iox2::RequestResponseOpenError::ServiceInCorruptedState;
     default:
@@ -958,12 +1016,18 @@ constexpr auto from<iox2::RequestResponseOpenError, iox2_request_response_open_o
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES;
     case iox2::RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS;
+    case iox2::RequestResponseOpenError::IncompatibleRequestCancellation:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_CANCELLATION;
+    case iox2::RequestResponseOpenError::IncompatibleDispatchPolicy:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_DISPATCH_POLICY;
     case iox2::RequestResponseOpenError::InsufficientPermissions:
         return iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::RequestResponseOpenError::InternalFailure:
         return iox2_request_response_open_or_create_error_e_O_INTERNAL_FAILURE;
     case iox2::RequestResponseOpenError::IsMarkedForDestruction:
         return iox2_request_response_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::RequestResponseOpenError::AccessDenied:
+        return iox2_request_response_open_or_create_error_e_O_ACCESS_DENIED;
     case iox2::RequestResponseOpenError::ServiceInCorruptedState:
         return iox2_request_response_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE;
     default:
@@ -1018,12 +1082,18 @@ constexpr auto from<int, iox2::RequestResponseOpenOrCreateError>(const int value
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleOverflowBehaviorForResponses;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS:
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleBehaviorForFireAndForgetRequests;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_CANCELLATION:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestCancellation;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_DISPATCH_POLICY:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleDispatchPolicy;
     case iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::RequestResponseOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_request_response_open_or_create_error_e_O_INTERNAL_FAILURE:
         return iox2::RequestResponseOpenOrCreateError::OpenInternalFailure;
     case iox2_request_response_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::RequestResponseOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_request_response_open_or_create_error_e_O_ACCESS_DENIED:
+        return iox2::RequestResponseOpenOrCreateError::OpenAccessDenied;
     case iox2_request_response_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
         return iox2::RequestResponseOpenOrCreateError::OpenServiceInCorruptedState;
 
@@ -1039,6 +1109,8 @@ constexpr auto from<int, iox2::RequestResponseOpenOrCreateError>(const int value
         return iox2::RequestResponseOpenOrCreateError::CreateHangsInCreation;
     case iox2_request_response_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::RequestResponseOpenOrCreateError::CreateServiceInCorruptedState;
+    case iox2_request_response_open_or_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS:
+        return iox2::RequestResponseOpenOrCreateError::CreateTypeSchemaExceedsLimits;
     case iox2_request_response_open_or_create_error_e_SYSTEM_IN_FLUX:
         return iox2::RequestResponseOpenOrCreateError::SystemInFlux;
 
@@ -1085,12 +1157,18 @@ constexpr auto from<iox2::RequestResponseOpenOrCreateError, iox2_request_respons
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleBehaviorForFireAndForgetRequests:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestCancellation:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_CANCELLATION;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleDispatchPolicy:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_DISPATCH_POLICY;
     case iox2::RequestResponseOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::RequestResponseOpenOrCreateError::OpenInternalFailure:
         return iox2_request_response_open_or_create_error_e_O_INTERNAL_FAILURE;
     case iox2::RequestResponseOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_request_response_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::RequestResponseOpenOrCreateError::OpenAccessDenied:
+        return iox2_request_response_open_or_create_error_e_O_ACCESS_DENIED;
     case iox2::RequestResponseOpenOrCreateError::OpenServiceInCorruptedState:
         return iox2_request_response_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE;
 
@@ -1106,6 +1184,8 @@ constexpr auto from<iox2::RequestResponseOpenOrCreateError, iox2_request_respons
         return iox2_request_response_open_or_create_error_e_C_HANGS_IN_CREATION;
     case iox2::RequestResponseOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_request_response_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
+    case iox2::RequestResponseOpenOrCreateError::CreateTypeSchemaExceedsLimits:
+        return iox2_request_response_open_or_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS;
     case iox2::RequestResponseOpenOrCreateError::SystemInFlux:
         return iox2_request_response_open_or_create_error_e_SYSTEM_IN_FLUX;
     default:
@@ -1139,6 +1219,10 @@ constexpr auto from<int, iox2::BlackboardCreateError>(const int value) noexcept
         return iox2::BlackboardCreateError::HangsInCreation;
     case iox2_blackboard_create_error_e_C_NO_ENTRIES_PROVIDED:
         return iox2::BlackboardCreateError::NoEntriesProvided;
+    case iox2_blackboard_create_error_e_C_INITIAL_VALUE_EXCEEDS_MAX_SLICE_LEN:
+        return iox2::BlackboardCreateError::InitialValueExceedsMaxSliceLen;
+    case iox2_blackboard_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS:
+        return iox2::BlackboardCreateError::TypeSchemaExceedsLimits;
     default:
         IOX_UNREACHABLE();
     }
@@ -1163,6 +1247,10 @@ from<iox2::BlackboardCreateError, iox2_blackboard_create_error_e>(const iox2::Bl
         return iox2_blackboard_create_error_e_C_HANGS_IN_CREATION;
     case iox2::BlackboardCreateError::NoEntriesProvided:
         return iox2_blackboard_create_error_e_C_NO_ENTRIES_PROVIDED;
+    case iox2::BlackboardCreateError::InitialValueExceedsMaxSliceLen:
+        return iox2_blackboard_create_error_e_C_INITIAL_VALUE_EXCEEDS_MAX_SLICE_LEN;
+    case iox2::BlackboardCreateError::TypeSchemaExceedsLimits:
+        return iox2_blackboard_create_error_e_C_TYPE_SCHEMA_EXCEEDS_LIMITS;
     default:
         IOX_UNREACHABLE();
     }
@@ -1198,10 +1286,14 @@ constexpr auto from<int, iox2::BlackboardOpenError>(const int value) noexcept ->
         return iox2::BlackboardOpenError::HangsInCreation;
     case iox2_blackboard_open_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::BlackboardOpenError::IsMarkedForDestruction;
+    case iox2_blackboard_open_error_e_O_ACCESS_DENIED:
+        return iox2::BlackboardOpenError::AccessDenied;
     case iox2_blackboard_open_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES:
         return iox2::BlackboardOpenError::ExceedsMaxNumberOfNodes;
     case iox2_blackboard_open_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES:
         return iox2::BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes;
+    case iox2_blackboard_open_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_WRITERS:
+        return iox2::BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters;
     default:
         IOX_UNREACHABLE();
     }
@@ -1232,10 +1324,14 @@ from<iox2::BlackboardOpenError, iox2_blackboard_open_error_e>(const iox2::Blackb
         return iox2_blackboard_open_error_e_O_HANGS_IN_CREATION;
     case iox2::BlackboardOpenError::IsMarkedForDestruction:
         return iox2_blackboard_open_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::BlackboardOpenError::AccessDenied:
+        return iox2_blackboard_open_error_e_O_ACCESS_DENIED;
     case iox2::BlackboardOpenError::ExceedsMaxNumberOfNodes:
         return iox2_blackboard_open_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes:
         return iox2_blackboard_open_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
+    case iox2::BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters:
+        return iox2_blackboard_open_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_WRITERS;
     default:
         IOX_UNREACHABLE();
     }
@@ -1286,6 +1382,10 @@ constexpr auto from<int, iox2::EntryHandleMutError>(const int value) noexcept ->
         return iox2::EntryHandleMutError::EntryDoesNotExist;
     case iox2_entry_handle_mut_error_e_HANDLE_ALREADY_EXISTS:
         return iox2::EntryHandleMutError::HandleAlreadyExists;
+    case iox2_entry_handle_mut_error_e_INTERNAL_FAILURE:
+        return iox2::EntryHandleMutError::InternalFailure;
+    case iox2_entry_handle_mut_error_e_ENTRY_OWNED_BY_ANOTHER_WRITER:
+        return iox2::EntryHandleMutError::EntryOwnedByAnotherWriter;
     default:
         IOX_UNREACHABLE();
     }
@@ -1300,6 +1400,10 @@ from<iox2::EntryHandleMutError, iox2_entry_handle_mut_error_e>(const iox2::Entry
         return iox2_entry_handle_mut_error_e_ENTRY_DOES_NOT_EXIST;
     case iox2::EntryHandleMutError::HandleAlreadyExists:
         return iox2_entry_handle_mut_error_e_HANDLE_ALREADY_EXISTS;
+    case iox2::EntryHandleMutError::InternalFailure:
+        return iox2_entry_handle_mut_error_e_INTERNAL_FAILURE;
+    case iox2::EntryHandleMutError::EntryOwnedByAnotherWriter:
+        return iox2_entry_handle_mut_error_e_ENTRY_OWNED_BY_ANOTHER_WRITER;
     default:
         IOX_UNREACHABLE();
     }
@@ -1375,6 +1479,8 @@ constexpr auto from<int, iox2::ClientCreateError>(const int value) noexcept -> i
         return iox2::ClientCreateError::UnableToCreateDataSegment;
     case iox2_client_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::ClientCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_client_create_error_e_INTERNAL_FAILURE:
+        return iox2::ClientCreateError::InternalFailure;
     }
 
     IOX_UNREACHABLE();
@@ -1390,6 +1496,8 @@ constexpr auto from<iox2::ClientCreateError, iox2_client_create_error_e>(const i
         return iox2_client_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::ClientCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_client_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::ClientCreateError::InternalFailure:
+        return iox2_client_create_error_e_INTERNAL_FAILURE;
     }
 
     IOX_UNREACHABLE();
@@ -1410,6 +1518,10 @@ constexpr auto from<int, iox2::ServerCreateError>(const int value) noexcept -> i
         return iox2::ServerCreateError::UnableToCreateDataSegment;
     case iox2_server_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::ServerCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_server_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT:
+        return iox2::ServerCreateError::UnableToLockDataSegment;
+    case iox2_server_create_error_e_INTERNAL_FAILURE:
+        return iox2::ServerCreateError::InternalFailure;
     }
 
     IOX_UNREACHABLE();
@@ -1425,6 +1537,10 @@ constexpr auto from<iox2::ServerCreateError, iox2_server_create_error_e>(const i
         return iox2_server_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::ServerCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_server_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::ServerCreateError::UnableToLockDataSegment:
+        return iox2_server_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT;
+    case iox2::ServerCreateError::InternalFailure:
+        return iox2_server_create_error_e_INTERNAL_FAILURE;
     }
 
     IOX_UNREACHABLE();
@@ -1515,6 +1631,8 @@ constexpr auto from<int, iox2::NotifierNotifyError>(const int value) noexcept ->
         return iox2::NotifierNotifyError::MissedDeadline;
     case iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME:
         return iox2::NotifierNotifyError::UnableToAcquireElapsedTime;
+    case iox2_notifier_notify_error_e_PAYLOAD_EXCEEDS_MAX_SIZE:
+        return iox2::NotifierNotifyError::PayloadExceedsMaxSize;
     }
 
     IOX_UNREACHABLE();
@@ -1531,6 +1649,8 @@ from<iox2::NotifierNotifyError, iox2_notifier_notify_error_e>(const iox2::Notifi
         return iox2_notifier_notify_error_e_MISSED_DEADLINE;
     case iox2::NotifierNotifyError::UnableToAcquireElapsedTime:
         return iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME;
+    case iox2::NotifierNotifyError::PayloadExceedsMaxSize:
+        return iox2_notifier_notify_error_e_PAYLOAD_EXCEEDS_MAX_SIZE;
     }
 
     IOX_UNREACHABLE();
@@ -1587,6 +1707,10 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
     case iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_publisher_create_error_e_INTERNAL_FAILURE:
+        return iox2::PublisherCreateError::InternalFailure;
+    case iox2_publisher_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT:
+        return iox2::PublisherCreateError::UnableToLockDataSegment;
     }
 
     IOX_UNREACHABLE();
@@ -1603,6 +1727,10 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::PublisherCreateError::InternalFailure:
+        return iox2_publisher_create_error_e_INTERNAL_FAILURE;
+    case iox2::PublisherCreateError::UnableToLockDataSegment:
+        return iox2_publisher_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT;
     }
 
     IOX_UNREACHABLE();
@@ -1624,6 +1752,8 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
     case iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_subscriber_create_error_e_INTERNAL_FAILURE:
+        return iox2::SubscriberCreateError::InternalFailure;
     }
 
     IOX_UNREACHABLE();
@@ -1640,6 +1770,8 @@ from<iox2::SubscriberCreateError, iox2_subscriber_create_error_e>(const iox2::Su
         return iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS;
     case iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::SubscriberCreateError::InternalFailure:
+        return iox2_subscriber_create_error_e_INTERNAL_FAILURE;
     }
 
     IOX_UNREACHABLE();
@@ -1665,6 +1797,10 @@ constexpr auto from<int, iox2::SendError>(const int value) noexcept -> iox2::Sen
         return iox2::SendError::LoanErrorExceedsMaxLoans;
     case iox2_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE:
         return iox2::SendError::LoanErrorExceedsMaxLoanSize;
+    case iox2_send_error_e_LOAN_ERROR_EXCEEDS_GRANTED_CREDITS:
+        return iox2::SendError::LoanErrorExceedsGrantedCredits;
+    case iox2_send_error_e_LOAN_ERROR_RESPONSE_STREAM_FINISHED:
+        return iox2::SendError::LoanErrorResponseStreamFinished;
     case iox2_send_error_e_LOAN_ERROR_INTERNAL_FAILURE:
         return iox2::SendError::LoanErrorInternalFailure;
     case iox2_send_error_e_CONNECTION_ERROR:
@@ -1687,6 +1823,10 @@ constexpr auto from<iox2::SendError, iox2_send_error_e>(const iox2::SendError va
         return iox2_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOANS;
     case iox2::SendError::LoanErrorExceedsMaxLoanSize:
         return iox2_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE;
+    case iox2::SendError::LoanErrorExceedsGrantedCredits:
+        return iox2_send_error_e_LOAN_ERROR_EXCEEDS_GRANTED_CREDITS;
+    case iox2::SendError::LoanErrorResponseStreamFinished:
+        return iox2_send_error_e_LOAN_ERROR_RESPONSE_STREAM_FINISHED;
     case iox2::SendError::LoanErrorInternalFailure:
         return iox2_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::SendError::ConnectionError:
@@ -1711,6 +1851,10 @@ constexpr auto from<int, iox2::ReceiveError>(const int value) noexcept -> iox2::
         return iox2::ReceiveError::UnableToMapSendersDataSegment;
     case iox2_receive_error_e_EXCEEDS_MAX_BORROWS:
         return iox2::ReceiveError::ExceedsMaxBorrows;
+    case iox2_receive_error_e_REQUEST_TIMED_OUT:
+        return iox2::ReceiveError::RequestTimedOut;
+    case iox2_receive_error_e_SERVER_DISCONNECTED:
+        return iox2::ReceiveError::ServerDisconnected;
     }
 
     IOX_UNREACHABLE();
@@ -1726,6 +1870,10 @@ constexpr auto from<iox2::ReceiveError, iox2_receive_error_e>(const iox2::Receiv
         return iox2_receive_error_e_UNABLE_TO_MAP_SENDERS_DATA_SEGMENT;
     case iox2::ReceiveError::ExceedsMaxBorrows:
         return iox2_receive_error_e_EXCEEDS_MAX_BORROWS;
+    case iox2::ReceiveError::RequestTimedOut:
+        return iox2_receive_error_e_REQUEST_TIMED_OUT;
+    case iox2::ReceiveError::ServerDisconnected:
+        return iox2_receive_error_e_SERVER_DISCONNECTED;
     }
 
     IOX_UNREACHABLE();
@@ -1746,6 +1894,10 @@ constexpr auto from<int, iox2::LoanError>(const int value) noexcept -> iox2::Loa
         return iox2::LoanError::OutOfMemory;
     case iox2_loan_error_e_EXCEEDS_MAX_LOAN_SIZE:
         return iox2::LoanError::ExceedsMaxLoanSize;
+    case iox2_loan_error_e_EXCEEDS_GRANTED_CREDITS:
+        return iox2::LoanError::ExceedsGrantedCredits;
+    case iox2_loan_error_e_RESPONSE_STREAM_FINISHED:
+        return iox2::LoanError::ResponseStreamFinished;
     case iox2_loan_error_e_INTERNAL_FAILURE:
         return iox2::LoanError::InternalFailure;
     }
@@ -1762,6 +1914,10 @@ constexpr auto from<iox2::LoanError, iox2_loan_error_e>(const iox2::LoanError va
         return iox2_loan_error_e_OUT_OF_MEMORY;
     case iox2::LoanError::ExceedsMaxLoanSize:
         return iox2_loan_error_e_EXCEEDS_MAX_LOAN_SIZE;
+    case iox2::LoanError::ExceedsGrantedCredits:
+        return iox2_loan_error_e_EXCEEDS_GRANTED_CREDITS;
+    case iox2::LoanError::ResponseStreamFinished:
+        return iox2_loan_error_e_RESPONSE_STREAM_FINISHED;
     case iox2::LoanError::InternalFailure:
         return iox2_loan_error_e_INTERNAL_FAILURE;
     }
@@ -1790,6 +1946,10 @@ constexpr auto from<int, iox2::RequestSendError>(const int value) noexcept -> io
         return iox2::RequestSendError::LoanErrorExceedsMaxLoans;
     case iox2_request_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE:
         return iox2::RequestSendError::LoanErrorExceedsMaxLoanSize;
+    case iox2_request_send_error_e_LOAN_ERROR_EXCEEDS_GRANTED_CREDITS:
+        return iox2::RequestSendError::LoanErrorExceedsGrantedCredits;
+    case iox2_request_send_error_e_LOAN_ERROR_RESPONSE_STREAM_FINISHED:
+        return iox2::RequestSendError::LoanErrorResponseStreamFinished;
     case iox2_request_send_error_e_LOAN_ERROR_INTERNAL_FAILURE:
         return iox2::RequestSendError::LoanErrorInternalFailure;
     case iox2_request_send_error_e_CONNECTION_ERROR:
@@ -1815,6 +1975,10 @@ constexpr auto from<iox2::RequestSendError, iox2_request_send_error_e>(const iox
         return iox2_request_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOANS;
     case iox2::RequestSendError::LoanErrorExceedsMaxLoanSize:
         return iox2_request_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE;
+    case iox2::RequestSendError::LoanErrorExceedsGrantedCredits:
+        return iox2_request_send_error_e_LOAN_ERROR_EXCEEDS_GRANTED_CREDITS;
+    case iox2::RequestSendError::LoanErrorResponseStreamFinished:
+        return iox2_request_send_error_e_LOAN_ERROR_RESPONSE_STREAM_FINISHED;
     case iox2::RequestSendError::LoanErrorInternalFailure:
         return iox2_request_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::RequestSendError::ConnectionError:
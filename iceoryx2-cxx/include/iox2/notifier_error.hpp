@@ -41,6 +41,10 @@ enum class NotifierNotifyError : uint8_t {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// A [`Notifier::notify_with_payload()`] was called and the provided
+    /// payload is larger than the maximum supported notification payload size
+    /// of the [`Service`]
+    PayloadExceedsMaxSize,
 };
 
 } // namespace iox2
@@ -31,6 +31,12 @@ enum class LoanError : uint8_t {
     /// port must be configured with an
     /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
     ExceedsMaxLoanSize,
+    /// The [`Client`] has not granted enough credits to loan another [`ResponseMut`], see
+    /// [`RequestMut::set_response_credits()`].
+    ExceedsGrantedCredits,
+    /// The final [`ResponseMut`] of the [`ActiveRequest`] was already sent with
+    /// [`ResponseMut::send_final()`], no further [`ResponseMut`] can be loaned or sent.
+    ResponseStreamFinished,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalFailure,
 };
@@ -51,6 +57,12 @@ enum class SendError : uint8_t {
     /// port must be configured with an
     /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
     LoanErrorExceedsMaxLoanSize,
+    /// The [`Client`] has not granted enough credits to loan another [`ResponseMut`], see
+    /// [`RequestMut::set_response_credits()`].
+    LoanErrorExceedsGrantedCredits,
+    /// The final [`ResponseMut`] of the [`ActiveRequest`] was already sent with
+    /// [`ResponseMut::send_final()`], no further [`ResponseMut`] can be loaned or sent.
+    LoanErrorResponseStreamFinished,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     LoanErrorInternalFailure,
     /// A failure occurred while establishing a connection to the ports counterpart port.
@@ -69,7 +81,15 @@ enum class ReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapSendersDataSegment
+    UnableToMapSendersDataSegment,
+
+    /// The timeout of the [`RequestMut`] has passed. It is only emitted by the
+    /// [`PendingResponse`].
+    RequestTimedOut,
+
+    /// The [`Server`] the [`RequestMut`] was dispatched to disconnected without sending a
+    /// [`Response`] and there is no other [`Server`] the request could be dispatched to.
+    ServerDisconnected
 };
 
 /// Failure that can be emitted when a [`RequestMut`] is sent.
@@ -92,6 +112,12 @@ enum class RequestSendError : uint8_t {
     /// port must be configured with an
     /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
     LoanErrorExceedsMaxLoanSize,
+    /// The [`Client`] has not granted enough credits to loan another [`ResponseMut`], see
+    /// [`RequestMut::set_response_credits()`].
+    LoanErrorExceedsGrantedCredits,
+    /// The final [`ResponseMut`] of the [`ActiveRequest`] was already sent with
+    /// [`ResponseMut::send_final()`], no further [`ResponseMut`] can be loaned or sent.
+    LoanErrorResponseStreamFinished,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     LoanErrorInternalFailure,
     /// A failure occurred while establishing a connection to the ports counterpart port.
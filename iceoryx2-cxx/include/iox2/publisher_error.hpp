@@ -32,6 +32,11 @@ enum class PublisherCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+    /// The datasegment in which the payload of the [`Publisher`] is stored,
+    /// could not be locked into RAM and the memory lock policy is required.
+    UnableToLockDataSegment,
 };
 } // namespace iox2
 
@@ -27,6 +27,11 @@ enum class ServerCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The datasegment in which the payload of the [`Server`] is stored, could not be locked
+    /// into RAM and the memory lock policy is required.
+    UnableToLockDataSegment,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
 };
 } // namespace iox2
 #endif
@@ -41,10 +41,16 @@ enum class BlackboardOpenError : uint8_t {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process
+    /// read-write access or the [`AttributeVerifier`] does not require the
+    /// attributes the [`AccessPolicy`] requires.
+    AccessDenied,
     /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
     ExceedsMaxNumberOfNodes,
     /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
     DoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] supports less [`Writer`](crate::port::writer::Writer)s than requested.
+    DoesNotSupportRequestedAmountOfWriters,
 };
 
 /// Errors that can occur when a new [`MessagingPattern::Blackboard`] [`Service`] shall be created.
@@ -64,6 +70,12 @@ enum class BlackboardCreateError : uint8_t {
     HangsInCreation,
     /// No key-value pairs have been provided. At least one is required.
     NoEntriesProvided,
+    /// The initial value of a slice entry contains more elements than the maximum slice length
+    /// of the entry.
+    InitialValueExceedsMaxSliceLen,
+    /// The type schema of a type exceeds the maximum number of fields or the maximum field name
+    /// length.
+    TypeSchemaExceedsLimits,
 };
 } // namespace iox2
 
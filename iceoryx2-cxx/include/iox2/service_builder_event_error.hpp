@@ -59,6 +59,13 @@ enum class EventOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process
+    /// read-write access or the [`AttributeVerifier`] does not require the
+    /// attributes the [`AccessPolicy`] requires.
+    AccessDenied,
+    /// The [`Service`] supports a smaller maximum notification payload size
+    /// than requested.
+    DoesNotSupportRequestedNotificationPayloadSize,
 };
 
 /// Failures that can occur when a new [`MessagingPattern::Event`] [`Service`] shall be created.
@@ -143,6 +150,13 @@ enum class EventOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process
+    /// read-write access or the [`AttributeVerifier`] does not require the
+    /// attributes the [`AccessPolicy`] requires.
+    OpenAccessDenied,
+    /// The [`Service`] supports a smaller maximum notification payload size
+    /// than requested.
+    OpenDoesNotSupportRequestedNotificationPayloadSize,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -49,6 +49,10 @@ enum class PublishSubscribeOpenError : uint8_t {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`]s lifespan settings are not equal to the user given requirements.
+    IncompatibleLifespan,
+    /// The [`Service`]s subscriber deadline settings are not equal to the user given requirements.
+    IncompatibleSubscriberDeadline,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -67,6 +71,10 @@ enum class PublishSubscribeOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process
+    /// read-write access or the [`AttributeVerifier`] does not require the
+    /// attributes the [`AccessPolicy`] requires.
+    AccessDenied,
 };
 
 /// Errors that can occur when a new [`MessagingPattern::PublishSubscribe`] [`Service`] shall be created.
@@ -92,6 +100,9 @@ enum class PublishSubscribeCreateError : uint8_t {
     /// initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
+    /// The type schema of a type exceeds the maximum number of fields or the maximum field name
+    /// length.
+    TypeSchemaExceedsLimits,
 };
 
 /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
@@ -127,6 +138,10 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     OpenDoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     OpenIncompatibleOverflowBehavior,
+    /// The [`Service`]s lifespan settings are not equal to the user given requirements.
+    OpenIncompatibleLifespan,
+    /// The [`Service`]s subscriber deadline settings are not equal to the user given requirements.
+    OpenIncompatibleSubscriberDeadline,
     /// The process has not enough permissions to open the [`Service`]
     OpenInsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -145,6 +160,10 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process
+    /// read-write access or the [`AttributeVerifier`] does not require the
+    /// attributes the [`AccessPolicy`] requires.
+    OpenAccessDenied,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -174,6 +193,9 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     CreateHangsInCreation,
+    /// The type schema of a type exceeds the maximum number of fields or the maximum field name
+    /// length.
+    CreateTypeSchemaExceedsLimits,
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
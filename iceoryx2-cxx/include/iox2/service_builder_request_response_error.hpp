@@ -56,6 +56,10 @@ enum class RequestResponseOpenError : uint8_t {
     IncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     IncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] does not support the required behavior for request cancellation.
+    IncompatibleRequestCancellation,
+    /// The [`Service`] has an incompatible dispatch policy.
+    IncompatibleDispatchPolicy,
     /// The process has not enough permissions to open the [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -64,6 +68,10 @@ enum class RequestResponseOpenError : uint8_t {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process
+    /// read-write access or the [`AttributeVerifier`] does not require the
+    /// attributes the [`AccessPolicy`] requires.
+    AccessDenied,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
 };
@@ -83,6 +91,9 @@ enum class RequestResponseCreateError : uint8_t {
     HangsInCreation,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
+    /// The type schema of a type exceeds the maximum number of fields or the maximum field name
+    /// length.
+    TypeSchemaExceedsLimits,
 };
 
 /// Errors that can occur when a [`MessagingPattern::RequestResponse`] [`Service`] shall be
@@ -125,6 +136,10 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     OpenIncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     OpenIncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] does not support the required behavior for request cancellation.
+    OpenIncompatibleRequestCancellation,
+    /// The [`Service`] has an incompatible dispatch policy.
+    OpenIncompatibleDispatchPolicy,
     /// The process has not enough permissions to open the [`Service`].
     OpenInsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -133,6 +148,10 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process
+    /// read-write access or the [`AttributeVerifier`] does not require the
+    /// attributes the [`AccessPolicy`] requires.
+    OpenAccessDenied,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     OpenServiceInCorruptedState,
 
@@ -149,6 +168,9 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     CreateHangsInCreation,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     CreateServiceInCorruptedState,
+    /// The type schema of a type exceeds the maximum number of fields or the maximum field name
+    /// length.
+    CreateTypeSchemaExceedsLimits,
 
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
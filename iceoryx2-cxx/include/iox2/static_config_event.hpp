@@ -55,6 +55,10 @@ class StaticConfigEvent {
     /// deadline.
     auto deadline() const -> iox::optional<iox::units::Duration>;
 
+    /// Returns the maximum size in bytes of the payload that can be attached to a
+    /// notification. If it is zero, notifications do not carry a payload.
+    auto max_notification_payload_size() const -> size_t;
+
   private:
     template <ServiceType>
     friend class PortFactoryEvent;
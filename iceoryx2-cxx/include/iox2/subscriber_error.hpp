@@ -30,6 +30,8 @@ enum class SubscriberCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
 };
 
 } // namespace iox2
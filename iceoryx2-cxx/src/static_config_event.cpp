@@ -66,4 +66,8 @@ auto StaticConfigEvent::deadline() const -> iox::optional<iox::units::Duration>
              + iox::units::Duration::fromNanoseconds(m_value.deadline_nanoseconds) };
 }
 
+auto StaticConfigEvent::max_notification_payload_size() const -> size_t {
+    return m_value.max_notification_payload_size;
+}
+
 } // namespace iox2
@@ -40,6 +40,8 @@ TEST(EnumConversionTest, connection_failure_into_c_str) {
     using Sut = iox2::ConnectionFailure;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToMapSendersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::RequestTimedOut)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ServerDisconnected)), 1U);
 }
 
 TEST(EnumConversionTest, listener_create_into_c_str) {
@@ -82,12 +84,14 @@ TEST(EnumConversionTest, notifier_create_into_c_str) {
 TEST(EnumConversionTest, notifier_notify_into_c_str) {
     using Sut = iox2::NotifierNotifyError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::EventIdOutOfBounds)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::PayloadExceedsMaxSize)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_create_into_c_str) {
     using Sut = iox2::PublisherCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedPublishers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToCreateDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToLockDataSegment)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_loan_into_c_str) {
@@ -95,6 +99,8 @@ TEST(EnumConversionTest, publisher_loan_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OutOfMemory)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxLoanedSamples)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxLoanSize)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsGrantedCredits)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ResponseStreamFinished)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalFailure)), 1U);
 }
 
@@ -105,6 +111,8 @@ TEST(EnumConversionTest, publisher_send_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorOutOfMemory)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorExceedsMaxLoans)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorExceedsMaxLoanSize)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorExceedsGrantedCredits)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorResponseStreamFinished)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorInternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ConnectionError)), 1U);
 }
@@ -124,6 +132,8 @@ TEST(EnumConversionTest, event_open_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::AccessDenied)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedNotificationPayloadSize)), 1U);
 }
 
 TEST(EnumConversionTest, event_create_into_c_str) {
@@ -152,6 +162,8 @@ TEST(EnumConversionTest, event_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenAccessDenied)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedNotificationPayloadSize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateInternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateIsBeingCreatedByAnotherInstance)), 1U);
@@ -175,11 +187,14 @@ TEST(EnumConversionTest, publish_subscribe_open_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleOverflowBehavior)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleLifespan)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleSubscriberDeadline)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::HangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::AccessDenied)), 1U);
 }
 
 TEST(EnumConversionTest, publish_subscribe_create_into_c_str) {
@@ -191,6 +206,7 @@ TEST(EnumConversionTest, publish_subscribe_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsBeingCreatedByAnotherInstance)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::HangsInCreation)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::TypeSchemaExceedsLimits)), 1U);
 }
 
 TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
@@ -207,11 +223,14 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleOverflowBehavior)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleLifespan)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleSubscriberDeadline)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenInsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenHangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenAccessDenied)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateSubscriberBufferMustBeLargerThanHistorySize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateAlreadyExists)), 1U);
@@ -220,6 +239,7 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateIsBeingCreatedByAnotherInstance)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateOldConnectionsStillActive)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateHangsInCreation)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateTypeSchemaExceedsLimits)), 1U);
 }
 
 TEST(EnumConversionTest, service_details_into_c_str) {
@@ -244,6 +264,8 @@ TEST(EnumConversionTest, subscriber_receive_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxBorrows)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToMapSendersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::RequestTimedOut)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ServerDisconnected)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_create_into_c_str) {
@@ -96,6 +96,8 @@ pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    INTERNAL_FAILURE,
+    UNABLE_TO_LOCK_DATA_SEGMENT,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -107,9 +109,15 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::UnableToCreateDataSegment => {
                 iox2_publisher_create_error_e::UNABLE_TO_CREATE_DATA_SEGMENT
             }
+            PublisherCreateError::UnableToLockDataSegment => {
+                iox2_publisher_create_error_e::UNABLE_TO_LOCK_DATA_SEGMENT
+            }
             PublisherCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_publisher_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            PublisherCreateError::InternalFailure => {
+                iox2_publisher_create_error_e::INTERNAL_FAILURE
+            }
         }) as c_int
     }
 }
@@ -140,7 +148,7 @@ impl PortFactoryPublisherBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryPublisherBuilderUnion>
 pub struct iox2_port_factory_publisher_builder_storage_t {
-    internal: [u8; 128], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
+    internal: [u8; 256], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -35,6 +35,8 @@ pub enum iox2_server_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SERVERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    UNABLE_TO_LOCK_DATA_SEGMENT,
+    INTERNAL_FAILURE,
 }
 
 impl IntoCInt for ServerCreateError {
@@ -43,12 +45,16 @@ impl IntoCInt for ServerCreateError {
             ServerCreateError::UnableToCreateDataSegment => {
                 iox2_server_create_error_e::UNABLE_TO_CREATE_DATA_SEGMENT
             }
+            ServerCreateError::UnableToLockDataSegment => {
+                iox2_server_create_error_e::UNABLE_TO_LOCK_DATA_SEGMENT
+            }
             ServerCreateError::ExceedsMaxSupportedServers => {
                 iox2_server_create_error_e::EXCEEDS_MAX_SUPPORTED_SERVERS
             }
             ServerCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_server_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            ServerCreateError::InternalFailure => iox2_server_create_error_e::INTERNAL_FAILURE,
         }) as c_int
     }
 }
@@ -110,7 +116,7 @@ impl PortFactoryServerBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryServerBuilderUnion>
 pub struct iox2_port_factory_server_builder_storage_t {
-    internal: [u8; 176], // magic number obtained with size_of::<Option<PortFactoryServerBuilderUnion>>()
+    internal: [u8; 320], // magic number obtained with size_of::<Option<PortFactoryServerBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -27,7 +27,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 48], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 64], // core::mem::size_of::<Option<Header>>()
 }
 
 #[repr(C)]
@@ -179,4 +179,57 @@ pub unsafe extern "C" fn iox2_publish_subscribe_header_number_of_elements(
 
     header.value.as_ref().number_of_elements()
 }
+
+/// Returns the sequence number the publisher assigned to the sample when it was sent.
+/// Every publisher starts with 0 and increments it by one with every sent sample.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///   [`iox2_sample_header()`](crate::iox2_sample_header)
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_sequence_number(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+) -> u64 {
+    header_handle.assert_non_null();
+
+    let header = &mut *header_handle.as_type();
+
+    header.value.as_ref().sequence_number()
+}
+
+/// Returns the point in time, acquired from the monotonic clock, when the sample was sent.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///   [`iox2_sample_header()`](crate::iox2_sample_header)
+/// * `seconds` - the seconds of the send timestamp
+/// * `nanoseconds` - the fractional part of the send timestamp in nanoseconds
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+/// * `seconds` is pointing to a valid memory location and non-null
+/// * `nanoseconds` is pointing to a valid memory location and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_send_timestamp(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+    seconds: *mut u64,
+    nanoseconds: *mut u32,
+) {
+    header_handle.assert_non_null();
+    debug_assert!(!seconds.is_null());
+    debug_assert!(!nanoseconds.is_null());
+
+    let header = &mut *header_handle.as_type();
+    let send_timestamp = header.value.as_ref().send_timestamp();
+
+    *seconds = send_timestamp.seconds();
+    *nanoseconds = send_timestamp.nanoseconds();
+}
 // END C API
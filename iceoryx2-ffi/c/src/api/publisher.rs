@@ -44,6 +44,8 @@ pub enum iox2_send_error_e {
     LOAN_ERROR_OUT_OF_MEMORY,
     LOAN_ERROR_EXCEEDS_MAX_LOANS,
     LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
+    LOAN_ERROR_EXCEEDS_GRANTED_CREDITS,
+    LOAN_ERROR_RESPONSE_STREAM_FINISHED,
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
 }
@@ -64,6 +66,12 @@ impl IntoCInt for SendError {
             SendError::LoanError(LoanError::ExceedsMaxLoanSize) => {
                 iox2_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE
             }
+            SendError::LoanError(LoanError::ExceedsGrantedCredits) => {
+                iox2_send_error_e::LOAN_ERROR_EXCEEDS_GRANTED_CREDITS
+            }
+            SendError::LoanError(LoanError::ResponseStreamFinished) => {
+                iox2_send_error_e::LOAN_ERROR_RESPONSE_STREAM_FINISHED
+            }
             SendError::LoanError(LoanError::InternalFailure) => {
                 iox2_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
@@ -78,6 +86,8 @@ impl IntoCInt for LoanError {
             LoanError::OutOfMemory => iox2_loan_error_e::OUT_OF_MEMORY,
             LoanError::ExceedsMaxLoans => iox2_loan_error_e::EXCEEDS_MAX_LOANED_SAMPLES,
             LoanError::ExceedsMaxLoanSize => iox2_loan_error_e::EXCEEDS_MAX_LOAN_SIZE,
+            LoanError::ExceedsGrantedCredits => iox2_loan_error_e::EXCEEDS_GRANTED_CREDITS,
+            LoanError::ResponseStreamFinished => iox2_loan_error_e::RESPONSE_STREAM_FINISHED,
             LoanError::InternalFailure => iox2_loan_error_e::INTERNAL_FAILURE,
         }) as c_int
     }
@@ -89,6 +99,8 @@ pub enum iox2_loan_error_e {
     OUT_OF_MEMORY = IOX2_OK as isize + 1,
     EXCEEDS_MAX_LOANED_SAMPLES,
     EXCEEDS_MAX_LOAN_SIZE,
+    EXCEEDS_GRANTED_CREDITS,
+    RESPONSE_STREAM_FINISHED,
     INTERNAL_FAILURE,
 }
 
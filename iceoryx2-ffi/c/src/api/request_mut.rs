@@ -42,6 +42,8 @@ pub enum iox2_request_send_error_e {
     LOAN_ERROR_OUT_OF_MEMORY,
     LOAN_ERROR_EXCEEDS_MAX_LOANS,
     LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
+    LOAN_ERROR_EXCEEDS_GRANTED_CREDITS,
+    LOAN_ERROR_RESPONSE_STREAM_FINISHED,
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
     EXCEEDS_MAX_ACTIVE_REQUESTS,
@@ -65,6 +67,12 @@ impl IntoCInt for RequestSendError {
             RequestSendError::SendError(SendError::LoanError(LoanError::ExceedsMaxLoanSize)) => {
                 iox2_request_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE
             }
+            RequestSendError::SendError(SendError::LoanError(LoanError::ExceedsGrantedCredits)) => {
+                iox2_request_send_error_e::LOAN_ERROR_EXCEEDS_GRANTED_CREDITS
+            }
+            RequestSendError::SendError(SendError::LoanError(
+                LoanError::ResponseStreamFinished,
+            )) => iox2_request_send_error_e::LOAN_ERROR_RESPONSE_STREAM_FINISHED,
             RequestSendError::SendError(SendError::LoanError(LoanError::InternalFailure)) => {
                 iox2_request_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
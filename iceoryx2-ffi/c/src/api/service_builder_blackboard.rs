@@ -72,6 +72,10 @@ pub enum iox2_blackboard_open_error_e {
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     #[CStr = "does not support requested amount of nodes"]
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
+    #[CStr = "does not support requested amount of writers"]
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_WRITERS,
+    #[CStr = "access denied"]
+    O_ACCESS_DENIED,
 }
 
 #[repr(C)]
@@ -91,6 +95,10 @@ pub enum iox2_blackboard_create_error_e {
     C_HANGS_IN_CREATION,
     #[CStr = "no entries provided"]
     C_NO_ENTRIES_PROVIDED,
+    #[CStr = "initial value exceeds max slice len"]
+    C_INITIAL_VALUE_EXCEEDS_MAX_SLICE_LEN,
+    #[CStr = "type schema exceeds limits"]
+    C_TYPE_SCHEMA_EXCEEDS_LIMITS,
 }
 
 impl IntoCInt for BlackboardOpenError {
@@ -115,6 +123,9 @@ impl IntoCInt for BlackboardOpenError {
             BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders => {
                 iox2_blackboard_open_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_READERS
             }
+            BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters => {
+                iox2_blackboard_open_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_WRITERS
+            }
             BlackboardOpenError::InsufficientPermissions => {
                 iox2_blackboard_open_error_e::O_INSUFFICIENT_PERMISSIONS
             }
@@ -130,6 +141,7 @@ impl IntoCInt for BlackboardOpenError {
             BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes => {
                 iox2_blackboard_open_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES
             }
+            BlackboardOpenError::AccessDenied => iox2_blackboard_open_error_e::O_ACCESS_DENIED,
         }) as c_int
     }
 }
@@ -158,6 +170,12 @@ impl IntoCInt for BlackboardCreateError {
             BlackboardCreateError::NoEntriesProvided => {
                 iox2_blackboard_create_error_e::C_NO_ENTRIES_PROVIDED
             }
+            BlackboardCreateError::InitialValueExceedsMaxSliceLen => {
+                iox2_blackboard_create_error_e::C_INITIAL_VALUE_EXCEEDS_MAX_SLICE_LEN
+            }
+            BlackboardCreateError::TypeSchemaExceedsLimits => {
+                iox2_blackboard_create_error_e::C_TYPE_SCHEMA_EXCEEDS_LIMITS
+            }
         }) as c_int
     }
 }
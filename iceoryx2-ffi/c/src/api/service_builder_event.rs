@@ -71,6 +71,10 @@ pub enum iox2_event_open_or_create_error_e {
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     #[CStr = "is marked for destruction"]
     O_IS_MARKED_FOR_DESTRUCTION,
+    #[CStr = "access denied"]
+    O_ACCESS_DENIED,
+    #[CStr = "does not support requested notification payload size"]
+    O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE,
     #[CStr = "service in corrupted state"]
     C_SERVICE_IN_CORRUPTED_STATE,
     #[CStr = "internal failure"]
@@ -141,6 +145,10 @@ impl IntoCInt for EventOpenError {
             EventOpenError::IncompatibleDeadline => {
                 iox2_event_open_or_create_error_e::O_INCOMPATIBLE_DEADLINE
             }
+            EventOpenError::AccessDenied => iox2_event_open_or_create_error_e::O_ACCESS_DENIED,
+            EventOpenError::DoesNotSupportRequestedNotificationPayloadSize => {
+                iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE
+            }
         }) as c_int
     }
 }
@@ -65,6 +65,10 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     #[CStr = "incompatible overflow behavior"]
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+    #[CStr = "incompatible lifespan"]
+    O_INCOMPATIBLE_LIFESPAN,
+    #[CStr = "incompatible subscriber deadline"]
+    O_INCOMPATIBLE_SUBSCRIBER_DEADLINE,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "service in corrupted state"]
@@ -75,6 +79,8 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     #[CStr = "is marked for destruction"]
     O_IS_MARKED_FOR_DESTRUCTION,
+    #[CStr = "access denied"]
+    O_ACCESS_DENIED,
     #[CStr = "service in corrupted state"]
     C_SERVICE_IN_CORRUPTED_STATE,
     #[CStr = "subscriber buffer must be larger than history size"]
@@ -91,6 +97,8 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     C_OLD_CONNECTION_STILL_ACTIVE,
     #[CStr = "hangs in creation"]
     C_HANGS_IN_CREATION,
+    #[CStr = "type schema exceeds limits"]
+    C_TYPE_SCHEMA_EXCEEDS_LIMITS,
     #[CStr = "same service is created and removed repeatedly"]
     SYSTEM_IN_FLUX,
 }
@@ -132,6 +140,12 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleOverflowBehavior => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR
          }
+         PublishSubscribeOpenError::IncompatibleLifespan => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_LIFESPAN
+         }
+         PublishSubscribeOpenError::IncompatibleSubscriberDeadline => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_SUBSCRIBER_DEADLINE
+         }
          PublishSubscribeOpenError::InsufficientPermissions => {
              iox2_pub_sub_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
          }
@@ -147,6 +161,9 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IsMarkedForDestruction => {
              iox2_pub_sub_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
          }
+         PublishSubscribeOpenError::AccessDenied => {
+             iox2_pub_sub_open_or_create_error_e::O_ACCESS_DENIED
+         }
         }) as c_int
     }
 }
@@ -173,6 +190,9 @@ impl IntoCInt for PublishSubscribeCreateError {
          PublishSubscribeCreateError::HangsInCreation => {
              iox2_pub_sub_open_or_create_error_e::C_HANGS_IN_CREATION
          }
+            PublishSubscribeCreateError::TypeSchemaExceedsLimits => {
+                iox2_pub_sub_open_or_create_error_e::C_TYPE_SCHEMA_EXCEEDS_LIMITS
+            }
         }) as c_int
     }
 }
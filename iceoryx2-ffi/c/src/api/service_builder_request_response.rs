@@ -87,6 +87,10 @@ pub enum iox2_request_response_open_or_create_error_e {
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES,
     #[CStr = "incompatible behavior for fire and forget requests"]
     O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS,
+    #[CStr = "incompatible request cancellation"]
+    O_INCOMPATIBLE_REQUEST_CANCELLATION,
+    #[CStr = "incompatible dispatch policy"]
+    O_INCOMPATIBLE_DISPATCH_POLICY,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "internal failure"]
@@ -95,6 +99,8 @@ pub enum iox2_request_response_open_or_create_error_e {
     O_IS_MARKED_FOR_DESTRUCTION,
     #[CStr = "service in corrupted state"]
     O_SERVICE_IN_CORRUPTED_STATE,
+    #[CStr = "access denied"]
+    O_ACCESS_DENIED,
     #[CStr = "already exists"]
     C_ALREADY_EXISTS,
     #[CStr = "internal failure"]
@@ -107,6 +113,8 @@ pub enum iox2_request_response_open_or_create_error_e {
     C_HANGS_IN_CREATION,
     #[CStr = "service in corrupted state"]
     C_SERVICE_IN_CORRUPTED_STATE,
+    #[CStr = "type schema exceeds limits"]
+    C_TYPE_SCHEMA_EXCEEDS_LIMITS,
     #[CStr = "system in flux"]
     SYSTEM_IN_FLUX,
 }
@@ -131,10 +139,13 @@ impl IntoCInt for RequestResponseOpenError {
             RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_REQUESTS,
             RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES,
             RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS,
+            RequestResponseOpenError::IncompatibleRequestCancellation => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_REQUEST_CANCELLATION,
+            RequestResponseOpenError::IncompatibleDispatchPolicy => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_DISPATCH_POLICY,
             RequestResponseOpenError::InsufficientPermissions => iox2_request_response_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS,
             RequestResponseOpenError::InternalFailure => iox2_request_response_open_or_create_error_e::O_INTERNAL_FAILURE,
             RequestResponseOpenError::IsMarkedForDestruction => iox2_request_response_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION,
             RequestResponseOpenError::ServiceInCorruptedState => iox2_request_response_open_or_create_error_e::O_SERVICE_IN_CORRUPTED_STATE,
+            RequestResponseOpenError::AccessDenied => iox2_request_response_open_or_create_error_e::O_ACCESS_DENIED,
         }) as c_int
     }
 }
@@ -160,6 +171,9 @@ impl IntoCInt for RequestResponseCreateError {
             RequestResponseCreateError::ServiceInCorruptedState => {
                 iox2_request_response_open_or_create_error_e::C_SERVICE_IN_CORRUPTED_STATE
             }
+            RequestResponseCreateError::TypeSchemaExceedsLimits => {
+                iox2_request_response_open_or_create_error_e::C_TYPE_SCHEMA_EXCEEDS_LIMITS
+            }
         }) as c_int
     }
 }
@@ -30,6 +30,7 @@ pub struct iox2_static_config_event_t {
     pub deadline_seconds: u64,
     pub deadline_nanoseconds: u32,
     pub has_deadline: bool,
+    pub max_notification_payload_size: usize,
 }
 
 impl From<&StaticConfig> for iox2_static_config_event_t {
@@ -57,6 +58,7 @@ impl From<&StaticConfig> for iox2_static_config_event_t {
             deadline_seconds: c.deadline().map(|v| v.as_secs()).unwrap_or(0),
             deadline_nanoseconds: c.deadline().map(|v| v.subsec_nanos()).unwrap_or(0),
             has_deadline: c.deadline().is_some(),
+            max_notification_payload_size: c.max_notification_payload_size(),
         }
     }
 }
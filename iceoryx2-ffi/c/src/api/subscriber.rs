@@ -37,6 +37,8 @@ pub enum iox2_receive_error_e {
     EXCEEDS_MAX_BORROWS = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+    REQUEST_TIMED_OUT,
+    SERVER_DISCONNECTED,
 }
 
 impl IntoCInt for ReceiveError {
@@ -49,6 +51,8 @@ impl IntoCInt for ReceiveError {
             ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(
                 _,
             )) => iox2_receive_error_e::UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+            ReceiveError::RequestTimedOut => iox2_receive_error_e::REQUEST_TIMED_OUT,
+            ReceiveError::ServerDisconnected => iox2_receive_error_e::SERVER_DISCONNECTED,
         }) as c_int
     }
 }
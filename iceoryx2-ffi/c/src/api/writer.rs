@@ -32,6 +32,8 @@ use iceoryx2_ffi_macros::{iceoryx2_ffi, CStrRepr};
 pub enum iox2_entry_handle_mut_error_e {
     ENTRY_DOES_NOT_EXIST = IOX2_OK as isize + 1,
     HANDLE_ALREADY_EXISTS,
+    INTERNAL_FAILURE,
+    ENTRY_OWNED_BY_ANOTHER_WRITER,
 }
 
 impl IntoCInt for EntryHandleMutError {
@@ -43,6 +45,10 @@ impl IntoCInt for EntryHandleMutError {
             EntryHandleMutError::HandleAlreadyExists => {
                 iox2_entry_handle_mut_error_e::HANDLE_ALREADY_EXISTS
             }
+            EntryHandleMutError::InternalFailure => iox2_entry_handle_mut_error_e::INTERNAL_FAILURE,
+            EntryHandleMutError::EntryOwnedByAnotherWriter => {
+                iox2_entry_handle_mut_error_e::ENTRY_OWNED_BY_ANOTHER_WRITER
+            }
         }) as c_int
     }
 }
@@ -12,7 +12,7 @@
 
 use pyo3::prelude::*;
 
-use crate::{node_id::NodeId, unique_publisher_id::UniquePublisherId};
+use crate::{duration::Duration, node_id::NodeId, unique_publisher_id::UniquePublisherId};
 
 #[pyclass(eq)]
 #[derive(PartialEq, Eq)]
@@ -38,4 +38,16 @@ impl HeaderPublishSubscribe {
     pub fn number_of_elements(&self) -> u64 {
         self.0.number_of_elements()
     }
+
+    #[getter]
+    /// Returns the sequence number the `Publisher` assigned to the `Sample` when it was sent.
+    pub fn sequence_number(&self) -> u64 {
+        self.0.sequence_number()
+    }
+
+    #[getter]
+    /// Returns the point in time, acquired from the monotonic clock, when the `Sample` was sent.
+    pub fn send_timestamp(&self) -> Duration {
+        Duration(self.0.send_timestamp().as_duration())
+    }
 }
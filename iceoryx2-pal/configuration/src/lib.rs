@@ -19,6 +19,7 @@ pub mod settings {
     pub const TEMP_DIRECTORY: &[u8] = b"/tmp/";
     pub const TEST_DIRECTORY: &[u8] = b"/tmp/iceoryx2/tests/";
     pub const SHARED_MEMORY_DIRECTORY: &[u8] = b"/dev/shm/";
+    pub const HUGE_PAGE_DIRECTORY: &[u8] = b"/dev/hugepages/";
     pub const PATH_SEPARATOR: u8 = b'/';
     pub const ROOT: &[u8] = b"/";
     pub const ICEORYX2_ROOT_PATH: &[u8] = b"/tmp/iceoryx2/";
@@ -39,6 +40,7 @@ pub mod settings {
     pub const TEMP_DIRECTORY: &[u8] = b"/data/iceoryx2/tmp/";
     pub const TEST_DIRECTORY: &[u8] = b"/data/iceoryx2/tests/";
     pub const SHARED_MEMORY_DIRECTORY: &[u8] = b"/dev/shmem/";
+    pub const HUGE_PAGE_DIRECTORY: &[u8] = b"/dev/hugepages/";
     pub const PATH_SEPARATOR: u8 = b'/';
     pub const ROOT: &[u8] = b"/";
     pub const ICEORYX2_ROOT_PATH: &[u8] = b"/data/iceoryx2/";
@@ -54,6 +56,7 @@ pub mod settings {
     pub const TEMP_DIRECTORY: &[u8] = b"C:\\Temp\\";
     pub const TEST_DIRECTORY: &[u8] = b"C:\\Temp\\iceoryx2\\tests\\";
     pub const SHARED_MEMORY_DIRECTORY: &[u8] = b"C:\\Temp\\iceoryx2\\shm\\";
+    pub const HUGE_PAGE_DIRECTORY: &[u8] = b"C:\\Temp\\iceoryx2\\hugepages\\";
     pub const PATH_SEPARATOR: u8 = b'\\';
     pub const ROOT: &[u8] = b"C:\\";
     pub const ICEORYX2_ROOT_PATH: &[u8] = b"C:\\Temp\\iceoryx2\\";
@@ -0,0 +1,31 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+#![allow(clippy::missing_safety_doc)]
+
+use iceoryx2_pal_posix::posix;
+
+pub const MPOL_BIND: posix::int = 2;
+pub const MPOL_MF_STRICT: posix::uint = 1 << 0;
+pub const MPOL_MF_MOVE: posix::uint = 1 << 1;
+
+pub unsafe fn mbind(
+    addr: *mut posix::void,
+    len: posix::ulong,
+    mode: posix::int,
+    nodemask: *const posix::ulong,
+    maxnode: posix::ulong,
+    flags: posix::uint,
+) -> posix::long {
+    libc::syscall(libc::SYS_mbind, addr, len, mode, nodemask, maxnode, flags)
+}
@@ -11,7 +11,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub mod epoll;
+pub mod mempolicy;
 pub mod signalfd;
 
 pub use epoll::*;
+pub use mempolicy::*;
 pub use signalfd::*;
@@ -179,11 +179,16 @@ pub const PF_UNIX: sa_family_t = libc::AF_UNIX as _;
 pub const INADDR_ANY: in_addr_t = 0;
 pub const SO_SNDBUF: int = libc::SO_SNDBUF as _;
 pub const SO_RCVBUF: int = libc::SO_RCVBUF as _;
+pub const SO_REUSEADDR: int = libc::SO_REUSEADDR as _;
 pub const SO_RCVTIMEO: int = libc::SO_RCVTIMEO as _;
 pub const SO_SNDTIMEO: int = libc::SO_SNDTIMEO as _;
 pub const SOCK_STREAM: int = libc::SOCK_STREAM as _;
 pub const SOCK_DGRAM: int = libc::SOCK_DGRAM as _;
 pub const IPPROTO_UDP: int = libc::IPPROTO_UDP as _;
+pub const IPPROTO_IP: int = libc::IPPROTO_IP as _;
+pub const IP_ADD_MEMBERSHIP: int = libc::IP_ADD_MEMBERSHIP as _;
+pub const IP_MULTICAST_IF: int = libc::IP_MULTICAST_IF as _;
+pub const IP_MULTICAST_LOOP: int = libc::IP_MULTICAST_LOOP as _;
 pub const SOCK_NONBLOCK: int = O_NONBLOCK;
 pub const MSG_PEEK: int = libc::MSG_PEEK as _;
 pub const SCM_MAX_FD: u32 = 253;
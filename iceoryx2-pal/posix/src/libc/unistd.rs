@@ -77,6 +77,10 @@ pub unsafe fn getgid() -> gid_t {
     libc::getgid()
 }
 
+pub unsafe fn getgroups(size: int, list: *mut gid_t) -> int {
+    libc::getgroups(size, list)
+}
+
 pub unsafe fn rmdir(pathname: *const c_char) -> int {
     libc::rmdir(pathname)
 }
@@ -176,6 +176,7 @@ pub const PF_UNIX: sa_family_t = crate::internal::AF_UNIX as _;
 pub const INADDR_ANY: in_addr_t = 0;
 pub const SO_SNDBUF: int = crate::internal::SO_SNDBUF as _;
 pub const SO_RCVBUF: int = crate::internal::SO_RCVBUF as _;
+pub const SO_REUSEADDR: int = crate::internal::SO_REUSEADDR as _;
 #[cfg(target_pointer_width = "32")]
 pub const SO_RCVTIMEO: int = crate::internal::SO_RCVTIMEO_OLD as _;
 #[cfg(target_pointer_width = "64")]
@@ -187,6 +188,10 @@ pub const SO_SNDTIMEO: int = crate::internal::SO_SNDTIMEO as _;
 pub const SOCK_STREAM: int = crate::internal::__socket_type_SOCK_STREAM as _;
 pub const SOCK_DGRAM: int = crate::internal::__socket_type_SOCK_DGRAM as _;
 pub const IPPROTO_UDP: int = crate::internal::IPPROTO_UDP as _;
+pub const IPPROTO_IP: int = crate::internal::IPPROTO_IP as _;
+pub const IP_ADD_MEMBERSHIP: int = crate::internal::IP_ADD_MEMBERSHIP as _;
+pub const IP_MULTICAST_IF: int = crate::internal::IP_MULTICAST_IF as _;
+pub const IP_MULTICAST_LOOP: int = crate::internal::IP_MULTICAST_LOOP as _;
 pub const SOCK_NONBLOCK: int = O_NONBLOCK;
 pub const MSG_PEEK: int = crate::internal::MSG_PEEK as _;
 pub const SCM_MAX_FD: u32 = 253;
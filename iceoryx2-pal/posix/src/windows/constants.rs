@@ -145,12 +145,17 @@ pub const SO_PASSCRED: int = 1;
 pub const SO_PEERCRED: int = 2;
 pub const SO_SNDBUF: int = windows_sys::Win32::Networking::WinSock::SO_SNDBUF as _;
 pub const SO_RCVBUF: int = windows_sys::Win32::Networking::WinSock::SO_RCVBUF as _;
+pub const SO_REUSEADDR: int = windows_sys::Win32::Networking::WinSock::SO_REUSEADDR as _;
 pub const SO_RCVTIMEO: int = windows_sys::Win32::Networking::WinSock::SO_RCVTIMEO as _;
 pub const SO_SNDTIMEO: int = windows_sys::Win32::Networking::WinSock::SO_SNDTIMEO as _;
 pub const SOCK_STREAM: int = windows_sys::Win32::Networking::WinSock::SOCK_STREAM as _;
 pub const SOCK_DGRAM: int = windows_sys::Win32::Networking::WinSock::SOCK_DGRAM as _;
 pub const SOCK_NONBLOCK: int = O_NONBLOCK;
 pub const IPPROTO_UDP: int = windows_sys::Win32::Networking::WinSock::IPPROTO_UDP as _;
+pub const IPPROTO_IP: int = windows_sys::Win32::Networking::WinSock::IPPROTO_IP as _;
+pub const IP_ADD_MEMBERSHIP: int = windows_sys::Win32::Networking::WinSock::IP_ADD_MEMBERSHIP as _;
+pub const IP_MULTICAST_IF: int = windows_sys::Win32::Networking::WinSock::IP_MULTICAST_IF as _;
+pub const IP_MULTICAST_LOOP: int = windows_sys::Win32::Networking::WinSock::IP_MULTICAST_LOOP as _;
 pub const MSG_PEEK: int = windows_sys::Win32::Networking::WinSock::MSG_PEEK as _;
 pub const SCM_MAX_FD: u32 = 253;
 pub const SCM_RIGHTS: int = 128;
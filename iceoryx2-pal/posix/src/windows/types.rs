@@ -22,7 +22,7 @@ use iceoryx2_pal_concurrency_sync::mutex::Mutex;
 use iceoryx2_pal_concurrency_sync::rwlock::*;
 use iceoryx2_pal_concurrency_sync::semaphore::Semaphore;
 use windows_sys::Win32::Foundation::{HANDLE, INVALID_HANDLE_VALUE};
-use windows_sys::Win32::Networking::WinSock::{SOCKADDR_IN, TIMEVAL};
+use windows_sys::Win32::Networking::WinSock::{IP_MREQ, SOCKADDR_IN, TIMEVAL};
 
 use crate::posix::MemZeroedStruct;
 use crate::posix::*;
@@ -321,3 +321,16 @@ impl SockAddrIn for sockaddr_in {
         unsafe { self.sin_addr.S_un.S_addr }
     }
 }
+
+pub type ip_mreq = IP_MREQ;
+impl MemZeroedStruct for ip_mreq {}
+
+impl IpMreq for ip_mreq {
+    fn set_imr_multiaddr(&mut self, value: u32) {
+        self.imr_multiaddr.S_un.S_addr = value;
+    }
+
+    fn set_imr_interface(&mut self, value: u32) {
+        self.imr_interface.S_un.S_addr = value;
+    }
+}
@@ -332,6 +332,10 @@ pub unsafe fn getgid() -> gid_t {
     gid_t::MAX
 }
 
+pub unsafe fn getgroups(_size: int, _list: *mut gid_t) -> int {
+    0
+}
+
 pub unsafe fn rmdir(pathname: *const c_char) -> int {
     let (has_removed, _) =
         win32call! {RemoveDirectoryA(pathname as*const u8), ignore ERROR_FILE_NOT_FOUND};
@@ -108,9 +108,9 @@ impl From<PublisherCreateError> for CreationError {
                 CreationError::PublisherAlreadyExists
             }
             PublisherCreateError::UnableToCreateDataSegment
-            | PublisherCreateError::FailedToDeployThreadsafetyPolicy => {
-                CreationError::PublisherCreationError
-            }
+            | PublisherCreateError::UnableToLockDataSegment
+            | PublisherCreateError::FailedToDeployThreadsafetyPolicy
+            | PublisherCreateError::InternalFailure => CreationError::PublisherCreationError,
         }
     }
 }
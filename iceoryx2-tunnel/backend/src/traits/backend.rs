@@ -15,7 +15,10 @@ use core::fmt::Debug;
 
 use iceoryx2::service::Service;
 
-use crate::traits::{Discovery, EventRelay, PublishSubscribeRelay, RelayFactory};
+use crate::traits::{
+    BlackboardRelay, Discovery, EventRelay, PublishSubscribeRelay, RelayFactory,
+    RequestResponseRelay,
+};
 
 /// Core interface for tunnel backends that extend iceoryx2 over another
 /// communication mechanism.
@@ -40,8 +43,12 @@ use crate::traits::{Discovery, EventRelay, PublishSubscribeRelay, RelayFactory};
 ///   ├── RelayFactory
 ///   │   ├── PublishSubscribeRelay
 ///   │   │   └── PublishSubscribeBuilder
-///   │   └── EventRelay
-///   │       └── EventBuilder
+///   │   ├── EventRelay
+///   │   │   └── EventBuilder
+///   │   ├── RequestResponseRelay
+///   │   │   └── RequestResponseBuilder
+///   │   └── BlackboardRelay
+///   │       └── BlackboardBuilder
 ///   └── Error
 /// ```
 ///
@@ -99,11 +106,19 @@ pub trait Backend<S: Service>: Sized {
     /// [`EventRelay`] implementation for the event messaging pattern
     type EventRelay: EventRelay<S> + Debug;
 
+    /// [`RequestResponseRelay`] implementation for the request-response messaging pattern
+    type RequestResponseRelay: RequestResponseRelay<S> + Debug;
+
+    /// [`BlackboardRelay`] implementation for the blackboard messaging pattern
+    type BlackboardRelay: BlackboardRelay<S> + Debug;
+
     /// Factory type for creating relay instances
     type RelayFactory<'a>: RelayFactory<
             S,
             PublishSubscribeRelay = Self::PublishSubscribeRelay,
             EventRelay = Self::EventRelay,
+            RequestResponseRelay = Self::RequestResponseRelay,
+            BlackboardRelay = Self::BlackboardRelay,
         > + Debug
     where
         Self: 'a;
@@ -0,0 +1,55 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::error::Error;
+
+use alloc::vec::Vec;
+
+use iceoryx2::service::Service;
+
+use crate::types::blackboard::Entry;
+
+/// Relay for tunneling iceoryx2 blackboards through a [`Backend`](crate::traits::Backend).
+///
+/// [`BlackboardRelay`] replicates the state of a blackboard that is written on one host
+/// into mirrored blackboards on remote hosts. Since the entries of a blackboard are
+/// defined when it is created, the complete set of [`Entry`]s is transmitted with every
+/// update so that a remote host is able to create the mirror from the first update it
+/// receives.
+///
+/// # Type Parameters
+///
+/// * `S` - The iceoryx2 [`Service`] type
+pub trait BlackboardRelay<S: Service> {
+    /// Error type returned when sending fails.
+    type SendError: Error;
+
+    /// Error type returned when receiving fails.
+    type ReceiveError: Error;
+
+    /// Sends the current state of all [`Entry`]s of a local blackboard to remote hosts.
+    ///
+    /// Hosts that join after the state was sent must still be able to receive
+    /// the most recently sent state.
+    fn send(&self, entries: &[Entry]) -> Result<(), Self::SendError>;
+
+    /// Attempts to receive the state of a blackboard on a remote host.
+    ///
+    /// Checks for incoming updates without blocking. When multiple updates are
+    /// available, only the most recent one is returned.
+    ///
+    /// # Returns
+    ///
+    /// * The [`Entry`]s of the blackboard on the remote host
+    /// * [`None`] when no updates are to be received
+    fn receive(&self) -> Result<Option<Vec<Entry>>, Self::ReceiveError>;
+}
@@ -15,8 +15,10 @@ use core::fmt::Debug;
 
 use iceoryx2::service::{static_config::StaticConfig, Service};
 
+use crate::traits::BlackboardRelay;
 use crate::traits::EventRelay;
 use crate::traits::PublishSubscribeRelay;
+use crate::traits::RequestResponseRelay;
 
 /// Builder pattern for constructing relay instances.
 ///
@@ -137,6 +139,14 @@ pub trait RelayFactory<S: Service> {
     /// to be built by [`RelayBuilder`]s created by the [`RelayFactory`]
     type EventRelay: EventRelay<S>;
 
+    /// The [`RequestResponse`](iceoryx2::service::messaging_pattern::MessagingPattern::RequestResponse)
+    /// to be built by [`RelayBuilder`]s created by the [`RelayFactory`]
+    type RequestResponseRelay: RequestResponseRelay<S>;
+
+    /// The [`Blackboard`](iceoryx2::service::messaging_pattern::MessagingPattern::Blackboard)
+    /// to be built by [`RelayBuilder`]s created by the [`RelayFactory`]
+    type BlackboardRelay: BlackboardRelay<S>;
+
     /// [RelayBuilder] type for creating [`PublishSubscribe`](iceoryx2::service::messaging_pattern::MessagingPattern::PublishSubscribe)
     /// relays.
     type PublishSubscribeBuilder<'a>: RelayBuilder<Relay = Self::PublishSubscribeRelay> + Debug + 'a
@@ -149,6 +159,18 @@ pub trait RelayFactory<S: Service> {
     where
         Self: 'a;
 
+    /// [RelayBuilder] type for creating [`RequestResponse`](iceoryx2::service::messaging_pattern::MessagingPattern::RequestResponse)
+    /// relays.
+    type RequestResponseBuilder<'a>: RelayBuilder<Relay = Self::RequestResponseRelay> + Debug + 'a
+    where
+        Self: 'a;
+
+    /// [RelayBuilder] type for creating [`Blackboard`](iceoryx2::service::messaging_pattern::MessagingPattern::Blackboard)
+    /// relays.
+    type BlackboardBuilder<'a>: RelayBuilder<Relay = Self::BlackboardRelay> + Debug + 'a
+    where
+        Self: 'a;
+
     /// Creates a [`RelayBuilder`] for [`PublishSubscribe`](iceoryx2::service::messaging_pattern::MessagingPattern::PublishSubscribe)
     /// relays.
     ///
@@ -182,4 +204,37 @@ pub trait RelayFactory<S: Service> {
     fn event<'a>(&self, static_config: &'a StaticConfig) -> Self::EventBuilder<'a>
     where
         Self: 'a;
+
+    /// Creates a [`RelayBuilder`] for [`RequestResponse`](iceoryx2::service::messaging_pattern::MessagingPattern::RequestResponse)
+    /// relays.
+    ///
+    /// # Parameters
+    ///
+    /// * `static_config` - The [`Service`]'s [`StaticConfig`] for which a builder will be created
+    ///
+    /// # Returns
+    ///
+    /// A [`RelayBuilder`] configured with the [`Service`]'s [`StaticConfig`].
+    /// The [`RelayBuilder`] can be further customized before calling [`RelayBuilder::create()`].
+    fn request_response<'a>(
+        &self,
+        static_config: &'a StaticConfig,
+    ) -> Self::RequestResponseBuilder<'a>
+    where
+        Self: 'a;
+
+    /// Creates a [`RelayBuilder`] for [`Blackboard`](iceoryx2::service::messaging_pattern::MessagingPattern::Blackboard)
+    /// relays.
+    ///
+    /// # Parameters
+    ///
+    /// * `static_config` - The [`Service`]'s [`StaticConfig`] for which a builder will be created
+    ///
+    /// # Returns
+    ///
+    /// A [`RelayBuilder`] configured with the [`Service`]'s [`StaticConfig`].
+    /// The [`RelayBuilder`] can be further customized before calling [`RelayBuilder::create()`].
+    fn blackboard<'a>(&self, static_config: &'a StaticConfig) -> Self::BlackboardBuilder<'a>
+    where
+        Self: 'a;
 }
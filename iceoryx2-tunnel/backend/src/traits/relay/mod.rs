@@ -10,10 +10,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+mod blackboard;
 mod event;
 mod factory;
 mod publish_subscribe;
+mod request_response;
 
+pub use blackboard::*;
 pub use event::*;
 pub use factory::*;
 pub use publish_subscribe::*;
+pub use request_response::*;
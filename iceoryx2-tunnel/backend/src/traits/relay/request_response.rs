@@ -0,0 +1,103 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::error::Error;
+
+use iceoryx2::service::Service;
+
+use crate::types::request_response::{
+    ActiveRequest, RemoteRequestId, RequestCompletionFn, RequestId, RequestLoanFn, RequestMut,
+    Response, ResponseLoanFn, ResponseMut,
+};
+
+pub trait RequestResponseRelay<S: Service> {
+    /// Error type returned when sending fails.
+    type SendError: Error;
+
+    /// Error type returned when receiving fails.
+    type ReceiveError: Error;
+
+    /// Sends a request of a local client to the servers on remote hosts.
+    ///
+    /// Transmits the payload of the [`ActiveRequest`] together with the
+    /// [`RequestId`] the tunnel assigned to it. The [`ActiveRequest`] remains
+    /// with the tunnel so that the responses can be routed back to it.
+    fn send_request(
+        &self,
+        request_id: RequestId,
+        request: &ActiveRequest<S>,
+    ) -> Result<(), Self::SendError>;
+
+    /// Attempts to receive a request of a client on a remote host.
+    ///
+    /// Checks for incoming requests without blocking. If a request is available,
+    /// it allocates shared memory via the provided loan function and copies the
+    /// request data into that memory.
+    ///
+    /// # Parameters
+    ///
+    /// * `loan` - Function to allocate shared memory of the requested size.
+    ///
+    /// # Returns
+    ///
+    /// * The [`RemoteRequestId`] that must be provided when the responses are
+    ///   sent back with [`RequestResponseRelay::send_response()`] together with
+    ///   the initialized [`RequestMut`]
+    /// * [`None`] when no requests are to be received
+    fn receive_request<LoanError>(
+        &self,
+        loan: &mut RequestLoanFn<'_, S, LoanError>,
+    ) -> Result<Option<(RemoteRequestId, RequestMut<S>)>, Self::ReceiveError>;
+
+    /// Sends a [`Response`] of a local server back to the host the request with
+    /// the [`RemoteRequestId`] originates from. The [`Response`] is consumed by
+    /// this operation.
+    fn send_response(
+        &self,
+        request_id: &RemoteRequestId,
+        response: Response<S>,
+    ) -> Result<(), Self::SendError>;
+
+    /// Signals the host the request with the [`RemoteRequestId`] originates from
+    /// that no further responses will be sent for it. Must be sent after the last
+    /// response of the request was sent with [`RequestResponseRelay::send_response()`].
+    fn send_request_completion(&self, request_id: &RemoteRequestId) -> Result<(), Self::SendError>;
+
+    /// Attempts to receive a response to a request that was sent with
+    /// [`RequestResponseRelay::send_request()`].
+    ///
+    /// Checks for incoming responses without blocking. If a response is available,
+    /// the loan function is called with the [`RequestId`] of the corresponding
+    /// request to allocate the response in shared memory. When the loan function
+    /// returns [`None`], the request is no longer pending and the response is
+    /// discarded. A request is sent to all remote hosts, therefore the completion
+    /// function is called with the [`RequestId`] of the request once every host
+    /// the request was sent to either signaled its completion with
+    /// [`RequestResponseRelay::send_request_completion()`] or is gone.
+    ///
+    /// # Parameters
+    ///
+    /// * `loan` - Function to allocate shared memory of the requested size for
+    ///   the request with the provided [`RequestId`].
+    /// * `complete` - Function to release the request with the provided
+    ///   [`RequestId`] since no further responses will be received for it.
+    ///
+    /// # Returns
+    ///
+    /// * [`ResponseMut`] - A response was successfully received and initialized
+    /// * [`None`] when no responses are to be received
+    fn receive_response<LoanError>(
+        &self,
+        loan: &mut ResponseLoanFn<'_, S, LoanError>,
+        complete: &mut RequestCompletionFn<'_>,
+    ) -> Result<Option<ResponseMut<S>>, Self::ReceiveError>;
+}
@@ -0,0 +1,35 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec::Vec;
+
+use iceoryx2::service::builder::CustomKeyMarker;
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+
+pub type Key = CustomKeyMarker;
+
+pub type Reader<S> = iceoryx2::port::reader::Reader<S, Key>;
+pub type Writer<S> = iceoryx2::port::writer::Writer<S, Key>;
+pub type EntryHandle<S> = iceoryx2::port::reader::__InternalEntryHandle<S>;
+pub type EntryHandleMut<S> = iceoryx2::port::writer::__InternalEntryHandleMut<S>;
+
+/// A single key-value pair of a blackboard in its raw representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The bytes of the key, the key type is defined in the
+    /// [`StaticConfig`](iceoryx2::service::static_config::blackboard::StaticConfig) of the service.
+    pub key: Vec<u8>,
+    /// The [`TypeDetail`] of the value.
+    pub value_type_details: TypeDetail,
+    /// The bytes of the value.
+    pub value: Vec<u8>,
+}
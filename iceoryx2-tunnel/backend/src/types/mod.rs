@@ -10,4 +10,6 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod blackboard;
 pub mod publish_subscribe;
+pub mod request_response;
@@ -0,0 +1,61 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::mem::MaybeUninit;
+
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::builder::CustomPayloadMarker;
+
+pub type Header = CustomHeaderMarker;
+pub type Payload = [CustomPayloadMarker];
+pub type PayloadUninit = [MaybeUninit<CustomPayloadMarker>];
+
+pub type Client<S> = iceoryx2::port::client::Client<S, Payload, Header, Payload, Header>;
+pub type Server<S> = iceoryx2::port::server::Server<S, Payload, Header, Payload, Header>;
+pub type RequestMut<S> = iceoryx2::request_mut::RequestMut<S, Payload, Header, Payload, Header>;
+pub type RequestMutUninit<S> =
+    iceoryx2::request_mut_uninit::RequestMutUninit<S, PayloadUninit, Header, Payload, Header>;
+pub type ActiveRequest<S> =
+    iceoryx2::active_request::ActiveRequest<S, Payload, Header, Payload, Header>;
+pub type PendingResponse<S> =
+    iceoryx2::pending_response::PendingResponse<S, Payload, Header, Payload, Header>;
+pub type Response<S> = iceoryx2::response::Response<S, Payload, Header>;
+pub type ResponseMut<S> = iceoryx2::response_mut::ResponseMut<S, Payload, Header>;
+pub type ResponseMutUninit<S> =
+    iceoryx2::response_mut_uninit::ResponseMutUninit<S, PayloadUninit, Header>;
+
+/// Identifies a request that was forwarded by the tunnel of the host it originates from.
+/// The id is assigned by the originating tunnel and is used to route the responses back to
+/// the [`ActiveRequest`] that represents the request on the originating host.
+pub type RequestId = u64;
+
+/// Identifies a request that was received from a remote host. It combines the
+/// backend-specific identifier of the originating host with the [`RequestId`] that the
+/// originating tunnel assigned to the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RemoteRequestId {
+    pub origin: u128,
+    pub request_id: RequestId,
+}
+
+pub type RequestLoanFn<'a, S, LoanError> =
+    dyn FnMut(usize) -> Result<RequestMutUninit<S>, LoanError> + 'a;
+
+/// Loans a response for the request with the provided [`RequestId`]. Returns [`None`] when
+/// the request is no longer pending on the originating host, in this case the response is
+/// discarded.
+pub type ResponseLoanFn<'a, S, LoanError> =
+    dyn FnMut(RequestId, usize) -> Result<Option<ResponseMutUninit<S>>, LoanError> + 'a;
+
+/// Releases the request with the provided [`RequestId`] after the host the request was
+/// sent to signaled that no further responses will be delivered for it.
+pub type RequestCompletionFn<'a> = dyn FnMut(RequestId) + 'a;
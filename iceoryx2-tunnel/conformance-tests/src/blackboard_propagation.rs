@@ -0,0 +1,461 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_conformance_test_macros::conformance_test_module;
+
+#[allow(clippy::module_inception)]
+#[conformance_test_module]
+pub mod blackboard_propagation {
+    use core::fmt::Debug;
+    use core::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_conformance_test_macros::conformance_test;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnel::Tunnel;
+    use iceoryx2_tunnel_backend::traits::{testing::Testing, Backend};
+
+    const MAX_ATTEMPTS: usize = 25;
+    const TIMEOUT: Duration = Duration::from_millis(250);
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, ZeroCopySend)]
+    #[repr(C)]
+    struct MyType {
+        id: u32,
+        value: f64,
+        active: bool,
+    }
+
+    fn generate_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "blackboard_relay_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn create_tunnel<S: Service, B: Backend<S> + Debug>(iceoryx_config: &Config) -> Tunnel<S, B> {
+        let backend_config = B::Config::default();
+        let tunnel_config = iceoryx2_tunnel::Config::default();
+        Tunnel::<S, B>::create(&tunnel_config, iceoryx_config, &backend_config).unwrap()
+    }
+
+    fn wait_for_remote_discovery<S: Service, B: Backend<S> + Debug, T: Testing>(
+        tunnel: &mut Tunnel<S, B>,
+    ) {
+        T::retry(
+            || {
+                tunnel.discover_over_backend().unwrap();
+                let service_discovered = tunnel.tunneled_services().len() == 1;
+                if service_discovered {
+                    return Ok(());
+                }
+                Err("Failed to discover remote services")
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+    }
+
+    #[conformance_test]
+    pub fn mirrors_blackboard_with_all_entries<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        // --- Host A ---
+        let iceoryx_config_a = generate_isolated_config();
+        let mut tunnel_a = create_tunnel::<S, B>(&iceoryx_config_a);
+
+        let node_a = NodeBuilder::new()
+            .config(&iceoryx_config_a)
+            .create::<S>()
+            .unwrap();
+        let service_a = node_a
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 7)
+            .add::<i32>(1, -13)
+            .add::<MyType>(
+                2,
+                MyType {
+                    id: 42,
+                    value: core::f64::consts::PI,
+                    active: true,
+                },
+            )
+            .create()
+            .unwrap();
+
+        tunnel_a.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+        assert_that!(tunnel_a.tunneled_services().contains(service_a.service_id()), eq true);
+
+        // --- Host B ---
+        let iceoryx_config_b = generate_isolated_config();
+        let mut tunnel_b = create_tunnel::<S, B>(&iceoryx_config_b);
+
+        wait_for_remote_discovery::<S, B, T>(&mut tunnel_b);
+        T::sync(service_a.service_id().as_str().to_string(), TIMEOUT);
+
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+
+        // === TEST ===
+        T::retry(
+            || match node_b
+                .service_builder(&service_name)
+                .blackboard_opener::<u64>()
+                .open()
+            {
+                Ok(_) => Ok(()),
+                Err(_) => {
+                    tunnel_a.propagate().unwrap();
+                    tunnel_b.propagate().unwrap();
+                    Err("Failed to open mirrored blackboard")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+
+        let reader_b = service_b.reader_builder().create().unwrap();
+        assert_that!(reader_b.entry::<u8>(&0).unwrap().get(), eq 7);
+        assert_that!(reader_b.entry::<i32>(&1).unwrap().get(), eq - 13);
+        assert_that!(reader_b.entry::<MyType>(&2).unwrap().get(), eq MyType {
+            id: 42,
+            value: core::f64::consts::PI,
+            active: true,
+        });
+        assert_that!(reader_b.entry::<u8>(&1), is_err);
+    }
+
+    #[conformance_test]
+    pub fn mirrors_blackboard_without_slice_entries<
+        S: Service,
+        B: Backend<S> + Debug,
+        T: Testing,
+    >() {
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        // --- Host A ---
+        let iceoryx_config_a = generate_isolated_config();
+        let mut tunnel_a = create_tunnel::<S, B>(&iceoryx_config_a);
+
+        let node_a = NodeBuilder::new()
+            .config(&iceoryx_config_a)
+            .create::<S>()
+            .unwrap();
+        let service_a = node_a
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 7)
+            .add_slice::<u8>(1, 8, b"abc")
+            .create()
+            .unwrap();
+
+        tunnel_a.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+
+        // --- Host B ---
+        let iceoryx_config_b = generate_isolated_config();
+        let mut tunnel_b = create_tunnel::<S, B>(&iceoryx_config_b);
+
+        wait_for_remote_discovery::<S, B, T>(&mut tunnel_b);
+        T::sync(service_a.service_id().as_str().to_string(), TIMEOUT);
+
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+
+        // === TEST ===
+        T::retry(
+            || match node_b
+                .service_builder(&service_name)
+                .blackboard_opener::<u64>()
+                .open()
+            {
+                Ok(_) => Ok(()),
+                Err(_) => {
+                    tunnel_a.propagate().unwrap();
+                    tunnel_b.propagate().unwrap();
+                    Err("Failed to open mirrored blackboard")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+
+        let reader_b = service_b.reader_builder().create().unwrap();
+        assert_that!(reader_b.entry::<u64>(&0).unwrap().get(), eq 7);
+        assert_that!(reader_b.entry_slice::<u8>(&1), is_err);
+    }
+
+    fn propagate_updates<S: Service, B: Backend<S> + Debug, T: Testing>(num: usize) {
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        // --- Host A ---
+        let iceoryx_config_a = generate_isolated_config();
+        let mut tunnel_a = create_tunnel::<S, B>(&iceoryx_config_a);
+
+        let node_a = NodeBuilder::new()
+            .config(&iceoryx_config_a)
+            .create::<S>()
+            .unwrap();
+        let service_a = node_a
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_with_default::<u64>(0)
+            .add_with_default::<MyType>(1)
+            .create()
+            .unwrap();
+        let writer_a = service_a.writer_builder().create().unwrap();
+        let counter_a = writer_a.entry::<u64>(&0).unwrap();
+        let my_type_a = writer_a.entry::<MyType>(&1).unwrap();
+
+        tunnel_a.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+
+        // --- Host B ---
+        let iceoryx_config_b = generate_isolated_config();
+        let mut tunnel_b = create_tunnel::<S, B>(&iceoryx_config_b);
+
+        wait_for_remote_discovery::<S, B, T>(&mut tunnel_b);
+        T::sync(service_a.service_id().as_str().to_string(), TIMEOUT);
+
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+        T::retry(
+            || match node_b
+                .service_builder(&service_name)
+                .blackboard_opener::<u64>()
+                .open()
+            {
+                Ok(_) => Ok(()),
+                Err(_) => {
+                    tunnel_a.propagate().unwrap();
+                    tunnel_b.propagate().unwrap();
+                    Err("Failed to open mirrored blackboard")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let reader_b = service_b.reader_builder().create().unwrap();
+        let counter_b = reader_b.entry::<u64>(&0).unwrap();
+        let my_type_b = reader_b.entry::<MyType>(&1).unwrap();
+
+        // === TEST ===
+        for i in 0..num {
+            let my_type = MyType {
+                id: i as u32,
+                value: core::f64::consts::E * i as f64,
+                active: i % 2 == 0,
+            };
+            counter_a.update_with_copy(i as u64 + 1);
+            my_type_a.update_with_copy(my_type);
+
+            T::retry(
+                || {
+                    if counter_b.get() == i as u64 + 1 && my_type_b.get() == my_type {
+                        Ok(())
+                    } else {
+                        tunnel_a.propagate().unwrap();
+                        tunnel_b.propagate().unwrap();
+                        Err("Failed to receive expected update")
+                    }
+                },
+                TIMEOUT,
+                Some(MAX_ATTEMPTS),
+            )
+            .unwrap();
+        }
+    }
+
+    #[conformance_test]
+    pub fn propagates_update<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        propagate_updates::<S, B, T>(1);
+    }
+
+    #[conformance_test]
+    pub fn propagates_update_many<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        propagate_updates::<S, B, T>(10);
+    }
+
+    #[conformance_test]
+    pub fn propagates_keys_added_after_discovery<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        // --- Host A ---
+        let iceoryx_config_a = generate_isolated_config();
+        let mut tunnel_a = create_tunnel::<S, B>(&iceoryx_config_a);
+
+        let node_a = NodeBuilder::new()
+            .config(&iceoryx_config_a)
+            .create::<S>()
+            .unwrap();
+        let service_a = node_a
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 3)
+            .reserve_keys(2, core::mem::size_of::<MyType>())
+            .create()
+            .unwrap();
+        let writer_a = service_a.writer_builder().create().unwrap();
+
+        tunnel_a.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+
+        // --- Host B ---
+        let iceoryx_config_b = generate_isolated_config();
+        let mut tunnel_b = create_tunnel::<S, B>(&iceoryx_config_b);
+
+        wait_for_remote_discovery::<S, B, T>(&mut tunnel_b);
+        T::sync(service_a.service_id().as_str().to_string(), TIMEOUT);
+
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+        T::retry(
+            || match node_b
+                .service_builder(&service_name)
+                .blackboard_opener::<u64>()
+                .open()
+            {
+                Ok(_) => Ok(()),
+                Err(_) => {
+                    tunnel_a.propagate().unwrap();
+                    tunnel_b.propagate().unwrap();
+                    Err("Failed to open mirrored blackboard")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let reader_b = service_b.reader_builder().create().unwrap();
+
+        // === TEST ===
+        let my_type = MyType {
+            id: 9,
+            value: core::f64::consts::E,
+            active: true,
+        };
+        writer_a.add::<MyType>(1, my_type).unwrap();
+
+        T::retry(
+            || match reader_b.entry::<MyType>(&1) {
+                Ok(entry) if entry.get() == my_type => Ok(()),
+                _ => {
+                    tunnel_a.propagate().unwrap();
+                    tunnel_b.propagate().unwrap();
+                    Err("Failed to receive added key")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        assert_that!(reader_b.entry::<u64>(&0).unwrap().get(), eq 3);
+
+        let my_type_a = writer_a.entry::<MyType>(&1).unwrap();
+        let updated = MyType { id: 10, ..my_type };
+        my_type_a.update_with_copy(updated);
+
+        let my_type_b = reader_b.entry::<MyType>(&1).unwrap();
+        T::retry(
+            || {
+                if my_type_b.get() == updated {
+                    Ok(())
+                } else {
+                    tunnel_a.propagate().unwrap();
+                    tunnel_b.propagate().unwrap();
+                    Err("Failed to receive update of added key")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+    }
+
+    #[conformance_test]
+    pub fn mirrored_blackboard_is_not_propagated_back<
+        S: Service,
+        B: Backend<S> + Debug,
+        T: Testing,
+    >() {
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        let iceoryx_config = generate_isolated_config();
+        let mut tunnel = create_tunnel::<S, B>(&iceoryx_config);
+
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        tunnel.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel.tunneled_services().len(), eq 1);
+        assert_that!(tunnel.tunneled_services().contains(service.service_id()), eq true);
+
+        // === TEST ===
+        // The tunnel only reads from the local blackboard, the writer remains
+        // available for the application.
+        tunnel.propagate().unwrap();
+        let writer = service.writer_builder().create();
+        assert_that!(writer, is_ok);
+    }
+}
@@ -10,7 +10,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod blackboard_discovery;
+pub mod blackboard_propagation;
 pub mod event_discovery;
 pub mod event_propagation;
 pub mod publish_subscribe_discovery;
 pub mod publish_subscribe_propagation;
+pub mod request_response_discovery;
+pub mod request_response_propagation;
+pub mod service_filter;
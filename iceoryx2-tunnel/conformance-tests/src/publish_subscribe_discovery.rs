@@ -70,6 +70,7 @@ pub mod publish_subscribe_discovery {
 
         let tunnel_config = iceoryx2_tunnel::Config {
             discovery_service: Some("iox2://discovery/services/".into()),
+            ..Default::default()
         };
         let mut tunnel =
             Tunnel::<S, B>::create(&tunnel_config, &iceoryx_config, &B::Config::default()).unwrap();
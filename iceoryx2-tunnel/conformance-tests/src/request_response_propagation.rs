@@ -0,0 +1,634 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_conformance_test_macros::conformance_test_module;
+
+#[allow(clippy::module_inception)]
+#[conformance_test_module]
+pub mod request_response_propagation {
+    use core::fmt::Debug;
+    use core::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_conformance_test_macros::conformance_test;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::test_fail;
+    use iceoryx2_tunnel::Tunnel;
+    use iceoryx2_tunnel_backend::traits::{testing::Testing, Backend};
+
+    fn generate_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "request_response_relay_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn propagate_struct_requests_and_responses<S: Service, B: Backend<S> + Debug, T: Testing>(
+        num: usize,
+    ) {
+        const MAX_ATTEMPTS: usize = 25;
+        const TIMEOUT: Duration = Duration::from_millis(250);
+
+        #[derive(Debug, Clone, PartialEq, ZeroCopySend)]
+        #[repr(C)]
+        struct MyRequest {
+            id: u32,
+            value: f64,
+        }
+
+        #[derive(Debug, Clone, PartialEq, ZeroCopySend)]
+        #[repr(C)]
+        struct MyResponse {
+            id: u32,
+            active: bool,
+        }
+
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        // --- Host A ---
+        let iceoryx_config_a = generate_isolated_config();
+        let backend_config_a = B::Config::default();
+        let tunnel_config_a = iceoryx2_tunnel::Config::default();
+        let mut tunnel_a =
+            Tunnel::<S, B>::create(&tunnel_config_a, &iceoryx_config_a, &backend_config_a).unwrap();
+
+        let node_a = NodeBuilder::new()
+            .config(&iceoryx_config_a)
+            .create::<S>()
+            .unwrap();
+        let service_a = node_a
+            .service_builder(&service_name)
+            .request_response::<MyRequest, MyResponse>()
+            .open_or_create()
+            .unwrap();
+        let client_a = service_a.client_builder().create().unwrap();
+
+        tunnel_a.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+        assert_that!(tunnel_a.tunneled_services().contains(service_a.service_id()), eq true);
+
+        // --- Host B ---
+        let iceoryx_config_b = generate_isolated_config();
+        let backend_config_b = B::Config::default();
+        let tunnel_config_b = iceoryx2_tunnel::Config::default();
+        let mut tunnel_b =
+            Tunnel::<S, B>::create(&tunnel_config_b, &iceoryx_config_b, &backend_config_b).unwrap();
+
+        // Wait for tunnel on host b to discover the service on host A
+        T::retry(
+            || {
+                tunnel_b.discover_over_backend().unwrap();
+                let service_discovered = tunnel_b.tunneled_services().len() == 1;
+                if service_discovered {
+                    return Ok(());
+                }
+                Err("Failed to discover remote services")
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        T::sync(service_a.service_id().as_str().to_string(), TIMEOUT);
+
+        // Create a server to connect to the tunneled service
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .request_response::<MyRequest, MyResponse>()
+            .open_or_create()
+            .unwrap();
+        let server_b = service_b.server_builder().create().unwrap();
+
+        // === TEST ===
+        for i in 0..num {
+            // Send request
+            let request_data = MyRequest {
+                id: 42 + i as u32,
+                value: core::f64::consts::PI + i as f64,
+            };
+            let response_data = MyResponse {
+                id: 73 + i as u32,
+                active: i % 2 == 0,
+            };
+
+            let pending_response_a = client_a.send_copy(request_data.clone()).unwrap();
+
+            // Propagate request over tunnels and respond
+            T::retry(
+                || match server_b.receive().unwrap() {
+                    Some(active_request_b) => {
+                        if *active_request_b.payload() == request_data {
+                            active_request_b.send_copy(response_data.clone()).unwrap();
+                            Ok(())
+                        } else {
+                            Err("received unexpected request")
+                        }
+                    }
+                    None => {
+                        tunnel_a.propagate().unwrap();
+                        tunnel_b.propagate().unwrap();
+                        Err("Failed to receive expected request")
+                    }
+                },
+                TIMEOUT,
+                Some(MAX_ATTEMPTS),
+            )
+            .unwrap();
+
+            // Propagate response back over tunnels
+            T::retry(
+                || match pending_response_a.receive().unwrap() {
+                    Some(response_received_at_a) => {
+                        if *response_received_at_a.payload() == response_data {
+                            Ok(())
+                        } else {
+                            Err("received unexpected response")
+                        }
+                    }
+                    None => {
+                        tunnel_b.propagate().unwrap();
+                        tunnel_a.propagate().unwrap();
+                        Err("Failed to receive expected response")
+                    }
+                },
+                TIMEOUT,
+                Some(MAX_ATTEMPTS),
+            )
+            .unwrap();
+        }
+    }
+
+    fn propagate_slice_requests_and_responses<S: Service, B: Backend<S> + Debug, T: Testing>(
+        num: usize,
+    ) {
+        const MAX_ATTEMPTS: usize = 25;
+        const TIMEOUT: Duration = Duration::from_millis(250);
+        const PAYLOAD_DATA_LENGTH: usize = 256;
+
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        // --- Host A ---
+        let iceoryx_config_a = generate_isolated_config();
+        let backend_config_a = B::Config::default();
+        let tunnel_config_a = iceoryx2_tunnel::Config::default();
+        let mut tunnel_a =
+            Tunnel::<S, B>::create(&tunnel_config_a, &iceoryx_config_a, &backend_config_a).unwrap();
+
+        let node_a = NodeBuilder::new()
+            .config(&iceoryx_config_a)
+            .create::<S>()
+            .unwrap();
+        let service_a = node_a
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .open_or_create()
+            .unwrap();
+        let client_a = service_a
+            .client_builder()
+            .initial_max_slice_len(PAYLOAD_DATA_LENGTH)
+            .create()
+            .unwrap();
+
+        tunnel_a.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+        assert_that!(tunnel_a.tunneled_services().contains(service_a.service_id()), eq true);
+
+        // --- Host B ---
+        let iceoryx_config_b = generate_isolated_config();
+        let backend_config_b = B::Config::default();
+        let tunnel_config_b = iceoryx2_tunnel::Config::default();
+        let mut tunnel_b =
+            Tunnel::<S, B>::create(&tunnel_config_b, &iceoryx_config_b, &backend_config_b).unwrap();
+
+        // Wait for tunnel on host b to discover the service on host A
+        T::retry(
+            || {
+                tunnel_b.discover_over_backend().unwrap();
+                let service_discovered = tunnel_b.tunneled_services().len() == 1;
+                if service_discovered {
+                    return Ok(());
+                }
+                Err("Failed to discover remote services")
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        T::sync(service_a.service_id().as_str().to_string(), TIMEOUT);
+
+        // Create a server to connect to the tunneled service
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .open_or_create()
+            .unwrap();
+        let server_b = service_b
+            .server_builder()
+            .initial_max_slice_len(PAYLOAD_DATA_LENGTH)
+            .create()
+            .unwrap();
+
+        // === TEST ===
+        for i in 0..num {
+            let mut request_data = String::with_capacity(PAYLOAD_DATA_LENGTH);
+            let mut response_data = String::with_capacity(PAYLOAD_DATA_LENGTH);
+            for j in 0..PAYLOAD_DATA_LENGTH {
+                let char_index = ((i * 7 + j * 13) % 26) as u8;
+                request_data.push((b'A' + char_index) as char);
+                response_data.push((b'a' + char_index) as char);
+            }
+
+            // Send request
+            let request = client_a.loan_slice_uninit(PAYLOAD_DATA_LENGTH).unwrap();
+            let request = request.write_from_slice(request_data.as_bytes());
+            let pending_response_a = request.send().unwrap();
+
+            // Propagate request over tunnels and respond
+            T::retry(
+                || match server_b.receive().unwrap() {
+                    Some(active_request_b) => {
+                        if *active_request_b.payload() == *request_data.as_bytes() {
+                            let response = active_request_b
+                                .loan_slice_uninit(PAYLOAD_DATA_LENGTH)
+                                .unwrap();
+                            let response = response.write_from_slice(response_data.as_bytes());
+                            response.send().unwrap();
+                            Ok(())
+                        } else {
+                            Err("received unexpected request")
+                        }
+                    }
+                    None => {
+                        tunnel_a.propagate().unwrap();
+                        tunnel_b.propagate().unwrap();
+                        Err("failed to receive expected request")
+                    }
+                },
+                TIMEOUT,
+                Some(MAX_ATTEMPTS),
+            )
+            .unwrap();
+
+            // Propagate response back over tunnels
+            T::retry(
+                || match pending_response_a.receive().unwrap() {
+                    Some(response_received_at_a) => {
+                        if *response_received_at_a.payload() == *response_data.as_bytes() {
+                            Ok(())
+                        } else {
+                            Err("received unexpected response")
+                        }
+                    }
+                    None => {
+                        tunnel_b.propagate().unwrap();
+                        tunnel_a.propagate().unwrap();
+                        Err("failed to receive expected response")
+                    }
+                },
+                TIMEOUT,
+                Some(MAX_ATTEMPTS),
+            )
+            .unwrap();
+        }
+    }
+
+    #[conformance_test]
+    pub fn propagates_struct_request_and_response<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        propagate_struct_requests_and_responses::<S, B, T>(1);
+    }
+
+    #[conformance_test]
+    pub fn propagates_struct_request_and_response_many<
+        S: Service,
+        B: Backend<S> + Debug,
+        T: Testing,
+    >() {
+        propagate_struct_requests_and_responses::<S, B, T>(10);
+    }
+
+    #[conformance_test]
+    pub fn propagates_slice_request_and_response<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        propagate_slice_requests_and_responses::<S, B, T>(1);
+    }
+
+    #[conformance_test]
+    pub fn propagates_slice_request_and_response_many<
+        S: Service,
+        B: Backend<S> + Debug,
+        T: Testing,
+    >() {
+        propagate_slice_requests_and_responses::<S, B, T>(10);
+    }
+
+    #[conformance_test]
+    pub fn dropping_remote_active_request_disconnects_pending_response<
+        S: Service,
+        B: Backend<S> + Debug,
+        T: Testing,
+    >() {
+        const MAX_ATTEMPTS: usize = 25;
+        const TIMEOUT: Duration = Duration::from_millis(250);
+
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        // --- Host A ---
+        let iceoryx_config_a = generate_isolated_config();
+        let backend_config_a = B::Config::default();
+        let tunnel_config_a = iceoryx2_tunnel::Config::default();
+        let mut tunnel_a =
+            Tunnel::<S, B>::create(&tunnel_config_a, &iceoryx_config_a, &backend_config_a).unwrap();
+
+        let node_a = NodeBuilder::new()
+            .config(&iceoryx_config_a)
+            .create::<S>()
+            .unwrap();
+        let service_a = node_a
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let client_a = service_a.client_builder().create().unwrap();
+
+        tunnel_a.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+
+        // --- Host B ---
+        let iceoryx_config_b = generate_isolated_config();
+        let backend_config_b = B::Config::default();
+        let tunnel_config_b = iceoryx2_tunnel::Config::default();
+        let mut tunnel_b =
+            Tunnel::<S, B>::create(&tunnel_config_b, &iceoryx_config_b, &backend_config_b).unwrap();
+
+        T::retry(
+            || {
+                tunnel_b.discover_over_backend().unwrap();
+                let service_discovered = tunnel_b.tunneled_services().len() == 1;
+                if service_discovered {
+                    return Ok(());
+                }
+                Err("Failed to discover remote services")
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        T::sync(service_a.service_id().as_str().to_string(), TIMEOUT);
+
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let server_b = service_b.server_builder().create().unwrap();
+
+        // === TEST ===
+        let pending_response_a = client_a.send_copy(1234).unwrap();
+
+        // Propagate request over tunnels, respond and drop the active request
+        T::retry(
+            || match server_b.receive().unwrap() {
+                Some(active_request_b) => {
+                    active_request_b.send_copy(5678).unwrap();
+                    Ok(())
+                }
+                None => {
+                    tunnel_a.propagate().unwrap();
+                    tunnel_b.propagate().unwrap();
+                    Err("Failed to receive expected request")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+
+        // The response is delivered and afterwards the request is released on host A
+        let mut received_response = false;
+        T::retry(
+            || {
+                tunnel_b.propagate().unwrap();
+                tunnel_a.propagate().unwrap();
+
+                if let Some(response) = pending_response_a.receive().unwrap() {
+                    received_response = *response.payload() == 5678;
+                }
+
+                if pending_response_a.is_connected() {
+                    Err("Pending response is still connected")
+                } else {
+                    Ok(())
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+
+        while let Some(response) = pending_response_a.receive().unwrap() {
+            received_response = *response.payload() == 5678;
+        }
+        assert_that!(received_response, eq true);
+    }
+
+    #[conformance_test]
+    pub fn completion_of_host_without_server_does_not_discard_responses_of_other_hosts<
+        S: Service,
+        B: Backend<S> + Debug,
+        T: Testing,
+    >() {
+        const MAX_ATTEMPTS: usize = 25;
+        const TIMEOUT: Duration = Duration::from_millis(250);
+
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        // --- Host A ---
+        let iceoryx_config_a = generate_isolated_config();
+        let backend_config_a = B::Config::default();
+        let tunnel_config_a = iceoryx2_tunnel::Config::default();
+        let mut tunnel_a =
+            Tunnel::<S, B>::create(&tunnel_config_a, &iceoryx_config_a, &backend_config_a).unwrap();
+
+        let node_a = NodeBuilder::new()
+            .config(&iceoryx_config_a)
+            .create::<S>()
+            .unwrap();
+        let service_a = node_a
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let client_a = service_a.client_builder().create().unwrap();
+
+        tunnel_a.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+
+        // --- Host B with a server and host C without a server ---
+        let iceoryx_config_b = generate_isolated_config();
+        let backend_config_b = B::Config::default();
+        let tunnel_config_b = iceoryx2_tunnel::Config::default();
+        let mut tunnel_b =
+            Tunnel::<S, B>::create(&tunnel_config_b, &iceoryx_config_b, &backend_config_b).unwrap();
+
+        let iceoryx_config_c = generate_isolated_config();
+        let backend_config_c = B::Config::default();
+        let tunnel_config_c = iceoryx2_tunnel::Config::default();
+        let mut tunnel_c =
+            Tunnel::<S, B>::create(&tunnel_config_c, &iceoryx_config_c, &backend_config_c).unwrap();
+
+        T::retry(
+            || {
+                tunnel_b.discover_over_backend().unwrap();
+                tunnel_c.discover_over_backend().unwrap();
+                let service_discovered = tunnel_b.tunneled_services().len() == 1
+                    && tunnel_c.tunneled_services().len() == 1;
+                if service_discovered {
+                    return Ok(());
+                }
+                Err("Failed to discover remote services")
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+        T::sync(service_a.service_id().as_str().to_string(), TIMEOUT);
+
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let server_b = service_b.server_builder().create().unwrap();
+
+        // === TEST ===
+        let pending_response_a = client_a.send_copy(1234).unwrap();
+
+        // Propagate the request to host B and keep it there without responding
+        let mut active_request_b = None;
+        T::retry(
+            || match server_b.receive().unwrap() {
+                Some(active_request) => {
+                    active_request_b = Some(active_request);
+                    Ok(())
+                }
+                None => {
+                    tunnel_a.propagate().unwrap();
+                    tunnel_c.propagate().unwrap();
+                    tunnel_b.propagate().unwrap();
+                    Err("Failed to receive expected request")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+
+        // Host C has no server and completes the request right away
+        for _ in 0..4 {
+            tunnel_c.propagate().unwrap();
+            tunnel_a.propagate().unwrap();
+            std::thread::sleep(TIMEOUT);
+        }
+        tunnel_a.propagate().unwrap();
+        assert_that!(pending_response_a.is_connected(), eq true);
+
+        // The response of host B is still delivered
+        active_request_b.take().unwrap().send_copy(5678).unwrap();
+        T::retry(
+            || match pending_response_a.receive().unwrap() {
+                Some(response) => {
+                    if *response.payload() == 5678 {
+                        Ok(())
+                    } else {
+                        Err("received unexpected response")
+                    }
+                }
+                None => {
+                    tunnel_b.propagate().unwrap();
+                    tunnel_a.propagate().unwrap();
+                    Err("Failed to receive expected response")
+                }
+            },
+            TIMEOUT,
+            Some(MAX_ATTEMPTS),
+        )
+        .unwrap();
+    }
+
+    #[conformance_test]
+    pub fn propagated_requests_do_not_loop_back<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        // === SETUP ===
+        let service_name = generate_service_name();
+
+        let backend_config = B::Config::default();
+        let iceoryx_config = generate_isolated_config();
+        let tunnel_config = iceoryx2_tunnel::Config::default();
+        let mut tunnel =
+            Tunnel::<S, B>::create(&tunnel_config, &iceoryx_config, &backend_config).unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        // Discover
+        tunnel.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel.tunneled_services().len(), eq 1);
+        assert_that!(tunnel.tunneled_services().contains(service.service_id()), eq true);
+
+        // ==================== TEST =====================
+
+        let _pending_response = client.send_copy(1234).unwrap();
+
+        // Receive - Request should be received from local client
+        while let Ok(Some(_)) = server.receive() {}
+
+        // Propagate
+        tunnel.propagate().unwrap();
+
+        // Receive - Request should not loop back and be received again
+        if server.receive().unwrap().is_some() {
+            test_fail!("request looped back")
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_conformance_test_macros::conformance_test_module;
+
+#[allow(clippy::module_inception)]
+#[conformance_test_module]
+pub mod service_filter {
+
+    use core::fmt::Debug;
+    use core::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::attribute::{AttributeKey, AttributeValue};
+    use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::testing::*;
+
+    use iceoryx2_bb_conformance_test_macros::conformance_test;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnel::{ServiceFilter, ServiceRule, Tunnel};
+    use iceoryx2_tunnel_backend::traits::testing::Testing;
+    use iceoryx2_tunnel_backend::traits::Backend;
+
+    // TODO: Move to iceoryx2::testing
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+    fn generate_service_name(prefix: &str) -> ServiceName {
+        ServiceName::new(&format!(
+            "service_filter_tests/{}/{}",
+            prefix,
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[conformance_test]
+    pub fn excluded_services_are_not_tunneled<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        // === SETUP ==
+        let iceoryx_config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+        let public_service = node
+            .service_builder(&generate_service_name("public"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let internal_service = node
+            .service_builder(&generate_service_name("internal"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let tunnel_config = iceoryx2_tunnel::Config {
+            filter: ServiceFilter::new()
+                .exclude(ServiceRule::new().service_name("service_filter_tests/internal/*")),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S, B>::create(&tunnel_config, &iceoryx_config, &B::Config::default()).unwrap();
+
+        // === TEST ===
+        tunnel.discover_over_iceoryx().unwrap();
+
+        assert_that!(tunnel.tunneled_services().len(), eq 1);
+        assert_that!(tunnel.tunneled_services().contains(public_service.service_id()), eq true);
+        assert_that!(tunnel.tunneled_services().contains(internal_service.service_id()), eq false);
+    }
+
+    #[conformance_test]
+    pub fn only_included_services_are_tunneled<S: Service, B: Backend<S> + Debug, T: Testing>() {
+        // === SETUP ==
+        let iceoryx_config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<S>()
+            .unwrap();
+        let attribute_key = AttributeKey::new(b"tunnel").unwrap();
+        let attribute_value = AttributeValue::new(b"yes").unwrap();
+        let pubsub_service = node
+            .service_builder(&generate_service_name("pubsub"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let attributed_event_service = node
+            .service_builder(&generate_service_name("event"))
+            .event()
+            .create_with_attributes(
+                &AttributeSpecifier::new()
+                    .define(&attribute_key, &attribute_value)
+                    .unwrap(),
+            )
+            .unwrap();
+        let event_service = node
+            .service_builder(&generate_service_name("event"))
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let tunnel_config = iceoryx2_tunnel::Config {
+            filter: ServiceFilter::new()
+                .include(ServiceRule::new().messaging_pattern(MessagingPattern::PublishSubscribe))
+                .include(
+                    ServiceRule::new().attributes(
+                        &AttributeVerifier::new()
+                            .require(&attribute_key, &attribute_value)
+                            .unwrap(),
+                    ),
+                ),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S, B>::create(&tunnel_config, &iceoryx_config, &B::Config::default()).unwrap();
+
+        // === TEST ===
+        tunnel.discover_over_iceoryx().unwrap();
+
+        assert_that!(tunnel.tunneled_services().len(), eq 2);
+        assert_that!(tunnel.tunneled_services().contains(pubsub_service.service_id()), eq true);
+        assert_that!(tunnel.tunneled_services().contains(attributed_event_service.service_id()), eq true);
+        assert_that!(tunnel.tunneled_services().contains(event_service.service_id()), eq false);
+    }
+
+    #[conformance_test]
+    pub fn excluded_remote_services_are_not_tunneled<
+        S: Service,
+        B: Backend<S> + Debug,
+        T: Testing,
+    >() {
+        // === SETUP ===
+        let public_service_name = generate_service_name("public");
+        let internal_service_name = generate_service_name("internal");
+
+        // Host A
+        let iceoryx_config_a = generate_isolated_config();
+        let tunnel_config_a = iceoryx2_tunnel::Config {
+            filter: ServiceFilter::new()
+                .exclude(ServiceRule::new().service_name("service_filter_tests/internal/*")),
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S, B>::create(&tunnel_config_a, &iceoryx_config_a, &B::Config::default())
+                .unwrap();
+
+        // Host B
+        let iceoryx_config_b = generate_isolated_config();
+        let tunnel_config_b = iceoryx2_tunnel::Config::default();
+        let mut tunnel_b =
+            Tunnel::<S, B>::create(&tunnel_config_b, &iceoryx_config_b, &B::Config::default())
+                .unwrap();
+
+        let node_b = NodeBuilder::new()
+            .config(&iceoryx_config_b)
+            .create::<S>()
+            .unwrap();
+        let public_service_b = node_b
+            .service_builder(&public_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let internal_service_b = node_b
+            .service_builder(&internal_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // === TEST ===
+        tunnel_b.discover_over_iceoryx().unwrap();
+        assert_that!(tunnel_b.tunneled_services().len(), eq 2);
+
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+        const MAX_RETRIES: usize = 5;
+        T::retry(
+            || {
+                tunnel_a.discover_over_backend().unwrap();
+
+                if tunnel_a
+                    .tunneled_services()
+                    .contains(public_service_b.service_id())
+                {
+                    return Ok(());
+                }
+                Err("Failed to discover remote services")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        )
+        .unwrap();
+
+        // the internal service is announced as well but must not be tunneled
+        assert_that!(tunnel_a.tunneled_services().len(), eq 1);
+        assert_that!(tunnel_a.tunneled_services().contains(internal_service_b.service_id()), eq false);
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use iceoryx2::service::attribute::{AttributeSet, AttributeVerifier};
+use iceoryx2::service::messaging_pattern::MessagingPattern;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as StaticMessagingPattern;
+use iceoryx2::service::static_config::StaticConfig;
+
+/// Selects services by their name, messaging pattern and attributes. Every
+/// criterion that is not set matches all services.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceRule {
+    service_name: Option<String>,
+    messaging_pattern: Option<MessagingPattern>,
+    attributes: Option<AttributeVerifier>,
+}
+
+impl ServiceRule {
+    /// Creates a [`ServiceRule`] that matches all services.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches only services whose name matches the glob pattern. `*` matches
+    /// any sequence of characters and `?` matches exactly one character.
+    pub fn service_name(mut self, pattern: &str) -> Self {
+        self.service_name = Some(pattern.into());
+        self
+    }
+
+    /// Matches only services with the provided [`MessagingPattern`].
+    pub fn messaging_pattern(mut self, messaging_pattern: MessagingPattern) -> Self {
+        self.messaging_pattern = Some(messaging_pattern);
+        self
+    }
+
+    /// Matches only services that define all required attributes of the
+    /// [`AttributeVerifier`].
+    pub fn attributes(mut self, attributes: &AttributeVerifier) -> Self {
+        self.attributes = Some(attributes.clone());
+        self
+    }
+
+    /// Returns `true` when the service described by the [`StaticConfig`]
+    /// satisfies all criteria of the rule.
+    pub fn matches(&self, static_config: &StaticConfig) -> bool {
+        if let Some(pattern) = &self.service_name {
+            if !glob_match(pattern, static_config.name().as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(messaging_pattern) = &self.messaging_pattern {
+            if Some(*messaging_pattern) != kind_of(static_config.messaging_pattern()) {
+                return false;
+            }
+        }
+
+        if let Some(attributes) = &self.attributes {
+            if !has_attributes(attributes, static_config.attributes()) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Decides which services are tunneled.
+///
+/// A service is tunneled when it matches at least one include rule and no
+/// exclude rule. Without include rules all services are included.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceFilter {
+    include: Vec<ServiceRule>,
+    exclude: Vec<ServiceRule>,
+}
+
+impl ServiceFilter {
+    /// Creates a [`ServiceFilter`] that allows all services.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule that selects services to be tunneled.
+    pub fn include(mut self, rule: ServiceRule) -> Self {
+        self.include.push(rule);
+        self
+    }
+
+    /// Adds a rule that selects services that must not be tunneled, even when
+    /// they are included.
+    pub fn exclude(mut self, rule: ServiceRule) -> Self {
+        self.exclude.push(rule);
+        self
+    }
+
+    /// Returns the include rules.
+    pub fn include_rules(&self) -> &[ServiceRule] {
+        &self.include
+    }
+
+    /// Returns the exclude rules.
+    pub fn exclude_rules(&self) -> &[ServiceRule] {
+        &self.exclude
+    }
+
+    /// Returns `true` when the service described by the [`StaticConfig`] shall
+    /// be tunneled.
+    pub fn is_allowed(&self, static_config: &StaticConfig) -> bool {
+        let is_included =
+            self.include.is_empty() || self.include.iter().any(|rule| rule.matches(static_config));
+
+        is_included && !self.exclude.iter().any(|rule| rule.matches(static_config))
+    }
+}
+
+fn kind_of(messaging_pattern: &StaticMessagingPattern) -> Option<MessagingPattern> {
+    match messaging_pattern {
+        StaticMessagingPattern::PublishSubscribe(_) => Some(MessagingPattern::PublishSubscribe),
+        StaticMessagingPattern::Event(_) => Some(MessagingPattern::Event),
+        StaticMessagingPattern::RequestResponse(_) => Some(MessagingPattern::RequestResponse),
+        StaticMessagingPattern::Blackboard(_) => Some(MessagingPattern::Blackboard),
+        _ => None,
+    }
+}
+
+// The AttributeVerifier is not used directly since it logs every mismatch.
+fn has_attributes(required: &AttributeVerifier, attributes: &AttributeSet) -> bool {
+    required.required_attributes().iter().all(|required| {
+        attributes
+            .iter()
+            .any(|attr| attr.key() == required.key() && attr.value() == required.value())
+    }) && required
+        .required_keys()
+        .iter()
+        .all(|key| attributes.iter().any(|attr| attr.key() == key))
+}
+
+// Matches on chars so that '?' always stands for exactly one character.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // position of the last '*' in the pattern and the text position it matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
@@ -13,7 +13,9 @@
 extern crate alloc;
 
 mod discovery;
+mod filter;
 mod ports;
 mod tunnel;
 
+pub use filter::*;
 pub use tunnel::*;
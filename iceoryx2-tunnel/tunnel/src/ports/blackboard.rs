@@ -0,0 +1,540 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2::constants::MAX_BLACKBOARD_KEY_SIZE;
+use iceoryx2::node::Node;
+use iceoryx2::prelude::CallbackProgression;
+use iceoryx2::service::builder::blackboard::{BlackboardOpenError, KeyMemory};
+use iceoryx2::service::port_factory::blackboard::PortFactory;
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use iceoryx2::service::{static_config::StaticConfig, Service};
+use iceoryx2_bb_log::{fail, trace, warn};
+use iceoryx2_tunnel_backend::types::blackboard::{
+    Entry, EntryHandle, EntryHandleMut, Key, Reader, Writer,
+};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Service,
+    Reader,
+    EntryHandle,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    EntriesIngestion,
+    InvalidEntries,
+    MirrorCreation,
+    Writer,
+    EntryInsertion,
+    EntryHandleMut,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    EntriesPropagation,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+struct OriginEntry<S: Service> {
+    key: Vec<u8>,
+    value_type_details: TypeDetail,
+    handle: EntryHandle<S>,
+}
+
+impl<S: Service> core::fmt::Debug for OriginEntry<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "OriginEntry {{ key: {:?}, value_type_details: {:?} }}",
+            self.key, self.value_type_details
+        )
+    }
+}
+
+struct MirrorEntry<S: Service> {
+    key: Vec<u8>,
+    value_type_details: TypeDetail,
+    handle: EntryHandleMut<S>,
+}
+
+impl<S: Service> core::fmt::Debug for MirrorEntry<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MirrorEntry {{ key: {:?}, value_type_details: {:?} }}",
+            self.key, self.value_type_details
+        )
+    }
+}
+
+#[derive(Debug)]
+enum Role<S: Service> {
+    // The blackboard exists on this host, updates of its writer are propagated
+    // to the backend.
+    Origin {
+        service: PortFactory<S, Key>,
+        reader: Reader<S>,
+        entries: Vec<OriginEntry<S>>,
+        // Slice entries are not relayed, their keys are remembered to warn
+        // only once.
+        unsupported_keys: Vec<Vec<u8>>,
+        last_propagated: Vec<Entry>,
+    },
+    // The blackboard exists on a remote host, it is created locally as soon as
+    // its entries are received over the backend.
+    Mirror {
+        writer: Option<Writer<S>>,
+        entries: Vec<MirrorEntry<S>>,
+    },
+}
+
+#[derive(Debug)]
+pub(crate) struct BlackboardPorts<S: Service> {
+    pub(crate) static_config: StaticConfig,
+    role: Role<S>,
+}
+
+fn key_eq_func(key_size: usize) -> Box<dyn Fn(*const u8, *const u8) -> bool> {
+    Box::new(move |lhs, rhs| {
+        KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::key_eq_comparison(lhs, rhs, &|lhs, rhs| unsafe {
+            core::slice::from_raw_parts(lhs, key_size) == core::slice::from_raw_parts(rhs, key_size)
+        })
+    })
+}
+
+impl<S: Service> BlackboardPorts<S> {
+    pub(crate) fn new(static_config: &StaticConfig, node: &Node<S>) -> Result<Self, CreationError> {
+        let origin = format!("BlackboardPorts<{}>::new", core::any::type_name::<S>());
+
+        let key_type_details = static_config.blackboard().type_details();
+        let service = unsafe {
+            node.service_builder(static_config.name())
+                .blackboard_opener::<Key>()
+                .__internal_set_key_type_details(key_type_details)
+                .__internal_set_key_eq_cmp_func(key_eq_func(key_type_details.size()))
+                .open()
+        };
+
+        let service = match service {
+            Ok(service) => service,
+            Err(BlackboardOpenError::DoesNotExist) => {
+                return Ok(BlackboardPorts {
+                    static_config: static_config.clone(),
+                    role: Role::Mirror {
+                        writer: None,
+                        entries: Vec::new(),
+                    },
+                });
+            }
+            Err(e) => {
+                fail!(from origin, with CreationError::Service,
+                    "Failed to open service {}({}) ({:?})",
+                    static_config.messaging_pattern(), static_config.name(), e);
+            }
+        };
+
+        let reader = fail!(
+            from origin,
+            when service.reader_builder().create(),
+            with CreationError::Reader,
+            "Failed to create Reader for {}({})", static_config.messaging_pattern(), static_config.name()
+        );
+
+        let mut entries = Vec::new();
+        let mut unsupported_keys = Vec::new();
+        fail!(
+            from origin,
+            when Self::add_new_origin_entries(&service, &reader, key_type_details.size(), &mut entries, &mut unsupported_keys),
+            "Failed to create entry handles for {}({})", static_config.messaging_pattern(), static_config.name()
+        );
+
+        Ok(BlackboardPorts {
+            static_config: static_config.clone(),
+            role: Role::Origin {
+                service,
+                reader,
+                entries,
+                unsupported_keys,
+                last_propagated: Vec::new(),
+            },
+        })
+    }
+
+    // Creates the entry handles of all keys of the blackboard that are not yet
+    // contained in `entries`. Keys can be added to the blackboard at runtime,
+    // therefore this is called before every propagation.
+    fn add_new_origin_entries(
+        service: &PortFactory<S, Key>,
+        reader: &Reader<S>,
+        key_size: usize,
+        entries: &mut Vec<OriginEntry<S>>,
+        unsupported_keys: &mut Vec<Vec<u8>>,
+    ) -> Result<(), CreationError> {
+        let mut new_keys = Vec::new();
+        unsafe {
+            service.__internal_list_entries(|key, value_type_details| {
+                let key = core::slice::from_raw_parts(key, key_size);
+                if !entries.iter().any(|e| e.key == key)
+                    && !unsupported_keys.iter().any(|k| k == key)
+                {
+                    new_keys.push((key.to_vec(), value_type_details.clone()));
+                }
+                CallbackProgression::Continue
+            })
+        };
+
+        for (key, value_type_details) in new_keys {
+            if value_type_details.variant() == TypeVariant::Dynamic {
+                warn!(from "BlackboardPorts::add_new_origin_entries",
+                    "Slice entries are not relayed, the entry of key {:?} is ignored", key);
+                unsupported_keys.push(key);
+                continue;
+            }
+
+            let handle = fail!(
+                from "BlackboardPorts::add_new_origin_entries",
+                when unsafe { reader.__internal_entry(key.as_ptr(), &value_type_details) },
+                with CreationError::EntryHandle,
+                "Failed to create entry handle"
+            );
+            entries.push(OriginEntry {
+                key,
+                value_type_details,
+                handle,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn create_mirror(
+        &self,
+        node: &Node<S>,
+        received: &[Entry],
+    ) -> Result<(Writer<S>, Vec<MirrorEntry<S>>), SendError> {
+        let blackboard_config = self.static_config.blackboard();
+        let key_type_details = blackboard_config.type_details();
+
+        let is_valid = |entry: &Entry| {
+            entry.key.len() == key_type_details.size()
+                && entry.value_type_details.variant() == TypeVariant::FixedSize
+                && entry.value.len() == entry.value_type_details.size()
+        };
+        if !received.iter().all(is_valid) {
+            fail!(from self, with SendError::InvalidEntries,
+                "Failed to create mirror of {}({}) since the received entries do not match their type details",
+                self.static_config.messaging_pattern(), self.static_config.name());
+        }
+
+        // The values are copied from the buffers during creation, they must
+        // outlive the builder.
+        let mut values: Vec<Vec<u8>> = received.iter().map(|e| e.value.clone()).collect();
+
+        let mut builder = unsafe {
+            node.service_builder(self.static_config.name())
+                .blackboard_creator::<Key>()
+                .__internal_set_key_type_details(key_type_details)
+                .__internal_set_key_eq_cmp_func(key_eq_func(key_type_details.size()))
+                .max_readers(blackboard_config.max_readers())
+                .max_nodes(blackboard_config.max_nodes())
+                .reserve_keys(
+                    blackboard_config.number_of_reserved_keys(),
+                    blackboard_config.max_reserved_value_size(),
+                )
+        };
+        for (entry, value) in received.iter().zip(values.iter_mut()) {
+            builder = unsafe {
+                builder.__internal_add(
+                    entry.key.as_ptr(),
+                    value.as_mut_ptr(),
+                    entry.value_type_details.clone(),
+                    Box::new(|| {}),
+                )
+            };
+        }
+
+        let service = fail!(
+            from self,
+            when builder.create(),
+            with SendError::MirrorCreation,
+            "Failed to create mirror of {}({})", self.static_config.messaging_pattern(), self.static_config.name()
+        );
+
+        let writer = fail!(
+            from self,
+            when service.writer_builder().create(),
+            with SendError::Writer,
+            "Failed to create Writer for {}({})", self.static_config.messaging_pattern(), self.static_config.name()
+        );
+
+        let mut entries = Vec::with_capacity(received.len());
+        for entry in received {
+            let handle = fail!(
+                from self,
+                when unsafe { writer.__internal_entry(entry.key.as_ptr(), &entry.value_type_details) },
+                with SendError::EntryHandleMut,
+                "Failed to create entry handle for {}({})", self.static_config.messaging_pattern(), self.static_config.name()
+            );
+            entries.push(MirrorEntry {
+                key: entry.key.clone(),
+                value_type_details: entry.value_type_details.clone(),
+                handle,
+            });
+        }
+
+        Ok((writer, entries))
+    }
+
+    // Adds a key that was added to the blackboard on the remote host after the
+    // mirror was created.
+    fn add_mirror_entry(writer: &Writer<S>, update: &Entry) -> Result<MirrorEntry<S>, SendError> {
+        let origin = "BlackboardPorts::add_mirror_entry";
+
+        fail!(
+            from origin,
+            when unsafe {
+                writer.__internal_add(
+                    update.key.as_ptr(),
+                    update.value.as_ptr(),
+                    &update.value_type_details,
+                )
+            },
+            with SendError::EntryInsertion,
+            "Failed to add new key to the mirror"
+        );
+
+        let handle = fail!(
+            from origin,
+            when unsafe { writer.__internal_entry(update.key.as_ptr(), &update.value_type_details) },
+            with SendError::EntryHandleMut,
+            "Failed to create entry handle for new key"
+        );
+
+        Ok(MirrorEntry {
+            key: update.key.clone(),
+            value_type_details: update.value_type_details.clone(),
+            handle,
+        })
+    }
+
+    pub(crate) fn send<IngestFn, IngestError>(
+        &mut self,
+        node: &Node<S>,
+        mut ingest: IngestFn,
+    ) -> Result<bool, SendError>
+    where
+        IngestFn: FnMut() -> Result<Option<Vec<Entry>>, IngestError>,
+    {
+        let received = fail!(
+            from self,
+            when ingest(),
+            with SendError::EntriesIngestion,
+            "Failed to ingest blackboard entries from backend"
+        );
+
+        let received = match received {
+            Some(received) => received,
+            None => return Ok(false),
+        };
+
+        if let Role::Mirror {
+            writer: None,
+            entries: _,
+        } = &self.role
+        {
+            let (writer, entries) = self.create_mirror(node, &received)?;
+            self.role = Role::Mirror {
+                writer: Some(writer),
+                entries,
+            };
+        }
+
+        match &mut self.role {
+            Role::Origin { .. } => {
+                warn!(from self,
+                    "Ignoring entries received from backend since the blackboard {}({}) is written on this host",
+                    self.static_config.messaging_pattern(), self.static_config.name());
+                Ok(false)
+            }
+            Role::Mirror { writer, entries } => {
+                trace!(
+                    from "BlackboardPorts::send",
+                    "Updating {}({})",
+                    self.static_config.messaging_pattern(),
+                    self.static_config.name()
+                );
+
+                for update in &received {
+                    let size = update.value_type_details.size();
+                    if update.value_type_details.variant() != TypeVariant::FixedSize
+                        || update.value.len() != size
+                    {
+                        warn!(from "BlackboardPorts::send", "Ignoring update of blackboard entry with unsupported value type or invalid value size");
+                        continue;
+                    }
+
+                    let position = entries.iter().position(|e| e.key == update.key);
+                    let entry = match position {
+                        Some(position)
+                            if entries[position].value_type_details
+                                == update.value_type_details =>
+                        {
+                            &entries[position]
+                        }
+                        Some(_) => {
+                            warn!(from "BlackboardPorts::send", "Ignoring update of blackboard entry with a different value type");
+                            continue;
+                        }
+                        None => {
+                            // The key was added to the blackboard on the remote host after the
+                            // mirror was created.
+                            let writer = match writer {
+                                Some(writer) => writer,
+                                None => continue,
+                            };
+                            if update.key.len()
+                                != self.static_config.blackboard().type_details().size()
+                            {
+                                warn!(from "BlackboardPorts::send", "Ignoring update of blackboard entry with invalid key size");
+                                continue;
+                            }
+                            match Self::add_mirror_entry(writer, update) {
+                                Ok(entry) => {
+                                    entries.push(entry);
+                                    &entries[entries.len() - 1]
+                                }
+                                Err(_) => {
+                                    warn!(from "BlackboardPorts::send", "Ignoring update of a blackboard entry that could not be added to the mirror");
+                                    continue;
+                                }
+                            }
+                        }
+                    };
+
+                    unsafe {
+                        let write_cell = entry.handle.__internal_get_ptr_to_write_cell(
+                            size,
+                            entry.value_type_details.alignment(),
+                        );
+                        core::ptr::copy_nonoverlapping(update.value.as_ptr(), write_cell, size);
+                        entry.handle.__internal_update_write_cell();
+                    }
+                }
+
+                Ok(true)
+            }
+        }
+    }
+
+    pub(crate) fn receive<PropagateFn, E>(
+        &mut self,
+        mut propagate: PropagateFn,
+    ) -> Result<bool, ReceiveError>
+    where
+        PropagateFn: FnMut(&[Entry]) -> Result<(), E>,
+    {
+        let (entries, last_propagated) = match &mut self.role {
+            Role::Origin {
+                service,
+                reader,
+                entries,
+                unsupported_keys,
+                last_propagated,
+            } => {
+                if Self::add_new_origin_entries(
+                    service,
+                    reader,
+                    self.static_config.blackboard().type_details().size(),
+                    entries,
+                    unsupported_keys,
+                )
+                .is_err()
+                {
+                    warn!(from "BlackboardPorts::receive",
+                        "Unable to create entry handles for keys that were added to {}({})",
+                        self.static_config.messaging_pattern(), self.static_config.name());
+                }
+                (entries, last_propagated)
+            }
+            Role::Mirror { .. } => return Ok(false),
+        };
+
+        let current: Vec<Entry> = entries
+            .iter()
+            .map(|entry| {
+                let mut value = vec![0u8; entry.value_type_details.size()];
+                unsafe {
+                    entry.handle.get(
+                        value.as_mut_ptr(),
+                        entry.value_type_details.size(),
+                        entry.value_type_details.alignment(),
+                    )
+                };
+                Entry {
+                    key: entry.key.clone(),
+                    value_type_details: entry.value_type_details.clone(),
+                    value,
+                }
+            })
+            .collect();
+
+        if current == *last_propagated {
+            return Ok(false);
+        }
+
+        trace!(
+            from "BlackboardPorts::receive",
+            "Received update of {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        fail!(
+            from "BlackboardPorts::receive",
+            when propagate(&current),
+            with ReceiveError::EntriesPropagation,
+            "Failed to propagate blackboard entries"
+        );
+        *last_propagated = current;
+
+        Ok(true)
+    }
+}
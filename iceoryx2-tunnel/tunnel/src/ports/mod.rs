@@ -10,5 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub(crate) mod blackboard;
 pub(crate) mod event;
 pub(crate) mod publish_subscribe;
+pub(crate) mod request_response;
@@ -0,0 +1,436 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::vec::Vec;
+
+use iceoryx2::node::Node;
+use iceoryx2::port::LoanError;
+use iceoryx2::prelude::AllocationStrategy;
+use iceoryx2::service::{static_config::StaticConfig, Service};
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_tunnel_backend::types::request_response::{
+    ActiveRequest, Client, Header, Payload, PendingResponse, RemoteRequestId, RequestCompletionFn,
+    RequestId, RequestLoanFn, RequestMut, Response, ResponseLoanFn, ResponseMut, Server,
+};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Service,
+    Client,
+    Server,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    RequestIngestion,
+    RequestDelivery,
+    ResponseIngestion,
+    ResponseDelivery,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    RequestReceive,
+    RequestPropagation,
+    ResponseReceive,
+    ResponsePropagation,
+    RequestCompletionPropagation,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+#[derive(Debug)]
+pub(crate) struct RequestResponsePorts<S: Service> {
+    pub(crate) static_config: StaticConfig,
+    pub(crate) client: Client<S>,
+    pub(crate) server: Server<S>,
+    next_request_id: RequestId,
+    // Requests of local clients that were propagated to the backend, the
+    // responses received over the backend are sent via these.
+    active_requests: BTreeMap<RequestId, ActiveRequest<S>>,
+    // Requests ingested from the backend, the responses of local servers are
+    // received via these.
+    pending_responses: BTreeMap<RemoteRequestId, PendingResponse<S>>,
+}
+
+impl<S: Service> RequestResponsePorts<S> {
+    pub(crate) fn new(static_config: &StaticConfig, node: &Node<S>) -> Result<Self, CreationError> {
+        let origin = format!("RequestResponsePorts<{}>::new", core::any::type_name::<S>());
+
+        let port_config = static_config.request_response();
+        let service = unsafe {
+            fail!(
+                from origin,
+                when node.service_builder(static_config.name())
+                        .request_response::<Payload, Payload>()
+                        .request_user_header::<Header>()
+                        .response_user_header::<Header>()
+                        .__internal_set_request_payload_type_details(
+                            &port_config.request_message_type_details().payload,
+                        )
+                        .__internal_set_request_header_type_details(
+                            &port_config.request_message_type_details().user_header,
+                        )
+                        .__internal_set_response_payload_type_details(
+                            &port_config.response_message_type_details().payload,
+                        )
+                        .__internal_set_response_header_type_details(
+                            &port_config.response_message_type_details().user_header,
+                        )
+                        .enable_safe_overflow_for_requests(port_config.has_safe_overflow_for_requests())
+                        .enable_safe_overflow_for_responses(port_config.has_safe_overflow_for_responses())
+                        .enable_fire_and_forget_requests(port_config.does_support_fire_and_forget_requests())
+                        .max_active_requests_per_client(port_config.max_active_requests_per_client())
+                        .max_loaned_requests(port_config.max_loaned_requests())
+                        .max_response_buffer_size(port_config.max_response_buffer_size())
+                        .max_borrowed_responses_per_pending_response(
+                            port_config.max_borrowed_responses_per_pending_response(),
+                        )
+                        .max_servers(port_config.max_servers())
+                        .max_clients(port_config.max_clients())
+                        .max_nodes(port_config.max_nodes())
+                        .open_or_create(),
+                with CreationError::Service,
+                "Failed to open or create service {}({})", static_config.messaging_pattern(), static_config.name()
+            )
+        };
+
+        let client = fail!(
+            from origin,
+            when service
+                .client_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create(),
+            with CreationError::Client,
+            "Failed to create Client for {}({})", static_config.messaging_pattern(), static_config.name()
+        );
+
+        let server = fail!(
+            from origin,
+            when service
+                .server_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create(),
+            with CreationError::Server,
+            "Failed to create Server for {}({})", static_config.messaging_pattern(), static_config.name()
+        );
+
+        Ok(RequestResponsePorts {
+            static_config: static_config.clone(),
+            client,
+            server,
+            next_request_id: 0,
+            active_requests: BTreeMap::new(),
+            pending_responses: BTreeMap::new(),
+        })
+    }
+
+    pub(crate) fn send_requests<IngestFn, IngestError>(
+        &mut self,
+        mut ingest: IngestFn,
+    ) -> Result<bool, SendError>
+    where
+        IngestFn: for<'a> FnMut(
+            &'a mut RequestLoanFn<'a, S, LoanError>,
+        )
+            -> Result<Option<(RemoteRequestId, RequestMut<S>)>, IngestError>,
+    {
+        let mut ingested = false;
+
+        let payload_size = self
+            .static_config
+            .request_response()
+            .request_message_type_details()
+            .payload
+            .size();
+
+        loop {
+            let client = &self.client;
+            let request = ingest(&mut |number_of_bytes| {
+                let number_of_elements = number_of_bytes / payload_size;
+
+                let request = unsafe { client.loan_custom_payload(number_of_elements) };
+                let request = fail!(
+                    from "RequestResponsePorts::send_requests",
+                    when request,
+                    "Failed to loan custom payload for request ingestion from backend"
+                );
+
+                Ok(request)
+            });
+
+            let request = fail!(
+                from self,
+                when request,
+                with SendError::RequestIngestion,
+                "Failed to ingest request from backend"
+            );
+
+            match request {
+                Some((remote_request_id, request)) => {
+                    trace!(
+                        from self,
+                        "Sending request {:?} of {}({})",
+                        remote_request_id,
+                        self.static_config.messaging_pattern(),
+                        self.static_config.name()
+                    );
+
+                    let pending_response = fail!(
+                        from self,
+                        when request.send(),
+                        with SendError::RequestDelivery,
+                        "Failed to send ingested request"
+                    );
+                    self.pending_responses
+                        .insert(remote_request_id, pending_response);
+
+                    ingested = true;
+                }
+                None => break,
+            }
+        }
+
+        Ok(ingested)
+    }
+
+    pub(crate) fn receive_requests<PropagateFn, E>(
+        &mut self,
+        mut propagate: PropagateFn,
+    ) -> Result<bool, ReceiveError>
+    where
+        PropagateFn: FnMut(RequestId, &ActiveRequest<S>) -> Result<(), E>,
+    {
+        let mut propagated = false;
+
+        loop {
+            let request = unsafe { self.server.receive_custom_payload() };
+            let request = fail!(
+                from self,
+                when request,
+                with ReceiveError::RequestReceive,
+                "Failed to receive custom payload request to propagate to backend"
+            );
+
+            match request {
+                Some(request) => {
+                    trace!(
+                        from self,
+                        "Received request of {}({})",
+                        self.static_config.messaging_pattern(),
+                        self.static_config.name()
+                    );
+
+                    if request.origin() == self.client.id() {
+                        // Ignore requests sent by the tunnel itself to avoid loopback.
+                        continue;
+                    }
+
+                    let request_id = self.next_request_id;
+                    self.next_request_id = self.next_request_id.wrapping_add(1);
+
+                    fail!(
+                        from self,
+                        when propagate(request_id, &request),
+                        with ReceiveError::RequestPropagation,
+                        "Failed to propagate request"
+                    );
+                    self.active_requests.insert(request_id, request);
+
+                    propagated = true;
+                }
+                None => break,
+            }
+        }
+
+        Ok(propagated)
+    }
+
+    pub(crate) fn send_responses<IngestFn, IngestError>(
+        &mut self,
+        mut ingest: IngestFn,
+    ) -> Result<bool, SendError>
+    where
+        IngestFn: for<'a> FnMut(
+            &'a mut ResponseLoanFn<'a, S, LoanError>,
+            &'a mut RequestCompletionFn<'a>,
+        ) -> Result<Option<ResponseMut<S>>, IngestError>,
+    {
+        let mut ingested = false;
+        let mut completed_requests = Vec::new();
+
+        let payload_size = self
+            .static_config
+            .request_response()
+            .response_message_type_details()
+            .payload
+            .size();
+
+        loop {
+            let active_requests = &self.active_requests;
+            let response = ingest(
+                &mut |request_id, number_of_bytes| {
+                    let active_request = match active_requests.get(&request_id) {
+                        Some(active_request) => active_request,
+                        None => return Ok(None),
+                    };
+
+                    let number_of_elements = number_of_bytes / payload_size;
+                    let response =
+                        unsafe { active_request.loan_custom_payload(number_of_elements) };
+                    let response = fail!(
+                        from "RequestResponsePorts::send_responses",
+                        when response,
+                        "Failed to loan custom payload for response ingestion from backend"
+                    );
+
+                    Ok(Some(response))
+                },
+                &mut |request_id| completed_requests.push(request_id),
+            );
+
+            let response = fail!(
+                from self,
+                when response,
+                with SendError::ResponseIngestion,
+                "Failed to ingest response from backend"
+            );
+
+            match response {
+                Some(response) => {
+                    trace!(
+                        from self,
+                        "Sending response of {}({})",
+                        self.static_config.messaging_pattern(),
+                        self.static_config.name()
+                    );
+
+                    fail!(
+                        from self,
+                        when response.send(),
+                        with SendError::ResponseDelivery,
+                        "Failed to send ingested response"
+                    );
+
+                    ingested = true;
+                }
+                None => break,
+            }
+        }
+
+        // Requests that were completed on the remote host or whose clients are
+        // gone will not receive any further responses.
+        for request_id in completed_requests {
+            self.active_requests.remove(&request_id);
+        }
+        self.active_requests
+            .retain(|_, active_request| active_request.is_connected());
+
+        Ok(ingested)
+    }
+
+    pub(crate) fn receive_responses<PropagateFn, CompleteFn, E>(
+        &mut self,
+        mut propagate: PropagateFn,
+        mut complete: CompleteFn,
+    ) -> Result<bool, ReceiveError>
+    where
+        PropagateFn: FnMut(&RemoteRequestId, Response<S>) -> Result<(), E>,
+        CompleteFn: FnMut(&RemoteRequestId) -> Result<(), E>,
+    {
+        let mut propagated = false;
+
+        for (remote_request_id, pending_response) in &self.pending_responses {
+            loop {
+                let response = unsafe { pending_response.receive_custom_payload() };
+                let response = fail!(
+                    from self,
+                    when response,
+                    with ReceiveError::ResponseReceive,
+                    "Failed to receive custom payload response to propagate to backend"
+                );
+
+                match response {
+                    Some(response) => {
+                        trace!(
+                            from self,
+                            "Received response to {:?} of {}({})",
+                            remote_request_id,
+                            self.static_config.messaging_pattern(),
+                            self.static_config.name()
+                        );
+
+                        fail!(
+                            from self,
+                            when propagate(remote_request_id, response),
+                            with ReceiveError::ResponsePropagation,
+                            "Failed to propagate response"
+                        );
+
+                        propagated = true;
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        // Requests that are no longer processed by any server or that received
+        // the final responses of all servers will not receive any further
+        // responses, the originating host is informed so that it can release them.
+        let completed_requests: Vec<RemoteRequestId> = self
+            .pending_responses
+            .iter()
+            .filter(|(_, pending_response)| {
+                !pending_response.has_response()
+                    && (!pending_response.is_connected() || pending_response.is_finished())
+            })
+            .map(|(remote_request_id, _)| *remote_request_id)
+            .collect();
+
+        for remote_request_id in completed_requests {
+            self.pending_responses.remove(&remote_request_id);
+
+            fail!(
+                from self,
+                when complete(&remote_request_id),
+                with ReceiveError::RequestCompletionPropagation,
+                "Failed to propagate completion of request {:?}", remote_request_id
+            );
+        }
+
+        Ok(propagated)
+    }
+}
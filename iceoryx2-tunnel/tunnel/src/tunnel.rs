@@ -24,13 +24,20 @@ use iceoryx2::service::static_config::StaticConfig;
 use iceoryx2::service::Service;
 use iceoryx2_bb_log::{fail, info, trace, warn};
 use iceoryx2_tunnel_backend::traits::{
-    Backend, Discovery, EventRelay, PublishSubscribeRelay, RelayBuilder, RelayFactory,
+    Backend, BlackboardRelay, Discovery, EventRelay, PublishSubscribeRelay, RelayBuilder,
+    RelayFactory, RequestResponseRelay,
 };
 use iceoryx2_tunnel_backend::types::publish_subscribe::LoanFn;
+use iceoryx2_tunnel_backend::types::request_response::{
+    RequestCompletionFn, RequestLoanFn, ResponseLoanFn,
+};
 
 use crate::discovery;
+use crate::filter::ServiceFilter;
+use crate::ports::blackboard::BlackboardPorts;
 use crate::ports::event::EventPorts;
 use crate::ports::publish_subscribe::PublishSubscribePorts;
+use crate::ports::request_response::RequestResponsePorts;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum CreationError {
@@ -57,6 +64,10 @@ pub enum DiscoveryError {
     PublishSubscribeRelayCreation,
     EventPortsCreation,
     EventRelayCreation,
+    RequestResponsePortsCreation,
+    RequestResponseRelayCreation,
+    BlackboardPortsCreation,
+    BlackboardRelayCreation,
     DiscoveryAnnouncement,
 }
 
@@ -74,6 +85,12 @@ pub enum PropagateError {
     PayloadIngestion,
     EventPropagation,
     EventIngestion,
+    RequestPropagation,
+    RequestIngestion,
+    ResponsePropagation,
+    ResponseIngestion,
+    BlackboardPropagation,
+    BlackboardIngestion,
 }
 
 impl core::fmt::Display for PropagateError {
@@ -88,6 +105,8 @@ impl core::error::Error for PropagateError {}
 pub(crate) struct Ports<S: Service> {
     pub(crate) publish_subscribe: BTreeMap<ServiceId, PublishSubscribePorts<S>>,
     pub(crate) event: BTreeMap<ServiceId, EventPorts<S>>,
+    pub(crate) request_response: BTreeMap<ServiceId, RequestResponsePorts<S>>,
+    pub(crate) blackboard: BTreeMap<ServiceId, BlackboardPorts<S>>,
 }
 
 impl<S: Service> Ports<S> {
@@ -95,14 +114,25 @@ impl<S: Service> Ports<S> {
         Self {
             publish_subscribe: BTreeMap::new(),
             event: BTreeMap::new(),
+            request_response: BTreeMap::new(),
+            blackboard: BTreeMap::new(),
         }
     }
+
+    fn contains(&self, service_id: &ServiceId) -> bool {
+        self.publish_subscribe.contains_key(service_id)
+            || self.event.contains_key(service_id)
+            || self.request_response.contains_key(service_id)
+            || self.blackboard.contains_key(service_id)
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct Relays<S: Service, B: Backend<S>> {
     publish_subscribe: BTreeMap<ServiceId, B::PublishSubscribeRelay>,
     event: BTreeMap<ServiceId, B::EventRelay>,
+    request_response: BTreeMap<ServiceId, B::RequestResponseRelay>,
+    blackboard: BTreeMap<ServiceId, B::BlackboardRelay>,
 }
 
 impl<S: Service, B: Backend<S>> Relays<S, B> {
@@ -110,6 +140,8 @@ impl<S: Service, B: Backend<S>> Relays<S, B> {
         Self {
             publish_subscribe: BTreeMap::new(),
             event: BTreeMap::new(),
+            request_response: BTreeMap::new(),
+            blackboard: BTreeMap::new(),
         }
     }
 }
@@ -117,6 +149,8 @@ impl<S: Service, B: Backend<S>> Relays<S, B> {
 #[derive(Debug, Default)]
 pub struct Config {
     pub discovery_service: Option<String>,
+    /// Restricts the services that are tunneled. All services are tunneled by default.
+    pub filter: ServiceFilter,
 }
 
 #[derive(Debug)]
@@ -127,6 +161,7 @@ pub struct Tunnel<S: Service, B: for<'a> Backend<S> + Debug> {
     relays: Relays<S, B>,
     subscriber: Option<discovery::subscriber::DiscoverySubscriber<S>>,
     tracker: Option<discovery::tracker::DiscoveryTracker<S>>,
+    filter: ServiceFilter,
 }
 
 impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
@@ -194,6 +229,7 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
             relays: Relays::new(),
             subscriber,
             tracker,
+            filter: tunnel_config.filter.clone(),
         })
     }
 
@@ -210,7 +246,7 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
             fail!(
                 from self,
                 when subscriber.discover(|static_config| {
-                    on_discovery(static_config, &self.node, &self.backend, &tunneled_services, &mut self.ports, &mut self.relays)
+                    on_discovery(static_config, &self.node, &self.backend, &self.filter, &tunneled_services, &mut self.ports, &mut self.relays)
                 }),
                 with DiscoveryError::DiscoveryOverService,
                 "Failed to discover services via subscriber to discovery service"
@@ -220,7 +256,7 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
             fail!(
                 from self,
                 when tracker.discover(|static_config| {
-                    on_discovery(static_config, &self.node, &self.backend, &tunneled_services, &mut self.ports, &mut self.relays)
+                    on_discovery(static_config, &self.node, &self.backend, &self.filter, &tunneled_services, &mut self.ports, &mut self.relays)
                 }),
                 with DiscoveryError::DiscoveryOverTracker,
                 "Failed to discover services via discovery tracker"
@@ -235,7 +271,7 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
         fail!(
             from self,
             when self.backend.discovery().discover(|static_config| {
-                on_discovery(static_config, &self.node, &self.backend, &tunneled_services, &mut self.ports, &mut self.relays)
+                on_discovery(static_config, &self.node, &self.backend, &self.filter, &tunneled_services, &mut self.ports, &mut self.relays)
             }),
             with DiscoveryError::DiscoveryOverBackend,
             "Failed to discover services via Backend"
@@ -268,6 +304,30 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
             };
         }
 
+        for (service_id, port) in &mut self.ports.request_response {
+            match self.relays.request_response.get(service_id) {
+                Some(relay) => {
+                    propagate_requests_and_responses::<S, B>(port, relay)?;
+                }
+                None => {
+                    warn!(from "Tunnel::propagate", "No relay available for {:?}", service_id);
+                    return Ok(());
+                }
+            };
+        }
+
+        for (service_id, port) in &mut self.ports.blackboard {
+            match self.relays.blackboard.get(service_id) {
+                Some(relay) => {
+                    propagate_blackboard_entries::<S, B>(&self.node, port, relay)?;
+                }
+                None => {
+                    warn!(from "Tunnel::propagate", "No relay available for {:?}", service_id);
+                    return Ok(());
+                }
+            };
+        }
+
         Ok(())
     }
 
@@ -276,6 +336,8 @@ impl<S: Service, B: for<'a> Backend<S> + Debug> Tunnel<S, B> {
             .publish_subscribe
             .keys()
             .chain(self.ports.event.keys())
+            .chain(self.ports.request_response.keys())
+            .chain(self.ports.blackboard.keys())
             .cloned()
             .collect()
     }
@@ -285,6 +347,7 @@ fn on_discovery<S: Service, B: Backend<S> + Debug>(
     static_config: &StaticConfig,
     node: &Node<S>,
     backend: &B,
+    filter: &ServiceFilter,
     services: &BTreeSet<ServiceId>,
     ports: &mut Ports<S>,
     relays: &mut Relays<S, B>,
@@ -295,11 +358,23 @@ fn on_discovery<S: Service, B: Backend<S> + Debug>(
         core::any::type_name::<B>()
     );
 
-    if services.contains(static_config.service_id()) {
+    // A service can be discovered multiple times in one discovery run when it
+    // is announced by several hosts.
+    if services.contains(static_config.service_id()) || ports.contains(static_config.service_id()) {
         // Nothing to do.
         return Ok(());
     }
 
+    if !filter.is_allowed(static_config) {
+        trace!(
+            from origin,
+            "Filtered {}({})",
+            static_config.messaging_pattern(),
+            static_config.name()
+        );
+        return Ok(());
+    }
+
     info!(
         from origin,
         "Discovered {}({})",
@@ -312,6 +387,12 @@ fn on_discovery<S: Service, B: Backend<S> + Debug>(
             setup_publish_subscribe(static_config, node, backend, ports, relays)
         }
         MessagingPattern::Event(_) => setup_event(static_config, node, backend, ports, relays),
+        MessagingPattern::RequestResponse(_) => {
+            setup_request_response(static_config, node, backend, ports, relays)
+        }
+        MessagingPattern::Blackboard(_) => {
+            setup_blackboard(static_config, node, backend, ports, relays)
+        }
         _ => {
             // Not supported. Nothing to do.
             info!(
@@ -413,6 +494,94 @@ fn setup_event<S: Service, B: Backend<S> + Debug>(
     Ok(())
 }
 
+fn setup_request_response<S: Service, B: Backend<S> + Debug>(
+    static_config: &StaticConfig,
+    node: &Node<S>,
+    backend: &B,
+    ports: &mut Ports<S>,
+    relays: &mut Relays<S, B>,
+) -> Result<(), DiscoveryError> {
+    let origin = format!(
+        "Tunnel<{}, {}>::setup_request_response()",
+        core::any::type_name::<S>(),
+        core::any::type_name::<B>()
+    );
+
+    let service_id = static_config.service_id();
+
+    let port = fail!(
+        from origin,
+        when RequestResponsePorts::new(static_config, node),
+        with DiscoveryError::RequestResponsePortsCreation,
+        "Failed to create request-response ports"
+    );
+    ports.request_response.insert(*service_id, port);
+
+    let relay = fail!(
+        from origin,
+        when backend
+            .relay_builder()
+            .request_response(static_config)
+            .create(),
+        with DiscoveryError::RequestResponseRelayCreation,
+        "Failed to create request-response relay"
+    );
+    relays.request_response.insert(*service_id, relay);
+
+    fail!(
+        from origin,
+        when backend.discovery().announce(static_config),
+        with DiscoveryError::DiscoveryAnnouncement,
+        "Failed to announce service over backend"
+    );
+
+    Ok(())
+}
+
+fn setup_blackboard<S: Service, B: Backend<S> + Debug>(
+    static_config: &StaticConfig,
+    node: &Node<S>,
+    backend: &B,
+    ports: &mut Ports<S>,
+    relays: &mut Relays<S, B>,
+) -> Result<(), DiscoveryError> {
+    let origin = format!(
+        "Tunnel<{}, {}>::setup_blackboard()",
+        core::any::type_name::<S>(),
+        core::any::type_name::<B>()
+    );
+
+    let service_id = static_config.service_id();
+
+    let port = fail!(
+        from origin,
+        when BlackboardPorts::new(static_config, node),
+        with DiscoveryError::BlackboardPortsCreation,
+        "Failed to create blackboard ports"
+    );
+    ports.blackboard.insert(*service_id, port);
+
+    let relay = fail!(
+        from origin,
+        when backend
+            .relay_builder()
+            .blackboard(static_config)
+            .create(),
+        with DiscoveryError::BlackboardRelayCreation,
+        "Failed to create blackboard relay"
+    );
+    relays.blackboard.insert(*service_id, relay);
+
+    fail!(
+        from origin,
+        when backend.discovery().announce(static_config),
+        with DiscoveryError::DiscoveryAnnouncement,
+        "Failed to announce service over backend"
+    );
+
+    Ok(())
+}
+
 fn propagate_publish_subscribe_payloads<S: Service, B: Backend<S> + Debug>(
     node_id: &NodeId,
     port: &PublishSubscribePorts<S>,
@@ -508,3 +677,135 @@ fn propagate_events<S: Service, B: Backend<S> + Debug>(
 
     Ok(())
 }
+
+fn propagate_requests_and_responses<S: Service, B: Backend<S> + Debug>(
+    port: &mut RequestResponsePorts<S>,
+    relay: &B::RequestResponseRelay,
+) -> Result<(), PropagateError> {
+    let origin = format!(
+        "Tunnel<{}, {}>::propagate_requests_and_responses()",
+        core::any::type_name::<S>(),
+        core::any::type_name::<B>()
+    );
+
+    let propagated = fail!(
+        from origin,
+        when port.receive_requests(|request_id, request| {
+            relay.send_request(request_id, request)
+        }),
+        with PropagateError::RequestPropagation,
+        "Failed to receive requests for propagation"
+    );
+    if propagated {
+        info!(
+            from origin,
+            "Propagated requests of {}({})",
+            port.static_config.messaging_pattern(),
+            port.static_config.name()
+        );
+    }
+
+    let ingested = fail!(
+        from origin,
+        when port.send_requests(|loan: &mut RequestLoanFn<_, _>| {
+            relay.receive_request::<_>(&mut |size| {
+            loan(size)})
+        }),
+        with PropagateError::RequestIngestion,
+        "Failed to ingest requests received from backend"
+    );
+    if ingested {
+        info!(
+            from origin,
+            "Ingested requests of {}({})",
+            port.static_config.messaging_pattern(),
+            port.static_config.name()
+        );
+    }
+
+    let propagated = fail!(
+        from origin,
+        when port.receive_responses(
+            |request_id, response| relay.send_response(request_id, response),
+            |request_id| relay.send_request_completion(request_id),
+        ),
+        with PropagateError::ResponsePropagation,
+        "Failed to receive responses for propagation"
+    );
+    if propagated {
+        info!(
+            from origin,
+            "Propagated responses of {}({})",
+            port.static_config.messaging_pattern(),
+            port.static_config.name()
+        );
+    }
+
+    let ingested = fail!(
+        from origin,
+        when port.send_responses(|loan: &mut ResponseLoanFn<_, _>, complete: &mut RequestCompletionFn| {
+            relay.receive_response::<_>(&mut |request_id, size| {
+            loan(request_id, size)}, complete)
+        }),
+        with PropagateError::ResponseIngestion,
+        "Failed to ingest responses received from backend"
+    );
+    if ingested {
+        info!(
+            from origin,
+            "Ingested responses of {}({})",
+            port.static_config.messaging_pattern(),
+            port.static_config.name()
+        );
+    }
+
+    Ok(())
+}
+
+fn propagate_blackboard_entries<S: Service, B: Backend<S> + Debug>(
+    node: &Node<S>,
+    port: &mut BlackboardPorts<S>,
+    relay: &B::BlackboardRelay,
+) -> Result<(), PropagateError> {
+    let origin = format!(
+        "Tunnel<{}, {}>::propagate_blackboard_entries()",
+        core::any::type_name::<S>(),
+        core::any::type_name::<B>()
+    );
+
+    let propagated = fail!(
+        from origin,
+        when port.receive(|entries| {
+            relay.send(entries)
+        }),
+        with PropagateError::BlackboardPropagation,
+        "Failed to receive blackboard entries for propagation"
+    );
+    if propagated {
+        info!(
+            from origin,
+            "Propagated {}({})",
+            port.static_config.messaging_pattern(),
+            port.static_config.name()
+        );
+    }
+
+    let ingested = fail!(
+        from origin,
+        when port.send(node, || {
+            relay.receive()
+        }),
+        with PropagateError::BlackboardIngestion,
+        "Failed to ingest blackboard entries received from backend"
+    );
+    if ingested {
+        info!(
+            from origin,
+            "Ingested {}({})",
+            port.static_config.messaging_pattern(),
+            port.static_config.name()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::testing::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_tunnel::{ServiceFilter, ServiceRule};
+
+fn static_config_of(name: &str, messaging_pattern: MessagingPattern) -> StaticConfig {
+    let config = generate_isolated_config();
+    let service_name = ServiceName::new(name).unwrap();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<local::Service>()
+        .unwrap();
+
+    let _service = node
+        .service_builder(&service_name)
+        .publish_subscribe::<u64>()
+        .open_or_create()
+        .unwrap();
+    let _service = node
+        .service_builder(&service_name)
+        .event()
+        .open_or_create()
+        .unwrap();
+
+    local::Service::details(&service_name, &config, messaging_pattern)
+        .unwrap()
+        .unwrap()
+        .static_details
+}
+
+#[test]
+fn service_rule_without_criteria_matches_all_services() {
+    let sut = ServiceRule::new();
+
+    assert_that!(sut.matches(&static_config_of("a/b", MessagingPattern::Event)), eq true);
+    assert_that!(sut.matches(&static_config_of("c", MessagingPattern::PublishSubscribe)), eq true);
+}
+
+#[test]
+fn service_rule_matches_service_name_glob() {
+    let static_config = static_config_of("camera/front/image", MessagingPattern::PublishSubscribe);
+
+    assert_that!(ServiceRule::new().service_name("camera/front/image").matches(&static_config), eq true);
+    assert_that!(ServiceRule::new().service_name("camera/*").matches(&static_config), eq true);
+    assert_that!(ServiceRule::new().service_name("*/image").matches(&static_config), eq true);
+    assert_that!(ServiceRule::new().service_name("camera/*/image").matches(&static_config), eq true);
+    assert_that!(ServiceRule::new().service_name("camera/fron?/image").matches(&static_config), eq true);
+    assert_that!(ServiceRule::new().service_name("*").matches(&static_config), eq true);
+
+    assert_that!(ServiceRule::new().service_name("camera").matches(&static_config), eq false);
+    assert_that!(ServiceRule::new().service_name("camera/front/image/*").matches(&static_config), eq false);
+    assert_that!(ServiceRule::new().service_name("*/rear/*").matches(&static_config), eq false);
+    assert_that!(ServiceRule::new().service_name("camera/front/imag?s").matches(&static_config), eq false);
+}
+
+#[test]
+fn service_rule_matches_messaging_pattern() {
+    let sut = ServiceRule::new().messaging_pattern(MessagingPattern::Event);
+
+    assert_that!(sut.matches(&static_config_of("x", MessagingPattern::Event)), eq true);
+    assert_that!(sut.matches(&static_config_of("x", MessagingPattern::PublishSubscribe)), eq false);
+}
+
+#[test]
+fn service_filter_without_rules_allows_all_services() {
+    let sut = ServiceFilter::new();
+
+    assert_that!(sut.is_allowed(&static_config_of("x", MessagingPattern::Event)), eq true);
+}
+
+#[test]
+fn service_filter_exclude_rules_take_precedence_over_include_rules() {
+    let sut = ServiceFilter::new()
+        .include(ServiceRule::new().service_name("sensor/*"))
+        .exclude(ServiceRule::new().service_name("sensor/raw/*"));
+
+    assert_that!(sut.is_allowed(&static_config_of("sensor/lidar", MessagingPattern::Event)), eq true);
+    assert_that!(sut.is_allowed(&static_config_of("sensor/raw/lidar", MessagingPattern::Event)), eq false);
+    assert_that!(sut.is_allowed(&static_config_of("actuator/motor", MessagingPattern::Event)), eq false);
+}
@@ -0,0 +1,179 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::service::Service;
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_bb_posix::thread::Thread;
+use iceoryx2_bb_posix::udp_socket::UdpServerBuilder;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::ipv4_address;
+use iceoryx2_tunnel_backend::traits::Backend;
+
+use crate::{
+    discovery::Discovery,
+    relays::{blackboard, event, publish_subscribe, request_response, Factory},
+    transport::Transport,
+    Config,
+};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    UniqueId,
+    UnicastSocket,
+    MulticastSocket,
+    MulticastConfiguration,
+    ReceiverThread,
+    Discovery,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// A [`Backend`] that discovers services via UDP multicast and relays the
+/// payloads via UDP unicast to the discovered hosts.
+#[derive(Debug)]
+pub struct UdpBackend<S: Service> {
+    transport: Arc<Transport>,
+    discovery: Discovery,
+    _unicast_receiver: Thread,
+    _multicast_receiver: Thread,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<S: Service> Drop for UdpBackend<S> {
+    fn drop(&mut self) {
+        // the receiver threads are joined when they are dropped
+        self.transport.shutdown();
+    }
+}
+
+impl<S: Service> Backend<S> for UdpBackend<S> {
+    type Config = Config;
+    type CreationError = CreationError;
+    type Discovery = Discovery;
+
+    type PublishSubscribeRelay = publish_subscribe::Relay<S>;
+    type EventRelay = event::Relay<S>;
+    type RequestResponseRelay = request_response::Relay<S>;
+    type BlackboardRelay = blackboard::Relay<S>;
+
+    type RelayFactory<'b>
+        = Factory<'b, S>
+    where
+        Self: 'b;
+
+    fn create(config: &Self::Config) -> Result<Self, Self::CreationError> {
+        let origin = "UdpBackend::create";
+
+        trace!(
+            from origin,
+            "Initializing UDP backend"
+        );
+
+        let id = fail!(
+            from origin,
+            when UniqueSystemId::new(),
+            with CreationError::UniqueId,
+            "Failed to create the id of the UDP backend"
+        );
+
+        let unicast_socket = fail!(
+            from origin,
+            when UdpServerBuilder::new().address(config.interface).listen(),
+            with CreationError::UnicastSocket,
+            "Failed to create the unicast socket"
+        );
+
+        let multicast_socket = fail!(
+            from origin,
+            when UdpServerBuilder::new()
+                .address(ipv4_address::UNSPECIFIED)
+                .port(config.multicast_port)
+                .reuse_address(true)
+                .listen(),
+            with CreationError::MulticastSocket,
+            "Failed to create the multicast socket on port {}", config.multicast_port
+        );
+
+        fail!(
+            from origin,
+            when multicast_socket.join_multicast_group(config.multicast_group, config.interface),
+            with CreationError::MulticastConfiguration,
+            "Failed to join the multicast group {}", config.multicast_group
+        );
+
+        fail!(
+            from origin,
+            when unicast_socket.set_multicast_interface(config.interface),
+            with CreationError::MulticastConfiguration,
+            "Failed to set the interface for outgoing multicast datagrams"
+        );
+
+        // other backends on the same host must receive the announcements
+        fail!(
+            from origin,
+            when unicast_socket.set_multicast_loop(true),
+            with CreationError::MulticastConfiguration,
+            "Failed to enable the loopback of outgoing multicast datagrams"
+        );
+
+        let transport = Arc::new(Transport::new(id.value(), config, unicast_socket));
+
+        let unicast_receiver = fail!(
+            from origin,
+            when transport.spawn_unicast_receiver(),
+            with CreationError::ReceiverThread,
+            "Failed to spawn the receiver thread of the unicast socket"
+        );
+
+        let multicast_receiver = match transport.spawn_multicast_receiver(multicast_socket) {
+            Ok(thread) => thread,
+            Err(e) => {
+                transport.shutdown();
+                fail!(from origin, with CreationError::ReceiverThread,
+                    "Failed to spawn the receiver thread of the multicast socket ({:?})", e);
+            }
+        };
+
+        let discovery = match Discovery::create(&transport) {
+            Ok(discovery) => discovery,
+            Err(e) => {
+                transport.shutdown();
+                fail!(from origin, with CreationError::Discovery,
+                    "Failed to create UDP discovery ({:?})", e);
+            }
+        };
+
+        Ok(Self {
+            transport,
+            discovery,
+            _unicast_receiver: unicast_receiver,
+            _multicast_receiver: multicast_receiver,
+            _phantom: core::marker::PhantomData,
+        })
+    }
+
+    fn relay_builder(&self) -> Self::RelayFactory<'_> {
+        Self::RelayFactory::new(&self.transport)
+    }
+
+    fn discovery(&self) -> &impl iceoryx2_tunnel_backend::traits::Discovery {
+        &self.discovery
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2_bb_system_types::ipv4_address::{self, Ipv4Address};
+use iceoryx2_bb_system_types::port::Port;
+
+/// Configuration of the [`UdpBackend`](crate::UdpBackend).
+///
+/// All hosts that shall discover each other must use the same multicast group and port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// The multicast group on which services are announced and discovered.
+    pub multicast_group: Ipv4Address,
+    /// The port on which the multicast group is joined.
+    pub multicast_port: Port,
+    /// The address of the network interface that is used for the communication. When
+    /// it is unspecified, the interface is chosen by the operating system.
+    pub interface: Ipv4Address,
+    /// The maximum size of a datagram including all headers. Messages that are
+    /// larger are fragmented.
+    pub max_datagram_size: usize,
+    /// The number of messages that can be buffered per relay until the oldest one
+    /// is discarded.
+    pub channel_capacity: usize,
+    /// The minimum time between two queries for the services of remote hosts.
+    pub discovery_interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            multicast_group: Ipv4Address::new(239, 255, 42, 2),
+            multicast_port: Port::new(42424),
+            interface: ipv4_address::UNSPECIFIED,
+            // MTU of ethernet minus the IPv4 and UDP headers
+            max_datagram_size: 1472,
+            channel_capacity: 64,
+            discovery_interval: Duration::from_secs(1),
+        }
+    }
+}
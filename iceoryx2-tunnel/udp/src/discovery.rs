@@ -0,0 +1,149 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::cell::Cell;
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2_bb_log::{fail, warn};
+
+use crate::transport::Transport;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    DiscoveryQuery,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DiscoveryError {
+    DiscoveryProcessing,
+    DiscoveryQuery,
+}
+
+impl core::fmt::Display for DiscoveryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DiscoveryError::{self:?}")
+    }
+}
+
+impl core::error::Error for DiscoveryError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum AnnouncementError {
+    Serialization,
+    NotifyingKnownHosts,
+}
+
+impl core::fmt::Display for AnnouncementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AnnouncementError::{self:?}")
+    }
+}
+
+impl core::error::Error for AnnouncementError {}
+
+#[derive(Debug)]
+pub struct Discovery {
+    transport: Arc<Transport>,
+    last_query: Cell<Instant>,
+}
+
+impl Discovery {
+    pub(crate) fn create(transport: &Arc<Transport>) -> Result<Self, CreationError> {
+        let origin = "Discovery::create()";
+
+        // Query immediately - replies are processed in the first `discover()` call
+        fail!(
+            from origin,
+            when transport.query(),
+            with CreationError::DiscoveryQuery,
+            "Failed to query the multicast group for services"
+        );
+
+        Ok(Self {
+            transport: transport.clone(),
+            last_query: Cell::new(Instant::now()),
+        })
+    }
+}
+
+impl iceoryx2_tunnel_backend::traits::Discovery for Discovery {
+    type DiscoveryError = DiscoveryError;
+    type AnnouncementError = AnnouncementError;
+
+    fn announce(&self, static_config: &StaticConfig) -> Result<(), Self::AnnouncementError> {
+        let service_config_serialized = fail!(
+            from self,
+            when serde_json::to_vec(&static_config),
+            with AnnouncementError::Serialization,
+            "Failed to serialize service config"
+        );
+
+        fail!(
+            from self,
+            when self.transport.announce(static_config.service_id(), service_config_serialized),
+            with AnnouncementError::NotifyingKnownHosts,
+            "Failed to announce service to the multicast group"
+        );
+
+        Ok(())
+    }
+
+    fn discover<E: core::error::Error, F: FnMut(&StaticConfig) -> Result<(), E>>(
+        &self,
+        mut process_discovery: F,
+    ) -> Result<(), DiscoveryError> {
+        for announcement in self.transport.take_discovered() {
+            match serde_json::from_slice::<StaticConfig>(&announcement) {
+                Ok(static_config) => {
+                    fail!(
+                        from self,
+                        when process_discovery(&static_config),
+                        with DiscoveryError::DiscoveryProcessing,
+                        "Failed to process discovery event"
+                    )
+                }
+                Err(e) => {
+                    warn!(
+                        "Skipping discovered service config, unable to deserialize: {}",
+                        e
+                    );
+                }
+            }
+        }
+
+        // Announcements are sent when a service is announced, the query catches up on
+        // the announcements that were missed, e.g. when they were sent before this
+        // host joined the multicast group.
+        if self.last_query.get().elapsed() >= self.transport.config().discovery_interval {
+            fail!(
+                from self,
+                when self.transport.query(),
+                with DiscoveryError::DiscoveryQuery,
+                "Failed to query the multicast group for services"
+            );
+            self.last_query.set(Instant::now());
+        }
+
+        Ok(())
+    }
+}
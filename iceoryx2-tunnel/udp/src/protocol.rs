@@ -0,0 +1,342 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The wire format of the UDP backend.
+//!
+//! Every datagram starts with a [`Header`] that identifies the sending backend, the
+//! kind of the message and the fragment of the message that is carried. Messages
+//! that do not fit into a single datagram are split by [`fragment()`] and put back
+//! together by the [`Reassembler`] on the receiving side.
+
+use std::collections::BTreeMap;
+
+use iceoryx2::service::service_id::ServiceId;
+
+/// Identifies datagrams of the UDP backend.
+pub const MAGIC: [u8; 4] = *b"IOX2";
+
+/// The version of the wire format.
+pub const VERSION: u8 = 1;
+
+/// The size of the request id that precedes the payload of requests and responses.
+pub const REQUEST_ID_SIZE: usize = core::mem::size_of::<u64>();
+
+/// Marks a [`MessageKind::Response`] that carries the payload of a response.
+pub const RESPONSE: u8 = 0;
+
+/// Marks a [`MessageKind::Response`] that signals that the sending backend will
+/// not send further responses to the request. It is sent in-band so that it is
+/// received after all responses of the request.
+pub const REQUEST_COMPLETION: u8 = 1;
+
+/// The size of the [`Header`] that precedes the payload of every datagram.
+pub const HEADER_SIZE: usize = 4 + 1 + 1 + 16 + 8 + 2 + 2;
+
+/// The kind of a message exchanged between backends.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MessageKind {
+    /// Requests all backends to announce their services.
+    Query = 0,
+    /// Carries the static config of a service.
+    Announcement = 1,
+    /// Informs that the sending backend is shutting down.
+    Departure = 2,
+    /// Carries the payload of a publish-subscribe sample.
+    PublishSubscribe = 3,
+    /// Carries the id of an event notification.
+    Event = 4,
+    /// Carries the payload of a request.
+    Request = 5,
+    /// Carries the payload of a response.
+    Response = 6,
+    /// Carries the entries of a blackboard.
+    Blackboard = 7,
+    /// Requests the most recently sent entries of a blackboard.
+    BlackboardQuery = 8,
+}
+
+impl TryFrom<u8> for MessageKind {
+    type Error = ReassemblyError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MessageKind::Query),
+            1 => Ok(MessageKind::Announcement),
+            2 => Ok(MessageKind::Departure),
+            3 => Ok(MessageKind::PublishSubscribe),
+            4 => Ok(MessageKind::Event),
+            5 => Ok(MessageKind::Request),
+            6 => Ok(MessageKind::Response),
+            7 => Ok(MessageKind::Blackboard),
+            8 => Ok(MessageKind::BlackboardQuery),
+            _ => Err(ReassemblyError::InvalidHeader),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum FragmentationError {
+    DatagramSizeTooSmall,
+    MessageTooLarge,
+}
+
+impl core::fmt::Display for FragmentationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FragmentationError::{self:?}")
+    }
+}
+
+impl core::error::Error for FragmentationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReassemblyError {
+    InvalidHeader,
+    InvalidFragment,
+}
+
+impl core::fmt::Display for ReassemblyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReassemblyError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReassemblyError {}
+
+/// Precedes the payload of every datagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub kind: MessageKind,
+    /// The id of the backend that sent the message.
+    pub sender: u128,
+    /// Identifies the message among all messages of the sender.
+    pub message_id: u64,
+    pub fragment_index: u16,
+    pub fragment_count: u16,
+}
+
+impl Header {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&MAGIC);
+        buffer.push(VERSION);
+        buffer.push(self.kind as u8);
+        buffer.extend_from_slice(&self.sender.to_le_bytes());
+        buffer.extend_from_slice(&self.message_id.to_le_bytes());
+        buffer.extend_from_slice(&self.fragment_index.to_le_bytes());
+        buffer.extend_from_slice(&self.fragment_count.to_le_bytes());
+    }
+
+    /// Decodes the [`Header`] of a datagram.
+    pub fn decode(datagram: &[u8]) -> Result<Header, ReassemblyError> {
+        if datagram.len() < HEADER_SIZE || datagram[0..4] != MAGIC || datagram[4] != VERSION {
+            return Err(ReassemblyError::InvalidHeader);
+        }
+
+        let header = Header {
+            kind: MessageKind::try_from(datagram[5])?,
+            sender: u128::from_le_bytes(datagram[6..22].try_into().unwrap()),
+            message_id: u64::from_le_bytes(datagram[22..30].try_into().unwrap()),
+            fragment_index: u16::from_le_bytes(datagram[30..32].try_into().unwrap()),
+            fragment_count: u16::from_le_bytes(datagram[32..34].try_into().unwrap()),
+        };
+
+        if header.fragment_count == 0 || header.fragment_index >= header.fragment_count {
+            return Err(ReassemblyError::InvalidFragment);
+        }
+
+        Ok(header)
+    }
+}
+
+/// A complete message received from another backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub kind: MessageKind,
+    /// The id of the backend that sent the message.
+    pub sender: u128,
+    pub payload: Vec<u8>,
+}
+
+/// Splits a message into datagrams of at most `max_datagram_size` bytes.
+pub fn fragment(
+    kind: MessageKind,
+    sender: u128,
+    message_id: u64,
+    payload: &[u8],
+    max_datagram_size: usize,
+) -> Result<Vec<Vec<u8>>, FragmentationError> {
+    if max_datagram_size <= HEADER_SIZE {
+        return Err(FragmentationError::DatagramSizeTooSmall);
+    }
+
+    let fragment_size = max_datagram_size - HEADER_SIZE;
+    let fragment_count = payload.len().div_ceil(fragment_size).max(1);
+    if fragment_count > u16::MAX as usize {
+        return Err(FragmentationError::MessageTooLarge);
+    }
+
+    let mut datagrams = Vec::with_capacity(fragment_count);
+    for fragment_index in 0..fragment_count {
+        let start = fragment_index * fragment_size;
+        let end = (start + fragment_size).min(payload.len());
+
+        let mut datagram = Vec::with_capacity(HEADER_SIZE + end - start);
+        Header {
+            kind,
+            sender,
+            message_id,
+            fragment_index: fragment_index as u16,
+            fragment_count: fragment_count as u16,
+        }
+        .encode(&mut datagram);
+        datagram.extend_from_slice(&payload[start..end]);
+        datagrams.push(datagram);
+    }
+
+    Ok(datagrams)
+}
+
+#[derive(Debug)]
+struct PendingMessage {
+    kind: MessageKind,
+    fragments: Vec<Option<Vec<u8>>>,
+    number_of_received_fragments: usize,
+    age: u64,
+}
+
+/// Puts the fragments created by [`fragment()`] back together.
+///
+/// Since datagrams can be lost, a message is only returned when all of its fragments
+/// were received. When more than `max_pending_messages` messages are incomplete,
+/// the oldest one is discarded.
+#[derive(Debug)]
+pub struct Reassembler {
+    max_pending_messages: usize,
+    pending: BTreeMap<(u128, u64), PendingMessage>,
+    age: u64,
+}
+
+impl Reassembler {
+    pub fn new(max_pending_messages: usize) -> Self {
+        Self {
+            max_pending_messages,
+            pending: BTreeMap::new(),
+            age: 0,
+        }
+    }
+
+    /// Returns the number of messages that are not yet complete.
+    pub fn number_of_pending_messages(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Adds a received datagram. Returns the [`Message`] when the datagram completes it.
+    pub fn insert(&mut self, datagram: &[u8]) -> Result<Option<Message>, ReassemblyError> {
+        let header = Header::decode(datagram)?;
+        let payload = &datagram[HEADER_SIZE..];
+
+        if header.fragment_count == 1 {
+            return Ok(Some(Message {
+                kind: header.kind,
+                sender: header.sender,
+                payload: payload.to_vec(),
+            }));
+        }
+
+        let key = (header.sender, header.message_id);
+        if !self.pending.contains_key(&key) {
+            if self.pending.len() >= self.max_pending_messages {
+                if let Some(oldest) = self
+                    .pending
+                    .iter()
+                    .min_by_key(|(_, pending)| pending.age)
+                    .map(|(key, _)| *key)
+                {
+                    self.pending.remove(&oldest);
+                }
+            }
+
+            self.age += 1;
+            self.pending.insert(
+                key,
+                PendingMessage {
+                    kind: header.kind,
+                    fragments: vec![None; header.fragment_count as usize],
+                    number_of_received_fragments: 0,
+                    age: self.age,
+                },
+            );
+        }
+
+        let pending = self.pending.get_mut(&key).unwrap();
+        if pending.kind != header.kind || pending.fragments.len() != header.fragment_count as usize
+        {
+            self.pending.remove(&key);
+            return Err(ReassemblyError::InvalidFragment);
+        }
+
+        let fragment = &mut pending.fragments[header.fragment_index as usize];
+        if fragment.is_none() {
+            *fragment = Some(payload.to_vec());
+            pending.number_of_received_fragments += 1;
+        }
+
+        if pending.number_of_received_fragments < pending.fragments.len() {
+            return Ok(None);
+        }
+
+        let pending = self.pending.remove(&key).unwrap();
+        Ok(Some(Message {
+            kind: pending.kind,
+            sender: header.sender,
+            payload: pending.fragments.into_iter().flatten().flatten().collect(),
+        }))
+    }
+}
+
+/// Prefixes the payload of a message with the [`ServiceId`] it belongs to.
+pub fn encode_service_payload(service_id: &ServiceId, payload: &[&[u8]]) -> Vec<u8> {
+    let service_id = service_id.as_str().as_bytes();
+    let payload_len: usize = payload.iter().map(|part| part.len()).sum();
+
+    let mut encoded = Vec::with_capacity(1 + service_id.len() + payload_len);
+    encoded.push(service_id.len() as u8);
+    encoded.extend_from_slice(service_id);
+    for part in payload {
+        encoded.extend_from_slice(part);
+    }
+    encoded
+}
+
+/// Creates the payload of the [`REQUEST_COMPLETION`] that answers the payload of
+/// a [`MessageKind::Request`]. Returns [`None`] when the request is malformed.
+pub fn encode_request_completion(request: &[u8]) -> Option<Vec<u8>> {
+    let (service_id, payload) = decode_service_payload(request)?;
+    let request_id = payload.get(..REQUEST_ID_SIZE)?;
+
+    let mut encoded = request[..1 + service_id.len()].to_vec();
+    encoded.extend_from_slice(request_id);
+    encoded.push(REQUEST_COMPLETION);
+    Some(encoded)
+}
+
+/// Splits the payload of a message into the [`ServiceId`] it belongs to and the
+/// remaining payload.
+pub fn decode_service_payload(payload: &[u8]) -> Option<(&str, &[u8])> {
+    let (len, payload) = payload.split_first()?;
+    if payload.len() < *len as usize {
+        return None;
+    }
+
+    let (service_id, payload) = payload.split_at(*len as usize);
+    Some((core::str::from_utf8(service_id).ok()?, payload))
+}
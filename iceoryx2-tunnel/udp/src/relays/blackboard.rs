@@ -0,0 +1,232 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::Service;
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_tunnel_backend::traits::{BlackboardRelay, RelayBuilder};
+use iceoryx2_tunnel_backend::types::blackboard::Entry;
+
+use crate::protocol::{decode_service_payload, encode_service_payload, MessageKind};
+use crate::transport::Transport;
+
+type EncodedEntries = Vec<(Vec<u8>, TypeDetail, Vec<u8>)>;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    DuplicateRelay,
+    EntriesQuery,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    Serialization,
+    EntriesSend,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    InvalidEntries,
+    Deserialization,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+#[derive(Debug)]
+pub struct Builder<'a, S: Service> {
+    transport: &'a Arc<Transport>,
+    static_config: &'a StaticConfig,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<'a, S: Service> Builder<'a, S> {
+    pub(crate) fn new(
+        transport: &'a Arc<Transport>,
+        static_config: &'a StaticConfig,
+    ) -> Builder<'a, S> {
+        Builder {
+            transport,
+            static_config,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: Service> RelayBuilder for Builder<'a, S> {
+    type CreationError = CreationError;
+    type Relay = Relay<S>;
+
+    fn create(self) -> Result<Self::Relay, Self::CreationError> {
+        let service_id = self.static_config.service_id();
+
+        if !self
+            .transport
+            .open_channel(MessageKind::Blackboard, service_id)
+        {
+            fail!(from self, with CreationError::DuplicateRelay,
+                "Failed to create relay since a relay for the blackboard service already exists");
+        }
+
+        // Query immediately - replies are processed in the first `receive()` call
+        if let Err(e) = self.transport.send_to_peers(
+            MessageKind::BlackboardQuery,
+            &encode_service_payload(service_id, &[]),
+        ) {
+            self.transport
+                .close_channel(MessageKind::Blackboard, service_id);
+            fail!(from self, with CreationError::EntriesQuery,
+                "Failed to query blackboard entries from remote hosts ({:?})", e);
+        }
+
+        Ok(Relay {
+            static_config: self.static_config.clone(),
+            transport: self.transport.clone(),
+            _phantom: core::marker::PhantomData,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Relay<S: Service> {
+    static_config: StaticConfig,
+    transport: Arc<Transport>,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<S: Service> Drop for Relay<S> {
+    fn drop(&mut self) {
+        self.transport
+            .close_channel(MessageKind::Blackboard, self.static_config.service_id());
+    }
+}
+
+impl<S: Service> Relay<S> {
+    fn decode(&self, payload: &[u8]) -> Result<Vec<Entry>, ReceiveError> {
+        let payload = match decode_service_payload(payload) {
+            Some((_, payload)) => payload,
+            None => {
+                fail!(from self, with ReceiveError::InvalidEntries,
+                    "Received blackboard entries without a valid service id");
+            }
+        };
+
+        let entries: EncodedEntries = fail!(
+            from self,
+            when serde_json::from_slice(payload),
+            with ReceiveError::Deserialization,
+            "Failed to deserialize blackboard entries"
+        );
+
+        Ok(entries
+            .into_iter()
+            .map(|(key, value_type_details, value)| Entry {
+                key,
+                value_type_details,
+                value,
+            })
+            .collect())
+    }
+}
+
+impl<S: Service> BlackboardRelay<S> for Relay<S> {
+    type SendError = SendError;
+    type ReceiveError = ReceiveError;
+
+    fn send(&self, entries: &[Entry]) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let encoded: Vec<(&[u8], &TypeDetail, &[u8])> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.key.as_slice(),
+                    &entry.value_type_details,
+                    entry.value.as_slice(),
+                )
+            })
+            .collect();
+        let entries = fail!(
+            from self,
+            when serde_json::to_vec(&encoded),
+            with SendError::Serialization,
+            "Failed to serialize blackboard entries"
+        );
+
+        let service_id = self.static_config.service_id();
+        let payload = encode_service_payload(service_id, &[&entries]);
+
+        fail!(
+            from self,
+            when self.transport.send_to_peers(MessageKind::Blackboard, &payload),
+            with SendError::EntriesSend,
+            "Failed to propagate blackboard entries to the peers"
+        );
+
+        // provide the last sent entries to hosts that join later
+        self.transport.store_blackboard(service_id, payload);
+
+        Ok(())
+    }
+
+    fn receive(&self) -> Result<Option<Vec<Entry>>, Self::ReceiveError> {
+        let mut entries = None;
+
+        // only the most recent entries are relevant
+        while let Some(message) = self
+            .transport
+            .receive_from_channel(MessageKind::Blackboard, self.static_config.service_id())
+        {
+            entries = Some(self.decode(&message.payload)?);
+        }
+
+        if entries.is_some() {
+            trace!(
+                from self,
+                "Received {}({})",
+                self.static_config.messaging_pattern(),
+                self.static_config.name()
+            );
+        }
+
+        Ok(entries)
+    }
+}
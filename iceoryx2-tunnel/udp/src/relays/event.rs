@@ -0,0 +1,167 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::prelude::EventId;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::Service;
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_tunnel_backend::traits::{EventRelay, RelayBuilder};
+
+use crate::protocol::{decode_service_payload, encode_service_payload, MessageKind};
+use crate::transport::Transport;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    DuplicateRelay,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    EventSend,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    InvalidEvent,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+#[derive(Debug)]
+pub struct Builder<'a, S: Service> {
+    transport: &'a Arc<Transport>,
+    static_config: &'a StaticConfig,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<'a, S: Service> Builder<'a, S> {
+    pub(crate) fn new(
+        transport: &'a Arc<Transport>,
+        static_config: &'a StaticConfig,
+    ) -> Builder<'a, S> {
+        Builder {
+            transport,
+            static_config,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: Service> RelayBuilder for Builder<'a, S> {
+    type CreationError = CreationError;
+    type Relay = Relay<S>;
+
+    fn create(self) -> Result<Self::Relay, Self::CreationError> {
+        if !self
+            .transport
+            .open_channel(MessageKind::Event, self.static_config.service_id())
+        {
+            fail!(from self, with CreationError::DuplicateRelay,
+                "Failed to create relay since a relay for the event service already exists");
+        }
+
+        Ok(Relay {
+            static_config: self.static_config.clone(),
+            transport: self.transport.clone(),
+            _phantom: core::marker::PhantomData,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Relay<S: Service> {
+    static_config: StaticConfig,
+    transport: Arc<Transport>,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<S: Service> Drop for Relay<S> {
+    fn drop(&mut self) {
+        self.transport
+            .close_channel(MessageKind::Event, self.static_config.service_id());
+    }
+}
+
+impl<S: Service> EventRelay<S> for Relay<S> {
+    type SendError = SendError;
+    type ReceiveError = ReceiveError;
+
+    fn send(&self, event_id: EventId) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let event_id = (event_id.as_value() as u64).to_le_bytes();
+        fail!(
+            from self,
+            when self.transport.send_to_peers(
+                MessageKind::Event,
+                &encode_service_payload(self.static_config.service_id(), &[&event_id]),
+            ),
+            with SendError::EventSend,
+            "Failed to propagate notification to the peers"
+        );
+
+        Ok(())
+    }
+
+    fn receive(&self) -> Result<Option<EventId>, Self::ReceiveError> {
+        let message = match self
+            .transport
+            .receive_from_channel(MessageKind::Event, self.static_config.service_id())
+        {
+            Some(message) => message,
+            None => return Ok(None),
+        };
+
+        trace!(
+            from self,
+            "Ingesting {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        match decode_service_payload(&message.payload)
+            .and_then(|(_, event_id)| <[u8; 8]>::try_from(event_id).ok())
+        {
+            Some(event_id) => Ok(Some(EventId::new(u64::from_le_bytes(event_id) as usize))),
+            None => Err(ReceiveError::InvalidEvent),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::service::{static_config::StaticConfig, Service};
+use iceoryx2_tunnel_backend::traits::RelayFactory;
+
+use crate::relays::{blackboard, event, publish_subscribe, request_response};
+use crate::transport::Transport;
+
+/// Factory for creating relay builders.
+///
+/// The factory holds a reference to the transport of the backend and can be used in multiple builders.
+#[derive(Debug)]
+pub struct Factory<'transport, S: Service> {
+    /// Reference to the transport. The transport must outlive the Factory.
+    transport: &'transport Arc<Transport>,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<'transport, S: Service> Factory<'transport, S> {
+    pub(crate) fn new(transport: &'transport Arc<Transport>) -> Self {
+        Factory {
+            transport,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'transport, S: Service> RelayFactory<S> for Factory<'transport, S> {
+    type PublishSubscribeRelay = publish_subscribe::Relay<S>;
+    type EventRelay = event::Relay<S>;
+    type RequestResponseRelay = request_response::Relay<S>;
+    type BlackboardRelay = blackboard::Relay<S>;
+
+    type PublishSubscribeBuilder<'config>
+        = publish_subscribe::Builder<'config, S>
+    where
+        Self: 'config;
+
+    type EventBuilder<'config>
+        = event::Builder<'config, S>
+    where
+        Self: 'config;
+
+    type RequestResponseBuilder<'config>
+        = request_response::Builder<'config, S>
+    where
+        Self: 'config;
+
+    type BlackboardBuilder<'config>
+        = blackboard::Builder<'config, S>
+    where
+        Self: 'config;
+
+    fn publish_subscribe<'config>(
+        &self,
+        static_config: &'config StaticConfig,
+    ) -> Self::PublishSubscribeBuilder<'config>
+    where
+        Self: 'config,
+    {
+        publish_subscribe::Builder::new(self.transport, static_config)
+    }
+
+    fn event<'config>(&self, static_config: &'config StaticConfig) -> Self::EventBuilder<'config>
+    where
+        Self: 'config,
+    {
+        event::Builder::new(self.transport, static_config)
+    }
+
+    fn request_response<'config>(
+        &self,
+        static_config: &'config StaticConfig,
+    ) -> Self::RequestResponseBuilder<'config>
+    where
+        Self: 'config,
+    {
+        request_response::Builder::new(self.transport, static_config)
+    }
+
+    fn blackboard<'config>(
+        &self,
+        static_config: &'config StaticConfig,
+    ) -> Self::BlackboardBuilder<'config>
+    where
+        Self: 'config,
+    {
+        blackboard::Builder::new(self.transport, static_config)
+    }
+}
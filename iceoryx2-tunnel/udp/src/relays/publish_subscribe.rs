@@ -0,0 +1,210 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::service::{
+    builder::{CustomHeaderMarker, CustomPayloadMarker},
+    static_config::StaticConfig,
+    Service,
+};
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_tunnel_backend::{
+    traits::{PublishSubscribeRelay, RelayBuilder},
+    types::publish_subscribe::{LoanFn, SampleMut},
+};
+
+use crate::protocol::{decode_service_payload, encode_service_payload, MessageKind};
+use crate::transport::Transport;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    DuplicateRelay,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    PayloadSend,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    InvalidSample,
+    IceoryxLoan,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+#[derive(Debug)]
+pub struct Builder<'a, S: Service> {
+    transport: &'a Arc<Transport>,
+    static_config: &'a StaticConfig,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<'a, S: Service> Builder<'a, S> {
+    pub(crate) fn new(
+        transport: &'a Arc<Transport>,
+        static_config: &'a StaticConfig,
+    ) -> Builder<'a, S> {
+        Builder {
+            transport,
+            static_config,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: Service> RelayBuilder for Builder<'a, S> {
+    type CreationError = CreationError;
+    type Relay = Relay<S>;
+
+    fn create(self) -> Result<Self::Relay, Self::CreationError> {
+        if !self.transport.open_channel(
+            MessageKind::PublishSubscribe,
+            self.static_config.service_id(),
+        ) {
+            fail!(from self, with CreationError::DuplicateRelay,
+                "Failed to create relay since a relay for the publish-subscribe service already exists");
+        }
+
+        Ok(Relay {
+            static_config: self.static_config.clone(),
+            transport: self.transport.clone(),
+            _phantom: core::marker::PhantomData,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Relay<S: Service> {
+    static_config: StaticConfig,
+    transport: Arc<Transport>,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<S: Service> Drop for Relay<S> {
+    fn drop(&mut self) {
+        self.transport.close_channel(
+            MessageKind::PublishSubscribe,
+            self.static_config.service_id(),
+        );
+    }
+}
+
+impl<S: Service> PublishSubscribeRelay<S> for Relay<S> {
+    type SendError = SendError;
+    type ReceiveError = ReceiveError;
+
+    fn send(
+        &self,
+        sample: iceoryx2::sample::Sample<S, [CustomPayloadMarker], CustomHeaderMarker>,
+    ) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let payload = sample.payload();
+        let payload =
+            unsafe { core::slice::from_raw_parts(payload.as_ptr().cast::<u8>(), payload.len()) };
+
+        fail!(
+            from self,
+            when self.transport.send_to_peers(
+                MessageKind::PublishSubscribe,
+                &encode_service_payload(self.static_config.service_id(), &[payload]),
+            ),
+            with SendError::PayloadSend,
+            "Failed to propagate publish-subscribe payload to the peers"
+        );
+
+        Ok(())
+    }
+
+    fn receive<LoanError>(
+        &self,
+        loan: &mut LoanFn<'_, S, LoanError>,
+    ) -> Result<Option<SampleMut<S>>, Self::ReceiveError> {
+        let message = match self.transport.receive_from_channel(
+            MessageKind::PublishSubscribe,
+            self.static_config.service_id(),
+        ) {
+            Some(message) => message,
+            None => return Ok(None),
+        };
+
+        trace!(
+            from self,
+            "Ingesting {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let udp_payload = match decode_service_payload(&message.payload) {
+            Some((_, payload)) => payload,
+            None => {
+                fail!(from self, with ReceiveError::InvalidSample,
+                    "Received a publish-subscribe payload without a valid service id");
+            }
+        };
+
+        let mut iceoryx_sample = fail!(
+            from self,
+            when loan(udp_payload.len()),
+            with ReceiveError::IceoryxLoan,
+            "Failed to loan sample from iceoryx"
+        );
+        let iceoryx_payload = iceoryx_sample.payload_mut();
+
+        debug_assert!(
+            iceoryx_payload.len() >= udp_payload.len(),
+            "loan_size ({}) is too small for received payload ({})",
+            iceoryx_payload.len(),
+            udp_payload.len()
+        );
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                udp_payload.as_ptr(),
+                iceoryx_payload.as_mut_ptr().cast(),
+                udp_payload.len(),
+            );
+        }
+
+        Ok(Some(unsafe { iceoryx_sample.assume_init() }))
+    }
+}
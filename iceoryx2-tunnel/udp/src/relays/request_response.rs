@@ -0,0 +1,447 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::Service;
+use iceoryx2_bb_log::{fail, trace, warn};
+use iceoryx2_tunnel_backend::traits::{RelayBuilder, RequestResponseRelay};
+use iceoryx2_tunnel_backend::types::request_response::{
+    ActiveRequest, RemoteRequestId, RequestCompletionFn, RequestId, RequestLoanFn, RequestMut,
+    Response, ResponseLoanFn, ResponseMut,
+};
+
+use crate::protocol::{
+    decode_service_payload, encode_service_payload, MessageKind, REQUEST_COMPLETION,
+    REQUEST_ID_SIZE, RESPONSE,
+};
+use crate::transport::Transport;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    DuplicateRelay,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    RequestSend,
+    ResponseSend,
+    RequestCompletionSend,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    InvalidRequest,
+    InvalidResponse,
+    IceoryxLoan,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+#[derive(Debug)]
+pub struct Builder<'a, S: Service> {
+    transport: &'a Arc<Transport>,
+    static_config: &'a StaticConfig,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<'a, S: Service> Builder<'a, S> {
+    pub(crate) fn new(
+        transport: &'a Arc<Transport>,
+        static_config: &'a StaticConfig,
+    ) -> Builder<'a, S> {
+        Builder {
+            transport,
+            static_config,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: Service> RelayBuilder for Builder<'a, S> {
+    type CreationError = CreationError;
+    type Relay = Relay<S>;
+
+    fn create(self) -> Result<Self::Relay, Self::CreationError> {
+        let service_id = self.static_config.service_id();
+
+        if !self
+            .transport
+            .open_channel(MessageKind::Request, service_id)
+        {
+            fail!(from self, with CreationError::DuplicateRelay,
+                "Failed to create relay since a relay for the request-response service already exists");
+        }
+
+        if !self
+            .transport
+            .open_channel(MessageKind::Response, service_id)
+        {
+            self.transport
+                .close_channel(MessageKind::Request, service_id);
+            fail!(from self, with CreationError::DuplicateRelay,
+                "Failed to create relay since a relay for the request-response service already exists");
+        }
+
+        Ok(Relay {
+            static_config: self.static_config.clone(),
+            transport: self.transport.clone(),
+            pending_hosts: Mutex::new(BTreeMap::new()),
+            _phantom: core::marker::PhantomData,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Relay<S: Service> {
+    static_config: StaticConfig,
+    transport: Arc<Transport>,
+    // The peers of each sent request that did not yet complete it.
+    pending_hosts: Mutex<BTreeMap<RequestId, BTreeSet<u128>>>,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<S: Service> Drop for Relay<S> {
+    fn drop(&mut self) {
+        let service_id = self.static_config.service_id();
+        self.transport
+            .close_channel(MessageKind::Request, service_id);
+        self.transport
+            .close_channel(MessageKind::Response, service_id);
+    }
+}
+
+impl<S: Service> Relay<S> {
+    /// Splits the request id from the payload of a request or response.
+    fn decode_request_id(payload: &[u8]) -> Option<(RequestId, &[u8])> {
+        let (_, payload) = decode_service_payload(payload)?;
+        if payload.len() < REQUEST_ID_SIZE {
+            return None;
+        }
+
+        let (request_id, payload) = payload.split_at(REQUEST_ID_SIZE);
+        Some((
+            RequestId::from_le_bytes(request_id.try_into().ok()?),
+            payload,
+        ))
+    }
+
+    /// Marks the request as completed by the peer and releases it once all
+    /// peers it was sent to completed it.
+    fn complete_request(
+        &self,
+        request_id: RequestId,
+        peer: u128,
+        complete: &mut RequestCompletionFn<'_>,
+    ) {
+        let mut pending_hosts = self.pending_hosts.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(peers) = pending_hosts.get_mut(&request_id) {
+            peers.remove(&peer);
+            if peers.is_empty() {
+                pending_hosts.remove(&request_id);
+                complete(request_id);
+            }
+        }
+    }
+
+    /// Releases the requests whose remaining peers departed.
+    fn complete_requests_of_departed_peers(&self, complete: &mut RequestCompletionFn<'_>) {
+        let peers = self.transport.peers();
+        self.pending_hosts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|request_id, pending_peers| {
+                pending_peers.retain(|peer| peers.contains(peer));
+                if pending_peers.is_empty() {
+                    complete(*request_id);
+                    return false;
+                }
+                true
+            });
+    }
+}
+
+impl<S: Service> RequestResponseRelay<S> for Relay<S> {
+    type SendError = SendError;
+    type ReceiveError = ReceiveError;
+
+    fn send_request(
+        &self,
+        request_id: RequestId,
+        request: &ActiveRequest<S>,
+    ) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending request {} of {}({})",
+            request_id,
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let payload = request.payload();
+        let payload =
+            unsafe { core::slice::from_raw_parts(payload.as_ptr().cast::<u8>(), payload.len()) };
+
+        // Requests sent while no peer is known are released when the client is
+        // gone since no peer will complete them.
+        let peers = self.transport.peers();
+
+        fail!(
+            from self,
+            when self.transport.send_to_peers(
+                MessageKind::Request,
+                &encode_service_payload(
+                    self.static_config.service_id(),
+                    &[&request_id.to_le_bytes(), payload],
+                ),
+            ),
+            with SendError::RequestSend,
+            "Failed to propagate request to the peers"
+        );
+
+        if !peers.is_empty() {
+            self.pending_hosts
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(request_id, peers);
+        }
+
+        Ok(())
+    }
+
+    fn receive_request<LoanError>(
+        &self,
+        loan: &mut RequestLoanFn<'_, S, LoanError>,
+    ) -> Result<Option<(RemoteRequestId, RequestMut<S>)>, Self::ReceiveError> {
+        let message = match self
+            .transport
+            .receive_from_channel(MessageKind::Request, self.static_config.service_id())
+        {
+            Some(message) => message,
+            None => return Ok(None),
+        };
+
+        let (request_id, udp_payload) = match Self::decode_request_id(&message.payload) {
+            Some(decoded) => decoded,
+            None => {
+                fail!(from self, with ReceiveError::InvalidRequest,
+                    "Received a request without a valid request id");
+            }
+        };
+
+        // responses are sent directly to the backend that sent the request
+        let remote_request_id = RemoteRequestId {
+            origin: message.sender,
+            request_id,
+        };
+
+        trace!(
+            from self,
+            "Ingesting request {:?} of {}({})",
+            remote_request_id,
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let mut iceoryx_request = fail!(
+            from self,
+            when loan(udp_payload.len()),
+            with ReceiveError::IceoryxLoan,
+            "Failed to loan request from iceoryx"
+        );
+        let iceoryx_payload = iceoryx_request.payload_mut();
+
+        debug_assert!(
+            iceoryx_payload.len() >= udp_payload.len(),
+            "loan_size ({}) is too small for received payload ({})",
+            iceoryx_payload.len(),
+            udp_payload.len()
+        );
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                udp_payload.as_ptr(),
+                iceoryx_payload.as_mut_ptr().cast(),
+                udp_payload.len(),
+            );
+        }
+
+        Ok(Some((remote_request_id, unsafe {
+            iceoryx_request.assume_init()
+        })))
+    }
+
+    fn send_response(
+        &self,
+        request_id: &RemoteRequestId,
+        response: Response<S>,
+    ) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending response to {:?} of {}({})",
+            request_id,
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let payload = response.payload();
+        let payload =
+            unsafe { core::slice::from_raw_parts(payload.as_ptr().cast::<u8>(), payload.len()) };
+
+        fail!(
+            from self,
+            when self.transport.send_to_peer(
+                request_id.origin,
+                MessageKind::Response,
+                &encode_service_payload(
+                    self.static_config.service_id(),
+                    &[&request_id.request_id.to_le_bytes(), &[RESPONSE], payload],
+                ),
+            ),
+            with SendError::ResponseSend,
+            "Failed to propagate response to the peer"
+        );
+
+        Ok(())
+    }
+
+    fn send_request_completion(&self, request_id: &RemoteRequestId) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending completion of {:?} of {}({})",
+            request_id,
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        fail!(
+            from self,
+            when self.transport.send_to_peer(
+                request_id.origin,
+                MessageKind::Response,
+                &encode_service_payload(
+                    self.static_config.service_id(),
+                    &[&request_id.request_id.to_le_bytes(), &[REQUEST_COMPLETION]],
+                ),
+            ),
+            with SendError::RequestCompletionSend,
+            "Failed to propagate request completion to the peer"
+        );
+
+        Ok(())
+    }
+
+    fn receive_response<LoanError>(
+        &self,
+        loan: &mut ResponseLoanFn<'_, S, LoanError>,
+        complete: &mut RequestCompletionFn<'_>,
+    ) -> Result<Option<ResponseMut<S>>, Self::ReceiveError> {
+        self.complete_requests_of_departed_peers(complete);
+
+        loop {
+            let message = match self
+                .transport
+                .receive_from_channel(MessageKind::Response, self.static_config.service_id())
+            {
+                Some(message) => message,
+                None => return Ok(None),
+            };
+
+            let (request_id, udp_payload) = match Self::decode_request_id(&message.payload) {
+                Some((request_id, [RESPONSE, udp_payload @ ..])) => (request_id, udp_payload),
+                Some((request_id, [REQUEST_COMPLETION])) => {
+                    trace!(
+                        from self,
+                        "Ingesting completion of request {} by {:032x} of {}({})",
+                        request_id,
+                        message.sender,
+                        self.static_config.messaging_pattern(),
+                        self.static_config.name()
+                    );
+                    self.complete_request(request_id, message.sender, complete);
+                    continue;
+                }
+                _ => {
+                    fail!(from self, with ReceiveError::InvalidResponse,
+                        "Received a response without a valid request id");
+                }
+            };
+
+            let iceoryx_response = fail!(
+                from self,
+                when loan(request_id, udp_payload.len()),
+                with ReceiveError::IceoryxLoan,
+                "Failed to loan response from iceoryx"
+            );
+
+            let mut iceoryx_response = match iceoryx_response {
+                Some(iceoryx_response) => iceoryx_response,
+                None => {
+                    warn!(from self,
+                        "Discarding response since the request {} is no longer pending.", request_id);
+                    continue;
+                }
+            };
+
+            trace!(
+                from self,
+                "Ingesting response to request {} of {}({})",
+                request_id,
+                self.static_config.messaging_pattern(),
+                self.static_config.name()
+            );
+
+            let iceoryx_payload = iceoryx_response.payload_mut();
+
+            debug_assert!(
+                iceoryx_payload.len() >= udp_payload.len(),
+                "loan_size ({}) is too small for received payload ({})",
+                iceoryx_payload.len(),
+                udp_payload.len()
+            );
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    udp_payload.as_ptr(),
+                    iceoryx_payload.as_mut_ptr().cast(),
+                    udp_payload.len(),
+                );
+            }
+
+            return Ok(Some(unsafe { iceoryx_response.assume_init() }));
+        }
+    }
+}
@@ -0,0 +1,390 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use iceoryx2::service::service_id::ServiceId;
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::thread::{Thread, ThreadBuilder, ThreadSpawnError};
+use iceoryx2_bb_posix::udp_socket::UdpServer;
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
+
+use crate::config::Config;
+use crate::protocol::{
+    decode_service_payload, encode_request_completion, fragment, Header, Message, MessageKind,
+    Reassembler,
+};
+
+/// The time after which a receiver thread checks whether it shall stop.
+const RECEIVE_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// The maximum number of fragmented messages per socket that are reassembled concurrently.
+const MAX_PENDING_MESSAGES: usize = 64;
+
+/// The maximum size of an UDP datagram.
+const RECEIVE_BUFFER_SIZE: usize = 65536;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    Fragmentation,
+    DatagramSend,
+    UnknownPeer,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Endpoint {
+    address: Ipv4Address,
+    port: Port,
+}
+
+type ChannelKey = (MessageKind, String);
+
+#[derive(Debug, Default)]
+struct State {
+    /// The unicast endpoints of the backends on remote hosts.
+    peers: BTreeMap<u128, Endpoint>,
+    /// The serialized static configs of the announced local services.
+    announcements: BTreeMap<String, Vec<u8>>,
+    /// The serialized static configs of remote services that were not yet processed.
+    discovered: Vec<Vec<u8>>,
+    /// The received messages for the relays of this host.
+    channels: BTreeMap<ChannelKey, VecDeque<Message>>,
+    /// The most recently sent entries of each blackboard.
+    blackboards: BTreeMap<String, Vec<u8>>,
+}
+
+/// Sends and receives the messages of the [`UdpBackend`](crate::UdpBackend).
+///
+/// Services are announced to the multicast group, all other messages are sent
+/// via unicast to the peers. A peer is any remote backend a message was
+/// received from. The datagrams are received by background threads so that
+/// remote hosts are served even when the tunnel is not polling.
+#[derive(Debug)]
+pub(crate) struct Transport {
+    id: u128,
+    config: Config,
+    socket: UdpServer,
+    next_message_id: IoxAtomicU64,
+    keep_running: IoxAtomicBool,
+    state: Mutex<State>,
+}
+
+impl Transport {
+    pub(crate) fn new(id: u128, config: &Config, socket: UdpServer) -> Self {
+        Self {
+            id,
+            config: *config,
+            socket,
+            next_message_id: IoxAtomicU64::new(0),
+            keep_running: IoxAtomicBool::new(true),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    pub(crate) fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Spawns a thread that receives the datagrams of the unicast socket.
+    pub(crate) fn spawn_unicast_receiver(self: &Arc<Self>) -> Result<Thread, ThreadSpawnError> {
+        let transport = self.clone();
+        ThreadBuilder::new().spawn(move || transport.receive(&transport.socket))
+    }
+
+    /// Spawns a thread that receives the datagrams of the multicast group.
+    pub(crate) fn spawn_multicast_receiver(
+        self: &Arc<Self>,
+        socket: UdpServer,
+    ) -> Result<Thread, ThreadSpawnError> {
+        let transport = self.clone();
+        ThreadBuilder::new().spawn(move || transport.receive(&socket))
+    }
+
+    /// Informs all peers that the backend is shutting down and stops the receiver threads.
+    pub(crate) fn shutdown(&self) {
+        if let Err(e) = self.send_to_group(MessageKind::Departure, &[]) {
+            warn!(from self, "Failed to inform peers about the shutdown ({:?}).", e);
+        }
+        self.keep_running.store(false, Ordering::Relaxed);
+    }
+
+    /// Announces a local service to all hosts and answers future queries with it.
+    pub(crate) fn announce(
+        &self,
+        service_id: &ServiceId,
+        static_config: Vec<u8>,
+    ) -> Result<(), SendError> {
+        self.state()
+            .announcements
+            .insert(service_id.as_str().to_string(), static_config.clone());
+        self.send_to_group(MessageKind::Announcement, &static_config)
+    }
+
+    /// Requests all hosts to announce their services.
+    pub(crate) fn query(&self) -> Result<(), SendError> {
+        self.send_to_group(MessageKind::Query, &[])
+    }
+
+    /// Returns the serialized static configs of the remote services that were
+    /// announced since the last call.
+    pub(crate) fn take_discovered(&self) -> Vec<Vec<u8>> {
+        core::mem::take(&mut self.state().discovered)
+    }
+
+    /// Starts to buffer the messages of the given kind for the service. Returns
+    /// `false` when the messages are already buffered.
+    pub(crate) fn open_channel(&self, kind: MessageKind, service_id: &ServiceId) -> bool {
+        let key = (kind, service_id.as_str().to_string());
+        let mut state = self.state();
+        if state.channels.contains_key(&key) {
+            return false;
+        }
+
+        state.channels.insert(key, VecDeque::new());
+        true
+    }
+
+    /// Stops to buffer the messages of the given kind for the service.
+    pub(crate) fn close_channel(&self, kind: MessageKind, service_id: &ServiceId) {
+        self.state()
+            .channels
+            .remove(&(kind, service_id.as_str().to_string()));
+    }
+
+    /// Returns the oldest buffered message of the given kind for the service.
+    pub(crate) fn receive_from_channel(
+        &self,
+        kind: MessageKind,
+        service_id: &ServiceId,
+    ) -> Option<Message> {
+        self.state()
+            .channels
+            .get_mut(&(kind, service_id.as_str().to_string()))
+            .and_then(|queue| queue.pop_front())
+    }
+
+    /// Stores the most recently sent entries of a blackboard to answer queries
+    /// of hosts that join later.
+    pub(crate) fn store_blackboard(&self, service_id: &ServiceId, payload: Vec<u8>) {
+        self.state()
+            .blackboards
+            .insert(service_id.as_str().to_string(), payload);
+    }
+
+    /// Returns the ids of all known peers.
+    pub(crate) fn peers(&self) -> BTreeSet<u128> {
+        self.state().peers.keys().copied().collect()
+    }
+
+    /// Sends a message to all peers. Failing to reach a single peer does not
+    /// prevent the message from being sent to the others.
+    pub(crate) fn send_to_peers(&self, kind: MessageKind, payload: &[u8]) -> Result<(), SendError> {
+        let peers: Vec<Endpoint> = self.state().peers.values().copied().collect();
+        let datagrams = self.fragment(kind, payload)?;
+
+        for peer in peers {
+            if let Err(e) = self.send_datagrams(&datagrams, peer) {
+                warn!(from self, "Failed to send {:?} to {}:{} ({:?}).", kind, peer.address, peer.port, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends a message to the peer with the provided id.
+    pub(crate) fn send_to_peer(
+        &self,
+        peer: u128,
+        kind: MessageKind,
+        payload: &[u8],
+    ) -> Result<(), SendError> {
+        let endpoint = match self.state().peers.get(&peer) {
+            Some(endpoint) => *endpoint,
+            None => {
+                fail!(from self, with SendError::UnknownPeer,
+                    "Unable to send {:?} since the peer {:032x} is unknown.", kind, peer);
+            }
+        };
+
+        let datagrams = self.fragment(kind, payload)?;
+        self.send_datagrams(&datagrams, endpoint)
+    }
+
+    fn send_to_group(&self, kind: MessageKind, payload: &[u8]) -> Result<(), SendError> {
+        let datagrams = self.fragment(kind, payload)?;
+        self.send_datagrams(
+            &datagrams,
+            Endpoint {
+                address: self.config.multicast_group,
+                port: self.config.multicast_port,
+            },
+        )
+    }
+
+    fn fragment(&self, kind: MessageKind, payload: &[u8]) -> Result<Vec<Vec<u8>>, SendError> {
+        let message_id = self.next_message_id.fetch_add(1, Ordering::Relaxed);
+        Ok(fail!(
+            from self,
+            when fragment(kind, self.id, message_id, payload, self.config.max_datagram_size),
+            with SendError::Fragmentation,
+            "Unable to send {:?} with {} bytes since it could not be fragmented.", kind, payload.len()
+        ))
+    }
+
+    fn send_datagrams(&self, datagrams: &[Vec<u8>], endpoint: Endpoint) -> Result<(), SendError> {
+        for datagram in datagrams {
+            fail!(
+                from self,
+                when self.socket.send_to(datagram, endpoint.address, endpoint.port),
+                with SendError::DatagramSend,
+                "Failed to send datagram to {}:{}.", endpoint.address, endpoint.port
+            );
+        }
+
+        Ok(())
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // the state remains consistent even when a thread panicked while holding the lock
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn receive(&self, socket: &UdpServer) {
+        let mut reassembler = Reassembler::new(MAX_PENDING_MESSAGES);
+        let mut buffer = vec![0u8; RECEIVE_BUFFER_SIZE];
+
+        while self.keep_running.load(Ordering::Relaxed) {
+            let details = match socket.timed_receive_from(&mut buffer, RECEIVE_TIMEOUT) {
+                Ok(Some(details)) => details,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!(from self, "Failed to receive datagram ({:?}).", e);
+                    continue;
+                }
+            };
+
+            let datagram = &buffer[..details.number_of_bytes];
+            match Header::decode(datagram) {
+                Ok(header) if header.sender == self.id => continue,
+                Ok(_) => (),
+                Err(e) => {
+                    debug!(from self, "Discarding datagram from {}:{} ({:?}).",
+                        details.source_ip, details.source_port, e);
+                    continue;
+                }
+            }
+
+            match reassembler.insert(datagram) {
+                Ok(Some(message)) => self.handle(
+                    message,
+                    Endpoint {
+                        address: details.source_ip,
+                        port: details.source_port,
+                    },
+                ),
+                Ok(None) => (),
+                Err(e) => {
+                    debug!(from self, "Discarding datagram from {}:{} ({:?}).",
+                        details.source_ip, details.source_port, e);
+                }
+            }
+        }
+    }
+
+    fn handle(&self, message: Message, source: Endpoint) {
+        let mut replies = Vec::new();
+
+        {
+            let mut state = self.state();
+            if message.kind == MessageKind::Departure {
+                state.peers.remove(&message.sender);
+                return;
+            }
+
+            // all messages are sent from the unicast socket of the sender
+            state.peers.insert(message.sender, source);
+
+            match message.kind {
+                MessageKind::Query => {
+                    replies.extend(
+                        state
+                            .announcements
+                            .values()
+                            .map(|announcement| (MessageKind::Announcement, announcement.clone())),
+                    );
+                }
+                MessageKind::Announcement => state.discovered.push(message.payload),
+                MessageKind::BlackboardQuery => {
+                    if let Some(entries) = decode_service_payload(&message.payload)
+                        .and_then(|(service_id, _)| state.blackboards.get(service_id))
+                    {
+                        replies.push((MessageKind::Blackboard, entries.clone()));
+                    }
+                }
+                _ => {
+                    let service_id = match decode_service_payload(&message.payload) {
+                        Some((service_id, _)) => service_id.to_string(),
+                        None => {
+                            debug!(from self, "Discarding {:?} without a valid service id.", message.kind);
+                            return;
+                        }
+                    };
+
+                    let capacity = self.config.channel_capacity;
+                    match state.channels.get_mut(&(message.kind, service_id)) {
+                        Some(queue) => {
+                            if queue.len() >= capacity {
+                                warn!(from self,
+                                    "Discarding the oldest {:?} since the capacity of {} messages is exceeded.",
+                                    message.kind, capacity);
+                                queue.pop_front();
+                            }
+                            queue.push_back(message);
+                        }
+                        // the request is not served on this host, the sender
+                        // must not wait for responses from it
+                        None if message.kind == MessageKind::Request => {
+                            if let Some(completion) = encode_request_completion(&message.payload) {
+                                replies.push((MessageKind::Response, completion));
+                            }
+                        }
+                        None => (),
+                    }
+                }
+            }
+        }
+
+        for (kind, payload) in replies {
+            let result = self
+                .fragment(kind, &payload)
+                .and_then(|datagrams| self.send_datagrams(&datagrams, source));
+            if let Err(e) = result {
+                warn!(from self, "Failed to reply with {:?} to {}:{} ({:?}).",
+                    kind, source.address, source.port, e);
+            }
+        }
+    }
+}
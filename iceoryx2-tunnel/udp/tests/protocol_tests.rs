@@ -0,0 +1,234 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_tunnel_udp::protocol::*;
+
+const SENDER: u128 = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321;
+
+fn payload(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn fragment_small_message_creates_one_datagram() {
+    let payload = payload(100);
+
+    let datagrams = fragment(MessageKind::PublishSubscribe, SENDER, 0, &payload, 1472).unwrap();
+
+    assert_that!(datagrams, len 1);
+    assert_that!(datagrams[0], len HEADER_SIZE + payload.len());
+}
+
+#[test]
+fn fragment_empty_message_creates_one_datagram() {
+    let datagrams = fragment(MessageKind::Query, SENDER, 0, &[], 1472).unwrap();
+
+    assert_that!(datagrams, len 1);
+    assert_that!(datagrams[0], len HEADER_SIZE);
+}
+
+#[test]
+fn fragment_large_message_creates_datagrams_not_exceeding_max_size() {
+    const MAX_DATAGRAM_SIZE: usize = 128;
+    let payload = payload(1000);
+
+    let datagrams = fragment(
+        MessageKind::PublishSubscribe,
+        SENDER,
+        0,
+        &payload,
+        MAX_DATAGRAM_SIZE,
+    )
+    .unwrap();
+
+    let fragment_size = MAX_DATAGRAM_SIZE - HEADER_SIZE;
+    assert_that!(datagrams, len payload.len().div_ceil(fragment_size));
+    for (n, datagram) in datagrams.iter().enumerate() {
+        assert_that!(datagram.len(), le MAX_DATAGRAM_SIZE);
+
+        let header = Header::decode(datagram).unwrap();
+        assert_that!(header.kind, eq MessageKind::PublishSubscribe);
+        assert_that!(header.sender, eq SENDER);
+        assert_that!(header.fragment_index as usize, eq n);
+        assert_that!(header.fragment_count as usize, eq datagrams.len());
+    }
+}
+
+#[test]
+fn fragment_with_too_small_datagram_size_fails() {
+    let result = fragment(MessageKind::Event, SENDER, 0, &payload(10), HEADER_SIZE);
+
+    assert_that!(result.err(), eq Some(FragmentationError::DatagramSizeTooSmall));
+}
+
+#[test]
+fn fragment_with_too_many_fragments_fails() {
+    let result = fragment(
+        MessageKind::Event,
+        SENDER,
+        0,
+        &payload(u16::MAX as usize + 1),
+        HEADER_SIZE + 1,
+    );
+
+    assert_that!(result.err(), eq Some(FragmentationError::MessageTooLarge));
+}
+
+#[test]
+fn reassembler_restores_fragmented_message() {
+    let payload = payload(5000);
+    let datagrams = fragment(MessageKind::Response, SENDER, 7, &payload, 200).unwrap();
+    let mut sut = Reassembler::new(4);
+
+    let (last, others) = datagrams.split_last().unwrap();
+    for datagram in others {
+        assert_that!(sut.insert(datagram).unwrap(), eq None);
+    }
+    assert_that!(sut.number_of_pending_messages(), eq 1);
+
+    let message = sut.insert(last).unwrap().unwrap();
+    assert_that!(message.kind, eq MessageKind::Response);
+    assert_that!(message.sender, eq SENDER);
+    assert_that!(message.payload, eq payload);
+    assert_that!(sut.number_of_pending_messages(), eq 0);
+}
+
+#[test]
+fn reassembler_restores_message_from_fragments_in_any_order() {
+    let payload = payload(3000);
+    let mut datagrams = fragment(MessageKind::Blackboard, SENDER, 1, &payload, 300).unwrap();
+    datagrams.reverse();
+    datagrams.swap(1, 4);
+    let mut sut = Reassembler::new(4);
+
+    let mut message = None;
+    for datagram in &datagrams {
+        assert_that!(message, eq None);
+        message = sut.insert(datagram).unwrap();
+    }
+
+    assert_that!(message.unwrap().payload, eq payload);
+}
+
+#[test]
+fn reassembler_ignores_duplicated_fragments() {
+    let payload = payload(1000);
+    let datagrams = fragment(MessageKind::Request, SENDER, 1, &payload, 300).unwrap();
+    let mut sut = Reassembler::new(4);
+
+    for datagram in &datagrams[..datagrams.len() - 1] {
+        assert_that!(sut.insert(datagram).unwrap(), eq None);
+        assert_that!(sut.insert(datagram).unwrap(), eq None);
+    }
+
+    let message = sut.insert(datagrams.last().unwrap()).unwrap().unwrap();
+    assert_that!(message.payload, eq payload);
+}
+
+#[test]
+fn reassembler_separates_interleaved_messages() {
+    let payload_a = payload(1000);
+    let payload_b: Vec<u8> = payload(1000).into_iter().rev().collect();
+    let datagrams_a = fragment(MessageKind::PublishSubscribe, SENDER, 1, &payload_a, 300).unwrap();
+    let datagrams_b = fragment(MessageKind::PublishSubscribe, SENDER, 2, &payload_b, 300).unwrap();
+    let mut sut = Reassembler::new(4);
+
+    let mut messages = vec![];
+    for (a, b) in datagrams_a.iter().zip(datagrams_b.iter()) {
+        messages.extend(sut.insert(a).unwrap());
+        messages.extend(sut.insert(b).unwrap());
+    }
+
+    assert_that!(messages, len 2);
+    assert_that!(messages[0].payload, eq payload_a);
+    assert_that!(messages[1].payload, eq payload_b);
+}
+
+#[test]
+fn reassembler_discards_oldest_pending_message_when_full() {
+    const MAX_PENDING_MESSAGES: usize = 2;
+    let payload = payload(1000);
+    let mut sut = Reassembler::new(MAX_PENDING_MESSAGES);
+
+    let datagrams: Vec<Vec<Vec<u8>>> = (0..MAX_PENDING_MESSAGES as u64 + 1)
+        .map(|id| fragment(MessageKind::PublishSubscribe, SENDER, id, &payload, 300).unwrap())
+        .collect();
+    for message in &datagrams {
+        assert_that!(sut.insert(&message[0]).unwrap(), eq None);
+    }
+    assert_that!(sut.number_of_pending_messages(), eq MAX_PENDING_MESSAGES);
+
+    // the first message was discarded, the remaining fragments start a new message
+    for datagram in &datagrams[0][1..] {
+        assert_that!(sut.insert(datagram).unwrap(), eq None);
+    }
+
+    // the last message is still complete
+    let mut message = None;
+    for datagram in &datagrams[MAX_PENDING_MESSAGES][1..] {
+        message = sut.insert(datagram).unwrap();
+    }
+    assert_that!(message.unwrap().payload, eq payload);
+}
+
+#[test]
+fn reassembler_rejects_invalid_datagrams() {
+    let mut sut = Reassembler::new(4);
+    let mut datagram = fragment(MessageKind::Event, SENDER, 0, &payload(10), 1472)
+        .unwrap()
+        .remove(0);
+
+    assert_that!(sut.insert(&datagram[..HEADER_SIZE - 1]).err(), eq Some(ReassemblyError::InvalidHeader));
+
+    datagram[0] = b'X';
+    assert_that!(sut.insert(&datagram).err(), eq Some(ReassemblyError::InvalidHeader));
+}
+
+#[test]
+fn reassembler_rejects_fragment_with_out_of_range_index() {
+    let mut sut = Reassembler::new(4);
+    let mut datagram = fragment(MessageKind::Event, SENDER, 0, &payload(10), 1472)
+        .unwrap()
+        .remove(0);
+
+    // fragment_index = fragment_count
+    datagram[30..32].copy_from_slice(&1u16.to_le_bytes());
+    assert_that!(sut.insert(&datagram).err(), eq Some(ReassemblyError::InvalidFragment));
+}
+
+#[test]
+fn request_completion_answers_request_of_same_service() {
+    let service_id = b"some_service_id";
+    let request_id = 42u64.to_le_bytes();
+    let mut request = vec![service_id.len() as u8];
+    request.extend_from_slice(service_id);
+    request.extend_from_slice(&request_id);
+    request.extend_from_slice(&payload(10));
+
+    let sut = encode_request_completion(&request).unwrap();
+
+    let (decoded_service_id, decoded) = decode_service_payload(&sut).unwrap();
+    assert_that!(decoded_service_id.as_bytes(), eq service_id.as_slice());
+    assert_that!(&decoded[..REQUEST_ID_SIZE], eq request_id.as_slice());
+    assert_that!(decoded[REQUEST_ID_SIZE], eq REQUEST_COMPLETION);
+    assert_that!(decoded.len(), eq REQUEST_ID_SIZE + 1);
+}
+
+#[test]
+fn request_completion_of_malformed_request_fails() {
+    assert_that!(encode_request_completion(&[]), is_none);
+    assert_that!(
+        encode_request_completion(&[3, b'a', b'b', b'c', 1, 2]),
+        is_none
+    );
+}
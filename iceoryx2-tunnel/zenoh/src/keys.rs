@@ -31,3 +31,31 @@ pub fn publish_subscribe(service_id: &ServiceId) -> String {
 pub fn event(service_id: &ServiceId) -> String {
     format!("iox2/event/{}", service_id.as_str())
 }
+
+pub fn requests(service_id: &ServiceId) -> String {
+    format!("iox2/request_response/{}/requests", service_id.as_str())
+}
+
+pub fn responses(service_id: &ServiceId, origin: u128) -> String {
+    format!(
+        "iox2/request_response/{}/responses/{:032x}",
+        service_id.as_str(),
+        origin
+    )
+}
+
+pub fn request_response_hosts(service_id: &ServiceId) -> String {
+    format!("iox2/request_response/{}/hosts/*", service_id.as_str())
+}
+
+pub fn request_response_host(service_id: &ServiceId, origin: u128) -> String {
+    format!(
+        "iox2/request_response/{}/hosts/{:032x}",
+        service_id.as_str(),
+        origin
+    )
+}
+
+pub fn blackboard(service_id: &ServiceId) -> String {
+    format!("iox2/blackboard/{}/entries", service_id.as_str())
+}
@@ -0,0 +1,288 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::{Arc, Mutex};
+
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::Service;
+use iceoryx2_bb_log::{error, fail, trace};
+use iceoryx2_tunnel_backend::traits::{BlackboardRelay, RelayBuilder};
+use iceoryx2_tunnel_backend::types::blackboard::Entry;
+use zenoh::handlers::{FifoChannel, FifoChannelHandler};
+use zenoh::pubsub::{Publisher, Subscriber};
+use zenoh::qos::Reliability;
+use zenoh::query::{Queryable, Reply};
+use zenoh::sample::{Locality, Sample};
+use zenoh::{Session, Wait};
+
+use crate::keys;
+
+type EncodedEntries = Vec<(Vec<u8>, TypeDetail, Vec<u8>)>;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    PublisherDeclaration,
+    SubscriberDeclaration,
+    QueryableDeclaration,
+    EntriesQuery,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    Serialization,
+    EntriesPut,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    EntriesReceive,
+    Deserialization,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+#[derive(Debug)]
+pub struct Builder<'a, S: Service> {
+    session: &'a Session,
+    static_config: &'a StaticConfig,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<'a, S: Service> Builder<'a, S> {
+    pub fn new(session: &'a Session, static_config: &'a StaticConfig) -> Builder<'a, S> {
+        Builder {
+            session,
+            static_config,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: Service> RelayBuilder for Builder<'a, S> {
+    type CreationError = CreationError;
+    type Relay = Relay<S>;
+
+    fn create(self) -> Result<Self::Relay, Self::CreationError> {
+        let key = keys::blackboard(self.static_config.service_id());
+
+        let publisher = fail!(
+            from self,
+            when self.session
+                .declare_publisher(key.clone())
+                .allowed_destination(Locality::Remote)
+                .reliability(Reliability::Reliable)
+                .wait(),
+            with CreationError::PublisherDeclaration,
+            "Failed to create zenoh publisher for blackboard entries"
+        );
+
+        // TODO(correctness): Make handler type and properties configurable
+        let subscriber = fail!(
+            from self,
+            when self.session
+                .declare_subscriber(key.clone())
+                .with(FifoChannel::new(10))
+                .allowed_origin(Locality::Remote)
+                .wait(),
+            with CreationError::SubscriberDeclaration,
+            "Failed to create zenoh subscriber for blackboard entries"
+        );
+
+        // Set up a queryable to provide the last sent entries to hosts that join later.
+        let last_sent_entries: Arc<Mutex<Option<Vec<u8>>>> = Arc::new(Mutex::new(None));
+        let queryable = {
+            let key = key.clone();
+            let last_sent_entries = last_sent_entries.clone();
+            fail!(
+                from self,
+                when self.session
+                    .declare_queryable(key.clone())
+                    .callback(move |query| {
+                        let entries = match last_sent_entries.lock() {
+                            Ok(entries) => entries.clone(),
+                            Err(_) => None,
+                        };
+                        if let Some(entries) = entries {
+                            let _ = query
+                                .reply(key.clone(), entries)
+                                .wait()
+                                .inspect_err(|e| {
+                                    error!("Failed to reply with blackboard entries {}: {}", key, e);
+                                });
+                        }
+                    })
+                    .allowed_origin(Locality::Remote)
+                    .wait(),
+                with CreationError::QueryableDeclaration,
+                "Failed to declare queryable for blackboard entries"
+            )
+        };
+
+        // Make query immediately - replies processed in first `receive()` call
+        let replies = fail!(
+            from self,
+            when self.session
+                .get(key)
+                .allowed_destination(Locality::Remote)
+                .wait(),
+            with CreationError::EntriesQuery,
+            "Failed to query blackboard entries from remote hosts"
+        );
+
+        Ok(Relay {
+            static_config: self.static_config.clone(),
+            publisher,
+            subscriber,
+            _queryable: queryable,
+            replies,
+            last_sent_entries,
+            _phantom: core::marker::PhantomData,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Relay<S: Service> {
+    static_config: StaticConfig,
+    publisher: Publisher<'static>,
+    subscriber: Subscriber<FifoChannelHandler<Sample>>,
+    _queryable: Queryable<()>,
+    replies: FifoChannelHandler<Reply>,
+    last_sent_entries: Arc<Mutex<Option<Vec<u8>>>>,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<S: Service> Relay<S> {
+    fn decode(&self, sample: &Sample) -> Result<Vec<Entry>, ReceiveError> {
+        let entries: EncodedEntries = fail!(
+            from self,
+            when serde_json::from_slice(&sample.payload().to_bytes()),
+            with ReceiveError::Deserialization,
+            "Failed to deserialize blackboard entries"
+        );
+
+        Ok(entries
+            .into_iter()
+            .map(|(key, value_type_details, value)| Entry {
+                key,
+                value_type_details,
+                value,
+            })
+            .collect())
+    }
+}
+
+impl<S: Service> BlackboardRelay<S> for Relay<S> {
+    type SendError = SendError;
+    type ReceiveError = ReceiveError;
+
+    fn send(&self, entries: &[Entry]) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending {}({})",
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let encoded: Vec<(&[u8], &TypeDetail, &[u8])> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.key.as_slice(),
+                    &entry.value_type_details,
+                    entry.value.as_slice(),
+                )
+            })
+            .collect();
+        let payload = fail!(
+            from self,
+            when serde_json::to_vec(&encoded),
+            with SendError::Serialization,
+            "Failed to serialize blackboard entries"
+        );
+
+        fail!(
+            from self,
+            when self.publisher.put(payload.clone()).wait(),
+            with SendError::EntriesPut,
+            "Failed to propagate blackboard entries to zenoh"
+        );
+
+        if let Ok(mut last_sent_entries) = self.last_sent_entries.lock() {
+            *last_sent_entries = Some(payload);
+        }
+
+        Ok(())
+    }
+
+    fn receive(&self) -> Result<Option<Vec<Entry>>, Self::ReceiveError> {
+        let mut entries = None;
+
+        // Replies to the initial query are older than any update received via the subscriber.
+        for reply in self.replies.drain() {
+            match reply.result() {
+                Ok(sample) => entries = Some(self.decode(sample)?),
+                Err(e) => {
+                    error!(from self, "Erroneous reply to blackboard entries query: {:?}", e);
+                }
+            }
+        }
+
+        loop {
+            let sample = fail!(
+                from self,
+                when self.subscriber.try_recv(),
+                with ReceiveError::EntriesReceive,
+                "Failed to receive blackboard entries from zenoh"
+            );
+
+            match sample {
+                Some(sample) => entries = Some(self.decode(&sample)?),
+                None => break,
+            }
+        }
+
+        if entries.is_some() {
+            trace!(
+                from self,
+                "Received {}({})",
+                self.static_config.messaging_pattern(),
+                self.static_config.name()
+            );
+        }
+
+        Ok(entries)
+    }
+}
@@ -14,7 +14,7 @@ use iceoryx2::service::{static_config::StaticConfig, Service};
 use iceoryx2_tunnel_backend::traits::RelayFactory;
 use zenoh::Session;
 
-use crate::relays::{event, publish_subscribe};
+use crate::relays::{blackboard, event, publish_subscribe, request_response};
 
 /// Factory for creating relay builders.
 ///
@@ -38,6 +38,8 @@ impl<'session, S: Service> Factory<'session, S> {
 impl<'session, S: Service> RelayFactory<S> for Factory<'session, S> {
     type PublishSubscribeRelay = publish_subscribe::Relay<S>;
     type EventRelay = event::Relay<S>;
+    type RequestResponseRelay = request_response::Relay<S>;
+    type BlackboardRelay = blackboard::Relay<S>;
 
     type PublishSubscribeBuilder<'config>
         = publish_subscribe::Builder<'config, S>
@@ -49,6 +51,16 @@ impl<'session, S: Service> RelayFactory<S> for Factory<'session, S> {
     where
         Self: 'config;
 
+    type RequestResponseBuilder<'config>
+        = request_response::Builder<'config, S>
+    where
+        Self: 'config;
+
+    type BlackboardBuilder<'config>
+        = blackboard::Builder<'config, S>
+    where
+        Self: 'config;
+
     fn publish_subscribe<'config>(
         &self,
         static_config: &'config StaticConfig,
@@ -65,4 +77,24 @@ impl<'session, S: Service> RelayFactory<S> for Factory<'session, S> {
     {
         event::Builder::new(self.session, static_config)
     }
+
+    fn request_response<'config>(
+        &self,
+        static_config: &'config StaticConfig,
+    ) -> Self::RequestResponseBuilder<'config>
+    where
+        Self: 'config,
+    {
+        request_response::Builder::new(self.session, static_config)
+    }
+
+    fn blackboard<'config>(
+        &self,
+        static_config: &'config StaticConfig,
+    ) -> Self::BlackboardBuilder<'config>
+    where
+        Self: 'config,
+    {
+        blackboard::Builder::new(self.session, static_config)
+    }
 }
@@ -10,8 +10,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod blackboard;
 pub mod event;
 mod factory;
 pub mod publish_subscribe;
+pub mod request_response;
 
 pub use factory::*;
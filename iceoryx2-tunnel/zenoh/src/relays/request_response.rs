@@ -0,0 +1,555 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::Service;
+use iceoryx2_bb_log::{fail, trace, warn};
+use iceoryx2_tunnel_backend::traits::{RelayBuilder, RequestResponseRelay};
+use iceoryx2_tunnel_backend::types::request_response::{
+    ActiveRequest, RemoteRequestId, RequestCompletionFn, RequestId, RequestLoanFn, RequestMut,
+    Response, ResponseLoanFn, ResponseMut,
+};
+use zenoh::bytes::ZBytes;
+use zenoh::handlers::{FifoChannel, FifoChannelHandler};
+use zenoh::liveliness::LivelinessToken;
+use zenoh::pubsub::{Publisher, Subscriber};
+use zenoh::qos::Reliability;
+use zenoh::sample::{Locality, Sample, SampleKind};
+use zenoh::{Session, Wait};
+
+use crate::keys;
+
+const ORIGIN_SIZE: usize = core::mem::size_of::<u128>();
+const REQUEST_ID_SIZE: usize = core::mem::size_of::<RequestId>();
+
+// Marks the kind of message in the attachment of samples published to the
+// response key, the completion of a request is sent in-band so that it is
+// received after all responses of the request. The attachment also carries
+// the origin of the responding host since a request is completed by each host
+// it was sent to.
+const RESPONSE: u8 = 0;
+const REQUEST_COMPLETION: u8 = 1;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    PublisherDeclaration,
+    SubscriberDeclaration,
+    LivelinessTokenDeclaration,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SendError {
+    RequestPut,
+    ResponsePut,
+    RequestCompletionPut,
+}
+
+impl core::fmt::Display for SendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveError {
+    RequestReceive,
+    ResponseReceive,
+    InvalidRequest,
+    InvalidResponse,
+    IceoryxLoan,
+}
+
+impl core::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveError {}
+
+#[derive(Debug)]
+pub struct Builder<'a, S: Service> {
+    session: &'a Session,
+    static_config: &'a StaticConfig,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<'a, S: Service> Builder<'a, S> {
+    pub fn new(session: &'a Session, static_config: &'a StaticConfig) -> Builder<'a, S> {
+        Builder {
+            session,
+            static_config,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: Service> RelayBuilder for Builder<'a, S> {
+    type CreationError = CreationError;
+    type Relay = Relay<S>;
+
+    fn create(self) -> Result<Self::Relay, Self::CreationError> {
+        let service_id = self.static_config.service_id();
+        let origin = u128::from_le_bytes(self.session.zid().to_le_bytes());
+
+        let request_publisher = fail!(
+            from self,
+            when self.session
+                .declare_publisher(keys::requests(service_id))
+                .allowed_destination(Locality::Remote)
+                .reliability(Reliability::Reliable)
+                .wait(),
+            with CreationError::PublisherDeclaration,
+            "Failed to create zenoh publisher for requests"
+        );
+
+        // TODO(correctness): Make handler type and properties configurable
+        let request_subscriber = fail!(
+            from self,
+            when self.session
+                .declare_subscriber(keys::requests(service_id))
+                .with(FifoChannel::new(10))
+                .allowed_origin(Locality::Remote)
+                .wait(),
+            with CreationError::SubscriberDeclaration,
+            "Failed to create zenoh subscriber for requests"
+        );
+
+        let response_subscriber = fail!(
+            from self,
+            when self.session
+                .declare_subscriber(keys::responses(service_id, origin))
+                .with(FifoChannel::new(10))
+                .allowed_origin(Locality::Remote)
+                .wait(),
+            with CreationError::SubscriberDeclaration,
+            "Failed to create zenoh subscriber for responses"
+        );
+
+        let host_token = fail!(
+            from self,
+            when self.session
+                .liveliness()
+                .declare_token(keys::request_response_host(service_id, origin))
+                .wait(),
+            with CreationError::LivelinessTokenDeclaration,
+            "Failed to declare zenoh liveliness token for the request-response relay"
+        );
+
+        // Track the remote hosts that have a relay for the service, a request
+        // is released once all hosts it was sent to completed it or are gone.
+        let hosts: Arc<Mutex<BTreeSet<u128>>> = Arc::new(Mutex::new(BTreeSet::new()));
+        let host_subscriber = {
+            let hosts = hosts.clone();
+            fail!(
+                from self,
+                when self.session
+                    .liveliness()
+                    .declare_subscriber(keys::request_response_hosts(service_id))
+                    .history(true)
+                    .callback(move |sample| {
+                        let host = sample
+                            .key_expr()
+                            .as_str()
+                            .rsplit('/')
+                            .next()
+                            .and_then(|host| u128::from_str_radix(host, 16).ok());
+                        let host = match host {
+                            Some(host) if host != origin => host,
+                            _ => return,
+                        };
+
+                        let mut hosts = hosts.lock().unwrap_or_else(|e| e.into_inner());
+                        match sample.kind() {
+                            SampleKind::Put => hosts.insert(host),
+                            SampleKind::Delete => hosts.remove(&host),
+                        };
+                    })
+                    .wait(),
+                with CreationError::SubscriberDeclaration,
+                "Failed to create zenoh liveliness subscriber for request-response hosts"
+            )
+        };
+
+        Ok(Relay {
+            static_config: self.static_config.clone(),
+            session: self.session.clone(),
+            origin,
+            request_publisher,
+            request_subscriber,
+            response_subscriber,
+            hosts,
+            pending_hosts: Mutex::new(BTreeMap::new()),
+            _host_token: host_token,
+            _host_subscriber: host_subscriber,
+            _phantom: core::marker::PhantomData,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Relay<S: Service> {
+    static_config: StaticConfig,
+    session: Session,
+    origin: u128,
+    request_publisher: Publisher<'static>,
+    request_subscriber: Subscriber<FifoChannelHandler<Sample>>,
+    response_subscriber: Subscriber<FifoChannelHandler<Sample>>,
+    hosts: Arc<Mutex<BTreeSet<u128>>>,
+    // The remote hosts of each sent request that did not yet complete it.
+    pending_hosts: Mutex<BTreeMap<RequestId, BTreeSet<u128>>>,
+    _host_token: LivelinessToken,
+    _host_subscriber: Subscriber<()>,
+    _phantom: core::marker::PhantomData<S>,
+}
+
+impl<S: Service> Relay<S> {
+    fn decode_remote_request_id(sample: &Sample) -> Option<RemoteRequestId> {
+        let attachment = sample.attachment()?.to_bytes();
+        if attachment.len() != ORIGIN_SIZE + REQUEST_ID_SIZE {
+            return None;
+        }
+
+        let (origin, request_id) = attachment.split_at(ORIGIN_SIZE);
+        Some(RemoteRequestId {
+            origin: u128::from_le_bytes(origin.try_into().ok()?),
+            request_id: RequestId::from_le_bytes(request_id.try_into().ok()?),
+        })
+    }
+
+    fn decode_request_id(sample: &Sample) -> Option<(RequestId, u8, u128)> {
+        let attachment = sample.attachment()?.to_bytes();
+        if attachment.len() != REQUEST_ID_SIZE + 1 + ORIGIN_SIZE {
+            return None;
+        }
+
+        let (request_id, kind) = attachment.split_at(REQUEST_ID_SIZE);
+        let (kind, host) = kind.split_at(1);
+        Some((
+            RequestId::from_le_bytes(request_id.try_into().ok()?),
+            kind[0],
+            u128::from_le_bytes(host.try_into().ok()?),
+        ))
+    }
+
+    /// Marks the request as completed by the host and releases it once all
+    /// hosts it was sent to completed it.
+    fn complete_request(
+        &self,
+        request_id: RequestId,
+        host: u128,
+        complete: &mut RequestCompletionFn<'_>,
+    ) {
+        let mut pending_hosts = self.pending_hosts.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(hosts) = pending_hosts.get_mut(&request_id) {
+            hosts.remove(&host);
+            if hosts.is_empty() {
+                pending_hosts.remove(&request_id);
+                complete(request_id);
+            }
+        }
+    }
+
+    /// Releases the requests whose remaining hosts are gone.
+    fn complete_requests_of_departed_hosts(&self, complete: &mut RequestCompletionFn<'_>) {
+        let hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        self.pending_hosts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|request_id, pending_hosts| {
+                pending_hosts.retain(|host| hosts.contains(host));
+                if pending_hosts.is_empty() {
+                    complete(*request_id);
+                    return false;
+                }
+                true
+            });
+    }
+
+    fn put_to_responses(
+        &self,
+        request_id: &RemoteRequestId,
+        kind: u8,
+        payload: &[u8],
+    ) -> zenoh::Result<()> {
+        let mut attachment = [0u8; REQUEST_ID_SIZE + 1 + ORIGIN_SIZE];
+        attachment[..REQUEST_ID_SIZE].copy_from_slice(&request_id.request_id.to_le_bytes());
+        attachment[REQUEST_ID_SIZE] = kind;
+        attachment[REQUEST_ID_SIZE + 1..].copy_from_slice(&self.origin.to_le_bytes());
+
+        self.session
+            .put(
+                keys::responses(self.static_config.service_id(), request_id.origin),
+                ZBytes::from(payload),
+            )
+            .attachment(ZBytes::from(attachment.as_slice()))
+            .allowed_destination(Locality::Remote)
+            .reliability(Reliability::Reliable)
+            .wait()
+    }
+}
+
+impl<S: Service> RequestResponseRelay<S> for Relay<S> {
+    type SendError = SendError;
+    type ReceiveError = ReceiveError;
+
+    fn send_request(
+        &self,
+        request_id: RequestId,
+        request: &ActiveRequest<S>,
+    ) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending request {} of {}({})",
+            request_id,
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let payload = request.payload();
+        let payload =
+            unsafe { core::slice::from_raw_parts(payload.as_ptr().cast::<u8>(), payload.len()) };
+
+        let mut attachment = [0u8; ORIGIN_SIZE + REQUEST_ID_SIZE];
+        attachment[..ORIGIN_SIZE].copy_from_slice(&self.origin.to_le_bytes());
+        attachment[ORIGIN_SIZE..].copy_from_slice(&request_id.to_le_bytes());
+
+        // Requests sent while no remote host is known are released when the
+        // client is gone since no host will complete them.
+        let hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner()).clone();
+
+        fail!(
+            from self,
+            when self.request_publisher
+                .put(ZBytes::from(payload))
+                .attachment(ZBytes::from(attachment.as_slice()))
+                .wait(),
+            with SendError::RequestPut,
+            "Failed to propagate request to zenoh"
+        );
+
+        if !hosts.is_empty() {
+            self.pending_hosts
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(request_id, hosts);
+        }
+
+        Ok(())
+    }
+
+    fn receive_request<LoanError>(
+        &self,
+        loan: &mut RequestLoanFn<'_, S, LoanError>,
+    ) -> Result<Option<(RemoteRequestId, RequestMut<S>)>, Self::ReceiveError> {
+        let zenoh_sample = fail!(
+            from self,
+            when self.request_subscriber.try_recv(),
+            with ReceiveError::RequestReceive,
+            "Failed to receive request from zenoh"
+        );
+
+        let zenoh_sample = match zenoh_sample {
+            Some(zenoh_sample) => zenoh_sample,
+            None => return Ok(None),
+        };
+
+        let remote_request_id = match Self::decode_remote_request_id(&zenoh_sample) {
+            Some(remote_request_id) => remote_request_id,
+            None => {
+                fail!(from self, with ReceiveError::InvalidRequest,
+                    "Received a request without a valid request id from zenoh");
+            }
+        };
+
+        trace!(
+            from self,
+            "Ingesting request {:?} of {}({})",
+            remote_request_id,
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let zenoh_payload = zenoh_sample.payload();
+        let mut iceoryx_request = fail!(
+            from self,
+            when loan(zenoh_payload.len()),
+            with ReceiveError::IceoryxLoan,
+            "Failed to loan request from iceoryx"
+        );
+        let iceoryx_payload = iceoryx_request.payload_mut();
+
+        debug_assert!(
+            iceoryx_payload.len() >= zenoh_payload.len(),
+            "loan_size ({}) is too small for received payload ({})",
+            iceoryx_payload.len(),
+            zenoh_payload.len()
+        );
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                zenoh_payload.to_bytes().as_ptr(),
+                iceoryx_payload.as_mut_ptr().cast(),
+                zenoh_payload.len(),
+            );
+        }
+
+        Ok(Some((remote_request_id, unsafe {
+            iceoryx_request.assume_init()
+        })))
+    }
+
+    fn send_response(
+        &self,
+        request_id: &RemoteRequestId,
+        response: Response<S>,
+    ) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending response to {:?} of {}({})",
+            request_id,
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        let payload = response.payload();
+        let payload =
+            unsafe { core::slice::from_raw_parts(payload.as_ptr().cast::<u8>(), payload.len()) };
+
+        fail!(
+            from self,
+            when self.put_to_responses(request_id, RESPONSE, payload),
+            with SendError::ResponsePut,
+            "Failed to propagate response to zenoh"
+        );
+
+        Ok(())
+    }
+
+    fn send_request_completion(&self, request_id: &RemoteRequestId) -> Result<(), Self::SendError> {
+        trace!(
+            from self,
+            "Sending completion of {:?} of {}({})",
+            request_id,
+            self.static_config.messaging_pattern(),
+            self.static_config.name()
+        );
+
+        fail!(
+            from self,
+            when self.put_to_responses(request_id, REQUEST_COMPLETION, &[]),
+            with SendError::RequestCompletionPut,
+            "Failed to propagate request completion to zenoh"
+        );
+
+        Ok(())
+    }
+
+    fn receive_response<LoanError>(
+        &self,
+        loan: &mut ResponseLoanFn<'_, S, LoanError>,
+        complete: &mut RequestCompletionFn<'_>,
+    ) -> Result<Option<ResponseMut<S>>, Self::ReceiveError> {
+        self.complete_requests_of_departed_hosts(complete);
+
+        loop {
+            let zenoh_sample = fail!(
+                from self,
+                when self.response_subscriber.try_recv(),
+                with ReceiveError::ResponseReceive,
+                "Failed to receive response from zenoh"
+            );
+
+            let zenoh_sample = match zenoh_sample {
+                Some(zenoh_sample) => zenoh_sample,
+                None => return Ok(None),
+            };
+
+            let request_id = match Self::decode_request_id(&zenoh_sample) {
+                Some((request_id, RESPONSE, _)) => request_id,
+                Some((request_id, REQUEST_COMPLETION, host)) => {
+                    trace!(
+                        from self,
+                        "Ingesting completion of request {} by {:032x} of {}({})",
+                        request_id,
+                        host,
+                        self.static_config.messaging_pattern(),
+                        self.static_config.name()
+                    );
+                    self.complete_request(request_id, host, complete);
+                    continue;
+                }
+                _ => {
+                    fail!(from self, with ReceiveError::InvalidResponse,
+                        "Received a response without a valid request id from zenoh");
+                }
+            };
+
+            let zenoh_payload = zenoh_sample.payload();
+            let iceoryx_response = fail!(
+                from self,
+                when loan(request_id, zenoh_payload.len()),
+                with ReceiveError::IceoryxLoan,
+                "Failed to loan response from iceoryx"
+            );
+
+            let mut iceoryx_response = match iceoryx_response {
+                Some(iceoryx_response) => iceoryx_response,
+                None => {
+                    warn!(from self,
+                        "Discarding response since the request {} is no longer pending.", request_id);
+                    continue;
+                }
+            };
+
+            trace!(
+                from self,
+                "Ingesting response to request {} of {}({})",
+                request_id,
+                self.static_config.messaging_pattern(),
+                self.static_config.name()
+            );
+
+            let iceoryx_payload = iceoryx_response.payload_mut();
+
+            debug_assert!(
+                iceoryx_payload.len() >= zenoh_payload.len(),
+                "loan_size ({}) is too small for received payload ({})",
+                iceoryx_payload.len(),
+                zenoh_payload.len()
+            );
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    zenoh_payload.to_bytes().as_ptr(),
+                    iceoryx_payload.as_mut_ptr().cast(),
+                    zenoh_payload.len(),
+                );
+            }
+
+            return Ok(Some(unsafe { iceoryx_response.assume_init() }));
+        }
+    }
+}
@@ -15,8 +15,12 @@ use iceoryx2_bb_conformance_test_macros::conformance_test_module;
 #[allow(clippy::module_inception)]
 #[conformance_test_module]
 pub mod active_request {
+    use core::time::Duration;
+
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
+    use iceoryx2::port::{LoanError, SendError};
+    use iceoryx2::prelude::UnableToDeliverStrategy;
     use iceoryx2::service::port_factory::request_response::PortFactory;
     use iceoryx2::testing::*;
     use iceoryx2::{
@@ -71,6 +75,35 @@ pub mod active_request {
         assert_that!(sut.is_connected(), eq false);
     }
 
+    #[conformance_test]
+    pub fn is_cancelled_when_pending_response_is_dropped<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let pending_response = test.client.send_copy(123).unwrap();
+
+        let sut = test.server.receive().unwrap().unwrap();
+        assert_that!(sut.is_cancelled(), eq false);
+
+        drop(pending_response);
+        assert_that!(sut.has_timed_out(), eq false);
+        assert_that!(sut.is_cancelled(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn is_cancelled_when_timeout_has_passed<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(10);
+        let test = TestFixture::<Sut>::new();
+        let _pending_response = test.client.send_copy_with_timeout(123, TIMEOUT).unwrap();
+
+        let sut = test.server.receive().unwrap().unwrap();
+        assert_that!(sut.has_timed_out(), eq false);
+        assert_that!(sut.is_cancelled(), eq false);
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(sut.has_timed_out(), eq true);
+        assert_that!(sut.is_cancelled(), eq true);
+        assert_that!(sut.is_connected(), eq true);
+    }
+
     #[conformance_test]
     pub fn is_connected_until_pending_response_is_dropped_multiple_connections<Sut: Service>() {
         let test = TestFixture::<Sut>::new();
@@ -308,4 +341,103 @@ pub mod active_request {
         assert_that!(*sut.user_header(), eq USER_HEADER);
         assert_that!(*sut.payload(), eq PAYLOAD);
     }
+
+    #[conformance_test]
+    pub fn available_credits_are_none_without_flow_control<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let _pending_response = test.client.send_copy(123).unwrap();
+        let sut = test.server.receive().unwrap().unwrap();
+
+        assert_that!(sut.header().response_credits(), is_none);
+        assert_that!(sut.available_credits(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn loan_fails_when_client_has_not_granted_further_credits<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service
+            .server_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+
+        let mut request = client.loan_uninit().unwrap();
+        request.set_response_credits(2);
+        let pending_response = request.write_payload(123).send().unwrap();
+
+        let sut = server.receive().unwrap().unwrap();
+        assert_that!(sut.header().response_credits(), eq Some(2));
+        assert_that!(sut.available_credits(), eq Some(2));
+
+        assert_that!(sut.send_copy(1), is_ok);
+        assert_that!(sut.send_copy(2), is_ok);
+        assert_that!(sut.available_credits(), eq Some(0));
+        assert_that!(sut.loan_uninit().err(), eq Some(LoanError::ExceedsGrantedCredits));
+
+        pending_response.grant_credits(1);
+        assert_that!(sut.available_credits(), eq Some(1));
+        assert_that!(sut.send_copy(3), is_ok);
+        assert_that!(sut.loan_uninit().err(), eq Some(LoanError::ExceedsGrantedCredits));
+    }
+
+    #[conformance_test]
+    pub fn dropping_unsent_response_returns_credit<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let mut request = test.client.loan_uninit().unwrap();
+        request.set_response_credits(1);
+        let _pending_response = request.write_payload(123).send().unwrap();
+
+        let sut = test.server.receive().unwrap().unwrap();
+        let response = sut.loan_uninit().unwrap();
+        assert_that!(sut.available_credits(), eq Some(0));
+
+        drop(response);
+        assert_that!(sut.available_credits(), eq Some(1));
+        assert_that!(sut.loan_uninit(), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn loan_fails_after_final_response_was_sent<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let _pending_response = test.client.send_copy(123).unwrap();
+
+        let sut = test.server.receive().unwrap().unwrap();
+        assert_that!(sut.send_copy(1), is_ok);
+        assert_that!(
+            sut.loan_uninit().unwrap().write_payload(2).send_final(),
+            is_ok
+        );
+
+        assert_that!(sut.loan_uninit().err(), eq Some(LoanError::ResponseStreamFinished));
+        let stream_finished = SendError::LoanError(LoanError::ResponseStreamFinished);
+        assert_that!(sut.send_copy(3).err(), eq Some(stream_finished));
+    }
+
+    #[conformance_test]
+    pub fn loaned_response_cannot_be_sent_after_final_response<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let pending_response = test.client.send_copy(123).unwrap();
+
+        let sut = test.server.receive().unwrap().unwrap();
+        let response_1 = sut.loan_uninit().unwrap().write_payload(1);
+        let response_2 = sut.loan_uninit().unwrap().write_payload(2);
+
+        let stream_finished = SendError::LoanError(LoanError::ResponseStreamFinished);
+        assert_that!(response_1.send_final(), is_ok);
+        assert_that!(response_2.send().err(), eq Some(stream_finished));
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 1);
+        assert_that!(response.header().is_final(), eq true);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+        assert_that!(pending_response.is_finished(), eq true);
+    }
 }
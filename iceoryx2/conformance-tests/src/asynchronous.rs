@@ -0,0 +1,435 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_conformance_test_macros::conformance_test_module;
+
+#[allow(clippy::module_inception)]
+#[conformance_test_module]
+pub mod asynchronous {
+    use core::future::{poll_fn, Future};
+    use core::pin::{pin, Pin};
+    use core::sync::atomic::Ordering;
+    use core::task::{Context, Poll, Waker};
+    use core::time::Duration;
+    use std::sync::Arc;
+    use std::task::Wake;
+    use std::thread::{self, Thread};
+
+    use futures_core::Stream;
+    use iceoryx2::asynchronous::{AsyncCreateError, AsyncFd, AsyncListener, AsyncReceiver};
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_conformance_test_macros::conformance_test;
+    use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+    use iceoryx2_bb_posix::file_descriptor_set::{
+        FileDescriptorSet, FileEvent, SynchronousMultiplexing,
+    };
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn thread_waker() -> Waker {
+        Waker::from(Arc::new(ThreadWaker(thread::current())))
+    }
+
+    // minimal single future executor, calls `on_first_pending` after the future returned
+    // the first time `Poll::Pending` and parks until the future is woken up
+    fn block_on<F: Future>(future: F, on_first_pending: impl FnOnce()) -> F::Output {
+        let mut future = pin!(future);
+        let waker = thread_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut on_first_pending = Some(on_first_pending);
+
+        loop {
+            if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+                return value;
+            }
+
+            if let Some(call) = on_first_pending.take() {
+                call();
+            }
+
+            thread::park();
+        }
+    }
+
+    struct MonitoredFd(FileDescriptor);
+
+    impl FileDescriptorBased for MonitoredFd {
+        fn file_descriptor(&self) -> &FileDescriptor {
+            &self.0
+        }
+    }
+
+    impl SynchronousMultiplexing for MonitoredFd {}
+
+    // minimal reactor that spawns a thread for every pending poll which wakes the task as
+    // soon as the file descriptor becomes readable
+    struct ThreadReactorFd {
+        fd: i32,
+        is_active: Arc<IoxAtomicBool>,
+    }
+
+    impl Drop for ThreadReactorFd {
+        fn drop(&mut self) {
+            self.is_active.store(false, Ordering::Relaxed);
+        }
+    }
+
+    impl AsyncFd for ThreadReactorFd {
+        fn register(fd: &FileDescriptor) -> Result<Self, AsyncCreateError> {
+            Ok(Self {
+                fd: unsafe { fd.native_handle() },
+                is_active: Arc::new(IoxAtomicBool::new(true)),
+            })
+        }
+
+        fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+            let waker = cx.waker().clone();
+            let is_active = self.is_active.clone();
+            let fd = self.fd;
+
+            thread::spawn(move || {
+                let fd = MonitoredFd(FileDescriptor::non_owning_new(fd).unwrap());
+                let fd_set = FileDescriptorSet::new();
+                let _guard = fd_set.add(&fd).unwrap();
+
+                while is_active.load(Ordering::Relaxed) {
+                    match fd_set.timed_wait(POLL_INTERVAL, FileEvent::Read, |_| {}) {
+                        Ok(0) => continue,
+                        _ => break,
+                    }
+                }
+
+                waker.wake();
+            });
+
+            Poll::Pending
+        }
+    }
+
+    #[conformance_test]
+    pub fn async_listener_returns_pending_without_event<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let mut sut = AsyncListener::<Sut, ThreadReactorFd>::new(
+            service.listener_builder().create().unwrap(),
+        )
+        .unwrap();
+
+        let mut wait_one = sut.wait_one();
+        let waker = thread_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert_that!(Pin::new(&mut wait_one).poll(&mut cx).is_pending(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn async_listener_wakes_up_on_notification<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let notifier = service.notifier_builder().create().unwrap();
+        let mut sut = AsyncListener::<Sut, ThreadReactorFd>::new(
+            service.listener_builder().create().unwrap(),
+        )
+        .unwrap();
+
+        let event_id = block_on(sut.wait_one(), || {
+            notifier
+                .notify_with_custom_event_id(EventId::new(12))
+                .unwrap();
+        });
+
+        assert_that!(event_id, eq Ok(EventId::new(12)));
+        assert_that!(sut.listener().try_wait_one().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn async_listener_stream_yields_notified_event_ids<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .event_id_max_value(16)
+            .create()
+            .unwrap();
+
+        let notifier = service.notifier_builder().create().unwrap();
+        let sut = AsyncListener::<Sut, ThreadReactorFd>::new(
+            service.listener_builder().create().unwrap(),
+        )
+        .unwrap();
+        let mut sut = pin!(sut);
+
+        let mut received_ids = vec![];
+        for id in [3, 7, 11] {
+            let event_id = block_on(poll_fn(|cx| sut.as_mut().poll_next(cx)), || {
+                notifier
+                    .notify_with_custom_event_id(EventId::new(id))
+                    .unwrap();
+            });
+            received_ids.push(event_id.unwrap().unwrap().as_value());
+        }
+
+        assert_that!(received_ids, eq vec![3, 7, 11]);
+    }
+
+    #[conformance_test]
+    pub fn async_subscriber_wakes_up_when_sample_arrives<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let mut sut =
+            AsyncReceiver::<_, ThreadReactorFd>::new(pubsub.subscriber_builder().create().unwrap())
+                .unwrap();
+
+        let sample = block_on(sut.receive(), || {
+            publisher.send_copy(8192).unwrap();
+        });
+
+        assert_that!(*sample.unwrap().unwrap(), eq 8192);
+    }
+
+    #[conformance_test]
+    pub fn async_subscriber_wakes_up_when_publisher_is_created_later<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut sut =
+            AsyncReceiver::<_, ThreadReactorFd>::new(pubsub.subscriber_builder().create().unwrap())
+                .unwrap();
+
+        let mut publisher = None;
+        let sample = block_on(sut.receive(), || {
+            publisher = Some(pubsub.publisher_builder().create().unwrap());
+            publisher.as_ref().unwrap().send_copy(4096).unwrap();
+        });
+
+        assert_that!(*sample.unwrap().unwrap(), eq 4096);
+    }
+
+    #[conformance_test]
+    pub fn async_subscriber_wakes_up_after_it_was_recreated<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let mut sut =
+            AsyncReceiver::<_, ThreadReactorFd>::new(pubsub.subscriber_builder().create().unwrap())
+                .unwrap();
+
+        let sample = block_on(sut.receive(), || {
+            publisher.send_copy(1).unwrap();
+        });
+        assert_that!(*sample.unwrap().unwrap(), eq 1);
+
+        let mut sut = AsyncReceiver::<_, ThreadReactorFd>::new(sut.into_inner()).unwrap();
+
+        let sample = block_on(sut.receive(), || {
+            publisher.send_copy(2).unwrap();
+        });
+        assert_that!(*sample.unwrap().unwrap(), eq 2);
+    }
+
+    #[conformance_test]
+    pub fn async_subscriber_stream_yields_samples<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let sut =
+            AsyncReceiver::<_, ThreadReactorFd>::new(pubsub.subscriber_builder().create().unwrap())
+                .unwrap();
+        let mut sut = pin!(sut);
+
+        let mut received_values = vec![];
+        for value in [5, 6, 7] {
+            let sample = block_on(poll_fn(|cx| sut.as_mut().poll_next(cx)), || {
+                publisher.send_copy(value).unwrap();
+            });
+            received_values.push(*sample.unwrap().unwrap());
+        }
+
+        assert_that!(received_values, eq vec![5, 6, 7]);
+    }
+
+    #[conformance_test]
+    pub fn async_subscriber_receives_samples_that_were_sent_before<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let pubsub = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(3)
+            .create()
+            .unwrap();
+
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let mut sut =
+            AsyncReceiver::<_, ThreadReactorFd>::new(pubsub.subscriber_builder().create().unwrap())
+                .unwrap();
+
+        for value in 0..3 {
+            publisher.send_copy(value).unwrap();
+        }
+
+        for value in 0..3 {
+            let sample = block_on(sut.receive(), || {});
+            assert_that!(*sample.unwrap().unwrap(), eq value);
+        }
+
+        let mut receive = sut.receive();
+        let waker = thread_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert_that!(Pin::new(&mut receive).poll(&mut cx).is_pending(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn async_server_wakes_up_when_request_arrives<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let mut sut =
+            AsyncReceiver::<_, ThreadReactorFd>::new(service.server_builder().create().unwrap())
+                .unwrap();
+
+        let mut pending_response = None;
+        let active_request = block_on(sut.receive(), || {
+            pending_response = Some(client.send_copy(42).unwrap());
+        });
+
+        assert_that!(pending_response, is_some);
+        assert_that!(*active_request.unwrap().unwrap(), eq 42);
+    }
+
+    #[conformance_test]
+    pub fn async_pending_response_ends_when_server_disconnects<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+        let mut sut =
+            AsyncReceiver::<_, ThreadReactorFd>::new(client.send_copy(1).unwrap()).unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+
+        let response = block_on(sut.receive(), || {
+            active_request.send_copy(2).unwrap();
+        });
+        assert_that!(*response.unwrap().unwrap(), eq 2);
+
+        let response = block_on(sut.receive(), || {
+            active_request.send_copy(3).unwrap();
+        });
+        assert_that!(*response.unwrap().unwrap(), eq 3);
+
+        let response = block_on(sut.receive(), || {
+            drop(active_request);
+        });
+        assert_that!(response.unwrap(), is_none);
+    }
+}
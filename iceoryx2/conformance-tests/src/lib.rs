@@ -11,6 +11,8 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub mod active_request;
+#[cfg(feature = "async")]
+pub mod asynchronous;
 pub mod client;
 pub mod listener;
 pub mod node;
@@ -19,8 +21,10 @@ pub mod notifier;
 pub mod pending_response;
 pub mod publisher;
 pub mod reader;
+pub mod rpc;
 pub mod sample;
 pub mod sample_mut;
+pub mod serialized;
 pub mod server;
 pub mod service;
 pub mod service_blackboard;
@@ -29,5 +33,6 @@ pub mod service_publish_subscribe;
 pub mod service_request_response;
 pub mod service_request_response_builder;
 pub mod subscriber;
+pub mod type_schema;
 pub mod waitset;
 pub mod writer;
@@ -67,6 +67,7 @@ impl Test for ZeroCopy {
 #[conformance_test_module]
 pub mod node_death {
     use iceoryx2::node::{CleanupState, NodeState};
+    use iceoryx2::port::writer::EntryHandleMutError;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
     use iceoryx2_bb_conformance_test_macros::conformance_test;
@@ -483,6 +484,56 @@ pub mod node_death {
         }
     }
 
+    #[conformance_test]
+    pub fn blackboard_entry_ownership_is_released_after_owner_node_crash<S: Test>() {
+        let _watchdog = Watchdog::new();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_service_name();
+
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let good_service = good_node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .max_writers(2)
+            .create()
+            .unwrap();
+        let good_writer = good_service.writer_builder().create().unwrap();
+
+        let mut bad_node = S::create_test_node(&config).node;
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let bad_writer = bad_service.writer_builder().create().unwrap();
+        let bad_entry_handle_mut = bad_writer.entry::<u64>(&0).unwrap();
+
+        let res = good_writer.entry::<u64>(&0);
+        assert_that!(res.err(), eq Some(EntryHandleMutError::EntryOwnedByAnotherWriter));
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(bad_entry_handle_mut);
+        core::mem::forget(bad_writer);
+        core::mem::forget(bad_service);
+
+        // the ownership is kept until the dead node is cleaned up
+        let res = good_writer.entry::<u64>(&0);
+        assert_that!(res.err(), eq Some(EntryHandleMutError::EntryOwnedByAnotherWriter));
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        let entry_handle_mut = good_writer.entry::<u64>(&0).unwrap();
+        entry_handle_mut.update_with_copy(42);
+
+        let reader = good_service.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u64>(&0).unwrap().get(), eq 42);
+    }
+
     #[conformance_test]
     pub fn opened_blackboard_can_be_accessed_after_creator_node_crash<S: Test>() {
         let _watchdog = Watchdog::new();
@@ -36,6 +36,8 @@ pub mod notifier {
     pub fn notify_error_display_works<S: Service>() {
         assert_that!(
             format!("{}", NotifierNotifyError::EventIdOutOfBounds), eq "NotifierNotifyError::EventIdOutOfBounds");
+        assert_that!(
+            format!("{}", NotifierNotifyError::PayloadExceedsMaxSize), eq "NotifierNotifyError::PayloadExceedsMaxSize");
     }
 
     #[conformance_test]
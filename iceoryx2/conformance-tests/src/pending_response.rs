@@ -15,8 +15,11 @@ use iceoryx2_bb_conformance_test_macros::conformance_test_module;
 #[allow(clippy::module_inception)]
 #[conformance_test_module]
 pub mod pending_response {
+    use core::time::Duration;
+
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
+    use iceoryx2::port::ReceiveError;
     use iceoryx2::service::port_factory::request_response::PortFactory;
     use iceoryx2::testing::*;
     use iceoryx2::{
@@ -140,4 +143,145 @@ pub mod pending_response {
         assert_that!(sut.has_response(), eq false);
         assert_that!(sut.receive().unwrap(), is_none);
     }
+
+    #[conformance_test]
+    pub fn request_without_timeout_does_not_time_out<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy(9).unwrap();
+
+        assert_that!(sut.header().timeout(), is_none);
+        assert_that!(sut.header().deadline(), is_none);
+        assert_that!(sut.has_timed_out(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn timeout_and_deadline_are_stored_in_request_header<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(3600);
+        let test = TestFixture::<Sut>::new();
+        let mut request = test.client.loan_uninit().unwrap();
+        request.set_timeout(TIMEOUT);
+        assert_that!(request.header().timeout(), eq Some(TIMEOUT));
+        assert_that!(request.header().deadline(), is_none);
+
+        let sut = request.write_payload(9).send().unwrap();
+        assert_that!(sut.header().timeout(), eq Some(TIMEOUT));
+        assert_that!(sut.header().deadline(), is_some);
+        assert_that!(sut.has_timed_out(), eq false);
+
+        let active_request = test.server_1.receive().unwrap().unwrap();
+        assert_that!(active_request.header().timeout(), eq Some(TIMEOUT));
+        assert_that!(active_request.header().deadline().unwrap().as_duration(), eq
+                     sut.header().deadline().unwrap().as_duration());
+    }
+
+    #[conformance_test]
+    pub fn receive_succeeds_before_timeout_has_passed<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test
+            .client
+            .send_copy_with_timeout(9, Duration::from_secs(3600))
+            .unwrap();
+
+        let active_request = test.server_1.receive().unwrap().unwrap();
+        active_request.send_copy(8).unwrap();
+
+        let response = sut.receive().unwrap();
+        assert_that!(response, is_some);
+        assert_that!(*response.unwrap(), eq 8);
+    }
+
+    #[conformance_test]
+    pub fn receive_fails_with_timeout_error_when_timeout_has_passed<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(10);
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy_with_timeout(9, TIMEOUT).unwrap();
+
+        let active_request = test.server_1.receive().unwrap().unwrap();
+        std::thread::sleep(TIMEOUT * 2);
+        active_request.send_copy(8).unwrap();
+
+        assert_that!(sut.has_timed_out(), eq true);
+        assert_that!(sut.receive().err(), eq Some(ReceiveError::RequestTimedOut));
+        assert_that!(sut.is_connected(), eq false);
+        assert_that!(active_request.is_connected(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn is_finished_when_every_server_sent_final_response<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy(123).unwrap();
+        let active_request_1 = test.server_1.receive().unwrap().unwrap();
+        let active_request_2 = test.server_2.receive().unwrap().unwrap();
+        assert_that!(sut.is_finished(), eq false);
+
+        active_request_1.send_copy(1).unwrap();
+        active_request_1
+            .loan_uninit()
+            .unwrap()
+            .write_payload(2)
+            .send_final()
+            .unwrap();
+
+        let response = sut.receive().unwrap().unwrap();
+        assert_that!(response.header().is_final(), eq false);
+        let response = sut.receive().unwrap().unwrap();
+        assert_that!(response.header().is_final(), eq true);
+        assert_that!(sut.is_finished(), eq false);
+
+        active_request_2
+            .loan_uninit()
+            .unwrap()
+            .write_payload(3)
+            .send_final()
+            .unwrap();
+        assert_that!(sut.is_finished(), eq false);
+        assert_that!(sut.receive().unwrap(), is_some);
+        assert_that!(sut.is_finished(), eq true);
+        assert_that!(sut.is_connected(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn iteration_ends_after_every_server_sent_final_response<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy(123).unwrap();
+        let active_request_1 = test.server_1.receive().unwrap().unwrap();
+        let active_request_2 = test.server_2.receive().unwrap().unwrap();
+
+        active_request_1.send_copy(1).unwrap();
+        active_request_2.send_copy(2).unwrap();
+        active_request_1
+            .loan_uninit()
+            .unwrap()
+            .write_payload(3)
+            .send_final()
+            .unwrap();
+        active_request_2
+            .loan_uninit()
+            .unwrap()
+            .write_payload(4)
+            .send_final()
+            .unwrap();
+
+        let mut payloads: Vec<u64> = sut.iter().map(|r| *r.unwrap()).collect();
+        payloads.sort();
+        assert_that!(payloads, eq vec![1, 2, 3, 4]);
+        assert_that!(sut.iter().next(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn iteration_ends_when_every_server_disconnected<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy(123).unwrap();
+        let active_request_1 = test.server_1.receive().unwrap().unwrap();
+        let active_request_2 = test.server_2.receive().unwrap().unwrap();
+
+        active_request_1.send_copy(1).unwrap();
+        active_request_2.send_copy(2).unwrap();
+        drop(active_request_1);
+        drop(active_request_2);
+
+        assert_that!(sut.is_finished(), eq false);
+        assert_that!(sut.iter().count(), eq 2);
+        assert_that!(sut.is_finished(), eq true);
+    }
 }
@@ -29,9 +29,10 @@ pub mod publisher {
     use iceoryx2_bb_conformance_test_macros::conformance_test;
     use iceoryx2_bb_posix::barrier::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
-    use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
+    use iceoryx2_pal_posix::posix::POSIX_SUPPORT_MEMORY_LOCK;
 
     const TIMEOUT: Duration = Duration::from_millis(25);
 
@@ -62,6 +63,95 @@ pub mod publisher {
         Ok(())
     }
 
+    #[conformance_test]
+    pub fn publisher_with_required_memory_lock_policy_works<Sut: Service>() {
+        test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+        let service_name = generate_name().unwrap();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .memory_lock_policy(MemoryLockPolicy::Required)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        for len in [1, 128, 4096] {
+            let sample = sut.loan_slice_uninit(len).unwrap();
+            let sample = sample.write_from_fn(|n| n as u64);
+            assert_that!(sample.send(), eq Ok(1));
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len len);
+            assert_that!(sample.payload()[len - 1], eq(len - 1) as u64);
+        }
+    }
+
+    #[conformance_test]
+    pub fn publisher_with_numa_placement_works<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        // on single node systems the placement is ignored
+        let sut = service
+            .publisher_builder()
+            .numa_placement(NumaPlacement::NodeOfCpus(CpuSet::from_cpus(&[0])))
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.send_copy(8912), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 8912);
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap()
+            .dynamic_details
+            .unwrap();
+        assert_that!(details.publishers, len 1);
+        assert_that!(details.publishers[0].publisher_id, eq sut.id());
+        assert_that!(details.servers, len 0);
+    }
+
+    #[conformance_test]
+    pub fn publisher_uses_memory_lock_policy_from_config<Sut: Service>(
+    ) -> core::result::Result<(), Box<dyn core::error::Error>> {
+        let service_name = generate_name()?;
+        let mut config = testing::generate_isolated_config();
+        config
+            .defaults
+            .publish_subscribe
+            .publisher_memory_lock_policy = MemoryLockPolicy::BestEffort;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_copy(1234), eq Ok(1));
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1234);
+
+        Ok(())
+    }
+
     #[conformance_test]
     pub fn loan_initializes_sample_with_default<Sut: Service>(
     ) -> core::result::Result<(), Box<dyn core::error::Error>> {
@@ -521,6 +611,8 @@ pub mod publisher {
             format!("{}", PublisherCreateError::ExceedsMaxSupportedPublishers), eq "PublisherCreateError::ExceedsMaxSupportedPublishers");
         assert_that!(
             format!("{}", PublisherCreateError::UnableToCreateDataSegment), eq "PublisherCreateError::UnableToCreateDataSegment");
+        assert_that!(
+            format!("{}", PublisherCreateError::UnableToLockDataSegment), eq "PublisherCreateError::UnableToLockDataSegment");
     }
 
     #[conformance_test]
@@ -531,6 +623,8 @@ pub mod publisher {
             format!("{}", LoanError::ExceedsMaxLoans), eq "LoanError::ExceedsMaxLoans");
         assert_that!(
             format!("{}", LoanError::ExceedsMaxLoanSize), eq "LoanError::ExceedsMaxLoanSize");
+        assert_that!(
+            format!("{}", LoanError::ExceedsGrantedCredits), eq "LoanError::ExceedsGrantedCredits");
         assert_that!(
             format!("{}", LoanError::InternalFailure), eq "LoanError::InternalFailure");
     }
@@ -273,4 +273,46 @@ pub mod reader {
             eq EntryHandleError::EntryDoesNotExist
         );
     }
+
+    #[conformance_test]
+    pub fn handle_cannot_be_acquired_for_slice_entry_with_custom_key_type<Sut: Service>() {
+        type KeyType = u64;
+        let key: KeyType = 3;
+        let key_ptr: *const KeyType = &key;
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _service = node
+            .service_builder(&service_name)
+            .blackboard_creator::<KeyType>()
+            .add_slice::<u8>(key, 8, b"abc")
+            .create()
+            .unwrap();
+
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .blackboard_opener::<CustomKeyMarker>()
+                .__internal_set_key_type_details(&TypeDetail::new::<KeyType>(
+                    TypeVariant::FixedSize,
+                ))
+                .__internal_set_key_eq_cmp_func(Box::new(|lhs, rhs| {
+                    KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::default_key_eq_comparison::<KeyType>(
+                        lhs, rhs,
+                    )
+                }))
+                .open()
+                .unwrap()
+        };
+        let reader = sut.reader_builder().create().unwrap();
+
+        let type_details = TypeDetail::new::<u8>(TypeVariant::Dynamic);
+        let entry_handle = unsafe { reader.__internal_entry(key_ptr as *const u8, &type_details) };
+        assert_that!(entry_handle, is_err);
+        assert_that!(
+            entry_handle.err().unwrap(),
+            eq EntryHandleError::EntryDoesNotExist
+        );
+    }
 }
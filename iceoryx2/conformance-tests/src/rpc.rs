@@ -0,0 +1,204 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_conformance_test_macros::conformance_test_module;
+
+#[allow(clippy::module_inception)]
+#[conformance_test_module]
+pub mod rpc {
+    use iceoryx2::node::{Node, NodeBuilder};
+    use iceoryx2::rpc::*;
+    use iceoryx2::service::attribute::AttributeSpecifier;
+    use iceoryx2::service::builder::request_response::RequestResponseOpenError;
+    use iceoryx2::service::port_factory::PortFactory;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_conformance_test_macros::conformance_test;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[rpc_service(version = 3)]
+    pub trait Calculator {
+        fn add(&mut self, lhs: u64, rhs: u64) -> u64;
+        fn reset(&mut self);
+        fn number_of_calls(&self) -> usize;
+    }
+
+    #[derive(Default)]
+    struct TestCalculator {
+        number_of_calls: usize,
+    }
+
+    impl Calculator for TestCalculator {
+        fn add(&mut self, lhs: u64, rhs: u64) -> u64 {
+            self.number_of_calls += 1;
+            lhs + rhs
+        }
+
+        fn reset(&mut self) {
+            self.number_of_calls = 0;
+        }
+
+        fn number_of_calls(&self) -> usize {
+            self.number_of_calls
+        }
+    }
+
+    struct TestFixture<Sut: Service> {
+        node: Node<Sut>,
+        service: RpcPortFactory<Sut, CalculatorService>,
+    }
+
+    impl<Sut: Service> TestFixture<Sut> {
+        fn new() -> Self {
+            let config = generate_isolated_config();
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let service = CalculatorService::create(&node, &generate_service_name()).unwrap();
+
+            Self { node, service }
+        }
+    }
+
+    #[conformance_test]
+    pub fn client_call_returns_value_of_dispatched_method<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let server = test.service.server_builder().create().unwrap();
+        let client = CalculatorClient::new(test.service.client_builder().create().unwrap());
+        let mut calculator = TestCalculator::default();
+
+        let pending_add = client.add(12, 30).unwrap();
+        let pending_number_of_calls = client.number_of_calls().unwrap();
+        assert_that!(server.dispatch(&mut calculator), eq Ok(2));
+
+        assert_that!(*pending_add.receive().unwrap().unwrap(), eq 42);
+        assert_that!(*pending_number_of_calls.receive().unwrap().unwrap(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn method_without_return_value_can_be_called<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let server = test.service.server_builder().create().unwrap();
+        let client = CalculatorClient::new(test.service.client_builder().create().unwrap());
+        let mut calculator = TestCalculator { number_of_calls: 5 };
+
+        let pending_reset = client.reset().unwrap();
+        assert_that!(server.dispatch(&mut calculator), eq Ok(1));
+
+        assert_that!(pending_reset.receive().unwrap(), is_some);
+        assert_that!(calculator.number_of_calls, eq 0);
+    }
+
+    #[conformance_test]
+    pub fn dispatch_without_requests_handles_nothing<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let server = test.service.server_builder().create().unwrap();
+        let _client = CalculatorClient::new(test.service.client_builder().create().unwrap());
+        let mut calculator = TestCalculator::default();
+
+        assert_that!(server.dispatch(&mut calculator), eq Ok(0));
+    }
+
+    #[conformance_test]
+    pub fn request_header_contains_method_id<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let server = test.service.server_builder().create().unwrap();
+        let client = CalculatorClient::new(test.service.client_builder().create().unwrap());
+
+        let _pending_add = client.add(1, 2).unwrap();
+        let _pending_reset = client.reset().unwrap();
+        let _pending_number_of_calls = client.number_of_calls().unwrap();
+
+        for expected_method_id in 0..3 {
+            let active_request = server.receive().unwrap().unwrap();
+            assert_that!(active_request.user_header().method_id(), eq expected_method_id);
+            assert_that!(
+                CalculatorService::method_id(active_request.payload()),
+                eq expected_method_id
+            );
+        }
+    }
+
+    #[conformance_test]
+    pub fn dispatch_discards_request_when_method_id_does_not_match_payload<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let server = test.service.server_builder().create().unwrap();
+        let client = CalculatorClient::new(test.service.client_builder().create().unwrap());
+        let mut calculator = TestCalculator::default();
+
+        let mut request = client.caller().loan_uninit().unwrap();
+        *request.user_header_mut() = RpcRequestHeader::new(1);
+        let pending_mismatch = request
+            .write_payload(CalculatorRequest::Add { lhs: 1, rhs: 2 })
+            .send()
+            .unwrap();
+        let pending_add = client.add(3, 4).unwrap();
+
+        assert_that!(server.dispatch(&mut calculator), eq Ok(1));
+        assert_that!(calculator.number_of_calls, eq 1);
+
+        assert_that!(pending_mismatch.receive().unwrap(), is_none);
+        assert_that!(pending_mismatch.is_connected(), eq false);
+        assert_that!(*pending_add.receive().unwrap().unwrap(), eq 7);
+    }
+
+    #[conformance_test]
+    pub fn receive_fails_when_response_belongs_to_another_method<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let server = test.service.server_builder().create().unwrap();
+        let client = CalculatorClient::new(test.service.client_builder().create().unwrap());
+
+        let pending_add = client.add(1, 2).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request
+            .loan_uninit()
+            .unwrap()
+            .write_payload(CalculatorResponse::NumberOfCalls(3))
+            .send()
+            .unwrap();
+
+        assert_that!(pending_add.receive().err(), eq Some(RpcReceiveError::UnexpectedResponse));
+    }
+
+    #[conformance_test]
+    pub fn open_succeeds_with_same_interface_version<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+
+        let sut = CalculatorService::open(&test.node, test.service.name());
+        assert_that!(sut, is_ok);
+        let sut = CalculatorService::open_or_create(&test.node, test.service.name());
+        assert_that!(sut, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_fails_with_different_interface_version<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_service_name();
+
+        let attributes = AttributeSpecifier::new()
+            .define(
+                &RPC_INTERFACE_ATTRIBUTE_KEY.try_into().unwrap(),
+                &CalculatorService::INTERFACE_NAME.try_into().unwrap(),
+            )
+            .unwrap()
+            .define(
+                &RPC_VERSION_ATTRIBUTE_KEY.try_into().unwrap(),
+                &"2".try_into().unwrap(),
+            )
+            .unwrap();
+        let _service = CalculatorService::service_builder(&node, &service_name)
+            .create_with_attributes(&attributes)
+            .unwrap();
+
+        let sut = CalculatorService::open(&node, &service_name);
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::IncompatibleAttributes));
+    }
+}
@@ -23,6 +23,7 @@ pub mod sample {
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
     use iceoryx2_bb_conformance_test_macros::conformance_test;
+    use iceoryx2_bb_posix::clock::Time;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
 
@@ -178,4 +179,74 @@ pub mod sample {
         assert_that!(*sample_1, eq PAYLOAD_1);
         assert_that!(*sample_2, eq PAYLOAD_2);
     }
+
+    #[conformance_test]
+    pub fn sequence_number_is_incremented_per_publisher<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        for n in 0..3 {
+            assert_that!(test_context.publisher_1.send_copy(n), eq Ok(1));
+            let sample = test_context.subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.sequence_number(), eq n);
+            assert_that!(sample.header().sequence_number(), eq n);
+        }
+
+        assert_that!(test_context.publisher_2.send_copy(0), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.sequence_number(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn send_timestamp_is_set_when_sample_is_sent<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        let before_loan = Time::now().unwrap();
+        let sample = test_context.publisher_1.loan_uninit().unwrap();
+        let after_loan = Time::now().unwrap();
+        assert_that!(sample.write_payload(1).send(), eq Ok(1));
+        let after_send = Time::now().unwrap();
+
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        let send_timestamp = sample.send_timestamp().as_duration();
+        assert_that!(send_timestamp, ge after_loan.as_duration());
+        assert_that!(send_timestamp, ge before_loan.as_duration());
+        assert_that!(send_timestamp, le after_send.as_duration());
+    }
+
+    #[conformance_test]
+    pub fn subscriber_reports_missed_samples_per_publisher<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.missed_samples(&publisher_1.id()), eq None);
+
+        assert_that!(publisher_1.send_copy(0), eq Ok(1));
+        assert_that!(publisher_2.send_copy(0), eq Ok(1));
+        while subscriber.receive().unwrap().is_some() {}
+        assert_that!(subscriber.missed_samples(&publisher_1.id()), eq Some(0));
+        assert_that!(subscriber.missed_samples(&publisher_2.id()), eq Some(0));
+
+        // the buffer holds 2 samples, the first 3 samples are overridden
+        for n in 1..6 {
+            assert_that!(publisher_1.send_copy(n), eq Ok(1));
+        }
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.sequence_number(), eq 4);
+        assert_that!(subscriber.missed_samples(&publisher_1.id()), eq Some(3));
+        assert_that!(subscriber.missed_samples(&publisher_2.id()), eq Some(0));
+    }
 }
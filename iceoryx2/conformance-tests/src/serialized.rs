@@ -0,0 +1,345 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_conformance_test_macros::conformance_test_module;
+
+#[allow(clippy::module_inception)]
+#[conformance_test_module]
+pub mod serialized {
+    use iceoryx2::prelude::*;
+    use iceoryx2::serialized::{
+        SerializedCreateError, SerializedPublisher, SerializedReceiveError, SerializedSubscriber,
+    };
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::static_config::message_type_details::TypeName;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_conformance_test_macros::conformance_test;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::serialize::cdr::Cdr;
+    use iceoryx2_cal::serialize::postcard::Postcard;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Telemetry {
+        sensor: String,
+        values: Vec<u64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct OtherTelemetry {
+        values: Vec<u64>,
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "serialized_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn telemetry(number_of_values: u64) -> Telemetry {
+        Telemetry {
+            sensor: "lidar".into(),
+            values: (0..number_of_values).collect(),
+        }
+    }
+
+    #[conformance_test]
+    pub fn serializer_is_stored_in_message_type_details<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let details = sut.static_config().message_type_details();
+        assert_that!(details.is_serialized(), eq true);
+        assert_that!(
+            details.serializer,
+            eq TypeName::try_from(core::any::type_name::<Postcard>()).unwrap()
+        );
+        assert_that!(
+            *details.payload.type_name(),
+            eq TypeName::try_from(core::any::type_name::<Telemetry>()).unwrap()
+        );
+        assert_that!(details.payload.size(), eq 1);
+        assert_that!(details.payload.alignment(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn open_with_same_serialized_payload_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_with_different_serializer_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Cdr>()
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[conformance_test]
+    pub fn open_with_different_serialized_payload_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<OtherTelemetry, Postcard>()
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[conformance_test]
+    pub fn open_without_serialized_payload_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[conformance_test]
+    pub fn create_serialized_port_fails_when_service_has_different_payload_type<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = SerializedPublisher::<_, Telemetry, Postcard>::new(
+            sut.publisher_builder().create().unwrap(),
+        );
+        assert_that!(publisher.err(), eq Some(SerializedCreateError::IncompatiblePayloadType));
+
+        let subscriber = SerializedSubscriber::<_, Telemetry, Postcard>::new(
+            sut.subscriber_builder().create().unwrap(),
+        );
+        assert_that!(subscriber.err(), eq Some(SerializedCreateError::IncompatiblePayloadType));
+    }
+
+    #[conformance_test]
+    pub fn create_serialized_port_fails_when_service_has_different_serializer<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let publisher = SerializedPublisher::<_, Telemetry, Cdr>::new(
+            sut.publisher_builder().create().unwrap(),
+        );
+        assert_that!(publisher.err(), eq Some(SerializedCreateError::IncompatibleSerializer));
+
+        let subscriber = SerializedSubscriber::<_, Telemetry, Cdr>::new(
+            sut.subscriber_builder().create().unwrap(),
+        );
+        assert_that!(subscriber.err(), eq Some(SerializedCreateError::IncompatibleSerializer));
+    }
+
+    #[conformance_test]
+    pub fn receive_without_sent_payload_returns_none<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let subscriber = SerializedSubscriber::<_, Telemetry, Postcard>::new(
+            sut.subscriber_builder().create().unwrap(),
+        )
+        .unwrap();
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn send_and_receive_serialized_payload_works<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let publisher = SerializedPublisher::<_, Telemetry, Postcard>::new(
+            sut.publisher_builder()
+                .initial_max_slice_len(256)
+                .create()
+                .unwrap(),
+        )
+        .unwrap();
+        let subscriber = SerializedSubscriber::<_, Telemetry, Postcard>::new(
+            sut.subscriber_builder().create().unwrap(),
+        )
+        .unwrap();
+
+        for n in 0..5 {
+            assert_that!(publisher.send(&telemetry(n)), eq Ok(1));
+            assert_that!(subscriber.receive().unwrap(), eq Some(telemetry(n)));
+            assert_that!(subscriber.receive().unwrap(), is_none);
+        }
+    }
+
+    #[conformance_test]
+    pub fn send_serialized_payload_larger_than_initial_slice_len_works_with_dynamic_allocation<
+        Sut: Service,
+    >() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Cdr>()
+            .create()
+            .unwrap();
+
+        let publisher = SerializedPublisher::<_, Telemetry, Cdr>::new(
+            sut.publisher_builder()
+                .initial_max_slice_len(8)
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create()
+                .unwrap(),
+        )
+        .unwrap();
+        let subscriber = SerializedSubscriber::<_, Telemetry, Cdr>::new(
+            sut.subscriber_builder().create().unwrap(),
+        )
+        .unwrap();
+
+        assert_that!(publisher.send(&telemetry(1024)), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap(), eq Some(telemetry(1024)));
+    }
+
+    #[conformance_test]
+    pub fn send_serialized_payload_larger_than_static_slice_len_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let publisher = SerializedPublisher::<_, Telemetry, Postcard>::new(
+            sut.publisher_builder()
+                .initial_max_slice_len(8)
+                .create()
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_that!(publisher.send(&telemetry(1024)), is_err);
+    }
+
+    #[conformance_test]
+    pub fn receive_invalid_serialized_payload_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .serialized_payload::<Telemetry, Postcard>()
+            .create()
+            .unwrap();
+
+        let publisher = SerializedPublisher::<_, Telemetry, Postcard>::new(
+            sut.publisher_builder()
+                .initial_max_slice_len(8)
+                .create()
+                .unwrap(),
+        )
+        .unwrap();
+        let subscriber = SerializedSubscriber::<_, Telemetry, Postcard>::new(
+            sut.subscriber_builder().create().unwrap(),
+        )
+        .unwrap();
+
+        let sample = publisher
+            .publisher()
+            .loan_slice_uninit(3)
+            .unwrap()
+            .write_from_slice(&[255, 255, 255]);
+        assert_that!(sample.send(), eq Ok(1));
+
+        assert_that!(
+            subscriber.receive().err(),
+            eq Some(SerializedReceiveError::DeserializationFailed)
+        );
+    }
+}
@@ -22,11 +22,15 @@ pub mod server {
     use iceoryx2::port::ReceiveError;
     use iceoryx2::prelude::*;
     use iceoryx2::service::port_factory::request_response::PortFactory;
+    use iceoryx2::service::port_factory::PortFactory as _;
     use iceoryx2::testing::*;
     use iceoryx2_bb_conformance_test_macros::conformance_test;
-    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
+    use iceoryx2_cal::event::Event;
     use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+    use iceoryx2_pal_posix::posix::POSIX_SUPPORT_MEMORY_LOCK;
 
     const TIMEOUT: Duration = Duration::from_millis(50);
 
@@ -82,6 +86,58 @@ pub mod server {
         assert_that!(*active_request, eq 1234);
     }
 
+    #[conformance_test]
+    pub fn server_with_required_memory_lock_policy_works<Sut: Service>() {
+        test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+        let (_node, service) = create_node_and_service::<Sut>();
+        let sut = service
+            .server_builder()
+            .memory_lock_policy(MemoryLockPolicy::Required)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1234).unwrap();
+        let active_request = sut.receive().unwrap().unwrap();
+        active_request.send_copy(5678).unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 5678);
+    }
+
+    #[conformance_test]
+    pub fn server_with_numa_placement_works<Sut: Service>() {
+        let (node, service) = create_node_and_service::<Sut>();
+        // on single node systems the placement is ignored
+        let sut = service
+            .server_builder()
+            .numa_placement(NumaPlacement::Node(0))
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1234).unwrap();
+        let active_request = sut.receive().unwrap().unwrap();
+        active_request.send_copy(5678).unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 5678);
+
+        let details = Sut::details(
+            service.name(),
+            node.config(),
+            MessagingPattern::RequestResponse,
+        )
+        .unwrap()
+        .unwrap()
+        .dynamic_details
+        .unwrap();
+        assert_that!(details.servers, len 1);
+        assert_that!(details.servers[0].server_id, eq sut.id());
+        assert_that!(details.publishers, len 0);
+    }
+
     #[conformance_test]
     pub fn receiving_requests_works_with_client_created_first<Sut: Service>() {
         let (_node, service) = create_node_and_service::<Sut>();
@@ -762,4 +818,134 @@ pub mod server {
             assert_that!(loans, len MAX_LOANED_RESPONSES * MAX_ACTIVE_REQUESTS * MAX_CLIENTS);
         }
     }
+
+    #[conformance_test]
+    pub fn server_without_request_cancellation_has_no_cancellation_signals<Sut: Service>() {
+        let (_node, service) = create_node_and_service::<Sut>();
+
+        let sut = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let _active_request = sut.receive().unwrap().unwrap();
+        drop(pending_response);
+
+        assert_that!(sut.cancellation_listener(), is_none);
+        assert_that!(sut.consume_cancellation_signals(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn server_receives_cancellation_signal_when_pending_response_is_dropped<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_request_cancellation(true)
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = sut.receive().unwrap().unwrap();
+        assert_that!(sut.consume_cancellation_signals(), eq false);
+
+        drop(pending_response);
+        assert_that!(sut.consume_cancellation_signals(), eq true);
+        assert_that!(active_request.is_cancelled(), eq true);
+        assert_that!(sut.consume_cancellation_signals(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn server_receives_no_cancellation_signal_for_finished_requests<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_request_cancellation(true)
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = sut.receive().unwrap().unwrap();
+        active_request.send_copy(1).unwrap();
+        drop(active_request);
+
+        assert_that!(pending_response.receive().unwrap(), is_some);
+        drop(pending_response);
+        assert_that!(sut.consume_cancellation_signals(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn server_receives_cancellation_signal_when_pending_response_times_out<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_request_cancellation(true)
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy_with_timeout(0, TIMEOUT).unwrap();
+        let active_request = sut.receive().unwrap().unwrap();
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(pending_response.receive().err(), eq Some(ReceiveError::RequestTimedOut));
+        assert_that!(sut.consume_cancellation_signals(), eq true);
+        assert_that!(active_request.is_cancelled(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn server_attached_to_waitset_wakes_up_on_cancellation<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_request_cancellation(true)
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = sut.receive().unwrap().unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset
+            .attach_notification(sut.cancellation_listener().unwrap())
+            .unwrap();
+
+        drop(pending_response);
+
+        let mut was_cancelled = false;
+        waitset
+            .wait_and_process_once_with_timeout(
+                |attachment_id| {
+                    if attachment_id.has_event_from(&guard) {
+                        was_cancelled =
+                            sut.consume_cancellation_signals() && active_request.is_cancelled();
+                    }
+                    CallbackProgression::Continue
+                },
+                TIMEOUT * 20,
+            )
+            .unwrap();
+
+        assert_that!(was_cancelled, eq true);
+    }
 }
@@ -23,19 +23,23 @@ pub mod service_blackboard {
     use iceoryx2::port::writer::*;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::blackboard::{
-        BlackboardCreateError, BlackboardOpenError, KeyMemory, KeyMemoryError,
+        BlackboardCreateError, BlackboardOpenError, BlackboardOpenOrCreateError, EntryInsertError,
+        KeyMemory, KeyMemoryError,
     };
+    use iceoryx2::service::builder::event::EventOpenError;
     use iceoryx2::service::builder::CustomKeyMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
     use iceoryx2_bb_conformance_test_macros::conformance_test;
     use iceoryx2_bb_container::string::*;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
-    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
+    use iceoryx2_cal::event::Event;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
     use std::sync::Arc;
     use std::sync::Barrier;
 
@@ -85,6 +89,52 @@ pub mod service_blackboard {
         assert_that!(*sut.name(), eq service_name);
     }
 
+    #[conformance_test]
+    pub fn list_keys_lists_all_keys<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .add::<i32>(5, -1)
+            .add::<u64>(9, 3)
+            .create()
+            .unwrap();
+
+        let mut keys = vec![];
+        sut.list_keys(|key| {
+            keys.push(*key);
+            CallbackProgression::Continue
+        });
+        keys.sort();
+
+        assert_that!(keys, eq vec![0, 5, 9]);
+    }
+
+    #[conformance_test]
+    pub fn list_keys_stops_when_callback_requests_it<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .add::<i32>(5, -1)
+            .create()
+            .unwrap();
+
+        let mut counter = 0;
+        sut.list_keys(|_| {
+            counter += 1;
+            CallbackProgression::Stop
+        });
+
+        assert_that!(counter, eq 1);
+    }
+
     #[conformance_test]
     pub fn creating_same_service_twice_fails<Sut: Service>() {
         let service_name = generate_name();
@@ -300,6 +350,38 @@ pub mod service_blackboard {
         assert_that!(sut2, is_ok);
     }
 
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_max_writers_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .max_writers(2)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .max_writers(3)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+    sut2.err().unwrap(), eq BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .max_writers(1)
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
     #[conformance_test]
     pub fn open_does_not_fail_when_service_owner_is_dropped<Sut: Service>() {
         let service_name = generate_name();
@@ -829,6 +911,74 @@ pub mod service_blackboard {
         assert_that!(reader, is_ok);
     }
 
+    #[conformance_test]
+    pub fn creating_max_supported_amount_of_writers_works<Sut: Service>() {
+        const MAX_WRITERS: usize = 4;
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .max_writers(MAX_WRITERS)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_writers(), eq MAX_WRITERS);
+
+        let mut writers = vec![];
+        for _ in 0..MAX_WRITERS {
+            let writer = sut.writer_builder().create();
+            assert_that!(writer, is_ok);
+            writers.push(writer);
+        }
+        assert_that!(sut.dynamic_config().number_of_writers(), eq MAX_WRITERS);
+
+        let writer = sut.writer_builder().create();
+        assert_that!(writer, is_err);
+        assert_that!(
+            writer.err().unwrap(), eq
+            WriterCreateError::ExceedsMaxSupportedWriters
+        );
+
+        writers.pop();
+        let writer = sut.writer_builder().create();
+        assert_that!(writer, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn max_writers_is_one_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_writers(), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn set_max_writers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .max_writers(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_writers(), eq 1);
+    }
+
     #[conformance_test]
     pub fn set_max_nodes_to_zero_adjusts_it_to_one<Sut: Service>() {
         let service_name = generate_name();
@@ -1193,6 +1343,8 @@ pub mod service_blackboard {
     "BlackboardOpenError::IncompatibleAttributes");
         assert_that!(format!("{}", BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders), eq
     "BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders");
+        assert_that!(format!("{}", BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters), eq
+    "BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters");
         assert_that!(format!("{}", BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes), eq
     "BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes");
         assert_that!(format!("{}", BlackboardOpenError::InsufficientPermissions), eq
@@ -1223,6 +1375,8 @@ pub mod service_blackboard {
     "BlackboardCreateError::IsBeingCreatedByAnotherInstance");
         assert_that!(format!("{}", BlackboardCreateError::NoEntriesProvided), eq
     "BlackboardCreateError::NoEntriesProvided");
+        assert_that!(format!("{}", BlackboardCreateError::InitialValueExceedsMaxSliceLen), eq
+    "BlackboardCreateError::InitialValueExceedsMaxSliceLen");
     }
 
     #[conformance_test]
@@ -1998,6 +2152,1077 @@ pub mod service_blackboard {
         assert_that!(read_value, eq write_value);
     }
 
+    #[derive(Debug, Clone, PartialEq, Eq, ZeroCopySend)]
+    #[repr(C)]
+    struct NonCopySetting {
+        id: u32,
+        name: StaticString<8>,
+    }
+
+    #[conformance_test]
+    pub fn add_slice_stores_initial_value<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u16>(0, 8, &[1, 2, 3])
+            .add::<u32>(1, 4)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let mut entry_handle = reader.entry_slice::<u16>(&0).unwrap();
+        assert_that!(entry_handle.max_slice_len(), eq 8);
+        assert_that!(entry_handle.get(), eq vec![1, 2, 3]);
+        assert_that!(reader.entry::<u32>(&1).unwrap().get(), eq 4);
+    }
+
+    #[conformance_test]
+    pub fn add_slice_with_initial_value_exceeding_max_slice_len_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 4)
+            .add_slice::<u8>(0, 2, b"hello")
+            .create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardCreateError::InitialValueExceedsMaxSliceLen);
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u8>(0, 5, b"hello")
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn slice_entry_handles_require_matching_value_variant<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u8>(0, 4, &[])
+            .add::<u8>(1, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(writer.entry::<u8>(&0).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(writer.entry_slice::<u8>(&1).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(writer.entry_slice::<u16>(&0).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(reader.entry::<u8>(&0).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.entry_slice::<u8>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.entry_slice::<u16>(&0).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+    }
+
+    #[conformance_test]
+    pub fn loan_slice_and_write_entry_value_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u32>(0, 16, &[7; 16])
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let mut entry_handle_mut = writer.entry_slice::<u32>(&0).unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let mut entry_handle = reader.entry_slice::<u32>(&0).unwrap();
+
+        entry_handle_mut
+            .loan_slice_uninit(3)
+            .unwrap()
+            .write_from_slice(&[1, 2, 3])
+            .update();
+        assert_that!(entry_handle.get(), eq vec![1, 2, 3]);
+
+        entry_handle_mut
+            .loan_slice_uninit(5)
+            .unwrap()
+            .write_from_fn(|i| i as u32 * 10)
+            .update();
+        assert_that!(entry_handle.get(), eq vec![0, 10, 20, 30, 40]);
+
+        entry_handle_mut
+            .loan_slice_uninit(0)
+            .unwrap()
+            .write_from_slice(&[])
+            .update();
+        assert_that!(entry_handle.read(|value| value.is_empty()), eq true);
+    }
+
+    #[conformance_test]
+    pub fn loan_slice_fails_when_slice_len_exceeds_max_slice_len<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u8>(0, 4, &[])
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let mut entry_handle_mut = writer.entry_slice::<u8>(&0).unwrap();
+
+        let entry_value_uninit = entry_handle_mut.loan_slice_uninit(5);
+        assert_that!(entry_value_uninit.err(), eq Some(EntryValueLoanError::ExceedsMaxSliceLen));
+        assert_that!(entry_handle_mut.loan_slice_uninit(4), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn dropped_slice_entry_value_discards_the_update<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u8>(0, 8, b"abc")
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let mut entry_handle_mut = writer.entry_slice::<u8>(&0).unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let mut entry_handle = reader.entry_slice::<u8>(&0).unwrap();
+
+        let _ = entry_handle_mut.loan_slice_uninit(2).unwrap();
+        assert_that!(entry_handle.get(), eq b"abc".to_vec());
+
+        {
+            let mut entry_value = entry_handle_mut
+                .loan_slice_uninit(2)
+                .unwrap()
+                .write_from_slice(b"de");
+            entry_value.payload_mut()[1] = b'x';
+            assert_that!(entry_value.payload(), eq b"dx");
+        }
+        assert_that!(entry_handle.get(), eq b"abc".to_vec());
+    }
+
+    #[conformance_test]
+    pub fn slice_of_non_copy_values_can_be_written_and_read<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let initial_value = [NonCopySetting {
+            id: 1,
+            name: StaticString::from_bytes(b"initial").unwrap(),
+        }];
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<NonCopySetting>(0, 3, &initial_value)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let mut entry_handle = reader.entry_slice::<NonCopySetting>(&0).unwrap();
+        assert_that!(entry_handle.get(), eq initial_value.to_vec());
+
+        let writer = sut.writer_builder().create().unwrap();
+        let mut entry_handle_mut = writer.entry_slice::<NonCopySetting>(&0).unwrap();
+        entry_handle_mut
+            .loan_slice_uninit(3)
+            .unwrap()
+            .write_from_fn(|i| NonCopySetting {
+                id: i as u32,
+                name: StaticString::from_bytes(b"updated").unwrap(),
+            })
+            .update();
+
+        let value = entry_handle.get();
+        assert_that!(value, len 3);
+        for (i, setting) in value.iter().enumerate() {
+            assert_that!(setting.id, eq i as u32);
+            assert_that!(setting.name, eq StaticString::<8>::from_bytes(b"updated").unwrap());
+        }
+    }
+
+    #[conformance_test]
+    pub fn concurrent_write_and_read_of_slice_is_never_torn<S: Service>() {
+        const MAX_SLICE_LEN: usize = 128;
+        let _watch_dog = Watchdog::new();
+        let number_of_readers = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 4);
+
+        let barrier = Barrier::new(number_of_readers + 1);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u64>(0, MAX_SLICE_LEN, &[])
+            .create()
+            .unwrap();
+
+        let keep_running = IoxAtomicBool::new(true);
+
+        std::thread::scope(|s| {
+            let t = s.spawn(|| {
+                let sut = node
+                    .service_builder(&service_name)
+                    .blackboard_opener::<u64>()
+                    .open()
+                    .unwrap();
+                let writer = sut.writer_builder().create().unwrap();
+                let mut entry_handle_mut = writer.entry_slice::<u64>(&0).unwrap();
+
+                barrier.wait();
+
+                let mut counter = 0;
+                while keep_running.load(Ordering::Relaxed) {
+                    counter += 1;
+                    let len = counter % (MAX_SLICE_LEN + 1);
+                    entry_handle_mut
+                        .loan_slice_uninit(len)
+                        .unwrap()
+                        .write_from_fn(|_| len as u64)
+                        .update();
+                }
+            });
+            let mut threads = vec![];
+            for _ in 0..number_of_readers {
+                threads.push(s.spawn(|| {
+                    let sut = node
+                        .service_builder(&service_name)
+                        .blackboard_opener::<u64>()
+                        .open()
+                        .unwrap();
+                    let reader = sut.reader_builder().create().unwrap();
+                    let mut entry_handle = reader.entry_slice::<u64>(&0).unwrap();
+                    barrier.wait();
+                    for _ in 0..1000 {
+                        entry_handle.read(|value| {
+                            for element in value {
+                                assert_that!(*element, eq value.len() as u64);
+                            }
+                        });
+                    }
+                }));
+            }
+            for t in threads {
+                t.join().unwrap();
+            }
+            keep_running.store(false, Ordering::Relaxed);
+            t.join().unwrap();
+        });
+    }
+
+    #[conformance_test]
+    pub fn entry_handle_detects_changes<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let entry_handle_mut = writer.entry::<u32>(&0).unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle = reader.entry::<u32>(&0).unwrap();
+
+        assert_that!(entry_handle.generation(), eq 0);
+        assert_that!(entry_handle.has_changed(), eq true);
+        assert_that!(entry_handle.get_if_changed(), eq Some(1));
+        assert_that!(entry_handle.has_changed(), eq false);
+        assert_that!(entry_handle.get_if_changed(), eq None);
+
+        entry_handle_mut.update_with_copy(2);
+        assert_that!(entry_handle.generation(), eq 1);
+        assert_that!(entry_handle.has_changed(), eq true);
+        assert_that!(entry_handle.get(), eq 2);
+        assert_that!(entry_handle.get_if_changed(), eq None);
+
+        // an update with the same value is still a change
+        let entry_handle_mut = entry_handle_mut.loan_uninit().write(2).update();
+        assert_that!(entry_handle.get_if_changed(), eq Some(2));
+
+        // a discarded update is not a change
+        let _entry_handle_mut = entry_handle_mut.loan_uninit().write(3).discard();
+        assert_that!(entry_handle.has_changed(), eq false);
+        assert_that!(entry_handle.generation(), eq 2);
+    }
+
+    #[conformance_test]
+    pub fn entry_handles_track_changes_independently<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let entry_handle_mut = writer.entry::<u32>(&0).unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle_1 = reader.entry::<u32>(&0).unwrap();
+        let entry_handle_2 = reader.entry::<u32>(&0).unwrap();
+
+        entry_handle_mut.update_with_copy(5);
+        assert_that!(entry_handle_1.get_if_changed(), eq Some(5));
+        assert_that!(entry_handle_1.has_changed(), eq false);
+        assert_that!(entry_handle_2.has_changed(), eq true);
+        assert_that!(entry_handle_2.get_if_changed(), eq Some(5));
+    }
+
+    #[conformance_test]
+    pub fn slice_entry_handle_detects_changes<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u8>(0, 8, b"a")
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let mut entry_handle_mut = writer.entry_slice::<u8>(&0).unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let mut entry_handle = reader.entry_slice::<u8>(&0).unwrap();
+
+        assert_that!(entry_handle.get_if_changed(), eq Some(b"a".to_vec()));
+        assert_that!(entry_handle.get_if_changed(), eq None);
+
+        entry_handle_mut
+            .loan_slice_uninit(2)
+            .unwrap()
+            .write_from_slice(b"bc")
+            .update();
+        assert_that!(entry_handle.generation(), eq 1);
+        assert_that!(entry_handle.has_changed(), eq true);
+        assert_that!(entry_handle.read(|value| value.len()), eq 2);
+        assert_that!(entry_handle.has_changed(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn blackboard_has_no_event_service_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().has_event_service(), eq false);
+        assert_that!(sut.event_service(), is_none);
+        assert_that!(
+            node.service_builder(&service_name).event().open().err(),
+            eq Some(EventOpenError::DoesNotExist)
+        );
+    }
+
+    #[conformance_test]
+    pub fn event_service_is_created_and_opened_with_the_blackboard<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .enable_event_service(true)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().has_event_service(), eq true);
+        assert_that!(sut.event_service(), is_some);
+        assert_that!(sut.event_service().unwrap().name(), eq & service_name);
+
+        let opener = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(opener.static_config().has_event_service(), eq true);
+        assert_that!(opener.event_service(), is_some);
+
+        drop(opener);
+        drop(sut);
+        assert_that!(
+            node.service_builder(&service_name).event().open().err(),
+            eq Some(EventOpenError::DoesNotExist)
+        );
+    }
+
+    #[conformance_test]
+    pub fn create_with_event_service_fails_when_event_service_already_exists<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .enable_event_service(true)
+            .create();
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::AlreadyExists));
+    }
+
+    #[conformance_test]
+    pub fn entry_updates_are_signaled_with_the_entry_id<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .add::<u64>(1, 2)
+            .add_slice::<u8>(2, 4, &[])
+            .enable_event_service(true)
+            .create()
+            .unwrap();
+
+        let listener = sut
+            .event_service()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let entry_handle_mut_0 = writer.entry::<u32>(&0).unwrap();
+        let entry_handle_mut_1 = writer.entry::<u64>(&1).unwrap();
+        let mut entry_handle_mut_2 = writer.entry_slice::<u8>(&2).unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+
+        entry_handle_mut_0.update_with_copy(3);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(entry_handle_mut_0.entry_id()));
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+
+        let _entry_handle_mut_1 = entry_handle_mut_1.loan_uninit().write(4).update();
+        entry_handle_mut_2
+            .loan_slice_uninit(1)
+            .unwrap()
+            .write_from_slice(b"x")
+            .update();
+
+        let mut entry_ids = vec![];
+        listener
+            .try_wait_all(|entry_id| entry_ids.push(entry_id))
+            .unwrap();
+        assert_that!(entry_ids, len 2);
+        assert_that!(entry_ids, contains _entry_handle_mut_1.entry_id());
+        assert_that!(entry_ids, contains entry_handle_mut_2.entry_id());
+    }
+
+    #[conformance_test]
+    pub fn waitset_wakes_up_on_entry_update<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .add::<u32>(1, 1)
+            .enable_event_service(true)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle = reader.entry::<u32>(&1).unwrap();
+        let listener = sut
+            .event_service()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset.attach_notification(&listener).unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        writer.entry::<u32>(&0).unwrap().update_with_copy(10);
+        writer.entry::<u32>(&1).unwrap().update_with_copy(11);
+
+        let mut received_value = None;
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                assert_that!(attachment_id.has_event_from(&guard), eq true);
+                listener
+                    .try_wait_all(|entry_id| {
+                        if entry_id == entry_handle.entry_id() {
+                            received_value = entry_handle.get_if_changed();
+                        }
+                    })
+                    .unwrap();
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(received_value, eq Some(11));
+    }
+
+    #[conformance_test]
+    pub fn open_or_create_creates_service_if_it_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 7)
+            .open_or_create();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u32>(&0).unwrap().get(), eq 7);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_or_create_opens_existing_service_without_overriding_values<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 7)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        writer.entry::<u32>(&0).unwrap().update_with_copy(8);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 9)
+            .open_or_create();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+
+        let reader = sut2.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u32>(&0).unwrap().get(), eq 8);
+    }
+
+    #[conformance_test]
+    pub fn open_or_create_adds_missing_keys_to_existing_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(2, 16)
+            .open_or_create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u64>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .add::<u64>(1, 2)
+            .add_slice::<u8>(2, 4, b"abc")
+            .open_or_create()
+            .unwrap();
+
+        assert_that!(reader.entry::<u32>(&0).unwrap().get(), eq 1);
+        assert_that!(reader.entry::<u64>(&1).unwrap().get(), eq 2);
+        assert_that!(reader.entry_slice::<u8>(&2).unwrap().get(), eq b"abc".to_vec());
+
+        let writer = sut2.writer_builder().create().unwrap();
+        writer.entry::<u64>(&1).unwrap().update_with_copy(3);
+        assert_that!(reader.entry::<u64>(&1).unwrap().get(), eq 3);
+    }
+
+    #[conformance_test]
+    pub fn open_or_create_fails_when_existing_key_has_different_value_type<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(2, 16)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 1)
+            .open_or_create();
+        assert_that!(
+            sut2.err(),
+            eq Some(BlackboardOpenOrCreateError::EntryInsertError(
+                EntryInsertError::KeyAlreadyExists
+            ))
+        );
+    }
+
+    #[conformance_test]
+    pub fn open_or_create_fails_when_no_keys_are_reserved<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .add::<u32>(1, 1)
+            .open_or_create();
+        assert_that!(
+            sut2.err(),
+            eq Some(BlackboardOpenOrCreateError::EntryInsertError(
+                EntryInsertError::ExceedsMaxNumberOfKeys
+            ))
+        );
+    }
+
+    #[conformance_test]
+    pub fn open_or_create_fails_with_incompatible_key_type<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u32>()
+            .add::<u32>(0, 1)
+            .open_or_create();
+        assert_that!(
+            sut2.err(),
+            eq Some(BlackboardOpenOrCreateError::BlackboardOpenError(
+                BlackboardOpenError::IncompatibleKeys
+            ))
+        );
+    }
+
+    #[conformance_test]
+    pub fn writer_can_add_keys_at_runtime<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(2, 8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u64>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+
+        assert_that!(writer.add::<u64>(1, 11), is_ok);
+        assert_that!(writer.add::<i8>(2, -2), is_ok);
+
+        let entry_handle_1 = reader.entry::<u64>(&1).unwrap();
+        let entry_handle_2 = reader.entry::<i8>(&2).unwrap();
+        assert_that!(entry_handle_1.get(), eq 11);
+        assert_that!(entry_handle_2.get(), eq - 2);
+        assert_that!(reader.entry::<u32>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+
+        let entry_handle_mut_1 = writer.entry::<u64>(&1).unwrap();
+        entry_handle_mut_1.update_with_copy(12);
+        assert_that!(entry_handle_1.get(), eq 12);
+        assert_that!(
+            writer.entry::<u64>(&1).err(),
+            eq Some(EntryHandleMutError::HandleAlreadyExists)
+        );
+
+        let mut keys = vec![];
+        sut.list_keys(|key| {
+            keys.push(*key);
+            CallbackProgression::Continue
+        });
+        assert_that!(keys, len 3);
+        assert_that!(keys, contains 0);
+        assert_that!(keys, contains 1);
+        assert_that!(keys, contains 2);
+    }
+
+    #[conformance_test]
+    pub fn keys_added_at_runtime_are_discovered_by_opened_services<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(1, 4)
+            .create()
+            .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+
+        let mut number_of_keys = 0;
+        sut2.list_keys(|_| {
+            number_of_keys += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(number_of_keys, eq 1);
+
+        let writer = sut.writer_builder().create().unwrap();
+        writer.add::<f32>(5, 1.5).unwrap();
+
+        let mut keys = vec![];
+        sut2.list_keys(|key| {
+            keys.push(*key);
+            CallbackProgression::Continue
+        });
+        assert_that!(keys, eq vec![0, 5]);
+
+        let reader = sut2.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<f32>(&5).unwrap().get(), eq 1.5);
+    }
+
+    #[conformance_test]
+    pub fn list_keys_stops_in_keys_added_at_runtime_when_callback_requests_it<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(3, 4)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        writer.add::<u32>(1, 1).unwrap();
+        writer.add::<u32>(2, 1).unwrap();
+
+        let mut counter = 0;
+        sut.list_keys(|_| {
+            counter += 1;
+            if counter == 2 {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+        assert_that!(counter, eq 2);
+    }
+
+    #[conformance_test]
+    pub fn add_fails_when_key_already_exists<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(3, 8)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(writer.add::<u32>(0, 2).err(), eq Some(EntryInsertError::KeyAlreadyExists));
+        assert_that!(writer.add::<u64>(0, 2).err(), eq Some(EntryInsertError::KeyAlreadyExists));
+        assert_that!(writer.add::<u32>(1, 2), is_ok);
+        assert_that!(writer.add::<u32>(1, 3).err(), eq Some(EntryInsertError::KeyAlreadyExists));
+
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u32>(&0).unwrap().get(), eq 1);
+        assert_that!(reader.entry::<u32>(&1).unwrap().get(), eq 2);
+    }
+
+    #[conformance_test]
+    pub fn add_fails_when_all_reserved_keys_are_used<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(2, 8)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(writer.add::<u32>(1, 1), is_ok);
+        assert_that!(writer.add::<u32>(2, 1), is_ok);
+        assert_that!(
+            writer.add::<u32>(3, 1).err(),
+            eq Some(EntryInsertError::ExceedsMaxNumberOfKeys)
+        );
+    }
+
+    #[conformance_test]
+    pub fn add_fails_without_reserved_keys<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(
+            writer.add::<u8>(1, 1).err(),
+            eq Some(EntryInsertError::ExceedsMaxNumberOfKeys)
+        );
+    }
+
+    #[conformance_test]
+    pub fn add_fails_when_value_exceeds_reserved_value_size<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(2, 8)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(
+            writer.add::<[u64; 2]>(1, [1, 2]).err(),
+            eq Some(EntryInsertError::ExceedsMaxValueSize)
+        );
+        assert_that!(writer.add::<[u8; 8]>(1, [1; 8]), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn keys_added_at_runtime_are_signaled_via_event_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve_keys(2, 8)
+            .enable_event_service(true)
+            .create()
+            .unwrap();
+        let listener = sut
+            .event_service()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        writer.add::<u64>(1, 1).unwrap();
+        let entry_handle_mut = writer.entry::<u64>(&1).unwrap();
+        entry_handle_mut.update_with_copy(2);
+
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(entry_handle_mut.entry_id()));
+    }
+
+    #[conformance_test]
+    pub fn concurrent_add_of_the_same_key_succeeds_only_once<S: Service>() {
+        let _watch_dog = Watchdog::new();
+        let number_of_threads = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 4);
+        let number_of_keys = 64;
+
+        let barrier = Barrier::new(number_of_threads);
+        let number_of_successful_adds = IoxAtomicUsize::new(0);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(u64::MAX, 0)
+            .reserve_keys(number_of_keys * number_of_threads, 8)
+            .max_writers(number_of_threads)
+            .create()
+            .unwrap();
+
+        std::thread::scope(|s| {
+            let mut threads = vec![];
+            for thread_id in 0..number_of_threads {
+                let barrier = &barrier;
+                let number_of_successful_adds = &number_of_successful_adds;
+                let node = &node;
+                let service_name = &service_name;
+                threads.push(s.spawn(move || {
+                    let service = node
+                        .service_builder(service_name)
+                        .blackboard_opener::<u64>()
+                        .open()
+                        .unwrap();
+                    let writer = service.writer_builder().create().unwrap();
+
+                    for key in 0..number_of_keys as u64 {
+                        barrier.wait();
+                        if writer.add::<u64>(key, thread_id as u64).is_ok() {
+                            number_of_successful_adds.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                }));
+            }
+
+            for t in threads {
+                t.join().unwrap();
+            }
+        });
+
+        assert_that!(number_of_successful_adds.load(Ordering::Relaxed), eq number_of_keys);
+
+        let reader = sut.reader_builder().create().unwrap();
+        for key in 0..number_of_keys as u64 {
+            assert_that!(reader.entry::<u64>(&key).unwrap().get(), lt number_of_threads as u64);
+        }
+
+        let mut listed_keys = 0;
+        sut.list_keys(|_| {
+            listed_keys += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(listed_keys, eq number_of_keys + 1);
+    }
+
+    #[conformance_test]
+    pub fn concurrent_open_or_create_builds_one_blackboard<S: Service>() {
+        let _watch_dog = Watchdog::new();
+        let number_of_threads = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 4);
+        let number_of_keys_per_thread = 4;
+
+        let barrier = Barrier::new(number_of_threads);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        std::thread::scope(|s| {
+            let mut threads = vec![];
+            for thread_id in 0..number_of_threads {
+                let barrier = &barrier;
+                let node = &node;
+                let service_name = &service_name;
+                threads.push(s.spawn(move || {
+                    let mut creator = node
+                        .service_builder(service_name)
+                        .blackboard_creator::<u64>()
+                        .reserve_keys(number_of_threads * number_of_keys_per_thread, 8)
+                        .add::<u64>(u64::MAX, 0);
+                    for i in 0..number_of_keys_per_thread {
+                        let key = (thread_id * number_of_keys_per_thread + i) as u64;
+                        creator = creator.add::<u64>(key, key);
+                    }
+
+                    barrier.wait();
+                    let sut = creator.open_or_create().unwrap();
+                    // wait until all threads have added their keys
+                    barrier.wait();
+
+                    let reader = sut.reader_builder().create().unwrap();
+                    assert_that!(reader.entry::<u64>(&u64::MAX).unwrap().get(), eq 0);
+                    for key in 0..(number_of_threads * number_of_keys_per_thread) as u64 {
+                        assert_that!(reader.entry::<u64>(&key).unwrap().get(), eq key);
+                    }
+
+                    let mut number_of_keys = 0;
+                    sut.list_keys(|_| {
+                        number_of_keys += 1;
+                        CallbackProgression::Continue
+                    });
+                    assert_that!(
+                        number_of_keys,
+                        eq number_of_threads * number_of_keys_per_thread + 1
+                    );
+                }));
+            }
+
+            for t in threads {
+                t.join().unwrap();
+            }
+        });
+    }
+
     #[conformance_test]
     pub fn key_memory_creation_fails_when_value_is_too_large<Sut: Service>() {
         let key: u16 = 256;
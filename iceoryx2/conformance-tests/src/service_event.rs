@@ -22,8 +22,10 @@ pub mod service_event {
     use std::time::Instant;
 
     use iceoryx2::port::listener::{Listener, ListenerCreateError};
+    use iceoryx2::port::notification_payload::MAX_NOTIFICATION_PAYLOAD_SIZE;
     use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
+    use iceoryx2::service::access_policy::{AccessPolicy, AccessRights};
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
     use iceoryx2::testing::*;
     use iceoryx2_bb_conformance_test_macros::conformance_test;
@@ -1271,6 +1273,8 @@ pub mod service_event {
             format!("{}", EventOpenError::DoesNotSupportRequestedAmountOfListeners), eq "EventOpenError::DoesNotSupportRequestedAmountOfListeners");
         assert_that!(
             format!("{}", EventOpenError::DoesNotSupportRequestedMaxEventId), eq "EventOpenError::DoesNotSupportRequestedMaxEventId");
+        assert_that!(
+            format!("{}", EventOpenError::DoesNotSupportRequestedNotificationPayloadSize), eq "EventOpenError::DoesNotSupportRequestedNotificationPayloadSize");
     }
 
     #[conformance_test]
@@ -1572,4 +1576,331 @@ pub mod service_event {
         }
         assert_that!(received_events, eq 1);
     }
+
+    #[conformance_test]
+    pub fn open_fails_with_access_denied_when_required_attribute_is_not_required<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let key = "clearance".try_into().unwrap();
+        let value = "diagnostics".try_into().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .access_policy(&AccessPolicy::new().require_attribute(&key, &value).unwrap())
+            .create_with_attributes(&AttributeSpecifier::new().define(&key, &value).unwrap())
+            .unwrap();
+
+        let sut2 = node.service_builder(&service_name).event().open();
+        assert_that!(sut2.err(), eq Some(EventOpenError::AccessDenied));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .open_with_attributes(&AttributeVerifier::new().require(&key, &value).unwrap());
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn notifier_and_listener_of_service_with_access_policy_can_communicate<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let event_id = EventId::new(12);
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .access_policy(&AccessPolicy::new().grant_group(AccessRights::ReadWrite))
+            .create()
+            .unwrap();
+
+        let notifier = sut
+            .notifier_builder()
+            .default_event_id(event_id)
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(notifier.notify(), is_ok);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(event_id));
+    }
+
+    #[conformance_test]
+    pub fn notification_payload_is_disabled_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_notification_payload_size(), eq 0);
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let result = notifier.notify_with_payload(EventId::new(1), &[1]);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::PayloadExceedsMaxSize));
+    }
+
+    #[conformance_test]
+    pub fn max_notification_payload_size_is_stored_in_static_config<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(12)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_notification_payload_size(), eq 12);
+
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
+        assert_that!(sut2.static_config().max_notification_payload_size(), eq 12);
+    }
+
+    #[conformance_test]
+    pub fn max_notification_payload_size_is_adjusted_to_largest_supported_value<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(MAX_NOTIFICATION_PAYLOAD_SIZE + 1)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_notification_payload_size(), eq MAX_NOTIFICATION_PAYLOAD_SIZE);
+    }
+
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_notification_payload_size_requirements<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(8)
+            .create();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(9)
+            .open();
+
+        assert_that!(sut2.err(), eq Some(EventOpenError::DoesNotSupportRequestedNotificationPayloadSize));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(8)
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn notify_with_payload_fails_when_payload_exceeds_max_size<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(4)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        let result = notifier.notify_with_payload(EventId::new(1), &[1, 2, 3, 4, 5]);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::PayloadExceedsMaxSize));
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+
+        let result = notifier.notify_with_payload(EventId::new(1), &[1, 2, 3, 4]);
+        assert_that!(result, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn notification_payload_is_delivered_with_event_id<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(MAX_NOTIFICATION_PAYLOAD_SIZE)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        let payload: Vec<u8> = (0..MAX_NOTIFICATION_PAYLOAD_SIZE as u8).collect();
+        assert_that!(notifier.notify_with_payload(EventId::new(3), &payload), eq Ok(1));
+
+        let (event_id, received_payload) = listener.try_wait_one_with_payload().unwrap().unwrap();
+        assert_that!(event_id, eq EventId::new(3));
+        assert_that!(received_payload.as_slice(), eq payload.as_slice());
+        assert_that!(listener.try_wait_one_with_payload().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn notification_payload_of_latest_notification_is_delivered_once<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(8)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(
+            notifier.notify_with_payload(EventId::new(1), &[1, 2, 3]),
+            is_ok
+        );
+        assert_that!(notifier.notify_with_payload(EventId::new(2), &[4]), is_ok);
+        assert_that!(
+            notifier.notify_with_payload(EventId::new(1), &[5, 6]),
+            is_ok
+        );
+
+        let mut received = vec![];
+        listener
+            .try_wait_all_with_payload(|id, payload| {
+                received.push((id, payload.to_vec()));
+            })
+            .unwrap();
+        received.sort();
+
+        assert_that!(received, eq vec![(EventId::new(1), vec![5, 6]), (EventId::new(2), vec![4])]);
+        assert_that!(listener.try_wait_one_with_payload().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn notify_without_payload_does_not_overwrite_payload<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(8)
+            .create()
+            .unwrap();
+
+        let notifier = sut
+            .notifier_builder()
+            .default_event_id(EventId::new(4))
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(
+            notifier.notify_with_payload(EventId::new(4), &[1, 2, 3]),
+            is_ok
+        );
+        assert_that!(notifier.notify(), is_ok);
+
+        let mut received = vec![];
+        listener
+            .timed_wait_all_with_payload(
+                |id, payload| {
+                    received.push((id, payload.to_vec()));
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+        received.sort();
+
+        assert_that!(received, eq vec![(EventId::new(4), vec![]), (EventId::new(4), vec![1, 2, 3])]);
+    }
+
+    #[conformance_test]
+    pub fn notification_payloads_of_different_notifiers_are_delivered<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(8)
+            .create()
+            .unwrap();
+
+        let notifier_1 = sut.notifier_builder().create().unwrap();
+        let notifier_2 = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(notifier_1.notify_with_payload(EventId::new(7), &[1]), is_ok);
+        assert_that!(notifier_2.notify_with_payload(EventId::new(7), &[2]), is_ok);
+
+        let mut received = vec![];
+        while let Some((id, payload)) = listener.try_wait_one_with_payload().unwrap() {
+            received.push((id, payload.to_vec()));
+        }
+        received.sort();
+
+        assert_that!(received, eq vec![(EventId::new(7), vec![1]), (EventId::new(7), vec![2])]);
+    }
+
+    #[conformance_test]
+    pub fn notification_payload_is_delivered_to_every_listener<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(8)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener_1 = sut.listener_builder().create().unwrap();
+        let listener_2 = sut.listener_builder().create().unwrap();
+
+        assert_that!(notifier.notify_with_payload(EventId::new(2), &[3, 4]), eq Ok(2));
+
+        for listener in [&listener_1, &listener_2] {
+            let (event_id, payload) = listener.try_wait_one_with_payload().unwrap().unwrap();
+            assert_that!(event_id, eq EventId::new(2));
+            assert_that!(payload.as_slice(), eq [3, 4]);
+        }
+    }
+
+    #[conformance_test]
+    pub fn listener_without_payload_receives_event_id_of_payload_notification<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notification_payload_size(8)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(notifier.notify_with_payload(EventId::new(5), &[1]), is_ok);
+
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(5)));
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
 }
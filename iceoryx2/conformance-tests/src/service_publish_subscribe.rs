@@ -16,6 +16,7 @@ use iceoryx2_bb_conformance_test_macros::conformance_test_module;
 #[conformance_test_module]
 pub mod service_publish_subscribe {
     use core::sync::atomic::Ordering;
+    use core::time::Duration;
     use std::sync::{Barrier, Mutex};
     use std::thread;
 
@@ -25,6 +26,7 @@ pub mod service_publish_subscribe {
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::port::LoanError;
     use iceoryx2::prelude::{AllocationStrategy, *};
+    use iceoryx2::service::access_policy::{AccessPolicy, AccessRights};
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
@@ -37,9 +39,13 @@ pub mod service_publish_subscribe {
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+    use iceoryx2_bb_posix::group::Gid;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::Uid;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
     use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
     #[derive(Debug, ZeroCopySend)]
@@ -442,6 +448,95 @@ pub mod service_publish_subscribe {
         );
     }
 
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_lifespan_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .lifespan(Duration::from_millis(10))
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .lifespan(Duration::from_millis(20))
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleLifespan));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .disable_lifespan()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleLifespan));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .lifespan(Duration::from_millis(10))
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_subscriber_deadline_requirement<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_deadline(Duration::from_millis(10))
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_deadline(Duration::from_millis(20))
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleSubscriberDeadline));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .disable_subscriber_deadline()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleSubscriberDeadline));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_deadline(Duration::from_millis(10))
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().subscriber_deadline(), eq Some(Duration::from_millis(10)));
+    }
+
+    #[conformance_test]
+    pub fn lifespan_and_subscriber_deadline_are_disabled_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().lifespan(), eq None);
+        assert_that!(sut.static_config().subscriber_deadline(), eq None);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.deadline(), eq None);
+    }
+
     #[conformance_test]
     pub fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -2772,6 +2867,10 @@ pub mod service_publish_subscribe {
                                   "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleOverflowBehavior), eq
                                   "PublishSubscribeOpenError::IncompatibleOverflowBehavior");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleLifespan), eq
+                                  "PublishSubscribeOpenError::IncompatibleLifespan");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleSubscriberDeadline), eq
+                                  "PublishSubscribeOpenError::IncompatibleSubscriberDeadline");
         assert_that!(format!("{}", PublishSubscribeOpenError::InsufficientPermissions), eq
                                   "PublishSubscribeOpenError::InsufficientPermissions");
         assert_that!(format!("{}", PublishSubscribeOpenError::ServiceInCorruptedState), eq
@@ -3367,6 +3466,33 @@ pub mod service_publish_subscribe {
         assert_that!(counter, eq 1);
     }
 
+    #[conformance_test]
+    pub fn publisher_priority_is_stored_in_dynamic_config<S: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let default_publisher = sut.publisher_builder().create().unwrap();
+        let important_publisher = sut.publisher_builder().priority(7).create().unwrap();
+
+        let mut priorities = vec![];
+        sut.dynamic_config().list_publishers(|details| {
+            priorities.push((details.publisher_id, details.priority));
+            CallbackProgression::Continue
+        });
+
+        assert_that!(priorities, len 2);
+        assert_that!(priorities, contains(default_publisher.id(), 0));
+        assert_that!(priorities, contains(important_publisher.id(), 7));
+    }
+
     #[conformance_test]
     pub fn listing_all_subscribers_works<S: Service>() {
         const NUMBER_OF_SUBSCRIBERS: usize = 18;
@@ -3469,4 +3595,372 @@ pub mod service_publish_subscribe {
         let recv_res = subscriber.receive();
         assert_that!(recv_res, is_ok);
     }
+
+    #[conformance_test]
+    pub fn samples_within_lifespan_are_delivered<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .lifespan(Duration::from_secs(3600))
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1234), is_ok);
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234);
+    }
+
+    #[conformance_test]
+    pub fn samples_exceeding_lifespan_are_dropped<Sut: Service>() {
+        const LIFESPAN: Duration = Duration::from_millis(25);
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .lifespan(LIFESPAN)
+            .subscriber_max_buffer_size(2)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        // the expired samples must be returned to the publisher, otherwise it runs out of memory
+        for _ in 0..4 {
+            assert_that!(publisher.send_copy(1), is_ok);
+            assert_that!(publisher.send_copy(2), is_ok);
+            std::thread::sleep(LIFESPAN * 2);
+            assert_that!(subscriber.receive().unwrap(), is_none);
+        }
+
+        assert_that!(publisher.send_copy(3), is_ok);
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 3);
+    }
+
+    #[conformance_test]
+    pub fn expired_history_is_not_delivered_to_late_joiners<Sut: Service>() {
+        const LIFESPAN: Duration = Duration::from_millis(25);
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .lifespan(LIFESPAN)
+            .history_size(2)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1), is_ok);
+        std::thread::sleep(LIFESPAN * 2);
+        assert_that!(publisher.send_copy(2), is_ok);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 2);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_deadline_is_reported_as_missed_when_no_sample_arrives<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const DEADLINE: Duration = Duration::from_millis(25);
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_deadline(DEADLINE)
+            .create()
+            .unwrap();
+
+        let _publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.deadline(), eq Some(DEADLINE));
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset
+            .attach_deadline(
+                subscriber.deadline_listener().unwrap(),
+                subscriber.deadline().unwrap(),
+            )
+            .unwrap();
+
+        let mut missed_deadline = false;
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                missed_deadline = attachment_id.has_missed_deadline(&guard);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(missed_deadline, eq true);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_deadline_wakes_up_waitset_when_sample_arrives<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_deadline(Duration::from_secs(3600))
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset
+            .attach_deadline(
+                subscriber.deadline_listener().unwrap(),
+                subscriber.deadline().unwrap(),
+            )
+            .unwrap();
+
+        assert_that!(publisher.send_copy(5678), is_ok);
+
+        let mut received_value = None;
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                assert_that!(attachment_id.has_event_from(&guard), eq true);
+                assert_that!(attachment_id.has_missed_deadline(&guard), eq false);
+                let deadline_listener = subscriber.deadline_listener().unwrap();
+                assert_that!(deadline_listener.try_wait_all(|_| {}), is_ok);
+                received_value = subscriber.receive().unwrap().map(|sample| *sample);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(received_value, eq Some(5678));
+    }
+
+    #[conformance_test]
+    pub fn subscriber_has_no_deadline_listener_without_subscriber_deadline<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.deadline(), is_none);
+        assert_that!(subscriber.deadline_listener(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn service_without_access_policy_has_default_access_policy<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(*sut.access_policy(), eq AccessPolicy::default());
+        assert_that!(sut.access_policy().is_explicit(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn owner_only_access_policy_is_distinct_from_default_access_policy<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .access_policy(&AccessPolicy::new())
+            .create()
+            .unwrap();
+
+        let policy = sut.access_policy();
+        assert_that!(*policy, ne AccessPolicy::default());
+        assert_that!(policy.is_explicit(), eq true);
+        assert_that!(policy.owner(), eq Some(Uid::from_self().value()));
+        assert_that!(policy.group(), is_some);
+        assert_that!(policy.group_rights(), eq AccessRights::None);
+        assert_that!(policy.others_rights(), eq AccessRights::None);
+
+        // the owner still has read-write access
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn access_policy_is_applied_and_visible_when_service_is_opened<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .access_policy(
+                &AccessPolicy::new()
+                    .grant_group(AccessRights::ReadWrite)
+                    .grant_others(AccessRights::ReadOnly),
+            )
+            .create()
+            .unwrap();
+
+        let policy = sut.access_policy();
+        assert_that!(policy.owner(), eq Some(Uid::from_self().value()));
+        assert_that!(policy.group(), is_some);
+        assert_that!(policy.group_rights(), eq AccessRights::ReadWrite);
+        assert_that!(policy.others_rights(), eq AccessRights::ReadOnly);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        assert_that!(sut2.access_policy(), eq policy);
+    }
+
+    #[conformance_test]
+    pub fn ports_of_service_with_explicit_access_policy_can_communicate<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .access_policy(
+                &AccessPolicy::new()
+                    .owned_by_group(Gid::from_self().value())
+                    .grant_group(AccessRights::ReadWrite),
+            )
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234);
+    }
+
+    #[conformance_test]
+    pub fn access_policy_of_opened_service_is_not_overridden<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .access_policy(&AccessPolicy::new().grant_others(AccessRights::ReadWrite))
+            .open()
+            .unwrap();
+
+        assert_that!(*sut2.access_policy(), eq AccessPolicy::default());
+    }
+
+    #[conformance_test]
+    pub fn open_fails_with_access_denied_when_required_attribute_is_not_required<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let key = "clearance".try_into().unwrap();
+        let value = "diagnostics".try_into().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .access_policy(&AccessPolicy::new().require_attribute(&key, &value).unwrap())
+            .create_with_attributes(&AttributeSpecifier::new().define(&key, &value).unwrap())
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::AccessDenied));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_with_attributes(&AttributeVerifier::new().require_key(&key).unwrap());
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::AccessDenied));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_with_attributes(&AttributeVerifier::new().require(&key, &value).unwrap());
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn ports_of_service_with_access_policy_can_communicate<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .access_policy(
+                &AccessPolicy::new()
+                    .grant_group(AccessRights::ReadWrite)
+                    .grant_others(AccessRights::ReadOnly),
+            )
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(8127), is_ok);
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8127);
+    }
 }
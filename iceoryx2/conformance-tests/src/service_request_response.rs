@@ -20,9 +20,10 @@ pub mod service_request_response {
     use iceoryx2::node::NodeBuilder;
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
-    use iceoryx2::port::LoanError;
+    use iceoryx2::port::{LoanError, ReceiveError};
     use iceoryx2::prelude::{PortFactory, *};
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+    use iceoryx2::service::dispatch_policy::DispatchPolicy;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::testing;
     use iceoryx2_bb_conformance_test_macros::conformance_test;
@@ -1609,4 +1610,203 @@ pub mod service_request_response {
         assert_that!(active_request.is_connected(), eq false);
         assert_that!(active_request.has_disconnect_hint(), eq false);
     }
+
+    fn create_dispatching_service<S: Service>(
+        node: &Node<S>,
+        dispatch_policy: DispatchPolicy,
+    ) -> iceoryx2::service::port_factory::request_response::PortFactory<S, u64, (), u64, ()> {
+        node.service_builder(&testing::generate_service_name())
+            .request_response::<u64, u64>()
+            .dispatch_policy(dispatch_policy)
+            .max_servers(3)
+            .max_active_requests_per_client(8)
+            .create()
+            .unwrap()
+    }
+
+    #[conformance_test]
+    pub fn broadcast_is_the_default_dispatch_policy<S: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&testing::generate_service_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let _server_1 = sut.server_builder().create().unwrap();
+        let _server_2 = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        assert_that!(sut.static_config().dispatch_policy(), eq DispatchPolicy::Broadcast);
+        let pending_response = client.send_copy(0).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 2);
+    }
+
+    #[conformance_test]
+    pub fn round_robin_dispatch_delivers_requests_to_servers_in_turns<S: Service>() {
+        const NUMBER_OF_REQUESTS_PER_SERVER: usize = 2;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = create_dispatching_service(&node, DispatchPolicy::RoundRobin);
+
+        let servers = [
+            sut.server_builder().create().unwrap(),
+            sut.server_builder().create().unwrap(),
+            sut.server_builder().create().unwrap(),
+        ];
+        let client = sut.client_builder().create().unwrap();
+
+        let mut pending_responses = vec![];
+        for n in 0..servers.len() * NUMBER_OF_REQUESTS_PER_SERVER {
+            let pending_response = client.send_copy(n as u64).unwrap();
+            assert_that!(pending_response.number_of_server_connections(), eq 1);
+            pending_responses.push(pending_response);
+        }
+
+        for server in &servers {
+            let mut number_of_requests = 0;
+            while server.receive().unwrap().is_some() {
+                number_of_requests += 1;
+            }
+            assert_that!(number_of_requests, eq NUMBER_OF_REQUESTS_PER_SERVER);
+        }
+    }
+
+    #[conformance_test]
+    pub fn least_outstanding_requests_dispatch_prefers_server_with_fewest_requests<S: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = create_dispatching_service(&node, DispatchPolicy::LeastOutstandingRequests);
+
+        let servers = [
+            sut.server_builder().create().unwrap(),
+            sut.server_builder().create().unwrap(),
+        ];
+        let client = sut.client_builder().create().unwrap();
+
+        let _pending_response_1 = client.send_copy(1).unwrap();
+        let (busy_server, active_request_1) = match servers[0].receive().unwrap() {
+            Some(active_request) => (0, active_request),
+            None => (1, servers[1].receive().unwrap().unwrap()),
+        };
+        let idle_server = 1 - busy_server;
+
+        let _pending_response_2 = client.send_copy(2).unwrap();
+        assert_that!(servers[busy_server].receive().unwrap(), is_none);
+        let active_request_2 = servers[idle_server].receive().unwrap().unwrap();
+        assert_that!(*active_request_2, eq 2);
+
+        // the server that finishes its request first receives the next one
+        drop(active_request_2);
+        let _pending_response_3 = client.send_copy(3).unwrap();
+        assert_that!(servers[busy_server].receive().unwrap(), is_none);
+        assert_that!(*servers[idle_server].receive().unwrap().unwrap(), eq 3);
+
+        drop(active_request_1);
+    }
+
+    #[conformance_test]
+    pub fn sticky_per_client_dispatch_delivers_all_requests_to_the_same_server<S: Service>() {
+        const NUMBER_OF_REQUESTS: usize = 5;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = create_dispatching_service(&node, DispatchPolicy::StickyPerClient);
+
+        let servers = [
+            sut.server_builder().create().unwrap(),
+            sut.server_builder().create().unwrap(),
+            sut.server_builder().create().unwrap(),
+        ];
+        let client = sut.client_builder().create().unwrap();
+
+        let mut pending_responses = vec![];
+        for n in 0..NUMBER_OF_REQUESTS {
+            pending_responses.push(client.send_copy(n as u64).unwrap());
+        }
+
+        let mut requests_per_server = vec![];
+        for server in &servers {
+            let mut number_of_requests = 0;
+            while server.receive().unwrap().is_some() {
+                number_of_requests += 1;
+            }
+            requests_per_server.push(number_of_requests);
+        }
+
+        assert_that!(requests_per_server, contains NUMBER_OF_REQUESTS);
+        assert_that!(requests_per_server.iter().sum::<usize>(), eq NUMBER_OF_REQUESTS);
+    }
+
+    #[conformance_test]
+    pub fn unanswered_request_is_redispatched_when_server_disconnects<S: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = create_dispatching_service(&node, DispatchPolicy::RoundRobin);
+
+        let mut servers = vec![
+            sut.server_builder().create().unwrap(),
+            sut.server_builder().create().unwrap(),
+        ];
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1234).unwrap();
+        let dispatched_server = servers
+            .iter()
+            .position(|server| server.has_requests().unwrap())
+            .unwrap();
+        servers.remove(dispatched_server);
+
+        assert_that!(pending_response.receive().unwrap(), is_none);
+
+        let active_request = servers[0].receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 1234);
+        active_request.send_copy(5678).unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 5678);
+    }
+
+    #[conformance_test]
+    pub fn receive_fails_when_server_disconnects_and_no_other_server_is_available<S: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = create_dispatching_service(&node, DispatchPolicy::LeastOutstandingRequests);
+
+        let server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1234).unwrap();
+        drop(server);
+
+        assert_that!(pending_response.receive().err(), eq Some(ReceiveError::ServerDisconnected));
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn answered_request_is_not_redispatched_when_server_disconnects<S: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = create_dispatching_service(&node, DispatchPolicy::StickyPerClient);
+
+        let mut servers = vec![
+            sut.server_builder().create().unwrap(),
+            sut.server_builder().create().unwrap(),
+        ];
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1234).unwrap();
+        let dispatched_server = servers
+            .iter()
+            .position(|server| server.has_requests().unwrap())
+            .unwrap();
+        let active_request = servers[dispatched_server].receive().unwrap().unwrap();
+        active_request.send_copy(5678).unwrap();
+        drop(active_request);
+        servers.remove(dispatched_server);
+
+        assert_that!(*pending_response.receive().unwrap().unwrap(), eq 5678);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+        assert_that!(servers[0].receive().unwrap(), is_none);
+    }
 }
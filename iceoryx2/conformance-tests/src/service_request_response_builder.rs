@@ -21,6 +21,7 @@ pub mod service_request_response_builder {
     use iceoryx2::service::builder::request_response::{
         RequestResponseCreateError, RequestResponseOpenError,
     };
+    use iceoryx2::service::dispatch_policy::DispatchPolicy;
     use iceoryx2::service::port_factory::client::ClientCreateError;
     use iceoryx2::service::port_factory::server::ServerCreateError;
     use iceoryx2::service::static_config::message_type_details::TypeVariant;
@@ -528,6 +529,64 @@ pub mod service_request_response_builder {
         assert_that!(sut_open, is_ok);
     }
 
+    #[conformance_test]
+    pub fn open_verifies_request_cancellation_setting_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_request_cancellation(true)
+            .create();
+        assert_that!(sut_create, is_ok);
+        let sut_create = sut_create.unwrap();
+        assert_that!(sut_create.static_config().has_request_cancellation(), eq true);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_request_cancellation(false)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestCancellation));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_request_cancellation(true)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_verifies_dispatch_policy_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .dispatch_policy(DispatchPolicy::RoundRobin)
+            .create();
+        assert_that!(sut_create, is_ok);
+        let sut_create = sut_create.unwrap();
+        assert_that!(sut_create.static_config().dispatch_policy(), eq DispatchPolicy::RoundRobin);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .dispatch_policy(DispatchPolicy::Broadcast)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleDispatchPolicy));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut_open, is_ok);
+        assert_that!(sut_open.unwrap().static_config().dispatch_policy(), eq DispatchPolicy::RoundRobin);
+    }
+
     #[conformance_test]
     pub fn open_verifies_max_borrowed_responses_per_pending_response_correctly<Sut: Service>() {
         let service_name = generate_service_name();
@@ -15,6 +15,8 @@ use iceoryx2_bb_conformance_test_macros::conformance_test_module;
 #[allow(clippy::module_inception)]
 #[conformance_test_module]
 pub mod subscriber {
+    use iceoryx2::port::receive_order::ReceiveOrder;
+    use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::port::ReceiveError;
     use iceoryx2::service::builder::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
@@ -76,6 +78,464 @@ pub mod subscriber {
         }
     }
 
+    #[conformance_test]
+    pub fn receive_latest_without_samples_returns_none<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let _publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.receive_latest().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_returns_newest_sample_and_discards_older_ones<Sut: Service>() {
+        const BUFFER_SIZE: usize = 5;
+        const ITERATIONS: u64 = 20;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        // every discarded sample must be returned to the publisher, otherwise it runs out of
+        // samples after a few iterations
+        for i in 0..ITERATIONS {
+            for n in 0..BUFFER_SIZE as u64 {
+                assert_that!(publisher.send_copy(i * 10 + n), eq Ok(1));
+            }
+
+            let sample = sut.receive_latest().unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq i * 10 + BUFFER_SIZE as u64 - 1);
+            assert_that!(sut.receive().unwrap(), is_none);
+            assert_that!(sut.has_samples().unwrap(), eq false);
+        }
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_selects_newest_sample_across_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher_1.send_copy(1), eq Ok(1));
+        assert_that!(publisher_2.send_copy(2), eq Ok(1));
+        assert_that!(publisher_1.send_copy(3), eq Ok(1));
+
+        let sample = sut.receive_latest().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        assert_that!(sample.header().publisher_id(), eq publisher_1.id());
+        drop(sample);
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        assert_that!(publisher_2.send_copy(4), eq Ok(1));
+        assert_that!(publisher_1.send_copy(5), eq Ok(1));
+        assert_that!(publisher_2.send_copy(6), eq Ok(1));
+
+        let sample = sut.receive_latest().unwrap().unwrap();
+        assert_that!(*sample, eq 6);
+        assert_that!(sample.header().publisher_id(), eq publisher_2.id());
+        drop(sample);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_does_not_count_discarded_samples_as_missed<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        for n in 0..4 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+        assert_that!(*sut.receive_latest().unwrap().unwrap(), eq 3);
+
+        assert_that!(publisher.send_copy(4), eq Ok(1));
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 4);
+
+        assert_that!(sut.missed_samples(&publisher.id()), eq Some(0));
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_works_with_slices<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(3)
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        for n in 1..4 {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            let sample = sample.write_from_fn(|i| i as u64 * n as u64);
+            assert_that!(sample.send(), eq Ok(1));
+        }
+
+        let sample = sut.receive_latest().unwrap().unwrap();
+        assert_that!(sample.payload(), eq [0, 3, 6]);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn filter_by_user_header_discards_non_matching_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 6;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .filter_by_user_header(|user_header| *user_header % 2 == 0)
+            .create()
+            .unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let mut sample = publisher.loan_uninit().unwrap();
+            *sample.user_header_mut() = n;
+            assert_that!(sample.write_payload(n * 10).send(), eq Ok(1));
+        }
+
+        for n in (0..NUMBER_OF_SAMPLES).step_by(2) {
+            let sample = sut.receive().unwrap().unwrap();
+            assert_that!(*sample.user_header(), eq n);
+            assert_that!(*sample, eq n * 10);
+        }
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.filtered_samples(), eq NUMBER_OF_SAMPLES / 2);
+    }
+
+    #[conformance_test]
+    pub fn filtered_samples_do_not_count_against_max_borrowed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .filter(|_, payload| *payload >= 10)
+            .create()
+            .unwrap();
+
+        for value in [1, 2, 3, 20] {
+            assert_that!(publisher.send_copy(value), eq Ok(1));
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 20);
+        assert_that!(sut.filtered_samples(), eq 3);
+
+        assert_that!(publisher.send_copy(30), eq Ok(1));
+        assert_that!(sut.receive().err(), eq Some(ReceiveError::ExceedsMaxBorrows));
+        drop(sample);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 30);
+    }
+
+    #[conformance_test]
+    pub fn receive_latest_returns_newest_sample_that_passes_the_filter<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .filter(|_, payload| *payload % 2 == 0)
+            .create()
+            .unwrap();
+
+        for value in [2, 4, 6, 7, 9] {
+            assert_that!(publisher.send_copy(value), eq Ok(1));
+        }
+
+        assert_that!(*sut.receive_latest().unwrap().unwrap(), eq 6);
+        assert_that!(sut.filtered_samples(), eq 2);
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        for value in [1, 3] {
+            assert_that!(publisher.send_copy(value), eq Ok(1));
+        }
+
+        assert_that!(sut.receive_latest().unwrap(), is_none);
+        assert_that!(sut.filtered_samples(), eq 4);
+        assert_that!(sut.has_samples().unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn filter_works_with_slices<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+        let sut = service
+            .subscriber_builder()
+            .filter(|_, payload| payload.len() > 2)
+            .create()
+            .unwrap();
+
+        for n in 1..5 {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            assert_that!(sample.write_from_fn(|i| i as u64).send(), eq Ok(1));
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq [0, 1, 2]);
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq [0, 1, 2, 3]);
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.filtered_samples(), eq 2);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_without_filter_receives_every_sample<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+        assert_that!(sut.filtered_samples(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn receive_order_priority_serves_higher_priority_publishers_first<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(3)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let low_priority_publisher = service.publisher_builder().priority(1).create().unwrap();
+        let default_priority_publisher = service.publisher_builder().create().unwrap();
+        let high_priority_publisher = service.publisher_builder().priority(9).create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        for n in 0..3 {
+            assert_that!(default_priority_publisher.send_copy(n), eq Ok(1));
+            assert_that!(low_priority_publisher.send_copy(10 + n), eq Ok(1));
+            assert_that!(high_priority_publisher.send_copy(20 + n), eq Ok(1));
+        }
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+
+        assert_that!(received, eq vec![20, 21, 22, 10, 11, 12, 0, 1, 2]);
+    }
+
+    #[conformance_test]
+    pub fn receive_order_priority_serves_lower_priority_publishers_when_higher_ones_are_empty<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let low_priority_publisher = service.publisher_builder().create().unwrap();
+        let high_priority_publisher = service.publisher_builder().priority(3).create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .receive_order(ReceiveOrder::Priority)
+            .create()
+            .unwrap();
+
+        assert_that!(low_priority_publisher.send_copy(1), eq Ok(1));
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+
+        assert_that!(low_priority_publisher.send_copy(2), eq Ok(1));
+        assert_that!(high_priority_publisher.send_copy(3), eq Ok(1));
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 3);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn receive_order_priority_is_kept_when_publishers_are_replaced<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(3)
+            .create()
+            .unwrap();
+
+        let high_priority_publisher = service.publisher_builder().priority(9).create().unwrap();
+        let medium_priority_publisher = service.publisher_builder().priority(4).create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .receive_order(ReceiveOrder::Priority)
+            .create()
+            .unwrap();
+        assert_that!(sut.update_connections(), is_ok);
+
+        drop(high_priority_publisher);
+        assert_that!(sut.update_connections(), is_ok);
+
+        let low_priority_publisher = service.publisher_builder().priority(1).create().unwrap();
+        let highest_priority_publisher = service.publisher_builder().priority(7).create().unwrap();
+
+        assert_that!(low_priority_publisher.send_copy(1), eq Ok(1));
+        assert_that!(medium_priority_publisher.send_copy(4), eq Ok(1));
+        assert_that!(highest_priority_publisher.send_copy(7), eq Ok(1));
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+
+        assert_that!(received, eq vec![7, 4, 1]);
+    }
+
+    #[conformance_test]
+    pub fn receive_order_round_robin_serves_publishers_in_turn<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().priority(9).create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .receive_order(ReceiveOrder::RoundRobin)
+            .create()
+            .unwrap();
+
+        for n in 0..4 {
+            assert_that!(publisher_1.send_copy(n), eq Ok(1));
+        }
+        for n in 0..2 {
+            assert_that!(publisher_2.send_copy(10 + n), eq Ok(1));
+        }
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+
+        assert_that!(received, eq vec![0, 10, 1, 11, 2, 3]);
+    }
+
     #[conformance_test]
     #[should_panic]
     #[cfg(debug_assertions)]
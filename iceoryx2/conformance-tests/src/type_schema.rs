@@ -0,0 +1,345 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_conformance_test_macros::conformance_test_module;
+
+#[allow(clippy::module_inception)]
+#[conformance_test_module]
+pub mod type_schema {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::{
+        PublishSubscribeCreateError, PublishSubscribeOpenError,
+    };
+    use iceoryx2::service::static_config::type_schema::TypeSchemaKind;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_conformance_test_macros::conformance_test;
+    use iceoryx2_bb_container::string::StaticString;
+    use iceoryx2_bb_container::vector::{StaticVec, Vector};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use serde_json::json;
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, ZeroCopySend)]
+    #[type_schema]
+    struct Pose {
+        id: u32,
+        position: [f32; 3],
+        valid: bool,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, ZeroCopySend)]
+    #[type_schema]
+    struct Diagnostics {
+        name: StaticString<8>,
+        codes: StaticVec<u16, 4>,
+        pose: Pose,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Vec2")]
+    #[type_schema]
+    struct Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Vec2")]
+    #[type_schema]
+    struct SwappedVec2 {
+        y: f32,
+        x: f32,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Vec2")]
+    struct OpaqueVec2 {
+        x: f32,
+        y: f32,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, ZeroCopySend)]
+    #[type_schema]
+    struct ManyFields {
+        s0: StaticString<4>,
+        s1: StaticString<4>,
+        s2: StaticString<4>,
+        s3: StaticString<4>,
+        s4: StaticString<4>,
+        s5: StaticString<4>,
+        s6: StaticString<4>,
+        s7: StaticString<4>,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, ZeroCopySend)]
+    #[type_schema]
+    struct TooManyFields {
+        m0: ManyFields,
+        m1: ManyFields,
+        m2: ManyFields,
+        m3: ManyFields,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, ZeroCopySend)]
+    #[type_schema]
+    struct TooLongFieldNames {
+        field_with_a_very_long_name_00: u8,
+        field_with_a_very_long_name_01: u8,
+        field_with_a_very_long_name_02: u8,
+        field_with_a_very_long_name_03: u8,
+        field_with_a_very_long_name_04: u8,
+        field_with_a_very_long_name_05: u8,
+        field_with_a_very_long_name_06: u8,
+        field_with_a_very_long_name_07: u8,
+        field_with_a_very_long_name_08: u8,
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "type_schema_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn as_bytes<T>(value: &T) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts((value as *const T).cast::<u8>(), core::mem::size_of::<T>())
+        }
+    }
+
+    #[conformance_test]
+    pub fn type_schema_is_stored_in_static_config<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<Pose>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+
+        let details = sut.static_config().message_type_details();
+        let schema = details.payload.schema();
+        let fields = schema.fields();
+        assert_that!(fields, len 5);
+
+        assert_that!(fields[0].kind(), eq TypeSchemaKind::Struct);
+        assert_that!(fields[0].size(), eq core::mem::size_of::<Pose>());
+        assert_that!(fields[0].number_of_members(), eq 3);
+
+        assert_that!(schema.field_name(&fields[1]), eq "id");
+        assert_that!(fields[1].kind(), eq TypeSchemaKind::U32);
+        assert_that!(fields[1].offset(), eq 0);
+
+        assert_that!(schema.field_name(&fields[2]), eq "position");
+        assert_that!(fields[2].kind(), eq TypeSchemaKind::Array);
+        assert_that!(fields[2].offset(), eq core::mem::offset_of!(Pose, position));
+        assert_that!(fields[2].capacity(), eq 3);
+        assert_that!(fields[3].kind(), eq TypeSchemaKind::F32);
+
+        assert_that!(schema.field_name(&fields[4]), eq "valid");
+        assert_that!(fields[4].kind(), eq TypeSchemaKind::Bool);
+        assert_that!(fields[4].offset(), eq core::mem::offset_of!(Pose, valid));
+
+        let fields = details.user_header.schema().fields();
+        assert_that!(fields, len 1);
+        assert_that!(fields[0].kind(), eq TypeSchemaKind::U64);
+    }
+
+    #[conformance_test]
+    pub fn type_schema_is_empty_for_types_without_schema<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<OpaqueVec2>()
+            .create()
+            .unwrap();
+
+        let details = sut.static_config().message_type_details();
+        assert_that!(details.payload.schema().is_empty(), eq true);
+        assert_that!(details.user_header.schema().is_empty(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn create_fails_when_type_schema_exceeds_the_maximum_number_of_fields<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<TooManyFields>()
+            .create();
+
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::TypeSchemaExceedsLimits));
+    }
+
+    #[conformance_test]
+    pub fn create_fails_when_field_names_of_type_schema_exceed_the_maximum_length<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<TooLongFieldNames>()
+            .create();
+
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::TypeSchemaExceedsLimits));
+    }
+
+    #[conformance_test]
+    pub fn open_with_same_type_name_and_same_schema_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Vec2>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Vec2>()
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_with_same_type_name_and_different_schema_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Vec2>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<SwappedVec2>()
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[conformance_test]
+    pub fn open_with_same_type_name_and_without_schema_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Vec2>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<OpaqueVec2>()
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn received_sample_can_be_decoded_with_type_schema<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<Pose>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher
+            .send_copy(Pose {
+                id: 7,
+                position: [1.5, -2.0, 3.25],
+                valid: true,
+            })
+            .unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+
+        let schema = sut.static_config().message_type_details().payload.schema();
+        let decoded = serde_json::to_value(schema.decode(as_bytes(sample.payload()))).unwrap();
+
+        assert_that!(
+            decoded,
+            eq json!({"id": 7, "position": [1.5, -2.0, 3.25], "valid": true})
+        );
+    }
+
+    #[conformance_test]
+    pub fn containers_can_be_decoded_with_type_schema<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<Diagnostics>()
+            .create()
+            .unwrap();
+
+        let mut codes = StaticVec::<u16, 4>::new();
+        codes.push(404).unwrap();
+        codes.push(503).unwrap();
+        let value = Diagnostics {
+            name: StaticString::from_bytes(b"lidar").unwrap(),
+            codes,
+            pose: Pose {
+                id: 1,
+                position: [0.0, 0.5, 1.0],
+                valid: false,
+            },
+        };
+
+        let schema = sut.static_config().message_type_details().payload.schema();
+        let decoded = serde_json::to_value(schema.decode(as_bytes(&value))).unwrap();
+
+        assert_that!(
+            decoded,
+            eq json!({
+                "name": "lidar",
+                "codes": [404, 503],
+                "pose": {"id": 1, "position": [0.0, 0.5, 1.0], "valid": false}
+            })
+        );
+    }
+
+    #[conformance_test]
+    pub fn decoding_fails_when_bytes_are_too_short<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<Pose>()
+            .create()
+            .unwrap();
+
+        let schema = sut.static_config().message_type_details().payload.schema();
+        let decoded = serde_json::to_value(schema.decode(&[0u8; 4]));
+
+        assert_that!(decoded, is_err);
+    }
+}
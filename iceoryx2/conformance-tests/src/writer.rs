@@ -130,6 +130,34 @@ pub mod writer {
         assert_that!(entry_handle_mut2, is_ok);
     }
 
+    #[conformance_test]
+    pub fn slice_entry_handle_mut_cannot_be_acquired_twice<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u64>(0, 4, &[0])
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let entry_handle_mut1 = writer.entry_slice::<u64>(&0);
+        assert_that!(entry_handle_mut1, is_ok);
+        let entry_handle_mut2 = writer.entry_slice::<u64>(&0);
+        assert_that!(entry_handle_mut2, is_err);
+        assert_that!(
+            entry_handle_mut2.err().unwrap(),
+            eq EntryHandleMutError::HandleAlreadyExists
+        );
+
+        drop(entry_handle_mut1);
+        let entry_handle_mut2 = writer.entry_slice::<u64>(&0);
+        assert_that!(entry_handle_mut2, is_ok);
+    }
+
     #[conformance_test]
     pub fn entry_handle_mut_prevents_another_writer<Sut: Service>() {
         let service_name = generate_name();
@@ -153,6 +181,99 @@ pub mod writer {
         assert_that!(res.err().unwrap(), eq WriterCreateError::ExceedsMaxSupportedWriters);
     }
 
+    #[conformance_test]
+    pub fn entry_owned_by_another_writer_cannot_be_acquired<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .add_slice::<u8>(1, 8, b"")
+            .max_writers(2)
+            .create()
+            .unwrap();
+
+        let writer1 = sut.writer_builder().create().unwrap();
+        let writer2 = sut.writer_builder().create().unwrap();
+
+        let _entry_handle_mut = writer1.entry::<u64>(&0).unwrap();
+        let _slice_entry_handle_mut = writer2.entry_slice::<u8>(&1).unwrap();
+
+        let res = writer2.entry::<u64>(&0);
+        assert_that!(res, is_err);
+        assert_that!(res.err().unwrap(), eq EntryHandleMutError::EntryOwnedByAnotherWriter);
+
+        let res = writer1.entry_slice::<u8>(&1);
+        assert_that!(res, is_err);
+        assert_that!(res.err().unwrap(), eq EntryHandleMutError::EntryOwnedByAnotherWriter);
+    }
+
+    #[conformance_test]
+    pub fn writer_keeps_entry_ownership_when_entry_handle_mut_is_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .max_writers(2)
+            .create()
+            .unwrap();
+
+        let writer1 = sut.writer_builder().create().unwrap();
+        let writer2 = sut.writer_builder().create().unwrap();
+
+        let entry_handle_mut = writer1.entry::<u64>(&0).unwrap();
+        drop(entry_handle_mut);
+
+        let res = writer2.entry::<u64>(&0);
+        assert_that!(res, is_err);
+        assert_that!(res.err().unwrap(), eq EntryHandleMutError::EntryOwnedByAnotherWriter);
+
+        let entry_handle_mut = writer1.entry::<u64>(&0);
+        assert_that!(entry_handle_mut, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn entry_ownership_is_released_when_writer_is_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .max_writers(2)
+            .create()
+            .unwrap();
+
+        let writer1 = sut.writer_builder().create().unwrap();
+        let writer2 = sut.writer_builder().create().unwrap();
+
+        let entry_handle_mut = writer1.entry::<u64>(&0).unwrap();
+        drop(writer1);
+
+        // the entry handle keeps the writer alive
+        let res = writer2.entry::<u64>(&0);
+        assert_that!(res, is_err);
+        assert_that!(res.err().unwrap(), eq EntryHandleMutError::EntryOwnedByAnotherWriter);
+
+        drop(entry_handle_mut);
+
+        let entry_handle_mut = writer2.entry::<u64>(&0);
+        assert_that!(entry_handle_mut, is_ok);
+        entry_handle_mut.unwrap().update_with_copy(1234);
+
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u64>(&0).unwrap().get(), eq 1234);
+    }
+
     #[conformance_test]
     pub fn entry_value_can_still_be_used_after_every_previous_service_state_owner_was_dropped<
         Sut: Service,
@@ -518,4 +639,47 @@ pub mod writer {
         }
         let _entry_handle_mut = entry_value_uninit.update();
     }
+
+    #[conformance_test]
+    pub fn handle_cannot_be_acquired_for_slice_entry_with_custom_key_type<Sut: Service>() {
+        type KeyType = u64;
+        let key: KeyType = 3;
+        let key_ptr: *const KeyType = &key;
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _service = node
+            .service_builder(&service_name)
+            .blackboard_creator::<KeyType>()
+            .add_slice::<u8>(key, 8, b"abc")
+            .create()
+            .unwrap();
+
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .blackboard_opener::<CustomKeyMarker>()
+                .__internal_set_key_type_details(&TypeDetail::new::<KeyType>(
+                    TypeVariant::FixedSize,
+                ))
+                .__internal_set_key_eq_cmp_func(Box::new(|lhs, rhs| {
+                    KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::default_key_eq_comparison::<KeyType>(
+                        lhs, rhs,
+                    )
+                }))
+                .open()
+                .unwrap()
+        };
+        let writer = sut.writer_builder().create().unwrap();
+
+        let type_details = TypeDetail::new::<u8>(TypeVariant::Dynamic);
+        let entry_handle_mut =
+            unsafe { writer.__internal_entry(key_ptr as *const u8, &type_details) };
+        assert_that!(entry_handle_mut, is_err);
+        assert_that!(
+            entry_handle_mut.err().unwrap(),
+            eq EntryHandleMutError::EntryDoesNotExist
+        );
+    }
 }
@@ -35,3 +35,9 @@ instantiate_conformance_tests_with_module!(
     iceoryx2_conformance_tests::publisher,
     iceoryx2::service::local_threadsafe::Service
 );
+
+instantiate_conformance_tests_with_module!(
+    ipc_huge_page,
+    iceoryx2_conformance_tests::publisher,
+    iceoryx2::service::ipc_huge_page::Service
+);
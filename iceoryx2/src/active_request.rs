@@ -47,12 +47,12 @@ use core::{
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
-use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::{adaptive_wait::AdaptiveWaitBuilder, unique_system_id::UniqueSystemId};
 use iceoryx2_cal::{
     arc_sync_policy::ArcSyncPolicy, shm_allocator::AllocationStrategy,
     zero_copy_connection::ChannelId,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
 use crate::{
     port::{
@@ -61,6 +61,7 @@ use crate::{
         server::{SharedServerState, INVALID_CONNECTION_ID},
         LoanError, SendError,
     },
+    prelude::UnableToDeliverStrategy,
     raw_sample::{RawSample, RawSampleMut},
     response_mut::ResponseMut,
     response_mut_uninit::ResponseMutUninit,
@@ -71,6 +72,14 @@ use crate::{
     },
 };
 
+/// The state of the [`Response`](crate::response::Response) stream that is shared between an
+/// [`ActiveRequest`] and its loaned [`ResponseMut`]s.
+#[derive(Debug, Default)]
+pub(crate) struct ResponseStreamState {
+    pub(crate) loan_counter: IoxAtomicUsize,
+    pub(crate) is_finished: IoxAtomicBool,
+}
+
 /// Represents a one-to-one connection to a [`Client`](crate::port::client::Client)
 /// holding the corresponding
 /// [`PendingResponse`](crate::pending_response::PendingResponse) that is coupled
@@ -92,7 +101,7 @@ pub struct ActiveRequest<
         RequestPayload,
     >,
     pub(crate) shared_state: Service::ArcThreadSafetyPolicy<SharedServerState<Service>>,
-    pub(crate) shared_loan_counter: Arc<IoxAtomicUsize>,
+    pub(crate) response_stream: Arc<ResponseStreamState>,
     pub(crate) max_loan_count: usize,
     pub(crate) details: ChunkDetails,
     pub(crate) request_id: u64,
@@ -165,10 +174,12 @@ impl<
     for ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
 {
     fn drop(&mut self) {
-        self.shared_state
-            .lock()
+        let shared_state = self.shared_state.lock();
+        shared_state
             .request_receiver
             .release_offset(&self.details, ChannelId::new(0));
+        shared_state.release_dispatched_request();
+        drop(shared_state);
         self.finish();
     }
 }
@@ -183,10 +194,16 @@ impl<
 {
     fn finish(&self) {
         if self.connection_id != INVALID_CONNECTION_ID {
-            self.shared_state
-                .lock()
+            let shared_state = self.shared_state.lock();
+            shared_state.response_sender.invalidate_channel_state(
+                self.channel_id,
+                self.connection_id,
+                self.request_id,
+            );
+            // the client may wait for further responses and must learn about the disconnect
+            shared_state
                 .response_sender
-                .invalidate_channel_state(self.channel_id, self.connection_id, self.request_id);
+                .wake_up(self.channel_id, self.connection_id);
         }
     }
 
@@ -220,6 +237,21 @@ impl<
         }
     }
 
+    /// Returns [`true`] when the timeout the [`Client`](crate::port::client::Client) defined
+    /// with [`RequestMut::set_timeout()`](crate::request_mut::RequestMut::set_timeout()) has
+    /// passed, otherwise [`false`].
+    pub fn has_timed_out(&self) -> bool {
+        self.header().has_timed_out()
+    }
+
+    /// Returns [`true`] when the [`Client`](crate::port::client::Client) is no longer
+    /// interested in the [`ResponseMut`]s, either since the corresponding
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) went out of scope or since
+    /// the timeout of the request has passed.
+    pub fn is_cancelled(&self) -> bool {
+        self.has_timed_out() || !self.is_connected()
+    }
+
     /// Returns a reference to the payload of the received
     /// [`RequestMut`](crate::request_mut::RequestMut)
     pub fn payload(&self) -> &RequestPayload {
@@ -244,8 +276,87 @@ impl<
         UniqueClientId(UniqueSystemId::from(self.details.origin))
     }
 
+    /// Returns the number of [`ResponseMut`]s the [`Server`](crate::port::server::Server) can
+    /// still loan until the [`Client`](crate::port::client::Client) grants further credits with
+    /// [`PendingResponse::grant_credits()`](crate::pending_response::PendingResponse::grant_credits()).
+    /// If the [`Client`](crate::port::client::Client) does not use flow control it returns
+    /// [`None`].
+    pub fn available_credits(&self) -> Option<u64> {
+        if self.connection_id != INVALID_CONNECTION_ID {
+            self.shared_state
+                .lock()
+                .response_sender
+                .credits(self.channel_id, self.connection_id)
+        } else {
+            None
+        }
+    }
+
+    fn try_acquire_credit(&self) -> bool {
+        self.connection_id == INVALID_CONNECTION_ID
+            || self
+                .shared_state
+                .lock()
+                .response_sender
+                .acquire_credit(self.channel_id, self.connection_id)
+    }
+
+    fn acquire_credit(&self) -> Result<(), LoanError> {
+        let msg = "Unable to loan memory for Response";
+        let mut has_credit = self.try_acquire_credit();
+
+        if !has_credit
+            && self
+                .shared_state
+                .lock()
+                .response_sender
+                .unable_to_deliver_strategy
+                == UnableToDeliverStrategy::Block
+        {
+            let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                with LoanError::InternalFailure,
+                "{msg} since the adaptive wait for further credits could not be created.");
+
+            fail!(from self, when adaptive_wait.wait_while(|| {
+                    has_credit = self.try_acquire_credit();
+                    !has_credit && self.is_connected()
+                }),
+                with LoanError::InternalFailure,
+                "{msg} since the wait for further credits failed.");
+        }
+
+        if !has_credit {
+            fail!(from self, with LoanError::ExceedsGrantedCredits,
+                "{msg} since the client has not granted further credits.");
+        }
+
+        Ok(())
+    }
+
+    fn release_loan(&self) {
+        self.response_stream
+            .loan_counter
+            .fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn release_loan_and_credit(&self) {
+        self.release_loan();
+        if self.connection_id != INVALID_CONNECTION_ID {
+            self.shared_state
+                .lock()
+                .response_sender
+                .return_credit(self.channel_id, self.connection_id);
+        }
+    }
+
     fn increment_loan_counter(&self) -> Result<(), LoanError> {
-        let mut current_loan_count = self.shared_loan_counter.load(Ordering::Relaxed);
+        if self.response_stream.is_finished.load(Ordering::Relaxed) {
+            fail!(from self, with LoanError::ResponseStreamFinished,
+                "Unable to loan memory for Response since the final response was already sent.");
+        }
+
+        let loan_counter = &self.response_stream.loan_counter;
+        let mut current_loan_count = loan_counter.load(Ordering::Relaxed);
         loop {
             if self.max_loan_count <= current_loan_count {
                 fail!(from self,
@@ -254,7 +365,7 @@ impl<
                 self.max_loan_count);
             }
 
-            match self.shared_loan_counter.compare_exchange(
+            match loan_counter.compare_exchange(
                 current_loan_count,
                 current_loan_count + 1,
                 Ordering::Relaxed,
@@ -308,11 +419,23 @@ impl<
     ) -> Result<ResponseMutUninit<Service, MaybeUninit<ResponsePayload>, ResponseHeader>, LoanError>
     {
         self.increment_loan_counter()?;
+        if let Err(e) = self.acquire_credit() {
+            self.release_loan();
+            return Err(e);
+        }
         let shared_state = self.shared_state.lock();
 
-        let chunk = shared_state
+        let chunk = match shared_state
             .response_sender
-            .allocate(shared_state.response_sender.sample_layout(1))?;
+            .allocate(shared_state.response_sender.sample_layout(1))
+        {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                drop(shared_state);
+                self.release_loan_and_credit();
+                return Err(e);
+            }
+        };
 
         let header_ptr: *mut service::header::request_response::ResponseHeader =
             chunk.header.cast();
@@ -324,6 +447,7 @@ impl<
                 )),
                 request_id: self.request_id,
                 number_of_elements: 1,
+                is_final: false,
             })
         };
         unsafe { user_header_ptr.write(ResponseHeader::default()) };
@@ -339,12 +463,13 @@ impl<
         Ok(ResponseMutUninit {
             response: ResponseMut {
                 ptr,
-                shared_loan_counter: self.shared_loan_counter.clone(),
+                response_stream: self.response_stream.clone(),
                 shared_state: self.shared_state.clone(),
                 offset_to_chunk: chunk.offset,
                 channel_id: self.channel_id,
                 connection_id: self.connection_id,
                 sample_size: chunk.size,
+                holds_credit: true,
                 _response_payload: PhantomData,
                 _response_header: PhantomData,
             },
@@ -556,9 +681,22 @@ impl<
         }
 
         self.increment_loan_counter()?;
+        drop(shared_state);
+        if let Err(e) = self.acquire_credit() {
+            self.release_loan();
+            return Err(e);
+        }
+        let shared_state = self.shared_state.lock();
 
         let response_layout = shared_state.response_sender.sample_layout(slice_len);
-        let chunk = shared_state.response_sender.allocate(response_layout)?;
+        let chunk = match shared_state.response_sender.allocate(response_layout) {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                drop(shared_state);
+                self.release_loan_and_credit();
+                return Err(e);
+            }
+        };
 
         let header_ptr: *mut service::header::request_response::ResponseHeader =
             chunk.header.cast();
@@ -570,6 +708,7 @@ impl<
                 )),
                 request_id: self.request_id,
                 number_of_elements: slice_len as _,
+                is_final: false,
             })
         };
         unsafe { user_header_ptr.write(ResponseHeader::default()) };
@@ -592,12 +731,13 @@ impl<
         Ok(ResponseMutUninit {
             response: ResponseMut {
                 ptr,
-                shared_loan_counter: self.shared_loan_counter.clone(),
+                response_stream: self.response_stream.clone(),
                 shared_state: self.shared_state.clone(),
                 offset_to_chunk: chunk.offset,
                 channel_id: self.channel_id,
                 connection_id: self.connection_id,
                 sample_size: chunk.size,
+                holds_credit: true,
                 _response_payload: PhantomData,
                 _response_header: PhantomData,
             },
@@ -0,0 +1,585 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Runtime agnostic [`Future`]s and [`Stream`]s for the receiving ports of iceoryx2.
+//!
+//! The wrappers are driven by a [`FileDescriptor`] that becomes readable whenever new data
+//! arrives. The [`AsyncListener`] uses the one of its [`Listener`]. The [`AsyncReceiver`] creates
+//! a [`WakeUpListener`] for its port, see [`TryReceive::create_wake_up_listener()`], that is
+//! notified by every sender that delivers data to the port or disconnects from it. No
+//! additional event service or [`Notifier`](crate::port::notifier::Notifier) is required.
+//!
+//! The connection to the executor is established via the [`AsyncFd`] trait. It has to be
+//! implemented once for the reactor of the executor that shall drive the [`Future`]s, for
+//! instance on top of `tokio::io::unix::AsyncFd` or `async_io::Async`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use core::task::{Context, Poll};
+//! use iceoryx2::asynchronous::{AsyncCreateError, AsyncFd, AsyncReceiver};
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_posix::file_descriptor::FileDescriptor;
+//!
+//! // a reactor that has no idea about file descriptors, wakes the task immediately and
+//! // turns every future into a polling loop
+//! struct BusyFd;
+//!
+//! impl AsyncFd for BusyFd {
+//!     fn register(_fd: &FileDescriptor) -> Result<Self, AsyncCreateError> {
+//!         Ok(BusyFd)
+//!     }
+//!
+//!     fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+//!         cx.waker().wake_by_ref();
+//!         Poll::Pending
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service_name: ServiceName = "My/Funk/ServiceName".try_into()?;
+//!
+//! let pubsub = node.service_builder(&service_name)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let mut subscriber = AsyncReceiver::<_, BusyFd>::new(pubsub.subscriber_builder().create()?)?;
+//!
+//! let receive_samples = async move {
+//!     while let Ok(Some(sample)) = subscriber.receive().await {
+//!         println!("received: {:?}", *sample);
+//!     }
+//! };
+//! // spawn receive_samples on the executor of your choice
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::event::{Event, ListenerWaitError};
+use iceoryx2_cal::zero_copy_connection::ChannelId;
+
+use crate::active_request::ActiveRequest;
+use crate::pending_response::PendingResponse;
+use crate::port::details::receiver::Receiver;
+use crate::port::event_id::EventId;
+use crate::port::listener::Listener;
+use crate::port::server::Server;
+use crate::port::subscriber::Subscriber;
+use crate::port::update_connections::UpdateConnections;
+use crate::port::ReceiveError;
+use crate::response::Response;
+use crate::sample::Sample;
+use crate::service;
+
+/// Defines the failures that can occur when an async wrapper is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AsyncCreateError {
+    /// The reactor of the executor was unable to monitor the [`FileDescriptor`].
+    UnableToRegisterFileDescriptor,
+    /// The [`WakeUpListener`] of the port could not be created.
+    UnableToCreateWakeUpListener,
+}
+
+impl core::fmt::Display for AsyncCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AsyncCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for AsyncCreateError {}
+
+/// Connects a [`FileDescriptor`] to the reactor of an executor.
+pub trait AsyncFd: Sized {
+    /// Registers the [`FileDescriptor`] at the reactor. The [`FileDescriptor`] outlives the
+    /// returned object but the object must not close it.
+    fn register(fd: &FileDescriptor) -> Result<Self, AsyncCreateError>;
+
+    /// Returns [`Poll::Ready`] when the [`FileDescriptor`] might be readable. Otherwise,
+    /// [`Poll::Pending`] is returned and the [`Waker`](core::task::Waker) of the [`Context`] must
+    /// be woken as soon as it becomes readable. The async wrappers always consume all pending
+    /// events before they poll again, so edge-triggered reactors are supported as well.
+    fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<()>;
+}
+
+/// A port that can receive data without blocking. It is implemented for the
+/// [`Subscriber`], the [`Server`] and the [`PendingResponse`].
+pub trait TryReceive {
+    /// The [`Service`](crate::service::Service) of the port.
+    type Service: service::Service;
+    /// The received data.
+    type Item;
+    /// The failure that can occur while receiving.
+    type Error;
+
+    /// Receives the next item. If none is available [`None`] is returned.
+    fn try_receive(&self) -> Result<Option<Self::Item>, Self::Error>;
+
+    /// Returns [`true`] when the port will never receive any new items. Items that were
+    /// already delivered can still be received.
+    fn is_terminated(&self) -> bool {
+        false
+    }
+
+    /// Creates the [`WakeUpListener`] of the port that is notified whenever a sender delivered
+    /// new data to the port or disconnected from it. When it is called again, the previously
+    /// created [`WakeUpListener`] is no longer notified.
+    fn create_wake_up_listener(&self) -> Result<WakeUpListener<Self::Service>, AsyncCreateError>;
+}
+
+/// Is notified by the senders of a port whenever they delivered new data to the port or
+/// disconnected from it. It is created with [`TryReceive::create_wake_up_listener()`].
+#[derive(Debug)]
+pub struct WakeUpListener<Service: service::Service> {
+    listener: <Service::Event as Event>::Listener,
+}
+
+impl<Service: service::Service> WakeUpListener<Service> {
+    fn new(receiver: &Receiver<Service>, channel_id: ChannelId) -> Result<Self, AsyncCreateError> {
+        match receiver.create_wake_up_listener(channel_id) {
+            Ok(listener) => Ok(Self { listener }),
+            Err(e) => {
+                fail!(from "WakeUpListener::new()", with AsyncCreateError::UnableToCreateWakeUpListener,
+                    "Unable to create the wake up listener ({:?}).", e);
+            }
+        }
+    }
+
+    /// Consumes all pending notifications without blocking.
+    pub fn consume_all(&self) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        fail!(from self, when self.listener.try_wait_all(|_| {}),
+            "Failed to consume the pending notifications of the wake up listener.");
+        Ok(())
+    }
+}
+
+impl<Service: service::Service> FileDescriptorBased for WakeUpListener<Service>
+where
+    <Service::Event as Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<Service: service::Service> SynchronousMultiplexing for WakeUpListener<Service> where
+    <Service::Event as Event>::Listener: SynchronousMultiplexing
+{
+}
+
+fn update_connections_of<Port: UpdateConnections>(port: &Port) {
+    // connects the port to all senders right away so that they learn about the new wake up
+    // listener before the first receive call
+    if let Err(e) = port.update_connections() {
+        warn!(from "TryReceive::create_wake_up_listener()",
+            "Unable to update the connections of the port ({:?}).", e);
+    }
+}
+
+/// Async wrapper around a [`Listener`].
+#[derive(Debug)]
+pub struct AsyncListener<Service: service::Service, Fd: AsyncFd> {
+    // declared before the listener so that the registration is released before the
+    // file descriptor is closed
+    fd: Fd,
+    listener: Listener<Service>,
+}
+
+impl<Service: service::Service, Fd: AsyncFd> AsyncListener<Service, Fd>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+{
+    /// Creates a new [`AsyncListener`] and registers the [`FileDescriptor`] of the [`Listener`]
+    /// at the reactor.
+    pub fn new(listener: Listener<Service>) -> Result<Self, AsyncCreateError> {
+        Ok(Self {
+            fd: Fd::register(listener.file_descriptor())?,
+            listener,
+        })
+    }
+}
+
+impl<Service: service::Service, Fd: AsyncFd> AsyncListener<Service, Fd> {
+    /// Returns a reference to the underlying [`Listener`].
+    pub fn listener(&self) -> &Listener<Service> {
+        &self.listener
+    }
+
+    /// Releases the registration and returns the underlying [`Listener`].
+    pub fn into_inner(self) -> Listener<Service> {
+        drop(self.fd);
+        self.listener
+    }
+
+    /// Polls for the next [`EventId`].
+    pub fn poll_wait_one(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<EventId, ListenerWaitError>> {
+        loop {
+            match self.listener.try_wait_one() {
+                Ok(Some(event_id)) => return Poll::Ready(Ok(event_id)),
+                Err(e) => return Poll::Ready(Err(e)),
+                Ok(None) => {
+                    if self.fd.poll_readable(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns a [`Future`] that resolves with the next [`EventId`].
+    pub fn wait_one(&mut self) -> WaitOne<'_, Service, Fd> {
+        WaitOne { listener: self }
+    }
+}
+
+impl<Service: service::Service, Fd: AsyncFd> Stream for AsyncListener<Service, Fd> {
+    type Item = Result<EventId, ListenerWaitError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: the wrapper is not structurally pinned, none of its fields is moved out of
+        // the pinned reference
+        unsafe { self.get_unchecked_mut() }
+            .poll_wait_one(cx)
+            .map(Some)
+    }
+}
+
+/// [`Future`] returned by [`AsyncListener::wait_one()`].
+#[derive(Debug)]
+pub struct WaitOne<'a, Service: service::Service, Fd: AsyncFd> {
+    listener: &'a mut AsyncListener<Service, Fd>,
+}
+
+impl<Service: service::Service, Fd: AsyncFd> Future for WaitOne<'_, Service, Fd> {
+    type Output = Result<EventId, ListenerWaitError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().listener.poll_wait_one(cx)
+    }
+}
+
+/// Async wrapper around a port that implements [`TryReceive`]. It is driven by the
+/// [`WakeUpListener`] of the port.
+#[derive(Debug)]
+pub struct AsyncReceiver<Port: TryReceive, Fd: AsyncFd> {
+    // declared before the wake up listener so that the registration is released before the
+    // file descriptor is closed
+    fd: Fd,
+    wake_up_listener: WakeUpListener<Port::Service>,
+    port: Port,
+}
+
+impl<Port: TryReceive, Fd: AsyncFd> AsyncReceiver<Port, Fd>
+where
+    <<Port::Service as service::Service>::Event as Event>::Listener: SynchronousMultiplexing,
+{
+    /// Creates a new [`AsyncReceiver`] and registers the [`FileDescriptor`] of the
+    /// [`WakeUpListener`] of the port at the reactor.
+    pub fn new(port: Port) -> Result<Self, AsyncCreateError> {
+        let wake_up_listener = port.create_wake_up_listener()?;
+
+        Ok(Self {
+            fd: Fd::register(wake_up_listener.file_descriptor())?,
+            wake_up_listener,
+            port,
+        })
+    }
+}
+
+impl<Port: TryReceive, Fd: AsyncFd> AsyncReceiver<Port, Fd> {
+    /// Returns a reference to the underlying port.
+    pub fn port(&self) -> &Port {
+        &self.port
+    }
+
+    /// Releases the registration and the [`WakeUpListener`] and returns the underlying port.
+    pub fn into_inner(self) -> Port {
+        drop(self.fd);
+        drop(self.wake_up_listener);
+        self.port
+    }
+
+    /// Polls for the next item. Returns [`None`] when the port is terminated and all items
+    /// were received.
+    pub fn poll_receive(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Port::Item>, Port::Error>> {
+        loop {
+            // the sender notifies after the delivery, therefore the notifications must be
+            // consumed before the port is checked for new data
+            if let Err(e) = self.wake_up_listener.consume_all() {
+                warn!(from "AsyncReceiver::poll_receive()",
+                    "Unable to consume the pending notifications ({:?}).", e);
+            }
+
+            let is_terminated = self.port.is_terminated();
+            match self.port.try_receive() {
+                Ok(Some(item)) => return Poll::Ready(Ok(Some(item))),
+                Err(e) => return Poll::Ready(Err(e)),
+                Ok(None) => {
+                    if is_terminated {
+                        return Poll::Ready(Ok(None));
+                    }
+
+                    if self.fd.poll_readable(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns a [`Future`] that resolves with the next item or [`None`] when the port is
+    /// terminated and all items were received.
+    pub fn receive(&mut self) -> Receive<'_, Port, Fd> {
+        Receive { receiver: self }
+    }
+}
+
+impl<Port: TryReceive, Fd: AsyncFd> Stream for AsyncReceiver<Port, Fd> {
+    type Item = Result<Port::Item, Port::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: the wrapper is not structurally pinned, none of its fields is moved out of
+        // the pinned reference
+        unsafe { self.get_unchecked_mut() }
+            .poll_receive(cx)
+            .map(|result| result.transpose())
+    }
+}
+
+/// [`Future`] returned by [`AsyncReceiver::receive()`].
+#[derive(Debug)]
+pub struct Receive<'a, Port: TryReceive, Fd: AsyncFd> {
+    receiver: &'a mut AsyncReceiver<Port, Fd>,
+}
+
+impl<Port: TryReceive, Fd: AsyncFd> Future for Receive<'_, Port, Fd> {
+    type Output = Result<Option<Port::Item>, Port::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().receiver.poll_receive(cx)
+    }
+}
+
+/// Async wrapper around a [`Subscriber`].
+pub type AsyncSubscriber<Service, Payload, UserHeader, Fd> =
+    AsyncReceiver<Subscriber<Service, Payload, UserHeader>, Fd>;
+
+/// Async wrapper around a [`Server`].
+pub type AsyncServer<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader, Fd> =
+    AsyncReceiver<
+        Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        Fd,
+    >;
+
+/// Async wrapper around a [`PendingResponse`]. The [`Stream`] ends when the
+/// [`PendingResponse`] is no longer connected and all [`Response`]s were received.
+pub type AsyncPendingResponse<
+    Service,
+    RequestPayload,
+    RequestHeader,
+    ResponsePayload,
+    ResponseHeader,
+    Fd,
+> = AsyncReceiver<
+    PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+    Fd,
+>;
+
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > TryReceive for Subscriber<Service, Payload, UserHeader>
+{
+    type Service = Service;
+    type Item = Sample<Service, Payload, UserHeader>;
+    type Error = ReceiveError;
+
+    fn try_receive(&self) -> Result<Option<Self::Item>, Self::Error> {
+        self.receive()
+    }
+
+    fn create_wake_up_listener(&self) -> Result<WakeUpListener<Service>, AsyncCreateError> {
+        let wake_up_listener = WakeUpListener::new(
+            &self.subscriber_shared_state.lock().receiver,
+            ChannelId::new(0),
+        )?;
+        update_connections_of(self);
+        Ok(wake_up_listener)
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > TryReceive for Subscriber<Service, [Payload], UserHeader>
+{
+    type Service = Service;
+    type Item = Sample<Service, [Payload], UserHeader>;
+    type Error = ReceiveError;
+
+    fn try_receive(&self) -> Result<Option<Self::Item>, Self::Error> {
+        self.receive()
+    }
+
+    fn create_wake_up_listener(&self) -> Result<WakeUpListener<Service>, AsyncCreateError> {
+        let wake_up_listener = WakeUpListener::new(
+            &self.subscriber_shared_state.lock().receiver,
+            ChannelId::new(0),
+        )?;
+        update_connections_of(self);
+        Ok(wake_up_listener)
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ZeroCopySend,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > TryReceive
+    for Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    type Service = Service;
+    type Item =
+        ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>;
+    type Error = ReceiveError;
+
+    fn try_receive(&self) -> Result<Option<Self::Item>, Self::Error> {
+        self.receive()
+    }
+
+    fn create_wake_up_listener(&self) -> Result<WakeUpListener<Service>, AsyncCreateError> {
+        let wake_up_listener = WakeUpListener::new(
+            &self.shared_state.lock().request_receiver,
+            ChannelId::new(0),
+        )?;
+        update_connections_of(self);
+        Ok(wake_up_listener)
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ZeroCopySend,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > TryReceive
+    for Server<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>
+{
+    type Service = Service;
+    type Item =
+        ActiveRequest<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>;
+    type Error = ReceiveError;
+
+    fn try_receive(&self) -> Result<Option<Self::Item>, Self::Error> {
+        self.receive()
+    }
+
+    fn create_wake_up_listener(&self) -> Result<WakeUpListener<Service>, AsyncCreateError> {
+        let wake_up_listener = WakeUpListener::new(
+            &self.shared_state.lock().request_receiver,
+            ChannelId::new(0),
+        )?;
+        update_connections_of(self);
+        Ok(wake_up_listener)
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+        ResponseHeader: Debug + ZeroCopySend,
+    > TryReceive
+    for PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    type Service = Service;
+    type Item = Response<Service, ResponsePayload, ResponseHeader>;
+    type Error = ReceiveError;
+
+    fn try_receive(&self) -> Result<Option<Self::Item>, Self::Error> {
+        self.receive()
+    }
+
+    fn is_terminated(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn create_wake_up_listener(&self) -> Result<WakeUpListener<Service>, AsyncCreateError> {
+        let client_shared_state = self.request.client_shared_state.lock();
+        let wake_up_listener = WakeUpListener::new(
+            &client_shared_state.response_receiver,
+            self.request.channel_id,
+        )?;
+        if let Err(e) = client_shared_state.update_connections() {
+            warn!(from self, "Unable to update the connections of the port ({:?}).", e);
+        }
+        Ok(wake_up_listener)
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+        ResponseHeader: Debug + ZeroCopySend,
+    > TryReceive
+    for PendingResponse<Service, RequestPayload, RequestHeader, [ResponsePayload], ResponseHeader>
+{
+    type Service = Service;
+    type Item = Response<Service, [ResponsePayload], ResponseHeader>;
+    type Error = ReceiveError;
+
+    fn try_receive(&self) -> Result<Option<Self::Item>, Self::Error> {
+        self.receive()
+    }
+
+    fn is_terminated(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn create_wake_up_listener(&self) -> Result<WakeUpListener<Service>, AsyncCreateError> {
+        let client_shared_state = self.request.client_shared_state.lock();
+        let wake_up_listener = WakeUpListener::new(
+            &client_shared_state.response_receiver,
+            self.request.channel_id,
+        )?;
+        if let Err(e) = client_shared_state.update_connections() {
+            warn!(from self, "Unable to update the connections of the port ({:?}).", e);
+        }
+        Ok(wake_up_listener)
+    }
+}
@@ -88,6 +88,7 @@ use serde::{Deserialize, Serialize};
 use iceoryx2_bb_log::{debug, fail, fatal_panic, info, trace, warn};
 
 use crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy;
+use iceoryx2_cal::shared_memory::MemoryLockPolicy;
 
 use iceoryx2_pal_configuration::settings::ICEORYX2_ROOT_PATH;
 
@@ -274,6 +275,14 @@ pub struct Defaults {
     pub blackboard: Blackboard,
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "MemoryLockPolicy")]
+enum MemoryLockPolicyDef {
+    Disabled,
+    BestEffort,
+    Required,
+}
+
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
 /// the user specifies custom QoS or port settings.
 #[non_exhaustive]
@@ -313,6 +322,10 @@ pub struct PublishSubscribe {
     /// disconnected from a service and the connection
     /// still contains unconsumed [`Sample`](crate::sample::Sample)s.
     pub subscriber_expired_connection_buffer: usize,
+    /// Defines if the data segment of a [`Publisher`](crate::port::publisher::Publisher) is
+    /// locked into RAM and pre-faulted, see [`MemoryLockPolicy`].
+    #[serde(with = "MemoryLockPolicyDef")]
+    pub publisher_memory_lock_policy: MemoryLockPolicy,
 }
 
 impl Default for PublishSubscribe {
@@ -328,6 +341,7 @@ impl Default for PublishSubscribe {
             enable_safe_overflow: true,
             unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
             subscriber_expired_connection_buffer: 128,
+            publisher_memory_lock_policy: MemoryLockPolicy::Disabled,
         }
     }
 }
@@ -444,6 +458,10 @@ pub struct RequestResonse {
     /// disconnected from a service and the connection
     /// still contains unconsumed [`ActiveRequest`](crate::active_request::ActiveRequest)s.
     pub server_expired_connection_buffer: usize,
+    /// Defines if the data segment of a [`Server`](crate::port::server::Server) is
+    /// locked into RAM and pre-faulted, see [`MemoryLockPolicy`].
+    #[serde(with = "MemoryLockPolicyDef")]
+    pub server_memory_lock_policy: MemoryLockPolicy,
 }
 
 impl Default for RequestResonse {
@@ -464,6 +482,7 @@ impl Default for RequestResonse {
             client_expired_connection_buffer: 128,
             server_expired_connection_buffer: 128,
             enable_fire_and_forget_requests: true,
+            server_memory_lock_policy: MemoryLockPolicy::Disabled,
         }
     }
 }
@@ -36,3 +36,15 @@ pub const MAX_BLACKBOARD_KEY_SIZE: usize = 64;
 /// The maximum alignment the [`MessagingPattern::Blackboard`](crate::service::static_config::messaging_pattern::MessagingPattern::Blackboard)
 /// supports for the keytype.
 pub const MAX_BLACKBOARD_KEY_ALIGNMENT: usize = 8;
+
+/// Defines how many fields a [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema)
+/// can have at most
+pub const MAX_TYPE_SCHEMA_FIELDS: usize = 24;
+
+/// Defines the maximum length of the name of a field of a
+/// [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema)
+pub const MAX_FIELD_NAME_LENGTH: usize = 32;
+
+/// Defines the maximum accumulated length of all field names of a
+/// [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema)
+pub const MAX_TYPE_SCHEMA_NAMES_LENGTH: usize = 256;
@@ -504,6 +504,8 @@
 //!    backend
 //!  * `libc_platform` - Uses the [libc crate](https://crates.io/crates/libc) for the platform
 //!    abstraction to simplify cross compilation. Works currently only for Linux based targets.
+//!  * `async` - Provides runtime agnostic [`Future`](core::future::Future)s and `Stream`s for
+//!    the receiving ports in the `asynchronous` module.
 //!
 //! # Custom Configuration
 //!
@@ -518,6 +520,9 @@ extern crate alloc;
 /// Constants that define limits and properties of an iceoryx2 system
 pub mod constants;
 
+#[cfg(feature = "async")]
+pub mod asynchronous;
+
 /// Handles iceoryx2s global configuration
 pub mod config;
 
@@ -559,6 +564,10 @@ pub mod response_mut;
 /// [`Client`](crate::port::client::Client)
 pub mod response_mut_uninit;
 
+/// Typed remote procedure calls that are generated from a trait with the
+/// [`rpc_service`](crate::rpc::rpc_service) macro
+pub mod rpc;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
@@ -568,6 +577,9 @@ pub mod sample_mut;
 /// The uninitialized payload that is sent by a [`Publisher`](crate::port::publisher::Publisher).
 pub mod sample_mut_uninit;
 
+/// Publish-subscribe communication for payload types that are serialized into a slice of bytes
+pub mod serialized;
+
 /// The foundation of communication the service with its
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
@@ -55,15 +55,18 @@
 //! # }
 //! ```
 
+use core::cell::UnsafeCell;
 use core::ops::Deref;
 use core::sync::atomic::Ordering;
 use core::{fmt::Debug, marker::PhantomData};
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveWait, AdaptiveWaitBuilder};
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
-use crate::port::client::ClientSharedState;
+use crate::port::client::{ClientSharedState, DispatchTarget};
 use crate::port::details::chunk::Chunk;
 use crate::port::details::chunk_details::ChunkDetails;
 use crate::raw_sample::RawSample;
@@ -87,6 +90,9 @@ pub struct PendingResponse<
     pub(crate) request:
         RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
     pub(crate) number_of_server_connections: usize,
+    pub(crate) dispatch_target: UnsafeCell<Option<DispatchTarget>>,
+    pub(crate) has_received_response: IoxAtomicBool,
+    pub(crate) number_of_final_responses: IoxAtomicUsize,
     pub(crate) _service: PhantomData<Service>,
     pub(crate) _response_payload: PhantomData<ResponsePayload>,
     pub(crate) _response_header: PhantomData<ResponseHeader>,
@@ -120,7 +126,7 @@ impl<
             .lock()
             .active_request_counter
             .fetch_sub(1, Ordering::Relaxed);
-        self.close();
+        self.cancel();
     }
 }
 
@@ -170,12 +176,21 @@ impl<
         ResponseHeader: Debug + ZeroCopySend,
     > PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
 {
-    fn close(&self) {
-        self.request
-            .client_shared_state
-            .lock()
+    fn cancel(&self) {
+        let client_shared_state = self.request.client_shared_state.lock();
+        let request_id = self.request.header().request_id;
+        let is_connected = client_shared_state
             .response_receiver
-            .invalidate_channel_state(self.request.channel_id, self.request.header().request_id);
+            .at_least_one_channel_has_state(self.request.channel_id, request_id);
+
+        client_shared_state
+            .response_receiver
+            .invalidate_channel_state(self.request.channel_id, request_id);
+
+        // only servers that still process the request are interested in the cancellation
+        if is_connected {
+            client_shared_state.notify_cancellation();
+        }
     }
 
     /// Marks the connection state that the [`Client`](crate::port::client::Client) wants to gracefully
@@ -231,6 +246,80 @@ impl<
         self.number_of_server_connections
     }
 
+    /// Returns [`true`] when the timeout defined with
+    /// [`RequestMut::set_timeout()`] has passed, otherwise [`false`]. A timed out
+    /// [`PendingResponse`] fails with [`ReceiveError::RequestTimedOut`] on receive.
+    pub fn has_timed_out(&self) -> bool {
+        self.request.header().has_timed_out()
+    }
+
+    /// Returns [`true`] when the stream of [`Response`]s has ended and no further [`Response`]
+    /// will be received. This is the case when every [`Server`](crate::port::server::Server)
+    /// that received the [`RequestMut`] has sent its final [`Response`] with
+    /// [`ResponseMut::send_final()`](crate::response_mut::ResponseMut::send_final()) or when
+    /// all [`Server`](crate::port::server::Server)s have disconnected and all
+    /// [`Response`]s were received.
+    pub fn is_finished(&self) -> bool {
+        let number_of_final_responses = self.number_of_final_responses.load(Ordering::Relaxed);
+        (self.number_of_server_connections != 0
+            && self.number_of_server_connections <= number_of_final_responses)
+            || (!self.is_connected() && !self.has_response())
+    }
+
+    /// Grants every [`Server`](crate::port::server::Server) `credits` additional
+    /// [`Response`]s when flow control was enabled with
+    /// [`RequestMut::set_response_credits()`]. Otherwise, the call has no effect.
+    pub fn grant_credits(&self, credits: u64) {
+        self.request
+            .client_shared_state
+            .lock()
+            .response_receiver
+            .grant_credits(self.request.channel_id, credits);
+    }
+
+    /// Returns a blocking iterator over the [`Response`]s. It waits until the next
+    /// [`Response`] arrives and ends as soon as [`PendingResponse::is_finished()`] returns
+    /// [`true`]. When receiving fails, the error is returned and the iteration ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node
+    /// #    .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #    .request_response::<u64, u64>()
+    /// #    .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// let pending_response = client.send_copy(0)?;
+    ///
+    /// # let active_request = server.receive()?.unwrap();
+    /// # active_request.send_copy(1)?;
+    /// # active_request.loan_uninit()?.write_payload(2).send_final()?;
+    /// for response in pending_response.iter() {
+    ///     println!("received response: {}", *response?);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(
+        &self,
+    ) -> Responses<'_, Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+    {
+        Responses {
+            pending_response: self,
+            adaptive_wait: AdaptiveWaitBuilder::new().create().ok(),
+            has_ended: false,
+        }
+    }
+
     /// Returns [`true`] when a [`Server`](crate::port::server::Server) has sent a [`Response`]
     /// otherwise [`false`].
     pub fn has_response(&self) -> bool {
@@ -242,14 +331,62 @@ impl<
     }
 
     fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
-        let client_shared_state = self.request.client_shared_state.lock();
         let msg = "Unable to receive response";
+        if self.has_timed_out() {
+            self.cancel();
+            fail!(from self, with ReceiveError::RequestTimedOut,
+                "{msg} since the request timed out.");
+        }
+
+        let client_shared_state = self.request.client_shared_state.lock();
         fail!(from self, when client_shared_state.update_connections(),
                 "{msg} since the connections could not be updated.");
 
-        client_shared_state
+        let request_id = self.request.header().request_id;
+        match client_shared_state
             .response_receiver
-            .receive(self.request.channel_id)
+            .receive(self.request.channel_id)?
+        {
+            Some((details, chunk)) => {
+                let header = unsafe {
+                    &*(chunk.header as *const service::header::request_response::ResponseHeader)
+                };
+                if header.request_id == request_id {
+                    self.has_received_response.store(true, Ordering::Relaxed);
+                    if header.is_final() {
+                        self.number_of_final_responses
+                            .fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+                Ok(Some((details, chunk)))
+            }
+            None => {
+                let dispatch_target = unsafe { &mut *self.dispatch_target.get() };
+                if let Some(target) = dispatch_target {
+                    if !self.has_received_response.load(Ordering::Relaxed)
+                        && !client_shared_state.is_server_connected(target)
+                    {
+                        // the server disconnected before it answered, another server takes over
+                        *dispatch_target = match client_shared_state.dispatch_request(
+                            self.request.offset_to_chunk,
+                            self.request.sample_size,
+                            self.request.channel_id,
+                            request_id,
+                            self.request.header().initial_response_credits(),
+                        ) {
+                            Ok(Some(new_target)) => Some(new_target),
+                            Ok(None) | Err(_) => {
+                                *dispatch_target = None;
+                                fail!(from self, with ReceiveError::ServerDisconnected,
+                                    "{msg} since the server disconnected before it responded and the request could not be dispatched to another server.");
+                            }
+                        };
+                    }
+                }
+
+                Ok(None)
+            }
+        }
     }
 }
 
@@ -459,3 +596,111 @@ impl<
         }
     }
 }
+
+/// Blocking iterator over the [`Response`]s of a [`PendingResponse`], acquired with
+/// [`PendingResponse::iter()`].
+pub struct Responses<
+    'a,
+    Service: crate::service::Service,
+    RequestPayload: Debug + ZeroCopySend + ?Sized,
+    RequestHeader: Debug + ZeroCopySend,
+    ResponsePayload: Debug + ZeroCopySend + ?Sized,
+    ResponseHeader: Debug + ZeroCopySend,
+> {
+    pending_response: &'a PendingResponse<
+        Service,
+        RequestPayload,
+        RequestHeader,
+        ResponsePayload,
+        ResponseHeader,
+    >,
+    adaptive_wait: Option<AdaptiveWait>,
+    has_ended: bool,
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > Debug
+    for Responses<'_, Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Responses {{ pending_response: {:?}, has_ended: {} }}",
+            self.pending_response, self.has_ended
+        )
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > Responses<'_, Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn next_impl<R, F: FnMut() -> Result<Option<R>, ReceiveError>>(
+        &mut self,
+        mut receive: F,
+    ) -> Option<Result<R, ReceiveError>> {
+        while !self.has_ended {
+            match receive() {
+                Ok(Some(response)) => return Some(Ok(response)),
+                Ok(None) => {
+                    if self.pending_response.is_finished() {
+                        self.has_ended = true;
+                    } else if let Some(adaptive_wait) = &mut self.adaptive_wait {
+                        let _ = adaptive_wait.wait();
+                    } else {
+                        core::hint::spin_loop();
+                    }
+                }
+                Err(e) => {
+                    self.has_ended = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > Iterator
+    for Responses<'_, Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    type Item = Result<Response<Service, ResponsePayload, ResponseHeader>, ReceiveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pending_response = self.pending_response;
+        self.next_impl(|| pending_response.receive())
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+        ResponseHeader: Debug + ZeroCopySend,
+    > Iterator
+    for Responses<'_, Service, RequestPayload, RequestHeader, [ResponsePayload], ResponseHeader>
+{
+    type Item = Result<Response<Service, [ResponsePayload], ResponseHeader>, ReceiveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pending_response = self.pending_response;
+        self.next_impl(|| pending_response.receive())
+    }
+}
@@ -74,7 +74,7 @@
 
 use core::{
     any::TypeId, cell::UnsafeCell, fmt::Debug, marker::PhantomData, mem::MaybeUninit,
-    sync::atomic::Ordering,
+    sync::atomic::Ordering, time::Duration,
 };
 use iceoryx2_bb_container::{queue::Queue, slotmap::SlotMap, vector::polymorphic_vec::*};
 
@@ -94,7 +94,10 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, Iox
 use crate::{
     pending_response::PendingResponse,
     port::{
-        details::data_segment::DataSegment, update_connections::UpdateConnections, UniqueClientId,
+        details::data_segment::{DataSegment, DataSegmentMemoryProperties},
+        notifier::NotifierSharedState,
+        update_connections::UpdateConnections,
+        UniqueClientId,
     },
     prelude::{PortFactory, UnableToDeliverStrategy},
     raw_sample::RawSampleMut,
@@ -103,7 +106,8 @@ use crate::{
     service::{
         self,
         builder::{CustomHeaderMarker, CustomPayloadMarker},
-        dynamic_config::request_response::{ClientDetails, ServerDetails},
+        dispatch_policy::DispatchPolicy,
+        dynamic_config::request_response::{ClientDetails, DynamicConfig, ServerDetails},
         header,
         naming_scheme::data_segment_name,
         port_factory::client::{ClientCreateError, LocalClientConfig, PortFactoryClient},
@@ -114,10 +118,11 @@ use crate::{
 use super::{
     details::{
         data_segment::DataSegmentType,
-        receiver::{Receiver, SenderDetails},
+        receiver::{Receiver, SenderDetails, WakeUpState},
         segment_state::SegmentState,
         sender::{ReceiverDetails, Sender},
     },
+    receive_order::ReceiveOrder,
     update_connections::ConnectionFailure,
     LoanError, SendError,
 };
@@ -169,6 +174,18 @@ pub(crate) struct ClientSharedState<Service: service::Service> {
     server_list_state: UnsafeCell<ContainerState<ServerDetails>>,
     pub(crate) active_request_counter: IoxAtomicUsize,
     pub(crate) available_channel_ids: UnsafeCell<Queue<ChannelId>>,
+    cancellation_notifier: Option<NotifierSharedState<Service>>,
+    dispatch_policy: DispatchPolicy,
+    dispatch_position: IoxAtomicUsize,
+    sticky_server: UnsafeCell<Option<DispatchTarget>>,
+}
+
+/// The [`Server`](crate::port::server::Server) a request was delivered to when the service
+/// uses a single server [`DispatchPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DispatchTarget {
+    pub(crate) connection_id: usize,
+    pub(crate) server_port_id: u128,
 }
 
 impl<Service: service::Service> Drop for ClientSharedState<Service> {
@@ -185,18 +202,150 @@ impl<Service: service::Service> Drop for ClientSharedState<Service> {
 }
 
 impl<Service: service::Service> ClientSharedState<Service> {
-    fn prepare_channel_to_receive_responses(&self, channel_id: ChannelId, request_id: u64) {
+    pub(crate) fn notify_cancellation(&self) {
+        if let Some(notifier) = &self.cancellation_notifier {
+            if let Err(e) = notifier.notify() {
+                warn!(from self,
+                    "Unable to notify the servers about the cancellation of a request ({:?}).", e);
+            }
+        }
+    }
+
+    fn prepare_channel_to_receive_responses(
+        &self,
+        channel_id: ChannelId,
+        request_id: u64,
+        response_credits: u64,
+    ) {
+        self.response_receiver
+            .set_credits(channel_id, response_credits);
         self.response_receiver
             .set_channel_state(channel_id, request_id);
     }
 
+    fn dynamic_config(&self) -> &DynamicConfig {
+        self.request_sender
+            .service_state
+            .dynamic_storage
+            .get()
+            .request_response()
+    }
+
+    pub(crate) fn is_server_connected(&self, target: &DispatchTarget) -> bool {
+        self.request_sender
+            .receiver_port_id_of(target.connection_id)
+            == Some(target.server_port_id)
+    }
+
+    fn connected_servers_starting_at(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        let number_of_connections = self.request_sender.connections.len();
+        (0..number_of_connections)
+            .map(move |n| (start + n) % number_of_connections)
+            .filter(|connection_id| {
+                self.request_sender
+                    .receiver_port_id_of(*connection_id)
+                    .is_some()
+            })
+    }
+
+    fn server_with_least_outstanding_requests(&self, start: usize) -> Option<usize> {
+        let dynamic_config = self.dynamic_config();
+        self.connected_servers_starting_at(start)
+            .min_by_key(|connection_id| {
+                dynamic_config.number_of_outstanding_requests(*connection_id)
+            })
+    }
+
+    fn select_server(&self) -> Option<DispatchTarget> {
+        let start = self.dispatch_position.load(Ordering::Relaxed);
+        let connection_id = match self.dispatch_policy {
+            DispatchPolicy::Broadcast | DispatchPolicy::RoundRobin => {
+                self.connected_servers_starting_at(start).next()
+            }
+            DispatchPolicy::LeastOutstandingRequests => {
+                self.server_with_least_outstanding_requests(start)
+            }
+            DispatchPolicy::StickyPerClient => match unsafe { *self.sticky_server.get() } {
+                Some(target) if self.is_server_connected(&target) => return Some(target),
+                _ => self.server_with_least_outstanding_requests(start),
+            },
+        }?;
+
+        // the next selection starts after the selected server so that servers with an equal
+        // number of outstanding requests are selected in turns
+        self.dispatch_position.store(
+            (connection_id + 1) % self.request_sender.connections.len(),
+            Ordering::Relaxed,
+        );
+
+        let target = DispatchTarget {
+            connection_id,
+            server_port_id: self.request_sender.receiver_port_id_of(connection_id)?,
+        };
+
+        if self.dispatch_policy == DispatchPolicy::StickyPerClient {
+            unsafe { *self.sticky_server.get() = Some(target) };
+        }
+
+        Some(target)
+    }
+
+    pub(crate) fn dispatch_request(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        request_id: u64,
+        response_credits: u64,
+    ) -> Result<Option<DispatchTarget>, SendError> {
+        let target = match self.select_server() {
+            Some(target) => target,
+            None => return Ok(None),
+        };
+
+        let dynamic_config = self.dynamic_config();
+        self.response_receiver.set_credits_of_connection(
+            target.connection_id,
+            channel_id,
+            response_credits,
+        );
+        self.response_receiver.set_channel_state_of_connection(
+            target.connection_id,
+            channel_id,
+            request_id,
+        );
+        dynamic_config.increment_outstanding_requests(target.connection_id);
+
+        let number_of_recipients = self.request_sender.deliver_offset_to_connection(
+            offset,
+            sample_size,
+            // All requests are delivered on the same channel, therefore we can use
+            // ChannelId::new(0).
+            ChannelId::new(0),
+            target.connection_id,
+            // a discarded request is never received by the server
+            |_| dynamic_config.decrement_outstanding_requests(target.connection_id),
+        );
+
+        match number_of_recipients {
+            Ok(0) | Err(_) => {
+                dynamic_config.decrement_outstanding_requests(target.connection_id);
+                self.response_receiver
+                    .invalidate_channel_state(channel_id, request_id);
+                number_of_recipients.map(|_| None)
+            }
+            Ok(_) => Ok(Some(target)),
+        }
+    }
+
     pub(crate) fn send_request(
         &self,
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
         request_id: u64,
-    ) -> Result<usize, RequestSendError> {
+        response_credits: u64,
+    ) -> Result<(usize, Option<DispatchTarget>), RequestSendError> {
         let msg = "Unable to send request";
 
         let active_request_counter = self.active_request_counter.load(Ordering::Relaxed);
@@ -215,16 +364,31 @@ impl<Service: service::Service> ClientSharedState<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
-        self.prepare_channel_to_receive_responses(channel_id, request_id);
+        if self.dispatch_policy.dispatches_to_single_server() {
+            self.active_request_counter.fetch_add(1, Ordering::Relaxed);
+            let target = self.dispatch_request(
+                offset,
+                sample_size,
+                channel_id,
+                request_id,
+                response_credits,
+            )?;
+            return Ok((target.is_some() as usize, target));
+        }
+
+        self.prepare_channel_to_receive_responses(channel_id, request_id, response_credits);
 
         self.active_request_counter.fetch_add(1, Ordering::Relaxed);
-        Ok(self.request_sender.deliver_offset(
-            offset,
-            sample_size,
-            // All requests are delivered on the same channel, therefore we can use
-            // ChannelId::new(0).
-            ChannelId::new(0),
-        )?)
+        Ok((
+            self.request_sender.deliver_offset(
+                offset,
+                sample_size,
+                // All requests are delivered on the same channel, therefore we can use
+                // ChannelId::new(0).
+                ChannelId::new(0),
+            )?,
+            None,
+        ))
     }
 
     pub(crate) fn update_connections(
@@ -261,6 +425,7 @@ impl<Service: service::Service> ClientSharedState<Service> {
                         max_number_of_segments: port.max_number_of_segments,
                         data_segment_type: port.data_segment_type,
                         number_of_samples: port.number_of_responses,
+                        priority: 0,
                     },
                 );
                 result = result.and(inner_result);
@@ -378,14 +543,18 @@ impl<
                 &segment_name,
                 sample_layout,
                 global_config,
+                service.static_config.access_policy(),
                 number_of_requests,
+                DataSegmentMemoryProperties::default(),
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
                 sample_layout,
                 global_config,
+                service.static_config.access_policy(),
                 number_of_requests,
                 client_factory.config.allocation_strategy,
+                DataSegmentMemoryProperties::default(),
             ),
         };
 
@@ -471,6 +640,21 @@ impl<
             enable_safe_overflow: static_config.enable_safe_overflow_for_responses,
             number_of_channels: number_of_requests,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
+            priority_order: UnsafeCell::new(
+                PolymorphicVec::new(HeapAllocator::global(), number_of_connections)
+                    .expect("Heap allocator provides memory."),
+            ),
+            receive_order: ReceiveOrder::default(),
+            round_robin_position: UnsafeCell::new(0),
+            wake_up_state: UnsafeCell::new(WakeUpState::default()),
+        };
+
+        let cancellation_notifier = match &client_factory.factory.cancellation_event_service {
+            None => None,
+            Some(event_service) => Some(fail!(from origin,
+                when event_service.notifier_builder().create_shared_state(),
+                with ClientCreateError::InternalFailure,
+                "{} since the notifier for the request cancellation could not be created.", msg)),
         };
 
         let client_shared_state = Service::ArcThreadSafetyPolicy::new(ClientSharedState {
@@ -487,6 +671,14 @@ impl<
             response_receiver,
             server_list_state: UnsafeCell::new(unsafe { server_list.get_state() }),
             active_request_counter: IoxAtomicUsize::new(0),
+            cancellation_notifier,
+            dispatch_policy: static_config.dispatch_policy,
+            // clients start at different servers so that they do not all select the same
+            // server first
+            dispatch_position: IoxAtomicUsize::new(
+                (client_id.value() % static_config.max_servers.max(1) as u128) as usize,
+            ),
+            sticky_server: UnsafeCell::new(None),
         });
 
         let client_shared_state = match client_shared_state {
@@ -667,12 +859,12 @@ impl<
         let header_ptr: *mut service::header::request_response::RequestHeader = chunk.header.cast();
         let user_header_ptr: *mut RequestHeader = chunk.user_header.cast();
         unsafe {
-            header_ptr.write(service::header::request_response::RequestHeader {
-                client_id: self.id(),
+            header_ptr.write(service::header::request_response::RequestHeader::new(
+                self.id(),
                 channel_id,
-                request_id: self.request_id_counter.fetch_add(1, Ordering::Relaxed),
-                number_of_elements: 1,
-            })
+                self.request_id_counter.fetch_add(1, Ordering::Relaxed),
+                1,
+            ))
         };
         unsafe { user_header_ptr.write(RequestHeader::default()) };
 
@@ -716,6 +908,49 @@ impl<
 
         request.write_payload(value).send()
     }
+
+    /// Copies the input value into a [`RequestMut`], sets the provided timeout with
+    /// [`RequestMut::set_timeout()`] and sends it. On success it returns a [`PendingResponse`]
+    /// that fails with [`ReceiveError::RequestTimedOut`](crate::port::ReceiveError::RequestTimedOut)
+    /// when no [`Response`](crate::response::Response) arrived in time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node
+    /// #    .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #    .request_response::<u64, u64>()
+    /// #    .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let pending_response = client.send_copy_with_timeout(815, Duration::from_millis(100))?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy_with_timeout(
+        &self,
+        value: RequestPayload,
+        timeout: Duration,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendError,
+    > {
+        let msg = "Unable to send copy of request with timeout";
+        let mut request = fail!(from self,
+                            when self.loan_uninit(),
+                            "{} since the loan of the request failed.", msg);
+
+        request.set_timeout(timeout);
+        request.write_payload(value).send()
+    }
 }
 
 impl<
@@ -945,12 +1180,12 @@ impl<
         let user_header_ptr: *mut RequestHeader = chunk.user_header.cast();
         let header_ptr = chunk.header as *mut header::request_response::RequestHeader;
         unsafe {
-            header_ptr.write(header::request_response::RequestHeader {
-                client_id: self.id(),
+            header_ptr.write(header::request_response::RequestHeader::new(
+                self.id(),
                 channel_id,
-                request_id: self.request_id_counter.fetch_add(1, Ordering::Relaxed),
-                number_of_elements: slice_len as _,
-            })
+                self.request_id_counter.fetch_add(1, Ordering::Relaxed),
+                slice_len as _,
+            ))
         };
         unsafe { user_header_ptr.write(RequestHeader::default()) };
 
@@ -12,9 +12,13 @@
 
 use core::sync::atomic::Ordering;
 
-use iceoryx2_cal::zero_copy_connection::{ChannelId, ZeroCopyPortDetails};
+use iceoryx2_cal::zero_copy_connection::{
+    ChannelId, ZeroCopyPortDetails, INITIAL_CHANNEL_CREDITS, INITIAL_CHANNEL_WAKE_UP,
+};
 
 pub(crate) const INVALID_CHANNEL_STATE: u64 = u64::MAX;
+pub(crate) const UNLIMITED_CHANNEL_CREDITS: u64 = INITIAL_CHANNEL_CREDITS;
+pub(crate) const NO_WAKE_UP: u64 = INITIAL_CHANNEL_WAKE_UP;
 const DISCONNECT_HINT_BIT: u64 = 1u64 << 63;
 
 pub(crate) trait ChannelManagement: ZeroCopyPortDetails {
@@ -72,6 +76,55 @@ pub(crate) trait ChannelManagement: ZeroCopyPortDetails {
             }
         }
     }
+
+    fn set_credits(&self, channel_id: ChannelId, credits: u64) {
+        self.channel_credits(channel_id)
+            .store(credits, Ordering::Relaxed);
+    }
+
+    fn credits(&self, channel_id: ChannelId) -> Option<u64> {
+        match self.channel_credits(channel_id).load(Ordering::Relaxed) {
+            UNLIMITED_CHANNEL_CREDITS => None,
+            v => Some(v),
+        }
+    }
+
+    fn grant_credits(&self, channel_id: ChannelId, credits: u64) {
+        // unlimited credits stay unlimited, granted credits never become unlimited
+        let _ = self.channel_credits(channel_id).fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |current| match current {
+                UNLIMITED_CHANNEL_CREDITS => None,
+                v => Some(v.saturating_add(credits).min(UNLIMITED_CHANNEL_CREDITS - 1)),
+            },
+        );
+    }
+
+    fn acquire_credit(&self, channel_id: ChannelId) -> bool {
+        match self.channel_credits(channel_id).fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |current| match current {
+                UNLIMITED_CHANNEL_CREDITS | 0 => None,
+                v => Some(v - 1),
+            },
+        ) {
+            Ok(_) => true,
+            Err(v) => v == UNLIMITED_CHANNEL_CREDITS,
+        }
+    }
+
+    // SeqCst pairs the announcement of the receiver with the delivery of the sender, so that
+    // either the sender notifies or the receiver sees the data when it checks the first time
+    fn set_wake_up_incarnation(&self, channel_id: ChannelId, incarnation: u64) {
+        self.channel_wake_up(channel_id)
+            .store(incarnation, Ordering::SeqCst);
+    }
+
+    fn wake_up_incarnation(&self, channel_id: ChannelId) -> u64 {
+        self.channel_wake_up(channel_id).load(Ordering::SeqCst)
+    }
 }
 
 impl<T: ZeroCopyPortDetails> ChannelManagement for T {}
@@ -18,8 +18,8 @@ use iceoryx2_cal::{
     event::NamedConceptBuilder,
     resizable_shared_memory::*,
     shared_memory::{
-        SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, SharedMemoryForPoolAllocator,
-        SharedMemoryOpenError, ShmPointer,
+        MemoryLockPolicy, NumaPlacement, SharedMemory, SharedMemoryBuilder,
+        SharedMemoryCreateError, SharedMemoryForPoolAllocator, SharedMemoryOpenError, ShmPointer,
     },
     shm_allocator::{
         self, pool_allocator::PoolAllocator, AllocationError, AllocationStrategy, PointerOffset,
@@ -31,6 +31,7 @@ use crate::{
     config,
     service::{
         self,
+        access_policy::AccessPolicy,
         config_scheme::{data_segment_config, resizable_data_segment_config},
     },
 };
@@ -54,6 +55,34 @@ impl DataSegmentType {
     }
 }
 
+/// Defines where the data segment of a zero copy capable sender port is placed in memory.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum DataSegmentPlacement {
+    /// The data segment is not bound to a NUMA node, the operating system decides where its
+    /// memory is placed.
+    #[default]
+    Unbound,
+    /// The data segment is bound to the NUMA node with the contained id.
+    NumaNode(u32),
+}
+
+impl DataSegmentPlacement {
+    pub(crate) fn new_from_numa_node(v: Option<usize>) -> Self {
+        match v {
+            Some(id) => DataSegmentPlacement::NumaNode(id as u32),
+            None => DataSegmentPlacement::Unbound,
+        }
+    }
+}
+
+/// Defines how the memory of a [`DataSegment`] is locked and placed.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct DataSegmentMemoryProperties {
+    pub(crate) memory_lock_policy: MemoryLockPolicy,
+    pub(crate) numa_placement: NumaPlacement,
+}
+
 #[derive(Debug)]
 enum MemoryType<Service: service::Service> {
     Static(Service::SharedMemory),
@@ -70,7 +99,9 @@ impl<Service: service::Service> DataSegment<Service> {
         segment_name: &FileName,
         chunk_layout: Layout,
         global_config: &config::Config,
+        access_policy: &AccessPolicy,
         number_of_chunks: usize,
+        memory_properties: DataSegmentMemoryProperties,
     ) -> Result<Self, SharedMemoryCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
             bucket_layout: chunk_layout,
@@ -78,13 +109,16 @@ impl<Service: service::Service> DataSegment<Service> {
         let msg = "Unable to create the static data segment since the underlying shared memory could not be created.";
         let origin = "DataSegment::create_static_segment()";
 
-        let segment_config = data_segment_config::<Service>(global_config);
+        let segment_config =
+            access_policy.apply_to_port_resource(data_segment_config::<Service>(global_config));
         let memory = fail!(from origin,
                                 when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
                                 Service::SharedMemory,
                                     >>::new(segment_name)
                                     .config(&segment_config)
                                     .size(chunk_layout.size() * number_of_chunks + chunk_layout.align() - 1)
+                                    .memory_lock_policy(memory_properties.memory_lock_policy)
+                                    .numa_placement(memory_properties.numa_placement)
                                     .create(&allocator_config),
                                 "{msg}");
 
@@ -97,13 +131,16 @@ impl<Service: service::Service> DataSegment<Service> {
         segment_name: &FileName,
         chunk_layout: Layout,
         global_config: &config::Config,
+        access_policy: &AccessPolicy,
         number_of_chunks: usize,
         allocation_strategy: AllocationStrategy,
+        memory_properties: DataSegmentMemoryProperties,
     ) -> Result<Self, SharedMemoryCreateError> {
         let msg = "Unable to create the dynamic data segment since the underlying shared memory could not be created.";
         let origin = "DataSegment::create_dynamic_segment()";
 
-        let segment_config = resizable_data_segment_config::<Service>(global_config);
+        let segment_config = access_policy
+            .apply_to_port_resource(resizable_data_segment_config::<Service>(global_config));
         let memory = fail!(from origin,
                     when <<Service::ResizableSharedMemory as ResizableSharedMemory<
                         PoolAllocator,
@@ -115,6 +152,8 @@ impl<Service: service::Service> DataSegment<Service> {
                     .max_number_of_chunks_hint(number_of_chunks)
                     .max_chunk_layout_hint(chunk_layout)
                     .allocation_strategy(allocation_strategy)
+                    .memory_lock_policy(memory_properties.memory_lock_policy)
+                    .numa_placement(memory_properties.numa_placement)
                     .create(),
                     "{msg}");
 
@@ -162,6 +201,13 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    pub(crate) fn placement(&self) -> DataSegmentPlacement {
+        DataSegmentPlacement::new_from_numa_node(match &self.memory {
+            MemoryType::Static(memory) => memory.numa_node(),
+            MemoryType::Dynamic(memory) => memory.numa_node(),
+        })
+    }
+
     pub(crate) fn max_number_of_segments(data_segment_type: DataSegmentType) -> u8 {
         match data_segment_type {
             DataSegmentType::Static => 1,
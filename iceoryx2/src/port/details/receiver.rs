@@ -14,6 +14,7 @@ use core::cell::UnsafeCell;
 
 use alloc::format;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::slotmap::SlotMapKey;
@@ -23,8 +24,10 @@ use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_log::{error, fail, warn};
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shm_allocator::PointerOffset;
 use iceoryx2_cal::zero_copy_connection::*;
 
+use crate::port::receive_order::ReceiveOrder;
 use crate::port::update_connections::ConnectionFailure;
 use crate::port::{DegradationAction, DegradationCallback, ReceiveError};
 use crate::service::naming_scheme::data_segment_name;
@@ -34,7 +37,7 @@ use crate::service::ServiceState;
 use crate::service::{self, config_scheme::connection_config, naming_scheme::connection_name};
 
 use super::channel_management::ChannelManagement;
-use super::channel_management::INVALID_CHANNEL_STATE;
+use super::channel_management::{INVALID_CHANNEL_STATE, NO_WAKE_UP};
 use super::chunk::Chunk;
 use super::chunk_details::ChunkDetails;
 use super::data_segment::{DataSegmentType, DataSegmentView};
@@ -45,6 +48,7 @@ pub(crate) struct SenderDetails {
     pub(crate) number_of_samples: usize,
     pub(crate) max_number_of_segments: u8,
     pub(crate) data_segment_type: DataSegmentType,
+    pub(crate) priority: u8,
 }
 
 #[derive(Debug)]
@@ -52,6 +56,7 @@ pub(crate) struct Connection<Service: service::Service> {
     pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
     pub(crate) data_segment: DataSegmentView<Service>,
     pub(crate) sender_port_id: u128,
+    pub(crate) priority: u8,
     tag: Tag,
 }
 
@@ -64,12 +69,10 @@ impl<Service: service::Service> Taggable for Connection<Service> {
 impl<Service: service::Service> Connection<Service> {
     fn new(
         this: &Receiver<Service>,
-        data_segment_type: DataSegmentType,
-        sender_port_id: u128,
-        number_of_samples: usize,
-        max_number_of_segments: u8,
+        sender_details: &SenderDetails,
         cyclic_tagger: &CyclicTagger,
     ) -> Result<Self, ConnectionFailure> {
+        let sender_port_id = sender_details.port_id;
         let msg = format!(
             "Unable to establish connection to sender port {:?} from receiver port {:?}.",
             sender_port_id, this.receiver_port_id
@@ -79,20 +82,21 @@ impl<Service: service::Service> Connection<Service> {
         let receiver = fail!(from this,
                         when <Service::Connection as ZeroCopyConnection>::
                             Builder::new( &connection_name(sender_port_id, this.receiver_port_id))
-                                    .config(&connection_config::<Service>(global_config))
+                                    .config(&this.service_state.static_config.access_policy().apply_to_port_resource(
+                                        connection_config::<Service>(global_config)))
                                     .buffer_size(this.buffer_size)
                                     .receiver_max_borrowed_samples_per_channel(this.receiver_max_borrowed_samples)
                                     .enable_safe_overflow(this.enable_safe_overflow)
-                                    .number_of_samples_per_segment(number_of_samples)
+                                    .number_of_samples_per_segment(sender_details.number_of_samples)
                                     .number_of_channels(this.number_of_channels)
                                     .initial_channel_state(INVALID_CHANNEL_STATE)
-                                    .max_supported_shared_memory_segments(max_number_of_segments)
+                                    .max_supported_shared_memory_segments(sender_details.max_number_of_segments)
                                     .timeout(global_config.global.service.creation_timeout)
                                     .create_receiver(),
                         "{} since the zero copy connection could not be established.", msg);
 
         let segment_name = data_segment_name(sender_port_id);
-        let data_segment = match data_segment_type {
+        let data_segment = match sender_details.data_segment_type {
             DataSegmentType::Static => {
                 DataSegmentView::open_static_segment(&segment_name, global_config)
             }
@@ -109,11 +113,34 @@ impl<Service: service::Service> Connection<Service> {
             receiver,
             data_segment,
             sender_port_id,
+            priority: sender_details.priority,
             tag: cyclic_tagger.create_tag(),
         })
     }
 }
 
+struct BorrowState {
+    active_channel_count: usize,
+    all_channels_exceed_max_borrows: bool,
+}
+
+impl Default for BorrowState {
+    fn default() -> Self {
+        Self {
+            active_channel_count: 0,
+            all_channels_exceed_max_borrows: true,
+        }
+    }
+}
+
+/// The wake up events the receiver announced to its senders, one incarnation per channel.
+#[derive(Debug, Default)]
+pub(crate) struct WakeUpState {
+    #[cfg(feature = "async")]
+    last_incarnation: u64,
+    incarnations: Vec<u64>,
+}
+
 #[derive(Debug)]
 pub(crate) struct Receiver<Service: service::Service> {
     pub(crate) connections: PolymorphicVec<'static, UnsafeCell<Option<SlotMapKey>>, HeapAllocator>,
@@ -129,6 +156,10 @@ pub(crate) struct Receiver<Service: service::Service> {
     pub(crate) enable_safe_overflow: bool,
     pub(crate) number_of_channels: usize,
     pub(crate) connection_storage: UnsafeCell<SlotMap<Connection<Service>>>,
+    pub(crate) priority_order: UnsafeCell<PolymorphicVec<'static, SlotMapKey, HeapAllocator>>,
+    pub(crate) receive_order: ReceiveOrder,
+    pub(crate) round_robin_position: UnsafeCell<usize>,
+    pub(crate) wake_up_state: UnsafeCell<WakeUpState>,
 }
 
 impl<Service: service::Service> Receiver<Service> {
@@ -159,6 +190,48 @@ impl<Service: service::Service> Receiver<Service> {
         ret_val
     }
 
+    pub(crate) fn set_channel_state_of_connection(
+        &self,
+        index: usize,
+        channel_id: ChannelId,
+        state: u64,
+    ) -> bool {
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        match unsafe { *self.connections[index].get() }.and_then(|key| connection_storage.get(key))
+        {
+            Some(connection) => connection.receiver.set_channel_state(channel_id, state),
+            None => false,
+        }
+    }
+
+    pub(crate) fn set_credits(&self, channel_id: ChannelId, credits: u64) {
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        for (_, connection) in connection_storage.iter() {
+            connection.receiver.set_credits(channel_id, credits);
+        }
+    }
+
+    pub(crate) fn set_credits_of_connection(
+        &self,
+        index: usize,
+        channel_id: ChannelId,
+        credits: u64,
+    ) {
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        if let Some(connection) =
+            unsafe { *self.connections[index].get() }.and_then(|key| connection_storage.get(key))
+        {
+            connection.receiver.set_credits(channel_id, credits);
+        }
+    }
+
+    pub(crate) fn grant_credits(&self, channel_id: ChannelId, credits: u64) {
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        for (_, connection) in connection_storage.iter() {
+            connection.receiver.grant_credits(channel_id, credits);
+        }
+    }
+
     pub(crate) fn at_least_one_channel_has_state(&self, channel_id: ChannelId, state: u64) -> bool {
         let mut ret_val = false;
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
@@ -190,6 +263,55 @@ impl<Service: service::Service> Receiver<Service> {
         }
     }
 
+    /// Creates the wake up event of the channel and announces it to all current and future
+    /// senders. They notify it whenever they delivered data to the channel. Senders that are
+    /// not yet connected to the receiver look for the wake up event on their first delivery.
+    #[cfg(feature = "async")]
+    pub(crate) fn create_wake_up_listener(
+        &self,
+        channel_id: ChannelId,
+    ) -> Result<
+        <Service::Event as iceoryx2_cal::event::Event>::Listener,
+        iceoryx2_cal::event::ListenerCreateError,
+    > {
+        use crate::service::config_scheme::event_config;
+        use crate::service::naming_scheme::wake_up_event_name;
+        use iceoryx2_cal::event::ListenerBuilder;
+
+        let event_name = wake_up_event_name(self.receiver_port_id, channel_id);
+        let event_config = self
+            .service_state
+            .static_config
+            .access_policy()
+            .apply_to_port_resource(event_config::<Service>(
+                self.service_state.shared_node.config(),
+            ));
+
+        let listener = fail!(from self,
+            when <Service::Event as iceoryx2_cal::event::Event>::ListenerBuilder::new(&event_name)
+                .config(&event_config)
+                .create(),
+            "Unable to create the wake up event \"{}\" for channel {}.", event_name, channel_id.value());
+
+        // a new incarnation lets the senders replace the notifiers of an earlier wake up event
+        let state = unsafe { &mut *self.wake_up_state.get() };
+        state.last_incarnation += 1;
+        let incarnation = state.last_incarnation;
+        state
+            .incarnations
+            .resize(self.number_of_channels, NO_WAKE_UP);
+        state.incarnations[channel_id.value()] = incarnation;
+
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        for (_, connection) in connection_storage.iter() {
+            connection
+                .receiver
+                .set_wake_up_incarnation(channel_id, incarnation);
+        }
+
+        Ok(listener)
+    }
+
     pub(crate) fn receiver_port_id(&self) -> u128 {
         self.receiver_port_id
     }
@@ -199,15 +321,18 @@ impl<Service: service::Service> Receiver<Service> {
         index: usize,
         sender_details: &SenderDetails,
     ) -> Result<(), ConnectionFailure> {
+        let connection = Connection::new(self, sender_details, &self.tagger)?;
+        let wake_up_state = unsafe { &*self.wake_up_state.get() };
+        for (id, incarnation) in wake_up_state.incarnations.iter().enumerate() {
+            if *incarnation != NO_WAKE_UP {
+                connection
+                    .receiver
+                    .set_wake_up_incarnation(ChannelId::new(id), *incarnation);
+            }
+        }
+
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
-        let key = connection_storage.insert(Connection::new(
-            self,
-            sender_details.data_segment_type,
-            sender_details.port_id,
-            sender_details.number_of_samples,
-            sender_details.max_number_of_segments,
-            &self.tagger,
-        )?);
+        let key = connection_storage.insert(connection);
         let key = match key {
             Some(v) => v,
             None => {
@@ -215,11 +340,41 @@ impl<Service: service::Service> Receiver<Service> {
             }
         };
 
+        // connections are ordered by descending priority and, within a priority, by key
+        let priority_order = unsafe { &mut *self.priority_order.get() };
+        let position = priority_order
+            .iter()
+            .position(|other_key| match connection_storage.get(*other_key) {
+                Some(other) => {
+                    other.priority < sender_details.priority
+                        || (other.priority == sender_details.priority
+                            && key.value() < other_key.value())
+                }
+                None => false,
+            })
+            .unwrap_or(priority_order.len());
+        if priority_order.insert(position, key).is_err() {
+            fatal_panic!(from self, "This should never happen! Internal connection priority order capacity exceeded.");
+        }
+
         unsafe { *self.connections[index].get() = Some(key) };
 
         Ok(())
     }
 
+    fn remove_from_connection_storage(
+        &self,
+        connection_storage: &mut SlotMap<Connection<Service>>,
+        key: SlotMapKey,
+    ) {
+        connection_storage.remove(key);
+
+        let priority_order = unsafe { &mut *self.priority_order.get() };
+        if let Some(position) = priority_order.iter().position(|k| *k == key) {
+            priority_order.remove(position);
+        }
+    }
+
     pub(crate) fn prepare_connection_removal(&self, index: usize) {
         if let Some(to_be_removed_connections) = &self.to_be_removed_connections {
             let key = unsafe { *self.connections[index].get() };
@@ -246,10 +401,10 @@ impl<Service: service::Service> Receiver<Service> {
                     {
                         warn!(from self,
                             "Expired connection buffer exceeded. A sender disconnected with undelivered samples that will be discarded. Increase the expired connection buffer to mitigate the problem.");
-                        connection_storage.remove(key);
+                        self.remove_from_connection_storage(connection_storage, key);
                     }
                 } else {
-                    connection_storage.remove(key);
+                    self.remove_from_connection_storage(connection_storage, key);
                 }
             }
         }
@@ -296,35 +451,69 @@ impl<Service: service::Service> Receiver<Service> {
         match connection.receiver.receive(channel_id) {
             Ok(data) => match data {
                 None => Ok(None),
-                Some(offset) => {
-                    let details = ChunkDetails {
-                        connection_key,
-                        offset,
-                        origin: connection.sender_port_id,
-                    };
+                Some(offset) => self.translate_received_offset(connection, connection_key, offset),
+            },
+            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+                    "{} since it would exceed the maximum {} of borrowed samples.",
+                    msg, connection.receiver.max_borrowed_samples());
+            }
+        }
+    }
 
-                    let offset = match connection
-                        .data_segment
-                        .register_and_translate_offset(offset)
-                    {
-                        Ok(offset) => offset,
-                        Err(e) => {
-                            if connection.data_segment.is_dynamic() {
-                                warn!(from self, "Lost a sample. This only happens in the dynamic use case when a sender has reallocated its data segment and gone out of scope before the receiver has mapped the realloacted data segment. To circumvent this, you could either use static memory or increase the initial max slice len.");
-                                return Ok(None);
-                            }
-                            fail!(from self, with ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(e)),
-                                "Unable to register and translate offset from sender {:?} since the received offset {:?} could not be registered and translated.",
-                                connection.sender_port_id, offset);
-                        }
-                    };
+    fn receive_latest_from_connection<K, F: FnMut(&ChunkDetails, &Chunk) -> Option<K>>(
+        &self,
+        connection: &Connection<Service>,
+        connection_key: SlotMapKey,
+        channel_id: ChannelId,
+        order_key: &mut F,
+    ) -> Result<Option<(K, ChunkDetails, Chunk)>, ReceiveError> {
+        let msg = "Unable to receive the latest sample";
+
+        let mut accepted: Option<(K, ChunkDetails, Chunk)> = None;
+        let mut translation_failure = None;
+        let received = connection.receiver.receive_latest(channel_id, |offset| {
+            if translation_failure.is_some() {
+                return false;
+            }
+
+            let (details, chunk) =
+                match self.translate_received_offset(connection, connection_key, offset) {
+                    Ok(Some(received)) => received,
+                    Ok(None) => return false,
+                    Err(e) => {
+                        translation_failure = Some(e);
+                        return false;
+                    }
+                };
+
+            let (is_accepted, discarded) = match order_key(&details, &chunk) {
+                Some(key) => (
+                    true,
+                    accepted.replace((key, details, chunk)).map(|(_, d, _)| d),
+                ),
+                None => (false, Some(details)),
+            };
+
+            if let Some(discarded) = discarded {
+                unsafe { connection.data_segment.unregister_offset(discarded.offset) };
+            }
+
+            is_accepted
+        });
 
-                    Ok(Some((
-                        details,
-                        Chunk::new(&self.message_type_details, offset),
-                    )))
+        match received {
+            Ok(received) => {
+                if let Some(e) = translation_failure {
+                    if let Some((_, details, _)) = accepted {
+                        self.release_offset(&details, channel_id);
+                    }
+                    return Err(e);
                 }
-            },
+
+                debug_assert!(received.is_some() == accepted.is_some());
+                Ok(accepted)
+            }
             Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
                 fail!(from self, with ReceiveError::ExceedsMaxBorrows,
                     "{} since it would exceed the maximum {} of borrowed samples.",
@@ -333,6 +522,40 @@ impl<Service: service::Service> Receiver<Service> {
         }
     }
 
+    fn translate_received_offset(
+        &self,
+        connection: &Connection<Service>,
+        connection_key: SlotMapKey,
+        offset: PointerOffset,
+    ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        let details = ChunkDetails {
+            connection_key,
+            offset,
+            origin: connection.sender_port_id,
+        };
+
+        let offset = match connection
+            .data_segment
+            .register_and_translate_offset(offset)
+        {
+            Ok(offset) => offset,
+            Err(e) => {
+                if connection.data_segment.is_dynamic() {
+                    warn!(from self, "Lost a sample. This only happens in the dynamic use case when a sender has reallocated its data segment and gone out of scope before the receiver has mapped the realloacted data segment. To circumvent this, you could either use static memory or increase the initial max slice len.");
+                    return Ok(None);
+                }
+                fail!(from self, with ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(e)),
+                    "Unable to register and translate offset from sender {:?} since the received offset {:?} could not be registered and translated.",
+                    connection.sender_port_id, offset);
+            }
+        };
+
+        Ok(Some((
+            details,
+            Chunk::new(&self.message_type_details, offset),
+        )))
+    }
+
     fn receive_from_to_be_removed_connections(
         &self,
         channel_id: ChannelId,
@@ -376,7 +599,7 @@ impl<Service: service::Service> Receiver<Service> {
 
                 for idx in clean_connections.iter().rev() {
                     to_be_removed_connections.remove(idx.0);
-                    connection_storage.remove(idx.1);
+                    self.remove_from_connection_storage(connection_storage, idx.1);
                 }
             }
         }
@@ -393,6 +616,39 @@ impl<Service: service::Service> Receiver<Service> {
         }
 
         let msg = "Unable to receive data";
+        let mut borrow_state = BorrowState::default();
+        let received = match self.receive_order {
+            ReceiveOrder::Priority => {
+                self.receive_in_priority_order(channel_id, &mut borrow_state)?
+            }
+            ReceiveOrder::RoundRobin => {
+                self.receive_in_round_robin_order(channel_id, &mut borrow_state)?
+            }
+        };
+
+        if received.is_some() {
+            return Ok(received);
+        }
+
+        if borrow_state.all_channels_exceed_max_borrows && borrow_state.active_channel_count != 0 {
+            fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+                 "{msg} since every channel exceeds the max number of borrows.");
+        }
+
+        Ok(None)
+    }
+
+    /// Receives the latest accepted data of every connection, releases all other data directly
+    /// and returns the data with the greatest key. Every chunk in the buffer is passed in order
+    /// to `order_key` which returns [`None`] when the chunk shall be discarded, otherwise the
+    /// key that orders the chunk.
+    pub(crate) fn receive_latest<K: Ord, F: FnMut(&ChunkDetails, &Chunk) -> Option<K>>(
+        &self,
+        channel_id: ChannelId,
+        mut order_key: F,
+    ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        let msg = "Unable to receive the latest data";
+        let mut latest: Option<(K, ChunkDetails, Chunk)> = None;
         let mut active_channel_count = 0;
         let mut all_channels_exceed_max_borrows = true;
         let connection_storage = unsafe { &*self.connection_storage.get() };
@@ -410,21 +666,137 @@ impl<Service: service::Service> Receiver<Service> {
                 all_channels_exceed_max_borrows = false;
             }
 
-            if let Some((details, absolute_address)) =
-                self.receive_from_connection(connection, connection_key, channel_id)?
-            {
-                return Ok(Some((details, absolute_address)));
+            let (key, details, chunk) = match self.receive_latest_from_connection(
+                connection,
+                connection_key,
+                channel_id,
+                &mut order_key,
+            )? {
+                Some(received) => received,
+                None => continue,
+            };
+
+            match &latest {
+                Some((latest_key, _, _)) if key <= *latest_key => {
+                    self.release_offset(&details, channel_id);
+                }
+                _ => {
+                    if let Some((_, outdated, _)) = latest.replace((key, details, chunk)) {
+                        self.release_offset(&outdated, channel_id);
+                    }
+                }
             }
         }
 
-        if all_channels_exceed_max_borrows && active_channel_count != 0 {
+        self.remove_drained_connections(channel_id);
+
+        if latest.is_none() && all_channels_exceed_max_borrows && active_channel_count != 0 {
             fail!(from self, with ReceiveError::ExceedsMaxBorrows,
                  "{msg} since every channel exceeds the max number of borrows.");
         }
 
+        Ok(latest.map(|(_, details, chunk)| (details, chunk)))
+    }
+
+    fn remove_drained_connections(&self, channel_id: ChannelId) {
+        if let Some(to_be_removed_connections) = &self.to_be_removed_connections {
+            let to_be_removed_connections = unsafe { &mut *to_be_removed_connections.get() };
+            let connection_storage = unsafe { &mut *self.connection_storage.get() };
+
+            for n in (0..to_be_removed_connections.len()).rev() {
+                let connection_key = to_be_removed_connections[n];
+                let is_drained = match connection_storage.get(connection_key) {
+                    Some(connection) => {
+                        !connection.receiver.has_data(channel_id)
+                            && connection.receiver.borrow_count(channel_id) == 0
+                    }
+                    None => true,
+                };
+
+                if is_drained {
+                    to_be_removed_connections.remove(n);
+                    self.remove_from_connection_storage(connection_storage, connection_key);
+                }
+            }
+        }
+    }
+
+    fn receive_in_priority_order(
+        &self,
+        channel_id: ChannelId,
+        borrow_state: &mut BorrowState,
+    ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        let priority_order = unsafe { &*self.priority_order.get() };
+        for connection_key in priority_order.iter() {
+            let connection = match connection_storage.get(*connection_key) {
+                Some(connection) => connection,
+                None => continue,
+            };
+
+            if let Some(data) =
+                self.try_receive(connection, *connection_key, channel_id, borrow_state)?
+            {
+                return Ok(Some(data));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn receive_in_round_robin_order(
+        &self,
+        channel_id: ChannelId,
+        borrow_state: &mut BorrowState,
+    ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        let round_robin_position = unsafe { &mut *self.round_robin_position.get() };
+        let start = *round_robin_position;
+
+        // first serve all connections starting at the current position, then the ones before
+        let connections = connection_storage
+            .iter()
+            .filter(|(connection_key, _)| start <= connection_key.value())
+            .chain(
+                connection_storage
+                    .iter()
+                    .filter(|(connection_key, _)| connection_key.value() < start),
+            );
+
+        for (connection_key, connection) in connections {
+            if let Some(data) =
+                self.try_receive(connection, connection_key, channel_id, borrow_state)?
+            {
+                *round_robin_position = connection_key.value() + 1;
+                return Ok(Some(data));
+            }
+        }
+
         Ok(None)
     }
 
+    fn try_receive(
+        &self,
+        connection: &Connection<Service>,
+        connection_key: SlotMapKey,
+        channel_id: ChannelId,
+        borrow_state: &mut BorrowState,
+    ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        if !connection.receiver.has_data(channel_id) {
+            return Ok(None);
+        }
+
+        borrow_state.active_channel_count += 1;
+        if connection.receiver.borrow_count(channel_id)
+            >= connection.receiver.max_borrowed_samples()
+        {
+            return Ok(None);
+        }
+        borrow_state.all_channels_exceed_max_borrows = false;
+
+        self.receive_from_connection(connection, connection_key, channel_id)
+    }
+
     pub(crate) fn start_update_connection_cycle(&self) {
         self.tagger.next_cycle();
     }
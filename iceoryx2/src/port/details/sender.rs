@@ -19,7 +19,10 @@ use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use iceoryx2_bb_elementary::cyclic_tagger::*;
-use iceoryx2_bb_log::{error, fail, fatal_panic, warn};
+use iceoryx2_bb_log::{debug, error, fail, fatal_panic, warn};
+use iceoryx2_cal::event::{
+    Event, Notifier, NotifierBuilder, NotifierCreateError, NotifierNotifyError, TriggerId,
+};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
@@ -31,13 +34,14 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 use crate::node::SharedNode;
 use crate::port::{DegradationAction, DegradationCallback, LoanError, SendError};
 use crate::prelude::UnableToDeliverStrategy;
-use crate::service::config_scheme::connection_config;
+use crate::service::config_scheme::{connection_config, event_config};
+use crate::service::naming_scheme::wake_up_event_name;
 use crate::service::static_config::message_type_details::{MessageTypeDetails, TypeVariant};
 use crate::service::{NoResource, ServiceState};
 use crate::{service, service::naming_scheme::connection_name};
 
 use super::channel_management::ChannelManagement;
-use super::channel_management::INVALID_CHANNEL_STATE;
+use super::channel_management::{INVALID_CHANNEL_STATE, NO_WAKE_UP};
 use super::chunk::ChunkMut;
 use super::data_segment::DataSegment;
 use super::segment_state::SegmentState;
@@ -48,10 +52,20 @@ pub(crate) struct ReceiverDetails {
     pub(crate) buffer_size: usize,
 }
 
+/// The notifier of the wake up event of a receiver channel. It is opened on the first
+/// delivery and reopened whenever the receiver announces a new incarnation of the event.
+#[derive(Debug)]
+struct WakeUp<Service: service::Service> {
+    is_initialized: bool,
+    incarnation: u64,
+    notifier: Option<<Service::Event as Event>::Notifier>,
+}
+
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) receiver_port_id: u128,
+    wake_ups: Vec<WakeUp<Service>>,
     tag: Tag,
 }
 
@@ -81,7 +95,8 @@ impl<Service: service::Service> Connection<Service> {
 
         let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
                         Builder::new( &connection_name(this.sender_port_id, receiver_port_id))
-                                .config(&connection_config::<Service>(this.shared_node.config()))
+                                .config(&this.service_state.static_config.access_policy().apply_to_port_resource(
+                                    connection_config::<Service>(this.shared_node.config())))
                                 .buffer_size(buffer_size)
                                 .receiver_max_borrowed_samples_per_channel(this.receiver_max_borrowed_samples)
                                 .enable_safe_overflow(this.enable_safe_overflow)
@@ -96,9 +111,47 @@ impl<Service: service::Service> Connection<Service> {
         Ok(Self {
             sender,
             receiver_port_id,
+            wake_ups: (0..this.number_of_channels)
+                .map(|_| WakeUp {
+                    is_initialized: false,
+                    incarnation: NO_WAKE_UP,
+                    notifier: None,
+                })
+                .collect(),
             tag,
         })
     }
+
+    fn wake_up(&mut self, this: &Sender<Service>, channel_id: ChannelId) {
+        let incarnation = self.sender.wake_up_incarnation(channel_id);
+        let wake_up = &mut self.wake_ups[channel_id.value()];
+        if !wake_up.is_initialized || wake_up.incarnation != incarnation {
+            wake_up.is_initialized = true;
+            wake_up.incarnation = incarnation;
+            wake_up.notifier = match <Service::Event as Event>::NotifierBuilder::new(
+                &wake_up_event_name(self.receiver_port_id, channel_id),
+            )
+            .config(&event_config::<Service>(this.shared_node.config()))
+            .open()
+            {
+                Ok(notifier) => Some(notifier),
+                // the receiver does not wait for a wake up
+                Err(NotifierCreateError::DoesNotExist) => None,
+                Err(e) => {
+                    debug!(from this,
+                            "Unable to open the wake up event of receiver {:?} ({:?}).",
+                            self.receiver_port_id, e);
+                    None
+                }
+            };
+        }
+
+        if let Some(notifier) = &wake_up.notifier {
+            if let Err(NotifierNotifyError::Disconnected) = notifier.notify(TriggerId::new(0)) {
+                wake_up.notifier = None;
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -149,12 +202,19 @@ impl<Service: service::Service> Sender<Service> {
         None
     }
 
-    fn deliver_offset_to_connection_impl(
+    pub(crate) fn receiver_port_id_of(&self, connection_id: usize) -> Option<u128> {
+        self.get(connection_id)
+            .as_ref()
+            .map(|connection| connection.receiver_port_id)
+    }
+
+    fn deliver_offset_to_connection_impl<F: FnMut(PointerOffset)>(
         &self,
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
         connection_id: usize,
+        mut discarded_sample_call: F,
     ) -> Result<usize, SendError> {
         let deliver_call = match self.unable_to_deliver_strategy {
             UnableToDeliverStrategy::Block => {
@@ -166,7 +226,7 @@ impl<Service: service::Service> Sender<Service> {
         };
 
         let mut number_of_recipients = 0;
-        if let Some(ref connection) = self.get(connection_id) {
+        if let Some(ref mut connection) = self.get_mut(connection_id) {
             match deliver_call(&connection.sender, offset, sample_size, channel_id) {
                 Err(ZeroCopySendError::ReceiveBufferFull)
                 | Err(ZeroCopySendError::UsedChunkListFull) => {
@@ -204,8 +264,11 @@ impl<Service: service::Service> Sender<Service> {
                     number_of_recipients += 1;
 
                     if let Some(old) = overflow {
+                        discarded_sample_call(old);
                         self.release_sample(old)
                     }
+
+                    connection.wake_up(self, channel_id);
                 }
             }
         }
@@ -238,6 +301,35 @@ impl<Service: service::Service> Sender<Service> {
         }
     }
 
+    pub(crate) fn credits(&self, channel_id: ChannelId, connection_id: usize) -> Option<u64> {
+        if let Some(ref connection) = self.get(connection_id) {
+            connection.sender.credits(channel_id)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn acquire_credit(&self, channel_id: ChannelId, connection_id: usize) -> bool {
+        if let Some(ref connection) = self.get(connection_id) {
+            connection.sender.acquire_credit(channel_id)
+        } else {
+            true
+        }
+    }
+
+    pub(crate) fn return_credit(&self, channel_id: ChannelId, connection_id: usize) {
+        if let Some(ref connection) = self.get(connection_id) {
+            connection.sender.grant_credits(channel_id, 1);
+        }
+    }
+
+    /// Notifies the wake up event the receiver announced for the channel, if there is any.
+    pub(crate) fn wake_up(&self, channel_id: ChannelId, connection_id: usize) {
+        if let Some(ref mut connection) = self.get_mut(connection_id) {
+            connection.wake_up(self, channel_id);
+        }
+    }
+
     pub(crate) fn invalidate_channel_state(
         &self,
         channel_id: ChannelId,
@@ -251,15 +343,22 @@ impl<Service: service::Service> Sender<Service> {
         }
     }
 
-    pub(crate) fn deliver_offset_to_connection(
+    pub(crate) fn deliver_offset_to_connection<F: FnMut(PointerOffset)>(
         &self,
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
         connection_id: usize,
+        discarded_sample_call: F,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
-        self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, connection_id)
+        self.deliver_offset_to_connection_impl(
+            offset,
+            sample_size,
+            channel_id,
+            connection_id,
+            discarded_sample_call,
+        )
     }
 
     pub(crate) fn deliver_offset(
@@ -273,7 +372,7 @@ impl<Service: service::Service> Sender<Service> {
         let mut number_of_recipients = 0;
         for i in 0..self.len() {
             number_of_recipients +=
-                self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, i)?;
+                self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, i, |_| {})?;
         }
         Ok(number_of_recipients)
     }
@@ -63,6 +63,7 @@ use core::time::Duration;
 
 use alloc::format;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::fail;
@@ -72,6 +73,7 @@ use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, NamedConceptMgmt, TriggerId};
 use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::config::Config;
 use crate::service::config_scheme::event_config;
@@ -81,6 +83,7 @@ use crate::service::{NoResource, ServiceState};
 use crate::{port::port_identifiers::UniqueListenerId, service};
 
 use super::event_id::EventId;
+use super::notification_payload::NotificationPayload;
 
 /// Defines the failures that can occur when a [`Listener`] is created with the
 /// [`crate::service::port_factory::listener::PortFactoryListener`].
@@ -114,6 +117,8 @@ pub struct Listener<Service: service::Service> {
         Service::ArcThreadSafetyPolicy<<Service::Event as iceoryx2_cal::event::Event>::Listener>,
     service_state: Arc<ServiceState<Service, NoResource>>,
     listener_id: UniqueListenerId,
+    // the sequence number of the latest received payload of every notification payload slot
+    received_payload_sequences: Vec<IoxAtomicU64>,
 }
 
 unsafe impl<Service: service::Service> Send for Listener<Service> where
@@ -166,11 +171,14 @@ impl<Service: service::Service> Listener<Service> {
         let listener_id = UniqueListenerId::new();
 
         let event_name = event_concept_name(&listener_id);
-        let event_config = event_config::<Service>(service.shared_node.config());
+        let event_config = service
+            .static_config
+            .access_policy()
+            .apply_to_port_resource(event_config::<Service>(service.shared_node.config()));
 
         let listener = fail!(from origin,
                              when <Service::Event as iceoryx2_cal::event::Event>::ListenerBuilder::new(&event_name).config(&event_config)
-                                .trigger_id_max(TriggerId::new(service.static_config.event().event_id_max_value))
+                                .trigger_id_max(service.dynamic_storage.get().event().trigger_id_max())
                                 .create(),
                              with ListenerCreateError::ResourceCreationFailed,
                              "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
@@ -188,6 +196,13 @@ impl<Service: service::Service> Listener<Service> {
             dynamic_listener_handle: None,
             listener,
             listener_id,
+            received_payload_sequences: (0..service
+                .dynamic_storage
+                .get()
+                .event()
+                .number_of_notification_payload_slots())
+                .map(|_| IoxAtomicU64::new(0))
+                .collect(),
         };
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -224,11 +239,11 @@ impl<Service: service::Service> Listener<Service> {
 
     /// Non-blocking wait for new [`EventId`]s. Collects all [`EventId`]s that were received and
     /// calls the provided callback is with the [`EventId`] as input argument.
-    pub fn try_wait_all<F: FnMut(EventId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
-        use iceoryx2_cal::event::Listener;
-        fail!(from self, when self.listener.lock().try_wait_all(callback),
-            "Failed to while calling try_wait on underlying event::Listener");
-        Ok(())
+    pub fn try_wait_all<F: FnMut(EventId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        self.try_wait_all_triggers(|id| callback(self.event_id(id)))
     }
 
     /// Blocking wait for new [`EventId`]s until the provided timeout has passed. Unblocks as soon
@@ -236,13 +251,10 @@ impl<Service: service::Service> Listener<Service> {
     /// calls the provided callback is with the [`EventId`] as input argument.
     pub fn timed_wait_all<F: FnMut(EventId)>(
         &self,
-        callback: F,
+        mut callback: F,
         timeout: Duration,
     ) -> Result<(), ListenerWaitError> {
-        use iceoryx2_cal::event::Listener;
-        fail!(from self, when self.listener.lock().timed_wait_all(callback, timeout),
-            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
-        Ok(())
+        self.timed_wait_all_triggers(|id| callback(self.event_id(id)), timeout)
     }
 
     /// Blocking wait for new [`EventId`]s. Unblocks as soon
@@ -250,21 +262,16 @@ impl<Service: service::Service> Listener<Service> {
     /// calls the provided callback is with the [`EventId`] as input argument.
     pub fn blocking_wait_all<F: FnMut(EventId)>(
         &self,
-        callback: F,
+        mut callback: F,
     ) -> Result<(), ListenerWaitError> {
-        use iceoryx2_cal::event::Listener;
-        fail!(from self, when self.listener.lock().blocking_wait_all(callback),
-            "Failed to while calling blocking_wait on underlying event::Listener");
-        Ok(())
+        self.blocking_wait_all_triggers(|id| callback(self.event_id(id)))
     }
 
     /// Non-blocking wait for a new [`EventId`]. If no [`EventId`] was notified it returns [`None`].
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn try_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
-        use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.lock().try_wait_one(),
-            "Failed to while calling try_wait on underlying event::Listener"))
+        Ok(self.try_wait_one_trigger()?.map(|id| self.event_id(id)))
     }
 
     /// Blocking wait for a new [`EventId`] until either an [`EventId`] was received or the timeout
@@ -272,11 +279,9 @@ impl<Service: service::Service> Listener<Service> {
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn timed_wait_one(&self, timeout: Duration) -> Result<Option<EventId>, ListenerWaitError> {
-        use iceoryx2_cal::event::Listener;
-        Ok(
-            fail!(from self, when self.listener.lock().timed_wait_one(timeout),
-            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout),
-        )
+        Ok(self
+            .timed_wait_one_trigger(timeout)?
+            .map(|id| self.event_id(id)))
     }
 
     /// Blocking wait for a new [`EventId`].
@@ -284,6 +289,219 @@ impl<Service: service::Service> Listener<Service> {
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn blocking_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
+        Ok(self
+            .blocking_wait_one_trigger()?
+            .map(|id| self.event_id(id)))
+    }
+
+    /// Non-blocking wait for new [`EventId`]s. Collects all [`EventId`]s that were received and
+    /// calls the provided callback with the [`EventId`] and the [`NotificationPayload`] of the
+    /// notification as input argument. A notification without payload comes with an empty
+    /// [`NotificationPayload`]. A notification whose [`NotificationPayload`] could not be read
+    /// is skipped and the call returns [`ListenerWaitError::InternalFailure`].
+    /// Payloads are not queued, see
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+    pub fn try_wait_all_with_payload<F: FnMut(EventId, &NotificationPayload)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        self.wait_all_with_payload(callback, |c| self.try_wait_all_triggers(c))
+    }
+
+    /// Blocking wait for new [`EventId`]s until the provided timeout has passed. Unblocks as soon
+    /// as an [`EventId`] was received and then collects all [`EventId`]s that were received and
+    /// calls the provided callback with the [`EventId`] and the [`NotificationPayload`] of the
+    /// notification as input argument. A notification without payload comes with an empty
+    /// [`NotificationPayload`]. A notification whose [`NotificationPayload`] could not be read
+    /// is skipped and the call returns [`ListenerWaitError::InternalFailure`].
+    /// Payloads are not queued, see
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+    pub fn timed_wait_all_with_payload<F: FnMut(EventId, &NotificationPayload)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        self.wait_all_with_payload(callback, |c| self.timed_wait_all_triggers(c, timeout))
+    }
+
+    /// Blocking wait for new [`EventId`]s. Unblocks as soon
+    /// as an [`EventId`] was received and then collects all [`EventId`]s that were received and
+    /// calls the provided callback with the [`EventId`] and the [`NotificationPayload`] of the
+    /// notification as input argument. A notification without payload comes with an empty
+    /// [`NotificationPayload`]. A notification whose [`NotificationPayload`] could not be read
+    /// is skipped and the call returns [`ListenerWaitError::InternalFailure`].
+    /// Payloads are not queued, see
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+    pub fn blocking_wait_all_with_payload<F: FnMut(EventId, &NotificationPayload)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        self.wait_all_with_payload(callback, |c| self.blocking_wait_all_triggers(c))
+    }
+
+    /// Non-blocking wait for a new [`EventId`] and the [`NotificationPayload`] of its
+    /// notification. If no [`EventId`] was notified it returns [`None`].
+    /// On error it returns [`ListenerWaitError`] is returned which describes the error
+    /// in detail.
+    /// Payloads are not queued, see
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+    pub fn try_wait_one_with_payload(
+        &self,
+    ) -> Result<Option<(EventId, NotificationPayload)>, ListenerWaitError> {
+        self.wait_one_with_payload(self.try_wait_one_trigger()?)
+    }
+
+    /// Blocking wait for a new [`EventId`] and the [`NotificationPayload`] of its
+    /// notification until either an [`EventId`] was received or the timeout
+    /// has passed. If no [`EventId`] was notified it returns [`None`].
+    /// On error it returns [`ListenerWaitError`] is returned which describes the error
+    /// in detail.
+    /// Payloads are not queued, see
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+    pub fn timed_wait_one_with_payload(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<(EventId, NotificationPayload)>, ListenerWaitError> {
+        self.wait_one_with_payload(self.timed_wait_one_trigger(timeout)?)
+    }
+
+    /// Blocking wait for a new [`EventId`] and the [`NotificationPayload`] of its
+    /// notification.
+    /// Sporadic wakeups can occur and if no [`EventId`] was notified it returns [`None`].
+    /// On error it returns [`ListenerWaitError`] is returned which describes the error
+    /// in detail.
+    /// Payloads are not queued, see
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+    pub fn blocking_wait_one_with_payload(
+        &self,
+    ) -> Result<Option<(EventId, NotificationPayload)>, ListenerWaitError> {
+        self.wait_one_with_payload(self.blocking_wait_one_trigger()?)
+    }
+
+    fn event_id(&self, trigger_id: TriggerId) -> EventId {
+        self.service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .split_trigger_id(trigger_id)
+            .0
+    }
+
+    /// Returns the [`EventId`] and the [`NotificationPayload`] of a notification or [`None`] when
+    /// the payload was already received with a previous notification.
+    fn receive_notification_payload(
+        &self,
+        trigger_id: TriggerId,
+    ) -> Result<Option<(EventId, NotificationPayload)>, ListenerWaitError> {
+        let event = self.service_state.dynamic_storage.get().event();
+        let (event_id, slot_index) = match event.split_trigger_id(trigger_id) {
+            (event_id, Some(slot_index)) => (event_id, slot_index),
+            (event_id, None) => return Ok(Some((event_id, NotificationPayload::default()))),
+        };
+
+        match event.read_notification_payload(slot_index) {
+            Some((sequence, payload)) => {
+                // when the notifier wrote the slot again before the previous notification was
+                // received, the previous notification already delivered the latest payload
+                let is_already_received = self
+                    .received_payload_sequences
+                    .get(slot_index)
+                    .is_some_and(|s| s.swap(sequence, Ordering::Relaxed) == sequence);
+
+                match is_already_received {
+                    true => Ok(None),
+                    false => Ok(Some((event_id, payload))),
+                }
+            }
+            None => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "Unable to read the notification payload of the EventId {:?} since it is still written by a notifier.",
+                    event_id);
+            }
+        }
+    }
+
+    fn wait_one_with_payload(
+        &self,
+        mut trigger_id: Option<TriggerId>,
+    ) -> Result<Option<(EventId, NotificationPayload)>, ListenerWaitError> {
+        while let Some(id) = trigger_id {
+            if let Some(notification) = self.receive_notification_payload(id)? {
+                return Ok(Some(notification));
+            }
+
+            trigger_id = self.try_wait_one_trigger()?;
+        }
+
+        Ok(None)
+    }
+
+    fn wait_all_with_payload<
+        F: FnMut(EventId, &NotificationPayload),
+        W: FnOnce(&mut dyn FnMut(TriggerId)) -> Result<(), ListenerWaitError>,
+    >(
+        &self,
+        mut callback: F,
+        wait_all: W,
+    ) -> Result<(), ListenerWaitError> {
+        let mut result = Ok(());
+        wait_all(&mut |id| match self.receive_notification_payload(id) {
+            Ok(Some((event_id, payload))) => callback(event_id, &payload),
+            Ok(None) => (),
+            Err(e) => result = Err(e),
+        })?;
+        result
+    }
+
+    fn try_wait_all_triggers<F: FnMut(TriggerId)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        fail!(from self, when self.listener.lock().try_wait_all(callback),
+            "Failed to while calling try_wait on underlying event::Listener");
+        Ok(())
+    }
+
+    fn timed_wait_all_triggers<F: FnMut(TriggerId)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        fail!(from self, when self.listener.lock().timed_wait_all(callback, timeout),
+            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+        Ok(())
+    }
+
+    fn blocking_wait_all_triggers<F: FnMut(TriggerId)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        fail!(from self, when self.listener.lock().blocking_wait_all(callback),
+            "Failed to while calling blocking_wait on underlying event::Listener");
+        Ok(())
+    }
+
+    fn try_wait_one_trigger(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        Ok(fail!(from self, when self.listener.lock().try_wait_one(),
+            "Failed to while calling try_wait on underlying event::Listener"))
+    }
+
+    fn timed_wait_one_trigger(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<TriggerId>, ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        Ok(
+            fail!(from self, when self.listener.lock().timed_wait_one(timeout),
+            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout),
+        )
+    }
+
+    fn blocking_wait_one_trigger(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
         Ok(
             fail!(from self, when self.listener.lock().blocking_wait_one(),
@@ -16,7 +16,7 @@ use tiny_fn::tiny_fn;
 use update_connections::ConnectionFailure;
 
 pub(crate) mod details;
-pub use details::data_segment::DataSegmentType;
+pub use details::data_segment::{DataSegmentPlacement, DataSegmentType};
 
 /// Sends requests to a [`Server`](crate::port::server::Server) and receives responses.
 pub mod client;
@@ -24,6 +24,8 @@ pub mod client;
 pub mod event_id;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
+/// Defines the small payload that can be attached to a notification.
+pub mod notification_payload;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
@@ -46,6 +48,9 @@ pub mod writer;
 /// receiver is full and the service does not overflow.
 pub mod unable_to_deliver_strategy;
 
+/// Defines in which order a receiver serves multiple senders.
+pub mod receive_order;
+
 use crate::port::port_identifiers::*;
 use crate::service;
 
@@ -92,6 +97,15 @@ pub enum LoanError {
     /// port must be configured with an
     /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
     ExceedsMaxLoanSize,
+    /// The [`Client`](crate::port::client::Client) has not granted enough credits to loan
+    /// another [`ResponseMut`](crate::response_mut::ResponseMut), see
+    /// [`RequestMut::set_response_credits()`](crate::request_mut::RequestMut::set_response_credits()).
+    ExceedsGrantedCredits,
+    /// The final [`ResponseMut`](crate::response_mut::ResponseMut) of the
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest) was already sent with
+    /// [`ResponseMut::send_final()`](crate::response_mut::ResponseMut::send_final()), no
+    /// further [`ResponseMut`](crate::response_mut::ResponseMut) can be loaned or sent.
+    ResponseStreamFinished,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalFailure,
 }
@@ -148,6 +162,19 @@ pub enum ReceiveError {
 
     /// Occurs when a receiver is unable to connect to a corresponding sender.
     ConnectionFailure(ConnectionFailure),
+
+    /// The timeout of the [`RequestMut`](crate::request_mut::RequestMut) has passed. It is
+    /// only emitted by the [`PendingResponse`](crate::pending_response::PendingResponse).
+    RequestTimedOut,
+
+    /// The [`Server`](crate::port::server::Server) the
+    /// [`RequestMut`](crate::request_mut::RequestMut) was dispatched to disconnected without
+    /// sending a [`Response`](crate::response::Response) and there is no other
+    /// [`Server`](crate::port::server::Server) the request could be dispatched to. It is only
+    /// emitted by the [`PendingResponse`](crate::pending_response::PendingResponse) when the
+    /// service uses a single server
+    /// [`DispatchPolicy`](crate::service::dispatch_policy::DispatchPolicy).
+    ServerDisconnected,
 }
 
 impl From<ConnectionFailure> for ReceiveError {
@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyPayloadEventName".try_into()?)
+//!     .event()
+//!     .max_notification_payload_size(8)
+//!     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//! let notifier = event.notifier_builder().create()?;
+//!
+//! notifier.notify_with_payload(EventId::new(5), &[1, 2, 3])?;
+//!
+//! while let Some((event_id, payload)) = listener.try_wait_one_with_payload()? {
+//!     println!("event {:?} was triggered with payload: {:?}", event_id, &*payload);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::ops::Deref;
+
+/// The upper bound for the
+/// [`StaticConfig::max_notification_payload_size()`](crate::service::static_config::event::StaticConfig::max_notification_payload_size())
+/// of an event [`Service`](crate::service::Service).
+pub const MAX_NOTIFICATION_PAYLOAD_SIZE: usize = 64;
+
+/// A small fixed-size payload that a [`Notifier`](crate::port::notifier::Notifier) can attach
+/// to a notification. It is delivered together with the
+/// [`EventId`](crate::port::event_id::EventId) to the
+/// [`Listener`](crate::port::listener::Listener).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotificationPayload {
+    len: usize,
+    data: [u8; MAX_NOTIFICATION_PAYLOAD_SIZE],
+}
+
+impl Default for NotificationPayload {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            data: [0; MAX_NOTIFICATION_PAYLOAD_SIZE],
+        }
+    }
+}
+
+impl core::fmt::Debug for NotificationPayload {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NotificationPayload {{ {:?} }}", self.as_slice())
+    }
+}
+
+impl Deref for NotificationPayload {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl NotificationPayload {
+    /// Creates a new [`NotificationPayload`] from the provided bytes. If the bytes exceed
+    /// [`MAX_NOTIFICATION_PAYLOAD_SIZE`] it returns [`None`].
+    pub fn new(value: &[u8]) -> Option<Self> {
+        if MAX_NOTIFICATION_PAYLOAD_SIZE < value.len() {
+            return None;
+        }
+
+        let mut new_self = Self::default();
+        new_self.data[..value.len()].copy_from_slice(value);
+        new_self.len = value.len();
+        Some(new_self)
+    }
+
+    pub(crate) fn from_raw_parts(len: usize, data: [u8; MAX_NOTIFICATION_PAYLOAD_SIZE]) -> Self {
+        Self {
+            len: len.min(MAX_NOTIFICATION_PAYLOAD_SIZE),
+            data,
+        }
+    }
+
+    /// Returns the bytes of the payload.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Returns the number of bytes of the payload.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when the payload does not contain any bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
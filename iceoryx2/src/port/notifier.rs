@@ -99,6 +99,11 @@ pub enum NotifierNotifyError {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// A [`Notifier::notify_with_payload()`] was called and the provided payload is larger
+    /// than the maximum supported
+    /// [`NotificationPayload`](crate::port::notification_payload::NotificationPayload) size
+    /// of the [`Service`](crate::service::Service)
+    PayloadExceedsMaxSize,
 }
 
 impl core::fmt::Display for NotifierNotifyError {
@@ -247,32 +252,24 @@ impl<Service: service::Service> ListenerConnections<Service> {
     }
 }
 
-/// Represents the sending endpoint of an event based communication.
+/// The state of a [`Notifier`]. It is not thread-safe on its own and is used directly by ports
+/// that share it within their own [`ArcSyncPolicy`] protected state.
 #[derive(Debug)]
-pub struct Notifier<Service: service::Service> {
-    listener_connections: Service::ArcThreadSafetyPolicy<ListenerConnections<Service>>,
+pub(crate) struct NotifierSharedState<Service: service::Service> {
+    listener_connections: ListenerConnections<Service>,
     default_event_id: EventId,
     event_id_max_value: usize,
+    max_notification_payload_size: usize,
     dynamic_notifier_handle: Option<ContainerHandle>,
     notifier_id: UniqueNotifierId,
     on_drop_notification: Option<EventId>,
     node_id: NodeId,
 }
 
-unsafe impl<Service: service::Service> Send for Notifier<Service> where
-    Service::ArcThreadSafetyPolicy<ListenerConnections<Service>>: Send + Sync
-{
-}
-
-unsafe impl<Service: service::Service> Sync for Notifier<Service> where
-    Service::ArcThreadSafetyPolicy<ListenerConnections<Service>>: Send + Sync
-{
-}
-
-impl<Service: service::Service> Drop for Notifier<Service> {
+impl<Service: service::Service> Drop for NotifierSharedState<Service> {
     fn drop(&mut self) {
         if let Some(event_id) = self.on_drop_notification {
-            if let Err(e) = self.notify_with_custom_event_id(event_id) {
+            if let Err(e) = self.notify_impl(event_id, None, false) {
                 warn!(from self, "Unable to send notifier_dropped_event {:?} due to ({:?}).",
                     event_id, e);
             }
@@ -280,7 +277,6 @@ impl<Service: service::Service> Drop for Notifier<Service> {
 
         if let Some(handle) = self.dynamic_notifier_handle {
             self.listener_connections
-                .lock()
                 .service_state
                 .dynamic_storage
                 .get()
@@ -290,14 +286,7 @@ impl<Service: service::Service> Drop for Notifier<Service> {
     }
 }
 
-impl<Service: service::Service> UpdateConnections for Notifier<Service> {
-    fn update_connections(&self) -> Result<(), super::update_connections::ConnectionFailure> {
-        self.listener_connections.lock().update_connections();
-        Ok(())
-    }
-}
-
-impl<Service: service::Service> Notifier<Service> {
+impl<Service: service::Service> NotifierSharedState<Service> {
     pub(crate) fn new(
         service: Arc<ServiceState<Service, NoResource>>,
         default_event_id: EventId,
@@ -309,7 +298,7 @@ impl<Service: service::Service> Notifier<Service> {
         new_self.on_drop_notification = static_config.notifier_dropped_event.map(EventId::new);
 
         if let Some(event_id) = static_config.notifier_created_event() {
-            match new_self.notify_with_custom_event_id(event_id) {
+            match new_self.notify_impl(event_id, None, false) {
                 Ok(_)
                 | Err(
                     NotifierNotifyError::MissedDeadline
@@ -326,7 +315,7 @@ impl<Service: service::Service> Notifier<Service> {
         Ok(new_self)
     }
 
-    pub(crate) fn new_without_auto_event_emission(
+    fn new_without_auto_event_emission(
         service: Arc<ServiceState<Service, NoResource>>,
         default_event_id: EventId,
     ) -> Result<Self, NotifierCreateError> {
@@ -338,34 +327,24 @@ impl<Service: service::Service> Notifier<Service> {
 
         let node_id = *service.shared_node.id();
         let static_config = service.static_config.event();
-        let listener_connections = Service::ArcThreadSafetyPolicy::new(ListenerConnections::new(
+        let listener_connections = ListenerConnections::new(
             listener_list.capacity(),
             service.clone(),
             UnsafeCell::new(unsafe { listener_list.get_state() }),
-        ));
-
-        let listener_connections = match listener_connections {
-            Ok(v) => v,
-            Err(e) => {
-                fail!(from origin, with NotifierCreateError::FailedToDeployThreadsafetyPolicy,
-                      "{msg} since the threadsafety policy could not be instantiated ({e:?}).");
-            }
-        };
+        );
 
         let mut new_self = Self {
             listener_connections,
             default_event_id,
             event_id_max_value: static_config.event_id_max_value,
+            max_notification_payload_size: static_config.max_notification_payload_size,
             dynamic_notifier_handle: None,
             notifier_id,
             on_drop_notification: None,
             node_id,
         };
 
-        new_self
-            .listener_connections
-            .lock()
-            .populate_listener_channels();
+        new_self.listener_connections.populate_listener_channels();
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
 
@@ -373,7 +352,6 @@ impl<Service: service::Service> Notifier<Service> {
         // the creation of all required channels
         let dynamic_notifier_handle = match new_self
             .listener_connections
-            .lock()
             .service_state
             .dynamic_storage
             .get()
@@ -394,60 +372,20 @@ impl<Service: service::Service> Notifier<Service> {
         Ok(new_self)
     }
 
-    /// Returns the [`UniqueNotifierId`] of the [`Notifier`]
-    pub fn id(&self) -> UniqueNotifierId {
-        self.notifier_id
-    }
-
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with the default
     /// event id provided on creation.
-    /// On success the number of
-    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
-    /// [`NotifierNotifyError`].
-    pub fn notify(&self) -> Result<usize, NotifierNotifyError> {
-        self.notify_with_custom_event_id(self.default_event_id)
+    pub(crate) fn notify(&self) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(self.default_event_id, None, false)
     }
 
-    /// Returns the deadline of the corresponding [`Service`](crate::service::Service).
-    pub fn deadline(&self) -> Option<Duration> {
-        self.listener_connections
-            .lock()
-            .service_state
-            .static_config
-            .event()
-            .deadline
-            .map(|v| v.value)
-    }
-
-    /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
-    /// [`EventId`].
-    /// On success the number of
-    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
-    /// [`NotifierNotifyError`].
-    pub fn notify_with_custom_event_id(
-        &self,
-        value: EventId,
-    ) -> Result<usize, NotifierNotifyError> {
-        self.__internal_notify(value, false)
-    }
-
-    /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
-    /// [`EventId`].
-    /// On success the number of
-    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
-    /// [`NotifierNotifyError`].
-    ///
-    /// When `skip_self_deliver` is set to true the [`Notifier`] will only notify
-    /// [`crate::port::listener::Listener`]s that were NOT created by the same node (have the same
-    /// [`crate::node::NodeId`])
-    #[doc(hidden)]
-    pub fn __internal_notify(
+    fn notify_impl(
         &self,
         value: EventId,
+        payload: Option<&[u8]>,
         skip_self_deliver: bool,
     ) -> Result<usize, NotifierNotifyError> {
         let msg = "Unable to notify event";
-        let listener_connections = self.listener_connections.lock();
+        let listener_connections = &self.listener_connections;
         listener_connections.update_connections();
 
         use iceoryx2_cal::event::Notifier;
@@ -459,10 +397,29 @@ impl<Service: service::Service> Notifier<Service> {
                             msg, value, self.event_id_max_value);
         }
 
+        let payload_len = payload.map_or(0, |p| p.len());
+        if self.max_notification_payload_size < payload_len {
+            fail!(from self, with NotifierNotifyError::PayloadExceedsMaxSize,
+                            "{} since the payload size of {} bytes exceeds the maximum supported notification payload size of {} bytes.",
+                            msg, payload_len, self.max_notification_payload_size);
+        }
+
+        // the payload must be stored before the listeners are woken up, a notification without
+        // payload does not touch the payload slots
+        let trigger_id = match (payload, self.dynamic_notifier_handle) {
+            (Some(payload), Some(handle)) => listener_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .write_notification_payload(handle, value, payload),
+            _ => value,
+        };
+
         for i in 0..listener_connections.len() {
             if let Some(ref connection) = listener_connections.get(i) {
                 if !(skip_self_deliver && connection.node_id == self.node_id) {
-                    match connection.notifier.notify(value) {
+                    match connection.notifier.notify(trigger_id) {
                         Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
                             listener_connections.remove(i);
                         }
@@ -512,3 +469,143 @@ impl<Service: service::Service> Notifier<Service> {
         Ok(number_of_triggered_listeners)
     }
 }
+
+/// Represents the sending endpoint of an event based communication.
+#[derive(Debug)]
+pub struct Notifier<Service: service::Service> {
+    notifier_shared_state: Service::ArcThreadSafetyPolicy<NotifierSharedState<Service>>,
+}
+
+unsafe impl<Service: service::Service> Send for Notifier<Service> where
+    Service::ArcThreadSafetyPolicy<NotifierSharedState<Service>>: Send + Sync
+{
+}
+
+unsafe impl<Service: service::Service> Sync for Notifier<Service> where
+    Service::ArcThreadSafetyPolicy<NotifierSharedState<Service>>: Send + Sync
+{
+}
+
+impl<Service: service::Service> UpdateConnections for Notifier<Service> {
+    fn update_connections(&self) -> Result<(), super::update_connections::ConnectionFailure> {
+        self.notifier_shared_state
+            .lock()
+            .listener_connections
+            .update_connections();
+        Ok(())
+    }
+}
+
+impl<Service: service::Service> Notifier<Service> {
+    pub(crate) fn new(
+        service: Arc<ServiceState<Service, NoResource>>,
+        default_event_id: EventId,
+    ) -> Result<Self, NotifierCreateError> {
+        Self::from_shared_state(NotifierSharedState::new(service, default_event_id)?)
+    }
+
+    pub(crate) fn new_without_auto_event_emission(
+        service: Arc<ServiceState<Service, NoResource>>,
+        default_event_id: EventId,
+    ) -> Result<Self, NotifierCreateError> {
+        Self::from_shared_state(NotifierSharedState::new_without_auto_event_emission(
+            service,
+            default_event_id,
+        )?)
+    }
+
+    fn from_shared_state(
+        notifier_shared_state: NotifierSharedState<Service>,
+    ) -> Result<Self, NotifierCreateError> {
+        match Service::ArcThreadSafetyPolicy::new(notifier_shared_state) {
+            Ok(notifier_shared_state) => Ok(Self {
+                notifier_shared_state,
+            }),
+            Err(e) => {
+                fail!(from "Notifier::new()", with NotifierCreateError::FailedToDeployThreadsafetyPolicy,
+                      "Unable to create Notifier port since the threadsafety policy could not be instantiated ({e:?}).");
+            }
+        }
+    }
+
+    /// Returns the [`UniqueNotifierId`] of the [`Notifier`]
+    pub fn id(&self) -> UniqueNotifierId {
+        self.notifier_shared_state.lock().notifier_id
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with the default
+    /// event id provided on creation.
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify(&self) -> Result<usize, NotifierNotifyError> {
+        self.notifier_shared_state.lock().notify()
+    }
+
+    /// Returns the deadline of the corresponding [`Service`](crate::service::Service).
+    pub fn deadline(&self) -> Option<Duration> {
+        self.notifier_shared_state
+            .lock()
+            .listener_connections
+            .service_state
+            .static_config
+            .event()
+            .deadline
+            .map(|v| v.value)
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
+    /// [`EventId`].
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_custom_event_id(
+        &self,
+        value: EventId,
+    ) -> Result<usize, NotifierNotifyError> {
+        self.__internal_notify(value, false)
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
+    /// [`EventId`] and attaches the provided payload to the notification. The payload must not
+    /// exceed
+    /// [`StaticConfig::max_notification_payload_size()`](crate::service::static_config::event::StaticConfig::max_notification_payload_size()).
+    ///
+    /// Payloads are not queued. Every [`Notifier`] has its own payload slot per [`EventId`]
+    /// that is overwritten by its next notification of that [`EventId`]. When the
+    /// [`crate::port::listener::Listener`] did not yet process the previous notification, both
+    /// notifications are merged into one that carries the latest payload.
+    ///
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_payload(
+        &self,
+        value: EventId,
+        payload: &[u8],
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notifier_shared_state
+            .lock()
+            .notify_impl(value, Some(payload), false)
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
+    /// [`EventId`].
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    ///
+    /// When `skip_self_deliver` is set to true the [`Notifier`] will only notify
+    /// [`crate::port::listener::Listener`]s that were NOT created by the same node (have the same
+    /// [`crate::node::NodeId`])
+    #[doc(hidden)]
+    pub fn __internal_notify(
+        &self,
+        value: EventId,
+        skip_self_deliver: bool,
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notifier_shared_state
+            .lock()
+            .notify_impl(value, None, skip_self_deliver)
+    }
+}
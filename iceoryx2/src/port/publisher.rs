@@ -116,16 +116,19 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::shared_memory::SharedMemoryCreateError;
 use iceoryx2_cal::shm_allocator::{AllocationStrategy, PointerOffset};
 use iceoryx2_cal::zero_copy_connection::{
     ChannelId, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
 
 use crate::port::details::sender::*;
+use crate::port::notifier::NotifierSharedState;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::prelude::UnableToDeliverStrategy;
 use crate::raw_sample::RawSampleMut;
@@ -135,12 +138,15 @@ use crate::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::data_segment_name;
+use crate::service::port_factory::event;
 use crate::service::port_factory::publisher::LocalPublisherConfig;
 use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::static_config::publish_subscribe;
 use crate::service::{self, NoResource, ServiceState};
 
-use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::data_segment::{
+    DataSegment, DataSegmentMemoryProperties, DataSegmentPlacement, DataSegmentType,
+};
 use super::details::segment_state::SegmentState;
 use super::port_identifiers::UniquePublisherId;
 use super::{LoanError, SendError};
@@ -156,9 +162,15 @@ pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     /// The datasegment in which the payload of the [`Publisher`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The datasegment in which the payload of the [`Publisher`] is stored, could not be locked
+    /// into RAM and the [`MemoryLockPolicy`](crate::prelude::MemoryLockPolicy) is
+    /// [`MemoryLockPolicy::Required`](crate::prelude::MemoryLockPolicy::Required).
+    UnableToLockDataSegment,
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -182,6 +194,8 @@ pub(crate) struct PublisherSharedState<Service: service::Service> {
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
     is_active: IoxAtomicBool,
+    sequence_number: IoxAtomicU64,
+    deadline_notifier: Option<NotifierSharedState<Service>>,
 }
 
 impl<Service: service::Service> PublisherSharedState<Service> {
@@ -283,6 +297,7 @@ impl<Service: service::Service> PublisherSharedState<Service> {
 
     pub(crate) fn send_sample(
         &self,
+        header: &mut Header,
         offset: PointerOffset,
         sample_size: usize,
     ) -> Result<usize, SendError> {
@@ -295,9 +310,28 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
+        let send_timestamp = Time::now().unwrap_or_else(|e| {
+            warn!(from self, "Unable to acquire the send timestamp ({:?}), the sample is sent without it.", e);
+            Time::default()
+        });
+        header.set_send_details(
+            self.sequence_number.fetch_add(1, Ordering::Relaxed),
+            send_timestamp,
+        );
+
         self.add_sample_to_history(offset, sample_size);
-        self.sender
-            .deliver_offset(offset, sample_size, ChannelId::new(0))
+        let number_of_recipients =
+            self.sender
+                .deliver_offset(offset, sample_size, ChannelId::new(0))?;
+
+        if let Some(notifier) = &self.deadline_notifier {
+            if let Err(e) = notifier.notify() {
+                warn!(from self,
+                    "Unable to notify the subscribers about the new sample for the deadline monitoring ({:?}).", e);
+            }
+        }
+
+        Ok(number_of_recipients)
     }
 }
 
@@ -365,6 +399,7 @@ impl<
     pub(crate) fn new(
         service: Arc<ServiceState<Service, NoResource>>,
         static_config: &publish_subscribe::StaticConfig,
+        deadline_event_service: Option<&event::PortFactory<Service>>,
         config: LocalPublisherConfig,
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
@@ -390,41 +425,69 @@ impl<
         let max_slice_len = config.initial_max_slice_len;
         let max_number_of_segments =
             DataSegment::<Service>::max_number_of_segments(data_segment_type);
-        let publisher_details = PublisherDetails {
+        let mut publisher_details = PublisherDetails {
             data_segment_type,
+            data_segment_placement: DataSegmentPlacement::Unbound,
             publisher_id: port_id,
             number_of_samples,
             max_slice_len,
             node_id: *service.shared_node.id(),
             max_number_of_segments,
+            priority: config.priority,
         };
         let global_config = service.shared_node.config();
 
         let segment_name = data_segment_name(publisher_details.publisher_id.value());
+        let memory_properties = DataSegmentMemoryProperties {
+            memory_lock_policy: config.memory_lock_policy,
+            numa_placement: config.numa_placement,
+        };
         let data_segment = match data_segment_type {
             DataSegmentType::Static => DataSegment::create_static_segment(
                 &segment_name,
                 sample_layout,
                 global_config,
+                service.static_config.access_policy(),
                 number_of_samples,
+                memory_properties,
             ),
             DataSegmentType::Dynamic => DataSegment::create_dynamic_segment(
                 &segment_name,
                 sample_layout,
                 global_config,
+                service.static_config.access_policy(),
                 number_of_samples,
                 config.allocation_strategy,
+                memory_properties,
             ),
         };
 
-        let data_segment = fail!(from origin,
-                when data_segment,
-                with PublisherCreateError::UnableToCreateDataSegment,
-                "{} since the data segment could not be acquired.", msg);
+        let data_segment = match data_segment {
+            Ok(data_segment) => data_segment,
+            Err(SharedMemoryCreateError::UnableToLockMemory) => {
+                fail!(from origin, with PublisherCreateError::UnableToLockDataSegment,
+                    "{} since the data segment could not be locked into memory.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with PublisherCreateError::UnableToCreateDataSegment,
+                    "{} since the data segment could not be acquired ({:?}).", msg, e);
+            }
+        };
+        publisher_details.data_segment_placement = data_segment.placement();
+
+        let deadline_notifier = match deadline_event_service {
+            None => None,
+            Some(event_service) => Some(fail!(from origin,
+                when event_service.notifier_builder().create_shared_state(),
+                with PublisherCreateError::InternalFailure,
+                "{} since the notifier for the subscriber deadline could not be created.", msg)),
+        };
 
         let publisher_shared_state =
             <Service as service::Service>::ArcThreadSafetyPolicy::new(PublisherSharedState {
                 is_active: IoxAtomicBool::new(true),
+                sequence_number: IoxAtomicU64::new(0),
+                deadline_notifier,
                 sender: Sender {
                     data_segment,
                     segment_states: {
@@ -37,7 +37,7 @@
 
 use crate::constants::MAX_BLACKBOARD_KEY_SIZE;
 use crate::prelude::EventId;
-use crate::service::builder::blackboard::{BlackboardResources, KeyMemory};
+use crate::service::builder::blackboard::{BlackboardResources, KeyMemory, SliceCellLayout};
 use crate::service::builder::CustomKeyMarker;
 use crate::service::dynamic_config::blackboard::ReaderDetails;
 use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
@@ -56,12 +56,18 @@ use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::{
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 extern crate alloc;
 use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
 
 use super::port_identifiers::UniqueReaderId;
 
+// Generations are 32-bit values, therefore this marker never matches a valid generation.
+const NOT_YET_READ: u64 = u64::MAX;
+
 #[derive(Debug)]
 struct ReaderSharedState<
     Service: service::Service,
@@ -203,7 +209,7 @@ impl<
             }
         };
 
-        let offset = self.get_entry_offset(
+        let (offset, _) = self.get_entry_offset(
             &key_mem,
             &TypeDetail::new::<ValueType>(TypeVariant::FixedSize),
             msg,
@@ -220,44 +226,72 @@ impl<
         Ok(EntryHandle::new(self.shared_state.clone(), atomic, offset))
     }
 
+    /// Creates a [`SliceEntryHandle`] for direct read access to a value that was added with
+    /// [`Creator::add_slice()`](crate::service::builder::blackboard::Creator::add_slice()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<u8>(1, 16, b"hello")
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// let entry_handle = reader.entry_slice::<u8>(&1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn entry_slice<ValueType: ZeroCopySend + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<SliceEntryHandle<Service, KeyType, ValueType>, EntryHandleError> {
+        let msg = "Unable to create slice entry handle";
+
+        // create KeyMemory from key
+        let key_mem = match KeyMemory::try_from(key) {
+            Ok(mem) => mem,
+            Err(_) => {
+                fatal_panic!(from self, "This should never happen! Key with invalid layout passed.");
+            }
+        };
+
+        let (offset, max_slice_len) = self.get_entry_offset(
+            &key_mem,
+            &TypeDetail::new::<ValueType>(TypeVariant::Dynamic),
+            msg,
+        )?;
+
+        Ok(SliceEntryHandle::new(
+            self.shared_state.clone(),
+            offset,
+            max_slice_len as usize,
+        ))
+    }
+
     fn get_entry_offset(
         &self,
         key_mem: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
         value_type_details: &TypeDetail,
         msg: &str,
-    ) -> Result<u64, EntryHandleError> {
+    ) -> Result<(u64, u64), EntryHandleError> {
         // check if key exists
-        let index = match unsafe {
-            self.shared_state
-                .service_state
-                .additional_resource
-                .mgmt
-                .get()
-                .map
-                .__internal_get(
-                    key_mem,
-                    self.shared_state
-                        .service_state
-                        .additional_resource
-                        .key_eq_func
-                        .as_ref(),
-                )
-        } {
-            Some(i) => i,
+        let entry = match self
+            .shared_state
+            .service_state
+            .additional_resource
+            .get_entry(key_mem)
+        {
+            Some(entry) => entry,
             None => {
                 fail!(from self, with EntryHandleError::EntryDoesNotExist,
                 "{} since no entry with the given key exists.", msg);
             }
         };
 
-        let entry = &self
-            .shared_state
-            .service_state
-            .additional_resource
-            .mgmt
-            .get()
-            .entries[index];
-
         // check if ValueType matches
         if *value_type_details != entry.type_details {
             fail!(from self, with EntryHandleError::EntryDoesNotExist,
@@ -266,7 +300,7 @@ impl<
 
         let offset = entry.offset.load(core::sync::atomic::Ordering::Relaxed);
 
-        Ok(offset)
+        Ok((offset, entry.max_slice_len))
     }
 }
 
@@ -293,6 +327,7 @@ pub struct EntryHandle<
 > {
     atomic: *const UnrestrictedAtomic<ValueType>,
     entry_id: EventId,
+    last_generation: IoxAtomicU64,
     _shared_state: Arc<ReaderSharedState<Service, KeyType>>,
 }
 
@@ -328,6 +363,7 @@ impl<
         Self {
             atomic,
             entry_id: EventId::new(offset as _),
+            last_generation: IoxAtomicU64::new(NOT_YET_READ),
             _shared_state: reader_state.clone(),
         }
     }
@@ -352,7 +388,255 @@ impl<
     /// # }
     /// ```
     pub fn get(&self) -> ValueType {
-        unsafe { (*self.atomic).load() }
+        let (value, generation) = unsafe { (*self.atomic).load_with_generation() };
+        self.last_generation
+            .store(generation as u64, Ordering::Relaxed);
+        value
+    }
+
+    /// Returns the generation of the current value. The generation is incremented with every
+    /// update of the value and wraps around on overflow.
+    pub fn generation(&self) -> u32 {
+        unsafe { (*self.atomic).generation() }
+    }
+
+    /// Returns true when the value was updated since it was read the last time with this
+    /// [`EntryHandle`] or when it was never read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// # let entry_handle = reader.entry::<i32>(&1)?;
+    /// if entry_handle.has_changed() {
+    ///     println!("new value: {}", entry_handle.get());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_changed(&self) -> bool {
+        self.last_generation.load(Ordering::Relaxed) != self.generation() as u64
+    }
+
+    /// Returns a copy of the value when it was updated since it was read the last time with
+    /// this [`EntryHandle`] or when it was never read. Otherwise [`None`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// # let entry_handle = reader.entry::<i32>(&1)?;
+    /// if let Some(value) = entry_handle.get_if_changed() {
+    ///     println!("new value: {value}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_if_changed(&self) -> Option<ValueType> {
+        if !self.has_changed() {
+            return None;
+        }
+
+        Some(self.get())
+    }
+
+    /// Returns an ID corresponding to the entry which can be used in an event based communication
+    /// setup.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
+/// A handle for direct read access to a specific blackboard value that stores a slice.
+pub struct SliceEntryHandle<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> {
+    atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
+    data_ptr: *const u8,
+    cell: SliceCellLayout,
+    max_slice_len: usize,
+    entry_id: EventId,
+    last_generation: IoxAtomicU64,
+    // The snapshot of the cell is taken into this buffer, it is allocated once when the handle
+    // is created so that reading the slice does not allocate.
+    snapshot: Vec<u8>,
+    _shared_state: Arc<ReaderSharedState<Service, KeyType>>,
+    _value: PhantomData<ValueType>,
+}
+
+// Safe since the pointers to the UnrestrictedAtomicMgmt and its data do not change, the
+// UnrestrictedAtomicMgmt supports concurrent loads, and shared_state ensures the lifetime of the
+// underlying memory
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > Send for SliceEntryHandle<Service, KeyType, ValueType>
+{
+}
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > Sync for SliceEntryHandle<Service, KeyType, ValueType>
+{
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > SliceEntryHandle<Service, KeyType, ValueType>
+{
+    fn new(
+        reader_state: Arc<ReaderSharedState<Service, KeyType>>,
+        offset: u64,
+        max_slice_len: usize,
+    ) -> Self {
+        let cell = SliceCellLayout::new::<ValueType>(max_slice_len);
+        let atomic_mgmt_ptr = (reader_state
+            .service_state
+            .additional_resource
+            .data
+            .payload_start_address() as u64
+            + offset) as *const UnrestrictedAtomicMgmt;
+        let data_ptr = atomic_mgmt_ptr as usize + core::mem::size_of::<UnrestrictedAtomicMgmt>();
+        let data_ptr = align(data_ptr, cell.alignment);
+
+        Self {
+            atomic_mgmt_ptr,
+            data_ptr: data_ptr as *const u8,
+            cell,
+            max_slice_len,
+            entry_id: EventId::new(offset as _),
+            last_generation: IoxAtomicU64::new(NOT_YET_READ),
+            snapshot: vec![0; cell.size + cell.alignment],
+            _shared_state: reader_state,
+            _value: PhantomData,
+        }
+    }
+
+    /// Returns the maximum number of elements the slice can hold.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Takes a consistent snapshot of the slice and calls `callback` with it. The snapshot is
+    /// never torn by a concurrent update of the [`Writer`](crate::port::writer::Writer).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<u8>(1, 16, b"hello")
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// # let mut entry_handle = reader.entry_slice::<u8>(&1)?;
+    /// let len = entry_handle.read(|value| value.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read<R, F: FnOnce(&[ValueType]) -> R>(&mut self, callback: F) -> R {
+        let snapshot = align(self.snapshot.as_mut_ptr() as usize, self.cell.alignment) as *mut u8;
+
+        let generation = unsafe {
+            (*self.atomic_mgmt_ptr).load_with_generation(
+                snapshot,
+                self.cell.size,
+                self.cell.alignment,
+                self.data_ptr,
+            )
+        };
+        self.last_generation
+            .store(generation as u64, Ordering::Relaxed);
+
+        let slice_len =
+            (unsafe { (snapshot as *const u64).read() } as usize).min(self.max_slice_len);
+        // The elements are a bytewise copy that is never dropped, therefore it is sound to hand
+        // out a reference to them also for non-Copy types.
+        let elements = unsafe {
+            core::slice::from_raw_parts(
+                snapshot.add(self.cell.elements_offset) as *const ValueType,
+                slice_len,
+            )
+        };
+
+        callback(elements)
+    }
+
+    /// Returns a consistent copy of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<u8>(1, 16, b"hello")
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// # let mut entry_handle = reader.entry_slice::<u8>(&1)?;
+    /// let value = entry_handle.get();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(&mut self) -> Vec<ValueType>
+    where
+        ValueType: Clone,
+    {
+        self.read(|value| value.to_vec())
+    }
+
+    /// Returns the generation of the current value. The generation is incremented with every
+    /// update of the value and wraps around on overflow.
+    pub fn generation(&self) -> u32 {
+        unsafe { (*self.atomic_mgmt_ptr).generation() }
+    }
+
+    /// Returns true when the value was updated since it was read the last time with this
+    /// [`SliceEntryHandle`] or when it was never read.
+    pub fn has_changed(&self) -> bool {
+        self.last_generation.load(Ordering::Relaxed) != self.generation() as u64
+    }
+
+    /// Returns a consistent copy of the slice when it was updated since it was read the last
+    /// time with this [`SliceEntryHandle`] or when it was never read. Otherwise [`None`] is
+    /// returned.
+    pub fn get_if_changed(&mut self) -> Option<Vec<ValueType>>
+    where
+        ValueType: Clone,
+    {
+        if !self.has_changed() {
+            return None;
+        }
+
+        Some(self.get())
     }
 
     /// Returns an ID corresponding to the entry which can be used in an event based communication
@@ -362,8 +646,27 @@ impl<
     }
 }
 
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > Debug for SliceEntryHandle<Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SliceEntryHandle<{}> {{ max_slice_len: {}, entry_id: {:?} }}",
+            core::any::type_name::<ValueType>(),
+            self.max_slice_len,
+            self.entry_id
+        )
+    }
+}
+
 impl<Service: service::Service> Reader<Service, CustomKeyMarker> {
     #[doc(hidden)]
+    /// Slice entries, whose value type has the [`TypeVariant::Dynamic`], are not supported.
+    ///
     /// # Safety
     ///
     ///   * key must be a valid pointer to a value of the set key type
@@ -374,6 +677,11 @@ impl<Service: service::Service> Reader<Service, CustomKeyMarker> {
     ) -> Result<__InternalEntryHandle<Service>, EntryHandleError> {
         let msg = "Unable to create entry handle";
 
+        if value_type_details.variant == TypeVariant::Dynamic {
+            fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                "{} since slice entries are not supported by the internal entry handle.", msg);
+        }
+
         let key_type_details = self
             .shared_state
             .service_state
@@ -394,7 +702,7 @@ impl<Service: service::Service> Reader<Service, CustomKeyMarker> {
             }
         };
 
-        let offset = self.get_entry_offset(&key_mem, value_type_details, msg)?;
+        let (offset, _) = self.get_entry_offset(&key_mem, value_type_details, msg)?;
 
         let atomic_mgmt_ptr = (self
             .shared_state
@@ -0,0 +1,30 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// Defines in which order a [`Subscriber`](crate::port::subscriber::Subscriber) receives the
+/// [`Sample`](crate::sample::Sample)s of multiple
+/// [`Publisher`](crate::port::publisher::Publisher)s.
+#[repr(C)]
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveOrder {
+    /// The [`Sample`](crate::sample::Sample)s of the
+    /// [`Publisher`](crate::port::publisher::Publisher) with the highest priority, defined via
+    /// [`PortFactoryPublisher::priority()`](crate::service::port_factory::publisher::PortFactoryPublisher::priority()),
+    /// are received first. [`Publisher`](crate::port::publisher::Publisher)s with the same
+    /// priority are served in a fixed order.
+    #[default]
+    Priority,
+    /// Every [`Publisher`](crate::port::publisher::Publisher) is served in turn, independent
+    /// of its priority, so that no [`Publisher`](crate::port::publisher::Publisher) can starve
+    /// another one.
+    RoundRobin,
+}
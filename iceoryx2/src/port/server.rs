@@ -89,7 +89,10 @@ use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::shared_memory::SharedMemoryCreateError;
 
+use crate::port::listener::Listener;
+use crate::port::receive_order::ReceiveOrder;
 use crate::port::update_connections::UpdateConnections;
 use crate::prelude::UnableToDeliverStrategy;
 use crate::service::builder::CustomPayloadMarker;
@@ -97,7 +100,7 @@ use crate::service::naming_scheme::data_segment_name;
 use crate::service::port_factory::server::LocalServerConfig;
 use crate::service::NoResource;
 use crate::{
-    active_request::ActiveRequest,
+    active_request::{ActiveRequest, ResponseStreamState},
     prelude::PortFactory,
     raw_sample::RawSample,
     service::{
@@ -108,7 +111,7 @@ use crate::{
     },
 };
 
-use super::details::data_segment::DataSegment;
+use super::details::data_segment::{DataSegment, DataSegmentMemoryProperties};
 use super::details::segment_state::SegmentState;
 use super::details::sender::{ReceiverDetails, Sender};
 use super::{
@@ -116,7 +119,7 @@ use super::{
         chunk::Chunk,
         chunk_details::ChunkDetails,
         data_segment::DataSegmentType,
-        receiver::{Receiver, SenderDetails},
+        receiver::{Receiver, SenderDetails, WakeUpState},
     },
     update_connections::ConnectionFailure,
     ReceiveError, UniqueServerId,
@@ -149,6 +152,26 @@ impl<Service: service::Service> Drop for SharedServerState<Service> {
 }
 
 impl<Service: service::Service> SharedServerState<Service> {
+    pub(crate) fn release_dispatched_request(&self) {
+        if !self
+            .service_state
+            .static_config
+            .request_response()
+            .dispatch_policy
+            .dispatches_to_single_server()
+        {
+            return;
+        }
+
+        if let Some(handle) = unsafe { *self.server_handle.get() } {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .decrement_outstanding_requests(handle.index() as usize);
+        }
+    }
+
     pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
         if unsafe {
             self.request_receiver
@@ -182,6 +205,7 @@ impl<Service: service::Service> SharedServerState<Service> {
                         number_of_samples: details.number_of_requests,
                         max_number_of_segments: details.max_number_of_segments,
                         data_segment_type: details.data_segment_type,
+                        priority: 0,
                     },
                 );
                 result = result.and(inner_result);
@@ -222,9 +246,10 @@ pub struct Server<
     ResponsePayload: Debug + ZeroCopySend + ?Sized,
     ResponseHeader: Debug + ZeroCopySend,
 > {
-    shared_state: Service::ArcThreadSafetyPolicy<SharedServerState<Service>>,
+    pub(crate) shared_state: Service::ArcThreadSafetyPolicy<SharedServerState<Service>>,
     max_loaned_responses_per_request: usize,
     enable_fire_and_forget: bool,
+    cancellation_listener: Option<Listener<Service>>,
     _request_payload: PhantomData<RequestPayload>,
     _request_header: PhantomData<RequestHeader>,
     _response_payload: PhantomData<ResponsePayload>,
@@ -344,6 +369,13 @@ impl<
             degradation_callback: server_factory.request_degradation_callback,
             number_of_channels: 1,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
+            priority_order: UnsafeCell::new(
+                PolymorphicVec::new(HeapAllocator::global(), number_of_connections)
+                    .expect("Heap allocator provides memory."),
+            ),
+            receive_order: ReceiveOrder::default(),
+            round_robin_position: UnsafeCell::new(0),
+            wake_up_state: UnsafeCell::new(WakeUpState::default()),
         };
 
         let global_config = service.shared_node.config();
@@ -356,26 +388,42 @@ impl<
         let sample_layout = static_config
             .response_message_type_details
             .sample_layout(server_factory.config.initial_max_slice_len);
+        let memory_properties = DataSegmentMemoryProperties {
+            memory_lock_policy: server_factory.config.memory_lock_policy,
+            numa_placement: server_factory.config.numa_placement,
+        };
         let data_segment = match data_segment_type {
             DataSegmentType::Static => DataSegment::<Service>::create_static_segment(
                 &segment_name,
                 sample_layout,
                 global_config,
+                service.static_config.access_policy(),
                 number_of_responses,
+                memory_properties,
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
                 sample_layout,
                 global_config,
+                service.static_config.access_policy(),
                 number_of_responses,
                 server_factory.config.allocation_strategy,
+                memory_properties,
             ),
         };
 
-        let data_segment = fail!(from origin,
-            when data_segment,
-            with ServerCreateError::UnableToCreateDataSegment,
-            "{} since the server data segment could not be created.", msg);
+        let data_segment = match data_segment {
+            Ok(data_segment) => data_segment,
+            Err(SharedMemoryCreateError::UnableToLockMemory) => {
+                fail!(from origin, with ServerCreateError::UnableToLockDataSegment,
+                    "{} since the server data segment could not be locked into memory.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with ServerCreateError::UnableToCreateDataSegment,
+                    "{} since the server data segment could not be created ({:?}).", msg, e);
+            }
+        };
+        let data_segment_placement = data_segment.placement();
 
         let response_sender = Sender {
             segment_states: {
@@ -427,8 +475,17 @@ impl<
             }
         };
 
+        let cancellation_listener = match &server_factory.factory.cancellation_event_service {
+            None => None,
+            Some(event_service) => Some(fail!(from origin,
+                when event_service.listener_builder().create(),
+                with ServerCreateError::InternalFailure,
+                "{} since the listener for the request cancellation could not be created.", msg)),
+        };
+
         let new_self = Self {
             max_loaned_responses_per_request: server_factory.max_loaned_responses_per_request,
+            cancellation_listener,
             enable_fire_and_forget: service
                 .static_config
                 .request_response()
@@ -460,6 +517,7 @@ impl<
                     number_of_responses,
                     max_slice_len: server_factory.config.initial_max_slice_len,
                     data_segment_type,
+                    data_segment_placement,
                     max_number_of_segments,
                 }) {
                 Some(v) => Some(v),
@@ -482,6 +540,35 @@ impl<
         ))
     }
 
+    /// Consumes all cancellation signals and returns [`true`] when at least one
+    /// [`Client`](crate::port::client::Client) cancelled a request since the last call,
+    /// otherwise [`false`]. A request is cancelled when its
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) goes out of scope or
+    /// times out while the [`Server`] still holds the corresponding [`ActiveRequest`]. The
+    /// affected [`ActiveRequest`]s can be identified with [`ActiveRequest::is_cancelled()`].
+    ///
+    /// When the [`Service`](crate::service::Service) has request cancellation enabled, the
+    /// [`Server::cancellation_listener()`] can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet) to wake up on these signals.
+    pub fn consume_cancellation_signals(&self) -> bool {
+        let mut has_signals = false;
+        if let Some(listener) = &self.cancellation_listener {
+            if let Err(e) = listener.try_wait_all(|_| has_signals = true) {
+                warn!(from self, "Unable to consume the cancellation signals ({:?}).", e);
+            }
+        }
+
+        has_signals
+    }
+
+    /// Returns the [`Listener`] that receives the cancellation signals of the
+    /// [`Client`](crate::port::client::Client)s. It can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet) to wake up when a request was cancelled. Returns
+    /// [`None`] when the [`Service`](crate::service::Service) has request cancellation disabled.
+    pub fn cancellation_listener(&self) -> Option<&Listener<Service>> {
+        self.cancellation_listener.as_ref()
+    }
+
     /// Returns true if the [`Server`] has [`RequestMut`](crate::request_mut::RequestMut)s in its buffer.
     pub fn has_requests(&self) -> Result<bool, ConnectionFailure> {
         let shared_state = self.shared_state.lock();
@@ -540,7 +627,7 @@ impl<
 
         ActiveRequest {
             details,
-            shared_loan_counter: Arc::new(IoxAtomicUsize::new(0)),
+            response_stream: Arc::new(ResponseStreamState::default()),
             max_loan_count: self.max_loaned_responses_per_request,
             request_id: header.request_id,
             channel_id: header.channel_id,
@@ -649,7 +736,7 @@ impl<
 
         ActiveRequest {
             details,
-            shared_loan_counter: Arc::new(IoxAtomicUsize::new(0)),
+            response_stream: Arc::new(ResponseStreamState::default()),
             max_loan_count: self.max_loaned_responses_per_request,
             request_id: header.request_id,
             channel_id: header.channel_id,
@@ -36,6 +36,7 @@ use core::cell::UnsafeCell;
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 
 use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::vector::polymorphic_vec::*;
@@ -49,11 +50,14 @@ use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
+use tiny_fn::tiny_fn;
 
+use crate::port::listener::Listener;
 use crate::port::update_connections::UpdateConnections;
 use crate::service::builder::CustomPayloadMarker;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
+use crate::service::port_factory::event;
 use crate::service::port_factory::subscriber::SubscriberConfig;
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::service::{NoResource, ServiceState};
@@ -62,11 +66,13 @@ use crate::{raw_sample::RawSample, sample::Sample, service};
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
 use super::details::receiver::*;
-use super::port_identifiers::UniqueSubscriberId;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::ConnectionFailure;
 use super::ReceiveError;
 
 use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Describes the failures when a new [`Subscriber`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
@@ -83,6 +89,8 @@ pub enum SubscriberCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
 }
 
 impl core::fmt::Display for SubscriberCreateError {
@@ -93,10 +101,81 @@ impl core::fmt::Display for SubscriberCreateError {
 
 impl core::error::Error for SubscriberCreateError {}
 
+#[derive(Debug, Clone, Copy)]
+struct SequenceState {
+    publisher_id: u128,
+    last_sequence_number: u64,
+    missed_samples: u64,
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberSharedState<Service: service::Service> {
     pub(crate) receiver: Receiver<Service>,
     pub(crate) publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    sequence_states: UnsafeCell<Vec<Option<SequenceState>>>,
+    sample_filter: Option<SampleFilter<'static>>,
+    filtered_samples: UnsafeCell<u64>,
+}
+
+impl<Service: service::Service> SubscriberSharedState<Service> {
+    fn track_sequence_number(&self, details: &ChunkDetails, sequence_number: u64) {
+        let sequence_states = unsafe { &mut *self.sequence_states.get() };
+        let state = &mut sequence_states[details.connection_key.value()];
+
+        match state {
+            Some(state) if state.publisher_id == details.origin => {
+                if state.last_sequence_number + 1 < sequence_number {
+                    state.missed_samples += sequence_number - state.last_sequence_number - 1;
+                }
+                state.last_sequence_number = sequence_number;
+            }
+            _ => {
+                *state = Some(SequenceState {
+                    publisher_id: details.origin,
+                    last_sequence_number: sequence_number,
+                    missed_samples: 0,
+                })
+            }
+        }
+    }
+
+    fn skip_to_sequence_number(&self, details: &ChunkDetails, sequence_number: u64) {
+        let sequence_states = unsafe { &mut *self.sequence_states.get() };
+        let state = &mut sequence_states[details.connection_key.value()];
+
+        match state {
+            Some(state) if state.publisher_id == details.origin => {
+                state.last_sequence_number = sequence_number;
+            }
+            _ => {
+                *state = Some(SequenceState {
+                    publisher_id: details.origin,
+                    last_sequence_number: sequence_number,
+                    missed_samples: 0,
+                })
+            }
+        }
+    }
+
+    fn missed_samples(&self, publisher_id: u128) -> Option<u64> {
+        let sequence_states = unsafe { &*self.sequence_states.get() };
+        sequence_states
+            .iter()
+            .flatten()
+            .find(|state| state.publisher_id == publisher_id)
+            .map(|state| state.missed_samples)
+    }
+}
+
+tiny_fn! {
+    /// Decides whether a received sample is handed to the user or released right away.
+    pub(crate) struct SampleFilter = Fn(chunk: &Chunk) -> bool | + Send;
+}
+
+impl Debug for SampleFilter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "")
+    }
 }
 
 /// The receiving endpoint of a publish-subscribe communication.
@@ -107,7 +186,9 @@ pub struct Subscriber<
     UserHeader: Debug + ZeroCopySend,
 > {
     dynamic_subscriber_handle: Option<ContainerHandle>,
-    subscriber_shared_state: Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
+    pub(crate) subscriber_shared_state:
+        Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
+    deadline_listener: Option<Listener<Service>>,
 
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -162,6 +243,7 @@ impl<
     pub(crate) fn new(
         service: Arc<ServiceState<Service, NoResource>>,
         static_config: &StaticConfig,
+        deadline_event_service: Option<&event::PortFactory<Service>>,
         config: SubscriberConfig,
     ) -> Result<Self, SubscriberCreateError> {
         let msg = "Failed to create Subscriber port";
@@ -182,6 +264,14 @@ impl<
             None => static_config.subscriber_max_buffer_size,
         };
 
+        let deadline_listener = match deadline_event_service {
+            None => None,
+            Some(event_service) => Some(fail!(from origin,
+                when event_service.listener_builder().create(),
+                with SubscriberCreateError::InternalFailure,
+                "{} since the listener for the subscriber deadline could not be created.", msg)),
+        };
+
         let number_of_to_be_removed_connections = service
             .shared_node
             .config()
@@ -194,6 +284,9 @@ impl<
 
         let subscriber_shared_state = Service::ArcThreadSafetyPolicy::new(SubscriberSharedState {
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
+            sequence_states: UnsafeCell::new(vec![None; number_of_connections]),
+            sample_filter: config.sample_filter,
+            filtered_samples: UnsafeCell::new(0),
             receiver: Receiver {
                 connections: PolymorphicVec::from_fn(
                     HeapAllocator::global(),
@@ -218,6 +311,13 @@ impl<
                 degradation_callback: config.degradation_callback,
                 number_of_channels: 1,
                 connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
+                priority_order: UnsafeCell::new(
+                    PolymorphicVec::new(HeapAllocator::global(), number_of_connections)
+                        .expect("Heap allocator provides memory."),
+                ),
+                receive_order: config.receive_order,
+                round_robin_position: UnsafeCell::new(0),
+                wake_up_state: UnsafeCell::new(WakeUpState::default()),
             },
         });
 
@@ -233,6 +333,7 @@ impl<
         let mut new_self = Self {
             subscriber_shared_state,
             dynamic_subscriber_handle: None,
+            deadline_listener,
             _payload: PhantomData,
             _user_header: PhantomData,
         };
@@ -286,6 +387,7 @@ impl<
                         number_of_samples: details.number_of_samples,
                         max_number_of_segments: details.max_number_of_segments,
                         data_segment_type: details.data_segment_type,
+                        priority: details.priority,
                     },
                 );
 
@@ -329,14 +431,120 @@ impl<
             .has_samples(ChannelId::new(0)))
     }
 
-    fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
-        fail!(from self, when self.update_connections(),
-                "Some samples are not being received since not all connections to publishers could be established.");
+    /// Returns the number of [`Sample`]s of the [`Publisher`](crate::port::publisher::Publisher)
+    /// with the provided [`UniquePublisherId`] that were never received by this [`Subscriber`].
+    /// They are detected by gaps in the [`Header::sequence_number()`] of consecutively received
+    /// [`Sample`]s and are caused, for instance, by an overflow in a service with
+    /// safe overflow enabled or by [`UnableToDeliverStrategy::DiscardSample`](crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy::DiscardSample).
+    ///
+    /// Returns [`None`] when no [`Sample`] of the [`Publisher`](crate::port::publisher::Publisher)
+    /// was received so far or when it is no longer connected.
+    pub fn missed_samples(&self, publisher_id: &UniquePublisherId) -> Option<u64> {
+        self.subscriber_shared_state
+            .lock()
+            .missed_samples(publisher_id.value())
+    }
 
+    /// Returns the number of [`Sample`]s that were received but discarded since they did not
+    /// match the filter that was set with
+    /// [`PortFactorySubscriber::filter()`](crate::service::port_factory::subscriber::PortFactorySubscriber::filter())
+    /// or
+    /// [`PortFactorySubscriber::filter_by_user_header()`](crate::service::port_factory::subscriber::PortFactorySubscriber::filter_by_user_header()).
+    pub fn filtered_samples(&self) -> u64 {
+        unsafe { *self.subscriber_shared_state.lock().filtered_samples.get() }
+    }
+
+    /// Returns the maximum time between two [`Sample`]s defined by the
+    /// [`Service`](crate::service::Service). When it is set, the
+    /// [`Subscriber::deadline_listener()`] can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet) via
+    /// [`WaitSet::attach_deadline()`](crate::waitset::WaitSet::attach_deadline()).
+    pub fn deadline(&self) -> Option<Duration> {
         self.subscriber_shared_state
             .lock()
             .receiver
-            .receive(ChannelId::new(0))
+            .service_state
+            .static_config
+            .publish_subscribe()
+            .subscriber_deadline
+    }
+
+    /// Returns the [`Listener`] that is notified by the
+    /// [`Publisher`](crate::port::publisher::Publisher)s about every delivered [`Sample`]. It
+    /// can be attached to a [`WaitSet`](crate::waitset::WaitSet) via
+    /// [`WaitSet::attach_deadline()`](crate::waitset::WaitSet::attach_deadline()) to detect
+    /// missed deadlines. The notifications are not consumed by [`Subscriber::receive()`] and
+    /// must be consumed with [`Listener::try_wait_all()`] after a wake up. Returns [`None`]
+    /// when the [`Service`](crate::service::Service) has no subscriber deadline.
+    pub fn deadline_listener(&self) -> Option<&Listener<Service>> {
+        self.deadline_listener.as_ref()
+    }
+
+    fn receive_impl(&self, latest: bool) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        fail!(from self, when self.update_connections(),
+                "Some samples are not being received since not all connections to publishers could be established.");
+
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let lifespan = subscriber_shared_state
+            .receiver
+            .service_state
+            .static_config
+            .publish_subscribe()
+            .lifespan;
+
+        let is_expired = |header: &Header| match lifespan {
+            Some(lifespan) => match header.send_timestamp().elapsed() {
+                Ok(age) => lifespan < age,
+                Err(_) => true,
+            },
+            None => false,
+        };
+
+        let is_filtered = |chunk: &Chunk| match &subscriber_shared_state.sample_filter {
+            Some(sample_filter) if !sample_filter.call(chunk) => {
+                unsafe { *subscriber_shared_state.filtered_samples.get() += 1 };
+                true
+            }
+            _ => false,
+        };
+
+        if latest {
+            // samples that are discarded in favor of a newer one were not missed
+            return subscriber_shared_state.receiver.receive_latest(
+                ChannelId::new(0),
+                |details, chunk| {
+                    let header = unsafe { &*(chunk.header as *const Header) };
+                    subscriber_shared_state
+                        .skip_to_sequence_number(details, header.sequence_number());
+
+                    if is_expired(header) || is_filtered(chunk) {
+                        None
+                    } else {
+                        Some(header.send_timestamp().as_duration())
+                    }
+                },
+            );
+        }
+
+        loop {
+            let received = subscriber_shared_state
+                .receiver
+                .receive(ChannelId::new(0))?;
+
+            if let Some((details, chunk)) = &received {
+                let header = unsafe { &*(chunk.header as *const Header) };
+                subscriber_shared_state.track_sequence_number(details, header.sequence_number());
+
+                if is_expired(header) || is_filtered(chunk) {
+                    subscriber_shared_state
+                        .receiver
+                        .release_offset(details, ChannelId::new(0));
+                    continue;
+                }
+            }
+
+            return Ok(received);
+        }
     }
 }
 
@@ -375,7 +583,34 @@ impl<
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
     pub fn receive(&self) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| Sample {
+        Ok(self
+            .receive_impl(false)?
+            .map(|(details, chunk)| self.create_sample(details, chunk)))
+    }
+
+    /// Receives only the newest [`crate::sample::Sample`] by
+    /// [`Header::send_timestamp()`] across all connected
+    /// [`crate::port::publisher::Publisher`]s. All older [`crate::sample::Sample`]s that are
+    /// still in the buffer are discarded and returned to their
+    /// [`crate::port::publisher::Publisher`]s without being mapped. Discarded
+    /// [`crate::sample::Sample`]s are not counted by [`Subscriber::missed_samples()`].
+    /// When a filter or a lifespan is set, the newest [`crate::sample::Sample`] that passes
+    /// them is returned. If no sample could be received [`None`] is returned. If a failure occurs
+    /// [`ReceiveError`] is returned.
+    pub fn receive_latest(
+        &self,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        Ok(self
+            .receive_impl(true)?
+            .map(|(details, chunk)| self.create_sample(details, chunk)))
+    }
+
+    fn create_sample(
+        &self,
+        details: ChunkDetails,
+        chunk: Chunk,
+    ) -> Sample<Service, Payload, UserHeader> {
+        Sample {
             subscriber_shared_state: self.subscriber_shared_state.clone(),
             details,
             ptr: unsafe {
@@ -385,7 +620,7 @@ impl<
                     chunk.payload.cast(),
                 )
             },
-        }))
+        }
     }
 }
 
@@ -400,22 +635,49 @@ impl<
     pub fn receive(&self) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
-        Ok(self.receive_impl()?.map(|(details, chunk)| {
-            let header_ptr = chunk.header as *const Header;
-            let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+        Ok(self
+            .receive_impl(false)?
+            .map(|(details, chunk)| self.create_sample(details, chunk)))
+    }
 
-            Sample {
-                subscriber_shared_state: self.subscriber_shared_state.clone(),
-                details,
-                ptr: unsafe {
-                    RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
-                        header_ptr,
-                        chunk.user_header.cast(),
-                        core::slice::from_raw_parts(chunk.payload.cast(), number_of_elements as _),
-                    )
-                },
-            }
-        }))
+    /// Receives only the newest [`crate::sample::Sample`] by
+    /// [`Header::send_timestamp()`] across all connected
+    /// [`crate::port::publisher::Publisher`]s. All older [`crate::sample::Sample`]s that are
+    /// still in the buffer are discarded and returned to their
+    /// [`crate::port::publisher::Publisher`]s without being mapped. Discarded
+    /// [`crate::sample::Sample`]s are not counted by [`Subscriber::missed_samples()`].
+    /// When a filter or a lifespan is set, the newest [`crate::sample::Sample`] that passes
+    /// them is returned. If no sample could be received [`None`] is returned. If a failure occurs
+    /// [`ReceiveError`] is returned.
+    pub fn receive_latest(
+        &self,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        Ok(self
+            .receive_impl(true)?
+            .map(|(details, chunk)| self.create_sample(details, chunk)))
+    }
+
+    fn create_sample(
+        &self,
+        details: ChunkDetails,
+        chunk: Chunk,
+    ) -> Sample<Service, [Payload], UserHeader> {
+        let header_ptr = chunk.header as *const Header;
+        let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+
+        Sample {
+            subscriber_shared_state: self.subscriber_shared_state.clone(),
+            details,
+            ptr: unsafe {
+                RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
+                    header_ptr,
+                    chunk.user_header.cast(),
+                    core::slice::from_raw_parts(chunk.payload.cast(), number_of_elements as _),
+                )
+            },
+        }
     }
 }
 
@@ -435,7 +697,7 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
     pub unsafe fn receive_custom_payload(
         &self,
     ) -> Result<Option<Sample<Service, [CustomPayloadMarker], UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| {
+        Ok(self.receive_impl(false)?.map(|(details, chunk)| {
             let header_ptr = chunk.header as *const Header;
             let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
             let number_of_bytes = number_of_elements as usize
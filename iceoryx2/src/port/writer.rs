@@ -42,7 +42,9 @@
 
 use crate::constants::MAX_BLACKBOARD_KEY_SIZE;
 use crate::prelude::EventId;
-use crate::service::builder::blackboard::{BlackboardResources, KeyMemory};
+use crate::service::builder::blackboard::{
+    BlackboardResources, EntryInsertError, KeyMemory, SliceCellLayout, NO_ENTRY_OWNER,
+};
 use crate::service::builder::CustomKeyMarker;
 use crate::service::dynamic_config::blackboard::WriterDetails;
 use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
@@ -51,20 +53,23 @@ use core::alloc::Layout;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
+use core::mem::MaybeUninit;
 use core::sync::atomic::Ordering;
 use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::{
-    Producer, UnrestrictedAtomic, UnrestrictedAtomicMgmt,
+    __internal_calculate_atomic_mgmt_and_payload_ptr, Producer, UnrestrictedAtomic,
+    UnrestrictedAtomicMgmt,
 };
-use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::shared_memory::SharedMemory;
 
 extern crate alloc;
 use alloc::sync::Arc;
 
+use super::notifier::Notifier;
 use super::port_identifiers::UniqueWriterId;
 
 #[derive(Debug)]
@@ -74,6 +79,7 @@ struct WriterSharedState<
 > {
     dynamic_writer_handle: Option<ContainerHandle>,
     service_state: Arc<ServiceState<Service, BlackboardResources<Service>>>,
+    ownership_token: u64,
     _key: PhantomData<KeyType>,
 }
 
@@ -93,6 +99,37 @@ impl<
     }
 }
 
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    > WriterSharedState<Service, KeyType>
+{
+    fn create_notifier(&self, msg: &str) -> Result<Option<Notifier<Service>>, EntryHandleMutError> {
+        match &self.service_state.additional_resource.event_service {
+            None => Ok(None),
+            Some(event_service) => match event_service.notifier_builder().create() {
+                Ok(notifier) => Ok(Some(notifier)),
+                Err(e) => {
+                    fail!(from self, with EntryHandleMutError::InternalFailure,
+                        "{} since the notifier of the event service could not be created ({:?}).", msg, e);
+                }
+            },
+        }
+    }
+}
+
+fn notify_update<Service: service::Service>(
+    notifier: &Option<Notifier<Service>>,
+    entry_id: EventId,
+) {
+    if let Some(notifier) = notifier {
+        if let Err(e) = notifier.notify_with_custom_event_id(entry_id) {
+            warn!(from notifier,
+                "Unable to notify the listeners about the update of the entry {:?} ({:?}).", entry_id, e);
+        }
+    }
+}
+
 /// Defines a failure that can occur when a [`Writer`] is created with
 /// [`crate::service::port_factory::writer::PortFactoryWriter`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -136,10 +173,12 @@ impl<
         let msg = "Unable to create Writer port";
 
         let writer_id = UniqueWriterId::new();
+        let ownership_token = service.additional_resource.new_writer_token();
         let mut new_self = Self {
             shared_state: Arc::new(WriterSharedState {
                 service_state: service.clone(),
                 dynamic_writer_handle: None,
+                ownership_token,
                 _key: PhantomData,
             }),
             writer_id,
@@ -150,10 +189,7 @@ impl<
         // !MUST! be the last task otherwise a writer is added to the dynamic config without the
         // creation of all required resources
         let dynamic_writer_handle = match service.dynamic_storage.get().blackboard().add_writer_id(
-            WriterDetails {
-                writer_id,
-                node_id: *service.shared_node.id(),
-            },
+            WriterDetails::new(writer_id, *service.shared_node.id(), ownership_token),
         ) {
             Some(unique_index) => unique_index,
             None => {
@@ -179,7 +215,9 @@ impl<
     }
 
     /// Creates a [`EntryHandleMut`] for direct write access to the value. There can be only one
-    /// [`EntryHandleMut`] per value.
+    /// [`EntryHandleMut`] per value. The [`Writer`] becomes the owner of the entry and
+    /// keeps the ownership until it goes out of scope. If the entry is owned by another
+    /// [`Writer`], [`EntryHandleMutError::EntryOwnedByAnotherWriter`] is returned.
     ///
     /// # Example
     ///
@@ -211,13 +249,15 @@ impl<
             }
         };
 
-        let offset = self.get_entry_offset(
+        let (offset, _) = self.get_entry_offset(
             &key_mem,
             &TypeDetail::new::<ValueType>(TypeVariant::FixedSize),
             msg,
         )?;
 
-        match EntryHandleMut::new(self.shared_state.clone(), offset) {
+        let notifier = self.shared_state.create_notifier(msg)?;
+
+        match EntryHandleMut::new(self.shared_state.clone(), offset, notifier) {
             Ok(handle) => Ok(handle),
             Err(e) => {
                 fail!(from self, with e,
@@ -226,44 +266,137 @@ impl<
         }
     }
 
+    /// Creates a [`SliceEntryHandleMut`] for direct write access to a value that was added with
+    /// [`Creator::add_slice()`](crate::service::builder::blackboard::Creator::add_slice()).
+    /// There can be only one [`SliceEntryHandleMut`] per value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<u8>(1, 16, b"hello")
+    /// #     .create()?;
+    /// #
+    /// # let writer = service.writer_builder().create()?;
+    /// let entry_handle_mut = writer.entry_slice::<u8>(&1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn entry_slice<ValueType: ZeroCopySend + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<SliceEntryHandleMut<Service, KeyType, ValueType>, EntryHandleMutError> {
+        let msg = "Unable to create slice entry handle";
+
+        // create KeyMemory from key
+        let key_mem = match KeyMemory::try_from(key) {
+            Ok(mem) => mem,
+            Err(_) => {
+                fatal_panic!(from self, "This should never happen! Key with invalid layout passed.");
+            }
+        };
+
+        let (offset, max_slice_len) = self.get_entry_offset(
+            &key_mem,
+            &TypeDetail::new::<ValueType>(TypeVariant::Dynamic),
+            msg,
+        )?;
+
+        let notifier = self.shared_state.create_notifier(msg)?;
+
+        match SliceEntryHandleMut::new(
+            self.shared_state.clone(),
+            offset,
+            max_slice_len as usize,
+            notifier,
+        ) {
+            Ok(handle) => Ok(handle),
+            Err(e) => {
+                fail!(from self, with e,
+                    "{} since a handle for the passed key and value type already exists.", msg);
+            }
+        }
+    }
+
+    /// Adds a new key-value pair to the blackboard. This requires that memory for additional
+    /// key-value pairs was reserved with
+    /// [`Creator::reserve_keys()`](crate::service::builder::blackboard::Creator::reserve_keys()).
+    /// As soon as the call returns, the new entry can be accessed by all
+    /// [`Writer`]s and [`Reader`](crate::port::reader::Reader)s of the blackboard.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .reserve_keys(4, 8)
+    /// #     .create()?;
+    /// #
+    /// # let writer = service.writer_builder().create()?;
+    /// writer.add::<u64>(2, 1234)?;
+    /// let entry_handle_mut = writer.entry::<u64>(&2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add<ValueType: ZeroCopySend + Copy + 'static>(
+        &self,
+        key: KeyType,
+        value: ValueType,
+    ) -> Result<(), EntryInsertError> {
+        let msg = "Unable to add the key-value pair";
+
+        // create KeyMemory from key
+        let key_mem = match KeyMemory::try_from(&key) {
+            Ok(mem) => mem,
+            Err(_) => {
+                fatal_panic!(from self, "This should never happen! Key with invalid layout passed.");
+            }
+        };
+
+        let mut value_writer = |mem: *mut u8| unsafe {
+            (mem as *mut UnrestrictedAtomic<ValueType>).write(UnrestrictedAtomic::new(value))
+        };
+
+        fail!(from self, when self.shared_state.service_state.additional_resource.insert_entry(
+                key_mem,
+                TypeDetail::new::<ValueType>(TypeVariant::FixedSize),
+                1,
+                Layout::new::<UnrestrictedAtomic<ValueType>>(),
+                &mut value_writer,
+            ),
+            "{} since the entry could not be inserted.", msg);
+
+        Ok(())
+    }
+
     fn get_entry_offset(
         &self,
         key_mem: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
         value_type_details: &TypeDetail,
         msg: &str,
-    ) -> Result<u64, EntryHandleMutError> {
+    ) -> Result<(u64, u64), EntryHandleMutError> {
         // check if key exists
-        let index = match unsafe {
-            self.shared_state
-                .service_state
-                .additional_resource
-                .mgmt
-                .get()
-                .map
-                .__internal_get(
-                    key_mem,
-                    self.shared_state
-                        .service_state
-                        .additional_resource
-                        .key_eq_func
-                        .as_ref(),
-                )
-        } {
-            Some(i) => i,
+        let entry = match self
+            .shared_state
+            .service_state
+            .additional_resource
+            .get_entry(key_mem)
+        {
+            Some(entry) => entry,
             None => {
                 fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
                 "{} since no entry with the given key exists.", msg);
             }
         };
 
-        let entry = &self
-            .shared_state
-            .service_state
-            .additional_resource
-            .mgmt
-            .get()
-            .entries[index];
-
         // check if ValueType matches
         if *value_type_details != entry.type_details {
             fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
@@ -272,7 +405,48 @@ impl<
 
         let offset = entry.offset.load(core::sync::atomic::Ordering::Relaxed);
 
-        Ok(offset)
+        // claim the ownership of the entry
+        let service_state = &self.shared_state.service_state;
+        let ownership_token = self.shared_state.ownership_token;
+        let mut current_owner = entry.owner.load(Ordering::Acquire);
+        while current_owner != ownership_token {
+            if current_owner != NO_ENTRY_OWNER
+                && service_state
+                    .dynamic_storage
+                    .get()
+                    .blackboard()
+                    .has_writer_with_ownership_token(current_owner)
+            {
+                fail!(from self, with EntryHandleMutError::EntryOwnedByAnotherWriter,
+                    "{} since the entry is owned by another writer.", msg);
+            }
+
+            match entry.owner.compare_exchange(
+                current_owner,
+                ownership_token,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    if current_owner != NO_ENTRY_OWNER {
+                        // The previous owner is gone but may have died while holding an entry
+                        // handle, therefore the producer is released.
+                        let atomic_mgmt_ptr = (service_state
+                            .additional_resource
+                            .data
+                            .payload_start_address()
+                            as u64
+                            + offset)
+                            as *const UnrestrictedAtomicMgmt;
+                        unsafe { (*atomic_mgmt_ptr).__internal_release_producer() };
+                    }
+                    break;
+                }
+                Err(owner) => current_owner = owner,
+            }
+        }
+
+        Ok((offset, entry.max_slice_len))
     }
 }
 
@@ -283,6 +457,10 @@ pub enum EntryHandleMutError {
     EntryDoesNotExist,
     /// The [`EntryHandleMut`] already exists.
     HandleAlreadyExists,
+    /// The entry is owned by another [`Writer`].
+    EntryOwnedByAnotherWriter,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
 }
 
 impl core::fmt::Display for EntryHandleMutError {
@@ -301,11 +479,13 @@ pub struct EntryHandleMut<
 > {
     producer: Producer<'static, ValueType>,
     entry_id: EventId,
+    notifier: Option<Notifier<Service>>,
     _shared_state: Arc<WriterSharedState<Service, KeyType>>,
 }
 
-// Safe since the producer implements Send + Sync and shared_state ensures the lifetime of the
-// producer (struct fields are dropped in the same order as declared)
+// Safe since the producer implements Send + Sync, the notifier is exclusively owned by the handle
+// and shared_state ensures the lifetime of the producer (struct fields are dropped in the same
+// order as declared)
 unsafe impl<
         Service: service::Service,
         KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
@@ -330,6 +510,7 @@ impl<
     fn new(
         writer_state: Arc<WriterSharedState<Service, KeyType>>,
         offset: u64,
+        notifier: Option<Notifier<Service>>,
     ) -> Result<Self, EntryHandleMutError> {
         let atomic = (writer_state
             .service_state
@@ -348,6 +529,7 @@ impl<
                     producer: p,
                     _shared_state: writer_state.clone(),
                     entry_id: EventId::new(offset as _),
+                    notifier,
                 })
             }
         }
@@ -374,6 +556,7 @@ impl<
     /// ```
     pub fn update_with_copy(&self, value: ValueType) {
         self.producer.store(value);
+        notify_update(&self.notifier, self.entry_id);
     }
 
     /// Consumes the [`EntryHandleMut`] and loans an uninitialized entry value that can be used to update without copy.
@@ -569,6 +752,10 @@ impl<
                 .producer
                 .__internal_update_write_cell()
         };
+        notify_update(
+            &self.entry_handle_mut.notifier,
+            self.entry_handle_mut.entry_id,
+        );
         self.entry_handle_mut
     }
 
@@ -598,11 +785,379 @@ impl<
     }
 }
 
+/// Defines a failure that can occur when a slice is loaned with
+/// [`SliceEntryHandleMut::loan_slice_uninit()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EntryValueLoanError {
+    /// The requested slice length exceeds the maximum slice length of the entry.
+    ExceedsMaxSliceLen,
+}
+
+impl core::fmt::Display for EntryValueLoanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EntryValueLoanError::{self:?}")
+    }
+}
+
+impl core::error::Error for EntryValueLoanError {}
+
+/// A handle for direct write access to a specific blackboard value that stores a slice.
+pub struct SliceEntryHandleMut<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> {
+    atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
+    data_ptr: *mut u8,
+    cell: SliceCellLayout,
+    max_slice_len: usize,
+    entry_id: EventId,
+    notifier: Option<Notifier<Service>>,
+    _shared_state: Arc<WriterSharedState<Service, KeyType>>,
+    _value: PhantomData<ValueType>,
+}
+
+// Safe since the pointers to the UnrestrictedAtomicMgmt and its data do not change, the notifier
+// is exclusively owned by the handle and shared_state ensures the lifetime of the underlying
+// memory
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > Send for SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+}
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > Sync for SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > Drop for SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn drop(&mut self) {
+        unsafe { (*self.atomic_mgmt_ptr).__internal_release_producer() };
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn new(
+        writer_state: Arc<WriterSharedState<Service, KeyType>>,
+        offset: u64,
+        max_slice_len: usize,
+        notifier: Option<Notifier<Service>>,
+    ) -> Result<Self, EntryHandleMutError> {
+        let cell = SliceCellLayout::new::<ValueType>(max_slice_len);
+        let atomic_mgmt_ptr = (writer_state
+            .service_state
+            .additional_resource
+            .data
+            .payload_start_address() as u64
+            + offset) as *const UnrestrictedAtomicMgmt;
+        let data_ptr = atomic_mgmt_ptr as usize + core::mem::size_of::<UnrestrictedAtomicMgmt>();
+        let data_ptr = align(data_ptr, cell.alignment);
+
+        match unsafe { (*atomic_mgmt_ptr).__internal_acquire_producer() } {
+            Ok(_) => Ok(Self {
+                atomic_mgmt_ptr,
+                data_ptr: data_ptr as *mut u8,
+                cell,
+                max_slice_len,
+                entry_id: EventId::new(offset as _),
+                notifier,
+                _shared_state: writer_state,
+                _value: PhantomData,
+            }),
+            Err(_) => Err(EntryHandleMutError::HandleAlreadyExists),
+        }
+    }
+
+    /// Returns the maximum number of elements the slice can hold.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Loans an uninitialized slice with `slice_len` elements that can be used to update the
+    /// value without copy. Dropping the [`SliceEntryValueUninit`] discards the update.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<u8>(1, 16, b"hello")
+    /// #     .create()?;
+    ///
+    /// # let writer = service.writer_builder().create()?;
+    /// let mut entry_handle_mut = writer.entry_slice::<u8>(&1)?;
+    /// let entry_value_uninit = entry_handle_mut.loan_slice_uninit(5)?;
+    /// let entry_value = entry_value_uninit.write_from_slice(b"world");
+    /// entry_value.update();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_slice_uninit(
+        &mut self,
+        slice_len: usize,
+    ) -> Result<SliceEntryValueUninit<'_, Service, KeyType, ValueType>, EntryValueLoanError> {
+        if slice_len > self.max_slice_len {
+            fail!(from self, with EntryValueLoanError::ExceedsMaxSliceLen,
+                "Unable to loan a slice with {} elements since the entry supports at most {} elements.",
+                slice_len, self.max_slice_len);
+        }
+
+        let write_cell_ptr = unsafe {
+            (*self.atomic_mgmt_ptr).__internal_get_ptr_to_write_cell(
+                self.cell.size,
+                self.cell.alignment,
+                self.data_ptr,
+            )
+        };
+        unsafe { (write_cell_ptr as *mut u64).write(slice_len as u64) };
+        let elements =
+            unsafe { write_cell_ptr.add(self.cell.elements_offset) } as *mut MaybeUninit<ValueType>;
+
+        Ok(SliceEntryValueUninit {
+            elements,
+            slice_len,
+            entry_handle_mut: self,
+        })
+    }
+
+    /// Returns an ID corresponding to the entry which can be used in an event based communication
+    /// setup.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > Debug for SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SliceEntryHandleMut<{}> {{ max_slice_len: {}, entry_id: {:?} }}",
+            core::any::type_name::<ValueType>(),
+            self.max_slice_len,
+            self.entry_id
+        )
+    }
+}
+
+/// Wrapper around an uninitialized slice entry value that can be used for a zero-copy update.
+pub struct SliceEntryValueUninit<
+    'a,
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> {
+    elements: *mut MaybeUninit<ValueType>,
+    slice_len: usize,
+    entry_handle_mut: &'a mut SliceEntryHandleMut<Service, KeyType, ValueType>,
+}
+
+impl<
+        'a,
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > SliceEntryValueUninit<'a, Service, KeyType, ValueType>
+{
+    /// Returns a mutable reference to the uninitialized slice.
+    pub fn payload_mut(&mut self) -> &mut [MaybeUninit<ValueType>] {
+        unsafe { core::slice::from_raw_parts_mut(self.elements, self.slice_len) }
+    }
+
+    /// Consumes the [`SliceEntryValueUninit`], initializes every element with the return value
+    /// of `initializer` and returns the initialized [`SliceEntryValue`].
+    pub fn write_from_fn<F: FnMut(usize) -> ValueType>(
+        mut self,
+        mut initializer: F,
+    ) -> SliceEntryValue<'a, Service, KeyType, ValueType> {
+        for (i, element) in self.payload_mut().iter_mut().enumerate() {
+            element.write(initializer(i));
+        }
+
+        // SAFETY: every element was initialized above
+        unsafe { self.assume_init() }
+    }
+
+    /// Consumes the [`SliceEntryValueUninit`] and returns the [`SliceEntryValue`].
+    ///
+    /// # Safety
+    ///
+    ///   * every element of the slice must be initialized
+    pub unsafe fn assume_init(self) -> SliceEntryValue<'a, Service, KeyType, ValueType> {
+        SliceEntryValue {
+            elements: self.elements as *mut ValueType,
+            slice_len: self.slice_len,
+            entry_handle_mut: self.entry_handle_mut,
+        }
+    }
+}
+
+impl<
+        'a,
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + Copy + 'static,
+    > SliceEntryValueUninit<'a, Service, KeyType, ValueType>
+{
+    /// Consumes the [`SliceEntryValueUninit`], copies `value` into the slice and returns the
+    /// initialized [`SliceEntryValue`].
+    ///
+    /// # Panics
+    ///
+    ///   * when the length of `value` differs from the loaned slice length
+    pub fn write_from_slice(
+        mut self,
+        value: &[ValueType],
+    ) -> SliceEntryValue<'a, Service, KeyType, ValueType> {
+        self.payload_mut().copy_from_slice(unsafe {
+            core::slice::from_raw_parts(
+                value.as_ptr() as *const MaybeUninit<ValueType>,
+                value.len(),
+            )
+        });
+
+        // SAFETY: every element was initialized by the copy above
+        unsafe { self.assume_init() }
+    }
+}
+
+/// Wrapper around an initialized slice entry value that can be used for a zero-copy update.
+/// Dropping the [`SliceEntryValue`] discards the update.
+pub struct SliceEntryValue<
+    'a,
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> {
+    elements: *mut ValueType,
+    slice_len: usize,
+    entry_handle_mut: &'a mut SliceEntryHandleMut<Service, KeyType, ValueType>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: ZeroCopySend + 'static,
+    > SliceEntryValue<'_, Service, KeyType, ValueType>
+{
+    /// Returns a reference to the slice.
+    pub fn payload(&self) -> &[ValueType] {
+        unsafe { core::slice::from_raw_parts(self.elements, self.slice_len) }
+    }
+
+    /// Returns a mutable reference to the slice.
+    pub fn payload_mut(&mut self) -> &mut [ValueType] {
+        unsafe { core::slice::from_raw_parts_mut(self.elements, self.slice_len) }
+    }
+
+    /// Makes the new value readable for [`Reader`](crate::port::reader::Reader)s and consumes
+    /// the [`SliceEntryValue`].
+    pub fn update(self) {
+        unsafe { (*self.entry_handle_mut.atomic_mgmt_ptr).__internal_update_write_cell() };
+        notify_update(
+            &self.entry_handle_mut.notifier,
+            self.entry_handle_mut.entry_id,
+        );
+    }
+}
+
 impl<Service: service::Service> Writer<Service, CustomKeyMarker> {
     #[doc(hidden)]
     /// # Safety
     ///
     ///   * key must be a valid pointer to a value of the set key type
+    ///   * value must be a valid pointer to a value described by `value_type_details`
+    ///   * `value_type_details` must have the [`TypeVariant::FixedSize`]
+    pub unsafe fn __internal_add(
+        &self,
+        key: *const u8,
+        value: *const u8,
+        value_type_details: &TypeDetail,
+    ) -> Result<(), EntryInsertError> {
+        let msg = "Unable to add the key-value pair";
+
+        if value_type_details.variant == TypeVariant::Dynamic {
+            fatal_panic!(from self, "{} since slice values are not supported.", msg);
+        }
+
+        let key_type_details = self
+            .shared_state
+            .service_state
+            .static_config
+            .blackboard()
+            .type_details();
+        let key_layout = unsafe {
+            Layout::from_size_align_unchecked(key_type_details.size, key_type_details.alignment)
+        };
+
+        // create KeyMemory from key ptr
+        let key_mem = unsafe {
+            match KeyMemory::try_from_ptr(key, key_layout) {
+                Ok(mem) => mem,
+                Err(_) => {
+                    fatal_panic!(from self, "This should never happen! Key with invalid layout set.");
+                }
+            }
+        };
+
+        let value_layout = unsafe {
+            Layout::from_size_align_unchecked(
+                UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_size(
+                    value_type_details.size,
+                    value_type_details.alignment,
+                ),
+                UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_alignment(
+                    value_type_details.alignment,
+                ),
+            )
+        };
+
+        let mut value_writer = |mem: *mut u8| unsafe {
+            let ptrs =
+                __internal_calculate_atomic_mgmt_and_payload_ptr(mem, value_type_details.alignment);
+            core::ptr::copy_nonoverlapping(value, ptrs.atomic_payload_ptr, value_type_details.size);
+        };
+
+        fail!(from self, when self.shared_state.service_state.additional_resource.insert_entry(
+                key_mem,
+                value_type_details.clone(),
+                1,
+                value_layout,
+                &mut value_writer,
+            ),
+            "{} since the entry could not be inserted.", msg);
+
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Slice entries, whose value type has the [`TypeVariant::Dynamic`], are not supported.
+    ///
+    /// # Safety
+    ///
+    ///   * key must be a valid pointer to a value of the set key type
     pub unsafe fn __internal_entry(
         &self,
         key: *const u8,
@@ -610,6 +1165,11 @@ impl<Service: service::Service> Writer<Service, CustomKeyMarker> {
     ) -> Result<__InternalEntryHandleMut<Service>, EntryHandleMutError> {
         let msg = "Unable to create entry handle";
 
+        if value_type_details.variant == TypeVariant::Dynamic {
+            fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                "{} since slice entries are not supported by the internal entry handle.", msg);
+        }
+
         let key_type_details = self
             .shared_state
             .service_state
@@ -630,7 +1190,7 @@ impl<Service: service::Service> Writer<Service, CustomKeyMarker> {
             }
         };
 
-        let offset = self.get_entry_offset(&key_mem, value_type_details, msg)?;
+        let (offset, _) = self.get_entry_offset(&key_mem, value_type_details, msg)?;
 
         let atomic_mgmt_ptr = (self
             .shared_state
@@ -643,10 +1203,13 @@ impl<Service: service::Service> Writer<Service, CustomKeyMarker> {
         let data_ptr = atomic_mgmt_ptr as usize + core::mem::size_of::<UnrestrictedAtomicMgmt>();
         let data_ptr = align(data_ptr, value_type_details.alignment);
 
+        let notifier = self.shared_state.create_notifier(msg)?;
+
         match __InternalEntryHandleMut::new(
             atomic_mgmt_ptr,
             data_ptr as *mut u8,
             EventId::new(offset as _),
+            notifier,
             self.shared_state.clone(),
         ) {
             Ok(handle) => Ok(handle),
@@ -665,6 +1228,7 @@ pub struct __InternalEntryHandleMut<Service: service::Service> {
     atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
     data_ptr: *mut u8,
     entry_id: EventId,
+    notifier: Option<Notifier<Service>>,
     _shared_state: Arc<WriterSharedState<Service, CustomKeyMarker>>,
 }
 
@@ -679,6 +1243,7 @@ impl<Service: service::Service> __InternalEntryHandleMut<Service> {
         atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
         data_ptr: *mut u8,
         entry_id: EventId,
+        notifier: Option<Notifier<Service>>,
         writer_state: Arc<WriterSharedState<Service, CustomKeyMarker>>,
     ) -> Result<Self, EntryHandleMutError> {
         match unsafe { (*atomic_mgmt_ptr).__internal_acquire_producer() } {
@@ -686,6 +1251,7 @@ impl<Service: service::Service> __InternalEntryHandleMut<Service> {
                 atomic_mgmt_ptr,
                 data_ptr,
                 entry_id,
+                notifier,
                 _shared_state: writer_state.clone(),
             }),
             Err(_) => Err(EntryHandleMutError::HandleAlreadyExists),
@@ -737,6 +1303,7 @@ impl<Service: service::Service> __InternalEntryHandleMut<Service> {
     ///   __internal_get_ptr_to_write_cell
     pub unsafe fn __internal_update_write_cell(&self) {
         unsafe { (*self.atomic_mgmt_ptr).__internal_update_write_cell() };
+        notify_update(&self.notifier, self.entry_id);
     }
 }
 
@@ -779,6 +1346,10 @@ impl<Service: service::Service> __InternalEntryValueUninit<Service> {
         unsafe {
             (*self.entry_handle_mut.atomic_mgmt_ptr).__internal_update_write_cell();
         }
+        notify_update(
+            &self.entry_handle_mut.notifier,
+            self.entry_handle_mut.entry_id,
+        );
         self.entry_handle_mut
     }
 
@@ -12,12 +12,15 @@
 
 pub use crate::config::Config;
 pub use crate::node::{node_name::NodeName, Node, NodeBuilder, NodeState};
-pub use crate::port::{event_id::EventId, unable_to_deliver_strategy::UnableToDeliverStrategy};
+pub use crate::port::{
+    event_id::EventId, notification_payload::NotificationPayload, receive_order::ReceiveOrder,
+    unable_to_deliver_strategy::UnableToDeliverStrategy,
+};
 pub use crate::service::messaging_pattern::MessagingPattern;
 pub use crate::service::{
     attribute::AttributeSet, attribute::AttributeSpecifier, attribute::AttributeVerifier, ipc,
-    ipc_threadsafe, local, local_threadsafe, port_factory::PortFactory, service_name::ServiceName,
-    Service, ServiceDetails,
+    ipc_huge_page, ipc_threadsafe, local, local_threadsafe, port_factory::PortFactory,
+    service_name::ServiceName, Service, ServiceDetails,
 };
 pub use crate::signal_handling_mode::SignalHandlingMode;
 pub use crate::waitset::{WaitSet, WaitSetAttachmentId, WaitSetBuilder, WaitSetGuard};
@@ -35,8 +38,10 @@ pub use iceoryx2_bb_log::set_log_level_from_env_or;
 #[cfg(feature = "std")]
 pub use iceoryx2_bb_log::set_log_level_from_env_or_default;
 pub use iceoryx2_bb_log::LogLevel;
+pub use iceoryx2_bb_posix::cpu_set::CpuSet;
 pub use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
 pub use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 pub use iceoryx2_bb_posix::process::ProcessId;
 pub use iceoryx2_bb_system_types::{file_name::FileName, file_path::FilePath, path::Path};
+pub use iceoryx2_cal::shared_memory::{MemoryLockPolicy, NumaPlacement};
 pub use iceoryx2_cal::shm_allocator::AllocationStrategy;
@@ -35,18 +35,19 @@
 //! # }
 //! ```
 
-use core::{fmt::Debug, marker::PhantomData};
+use core::{cell::UnsafeCell, fmt::Debug, marker::PhantomData, time::Duration};
 use core::{
     ops::{Deref, DerefMut},
     sync::atomic::Ordering,
 };
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
-use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::{fatal_panic, warn};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
 
 use iceoryx2_cal::shm_allocator::PointerOffset;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
 use crate::{
     pending_response::PendingResponse,
@@ -210,23 +211,53 @@ impl<
         self.ptr.as_payload_mut()
     }
 
+    /// Defines the maximum time the [`Client`](crate::port::client::Client) waits for the
+    /// [`Response`](crate::response::Response)s. The deadline starts when the [`RequestMut`]
+    /// is sent and is stored in the
+    /// [`RequestHeader`](service::header::request_response::RequestHeader) so that the
+    /// [`Server`](crate::port::server::Server) can stop processing it when it expires.
+    /// When the deadline has passed, the
+    /// [`PendingResponse`] fails with [`ReceiveError::RequestTimedOut`](crate::port::ReceiveError::RequestTimedOut).
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.ptr.as_header_mut().set_timeout(timeout);
+    }
+
+    /// Enables credit based flow control for the [`Response`](crate::response::Response)s of
+    /// this [`RequestMut`]. Every [`Server`](crate::port::server::Server) may loan at most
+    /// `credits` [`ResponseMut`](crate::response_mut::ResponseMut)s until the
+    /// [`Client`](crate::port::client::Client) grants further credits with
+    /// [`PendingResponse::grant_credits()`]. When a [`Server`](crate::port::server::Server) runs
+    /// out of credits its loan blocks when it uses [`UnableToDeliverStrategy::Block`](crate::prelude::UnableToDeliverStrategy::Block),
+    /// otherwise it fails with [`LoanError::ExceedsGrantedCredits`](crate::port::LoanError::ExceedsGrantedCredits).
+    pub fn set_response_credits(&mut self, credits: u64) {
+        self.ptr.as_header_mut().set_response_credits(credits);
+    }
+
     /// Sends the [`RequestMut`] to all connected
     /// [`Server`](crate::port::server::Server)s of the
     /// [`Service`](crate::service::Service).
     pub fn send(
-        self,
+        mut self,
     ) -> Result<
         PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
         RequestSendError,
     > {
+        match Time::now() {
+            Ok(send_timestamp) => self.ptr.as_header_mut().set_send_timestamp(send_timestamp),
+            Err(e) => {
+                warn!(from self, "Unable to acquire the send timestamp ({:?}), the request is sent without a deadline.", e);
+            }
+        }
+
         let client_shared_state = self.client_shared_state.lock();
         match client_shared_state.send_request(
             self.offset_to_chunk,
             self.sample_size,
             self.channel_id,
             self.header().request_id,
+            self.header().initial_response_credits(),
         ) {
-            Ok(number_of_server_connections) => {
+            Ok((number_of_server_connections, dispatch_target)) => {
                 self.was_sample_sent.store(true, Ordering::Relaxed);
                 client_shared_state
                     .request_sender
@@ -235,6 +266,9 @@ impl<
                 drop(client_shared_state);
                 let active_request = PendingResponse {
                     number_of_server_connections,
+                    dispatch_target: UnsafeCell::new(dispatch_target),
+                    has_received_response: IoxAtomicBool::new(false),
+                    number_of_final_responses: IoxAtomicUsize::new(0),
                     request: self,
                     _service: PhantomData,
                     _response_payload: PhantomData,
@@ -40,7 +40,7 @@
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 
 use crate::{port::client::ClientSharedState, request_mut::RequestMut, service};
-use core::{fmt::Debug, mem::MaybeUninit};
+use core::{fmt::Debug, mem::MaybeUninit, time::Duration};
 
 /// A version of the [`RequestMut`] where the payload is not initialized which allows
 /// true zero copy usage. To send a [`RequestMutUninit`] it must be first initialized
@@ -117,6 +117,18 @@ impl<
     pub fn payload_mut(&mut self) -> &mut RequestPayload {
         self.request.payload_mut()
     }
+
+    /// Defines the maximum time the [`Client`](crate::port::client::Client) waits for the
+    /// [`Response`](crate::response::Response)s. See [`RequestMut::set_timeout()`].
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.request.set_timeout(timeout)
+    }
+
+    /// Enables credit based flow control for the [`Response`](crate::response::Response)s.
+    /// See [`RequestMut::set_response_credits()`].
+    pub fn set_response_credits(&mut self, credits: u64) {
+        self.request.set_response_credits(credits)
+    }
 }
 
 impl<
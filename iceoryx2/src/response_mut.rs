@@ -47,21 +47,20 @@ use core::{
     sync::atomic::Ordering,
 };
 
-use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
-use iceoryx2_bb_log::fail;
-use iceoryx2_cal::{
-    arc_sync_policy::ArcSyncPolicy, shm_allocator::PointerOffset, zero_copy_connection::ChannelId,
-};
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
-
 use crate::{
+    active_request::ResponseStreamState,
     port::{
         server::{SharedServerState, INVALID_CONNECTION_ID},
-        SendError,
+        LoanError, SendError,
     },
     raw_sample::RawSampleMut,
     service,
 };
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::{
+    arc_sync_policy::ArcSyncPolicy, shm_allocator::PointerOffset, zero_copy_connection::ChannelId,
+};
 
 /// Acquired by a [`ActiveRequest`](crate::active_request::ActiveRequest) with
 ///  * [`ActiveRequest::loan()`](crate::active_request::ActiveRequest::loan())
@@ -83,11 +82,12 @@ pub struct ResponseMut<
         ResponsePayload,
     >,
     pub(crate) shared_state: Service::ArcThreadSafetyPolicy<SharedServerState<Service>>,
-    pub(crate) shared_loan_counter: Arc<IoxAtomicUsize>,
+    pub(crate) response_stream: Arc<ResponseStreamState>,
     pub(crate) offset_to_chunk: PointerOffset,
     pub(crate) sample_size: usize,
     pub(crate) channel_id: ChannelId,
     pub(crate) connection_id: usize,
+    pub(crate) holds_credit: bool,
     pub(crate) _response_payload: PhantomData<ResponsePayload>,
     pub(crate) _response_header: PhantomData<ResponseHeader>,
 }
@@ -130,11 +130,20 @@ impl<
     > Drop for ResponseMut<Service, ResponsePayload, ResponseHeader>
 {
     fn drop(&mut self) {
-        self.shared_state
-            .lock()
+        let shared_state = self.shared_state.lock();
+        shared_state
             .response_sender
             .return_loaned_sample(self.offset_to_chunk);
-        self.shared_loan_counter.fetch_sub(1, Ordering::Relaxed);
+
+        // a response that was never sent does not consume the credit the client granted
+        if self.holds_credit && self.connection_id != INVALID_CONNECTION_ID {
+            shared_state
+                .response_sender
+                .return_credit(self.channel_id, self.connection_id);
+        }
+        self.response_stream
+            .loan_counter
+            .fetch_sub(1, Ordering::Relaxed);
     }
 }
 
@@ -330,7 +339,16 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<(), SendError> {
+    pub fn send(mut self) -> Result<(), SendError> {
+        if self.response_stream.is_finished.load(Ordering::Relaxed) {
+            fail!(from self, with SendError::LoanError(LoanError::ResponseStreamFinished),
+                "Unable to send response since the final response was already sent.");
+        }
+
+        self.deliver()
+    }
+
+    fn deliver(&mut self) -> Result<(), SendError> {
         let msg = "Unable to send response";
 
         let shared_state = self.shared_state.lock();
@@ -343,9 +361,68 @@ impl<
                 self.sample_size,
                 self.channel_id,
                 self.connection_id,
+                |_| {},
             )?;
         }
+        drop(shared_state);
 
+        self.holds_credit = false;
         Ok(())
     }
+
+    /// Sends the [`ResponseMut`] as the last
+    /// [`Response`](crate::response::Response) of the stream to the corresponding
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) of the
+    /// [`Client`](crate::port::client::Client). The
+    /// [`Client`](crate::port::client::Client) can identify it with
+    /// [`ResponseHeader::is_final()`](service::header::request_response::ResponseHeader::is_final())
+    /// and [`PendingResponse::is_finished()`](crate::pending_response::PendingResponse::is_finished())
+    /// returns [`true`] as soon as all [`Server`](crate::port::server::Server)s have sent their
+    /// final [`Response`](crate::response::Response). Afterwards, every further loan or send of
+    /// the [`ActiveRequest`](crate::active_request::ActiveRequest) fails with
+    /// [`LoanError::ResponseStreamFinished`].
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"ResponseMutExample7".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    /// # let server = service.server_builder().create()?;
+    /// # let pending_response = client.send_copy(0)?;
+    /// # let active_request = server.receive()?.unwrap();
+    ///
+    /// active_request.send_copy(123)?;
+    ///
+    /// let mut response = active_request.loan()?;
+    /// *response.payload_mut() = 456;
+    /// response.send_final()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_final(mut self) -> Result<(), SendError> {
+        if self
+            .response_stream
+            .is_finished
+            .swap(true, Ordering::Relaxed)
+        {
+            fail!(from self, with SendError::LoanError(LoanError::ResponseStreamFinished),
+                "Unable to send the final response since the final response was already sent.");
+        }
+
+        self.ptr.as_header_mut().is_final = true;
+        let result = self.deliver();
+        if result.is_err() {
+            self.response_stream
+                .is_finished
+                .store(false, Ordering::Relaxed);
+        }
+
+        result
+    }
 }
@@ -0,0 +1,420 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Typed remote procedure calls on top of the
+//! [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
+//!
+//! A trait annotated with [`rpc_service`](crate::rpc::rpc_service) describes the interface of a
+//! service. Every method must take `&self` or `&mut self` and its arguments and return value
+//! must implement [`ZeroCopySend`](iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend)
+//! and [`Clone`]. For a trait `Calculator` the macro generates
+//!
+//!  * `CalculatorRequest` - an enum with one variant per method that carries its arguments,
+//!  * `CalculatorResponse` - an enum with one variant per method that carries its return value,
+//!  * `CalculatorService` - the interface definition that implements
+//!    [`RpcInterface`](crate::rpc::RpcInterface) and therefore
+//!    [`RpcService`](crate::rpc::RpcService),
+//!  * `CalculatorClient` - a typed client stub with one method per trait method. Created with
+//!    an [`RpcClient`](crate::rpc::RpcClient) every method returns a
+//!    [`PendingCall`](crate::rpc::PendingCall),
+//!  * `CalculatorDispatcher` - a server dispatcher trait, implemented for the
+//!    [`RpcServer`](crate::rpc::RpcServer), that receives all requests, calls the corresponding
+//!    method of a `Calculator` implementation and sends its return value back.
+//!
+//! Every request carries the id of the called method in its
+//! [`RpcRequestHeader`](crate::rpc::RpcRequestHeader). The method ids are assigned in
+//! declaration order. Whenever the interface changes, the `version` of the
+//! [`rpc_service`](crate::rpc::rpc_service) must be increased. Interface name and version are
+//! stored as service attributes so that opening a service with a different version fails with
+//! [`RequestResponseOpenError::IncompatibleAttributes`](crate::service::builder::request_response::RequestResponseOpenError::IncompatibleAttributes).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::rpc::{rpc_service, RpcService};
+//!
+//! #[rpc_service(version = 1)]
+//! pub trait Calculator {
+//!     fn add(&mut self, lhs: u64, rhs: u64) -> u64;
+//!     fn reset(&mut self);
+//! }
+//!
+//! #[derive(Default)]
+//! struct MyCalculator {
+//!     number_of_calls: usize,
+//! }
+//!
+//! impl Calculator for MyCalculator {
+//!     fn add(&mut self, lhs: u64, rhs: u64) -> u64 {
+//!         self.number_of_calls += 1;
+//!         lhs + rhs
+//!     }
+//!
+//!     fn reset(&mut self) {
+//!         self.number_of_calls = 0;
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = CalculatorService::open_or_create(&node, &"My/Calculator".try_into()?)?;
+//!
+//! let server = service.server_builder().create()?;
+//! let client = CalculatorClient::new(service.client_builder().create()?);
+//!
+//! let pending_call = client.add(40, 2)?;
+//!
+//! let mut calculator = MyCalculator::default();
+//! server.dispatch(&mut calculator)?;
+//!
+//! if let Some(sum) = pending_call.receive()? {
+//!     assert_eq!(*sum, 42);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::ops::Deref;
+
+use alloc::format;
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, warn};
+
+use crate::node::Node;
+use crate::pending_response::PendingResponse;
+use crate::port::client::{Client, RequestSendError};
+use crate::port::server::Server;
+use crate::port::{ReceiveError, SendError};
+use crate::response::Response;
+use crate::service::attribute::{
+    AttributeKey, AttributeSpecifier, AttributeValue, AttributeVerifier,
+};
+use crate::service::builder::request_response::{
+    Builder, RequestResponseCreateError, RequestResponseOpenError, RequestResponseOpenOrCreateError,
+};
+use crate::service::port_factory::request_response::PortFactory;
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+
+pub use iceoryx2_bb_derive_macros::rpc_service;
+pub use iceoryx2_bb_elementary_traits::rpc_interface::{
+    RpcCaller, RpcInterface, RpcResponder, RpcResponseExtractor,
+};
+
+/// The attribute key under which the interface name of an [`RpcService`] is stored.
+pub const RPC_INTERFACE_ATTRIBUTE_KEY: &str = "iceoryx2.rpc.interface";
+
+/// The attribute key under which the interface version of an [`RpcService`] is stored.
+pub const RPC_VERSION_ATTRIBUTE_KEY: &str = "iceoryx2.rpc.version";
+
+/// The [`PortFactory`] of an [`RpcService`].
+pub type RpcPortFactory<S, I> = PortFactory<
+    S,
+    <I as RpcInterface>::Request,
+    RpcRequestHeader,
+    <I as RpcInterface>::Response,
+    (),
+>;
+
+/// The [`Client`] of an [`RpcService`].
+pub type RpcClient<S, I> =
+    Client<S, <I as RpcInterface>::Request, RpcRequestHeader, <I as RpcInterface>::Response, ()>;
+
+/// The [`Server`] of an [`RpcService`].
+pub type RpcServer<S, I> =
+    Server<S, <I as RpcInterface>::Request, RpcRequestHeader, <I as RpcInterface>::Response, ()>;
+
+/// Failures that can occur when a [`PendingCall`] receives the return value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcReceiveError {
+    /// The server responded with the return value of another method.
+    UnexpectedResponse,
+
+    /// Underlying [`ReceiveError`]s.
+    ReceiveError(ReceiveError),
+}
+
+impl From<ReceiveError> for RpcReceiveError {
+    fn from(value: ReceiveError) -> Self {
+        RpcReceiveError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for RpcReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RpcReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for RpcReceiveError {}
+
+/// Failures that can occur when an [`RpcServer`] dispatches the received requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcDispatchError {
+    /// Underlying [`ReceiveError`]s.
+    ReceiveError(ReceiveError),
+
+    /// Underlying [`SendError`]s.
+    SendError(SendError),
+}
+
+impl From<ReceiveError> for RpcDispatchError {
+    fn from(value: ReceiveError) -> Self {
+        RpcDispatchError::ReceiveError(value)
+    }
+}
+
+impl From<SendError> for RpcDispatchError {
+    fn from(value: SendError) -> Self {
+        RpcDispatchError::SendError(value)
+    }
+}
+
+impl core::fmt::Display for RpcDispatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RpcDispatchError::{self:?}")
+    }
+}
+
+impl core::error::Error for RpcDispatchError {}
+
+/// The request user header of every [`RpcService`]. It identifies the method that shall be
+/// called.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+pub struct RpcRequestHeader {
+    method_id: u32,
+}
+
+impl RpcRequestHeader {
+    /// Creates a new [`RpcRequestHeader`] for the provided method id.
+    pub fn new(method_id: u32) -> Self {
+        Self { method_id }
+    }
+
+    /// Returns the id of the method that shall be called.
+    pub fn method_id(&self) -> u32 {
+        self.method_id
+    }
+}
+
+/// The request-response service of an [`RpcInterface`] that is generated by [`rpc_service`].
+/// It is implemented for every [`RpcInterface`].
+pub trait RpcService: RpcInterface {
+    /// Returns the [`AttributeSpecifier`] that stores interface name and version when the
+    /// service is created.
+    fn attribute_specifier() -> AttributeSpecifier {
+        let (interface_key, interface_name, version_key, version) = rpc_attributes::<Self>();
+        AttributeSpecifier::new()
+            .define(&interface_key, &interface_name)
+            .and_then(|s| s.define(&version_key, &version))
+            .expect("The interface name and version always fit into the attribute set.")
+    }
+
+    /// Returns the [`AttributeVerifier`] that requires the same interface name and version
+    /// when the service is opened.
+    fn attribute_verifier() -> AttributeVerifier {
+        let (interface_key, interface_name, version_key, version) = rpc_attributes::<Self>();
+        AttributeVerifier::new()
+            .require(&interface_key, &interface_name)
+            .and_then(|v| v.require(&version_key, &version))
+            .expect("The interface name and version always fit into the attribute set.")
+    }
+
+    /// Returns the request-response service [`Builder`] with the [`RpcRequestHeader`]. It can
+    /// be used to adjust the service settings. To keep the version check, the service must be
+    /// created with [`RpcService::attribute_specifier()`] or opened with
+    /// [`RpcService::attribute_verifier()`].
+    fn service_builder<S: Service>(
+        node: &Node<S>,
+        name: &ServiceName,
+    ) -> Builder<Self::Request, RpcRequestHeader, Self::Response, (), S> {
+        node.service_builder(name)
+            .request_response::<Self::Request, Self::Response>()
+            .request_user_header::<RpcRequestHeader>()
+    }
+
+    /// Opens the service when it exists, otherwise it is created.
+    fn open_or_create<S: Service>(
+        node: &Node<S>,
+        name: &ServiceName,
+    ) -> Result<RpcPortFactory<S, Self>, RequestResponseOpenOrCreateError> {
+        Self::service_builder(node, name)
+            .open_or_create_with_attributes(&Self::attribute_verifier())
+    }
+
+    /// Opens an existing service. Fails with [`RequestResponseOpenError::IncompatibleAttributes`]
+    /// when the service has another interface version.
+    fn open<S: Service>(
+        node: &Node<S>,
+        name: &ServiceName,
+    ) -> Result<RpcPortFactory<S, Self>, RequestResponseOpenError> {
+        Self::service_builder(node, name).open_with_attributes(&Self::attribute_verifier())
+    }
+
+    /// Creates a new service.
+    fn create<S: Service>(
+        node: &Node<S>,
+        name: &ServiceName,
+    ) -> Result<RpcPortFactory<S, Self>, RequestResponseCreateError> {
+        Self::service_builder(node, name).create_with_attributes(&Self::attribute_specifier())
+    }
+}
+
+impl<I: RpcInterface> RpcService for I {}
+
+fn rpc_attributes<I: RpcInterface>() -> (AttributeKey, AttributeValue, AttributeKey, AttributeValue)
+{
+    let msg = "The rpc attributes are always valid.";
+    (
+        RPC_INTERFACE_ATTRIBUTE_KEY.try_into().expect(msg),
+        I::INTERFACE_NAME.try_into().expect(msg),
+        RPC_VERSION_ATTRIBUTE_KEY.try_into().expect(msg),
+        format!("{}", I::VERSION).as_str().try_into().expect(msg),
+    )
+}
+
+/// The return value of a method that was received by a [`PendingCall`].
+pub struct CallResponse<S: Service, I: RpcInterface, T> {
+    response: Response<S, I::Response, ()>,
+    extract: RpcResponseExtractor<I::Response, T>,
+}
+
+impl<S: Service, I: RpcInterface, T> Debug for CallResponse<S, I, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CallResponse {{ response: {:?} }}", self.response)
+    }
+}
+
+impl<S: Service, I: RpcInterface, T> Deref for CallResponse<S, I, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        (self.extract)(self.response.payload())
+            .expect("The response variant is verified when it is received.")
+    }
+}
+
+impl<S: Service, I: RpcInterface, T> CallResponse<S, I, T> {
+    /// Returns the underlying [`Response`].
+    pub fn response(&self) -> &Response<S, I::Response, ()> {
+        &self.response
+    }
+}
+
+/// A method call that was sent by an [`RpcClient`] and waits for the return value.
+pub struct PendingCall<S: Service, I: RpcInterface, T> {
+    pending_response: PendingResponse<S, I::Request, RpcRequestHeader, I::Response, ()>,
+    extract: RpcResponseExtractor<I::Response, T>,
+}
+
+impl<S: Service, I: RpcInterface, T> Debug for PendingCall<S, I, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "PendingCall {{ pending_response: {:?} }}",
+            self.pending_response
+        )
+    }
+}
+
+impl<S: Service, I: RpcInterface, T> PendingCall<S, I, T> {
+    fn send(
+        client: &RpcClient<S, I>,
+        request: I::Request,
+        extract: RpcResponseExtractor<I::Response, T>,
+    ) -> Result<Self, RequestSendError> {
+        let mut request_uninit = client.loan_uninit()?;
+        *request_uninit.user_header_mut() = RpcRequestHeader::new(I::method_id(&request));
+        let pending_response = request_uninit.write_payload(request).send()?;
+
+        Ok(Self {
+            pending_response,
+            extract,
+        })
+    }
+
+    /// Receives the return value of the called method.
+    pub fn receive(&self) -> Result<Option<CallResponse<S, I, T>>, RpcReceiveError> {
+        let response = match self.pending_response.receive()? {
+            Some(response) => response,
+            None => return Ok(None),
+        };
+
+        if (self.extract)(response.payload()).is_none() {
+            fail!(from self, with RpcReceiveError::UnexpectedResponse,
+                "Unable to receive the return value since the server responded with the return value of another method.");
+        }
+
+        Ok(Some(CallResponse {
+            response,
+            extract: self.extract,
+        }))
+    }
+
+    /// Returns the underlying [`PendingResponse`].
+    pub fn pending_response(
+        &self,
+    ) -> &PendingResponse<S, I::Request, RpcRequestHeader, I::Response, ()> {
+        &self.pending_response
+    }
+}
+
+impl<S: Service, I: RpcInterface> RpcCaller<I> for RpcClient<S, I> {
+    type PendingCall<T> = PendingCall<S, I, T>;
+    type Error = RequestSendError;
+
+    /// Sends the request with the corresponding method id in the [`RpcRequestHeader`].
+    fn call<T>(
+        &self,
+        request: I::Request,
+        extract: RpcResponseExtractor<I::Response, T>,
+    ) -> Result<Self::PendingCall<T>, Self::Error> {
+        PendingCall::send(self, request, extract)
+    }
+}
+
+impl<S: Service, I: RpcInterface> RpcResponder<I> for RpcServer<S, I> {
+    type Error = RpcDispatchError;
+
+    /// Requests whose method id in the [`RpcRequestHeader`] does not match the method of the
+    /// payload are discarded without a response.
+    fn respond<F: FnMut(&I::Request) -> I::Response>(
+        &self,
+        mut handle: F,
+    ) -> Result<usize, Self::Error> {
+        let mut number_of_requests = 0;
+        while let Some(active_request) = self.receive()? {
+            let method_id = I::method_id(active_request.payload());
+            if active_request.user_header().method_id() != method_id {
+                warn!(from self,
+                    "Discard the request since the method id {} in the request header does not match the method id {} of the payload.",
+                    active_request.user_header().method_id(), method_id);
+                continue;
+            }
+
+            let response = handle(active_request.payload());
+            active_request
+                .loan_uninit()
+                .map_err(SendError::LoanError)?
+                .write_payload(response)
+                .send()?;
+            number_of_requests += 1;
+        }
+
+        Ok(number_of_requests)
+    }
+}
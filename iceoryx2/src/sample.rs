@@ -33,6 +33,7 @@
 use core::{fmt::Debug, ops::Deref};
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
@@ -137,4 +138,15 @@ impl<
     pub fn origin(&self) -> UniquePublisherId {
         UniquePublisherId(UniqueSystemId::from(self.details.origin))
     }
+
+    /// Returns the sequence number the [`Publisher`](crate::port::publisher::Publisher)
+    /// assigned to the [`Sample`] when it was sent. See [`Header::sequence_number()`].
+    pub fn sequence_number(&self) -> u64 {
+        self.header().sequence_number()
+    }
+
+    /// Returns the point in time when the [`Sample`] was sent. See [`Header::send_timestamp()`].
+    pub fn send_timestamp(&self) -> Time {
+        self.header().send_timestamp()
+    }
 }
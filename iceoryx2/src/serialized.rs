@@ -0,0 +1,337 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Publish-subscribe communication for payload types that are not
+//! [`ZeroCopySend`](iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend), like types
+//! that contain a [`String`](alloc::string::String) or a [`Vec`](alloc::vec::Vec).
+//!
+//! The [`SerializedPublisher`](crate::serialized::SerializedPublisher) serializes the payload
+//! with a [`Serialize`](iceoryx2_cal::serialize::Serialize) implementation into a loaned slice of
+//! bytes and the [`SerializedSubscriber`](crate::serialized::SerializedSubscriber) deserializes
+//! it again. The underlying [`Service`](crate::service::Service) has the payload type `[u8]` and
+//! must be created or opened with
+//! [`serialized_payload()`](crate::service::builder::publish_subscribe::Builder::serialized_payload())
+//! so that the payload type and the [`Serialize`](iceoryx2_cal::serialize::Serialize)
+//! implementation are stored in the
+//! [`MessageTypeDetails`](crate::service::static_config::message_type_details::MessageTypeDetails).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::serialized::{SerializedPublisher, SerializedSubscriber};
+//! use iceoryx2_cal::serialize::postcard::Postcard;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Telemetry {
+//!     sensor: String,
+//!     values: Vec<f64>,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<[u8]>()
+//!     .serialized_payload::<Telemetry, Postcard>()
+//!     .open_or_create()?;
+//!
+//! let publisher = SerializedPublisher::<_, Telemetry, Postcard>::new(
+//!     service
+//!         .publisher_builder()
+//!         .initial_max_slice_len(64)
+//!         // the size of the serialized payload is only known at runtime
+//!         .allocation_strategy(AllocationStrategy::PowerOfTwo)
+//!         .create()?,
+//! )?;
+//! let subscriber =
+//!     SerializedSubscriber::<_, Telemetry, Postcard>::new(service.subscriber_builder().create()?)?;
+//!
+//! publisher.send(&Telemetry {
+//!     sensor: "lidar".into(),
+//!     values: vec![1.0, 2.0, 3.0],
+//! })?;
+//!
+//! while let Some(telemetry) = subscriber.receive()? {
+//!     println!("received: {:?}", telemetry);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::any::type_name;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_container::string::String;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::serialize::Serialize;
+
+use crate::port::publisher::Publisher;
+use crate::port::subscriber::Subscriber;
+use crate::port::{ReceiveError, SendError};
+use crate::service;
+use crate::service::static_config::message_type_details::MessageTypeDetails;
+
+/// Defines the failures that can occur when a [`SerializedPublisher`] or a
+/// [`SerializedSubscriber`] is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerializedCreateError {
+    /// The payload type of the [`Service`](crate::service::Service) differs from the type of the
+    /// serialized port.
+    IncompatiblePayloadType,
+    /// The [`Service`](crate::service::Service) uses another [`Serialize`] implementation or
+    /// was not created with a serialized payload.
+    IncompatibleSerializer,
+}
+
+impl core::fmt::Display for SerializedCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SerializedCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for SerializedCreateError {}
+
+/// Defines the failures that can occur when a [`SerializedPublisher`] sends a payload.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerializedSendError {
+    /// The payload could not be serialized.
+    SerializationFailed,
+    /// The serialized payload could not be sent.
+    SendError(SendError),
+}
+
+impl From<SendError> for SerializedSendError {
+    fn from(value: SendError) -> Self {
+        SerializedSendError::SendError(value)
+    }
+}
+
+impl core::fmt::Display for SerializedSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SerializedSendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SerializedSendError {}
+
+/// Defines the failures that can occur when a [`SerializedSubscriber`] receives a payload.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerializedReceiveError {
+    /// The received payload could not be deserialized.
+    DeserializationFailed,
+    /// The serialized payload could not be received.
+    ReceiveError(ReceiveError),
+}
+
+impl From<ReceiveError> for SerializedReceiveError {
+    fn from(value: ReceiveError) -> Self {
+        SerializedReceiveError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for SerializedReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SerializedReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for SerializedReceiveError {}
+
+fn verify_type_details<T, S>(
+    origin: &str,
+    details: &MessageTypeDetails,
+) -> Result<(), SerializedCreateError> {
+    let msg = "Unable to create serialized port";
+    if details.payload.type_name().as_str() != type_name::<T>() {
+        fail!(from origin, with SerializedCreateError::IncompatiblePayloadType,
+            "{} since the service has the payload type \"{}\" but \"{}\" is required.",
+            msg, details.payload.type_name(), type_name::<T>());
+    }
+
+    if details.serializer.as_str() != type_name::<S>() {
+        fail!(from origin, with SerializedCreateError::IncompatibleSerializer,
+            "{} since the service uses the serializer \"{}\" but \"{}\" is required.",
+            msg, details.serializer, type_name::<S>());
+    }
+
+    Ok(())
+}
+
+/// Sends payloads of type `T` that are serialized with the [`Serialize`] implementation `S`.
+pub struct SerializedPublisher<
+    Service: service::Service,
+    T: serde::Serialize,
+    S: Serialize,
+    UserHeader: Debug + ZeroCopySend + Default = (),
+> {
+    publisher: Publisher<Service, [u8], UserHeader>,
+    _payload: PhantomData<T>,
+    _serializer: PhantomData<S>,
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize,
+        S: Serialize,
+        UserHeader: Debug + ZeroCopySend + Default,
+    > Debug for SerializedPublisher<Service, T, S, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SerializedPublisher<{}, {}> {{ publisher: {:?} }}",
+            type_name::<T>(),
+            type_name::<S>(),
+            self.publisher
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize,
+        S: Serialize,
+        UserHeader: Debug + ZeroCopySend + Default,
+    > SerializedPublisher<Service, T, S, UserHeader>
+{
+    /// Creates a new [`SerializedPublisher`] from a [`Publisher`] of a
+    /// [`Service`](crate::service::Service) that was created with `T` and `S` as
+    /// [`serialized_payload()`](crate::service::builder::publish_subscribe::Builder::serialized_payload()).
+    pub fn new(
+        publisher: Publisher<Service, [u8], UserHeader>,
+    ) -> Result<Self, SerializedCreateError> {
+        verify_type_details::<T, S>(
+            "SerializedPublisher::new()",
+            &publisher
+                .publisher_shared_state
+                .lock()
+                .sender
+                .message_type_details,
+        )?;
+
+        Ok(Self {
+            publisher,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the underlying [`Publisher`].
+    pub fn publisher(&self) -> &Publisher<Service, [u8], UserHeader> {
+        &self.publisher
+    }
+
+    /// Serializes the value into a loaned sample and sends it to all connected
+    /// [`Subscriber`]s. On success the number of recipients is returned.
+    pub fn send(&self, value: &T) -> Result<usize, SerializedSendError> {
+        let msg = "Unable to send serialized payload";
+        let payload = fail!(from self, when S::serialize(value),
+            with SerializedSendError::SerializationFailed,
+            "{} since the payload could not be serialized.", msg);
+
+        let sample = fail!(from self, when self.publisher.loan_slice_uninit(payload.len()),
+            to SendError,
+            "{} since the sample could not be loaned.", msg);
+        let sample = sample.write_from_slice(&payload);
+
+        Ok(fail!(from self, when sample.send(),
+            "{} since the sample could not be sent.", msg))
+    }
+}
+
+/// Receives payloads of type `T` that are serialized with the [`Serialize`] implementation `S`.
+pub struct SerializedSubscriber<
+    Service: service::Service,
+    T: serde::de::DeserializeOwned,
+    S: Serialize,
+    UserHeader: Debug + ZeroCopySend = (),
+> {
+    subscriber: Subscriber<Service, [u8], UserHeader>,
+    _payload: PhantomData<T>,
+    _serializer: PhantomData<S>,
+}
+
+impl<
+        Service: service::Service,
+        T: serde::de::DeserializeOwned,
+        S: Serialize,
+        UserHeader: Debug + ZeroCopySend,
+    > Debug for SerializedSubscriber<Service, T, S, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SerializedSubscriber<{}, {}> {{ subscriber: {:?} }}",
+            type_name::<T>(),
+            type_name::<S>(),
+            self.subscriber
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        T: serde::de::DeserializeOwned,
+        S: Serialize,
+        UserHeader: Debug + ZeroCopySend,
+    > SerializedSubscriber<Service, T, S, UserHeader>
+{
+    /// Creates a new [`SerializedSubscriber`] from a [`Subscriber`] of a
+    /// [`Service`](crate::service::Service) that was created with `T` and `S` as
+    /// [`serialized_payload()`](crate::service::builder::publish_subscribe::Builder::serialized_payload()).
+    pub fn new(
+        subscriber: Subscriber<Service, [u8], UserHeader>,
+    ) -> Result<Self, SerializedCreateError> {
+        verify_type_details::<T, S>(
+            "SerializedSubscriber::new()",
+            &subscriber
+                .subscriber_shared_state
+                .lock()
+                .receiver
+                .message_type_details,
+        )?;
+
+        Ok(Self {
+            subscriber,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the underlying [`Subscriber`].
+    pub fn subscriber(&self) -> &Subscriber<Service, [u8], UserHeader> {
+        &self.subscriber
+    }
+
+    /// Receives the next sample and deserializes it. If no sample could be received [`None`] is
+    /// returned. The sample is released before the value is returned.
+    pub fn receive(&self) -> Result<Option<T>, SerializedReceiveError> {
+        let msg = "Unable to receive serialized payload";
+        let sample = match fail!(from self, when self.subscriber.receive(),
+                        "{} since the sample could not be received.", msg)
+        {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        Ok(Some(
+            fail!(from self, when S::deserialize(sample.payload()),
+                with SerializedReceiveError::DeserializationFailed,
+                "{} since the payload could not be deserialized.", msg),
+        ))
+    }
+}
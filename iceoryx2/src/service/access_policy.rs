@@ -0,0 +1,386 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`AccessPolicy`](crate::service::access_policy::AccessPolicy) defines who is allowed to
+//! access a [`crate::service::Service`]. It is provided when the [`crate::service::Service`] is
+//! created and stored in its static config. The policy is applied as owner and permissions to
+//! the underlying resources of the [`crate::service::Service`], like the static and dynamic
+//! config. The resources of the ports, like the data segments, the connections and the event
+//! resources, are owned by the user that creates the port and receive only the permissions and,
+//! when the user is a member of it, the owning group.
+//!
+//! Without an explicit [`AccessPolicy`](crate::service::access_policy::AccessPolicy), the
+//! [`AccessPolicy::default()`](crate::service::access_policy::AccessPolicy::default()), the
+//! resources are created with the default permissions of the underlying concepts and no access
+//! check is performed when the [`crate::service::Service`] is opened. An explicit
+//! [`AccessPolicy`](crate::service::access_policy::AccessPolicy) created with
+//! [`AccessPolicy::new()`](crate::service::access_policy::AccessPolicy::new()) grants access
+//! only to the owner unless further [`AccessRights`](crate::service::access_policy::AccessRights)
+//! are granted.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::access_policy::{AccessPolicy, AccessRights};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .access_policy(
+//!         &AccessPolicy::new()
+//!             // all members of the owning group can open the service
+//!             .grant_group(AccessRights::ReadWrite)
+//!             // everyone else can only discover the service and read its static config
+//!             .grant_others(AccessRights::ReadOnly),
+//!     )
+//!     .create()?;
+//!
+//! println!("access policy: {:?}", service.access_policy());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Attribute Based Restrictions
+//!
+//! Additionally, the [`AccessPolicy`](crate::service::access_policy::AccessPolicy) can require
+//! [`Attribute`](crate::service::attribute::Attribute)s that an opener has to require in its
+//! [`AttributeVerifier`](crate::service::attribute::AttributeVerifier). Since the requirements of
+//! the [`AttributeVerifier`](crate::service::attribute::AttributeVerifier) are verified against
+//! the attributes of the [`crate::service::Service`], they have to be defined on creation as well.
+//! This restriction is cooperative, everyone who can read the static config can satisfy it.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::access_policy::AccessPolicy;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service_name = "My/Funk/RestrictedService".try_into()?;
+//!
+//! let service = node.service_builder(&service_name)
+//!     .event()
+//!     .access_policy(&AccessPolicy::new().require_attribute(
+//!         &"clearance".try_into()?,
+//!         &"diagnostics".try_into()?,
+//!     )?)
+//!     .create_with_attributes(
+//!         &AttributeSpecifier::new()
+//!             .define(&"clearance".try_into()?, &"diagnostics".try_into()?)?,
+//!     )?;
+//!
+//! // fails with EventOpenError::AccessDenied
+//! assert!(node.service_builder(&service_name).event().open().is_err());
+//!
+//! let opened_service = node.service_builder(&service_name)
+//!     .event()
+//!     .open_with_attributes(
+//!         &AttributeVerifier::new()
+//!             .require(&"clearance".try_into()?, &"diagnostics".try_into()?)?,
+//!     )?;
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::group::{Gid, Group};
+use iceoryx2_bb_posix::ownership::{Ownership, OwnershipBuilder};
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::user::{Uid, User};
+use iceoryx2_cal::named_concept::NamedConceptConfiguration;
+use serde::{Deserialize, Serialize};
+
+use super::attribute::{
+    AttributeDefinitionError, AttributeKey, AttributeSet, AttributeValue, AttributeVerifier,
+};
+
+/// Defines the rights a user has on a [`crate::service::Service`].
+#[repr(C)]
+#[derive(
+    Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
+)]
+pub enum AccessRights {
+    /// The user can neither discover nor open the [`crate::service::Service`].
+    #[default]
+    None,
+    /// The user can discover the [`crate::service::Service`] and read its static config but
+    /// cannot open it since opening requires the registration in the dynamic config.
+    ReadOnly,
+    /// The user can open the [`crate::service::Service`] and create ports.
+    ReadWrite,
+}
+
+/// Defines the owning user and group of a [`crate::service::Service`] and the [`AccessRights`]
+/// of all other users. See the [module documentation](crate::service::access_policy) for
+/// details.
+#[repr(C)]
+#[derive(Debug, Clone, Eq, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+pub struct AccessPolicy {
+    is_explicit: bool,
+    owner: Option<u32>,
+    group: Option<u32>,
+    group_rights: AccessRights,
+    others_rights: AccessRights,
+    required_attributes: AttributeSet,
+}
+
+impl Default for AccessPolicy {
+    fn default() -> Self {
+        Self {
+            is_explicit: false,
+            owner: None,
+            group: None,
+            group_rights: AccessRights::None,
+            others_rights: AccessRights::None,
+            required_attributes: AttributeSet::new(),
+        }
+    }
+}
+
+impl AccessPolicy {
+    /// Creates a new explicit [`AccessPolicy`] that grants access only to the creating user.
+    /// In contrast to [`AccessPolicy::default()`] it is enforced even when no further
+    /// [`AccessRights`] are granted.
+    pub fn new() -> Self {
+        Self {
+            is_explicit: true,
+            ..Self::default()
+        }
+    }
+
+    /// Defines the user id of the owner. If it is not set, the creating user is the owner.
+    /// Transferring the ownership to another user requires elevated privileges.
+    pub fn owned_by_user(mut self, uid: u32) -> Self {
+        self.is_explicit = true;
+        self.owner = Some(uid);
+        self
+    }
+
+    /// Defines the group id of the owning group. If it is not set, the group of the creating
+    /// user is the owning group. The creating user must be a member of the group.
+    pub fn owned_by_group(mut self, gid: u32) -> Self {
+        self.is_explicit = true;
+        self.group = Some(gid);
+        self
+    }
+
+    /// Defines the [`AccessRights`] of the members of the owning group.
+    pub fn grant_group(mut self, value: AccessRights) -> Self {
+        self.is_explicit = true;
+        self.group_rights = value;
+        self
+    }
+
+    /// Defines the [`AccessRights`] of all users that are neither the owner nor a member of the
+    /// owning group.
+    pub fn grant_others(mut self, value: AccessRights) -> Self {
+        self.is_explicit = true;
+        self.others_rights = value;
+        self
+    }
+
+    /// Requires that the opener requires the attribute in its [`AttributeVerifier`].
+    pub fn require_attribute(
+        mut self,
+        key: &AttributeKey,
+        value: &AttributeValue,
+    ) -> Result<Self, AttributeDefinitionError> {
+        fail!(from self, when self.required_attributes.add(key, value),
+            "Unable to add the attribute requirement {}={} to the access policy.", key, value);
+        self.is_explicit = true;
+        Ok(self)
+    }
+
+    /// Returns true when the [`AccessPolicy`] was explicitly defined and is enforced. It is
+    /// false for the [`AccessPolicy::default()`] that a [`crate::service::Service`] uses when
+    /// no [`AccessPolicy`] was provided on creation.
+    pub fn is_explicit(&self) -> bool {
+        self.is_explicit
+    }
+
+    /// Returns the user id of the owner. It is [`None`] when the [`AccessPolicy`] is not
+    /// explicitly defined and the resources are owned by the creating user.
+    pub fn owner(&self) -> Option<u32> {
+        self.owner
+    }
+
+    /// Returns the group id of the owning group. It is [`None`] when the [`AccessPolicy`] is
+    /// not explicitly defined and the resources are owned by the group of the creating user.
+    pub fn group(&self) -> Option<u32> {
+        self.group
+    }
+
+    /// Returns the [`AccessRights`] of the members of the owning group.
+    pub fn group_rights(&self) -> AccessRights {
+        self.group_rights
+    }
+
+    /// Returns the [`AccessRights`] of all other users.
+    pub fn others_rights(&self) -> AccessRights {
+        self.others_rights
+    }
+
+    /// Returns the attributes an opener has to require.
+    pub fn required_attributes(&self) -> &AttributeSet {
+        &self.required_attributes
+    }
+
+    /// Replaces an undefined owner and group with the user and group of the process so that
+    /// the policy can be verified by other processes.
+    pub(crate) fn resolve(mut self) -> Self {
+        if self.is_explicit {
+            self.owner.get_or_insert(Uid::from_self().value());
+            self.group.get_or_insert(Gid::from_self().value());
+        }
+        self
+    }
+
+    fn rights_as_permission(
+        rights: AccessRights,
+        read: Permission,
+        write: Permission,
+    ) -> Permission {
+        match rights {
+            AccessRights::None => Permission::none(),
+            AccessRights::ReadOnly => read,
+            AccessRights::ReadWrite => read | write,
+        }
+    }
+
+    fn permission(&self) -> Permission {
+        Permission::OWNER_ALL
+            | Self::rights_as_permission(
+                self.group_rights,
+                Permission::GROUP_READ,
+                Permission::GROUP_WRITE,
+            )
+            | Self::rights_as_permission(
+                self.others_rights,
+                Permission::OTHERS_READ,
+                Permission::OTHERS_WRITE,
+            )
+    }
+
+    fn ownership(&self) -> Ownership {
+        OwnershipBuilder::new()
+            .uid(
+                self.owner
+                    .map_or_else(Uid::from_self, |v| Uid::new_from_native(v as _)),
+            )
+            .gid(
+                self.group
+                    .map_or_else(Gid::from_self, |v| Gid::new_from_native(v as _)),
+            )
+            .create()
+    }
+
+    /// Returns the same policy without the attribute requirements. It is used for internal
+    /// services that are opened without attributes.
+    pub(crate) fn without_attribute_requirements(&self) -> Self {
+        Self {
+            required_attributes: AttributeSet::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Applies the owner and the permissions to the configuration of a resource of the
+    /// [`crate::service::Service`] that is created together with the
+    /// [`crate::service::Service`]. When no explicit [`AccessPolicy`] was defined, the resource
+    /// defaults are used.
+    pub(crate) fn apply<Config: NamedConceptConfiguration>(&self, config: Config) -> Config {
+        if !self.is_explicit {
+            return config;
+        }
+
+        config
+            .permission(self.permission())
+            .ownership(self.ownership())
+    }
+
+    /// Applies the permissions to the configuration of a resource that is created by a port.
+    /// The resource stays owned by the creating user since only privileged users can transfer
+    /// the ownership. The owning group is applied when the process is a member of it. When no
+    /// explicit [`AccessPolicy`] was defined, the resource defaults are used.
+    pub(crate) fn apply_to_port_resource<Config: NamedConceptConfiguration>(
+        &self,
+        config: Config,
+    ) -> Config {
+        if !self.is_explicit {
+            return config;
+        }
+
+        let gid = match self.group {
+            Some(group) if Gid::new_from_native(group as _).is_group_of_self() => {
+                Gid::new_from_native(group as _)
+            }
+            _ => Gid::from_self(),
+        };
+
+        config.permission(self.permission()).ownership(
+            OwnershipBuilder::new()
+                .uid(Uid::from_self())
+                .gid(gid)
+                .create(),
+        )
+    }
+
+    fn rights_of_process(&self) -> AccessRights {
+        if !self.is_explicit || self.owner == Some(Uid::from_self().value()) {
+            return AccessRights::ReadWrite;
+        }
+
+        let is_group_member = match self.group {
+            None => false,
+            Some(group) if Gid::new_from_native(group as _).is_group_of_self() => true,
+            Some(group) => match (
+                Group::from_gid(Gid::new_from_native(group as _)),
+                User::from_self(),
+            ) {
+                (Ok(group), Ok(user)) => match (group.details(), user.details()) {
+                    (Some(group), Some(user)) => group.members().contains(user.name()),
+                    _ => false,
+                },
+                _ => false,
+            },
+        };
+
+        if is_group_member {
+            self.group_rights
+        } else {
+            self.others_rights
+        }
+    }
+
+    /// Returns true when the process has [`AccessRights::ReadWrite`] and the
+    /// [`AttributeVerifier`] requires all attributes the [`AccessPolicy`] requires.
+    pub(crate) fn grants_access(&self, verifier: &AttributeVerifier) -> bool {
+        let rights = self.rights_of_process();
+        if rights != AccessRights::ReadWrite {
+            debug!(from self, "The process has only {:?} access rights.", rights);
+            return false;
+        }
+
+        for required in self.required_attributes.iter() {
+            if !verifier.required_attributes().contains(required) {
+                debug!(from self, "The opener does not require the attribute {}={}.",
+                    required.key(), required.value());
+                return false;
+            }
+        }
+
+        true
+    }
+}
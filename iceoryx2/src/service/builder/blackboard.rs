@@ -16,8 +16,10 @@
 //!
 
 use core::alloc::Layout;
+use core::cell::UnsafeCell;
 use core::hash::Hash;
 use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
 
 use alloc::boxed::Box;
 use alloc::format;
@@ -29,32 +31,39 @@ use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_container::string::*;
 use iceoryx2_bb_container::vector::relocatable_vec::*;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_elementary::static_assert::static_assert_eq;
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::*;
 use iceoryx2_bb_log::{error, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
 
 use crate::constants::{MAX_BLACKBOARD_KEY_ALIGNMENT, MAX_BLACKBOARD_KEY_SIZE};
 use crate::service;
+use crate::service::builder::event::EventCreateError;
 use crate::service::builder::CustomKeyMarker;
 use crate::service::config_scheme::{blackboard_data_config, blackboard_mgmt_config};
 use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
 use crate::service::dynamic_config::MessagingPatternSettings;
 use crate::service::naming_scheme::blackboard_name;
-use crate::service::port_factory::blackboard;
+use crate::service::port_factory::{blackboard, event};
 use crate::service::static_config::message_type_details::TypeDetail;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 
 use super::{OpenDynamicStorageFailure, ServiceState};
 
+use self::access_policy::AccessPolicy;
 use self::attribute::{AttributeSpecifier, AttributeVerifier};
 use builder::RETRY_LIMIT;
 
+// The bump allocator of the blackboard data segment supports at most an alignment of 8.
+const MAX_RESERVED_VALUE_ALIGNMENT: usize = 8;
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ServiceAvailabilityState {
     ServiceState(ServiceState),
@@ -78,6 +87,8 @@ pub enum BlackboardOpenError {
     IncompatibleMessagingPattern,
     /// The [`Service`] supports less [`Reader`](crate::port::reader::Reader)s than requested.
     DoesNotSupportRequestedAmountOfReaders,
+    /// The [`Service`] supports less [`Writer`](crate::port::writer::Writer)s than requested.
+    DoesNotSupportRequestedAmountOfWriters,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
@@ -91,6 +102,9 @@ pub enum BlackboardOpenError {
     ExceedsMaxNumberOfNodes,
     /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
     DoesNotSupportRequestedAmountOfNodes,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process read-write access or
+    /// the [`AttributeVerifier`] does not require the attributes the [`AccessPolicy`] requires.
+    AccessDenied,
 }
 
 impl core::fmt::Display for BlackboardOpenError {
@@ -139,6 +153,14 @@ pub enum BlackboardCreateError {
     HangsInCreation,
     /// No key-value pairs have been provided. At least one is required.
     NoEntriesProvided,
+    /// The initial value of a slice entry contains more elements than the maximum slice length
+    /// of the entry.
+    InitialValueExceedsMaxSliceLen,
+    /// The [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema) of a type
+    /// exceeds [`MAX_TYPE_SCHEMA_FIELDS`](crate::constants::MAX_TYPE_SCHEMA_FIELDS),
+    /// [`MAX_FIELD_NAME_LENGTH`](crate::constants::MAX_FIELD_NAME_LENGTH) or
+    /// [`MAX_TYPE_SCHEMA_NAMES_LENGTH`](crate::constants::MAX_TYPE_SCHEMA_NAMES_LENGTH).
+    TypeSchemaExceedsLimits,
 }
 
 impl core::fmt::Display for BlackboardCreateError {
@@ -169,6 +191,75 @@ impl From<ServiceAvailabilityState> for BlackboardCreateError {
     }
 }
 
+/// Errors that can occur when a [`MessagingPattern::Blackboard`] [`Service`] shall be
+/// created or opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackboardOpenOrCreateError {
+    /// Failures that can occur when an existing [`Service`] could not be opened.
+    BlackboardOpenError(BlackboardOpenError),
+    /// Failures that can occur when a [`Service`] could not be created.
+    BlackboardCreateError(BlackboardCreateError),
+    /// Failures that can occur when the key-value pairs of the [`Creator`] could not be added to
+    /// an existing [`Service`].
+    EntryInsertError(EntryInsertError),
+    /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
+    /// high frequency.
+    SystemInFlux,
+}
+
+impl From<ServiceAvailabilityState> for BlackboardOpenOrCreateError {
+    fn from(value: ServiceAvailabilityState) -> Self {
+        BlackboardOpenOrCreateError::BlackboardOpenError(value.into())
+    }
+}
+
+impl From<BlackboardOpenError> for BlackboardOpenOrCreateError {
+    fn from(value: BlackboardOpenError) -> Self {
+        Self::BlackboardOpenError(value)
+    }
+}
+
+impl From<BlackboardCreateError> for BlackboardOpenOrCreateError {
+    fn from(value: BlackboardCreateError) -> Self {
+        Self::BlackboardCreateError(value)
+    }
+}
+
+impl From<EntryInsertError> for BlackboardOpenOrCreateError {
+    fn from(value: EntryInsertError) -> Self {
+        Self::EntryInsertError(value)
+    }
+}
+
+impl core::fmt::Display for BlackboardOpenOrCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BlackboardOpenOrCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for BlackboardOpenOrCreateError {}
+
+/// Errors that can occur when a key-value pair is added to an existing
+/// [`MessagingPattern::Blackboard`] [`Service`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntryInsertError {
+    /// An entry with the same key already exists.
+    KeyAlreadyExists,
+    /// All key-value pairs that were reserved with [`Creator::reserve_keys()`] are already in use.
+    ExceedsMaxNumberOfKeys,
+    /// The size or the alignment of the value exceeds the memory that was reserved for every
+    /// key-value pair with [`Creator::reserve_keys()`].
+    ExceedsMaxValueSize,
+}
+
+impl core::fmt::Display for EntryInsertError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EntryInsertError::{self:?}")
+    }
+}
+
+impl core::error::Error for EntryInsertError {}
+
 #[doc(hidden)]
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum KeyMemoryError {
@@ -280,6 +371,7 @@ pub struct BuilderInternals {
     internal_value_size: usize,
     internal_value_alignment: usize,
     internal_value_cleanup_callback: Box<dyn FnMut()>,
+    max_slice_len: usize,
 }
 
 impl Debug for BuilderInternals {
@@ -310,15 +402,88 @@ impl BuilderInternals {
             internal_value_size: value_size,
             internal_value_alignment: value_alignment,
             internal_value_cleanup_callback: value_cleanup_callback,
+            max_slice_len: 1,
+        }
+    }
+}
+
+/// Describes the memory layout of a single cell of a slice entry. Every cell starts with the
+/// current length of the slice followed by `max_slice_len` elements.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SliceCellLayout {
+    pub(crate) size: usize,
+    pub(crate) alignment: usize,
+    pub(crate) elements_offset: usize,
+}
+
+impl SliceCellLayout {
+    pub(crate) fn new<ValueType>(max_slice_len: usize) -> Self {
+        let alignment = core::mem::align_of::<u64>().max(core::mem::align_of::<ValueType>());
+        let elements_offset = align(
+            core::mem::size_of::<u64>(),
+            core::mem::align_of::<ValueType>(),
+        );
+        let size = align(
+            elements_offset + core::mem::size_of::<ValueType>() * max_slice_len,
+            alignment,
+        );
+
+        Self {
+            size,
+            alignment,
+            elements_offset,
         }
     }
 }
 
+/// The owner token of an [`Entry`] that is not yet owned by any
+/// [`Writer`](crate::port::writer::Writer).
+pub(crate) const NO_ENTRY_OWNER: u64 = 0;
+
 #[repr(C)]
 #[derive(Debug, ZeroCopySend)]
 pub(crate) struct Entry {
     pub(crate) type_details: TypeDetail,
     pub(crate) offset: IoxAtomicU64,
+    pub(crate) max_slice_len: u64,
+    /// The ownership token of the [`Writer`](crate::port::writer::Writer) that owns the entry.
+    pub(crate) owner: IoxAtomicU64,
+}
+
+/// The [`ReservedEntry`] is not used or its insertion is in progress.
+const RESERVED_ENTRY_UNUSED: u8 = 0;
+/// The key of the [`ReservedEntry`] is published so that concurrent insertions of the same key
+/// can detect each other, the entry is not yet visible.
+const RESERVED_ENTRY_KEY_PUBLISHED: u8 = 1;
+/// The [`ReservedEntry`] is fully initialized and visible.
+const RESERVED_ENTRY_READY: u8 = 2;
+/// The insertion of the [`ReservedEntry`] lost against a concurrent insertion of the same key.
+const RESERVED_ENTRY_DISCARDED: u8 = 3;
+
+/// An entry whose memory is reserved when the blackboard is created and that can be used to
+/// add a key-value pair to the blackboard at runtime.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct ReservedEntry {
+    key: UnsafeCell<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>>,
+    entry: UnsafeCell<Entry>,
+    state: IoxAtomicU8,
+}
+
+// Safe since a reserved entry is only modified by the inserter that claimed it via
+// Mgmt::number_of_claimed_entries and it is only read after its key was published via the state.
+unsafe impl Send for ReservedEntry {}
+unsafe impl Sync for ReservedEntry {}
+unsafe impl ZeroCopySend for ReservedEntry {}
+
+impl ReservedEntry {
+    fn key(&self) -> &KeyMemory<MAX_BLACKBOARD_KEY_SIZE> {
+        unsafe { &*self.key.get() }
+    }
+
+    fn entry(&self) -> &Entry {
+        unsafe { &*self.entry.get() }
+    }
 }
 
 #[repr(C)]
@@ -326,24 +491,243 @@ pub(crate) struct Entry {
 pub(crate) struct Mgmt {
     pub(crate) map: RelocatableFlatMap<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>,
     pub(crate) entries: RelocatableVec<Entry>,
+    pub(crate) reserved_entries: RelocatableVec<ReservedEntry>,
+    pub(crate) number_of_claimed_entries: IoxAtomicUsize,
+    pub(crate) reserved_value_size: usize,
+    pub(crate) reserved_value_alignment: usize,
+    pub(crate) writer_token_counter: IoxAtomicU64,
+}
+
+impl Mgmt {
+    fn claimed_entries(&self) -> &[ReservedEntry] {
+        match self.number_of_claimed_entries.load(Ordering::Acquire) {
+            // the reserved entries are not initialized when no keys were reserved
+            0 => &[],
+            n => &self.reserved_entries[..n],
+        }
+    }
+
+    fn inserted_entries(&self) -> impl Iterator<Item = &ReservedEntry> {
+        self.claimed_entries()
+            .iter()
+            .filter(|e| e.state.load(Ordering::Acquire) == RESERVED_ENTRY_READY)
+    }
+
+    /// Claims the next unused reserved entry without blocking. Every entry is claimed at most
+    /// once, therefore the claiming inserter has exclusive access to it until it is published.
+    fn claim_reserved_entry(&self) -> Option<usize> {
+        let mut index = self.number_of_claimed_entries.load(Ordering::Relaxed);
+        loop {
+            if index == self.reserved_entries.len() {
+                return None;
+            }
+
+            match self.number_of_claimed_entries.compare_exchange_weak(
+                index,
+                index + 1,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Some(index),
+                Err(v) => index = v,
+            }
+        }
+    }
 }
 
 pub(crate) struct BlackboardResources<ServiceType: service::Service> {
     pub(crate) mgmt: ServiceType::BlackboardMgmt<Mgmt>,
     pub(crate) data: ServiceType::BlackboardPayload,
     pub(crate) key_eq_func: Rc<dyn Fn(*const u8, *const u8) -> bool>,
+    pub(crate) event_service: Option<event::PortFactory<ServiceType>>,
 }
 
 impl<ServiceType: service::Service> Debug for BlackboardResources<ServiceType> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "BlackboardResources {{ mgmt: {:?}, data: {:?} }}",
-            self.mgmt, self.data
+            "BlackboardResources {{ mgmt: {:?}, data: {:?}, event_service: {:?} }}",
+            self.mgmt, self.data, self.event_service
         )
     }
 }
 
+impl<ServiceType: service::Service> BlackboardResources<ServiceType> {
+    /// Returns a token that is unique for the lifetime of the blackboard. It is used by a
+    /// [`Writer`](crate::port::writer::Writer) to claim the ownership of entries.
+    pub(crate) fn new_writer_token(&self) -> u64 {
+        self.mgmt
+            .get()
+            .writer_token_counter
+            .fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Returns the entry of the given key. Entries that are added at runtime are only visible
+    /// after they are fully initialized.
+    pub(crate) fn get_entry(&self, key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>) -> Option<&Entry> {
+        let mgmt = self.mgmt.get();
+        if let Some(index) = unsafe { mgmt.map.__internal_get(key, self.key_eq_func.as_ref()) } {
+            return Some(&mgmt.entries[index]);
+        }
+
+        mgmt.inserted_entries()
+            .find(|reserved_entry| {
+                (self.key_eq_func)(
+                    reserved_entry.key.get() as *const u8,
+                    (key as *const KeyMemory<MAX_BLACKBOARD_KEY_SIZE>).cast(),
+                )
+            })
+            .map(|reserved_entry| reserved_entry.entry())
+    }
+
+    /// Calls the provided callback for every entry, including the entries that were added at
+    /// runtime, until either all entries were visited or the callback returns
+    /// [`CallbackProgression::Stop`].
+    pub(crate) fn for_each_entry<
+        F: FnMut(&KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, &Entry) -> CallbackProgression,
+    >(
+        &self,
+        mut callback: F,
+    ) {
+        let mgmt = self.mgmt.get();
+        let mut progression = CallbackProgression::Continue;
+        unsafe {
+            mgmt.map.for_each(|key, index| {
+                progression = callback(key, &mgmt.entries[*index]);
+                progression
+            })
+        };
+
+        if progression == CallbackProgression::Stop {
+            return;
+        }
+
+        for reserved_entry in mgmt.inserted_entries() {
+            if callback(reserved_entry.key(), reserved_entry.entry()) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
+    /// Adds a new key-value pair to the blackboard by using the next unused reserved entry. The
+    /// value is written by the `value_writer` into the memory of the reserved entry.
+    ///
+    /// The insertion never blocks. Concurrent insertions of the same key publish their key first
+    /// and resolve the conflict with a compare-exchange on the state of the reserved entries, so
+    /// that at most one of them becomes visible.
+    pub(crate) fn insert_entry(
+        &self,
+        key: KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
+        value_type_details: TypeDetail,
+        max_slice_len: u64,
+        value_layout: Layout,
+        value_writer: &mut dyn FnMut(*mut u8),
+    ) -> Result<(), EntryInsertError> {
+        let msg = "Unable to insert the key-value pair into the blackboard";
+        let mgmt = self.mgmt.get();
+
+        if self.get_entry(&key).is_some() {
+            fail!(from self, with EntryInsertError::KeyAlreadyExists,
+                "{} since an entry with the same key already exists.", msg);
+        }
+
+        if mgmt.number_of_claimed_entries.load(Ordering::Relaxed) == mgmt.reserved_entries.len() {
+            fail!(from self, with EntryInsertError::ExceedsMaxNumberOfKeys,
+                "{} since all {} reserved entries are already in use.", msg, mgmt.reserved_entries.len());
+        }
+
+        if value_layout.size() > mgmt.reserved_value_size
+            || value_layout.align() > mgmt.reserved_value_alignment
+        {
+            fail!(from self, with EntryInsertError::ExceedsMaxValueSize,
+                "{} since the value requires {:?} but only a size of {} and an alignment of {} are reserved.",
+                msg, value_layout, mgmt.reserved_value_size, mgmt.reserved_value_alignment);
+        }
+
+        let index = match mgmt.claim_reserved_entry() {
+            Some(index) => index,
+            None => {
+                fail!(from self, with EntryInsertError::ExceedsMaxNumberOfKeys,
+                    "{} since all {} reserved entries are already in use.", msg, mgmt.reserved_entries.len());
+            }
+        };
+
+        let reserved_entry = &mgmt.reserved_entries[index];
+        // Safe since the reserved entry was claimed by this call and is not yet published.
+        unsafe {
+            *reserved_entry.key.get() = key;
+            let entry = &mut *reserved_entry.entry.get();
+            entry.type_details = value_type_details;
+            entry.max_slice_len = max_slice_len;
+            value_writer(
+                (self.data.payload_start_address() as u64 + entry.offset.load(Ordering::Relaxed))
+                    as *mut u8,
+            );
+        }
+
+        // The key is published and all other published keys are inspected afterwards. The
+        // sequentially consistent ordering ensures that of two concurrent insertions of the same
+        // key at least one sees the other.
+        reserved_entry
+            .state
+            .store(RESERVED_ENTRY_KEY_PUBLISHED, Ordering::SeqCst);
+
+        for (other_index, other) in mgmt.claimed_entries().iter().enumerate() {
+            if other_index == index {
+                continue;
+            }
+
+            let mut other_state = other.state.load(Ordering::SeqCst);
+            if !(other_state == RESERVED_ENTRY_KEY_PUBLISHED || other_state == RESERVED_ENTRY_READY)
+                || !(self.key_eq_func)(
+                    other.key.get() as *const u8,
+                    reserved_entry.key.get() as *const u8,
+                )
+            {
+                continue;
+            }
+
+            // The insertion with the lower index wins unless the other one is already visible.
+            if other_state == RESERVED_ENTRY_KEY_PUBLISHED && index < other_index {
+                match other.state.compare_exchange(
+                    RESERVED_ENTRY_KEY_PUBLISHED,
+                    RESERVED_ENTRY_DISCARDED,
+                    Ordering::SeqCst,
+                    Ordering::SeqCst,
+                ) {
+                    Ok(_) => continue,
+                    Err(v) => other_state = v,
+                }
+            }
+
+            if other_state != RESERVED_ENTRY_DISCARDED {
+                reserved_entry
+                    .state
+                    .store(RESERVED_ENTRY_DISCARDED, Ordering::SeqCst);
+                fail!(from self, with EntryInsertError::KeyAlreadyExists,
+                    "{} since an entry with the same key was inserted concurrently.", msg);
+            }
+        }
+
+        // Fails when a concurrent insertion of the same key with a lower index discarded this one.
+        if reserved_entry
+            .state
+            .compare_exchange(
+                RESERVED_ENTRY_KEY_PUBLISHED,
+                RESERVED_ENTRY_READY,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_err()
+        {
+            fail!(from self, with EntryInsertError::KeyAlreadyExists,
+                "{} since an entry with the same key was inserted concurrently.", msg);
+        }
+
+        Ok(())
+    }
+}
+
 impl<ServiceType: service::Service> ServiceResource for BlackboardResources<ServiceType> {
     fn acquire_ownership(&self) {
         self.data.acquire_ownership();
@@ -357,10 +741,12 @@ struct Builder<
 > {
     base: builder::BuilderWithServiceType<ServiceType>,
     verify_max_readers: bool,
+    verify_max_writers: bool,
     verify_max_nodes: bool,
     internals: Vec<BuilderInternals>,
+    initial_value_exceeds_max_slice_len: bool,
     override_key_type: Option<TypeDetail>,
-    key_eq_func: Box<dyn Fn(*const u8, *const u8) -> bool>,
+    key_eq_func: Rc<dyn Fn(*const u8, *const u8) -> bool>,
     _key: PhantomData<KeyType>,
 }
 
@@ -372,10 +758,11 @@ impl<
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "Builder<{}, {}> {{ verify_max_readers: {}, verify_max_nodes: {}, internals: {:?} }}",
+            "Builder<{}, {}> {{ verify_max_readers: {}, verify_max_writers: {}, verify_max_nodes: {}, internals: {:?} }}",
             core::any::type_name::<KeyType>(),
             core::any::type_name::<ServiceType>(),
             self.verify_max_readers,
+            self.verify_max_writers,
             self.verify_max_nodes,
             self.internals
         )
@@ -391,10 +778,12 @@ impl<
         let mut new_self = Self {
             base,
             verify_max_readers: false,
+            verify_max_writers: false,
             verify_max_nodes: false,
             internals: Vec::<BuilderInternals>::new(),
+            initial_value_exceeds_max_slice_len: false,
             override_key_type: None,
-            key_eq_func: Box::new(|lhs: *const u8, rhs: *const u8| {
+            key_eq_func: Rc::new(|lhs: *const u8, rhs: *const u8| {
                 KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::default_key_eq_comparison::<KeyType>(lhs, rhs)
             }),
             _key: PhantomData,
@@ -465,6 +854,14 @@ impl<
         self.verify_max_readers = true;
     }
 
+    /// If the [`Service`] is created it defines how many [`Writer`](crate::port::writer::Writer)s
+    /// shall be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`Writer`](crate::port::writer::Writer)s must be at least supported.
+    fn max_writers(&mut self, value: usize) {
+        self.config_details_mut().max_writers = value;
+        self.verify_max_writers = true;
+    }
+
     /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
     /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
     /// [`Node`](crate::node::Node)s must be at least supported.
@@ -472,127 +869,552 @@ impl<
         self.config_details_mut().max_nodes = value;
         self.verify_max_nodes = true;
     }
-}
 
-/// Builder to create a new [`MessagingPattern::Blackboard`] based [`Service`]s
-///
-/// # Example
-///
-/// See [`crate::service`]
-#[derive(Debug)]
-pub struct Creator<
-    KeyType: Send + Sync + Eq + Clone + Copy + Debug + ZeroCopySend + Hash,
-    ServiceType: service::Service,
-> {
-    builder: Builder<KeyType, ServiceType>,
-}
+    fn verify_service_configuration(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+        verifier: &AttributeVerifier,
+    ) -> Result<static_config::blackboard::StaticConfig, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
 
-impl<
-        KeyType: Send + Sync + Eq + Clone + Copy + Debug + ZeroCopySend + Hash,
-        ServiceType: service::Service,
-    > Creator<KeyType, ServiceType>
-{
-    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
-        Self {
-            builder: Builder::new(base),
+        if !existing_settings.access_policy().grants_access(verifier) {
+            fail!(from self, with BlackboardOpenError::AccessDenied,
+                "{} since the access policy {:?} of the service does not grant access.",
+                msg, existing_settings.access_policy());
         }
-    }
-
-    /// Defines how many [`Reader`](crate::port::reader::Reader)s shall be supported at most.
-    pub fn max_readers(mut self, value: usize) -> Self {
-        self.builder.max_readers(value);
-        self
-    }
 
-    /// Defines how many [`Node`](crate::node::Node)s shall be able to open it in parallel.
-    pub fn max_nodes(mut self, value: usize) -> Self {
-        self.builder.max_nodes(value);
-        self
-    }
+        let existing_attributes = existing_settings.attributes();
+        if let Err(incompatible_key) = verifier.verify_requirements(existing_attributes) {
+            fail!(from self, with BlackboardOpenError::IncompatibleAttributes,
+                "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
+                msg, incompatible_key, verifier, existing_attributes);
+        }
 
-    /// Adds key-value pairs to the blackboard.
-    pub fn add<ValueType: ZeroCopySend + Copy + 'static>(
-        mut self,
-        key: KeyType,
-        value: ValueType,
-    ) -> Self {
-        let key_mem = match KeyMemory::try_from(&key) {
-            Err(_) => {
-                fatal_panic!(from self,
-                    "This should never happen! Calling add() with a key type that has an invalid layout.")
+        let required_settings = self.base.service_config.blackboard();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            p => {
+                fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Blackboard is required.", msg, p);
             }
-            Ok(mem) => mem,
-        };
-
-        let internals = BuilderInternals {
-            key: key_mem,
-            value_type_details: TypeDetail::new::<ValueType>(
-                message_type_details::TypeVariant::FixedSize,
-            ),
-            value_writer: Box::new(move |mem: *mut u8| {
-                let mem: *mut UnrestrictedAtomic<ValueType> =
-                    mem as *mut UnrestrictedAtomic<ValueType>;
-                unsafe { mem.write(UnrestrictedAtomic::<ValueType>::new(value)) };
-            }),
-            internal_value_size: core::mem::size_of::<UnrestrictedAtomic<ValueType>>(),
-            internal_value_alignment: core::mem::align_of::<UnrestrictedAtomic<ValueType>>(),
-            internal_value_cleanup_callback: Box::new(|| {}),
         };
-        self.builder.internals.push(internals);
-
-        self
-    }
-
-    /// Adds key-value pairs to the blackboard where value is a default value.
-    pub fn add_with_default<ValueType: ZeroCopySend + Copy + 'static + Default>(
-        self,
-        key: KeyType,
-    ) -> Self {
-        self.add(key, ValueType::default())
-    }
 
-    /// Validates configuration and overrides the invalid setting with meaningful values.
-    fn adjust_configuration_to_meaningful_values(&mut self) {
-        let origin = format!("{self:?}");
-        let settings = self.builder.base.service_config.blackboard_mut();
+        if self.verify_max_readers && existing_settings.max_readers < required_settings.max_readers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
+                                "{} since the service supports only {} readers but a support of {} readers was requested.",
+                                msg, existing_settings.max_readers, required_settings.max_readers);
+        }
 
-        if settings.max_readers == 0 {
-            warn!(from origin, "Setting the maximum amount of readers to 0 is not supported. Adjust it to 1, the smallest supported value.");
-            settings.max_readers = 1;
+        if self.verify_max_writers && existing_settings.max_writers < required_settings.max_writers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters,
+                                "{} since the service supports only {} writers but a support of {} writers was requested.",
+                                msg, existing_settings.max_writers, required_settings.max_writers);
         }
 
-        if settings.max_nodes == 0 {
-            warn!(from origin,
-                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
-            settings.max_nodes = 1;
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes,
+                                "{} since the service supports only {} nodes but {} are required.",
+                                msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
-    }
 
-    /// Creates a new [`Service`].
-    pub fn create(
-        mut self,
-    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
-        self.builder.prepare_config_details();
-        self.create_impl(&AttributeSpecifier::new())
+        Ok(existing_settings.clone())
     }
 
-    /// Creates a new [`Service`] with a set of attributes.
-    pub fn create_with_attributes(
-        mut self,
-        attributes: &AttributeSpecifier,
-    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
-        self.builder.prepare_config_details();
-        self.create_impl(attributes)
-    }
+    fn open_impl(
+        &mut self,
+        attributes: &AttributeVerifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
 
-    fn create_impl(
-        mut self,
-        attributes: &AttributeSpecifier,
-    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
-        let msg = "Unable to create blackboard service";
+        let mut service_open_retry_count = 0;
+        loop {
+            match self.is_service_available(msg)? {
+                None => {
+                    fail!(from self, with BlackboardOpenError::DoesNotExist, "{} since the service does not exist.", msg);
+                }
+                Some((static_config, static_storage)) => {
+                    let blackboard_static_config =
+                        self.verify_service_configuration(&static_config, attributes)?;
+
+                    let service_tag = self
+                        .base
+                        .create_node_service_tag(msg, BlackboardOpenError::InternalFailure)?;
+
+                    let dynamic_config = match self.base.open_dynamic_config_storage() {
+                        Ok(v) => v,
+                        Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
+                            fail!(from self, with BlackboardOpenError::IsMarkedForDestruction,
+                                "{} since the service is marked for destruction.", msg);
+                        }
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with BlackboardOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(OpenDynamicStorageFailure::DynamicStorageOpenError(
+                            DynamicStorageOpenError::DoesNotExist,
+                        )) => {
+                            fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                                "{} since the dynamic segment of the service is missing.", msg);
+                        }
+                        Err(e) => {
+                            if self.is_service_available(msg)?.is_none() {
+                                fail!(from self, with BlackboardOpenError::DoesNotExist, "{}, since the service does not exist.", msg);
+                            }
+
+                            service_open_retry_count += 1;
+
+                            if RETRY_LIMIT < service_open_retry_count {
+                                fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                                    "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
+                                    msg, e);
+                            }
+
+                            continue;
+                        }
+                    };
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::Blackboard(blackboard_static_config.clone());
+
+                    let name = blackboard_name(self.base.service_config.service_id().as_str());
+                    let mut mgmt_config =
+                        blackboard_mgmt_config::<ServiceType, Mgmt>(self.base.shared_node.config());
+                    let mgmt_name = self.config_details().type_details.type_name.as_str();
+                    // The name was set in create_impl to be able to remove the concept when a node
+                    // dies. Safe since the same name is set in
+                    // ServiceInternal::__internal_remove_node_from_service.
+                    unsafe {
+                        <ServiceType::BlackboardMgmt<Mgmt> as DynamicStorage<
+                            Mgmt,
+                        >>::__internal_set_type_name_in_config(
+                            &mut mgmt_config, mgmt_name
+                        )
+                    };
+                    let mgmt_storage = fail!(from self, when
+                        <ServiceType::BlackboardMgmt<Mgmt> as DynamicStorage<Mgmt>
+                        >::Builder::new(&name)
+                            .config(&mgmt_config)
+                            .has_ownership(false)
+                            .open(),
+                        with BlackboardOpenError::ServiceInCorruptedState,
+                        "{} since the blackboard management information could not be opened. This could indicate a corrupted system.", msg);
+
+                    let shm_config =
+                        blackboard_data_config::<ServiceType>(self.base.shared_node.config());
+                    let payload_shm = match <<ServiceType::BlackboardPayload as SharedMemory<
+                        iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator,
+                    >>::Builder as NamedConceptBuilder<ServiceType::BlackboardPayload>>::new(
+                        &name
+                    )
+                    .config(&shm_config)
+                    .open()
+                    {
+                        Ok(v) => v,
+                        Err(_) => {
+                            fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                                "{} since the blackboard payload data segment could not be opened. This could indicate a corrupted system.",
+                                msg);
+                        }
+                    };
+
+                    let event_service = if blackboard_static_config.has_event_service {
+                        match builder::Builder::new(
+                            static_config.name(),
+                            self.base.shared_node.clone(),
+                        )
+                        .event()
+                        .open()
+                        {
+                            Ok(v) => Some(v),
+                            Err(e) => {
+                                fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                                    "{} since the corresponding event service could not be opened ({:?}).",
+                                    msg, e);
+                            }
+                        }
+                    } else {
+                        None
+                    };
+
+                    if let Some(service_tag) = service_tag {
+                        service_tag.release_ownership();
+                    }
+
+                    return Ok(blackboard::PortFactory::<ServiceType, KeyType>::new(
+                        service::ServiceState::new(
+                            static_config,
+                            self.base.shared_node.clone(),
+                            dynamic_config,
+                            static_storage,
+                            BlackboardResources {
+                                mgmt: mgmt_storage,
+                                data: payload_shm,
+                                key_eq_func: self.key_eq_func.clone(),
+                                event_service,
+                            },
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Builder to create a new [`MessagingPattern::Blackboard`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Creator<
+    KeyType: Send + Sync + Eq + Clone + Copy + Debug + ZeroCopySend + Hash,
+    ServiceType: service::Service,
+> {
+    builder: Builder<KeyType, ServiceType>,
+}
+
+impl<
+        KeyType: Send + Sync + Eq + Clone + Copy + Debug + ZeroCopySend + Hash,
+        ServiceType: service::Service,
+    > Creator<KeyType, ServiceType>
+{
+    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        Self {
+            builder: Builder::new(base),
+        }
+    }
+
+    /// Defines how many [`Reader`](crate::port::reader::Reader)s shall be supported at most.
+    pub fn max_readers(mut self, value: usize) -> Self {
+        self.builder.max_readers(value);
+        self
+    }
+
+    /// Defines how many [`Writer`](crate::port::writer::Writer)s shall be supported at most.
+    /// Every key can be owned by only one [`Writer`](crate::port::writer::Writer) at a time.
+    /// The first [`Writer`](crate::port::writer::Writer) that creates an entry handle for a key
+    /// becomes its owner until it goes out of scope or its [`Node`](crate::node::Node) dies and
+    /// is cleaned up. By default, only one [`Writer`](crate::port::writer::Writer) is supported.
+    pub fn max_writers(mut self, value: usize) -> Self {
+        self.builder.max_writers(value);
+        self
+    }
+
+    /// Defines how many [`Node`](crate::node::Node)s shall be able to open it in parallel.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.builder.max_nodes(value);
+        self
+    }
+
+    /// Defines the [`AccessPolicy`] that is applied to all underlying resources.
+    pub fn access_policy(mut self, value: &AccessPolicy) -> Self {
+        self.builder.base.service_config.access_policy = value.clone().resolve();
+        self
+    }
+
+    /// Defines whether the [`Service`] owns an event service with the same name. When enabled,
+    /// every update of an entry is signaled with the entry id of the updated entry, see
+    /// [`EntryHandleMut::entry_id()`](crate::port::writer::EntryHandleMut::entry_id()), so that
+    /// a [`Listener`](crate::port::listener::Listener) can wait for updates of specific keys.
+    /// The event service can be accessed with
+    /// [`PortFactory::event_service()`](crate::service::port_factory::blackboard::PortFactory::event_service()).
+    pub fn enable_event_service(mut self, value: bool) -> Self {
+        self.builder.config_details_mut().has_event_service = value;
+        self
+    }
+
+    /// Reserves memory for `number_of_keys` additional key-value pairs that can be added after
+    /// the [`Service`] was created, either with
+    /// [`Writer::add()`](crate::port::writer::Writer::add()) or with
+    /// [`Creator::open_or_create()`] by another participant that defines keys which do not exist
+    /// yet. The value of every additional key-value pair can occupy at most `max_value_size`
+    /// bytes and must have an alignment of at most 8.
+    pub fn reserve_keys(mut self, number_of_keys: usize, max_value_size: usize) -> Self {
+        self.builder.config_details_mut().number_of_reserved_keys = number_of_keys;
+        self.builder.config_details_mut().max_reserved_value_size = max_value_size;
+        self
+    }
+
+    /// Adds key-value pairs to the blackboard.
+    pub fn add<ValueType: ZeroCopySend + Copy + 'static>(
+        mut self,
+        key: KeyType,
+        value: ValueType,
+    ) -> Self {
+        let key_mem = match KeyMemory::try_from(&key) {
+            Err(_) => {
+                fatal_panic!(from self,
+                    "This should never happen! Calling add() with a key type that has an invalid layout.")
+            }
+            Ok(mem) => mem,
+        };
+
+        let internals = BuilderInternals {
+            key: key_mem,
+            value_type_details: TypeDetail::new::<ValueType>(
+                message_type_details::TypeVariant::FixedSize,
+            ),
+            value_writer: Box::new(move |mem: *mut u8| {
+                let mem: *mut UnrestrictedAtomic<ValueType> =
+                    mem as *mut UnrestrictedAtomic<ValueType>;
+                unsafe { mem.write(UnrestrictedAtomic::<ValueType>::new(value)) };
+            }),
+            internal_value_size: core::mem::size_of::<UnrestrictedAtomic<ValueType>>(),
+            internal_value_alignment: core::mem::align_of::<UnrestrictedAtomic<ValueType>>(),
+            internal_value_cleanup_callback: Box::new(|| {}),
+            max_slice_len: 1,
+        };
+        self.builder.internals.push(internals);
+
+        self
+    }
+
+    /// Adds a key-value pair to the blackboard where the value is a slice that can hold at most
+    /// `max_slice_len` elements. The entry is initialized with a copy of `value`. If `value`
+    /// contains more than `max_slice_len` elements, the creation of the blackboard fails with
+    /// [`BlackboardCreateError::InitialValueExceedsMaxSliceLen`].
+    pub fn add_slice<ValueType: ZeroCopySend + Clone + 'static>(
+        mut self,
+        key: KeyType,
+        max_slice_len: usize,
+        value: &[ValueType],
+    ) -> Self {
+        let key_mem = match KeyMemory::try_from(&key) {
+            Err(_) => {
+                fatal_panic!(from self,
+                    "This should never happen! Calling add_slice() with a key type that has an invalid layout.")
+            }
+            Ok(mem) => mem,
+        };
+
+        if value.len() > max_slice_len {
+            warn!(from self,
+                "The initial value of the entry with the key {:?} has {} elements but the maximum slice length is {}. The creation of the blackboard will fail.",
+                key, value.len(), max_slice_len);
+            self.builder.initial_value_exceeds_max_slice_len = true;
+            return self;
+        }
+
+        let cell = SliceCellLayout::new::<ValueType>(max_slice_len);
+        let value = value.to_vec();
+        let internals = BuilderInternals {
+            key: key_mem,
+            value_type_details: TypeDetail::new::<ValueType>(
+                message_type_details::TypeVariant::Dynamic,
+            ),
+            value_writer: Box::new(move |raw_memory_ptr: *mut u8| {
+                let ptrs = unsafe {
+                    __internal_calculate_atomic_mgmt_and_payload_ptr(raw_memory_ptr, cell.alignment)
+                };
+                unsafe {
+                    (ptrs.atomic_payload_ptr as *mut u64).write(value.len() as u64);
+                    let elements =
+                        ptrs.atomic_payload_ptr.add(cell.elements_offset) as *mut ValueType;
+                    for (i, element) in value.iter().enumerate() {
+                        elements.add(i).write(element.clone());
+                    }
+                }
+            }),
+            internal_value_size: UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_size(
+                cell.size,
+                cell.alignment,
+            ),
+            internal_value_alignment:
+                UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_alignment(cell.alignment),
+            internal_value_cleanup_callback: Box::new(|| {}),
+            max_slice_len,
+        };
+        self.builder.internals.push(internals);
+
+        self
+    }
+
+    /// Adds key-value pairs to the blackboard where value is a default value.
+    pub fn add_with_default<ValueType: ZeroCopySend + Copy + 'static + Default>(
+        self,
+        key: KeyType,
+    ) -> Self {
+        self.add(key, ValueType::default())
+    }
+
+    /// Validates configuration and overrides the invalid setting with meaningful values.
+    fn adjust_configuration_to_meaningful_values(&mut self) {
+        let origin = format!("{self:?}");
+        let settings = self.builder.base.service_config.blackboard_mut();
+
+        if settings.max_readers == 0 {
+            warn!(from origin, "Setting the maximum amount of readers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_readers = 1;
+        }
+
+        if settings.max_writers == 0 {
+            warn!(from origin, "Setting the maximum amount of writers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_writers = 1;
+        }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. When an existing [`Service`] is opened, all key-value pairs that were added to
+    /// the [`Creator`] and that do not exist yet are added to the [`Service`]. This requires
+    /// that enough keys were reserved with [`Creator::reserve_keys()`] when the [`Service`] was
+    /// created. Key-value pairs that already exist must have the same value type.
+    pub fn open_or_create(
+        self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes.
+    ///
+    /// If the [`Service`] already exists all attribute requirements must be satisfied
+    /// otherwise the open process will fail. If the [`Service`] does not exist the required
+    /// attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        self.builder.prepare_config_details();
+        self.open_or_create_impl(verifier)
+    }
+
+    fn open_or_create_impl(
+        &mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        let msg = "Unable to open or create blackboard service";
+
+        let mut retry_count = 0;
+        loop {
+            if RETRY_LIMIT < retry_count {
+                fail!(from self,
+                      with BlackboardOpenOrCreateError::SystemInFlux,
+                      "{} since an instance is creating and removing the same service repeatedly.",
+                      msg);
+            }
+            retry_count += 1;
+
+            match self.builder.is_service_available(msg)? {
+                Some(_) => match self.builder.open_impl(verifier) {
+                    Ok(factory) => {
+                        self.insert_missing_entries(&factory, msg)?;
+                        return Ok(factory);
+                    }
+                    Err(BlackboardOpenError::DoesNotExist) => continue,
+                    Err(e) => return Err(e.into()),
+                },
+                None => {
+                    match self
+                        .create_impl(&AttributeSpecifier(verifier.required_attributes().clone()))
+                    {
+                        Ok(factory) => return Ok(factory),
+                        Err(BlackboardCreateError::AlreadyExists)
+                        | Err(BlackboardCreateError::IsBeingCreatedByAnotherInstance) => {
+                            continue;
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+            }
+        }
+    }
+
+    fn insert_missing_entries(
+        &mut self,
+        factory: &blackboard::PortFactory<ServiceType, KeyType>,
+        msg: &str,
+    ) -> Result<(), EntryInsertError> {
+        let resources = &factory.service.additional_resource;
+        for i in 0..self.builder.internals.len() {
+            let internals = &mut self.builder.internals[i];
+            let value_layout = unsafe {
+                Layout::from_size_align_unchecked(
+                    internals.internal_value_size,
+                    internals.internal_value_alignment,
+                )
+            };
+
+            let result = match resources.insert_entry(
+                internals.key,
+                internals.value_type_details.clone(),
+                internals.max_slice_len as u64,
+                value_layout,
+                &mut *internals.value_writer,
+            ) {
+                Err(EntryInsertError::KeyAlreadyExists)
+                    if resources.get_entry(&internals.key).is_some_and(|entry| {
+                        entry.type_details == internals.value_type_details
+                    }) =>
+                {
+                    Ok(())
+                }
+                result => result,
+            };
+
+            if let Err(e) = result {
+                fail!(from self, with e,
+                    "{} since a key-value pair could not be added to the existing service ({:?}).",
+                    msg, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.builder.prepare_config_details();
+        self.create_impl(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.builder.prepare_config_details();
+        self.create_impl(attributes)
+    }
+
+    fn create_impl(
+        &mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        let msg = "Unable to create blackboard service";
 
         self.adjust_configuration_to_meaningful_values();
 
+        if self.builder.initial_value_exceeds_max_slice_len {
+            fail!(from self, with BlackboardCreateError::InitialValueExceedsMaxSliceLen,
+                "{} since the initial value of a slice entry exceeds its maximum slice length.", msg);
+        }
+
+        if self
+            .builder
+            .config_details()
+            .type_details
+            .schema
+            .exceeds_limits()
+            || self
+                .builder
+                .internals
+                .iter()
+                .any(|i| i.value_type_details.schema.exceeds_limits())
+        {
+            fail!(from self, with BlackboardCreateError::TypeSchemaExceedsLimits,
+                "{} since the type schema of the key or of a value exceeds its limits.", msg);
+        }
+
         match self.builder.is_service_available(msg)? {
             Some(_) => {
                 fail!(from self, with BlackboardCreateError::AlreadyExists, "{} since the service already exists.", msg);
@@ -629,7 +1451,7 @@ impl<
                 // create dynamic config
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_readers: blackboard_config.max_readers,
-                    number_of_writers: 1,
+                    number_of_writers: blackboard_config.max_writers,
                 };
 
                 let dynamic_config = match self.builder.base.create_dynamic_config_storage(
@@ -657,7 +1479,13 @@ impl<
                 // create the payload data segment for the writer
                 let name = blackboard_name(self.builder.base.service_config.service_id().as_str());
                 let shm_config =
-                    blackboard_data_config::<ServiceType>(self.builder.base.shared_node.config());
+                    self.builder
+                        .base
+                        .service_config
+                        .access_policy
+                        .apply(blackboard_data_config::<ServiceType>(
+                            self.builder.base.shared_node.config(),
+                        ));
                 let mut payload_size = 0;
                 if self.builder.internals.is_empty() {
                     fail!(from self,  with BlackboardCreateError::NoEntriesProvided,
@@ -666,6 +1494,59 @@ impl<
                 for i in &self.builder.internals {
                     payload_size += i.internal_value_size + i.internal_value_alignment - 1;
                 }
+                let reserved_value_alignment =
+                    UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_alignment(
+                        MAX_RESERVED_VALUE_ALIGNMENT,
+                    );
+                let reserved_value_size =
+                    UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_size(
+                        self.builder.config_details().max_reserved_value_size,
+                        MAX_RESERVED_VALUE_ALIGNMENT,
+                    );
+                let number_of_reserved_keys = self.builder.config_details().number_of_reserved_keys;
+                payload_size +=
+                    number_of_reserved_keys * (reserved_value_size + reserved_value_alignment - 1);
+
+                // create the event service that signals entry updates
+                let event_service = if self.builder.config_details().has_event_service {
+                    match builder::Builder::new(
+                        self.builder.base.service_config.name(),
+                        self.builder.base.shared_node.clone(),
+                    )
+                    .event()
+                    .access_policy(
+                        &self
+                            .builder
+                            .base
+                            .service_config
+                            .access_policy
+                            .without_attribute_requirements(),
+                    )
+                    .max_nodes(self.builder.config_details().max_nodes)
+                    .max_notifiers(
+                        (self.builder.internals.len() + number_of_reserved_keys)
+                            * self.builder.config_details().max_writers,
+                    )
+                    .event_id_max_value(payload_size)
+                    .disable_notifier_created_event()
+                    .disable_notifier_dropped_event()
+                    .disable_notifier_dead_event()
+                    .create()
+                    {
+                        Ok(v) => Some(v),
+                        Err(EventCreateError::AlreadyExists) => {
+                            fail!(from self, with BlackboardCreateError::AlreadyExists,
+                                "{} since the corresponding event service already exists.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with BlackboardCreateError::InternalFailure,
+                                "{} since the corresponding event service could not be created ({:?}).", msg, e);
+                        }
+                    }
+                } else {
+                    None
+                };
+
                 let payload_shm = match <<ServiceType::BlackboardPayload as SharedMemory<
                     iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator,
                 >>::Builder as NamedConceptBuilder<ServiceType::BlackboardPayload>>::new(
@@ -687,9 +1568,14 @@ impl<
                 // create the management segment
                 let capacity = self.builder.internals.len();
 
-                let mut mgmt_config = blackboard_mgmt_config::<ServiceType, Mgmt>(
-                    self.builder.base.shared_node.config(),
-                );
+                let mut mgmt_config =
+                    self.builder
+                        .base
+                        .service_config
+                        .access_policy
+                        .apply(blackboard_mgmt_config::<ServiceType, Mgmt>(
+                            self.builder.base.shared_node.config(),
+                        ));
                 let mgmt_name = self
                     .builder
                     .config_details()
@@ -710,9 +1596,9 @@ impl<
                     >>::Builder::new(&name)
                         .config(&mgmt_config)
                         .has_ownership(false)
-                        .supplementary_size(RelocatableFlatMap::<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>::const_memory_size(capacity)+RelocatableVec::<Entry>::const_memory_size(capacity))
+                        .supplementary_size(RelocatableFlatMap::<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>::const_memory_size(capacity)+RelocatableVec::<Entry>::const_memory_size(capacity)+RelocatableVec::<ReservedEntry>::const_memory_size(number_of_reserved_keys))
                         .initializer(|entry: &mut Mgmt, allocator: &mut BumpAllocator| {
-                            if unsafe {entry.map.init(allocator)}.is_err() || unsafe {entry.entries.init(allocator).is_err()} {
+                            if unsafe {entry.map.init(allocator)}.is_err() || unsafe {entry.entries.init(allocator).is_err()} || (number_of_reserved_keys > 0 && unsafe {entry.reserved_entries.init(allocator).is_err()}) {
                                 return false
                             }
                             for i in 0..capacity {
@@ -727,20 +1613,48 @@ impl<
                                 };
                                 (*self.builder.internals[i].value_writer)(mem.data_ptr);
                                 // write offset to value in payload_shm to entries vector
-                                let res = entry.entries.push(Entry{type_details: self.builder.internals[i].value_type_details.clone(), offset: IoxAtomicU64::new(mem.offset.offset() as u64)});
+                                let res = entry.entries.push(Entry{type_details: self.builder.internals[i].value_type_details.clone(), offset: IoxAtomicU64::new(mem.offset.offset() as u64), max_slice_len: self.builder.internals[i].max_slice_len as u64, owner: IoxAtomicU64::new(NO_ENTRY_OWNER)});
                                 if res.is_err() {
                                     error!(from self, "Writing the value offset to the blackboard management segment failed.");
                                     return false
                                 }
                                 // write offset index to map
-                                let res = unsafe {entry.map.__internal_insert(self.builder.internals[i].key, entry.entries.len() - 1, &self.builder.key_eq_func)};
+                                let res = unsafe {entry.map.__internal_insert(self.builder.internals[i].key, entry.entries.len() - 1, self.builder.key_eq_func.as_ref())};
                                 if res.is_err() {
                                     error!(from self, "Inserting the key-value pair into the blackboard management segment failed.");
                                     return false
                                 }
                             }
+                            for _ in 0..number_of_reserved_keys {
+                                // reserve the memory for key-value pairs that are added at runtime
+                                let mem = match payload_shm.allocate(unsafe { Layout::from_size_align_unchecked(reserved_value_size, reserved_value_alignment) })
+                                {
+                                    Ok(m) => m,
+                                    Err(_) => {
+                                        error!(from self, "Reserving memory in the blackboard data segment failed.");
+                                        return false
+                                    }
+                                };
+                                let res = entry.reserved_entries.push(ReservedEntry {
+                                    key: UnsafeCell::new(KeyMemory { data: [0; MAX_BLACKBOARD_KEY_SIZE] }),
+                                    entry: UnsafeCell::new(Entry { type_details: TypeDetail::new::<()>(message_type_details::TypeVariant::FixedSize), offset: IoxAtomicU64::new(mem.offset.offset() as u64), max_slice_len: 0, owner: IoxAtomicU64::new(NO_ENTRY_OWNER) }),
+                                    state: IoxAtomicU8::new(RESERVED_ENTRY_UNUSED),
+                                });
+                                if res.is_err() {
+                                    error!(from self, "Writing the reserved entry to the blackboard management segment failed.");
+                                    return false
+                                }
+                            }
                             true})
-                        .create(Mgmt{ map: unsafe { RelocatableFlatMap::<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>::new_uninit(capacity) }, entries: unsafe {RelocatableVec::<Entry>::new_uninit(capacity)}}),
+                        .create(Mgmt{
+                            map: unsafe { RelocatableFlatMap::<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>::new_uninit(capacity) },
+                            entries: unsafe {RelocatableVec::<Entry>::new_uninit(capacity)},
+                            reserved_entries: unsafe {RelocatableVec::<ReservedEntry>::new_uninit(number_of_reserved_keys)},
+                            number_of_claimed_entries: IoxAtomicUsize::new(0),
+                            reserved_value_size,
+                            reserved_value_alignment,
+                            writer_token_counter: IoxAtomicU64::new(NO_ENTRY_OWNER + 1),
+                        }),
                             with BlackboardCreateError::ServiceInCorruptedState, "{} since the blackboard management segment could not be created. This could indicate a corrupted system.",
                             msg);
 
@@ -756,14 +1670,15 @@ impl<
 
                 Ok(blackboard::PortFactory::<ServiceType, KeyType>::new(
                     service::ServiceState::new(
-                        self.builder.base.service_config.clone(),
+                        (*self.builder.base.service_config).clone(),
                         self.builder.base.shared_node.clone(),
                         dynamic_config,
                         unlocked_static_details,
                         BlackboardResources {
                             mgmt: mgmt_storage,
                             data: payload_shm,
-                            key_eq_func: Rc::new(self.builder.key_eq_func),
+                            key_eq_func: self.builder.key_eq_func.clone(),
+                            event_service,
                         },
                     ),
                 ))
@@ -784,7 +1699,7 @@ impl<ServiceType: service::Service> Creator<CustomKeyMarker, ServiceType> {
         mut self,
         key_eq_func: Box<dyn Fn(*const u8, *const u8) -> bool>,
     ) -> Self {
-        self.builder.key_eq_func = key_eq_func;
+        self.builder.key_eq_func = Rc::from(key_eq_func);
         self
     }
 
@@ -875,54 +1790,18 @@ impl<
         self
     }
 
+    /// Defines how many [`Writer`](crate::port::writer::Writer)s must be at least supported.
+    pub fn max_writers(mut self, value: usize) -> Self {
+        self.builder.max_writers(value);
+        self
+    }
+
     /// Defines how many [`Node`](crate::node::Node)s must be at least supported.
     pub fn max_nodes(mut self, value: usize) -> Self {
         self.builder.max_nodes(value);
         self
     }
 
-    fn verify_service_configuration(
-        &self,
-        existing_settings: &static_config::StaticConfig,
-        verifier: &AttributeVerifier,
-    ) -> Result<static_config::blackboard::StaticConfig, BlackboardOpenError> {
-        let msg = "Unable to open blackboard service";
-
-        let existing_attributes = existing_settings.attributes();
-        if let Err(incompatible_key) = verifier.verify_requirements(existing_attributes) {
-            fail!(from self, with BlackboardOpenError::IncompatibleAttributes,
-                "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
-                msg, incompatible_key, verifier, existing_attributes);
-        }
-
-        let required_settings = self.builder.base.service_config.blackboard();
-        let existing_settings = match &existing_settings.messaging_pattern {
-            MessagingPattern::Blackboard(ref v) => v,
-            p => {
-                fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
-                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Blackboard is required.", msg, p);
-            }
-        };
-
-        if self.builder.verify_max_readers
-            && existing_settings.max_readers < required_settings.max_readers
-        {
-            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
-                                "{} since the service supports only {} readers but a support of {} readers was requested.",
-                                msg, existing_settings.max_readers, required_settings.max_readers);
-        }
-
-        if self.builder.verify_max_nodes
-            && existing_settings.max_nodes < required_settings.max_nodes
-        {
-            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes,
-                                "{} since the service supports only {} nodes but {} are required.",
-                                msg, existing_settings.max_nodes, required_settings.max_nodes);
-        }
-
-        Ok(existing_settings.clone())
-    }
-
     /// Opens an existing [`Service`].
     pub fn open(
         self,
@@ -937,135 +1816,7 @@ impl<
         verifier: &AttributeVerifier,
     ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
         self.builder.prepare_config_details();
-        self.open_impl(verifier)
-    }
-
-    fn open_impl(
-        mut self,
-        attributes: &AttributeVerifier,
-    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
-        let msg = "Unable to open blackboard service";
-
-        let mut service_open_retry_count = 0;
-        loop {
-            match self.builder.is_service_available(msg)? {
-                None => {
-                    fail!(from self, with BlackboardOpenError::DoesNotExist, "{} since the service does not exist.", msg);
-                }
-                Some((static_config, static_storage)) => {
-                    let blackboard_static_config =
-                        self.verify_service_configuration(&static_config, attributes)?;
-
-                    let service_tag = self
-                        .builder
-                        .base
-                        .create_node_service_tag(msg, BlackboardOpenError::InternalFailure)?;
-
-                    let dynamic_config = match self.builder.base.open_dynamic_config_storage() {
-                        Ok(v) => v,
-                        Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
-                            fail!(from self, with BlackboardOpenError::IsMarkedForDestruction,
-                                "{} since the service is marked for destruction.", msg);
-                        }
-                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
-                            fail!(from self, with BlackboardOpenError::ExceedsMaxNumberOfNodes,
-                                "{} since it would exceed the maximum number of supported nodes.", msg);
-                        }
-                        Err(OpenDynamicStorageFailure::DynamicStorageOpenError(
-                            DynamicStorageOpenError::DoesNotExist,
-                        )) => {
-                            fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
-                                "{} since the dynamic segment of the service is missing.", msg);
-                        }
-                        Err(e) => {
-                            if self.builder.is_service_available(msg)?.is_none() {
-                                fail!(from self, with BlackboardOpenError::DoesNotExist, "{}, since the service does not exist.", msg);
-                            }
-
-                            service_open_retry_count += 1;
-
-                            if RETRY_LIMIT < service_open_retry_count {
-                                fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
-                                    "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
-                                    msg, e);
-                            }
-
-                            continue;
-                        }
-                    };
-
-                    self.builder.base.service_config.messaging_pattern =
-                        MessagingPattern::Blackboard(blackboard_static_config.clone());
-
-                    let name =
-                        blackboard_name(self.builder.base.service_config.service_id().as_str());
-                    let mut mgmt_config = blackboard_mgmt_config::<ServiceType, Mgmt>(
-                        self.builder.base.shared_node.config(),
-                    );
-                    let mgmt_name = self
-                        .builder
-                        .config_details()
-                        .type_details
-                        .type_name
-                        .as_str();
-                    // The name was set in create_impl to be able to remove the concept when a node
-                    // dies. Safe since the same name is set in
-                    // ServiceInternal::__internal_remove_node_from_service.
-                    unsafe {
-                        <ServiceType::BlackboardMgmt<Mgmt> as DynamicStorage<
-                            Mgmt,
-                        >>::__internal_set_type_name_in_config(
-                            &mut mgmt_config, mgmt_name
-                        )
-                    };
-                    let mgmt_storage = fail!(from self, when
-                        <ServiceType::BlackboardMgmt<Mgmt> as DynamicStorage<Mgmt>
-                        >::Builder::new(&name)
-                            .config(&mgmt_config)
-                            .has_ownership(false)
-                            .open(),
-                        with BlackboardOpenError::ServiceInCorruptedState,
-                        "{} since the blackboard management information could not be opened. This could indicate a corrupted system.", msg);
-
-                    let shm_config = blackboard_data_config::<ServiceType>(
-                        self.builder.base.shared_node.config(),
-                    );
-                    let payload_shm = match <<ServiceType::BlackboardPayload as SharedMemory<
-                        iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator,
-                    >>::Builder as NamedConceptBuilder<ServiceType::BlackboardPayload>>::new(
-                        &name
-                    )
-                    .config(&shm_config)
-                    .open()
-                    {
-                        Ok(v) => v,
-                        Err(_) => {
-                            fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
-                                "{} since the blackboard payload data segment could not be opened. This could indicate a corrupted system.",
-                                msg);
-                        }
-                    };
-
-                    if let Some(service_tag) = service_tag {
-                        service_tag.release_ownership();
-                    }
-
-                    return Ok(blackboard::PortFactory::<ServiceType, KeyType>::new(
-                        service::ServiceState::new(
-                            static_config,
-                            self.builder.base.shared_node.clone(),
-                            dynamic_config,
-                            static_storage,
-                            BlackboardResources {
-                                mgmt: mgmt_storage,
-                                data: payload_shm,
-                                key_eq_func: Rc::new(self.builder.key_eq_func),
-                            },
-                        ),
-                    ));
-                }
-            }
-        }
+        self.builder.open_impl(verifier)
     }
 }
 
@@ -1075,4 +1826,13 @@ impl<ServiceType: service::Service> Opener<CustomKeyMarker, ServiceType> {
         self.builder.override_key_type = Some(value.clone());
         self
     }
+
+    #[doc(hidden)]
+    pub unsafe fn __internal_set_key_eq_cmp_func(
+        mut self,
+        key_eq_func: Box<dyn Fn(*const u8, *const u8) -> bool>,
+    ) -> Self {
+        self.builder.key_eq_func = Rc::from(key_eq_func);
+        self
+    }
 }
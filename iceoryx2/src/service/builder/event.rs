@@ -22,6 +22,7 @@ use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 
+use crate::port::notification_payload::MAX_NOTIFICATION_PAYLOAD_SIZE;
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::dynamic_config::MessagingPatternSettings;
 use crate::service::port_factory::event;
@@ -29,6 +30,7 @@ use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use crate::service::{self, dynamic_config::event::DynamicConfigSettings};
 
+use self::access_policy::AccessPolicy;
 use self::attribute::{AttributeSpecifier, AttributeVerifier};
 use builder::RETRY_LIMIT;
 use static_config::event::Deadline;
@@ -73,12 +75,19 @@ pub enum EventOpenError {
     DoesNotSupportRequestedMaxEventId,
     /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
     DoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] supports a smaller maximum
+    /// [`NotificationPayload`](crate::port::notification_payload::NotificationPayload) size
+    /// than requested.
+    DoesNotSupportRequestedNotificationPayloadSize,
     /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
     ExceedsMaxNumberOfNodes,
     /// The [`Service`] is marked for destruction and currently cleaning up since no one is using it anymore.
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process read-write access or
+    /// the [`AttributeVerifier`] does not require the attributes the [`AccessPolicy`] requires.
+    AccessDenied,
 }
 
 impl core::fmt::Display for EventOpenError {
@@ -194,6 +203,7 @@ pub struct Builder<ServiceType: service::Service> {
     verify_notifier_created_event: bool,
     verify_notifier_dropped_event: bool,
     verify_notifier_dead_event: bool,
+    verify_max_notification_payload_size: bool,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -208,6 +218,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_notifier_dead_event: false,
             verify_notifier_created_event: false,
             verify_notifier_dropped_event: false,
+            verify_max_notification_payload_size: false,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
@@ -254,6 +265,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines the [`AccessPolicy`] that is applied to all
+    /// underlying resources. If an existing [`Service`] is opened the setting is ignored and
+    /// the [`AccessPolicy`] of the existing [`Service`] is verified instead.
+    pub fn access_policy(mut self, value: &AccessPolicy) -> Self {
+        self.base.service_config.access_policy = value.clone().resolve();
+        self
+    }
+
     /// If the [`Service`] is created it set the greatest supported [`NodeId`] value
     /// If an existing [`Service`] is opened it defines the value size the [`NodeId`]
     /// must at least support.
@@ -329,6 +348,17 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines the maximum size in bytes of the
+    /// [`NotificationPayload`](crate::port::notification_payload::NotificationPayload) a
+    /// [`Notifier`](crate::port::notifier::Notifier) can attach to a notification. If an
+    /// existing [`Service`] is opened it defines the size that must be at least supported.
+    /// The value is bounded by [`MAX_NOTIFICATION_PAYLOAD_SIZE`].
+    pub fn max_notification_payload_size(mut self, value: usize) -> Self {
+        self.config_details().max_notification_payload_size = value;
+        self.verify_max_notification_payload_size = true;
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(self) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
@@ -514,6 +544,8 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_listeners: event_config.max_listeners,
                     number_of_notifiers: event_config.max_notifiers,
+                    number_of_event_ids: event_config.event_id_max_value.saturating_add(1),
+                    max_notification_payload_size: event_config.max_notification_payload_size,
                 };
 
                 let dynamic_config = match self.base.create_dynamic_config_storage(
@@ -549,7 +581,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 }
 
                 Ok(event::PortFactory::new(service::ServiceState::new(
-                    self.base.service_config.clone(),
+                    (*self.base.service_config).clone(),
                     self.base.shared_node.clone(),
                     dynamic_config,
                     unlocked_static_details,
@@ -581,6 +613,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             warn!(from origin, "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
+
+        if settings.max_notification_payload_size > MAX_NOTIFICATION_PAYLOAD_SIZE {
+            warn!(from origin, "The maximum notification payload size of {} exceeds the largest supported size. Adjust it to {}, the largest supported value.",
+                settings.max_notification_payload_size, MAX_NOTIFICATION_PAYLOAD_SIZE);
+            settings.max_notification_payload_size = MAX_NOTIFICATION_PAYLOAD_SIZE;
+        }
     }
 
     fn verify_service_configuration(
@@ -590,6 +628,15 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     ) -> Result<static_config::event::StaticConfig, EventOpenError> {
         let msg = "Unable to open event";
 
+        if !existing_settings
+            .access_policy()
+            .grants_access(required_attributes)
+        {
+            fail!(from self, with EventOpenError::AccessDenied,
+                "{} since the access policy {:?} of the service does not grant access.",
+                msg, existing_settings.access_policy());
+        }
+
         let existing_attributes = existing_settings.attributes();
         if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
         {
@@ -637,6 +684,15 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
 
+        if self.verify_max_notification_payload_size
+            && existing_settings.max_notification_payload_size
+                < required_settings.max_notification_payload_size
+        {
+            fail!(from self, with EventOpenError::DoesNotSupportRequestedNotificationPayloadSize,
+                "{} since the event supports notification payloads of at most {} bytes but a support of {} bytes was requested.",
+                msg, existing_settings.max_notification_payload_size, required_settings.max_notification_payload_size);
+        }
+
         if self.verify_notifier_created_event
             && existing_settings.notifier_created_event != required_settings.notifier_created_event
         {
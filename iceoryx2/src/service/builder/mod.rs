@@ -30,6 +30,7 @@ use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
 
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec;
@@ -224,7 +225,7 @@ impl<S: Service> Builder<S> {
 #[doc(hidden)]
 #[derive(Debug, Clone)]
 pub struct BuilderWithServiceType<ServiceType: service::Service> {
-    service_config: StaticConfig,
+    service_config: Box<StaticConfig>,
     shared_node: Arc<SharedNode<ServiceType>>,
     _phantom_data: PhantomData<ServiceType>,
 }
@@ -232,7 +233,7 @@ pub struct BuilderWithServiceType<ServiceType: service::Service> {
 impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
     fn new(service_config: StaticConfig, shared_node: Arc<SharedNode<ServiceType>>) -> Self {
         Self {
-            service_config,
+            service_config: Box::new(service_config),
             shared_node,
             _phantom_data: PhantomData,
         }
@@ -362,7 +363,9 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         >>::Builder<'_> as NamedConceptBuilder<
             ServiceType::DynamicStorage,
         >>::new(&self.service_config.service_id().0.into())
-            .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
+            .config(&self.service_config.access_policy.apply(
+                dynamic_config_storage_config::<ServiceType>(self.shared_node.config()),
+            ))
             .supplementary_size(additional_size + required_memory_size)
             .has_ownership(false)
             .initializer(Self::config_init_call)
@@ -497,8 +500,8 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             fail!(from self, when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
                         ServiceType::StaticStorage,
                     >>::new(&self.service_config.service_id().0.into())
-                    .config(&static_config_storage_config::<ServiceType>(
-                        self.shared_node.config(),
+                    .config(&self.service_config.access_policy.apply(
+                        static_config_storage_config::<ServiceType>(self.shared_node.config()),
                     ))
                     .has_ownership(true)
                     .create_locked(),
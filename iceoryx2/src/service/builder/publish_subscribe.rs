@@ -15,6 +15,7 @@
 //! See [`crate::service`]
 //!
 use core::marker::PhantomData;
+use core::time::Duration;
 
 use alloc::format;
 
@@ -25,18 +26,21 @@ use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::StaticStorageLocked;
 
+use crate::service::builder::event::EventCreateError;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::publish_subscribe;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::static_config::type_schema::TypeSchema;
 use crate::service::*;
 use crate::service::{self, dynamic_config::MessagingPatternSettings};
 
 use super::{CustomHeaderMarker, CustomPayloadMarker, OpenDynamicStorageFailure, ServiceState};
 
 use self::{
+    access_policy::AccessPolicy,
     attribute::{AttributeSpecifier, AttributeVerifier},
-    message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant},
+    message_type_details::{MessageTypeDetails, TypeDetail, TypeName, TypeVariant},
 };
 use builder::RETRY_LIMIT;
 
@@ -67,6 +71,10 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`]s lifespan settings are not equal to the user given requirements.
+    IncompatibleLifespan,
+    /// The [`Service`]s subscriber deadline settings are not equal to the user given requirements.
+    IncompatibleSubscriberDeadline,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
@@ -80,6 +88,9 @@ pub enum PublishSubscribeOpenError {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process read-write access or
+    /// the [`AttributeVerifier`] does not require the attributes the [`AccessPolicy`] requires.
+    AccessDenied,
 }
 
 impl core::fmt::Display for PublishSubscribeOpenError {
@@ -132,6 +143,11 @@ pub enum PublishSubscribeCreateError {
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
+    /// The [`TypeSchema`] of a type exceeds
+    /// [`MAX_TYPE_SCHEMA_FIELDS`](crate::constants::MAX_TYPE_SCHEMA_FIELDS),
+    /// [`MAX_FIELD_NAME_LENGTH`](crate::constants::MAX_FIELD_NAME_LENGTH) or
+    /// [`MAX_TYPE_SCHEMA_NAMES_LENGTH`](crate::constants::MAX_TYPE_SCHEMA_NAMES_LENGTH).
+    TypeSchemaExceedsLimits,
 }
 
 impl core::fmt::Display for PublishSubscribeCreateError {
@@ -222,6 +238,7 @@ pub struct Builder<
     override_alignment: Option<usize>,
     override_payload_type: Option<TypeDetail>,
     override_user_header_type: Option<TypeDetail>,
+    override_serializer: Option<TypeName>,
     verify_number_of_subscribers: bool,
     verify_number_of_publishers: bool,
     verify_subscriber_max_buffer_size: bool,
@@ -229,6 +246,8 @@ pub struct Builder<
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
     verify_max_nodes: bool,
+    verify_lifespan: bool,
+    verify_subscriber_deadline: bool,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -245,6 +264,7 @@ impl<
             override_alignment: self.override_alignment,
             override_payload_type: self.override_payload_type.clone(),
             override_user_header_type: self.override_user_header_type.clone(),
+            override_serializer: self.override_serializer,
             verify_number_of_subscribers: self.verify_number_of_subscribers,
             verify_number_of_publishers: self.verify_number_of_publishers,
             verify_subscriber_max_buffer_size: self.verify_subscriber_max_buffer_size,
@@ -252,6 +272,8 @@ impl<
             verify_publisher_history_size: self.verify_publisher_history_size,
             verify_enable_safe_overflow: self.verify_enable_safe_overflow,
             verify_max_nodes: self.verify_max_nodes,
+            verify_lifespan: self.verify_lifespan,
+            verify_subscriber_deadline: self.verify_subscriber_deadline,
             _data: PhantomData,
             _user_header: PhantomData,
         }
@@ -274,9 +296,12 @@ impl<
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
             verify_max_nodes: false,
+            verify_lifespan: false,
+            verify_subscriber_deadline: false,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
+            override_serializer: None,
             _data: PhantomData,
             _user_header: PhantomData,
         };
@@ -406,6 +431,52 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created it defines the [`AccessPolicy`] that is applied to all
+    /// underlying resources. If an existing [`Service`] is opened the setting is ignored and
+    /// the [`AccessPolicy`] of the existing [`Service`] is verified instead.
+    pub fn access_policy(mut self, value: &AccessPolicy) -> Self {
+        self.base.service_config.access_policy = value.clone().resolve();
+        self
+    }
+
+    /// Enables the lifespan property of the service. A [`crate::sample::Sample`] that was not
+    /// received by a [`crate::port::subscriber::Subscriber`] within the provided `lifespan`
+    /// after it was sent is dropped instead of delivered. This includes the history that is
+    /// delivered to [`crate::port::subscriber::Subscriber`]s that connect later.
+    pub fn lifespan(mut self, lifespan: Duration) -> Self {
+        self.config_details_mut().lifespan = Some(lifespan);
+        self.verify_lifespan = true;
+        self
+    }
+
+    /// Disables the lifespan property of the service. [`crate::sample::Sample`]s are delivered
+    /// regardless of their age.
+    pub fn disable_lifespan(mut self) -> Self {
+        self.config_details_mut().lifespan = None;
+        self.verify_lifespan = true;
+        self
+    }
+
+    /// Enables the subscriber deadline property of the service. The
+    /// [`crate::port::subscriber::Subscriber::deadline_listener()`] can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet) with
+    /// [`WaitSet::attach_deadline()`](crate::waitset::WaitSet::attach_deadline()) that reports
+    /// when no new [`crate::sample::Sample`] arrived after at least the provided `deadline`.
+    /// The [`Service`] owns an event service with the same name that is used to signal the
+    /// arrival of new [`crate::sample::Sample`]s.
+    pub fn subscriber_deadline(mut self, deadline: Duration) -> Self {
+        self.config_details_mut().subscriber_deadline = Some(deadline);
+        self.verify_subscriber_deadline = true;
+        self
+    }
+
+    /// Disables the subscriber deadline property of the service.
+    pub fn disable_subscriber_deadline(mut self) -> Self {
+        self.config_details_mut().subscriber_deadline = None;
+        self.verify_subscriber_deadline = true;
+        self
+    }
+
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_configuration_to_meaningful_values(&mut self) {
         let origin = format!("{self:?}");
@@ -449,6 +520,12 @@ impl<
     ) -> Result<static_config::publish_subscribe::StaticConfig, PublishSubscribeOpenError> {
         let msg = "Unable to open publish subscribe service";
 
+        if !existing_settings.access_policy().grants_access(verifier) {
+            fail!(from self, with PublishSubscribeOpenError::AccessDenied,
+                "{} since the access policy {:?} of the service does not grant access.",
+                msg, existing_settings.access_policy());
+        }
+
         let existing_attributes = existing_settings.attributes();
         if let Err(incompatible_key) = verifier.verify_requirements(existing_attributes) {
             fail!(from self, with PublishSubscribeOpenError::IncompatibleAttributes,
@@ -521,6 +598,20 @@ impl<
                                 msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
 
+        if self.verify_lifespan && existing_settings.lifespan != required_settings.lifespan {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleLifespan,
+                "{} since the lifespan is {:?} but a lifespan of {:?} is required.",
+                msg, existing_settings.lifespan, required_settings.lifespan);
+        }
+
+        if self.verify_subscriber_deadline
+            && existing_settings.subscriber_deadline != required_settings.subscriber_deadline
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleSubscriberDeadline,
+                "{} since the subscriber deadline is {:?} but a subscriber deadline of {:?} is required.",
+                msg, existing_settings.subscriber_deadline, required_settings.subscriber_deadline);
+        }
+
         Ok(existing_settings.clone())
     }
 
@@ -543,6 +634,15 @@ impl<
                 "{} since the history size is greater than the subscriber buffer size. The subscriber buffer size must be always greater or equal to the history size in the non-overflowing setup.", msg);
         }
 
+        if self
+            .config_details()
+            .message_type_details
+            .exceeds_type_schema_limits()
+        {
+            fail!(from self, with PublishSubscribeCreateError::TypeSchemaExceedsLimits,
+                "{} since the type schema of the payload or user header exceeds its limits.", msg);
+        }
+
         match self.is_service_available(msg)? {
             None => {
                 let service_tag = self
@@ -596,6 +696,43 @@ impl<
                     }
                 };
 
+                // create the event service that signals the arrival of new samples
+                let deadline_event_service = if pubsub_config.subscriber_deadline.is_some() {
+                    match builder::Builder::new(
+                        self.base.service_config.name(),
+                        self.base.shared_node.clone(),
+                    )
+                    .event()
+                    .access_policy(
+                        &self
+                            .base
+                            .service_config
+                            .access_policy
+                            .without_attribute_requirements(),
+                    )
+                    .max_nodes(pubsub_config.max_nodes)
+                    .max_notifiers(pubsub_config.max_publishers)
+                    .max_listeners(pubsub_config.max_subscribers)
+                    .disable_deadline()
+                    .disable_notifier_created_event()
+                    .disable_notifier_dropped_event()
+                    .disable_notifier_dead_event()
+                    .create()
+                    {
+                        Ok(v) => Some(v),
+                        Err(EventCreateError::AlreadyExists) => {
+                            fail!(from self, with PublishSubscribeCreateError::AlreadyExists,
+                                "{} since the corresponding event service already exists.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with PublishSubscribeCreateError::InternalFailure,
+                                "{} since the corresponding event service could not be created ({:?}).", msg, e);
+                        }
+                    }
+                } else {
+                    None
+                };
+
                 self.base.service_config.attributes = attributes.0.clone();
                 let service_config = fail!(from self,
                             when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
@@ -614,12 +751,13 @@ impl<
 
                 Ok(publish_subscribe::PortFactory::new(
                     service::ServiceState::new(
-                        self.base.service_config.clone(),
+                        (*self.base.service_config).clone(),
                         self.base.shared_node.clone(),
                         dynamic_config,
                         unlocked_static_details,
                         NoResource,
                     ),
+                    deadline_event_service,
                 ))
             }
             Some(_) => {
@@ -687,6 +825,28 @@ impl<
                         }
                     };
 
+                    let deadline_event_service = if pub_sub_static_config
+                        .subscriber_deadline
+                        .is_some()
+                    {
+                        match builder::Builder::new(
+                            static_config.name(),
+                            self.base.shared_node.clone(),
+                        )
+                        .event()
+                        .open()
+                        {
+                            Ok(v) => Some(v),
+                            Err(e) => {
+                                fail!(from self, with PublishSubscribeOpenError::ServiceInCorruptedState,
+                                    "{} since the corresponding event service could not be opened ({:?}).",
+                                    msg, e);
+                            }
+                        }
+                    } else {
+                        None
+                    };
+
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::PublishSubscribe(pub_sub_static_config.clone());
 
@@ -702,6 +862,7 @@ impl<
                             static_storage,
                             NoResource,
                         ),
+                        deadline_event_service,
                     ));
                 }
             }
@@ -774,6 +935,40 @@ impl<UserHeader: Debug + ZeroCopySend, ServiceType: service::Service>
     }
 }
 
+impl<UserHeader: Debug + ZeroCopySend, ServiceType: service::Service>
+    Builder<[u8], UserHeader, ServiceType>
+{
+    /// Defines that the payload is a `T` that is serialized with the [`Serialize`]
+    /// implementation `S` into a slice of bytes. Since the payload is copied into the
+    /// [`Sample`](crate::sample::Sample), `T` does not have to be [`ZeroCopySend`], it only has to
+    /// implement [`serde::Serialize`] and [`serde::de::DeserializeOwned`].
+    ///
+    /// The name of `T` and `S` are stored in the [`MessageTypeDetails`] of the [`Service`], so
+    /// opening the [`Service`] with another payload type or another [`Serialize`] implementation
+    /// fails. The payload can be sent and received with the
+    /// [`SerializedPublisher`](crate::serialized::SerializedPublisher) and the
+    /// [`SerializedSubscriber`](crate::serialized::SerializedSubscriber).
+    pub fn serialized_payload<T: serde::Serialize + serde::de::DeserializeOwned, S: Serialize>(
+        mut self,
+    ) -> Self {
+        self.override_payload_type = Some(TypeDetail {
+            variant: TypeVariant::Dynamic,
+            type_name: serialized_type_name::<T>(),
+            size: core::mem::size_of::<u8>(),
+            alignment: core::mem::align_of::<u8>(),
+            schema: TypeSchema::default(),
+        });
+        self.override_serializer = Some(serialized_type_name::<S>());
+        self
+    }
+}
+
+fn serialized_type_name<T: ?Sized>() -> TypeName {
+    fatal_panic!(from "publish_subscribe::Builder::serialized_payload()",
+        when TypeName::try_from(core::any::type_name::<T>()),
+        "The type name of {} does not fit into fixed-size TypeName", core::any::type_name::<T>())
+}
+
 impl<Payload: Debug + ?Sized + ZeroCopySend, ServiceType: service::Service>
     Builder<Payload, CustomHeaderMarker, ServiceType>
 {
@@ -893,6 +1088,10 @@ impl<
             self.config_details_mut().message_type_details.payload = details.clone();
         }
 
+        if let Some(serializer) = &self.override_serializer {
+            self.config_details_mut().message_type_details.serializer = *serializer;
+        }
+
         if let Some(details) = &self.override_user_header_type {
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
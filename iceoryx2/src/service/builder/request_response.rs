@@ -23,7 +23,10 @@ use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::{StaticStorage, StaticStorageCreateError, StaticStorageLocked};
 
 use crate::prelude::{AttributeSpecifier, AttributeVerifier};
+use crate::service::access_policy::AccessPolicy;
+use crate::service::builder::event::EventCreateError;
 use crate::service::builder::OpenDynamicStorageFailure;
+use crate::service::dispatch_policy::DispatchPolicy;
 use crate::service::dynamic_config::request_response::DynamicConfigSettings;
 use crate::service::dynamic_config::MessagingPatternSettings;
 use crate::service::port_factory::request_response;
@@ -75,6 +78,10 @@ pub enum RequestResponseOpenError {
     IncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     IncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] does not support the required behavior for request cancellation.
+    IncompatibleRequestCancellation,
+    /// The [`Service`] has an incompatible [`DispatchPolicy`].
+    IncompatibleDispatchPolicy,
     /// The process has not enough permissions to open the [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -85,6 +92,9 @@ pub enum RequestResponseOpenError {
     IsMarkedForDestruction,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
+    /// The [`AccessPolicy`] of the [`Service`] does not grant the process read-write access or
+    /// the [`AttributeVerifier`] does not require the attributes the [`AccessPolicy`] requires.
+    AccessDenied,
 }
 
 impl core::fmt::Display for RequestResponseOpenError {
@@ -136,6 +146,11 @@ pub enum RequestResponseCreateError {
     HangsInCreation,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
+    /// The [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema) of a type
+    /// exceeds [`MAX_TYPE_SCHEMA_FIELDS`](crate::constants::MAX_TYPE_SCHEMA_FIELDS),
+    /// [`MAX_FIELD_NAME_LENGTH`](crate::constants::MAX_FIELD_NAME_LENGTH) or
+    /// [`MAX_TYPE_SCHEMA_NAMES_LENGTH`](crate::constants::MAX_TYPE_SCHEMA_NAMES_LENGTH).
+    TypeSchemaExceedsLimits,
 }
 
 impl core::fmt::Display for RequestResponseCreateError {
@@ -243,6 +258,8 @@ pub struct Builder<
     verify_max_nodes: bool,
     verify_max_borrowed_responses_per_pending_response: bool,
     verify_enable_fire_and_forget_requests: bool,
+    verify_enable_request_cancellation: bool,
+    verify_dispatch_policy: bool,
 
     _request_payload: PhantomData<RequestPayload>,
     _request_header: PhantomData<RequestHeader>,
@@ -280,6 +297,8 @@ impl<
             verify_max_borrowed_responses_per_pending_response: self
                 .verify_max_borrowed_responses_per_pending_response,
             verify_enable_fire_and_forget_requests: self.verify_enable_fire_and_forget_requests,
+            verify_enable_request_cancellation: self.verify_enable_request_cancellation,
+            verify_dispatch_policy: self.verify_dispatch_policy,
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -315,6 +334,8 @@ impl<
             verify_max_nodes: false,
             verify_max_borrowed_responses_per_pending_response: false,
             verify_enable_fire_and_forget_requests: false,
+            verify_enable_request_cancellation: false,
+            verify_dispatch_policy: false,
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -409,6 +430,35 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if request cancellation is signaled or not.
+    /// When it is enabled, a [`Client`](crate::port::client::Client) notifies all
+    /// [`Server`](crate::port::server::Server)s when a
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) is dropped or times out
+    /// while a [`Server`](crate::port::server::Server) still holds the corresponding
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest). The
+    /// [`Server`](crate::port::server::Server) can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet) to wake up on this signal.
+    /// The [`Service`] owns an event service with the same name that is used for the
+    /// signaling.
+    /// If an existing [`Service`] is opened it requires the service to have the defined
+    /// request cancellation behavior.
+    pub fn enable_request_cancellation(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_request_cancellation = value;
+        self.verify_enable_request_cancellation = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines the [`DispatchPolicy`] that decides to which
+    /// [`Server`](crate::port::server::Server)s a [`Client`](crate::port::client::Client)
+    /// delivers a [`RequestMut`](crate::request_mut::RequestMut).
+    /// If an existing [`Service`] is opened it requires the service to have the defined
+    /// [`DispatchPolicy`].
+    pub fn dispatch_policy(mut self, value: DispatchPolicy) -> Self {
+        self.config_details_mut().dispatch_policy = value;
+        self.verify_dispatch_policy = true;
+        self
+    }
+
     /// Defines how many active requests a [`Server`](crate::port::server::Server) can hold in
     /// parallel per [`Client`](crate::port::client::Client). The objects are used to send answers to a request that was received earlier
     /// from a [`Client`](crate::port::client::Client)
@@ -461,6 +511,14 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created it defines the [`AccessPolicy`] that is applied to all
+    /// underlying resources. If an existing [`Service`] is opened the setting is ignored and
+    /// the [`AccessPolicy`] of the existing [`Service`] is verified instead.
+    pub fn access_policy(mut self, value: &AccessPolicy) -> Self {
+        self.base.service_config.access_policy = value.clone().resolve();
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`Response`](crate::response::Response)s shall
     /// be able to be borrowed in parallel per [`PendingResponse`](crate::pending_response::PendingResponse). If an existing [`Service`] is opened it defines how many
     /// borrows must be at least supported.
@@ -525,6 +583,12 @@ impl<
     ) -> Result<static_config::request_response::StaticConfig, RequestResponseOpenError> {
         let msg = "Unable to open request response service";
 
+        if !existing_settings.access_policy().grants_access(verifier) {
+            fail!(from self, with RequestResponseOpenError::AccessDenied,
+                "{} since the access policy {:?} of the service does not grant access.",
+                msg, existing_settings.access_policy());
+        }
+
         let existing_attributes = existing_settings.attributes();
         if let Err(incompatible_key) = verifier.verify_requirements(existing_attributes) {
             fail!(from self, with RequestResponseOpenError::IncompatibleAttributes,
@@ -569,6 +633,23 @@ impl<
                 msg);
         }
 
+        if self.verify_enable_request_cancellation
+            && existing_configuration.enable_request_cancellation
+                != required_configuration.enable_request_cancellation
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleRequestCancellation,
+                "{} since the service has an incompatible behavior for request cancellation.",
+                msg);
+        }
+
+        if self.verify_dispatch_policy
+            && existing_configuration.dispatch_policy != required_configuration.dispatch_policy
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleDispatchPolicy,
+                "{} since the service has the dispatch policy {:?} but {:?} is required.",
+                msg, existing_configuration.dispatch_policy, required_configuration.dispatch_policy);
+        }
+
         if self.verify_max_active_requests_per_client
             && existing_configuration.max_active_requests_per_client
                 < required_configuration.max_active_requests_per_client
@@ -686,6 +767,19 @@ impl<
         let msg = "Unable to create request response service";
         self.adjust_configuration_to_meaningful_values();
 
+        if self
+            .config_details()
+            .request_message_type_details
+            .exceeds_type_schema_limits()
+            || self
+                .config_details()
+                .response_message_type_details
+                .exceeds_type_schema_limits()
+        {
+            fail!(from self, with RequestResponseCreateError::TypeSchemaExceedsLimits,
+                "{} since the type schema of the request or response exceeds its limits.", msg);
+        }
+
         match self.is_service_available(msg)? {
             Some(_) => {
                 fail!(from self, with RequestResponseCreateError::AlreadyExists,
@@ -745,6 +839,45 @@ impl<
                     }
                 };
 
+                // create the event service that signals the cancellation of requests
+                let cancellation_event_service = if request_response_config
+                    .enable_request_cancellation
+                {
+                    match builder::Builder::new(
+                        self.base.service_config.name(),
+                        self.base.shared_node.clone(),
+                    )
+                    .event()
+                    .access_policy(
+                        &self
+                            .base
+                            .service_config
+                            .access_policy
+                            .without_attribute_requirements(),
+                    )
+                    .max_nodes(request_response_config.max_nodes)
+                    .max_notifiers(request_response_config.max_clients)
+                    .max_listeners(request_response_config.max_servers)
+                    .disable_deadline()
+                    .disable_notifier_created_event()
+                    .disable_notifier_dropped_event()
+                    .disable_notifier_dead_event()
+                    .create()
+                    {
+                        Ok(v) => Some(v),
+                        Err(EventCreateError::AlreadyExists) => {
+                            fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                                "{} since the corresponding event service already exists.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with RequestResponseCreateError::InternalFailure,
+                                "{} since the corresponding event service could not be created ({:?}).", msg, e);
+                        }
+                    }
+                } else {
+                    None
+                };
+
                 self.base.service_config.attributes = attributes.0.clone();
                 let serialized_service_config = fail!(from self,
                           when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
@@ -765,12 +898,13 @@ impl<
 
                 Ok(request_response::PortFactory::new(
                     service::ServiceState::new(
-                        self.base.service_config.clone(),
+                        (*self.base.service_config).clone(),
                         self.base.shared_node.clone(),
                         dynamic_config,
                         unlocked_static_details,
                         NoResource,
                     ),
+                    cancellation_event_service,
                 ))
             }
         }
@@ -845,6 +979,27 @@ impl<
                         }
                     };
 
+                    let cancellation_event_service = if request_response_static_config
+                        .enable_request_cancellation
+                    {
+                        match builder::Builder::new(
+                            static_config.name(),
+                            self.base.shared_node.clone(),
+                        )
+                        .event()
+                        .open()
+                        {
+                            Ok(v) => Some(v),
+                            Err(e) => {
+                                fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                                    "{} since the corresponding event service could not be opened ({:?}).",
+                                    msg, e);
+                            }
+                        }
+                    } else {
+                        None
+                    };
+
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::RequestResponse(request_response_static_config.clone());
 
@@ -860,6 +1015,7 @@ impl<
                             static_storage,
                             NoResource,
                         ),
+                        cancellation_event_service,
                     ));
                 }
             }
@@ -0,0 +1,94 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`DispatchPolicy`](crate::service::dispatch_policy::DispatchPolicy) defines to which
+//! [`Server`](crate::port::server::Server)s a [`Client`](crate::port::client::Client) delivers a
+//! [`RequestMut`](crate::request_mut::RequestMut). It is provided when the
+//! [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+//! based [`Service`](crate::service::Service) is created and stored in its static config.
+//!
+//! With [`DispatchPolicy::Broadcast`](crate::service::dispatch_policy::DispatchPolicy::Broadcast),
+//! the default, every request is delivered to all connected
+//! [`Server`](crate::port::server::Server)s. All other policies deliver a request to exactly
+//! one [`Server`](crate::port::server::Server) so that a pool of identical
+//! [`Server`](crate::port::server::Server)s can share the work.
+//!
+//! When the [`Server`](crate::port::server::Server) a request was dispatched to disconnects
+//! before it has sent a [`Response`](crate::response::Response), the
+//! [`PendingResponse`](crate::pending_response::PendingResponse) dispatches the request again
+//! to another [`Server`](crate::port::server::Server). If there is none, it fails with
+//! [`ReceiveError::ServerDisconnected`](crate::port::ReceiveError::ServerDisconnected).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::dispatch_policy::DispatchPolicy;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .dispatch_policy(DispatchPolicy::LeastOutstandingRequests)
+//!     .create()?;
+//!
+//! let worker_1 = service.server_builder().create()?;
+//! let worker_2 = service.server_builder().create()?;
+//! let client = service.client_builder().create()?;
+//!
+//! let pending_response = client.send_copy(1234)?;
+//! // the request was delivered to exactly one of the workers
+//! assert_eq!(pending_response.number_of_server_connections(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use serde::{Deserialize, Serialize};
+
+/// Defines to which [`Server`](crate::port::server::Server)s a
+/// [`Client`](crate::port::client::Client) delivers a
+/// [`RequestMut`](crate::request_mut::RequestMut).
+#[repr(C)]
+#[derive(
+    Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
+)]
+pub enum DispatchPolicy {
+    /// Every request is delivered to all connected [`Server`](crate::port::server::Server)s.
+    #[default]
+    Broadcast,
+    /// Every request is delivered to exactly one [`Server`](crate::port::server::Server). The
+    /// [`Client`](crate::port::client::Client) selects the connected
+    /// [`Server`](crate::port::server::Server)s in turns.
+    RoundRobin,
+    /// Every request is delivered to exactly one [`Server`](crate::port::server::Server), the
+    /// one with the least number of outstanding requests of all
+    /// [`Client`](crate::port::client::Client)s. A request is outstanding from the moment it was
+    /// dispatched until the [`Server`](crate::port::server::Server) drops the corresponding
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest).
+    LeastOutstandingRequests,
+    /// Every request is delivered to exactly one [`Server`](crate::port::server::Server). A
+    /// [`Client`](crate::port::client::Client) selects the
+    /// [`Server`](crate::port::server::Server) with the least number of outstanding requests
+    /// once and sends all further requests to it until it disconnects.
+    StickyPerClient,
+}
+
+impl DispatchPolicy {
+    /// Returns [`true`] when a request is delivered to exactly one
+    /// [`Server`](crate::port::server::Server), otherwise [`false`].
+    pub fn dispatches_to_single_server(&self) -> bool {
+        *self != DispatchPolicy::Broadcast
+    }
+}
@@ -66,6 +66,23 @@ pub struct WriterDetails {
     /// The [`NodeId`] of the [`Node`](crate::node::Node) under which the
     /// [`Writer`](crate::port::writer::Writer) was created.
     pub node_id: NodeId,
+    ownership_token: u64,
+}
+
+impl WriterDetails {
+    pub(crate) fn new(writer_id: UniqueWriterId, node_id: NodeId, ownership_token: u64) -> Self {
+        Self {
+            writer_id,
+            node_id,
+            ownership_token,
+        }
+    }
+
+    /// Returns the token with which the [`Writer`](crate::port::writer::Writer) claims the
+    /// ownership of entries.
+    pub(crate) fn ownership_token(&self) -> u64 {
+        self.ownership_token
+    }
 }
 
 /// The dynamic configuration of an
@@ -177,4 +194,19 @@ impl DynamicConfig {
     pub(crate) fn release_writer_handle(&self, handle: ContainerHandle) {
         unsafe { self.writers.remove(handle, ReleaseMode::Default) };
     }
+
+    /// Returns true when a [`Writer`](crate::port::writer::Writer) with the given ownership
+    /// token is connected to the service.
+    pub(crate) fn has_writer_with_ownership_token(&self, ownership_token: u64) -> bool {
+        let mut has_writer = false;
+        self.list_writers(|details| {
+            if details.ownership_token() == ownership_token {
+                has_writer = true;
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+        has_writer
+    }
 }
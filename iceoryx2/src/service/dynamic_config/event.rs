@@ -26,15 +26,24 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_container::vector::relocatable_vec::RelocatableVec;
+use iceoryx2_bb_container::vector::Vector;
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_cal::event::TriggerId;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::{
     node::NodeId,
-    port::port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId},
+    port::{
+        event_id::EventId,
+        notification_payload::{NotificationPayload, MAX_NOTIFICATION_PAYLOAD_SIZE},
+        port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId},
+    },
 };
 
 use super::PortCleanupAction;
@@ -44,8 +53,43 @@ use super::PortCleanupAction;
 pub(crate) struct DynamicConfigSettings {
     pub number_of_listeners: usize,
     pub number_of_notifiers: usize,
+    pub number_of_event_ids: usize,
+    pub max_notification_payload_size: usize,
+}
+
+impl DynamicConfigSettings {
+    fn notification_payload_slot_size(&self) -> usize {
+        if self.max_notification_payload_size == 0 {
+            return 0;
+        }
+
+        // sequence counter + payload length + payload data
+        2 + self
+            .max_notification_payload_size
+            .div_ceil(PAYLOAD_WORD_SIZE)
+    }
+
+    fn number_of_notification_payload_slots(&self) -> usize {
+        if self.max_notification_payload_size == 0 {
+            return 0;
+        }
+
+        self.number_of_notifiers
+            .saturating_mul(self.number_of_event_ids)
+    }
+
+    fn number_of_notification_payload_words(&self) -> usize {
+        self.number_of_notification_payload_slots()
+            .saturating_mul(self.notification_payload_slot_size())
+    }
 }
 
+const PAYLOAD_WORD_SIZE: usize = core::mem::size_of::<u64>();
+// a notification payload slot is only occupied for the duration of a copy of at most
+// MAX_NOTIFICATION_PAYLOAD_SIZE bytes, a slot that stays occupied for longer belongs most likely
+// to a notifier that died while writing
+const MAX_NOTIFICATION_PAYLOAD_ACCESS_RETRIES: u64 = 1_000_000;
+
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
 /// based service. Contains dynamic parameters like the connected endpoints etc..
 #[repr(C)]
@@ -54,6 +98,12 @@ pub struct DynamicConfig {
     pub(crate) listeners: Container<ListenerDetails>,
     pub(crate) notifiers: Container<NotifierDetails>,
     pub(crate) elapsed_time_since_last_notification: IoxAtomicU64,
+    // one seqlock protected slot per notifier and event id containing the payload of the latest
+    // notification, it is written only by the notifier that owns it
+    notification_payloads: RelocatableVec<IoxAtomicU64>,
+    notification_payload_slot_size: usize,
+    number_of_notification_payload_slots: usize,
+    number_of_event_ids: usize,
 }
 
 /// Contains the communication settings of the connected
@@ -86,6 +136,12 @@ impl DynamicConfig {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
             elapsed_time_since_last_notification: IoxAtomicU64::new(0),
+            notification_payloads: unsafe {
+                RelocatableVec::new_uninit(config.number_of_notification_payload_words())
+            },
+            notification_payload_slot_size: config.notification_payload_slot_size(),
+            number_of_notification_payload_slots: config.number_of_notification_payload_slots(),
+            number_of_event_ids: config.number_of_event_ids,
         }
     }
 
@@ -96,11 +152,24 @@ impl DynamicConfig {
         fatal_panic!(from "event::DynamicConfig::init",
             when self.notifiers.init(allocator),
             "This should never happen! Unable to initialize notifier port id container.");
+
+        if self.notification_payloads.capacity() != 0 {
+            fatal_panic!(from "event::DynamicConfig::init",
+                when self.notification_payloads.init(allocator),
+                "This should never happen! Unable to initialize notification payload slots.");
+            for _ in 0..self.notification_payloads.capacity() {
+                self.notification_payloads
+                    .push_unchecked(IoxAtomicU64::new(0));
+            }
+        }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<ListenerDetails>::memory_size(config.number_of_listeners)
             + Container::<NotifierDetails>::memory_size(config.number_of_notifiers)
+            + RelocatableVec::<IoxAtomicU64>::memory_size(
+                config.number_of_notification_payload_words(),
+            )
     }
 
     /// Returns how many [`Listener`](crate::port::listener::Listener) ports are currently connected.
@@ -186,6 +255,132 @@ impl DynamicConfig {
     }
 
     pub(crate) fn release_notifier_handle(&self, handle: ContainerHandle) {
+        // the slots must be reclaimed before the handle can be acquired by another notifier
+        self.reclaim_notification_payload_slots(handle);
         unsafe { self.notifiers.remove(handle, ReleaseMode::Default) };
     }
+
+    /// Returns the largest [`TriggerId`] that a notification of the service can use. Payload
+    /// notifications use the [`TriggerId`]s above the [`EventId`]s to identify the payload slot
+    /// of the notifier.
+    pub(crate) fn trigger_id_max(&self) -> TriggerId {
+        TriggerId::new(
+            self.number_of_event_ids
+                .saturating_add(self.number_of_notification_payload_slots)
+                .saturating_sub(1),
+        )
+    }
+
+    /// Splits the [`TriggerId`] of a received notification into the [`EventId`] and the index of
+    /// the payload slot. Notifications without payload have no payload slot.
+    pub(crate) fn split_trigger_id(&self, trigger_id: TriggerId) -> (EventId, Option<usize>) {
+        match trigger_id.as_value().checked_sub(self.number_of_event_ids) {
+            Some(slot_index) if self.notification_payload_slot_size != 0 => (
+                EventId::new(slot_index % self.number_of_event_ids),
+                Some(slot_index),
+            ),
+            _ => (trigger_id, None),
+        }
+    }
+
+    pub(crate) fn number_of_notification_payload_slots(&self) -> usize {
+        self.number_of_notification_payload_slots
+    }
+
+    /// Stores the payload in the slot of the notifier and the [`EventId`] and returns the
+    /// [`TriggerId`] with which the listeners must be notified. Only the notifier that owns the
+    /// `notifier_handle` is allowed to write into its slots.
+    pub(crate) fn write_notification_payload(
+        &self,
+        notifier_handle: ContainerHandle,
+        event_id: EventId,
+        payload: &[u8],
+    ) -> TriggerId {
+        let slot_index =
+            notifier_handle.index() as usize * self.number_of_event_ids + event_id.as_value();
+        let slot = match self.notification_payload_slot(slot_index) {
+            Some(slot) => slot,
+            None => return event_id,
+        };
+        let sequence = &slot[0];
+
+        // an odd sequence number marks a slot that is currently written
+        let current = sequence.load(Ordering::Relaxed) | 1;
+        sequence.store(current, Ordering::Relaxed);
+        core::sync::atomic::fence(Ordering::Release);
+
+        slot[1].store(payload.len() as u64, Ordering::Relaxed);
+        for (word, chunk) in slot[2..].iter().zip(payload.chunks(PAYLOAD_WORD_SIZE)) {
+            let mut bytes = [0u8; PAYLOAD_WORD_SIZE];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            word.store(u64::from_le_bytes(bytes), Ordering::Relaxed);
+        }
+
+        sequence.store(current + 1, Ordering::Release);
+        TriggerId::new(self.number_of_event_ids + slot_index)
+    }
+
+    /// Reads the payload of a payload slot together with the sequence number of the write.
+    /// Returns [`None`] when the slot stayed occupied by a writer.
+    pub(crate) fn read_notification_payload(
+        &self,
+        slot_index: usize,
+    ) -> Option<(u64, NotificationPayload)> {
+        let slot = match self.notification_payload_slot(slot_index) {
+            Some(slot) => slot,
+            None => return Some((0, NotificationPayload::default())),
+        };
+        let sequence = &slot[0];
+
+        for _ in 0..MAX_NOTIFICATION_PAYLOAD_ACCESS_RETRIES {
+            let start = sequence.load(Ordering::Acquire);
+            if start % 2 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+
+            let len = slot[1].load(Ordering::Relaxed) as usize;
+            let mut data = [0u8; MAX_NOTIFICATION_PAYLOAD_SIZE];
+            for (word, chunk) in slot[2..].iter().zip(data.chunks_mut(PAYLOAD_WORD_SIZE)) {
+                let bytes = word.load(Ordering::Relaxed).to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+
+            core::sync::atomic::fence(Ordering::Acquire);
+            if sequence.load(Ordering::Relaxed) == start {
+                return Some((start, NotificationPayload::from_raw_parts(len, data)));
+            }
+        }
+
+        None
+    }
+
+    // a notifier that died while writing leaves its slot occupied, the sequence numbers are
+    // never reset so that a listener does not mistake the next payload for an already
+    // received one
+    fn reclaim_notification_payload_slots(&self, notifier_handle: ContainerHandle) {
+        let first_slot = notifier_handle.index() as usize * self.number_of_event_ids;
+        for slot_index in first_slot..first_slot + self.number_of_event_ids {
+            let slot = match self.notification_payload_slot(slot_index) {
+                Some(slot) => slot,
+                None => return,
+            };
+
+            let sequence = slot[0].load(Ordering::Relaxed);
+            if sequence % 2 == 1 {
+                slot[1].store(0, Ordering::Relaxed);
+                slot[0].store(sequence + 1, Ordering::Release);
+            }
+        }
+    }
+
+    fn notification_payload_slot(&self, slot_index: usize) -> Option<&[IoxAtomicU64]> {
+        if self.number_of_notification_payload_slots <= slot_index {
+            return None;
+        }
+
+        let start = slot_index * self.notification_payload_slot_size;
+        self.notification_payloads
+            .get(start..start + self.notification_payload_slot_size)
+    }
 }
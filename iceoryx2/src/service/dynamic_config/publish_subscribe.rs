@@ -34,7 +34,7 @@ use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 use crate::{
     node::NodeId,
     port::{
-        details::data_segment::DataSegmentType,
+        details::data_segment::{DataSegmentPlacement, DataSegmentType},
         port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
     },
 };
@@ -66,10 +66,17 @@ pub struct PublisherDetails {
     /// The type of data segment the [`Publisher`](crate::port::publisher::Publisher)
     /// has.
     pub data_segment_type: DataSegmentType,
+    /// Defines where the data segment of the
+    /// [`Publisher`](crate::port::publisher::Publisher) is placed in memory.
+    pub data_segment_placement: DataSegmentPlacement,
     /// If the [`Publisher`](crate::port::publisher::Publisher) has the
     /// [`DataSegmentType::Dynamic`] it defines how many segment the
     /// [`Publisher`](crate::port::publisher::Publisher) can have at most.
     pub max_number_of_segments: u8,
+    /// The priority with which the [`Sample`](crate::sample::Sample)s of the
+    /// [`Publisher`](crate::port::publisher::Publisher) are received. Higher values are
+    /// received first.
+    pub priority: u8,
 }
 
 /// Contains the communication settings of the connected
@@ -10,16 +10,21 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::sync::atomic::Ordering;
+
 use iceoryx2_bb_container::queue::RelocatableContainer;
+use iceoryx2_bb_container::vector::relocatable_vec::RelocatableVec;
+use iceoryx2_bb_container::vector::Vector;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{Container, ContainerHandle, ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 use crate::{
     node::NodeId,
     port::{
-        details::data_segment::DataSegmentType,
+        details::data_segment::{DataSegmentPlacement, DataSegmentType},
         port_identifiers::{UniqueClientId, UniquePortId, UniqueServerId},
     },
 };
@@ -46,6 +51,9 @@ pub struct ServerDetails {
     /// The type of data segment the [`Server`](crate::port::server::Server)
     /// uses.
     pub data_segment_type: DataSegmentType,
+    /// Defines where the data segment of the [`Server`](crate::port::server::Server) is
+    /// placed in memory.
+    pub data_segment_placement: DataSegmentPlacement,
     /// If the [`Server`](crate::port::server::Server) has the
     /// [`DataSegmentType::Dynamic`] it defines how many segment the
     /// [`Server`](crate::port::server::Server) can have at most.
@@ -93,6 +101,8 @@ pub(crate) struct DynamicConfigSettings {
 pub struct DynamicConfig {
     pub(crate) servers: Container<ServerDetails>,
     pub(crate) clients: Container<ClientDetails>,
+    // number of outstanding requests per server, indexed by the server's container handle
+    outstanding_requests: RelocatableVec<IoxAtomicUsize>,
 }
 
 impl DynamicConfig {
@@ -100,6 +110,7 @@ impl DynamicConfig {
         Self {
             servers: unsafe { Container::new_uninit(config.number_of_servers) },
             clients: unsafe { Container::new_uninit(config.number_of_clients) },
+            outstanding_requests: unsafe { RelocatableVec::new_uninit(config.number_of_servers) },
         }
     }
 
@@ -110,11 +121,19 @@ impl DynamicConfig {
         fatal_panic!(from self,
             when self.clients.init(allocator),
             "This should never happen! Unable to initialize clients port id container.");
+        fatal_panic!(from self,
+            when self.outstanding_requests.init(allocator),
+            "This should never happen! Unable to initialize outstanding requests counters.");
+        for _ in 0..self.outstanding_requests.capacity() {
+            self.outstanding_requests
+                .push_unchecked(IoxAtomicUsize::new(0));
+        }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<ServerDetails>::memory_size(config.number_of_servers)
             + Container::<ClientDetails>::memory_size(config.number_of_clients)
+            + RelocatableVec::<IoxAtomicUsize>::memory_size(config.number_of_servers)
     }
 
     /// Returns how many [`crate::port::client::Client`] ports are currently connected.
@@ -172,9 +191,33 @@ impl DynamicConfig {
     }
 
     pub(crate) fn release_server_handle(&self, handle: ContainerHandle) {
+        if let Some(counter) = self.outstanding_requests.get(handle.index() as usize) {
+            counter.store(0, Ordering::Relaxed);
+        }
         unsafe { self.servers.remove(handle, ReleaseMode::Default) };
     }
 
+    pub(crate) fn number_of_outstanding_requests(&self, server_index: usize) -> usize {
+        self.outstanding_requests
+            .get(server_index)
+            .map(|counter| counter.load(Ordering::Relaxed))
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn increment_outstanding_requests(&self, server_index: usize) {
+        if let Some(counter) = self.outstanding_requests.get(server_index) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn decrement_outstanding_requests(&self, server_index: usize) {
+        if let Some(counter) = self.outstanding_requests.get(server_index) {
+            // the counter is reset when the server is released, late decrements must not wrap
+            let _ =
+                counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| v.checked_sub(1));
+        }
+    }
+
     /// Iterates over all [`Server`](crate::port::server::Server)s and calls the
     /// callback with the corresponding [`ServerDetails`].
     /// The callback shall return [`CallbackProgression::Continue`] when the iteration shall
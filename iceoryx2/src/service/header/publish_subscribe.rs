@@ -33,6 +33,7 @@
 
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::clock::{ClockType, Time, TimeBuilder};
 
 use crate::{node::NodeId, port::port_identifiers::UniquePublisherId};
 
@@ -44,6 +45,8 @@ pub struct Header {
     node_id: NodeId,
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    sequence_number: u64,
+    send_timestamp: u64,
 }
 
 impl Header {
@@ -56,9 +59,16 @@ impl Header {
             node_id,
             publisher_port_id,
             number_of_elements,
+            sequence_number: 0,
+            send_timestamp: 0,
         }
     }
 
+    pub(crate) fn set_send_details(&mut self, sequence_number: u64, send_timestamp: Time) {
+        self.sequence_number = sequence_number;
+        self.send_timestamp = send_timestamp.as_duration().as_nanos() as u64;
+    }
+
     /// Returns the [`NodeId`] of the source node that published the
     /// [`Sample`](crate::sample::Sample).
     pub fn node_id(&self) -> NodeId {
@@ -83,4 +93,24 @@ impl Header {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the sequence number of the [`Sample`](crate::sample::Sample). Every
+    /// [`Publisher`](crate::port::publisher::Publisher) starts with `0` and increments it by one
+    /// with every sent [`Sample`](crate::sample::Sample). A gap between two consecutively
+    /// received sequence numbers of the same publisher means that samples were lost, for
+    /// instance due to an overflow.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the point in time when the [`Sample`](crate::sample::Sample) was sent. It is
+    /// acquired from [`ClockType::default()`], which is the monotonic clock on all platforms
+    /// that support it, and can be used to measure the latency via [`Time::elapsed()`].
+    pub fn send_timestamp(&self) -> Time {
+        TimeBuilder::new()
+            .clock_type(ClockType::default())
+            .seconds(self.send_timestamp / 1_000_000_000)
+            .nanoseconds((self.send_timestamp % 1_000_000_000) as u32)
+            .create()
+    }
 }
@@ -10,10 +10,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::clock::{ClockType, Time, TimeBuilder};
 use iceoryx2_cal::zero_copy_connection::ChannelId;
 
+use crate::port::details::channel_management::UNLIMITED_CHANNEL_CREDITS;
 use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
 
 /// Request header used by
@@ -25,9 +30,62 @@ pub struct RequestHeader {
     pub(crate) channel_id: ChannelId,
     pub(crate) request_id: u64,
     pub(crate) number_of_elements: u64,
+    timeout: u64,
+    deadline: u64,
+    response_credits: u64,
 }
 
 impl RequestHeader {
+    pub(crate) fn new(
+        client_id: UniqueClientId,
+        channel_id: ChannelId,
+        request_id: u64,
+        number_of_elements: u64,
+    ) -> Self {
+        Self {
+            client_id,
+            channel_id,
+            request_id,
+            number_of_elements,
+            timeout: 0,
+            deadline: 0,
+            response_credits: UNLIMITED_CHANNEL_CREDITS,
+        }
+    }
+
+    pub(crate) fn set_response_credits(&mut self, credits: u64) {
+        self.response_credits = credits.min(UNLIMITED_CHANNEL_CREDITS - 1);
+    }
+
+    pub(crate) fn initial_response_credits(&self) -> u64 {
+        self.response_credits
+    }
+
+    pub(crate) fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = (timeout.as_nanos() as u64).max(1);
+    }
+
+    pub(crate) fn set_send_timestamp(&mut self, send_timestamp: Time) {
+        if self.timeout != 0 {
+            self.deadline =
+                (send_timestamp.as_duration().as_nanos() as u64).saturating_add(self.timeout);
+        }
+    }
+
+    pub(crate) fn has_timed_out(&self) -> bool {
+        match self.deadline() {
+            None => false,
+            Some(deadline) => match Time::now() {
+                Ok(now) => deadline.as_duration() <= now.as_duration(),
+                Err(e) => {
+                    warn!(from self,
+                        "Unable to acquire the current time to check the request timeout, the request is considered as not timed out ({:?}).", e);
+                    false
+                }
+            },
+        }
+    }
+
     /// Returns the [`UniqueClientId`] of the [`Client`](crate::port::client::Client)
     /// which sent the [`RequestMut`](crate::request_mut::RequestMut)
     pub fn client_id(&self) -> UniqueClientId {
@@ -46,6 +104,47 @@ impl RequestHeader {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the timeout the [`Client`](crate::port::client::Client) defined for the
+    /// [`RequestMut`](crate::request_mut::RequestMut). If no timeout was defined it returns
+    /// [`None`].
+    pub fn timeout(&self) -> Option<Duration> {
+        match self.timeout {
+            0 => None,
+            v => Some(Duration::from_nanos(v)),
+        }
+    }
+
+    /// Returns the point in time when the [`RequestMut`](crate::request_mut::RequestMut)
+    /// times out. It is the send timestamp plus [`RequestHeader::timeout()`], acquired from
+    /// [`ClockType::default()`]. If no timeout was defined, the
+    /// [`RequestMut`](crate::request_mut::RequestMut) was not yet sent or its send timestamp
+    /// could not be acquired it returns [`None`].
+    pub fn deadline(&self) -> Option<Time> {
+        match self.deadline {
+            0 => None,
+            v => Some(
+                TimeBuilder::new()
+                    .clock_type(ClockType::default())
+                    .seconds(v / 1_000_000_000)
+                    .nanoseconds((v % 1_000_000_000) as u32)
+                    .create(),
+            ),
+        }
+    }
+
+    /// Returns the number of [`ResponseMut`](crate::response_mut::ResponseMut)s the
+    /// [`Server`](crate::port::server::Server) is allowed to loan before the
+    /// [`Client`](crate::port::client::Client) grants further credits with
+    /// [`PendingResponse::grant_credits()`](crate::pending_response::PendingResponse::grant_credits()).
+    /// If the [`RequestMut`](crate::request_mut::RequestMut) does not use flow control it
+    /// returns [`None`].
+    pub fn response_credits(&self) -> Option<u64> {
+        match self.response_credits {
+            UNLIMITED_CHANNEL_CREDITS => None,
+            v => Some(v),
+        }
+    }
 }
 
 /// Response header used by
@@ -56,6 +155,7 @@ pub struct ResponseHeader {
     pub(crate) server_id: UniqueServerId,
     pub(crate) request_id: u64,
     pub(crate) number_of_elements: u64,
+    pub(crate) is_final: bool,
 }
 
 impl ResponseHeader {
@@ -78,4 +178,11 @@ impl ResponseHeader {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns `true` when the [`Response`](crate::response::Response) is the last one the
+    /// [`Server`](crate::port::server::Server) sends for the corresponding request, see
+    /// [`ResponseMut::send_final()`](crate::response_mut::ResponseMut::send_final()).
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
 }
@@ -234,6 +234,12 @@ pub mod static_config;
 /// Represents static features of a service that can be set when a [`Service`] is created.
 pub mod attribute;
 
+/// Defines who is allowed to access a [`Service`]. It is set when the [`Service`] is created.
+pub mod access_policy;
+
+/// Defines to which servers a request is delivered. It is set when the [`Service`] is created.
+pub mod dispatch_policy;
+
 /// A configuration when communicating within a single process or single address space.
 pub mod local;
 
@@ -250,6 +256,10 @@ pub mod ipc;
 /// [`Send`] but at the cost of an additional internal mutex.
 pub mod ipc_threadsafe;
 
+/// A configuration when communicating between different processes using posix mechanisms
+/// where the payload is stored in huge pages.
+pub mod ipc_huge_page;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -365,6 +375,14 @@ impl core::error::Error for ServiceListError {}
 pub struct ServiceDynamicDetails<S: Service> {
     /// A list of all [`Node`](crate::node::Node)s that are registered at the [`Service`]
     pub nodes: Vec<NodeState<S>>,
+    /// A list of all [`Publisher`](crate::port::publisher::Publisher)s that are connected to
+    /// the [`Service`]. Empty if the [`Service`] is not a
+    /// [`MessagingPattern::PublishSubscribe`] based [`Service`].
+    pub publishers: Vec<dynamic_config::publish_subscribe::PublisherDetails>,
+    /// A list of all [`Server`](crate::port::server::Server)s that are connected to
+    /// the [`Service`]. Empty if the [`Service`] is not a
+    /// [`MessagingPattern::RequestResponse`] based [`Service`].
+    pub servers: Vec<dynamic_config::request_response::ServerDetails>,
 }
 
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`].
@@ -461,7 +479,7 @@ pub mod internal {
         prelude::EventId,
         service::stale_resource_cleanup::{
             remove_data_segment_of_port, remove_receiver_port_from_all_connections,
-            remove_sender_port_from_all_connections,
+            remove_sender_port_from_all_connections, remove_wake_up_events_of_receiver_port,
         },
     };
 
@@ -572,6 +590,15 @@ pub mod internal {
         port_name: &str,
     ) -> Result<(), CleanupFailure> {
         remove_sender_connection_and_data_segment::<S>(id, config, origin, port_name)?;
+        remove_receiver_connections_and_wake_up_events::<S>(id, config, origin, port_name)
+    }
+
+    fn remove_receiver_connections_and_wake_up_events<S: Service>(
+        id: u128,
+        config: &config::Config,
+        origin: &str,
+        port_name: &str,
+    ) -> Result<(), CleanupFailure> {
         unsafe { remove_receiver_port_from_all_connections::<S>(id, config) }.map_err(|e| {
             debug!(from origin,
                     "Failed to remove the {} ({:?}) from all of its incoming connections ({:?}).",
@@ -579,6 +606,13 @@ pub mod internal {
             CleanupFailure
         })?;
 
+        unsafe { remove_wake_up_events_of_receiver_port::<S>(id, config) }.map_err(|e| {
+            debug!(from origin,
+                    "Failed to remove the {} ({:?}) wake up events ({:?}).",
+                    port_name, id, e);
+            CleanupFailure
+        })?;
+
         Ok(())
     }
 
@@ -674,10 +708,14 @@ pub mod internal {
                         }
                     }
                     UniquePortId::Subscriber(ref id) => {
-                        if let Err(e) = unsafe {
-                            remove_receiver_port_from_all_connections::<S>(id.value(), config)
-                        } {
-                            debug!(from origin, "Failed to remove the subscriber ({:?}) from all of its connections ({:?}).", id, e);
+                        if remove_receiver_connections_and_wake_up_events::<S>(
+                            id.value(),
+                            config,
+                            &origin,
+                            "subscriber",
+                        )
+                        .is_err()
+                        {
                             return PortCleanupAction::SkipPort;
                         }
                     }
@@ -1042,7 +1080,30 @@ fn details<S: Service>(
             };
             CallbackProgression::Continue
         });
-        Some(ServiceDynamicDetails { nodes })
+
+        let mut publishers = vec![];
+        let mut servers = vec![];
+        match service_config.messaging_pattern() {
+            static_config::messaging_pattern::MessagingPattern::PublishSubscribe(_) => {
+                d.get().publish_subscribe().list_publishers(|details| {
+                    publishers.push(*details);
+                    CallbackProgression::Continue
+                })
+            }
+            static_config::messaging_pattern::MessagingPattern::RequestResponse(_) => {
+                d.get().request_response().list_servers(|details| {
+                    servers.push(*details);
+                    CallbackProgression::Continue
+                })
+            }
+            _ => (),
+        }
+
+        Some(ServiceDynamicDetails {
+            nodes,
+            publishers,
+            servers,
+        })
     } else {
         None
     };
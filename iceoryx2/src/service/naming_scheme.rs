@@ -10,12 +10,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use alloc::format;
 use alloc::string::ToString;
 
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::FileName;
 
+use iceoryx2_cal::zero_copy_connection::ChannelId;
+
 use crate::port::port_identifiers::UniqueListenerId;
 
 pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
@@ -46,6 +49,20 @@ pub(crate) fn extract_receiver_port_id_from_connection(connection: &FileName) ->
     receiver_port_id.parse::<u128>().ok()
 }
 
+pub(crate) fn wake_up_event_name(receiver_port_id: u128, channel_id: ChannelId) -> FileName {
+    let msg = "The system does not support the required file name length for the wake up event.";
+    let origin = "wake_up_event_name()";
+
+    let name = format!("{}_{}", receiver_port_id, channel_id.value());
+    fatal_panic!(from origin, when FileName::new(name.as_bytes()), "{}", msg)
+}
+
+pub(crate) fn extract_receiver_port_id_from_wake_up_event(event: &FileName) -> Option<u128> {
+    let name = core::str::from_utf8(event.as_bytes()).ok()?;
+    let (receiver_port_id, _) = name.split_once('_')?;
+    receiver_port_id.parse::<u128>().ok()
+}
+
 pub(crate) fn data_segment_name(port_id_value: u128) -> FileName {
     let msg = "The system does not support the required file name length for the data segment.";
     let origin = "data_segment_name()";
@@ -37,14 +37,17 @@
 //! # }
 //! ```
 
-use super::nodes;
 use super::reader::PortFactoryReader;
 use super::writer::PortFactoryWriter;
+use super::{event, nodes};
 use crate::node::NodeListFailure;
+use crate::service::access_policy::AccessPolicy;
 use crate::service::attribute::AttributeSet;
 use crate::service::builder::blackboard::BlackboardResources;
+use crate::service::builder::CustomKeyMarker;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
+use crate::service::static_config::message_type_details::TypeDetail;
 use crate::service::{self, dynamic_config, static_config, ServiceState};
 use core::fmt::Debug;
 use core::hash::Hash;
@@ -90,6 +93,10 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn access_policy(&self) -> &AccessPolicy {
+        self.service.static_config.access_policy()
+    }
+
     fn static_config(&self) -> &static_config::blackboard::StaticConfig {
         self.service.static_config.blackboard()
     }
@@ -169,4 +176,96 @@ impl<
     pub fn reader_builder(&self) -> PortFactoryReader<'_, Service, KeyType> {
         PortFactoryReader::new(self)
     }
+
+    /// Returns the event service owned by the blackboard if it was created with
+    /// [`Creator::enable_event_service()`](crate::service::builder::blackboard::Creator::enable_event_service()).
+    /// Every entry update is signaled with the entry id of the updated entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// type KeyType = u64;
+    /// let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .blackboard_creator::<KeyType>()
+    ///     .add::<i32>(0,0)
+    ///     .enable_event_service(true)
+    ///     .create()?;
+    ///
+    /// let reader = blackboard.reader_builder().create()?;
+    /// let entry_handle = reader.entry::<i32>(&0)?;
+    /// let listener = blackboard.event_service().unwrap().listener_builder().create()?;
+    ///
+    /// listener.try_wait_all(|entry_id| {
+    ///     if entry_id == entry_handle.entry_id() {
+    ///         println!("new value: {}", entry_handle.get());
+    ///     }
+    /// })?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn event_service(&self) -> Option<&event::PortFactory<Service>> {
+        self.service.additional_resource.event_service.as_ref()
+    }
+
+    /// Calls the provided callback for every key of the blackboard until either all keys
+    /// were visited or the callback returns [`CallbackProgression::Stop`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// type KeyType = u64;
+    /// let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .blackboard_creator::<KeyType>()
+    ///     .add::<i32>(0,0)
+    ///     .add::<u8>(1,0)
+    ///     .create()?;
+    ///
+    /// blackboard.list_keys(|key| {
+    ///     println!("key: {key}");
+    ///     CallbackProgression::Continue
+    /// });
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_keys<F: FnMut(&KeyType) -> CallbackProgression>(&self, mut callback: F) {
+        self.list_entries(|key, _| callback(unsafe { &*key.cast::<KeyType>() }))
+    }
+
+    fn list_entries<F: FnMut(*const u8, &TypeDetail) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.service
+            .additional_resource
+            .for_each_entry(|key, entry| callback(key.data.as_ptr(), &entry.type_details));
+    }
+}
+
+impl<Service: service::Service> PortFactory<Service, CustomKeyMarker> {
+    #[doc(hidden)]
+    /// Calls the provided callback with a pointer to the key and the value type details of
+    /// every entry until either all entries were visited or the callback returns
+    /// [`CallbackProgression::Stop`].
+    ///
+    /// # Safety
+    ///
+    ///   * the key pointer is only valid during the callback
+    pub unsafe fn __internal_list_entries<
+        F: FnMut(*const u8, &TypeDetail) -> CallbackProgression,
+    >(
+        &self,
+        callback: F,
+    ) {
+        self.list_entries(callback)
+    }
 }
@@ -38,6 +38,7 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
+use crate::service::access_policy::AccessPolicy;
 use crate::service::attribute::AttributeSet;
 use crate::service::service_id::ServiceId;
 use crate::service::{self, static_config, NoResource, ServiceState};
@@ -79,6 +80,10 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.static_config.attributes()
     }
 
+    fn access_policy(&self) -> &AccessPolicy {
+        self.service.static_config.access_policy()
+    }
+
     fn static_config(&self) -> &static_config::event::StaticConfig {
         self.service.static_config.event()
     }
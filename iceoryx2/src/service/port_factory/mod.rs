@@ -15,6 +15,7 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use crate::config::Config;
 use crate::node::{NodeListFailure, NodeState};
 
+use super::access_policy::AccessPolicy;
 use super::dynamic_config::DynamicConfig;
 use super::service_id::ServiceId;
 use super::{attribute::AttributeSet, service_name::ServiceName};
@@ -80,6 +81,9 @@ pub trait PortFactory {
     /// Returns the attributes defined in the [`crate::service::Service`]
     fn attributes(&self) -> &AttributeSet;
 
+    /// Returns the [`AccessPolicy`] of the [`crate::service::Service`]
+    fn access_policy(&self) -> &AccessPolicy;
+
     /// Returns the StaticConfig of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     fn static_config(&self) -> &Self::StaticConfig;
@@ -30,7 +30,10 @@
 //! ```
 use core::fmt::Debug;
 
-use crate::port::{event_id::EventId, notifier::Notifier, notifier::NotifierCreateError};
+use crate::port::{
+    event_id::EventId,
+    notifier::{Notifier, NotifierCreateError, NotifierSharedState},
+};
 use iceoryx2_bb_log::fail;
 
 use crate::service;
@@ -70,4 +73,13 @@ impl<'factory, Service: service::Service> PortFactoryNotifier<'factory, Service>
                     "Failed to create new Notifier port."),
         )
     }
+
+    pub(crate) fn create_shared_state(
+        self,
+    ) -> Result<NotifierSharedState<Service>, NotifierCreateError> {
+        Ok(
+            fail!(from self, when NotifierSharedState::new(self.factory.service.clone(), self.default_event_id),
+                    "Failed to create new Notifier port."),
+        )
+    }
 }
@@ -48,12 +48,14 @@ use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
+use crate::service::access_policy::AccessPolicy;
 use crate::service::attribute::AttributeSet;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config, NoResource, ServiceState};
 use alloc::sync::Arc;
 
+use super::event;
 use super::nodes;
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 
@@ -69,6 +71,7 @@ pub struct PortFactory<
     UserHeader: Debug + ZeroCopySend,
 > {
     pub(crate) service: Arc<ServiceState<Service, NoResource>>,
+    pub(crate) deadline_event_service: Option<event::PortFactory<Service>>,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -110,6 +113,10 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn access_policy(&self) -> &AccessPolicy {
+        self.service.static_config.access_policy()
+    }
+
     fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
         self.service.static_config.publish_subscribe()
     }
@@ -136,9 +143,13 @@ impl<
         UserHeader: Debug + ZeroCopySend,
     > PortFactory<Service, Payload, UserHeader>
 {
-    pub(crate) fn new(service: ServiceState<Service, NoResource>) -> Self {
+    pub(crate) fn new(
+        service: ServiceState<Service, NoResource>,
+        deadline_event_service: Option<event::PortFactory<Service>>,
+    ) -> Self {
         Self {
             service: Arc::new(service),
+            deadline_event_service,
             _payload: PhantomData,
             _user_header: PhantomData,
         }
@@ -60,6 +60,7 @@ use alloc::format;
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
+use iceoryx2_cal::shared_memory::{MemoryLockPolicy, NumaPlacement};
 use iceoryx2_cal::shm_allocator::AllocationStrategy;
 
 use crate::{
@@ -80,6 +81,9 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) memory_lock_policy: MemoryLockPolicy,
+    pub(crate) numa_placement: NumaPlacement,
+    pub(crate) priority: u8,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -122,6 +126,9 @@ impl<
                 degradation_callback: None,
                 initial_max_slice_len: self.config.initial_max_slice_len,
                 allocation_strategy: self.config.allocation_strategy,
+                memory_lock_policy: self.config.memory_lock_policy,
+                numa_placement: self.config.numa_placement,
+                priority: self.config.priority,
             },
             factory: self.factory,
         }
@@ -155,6 +162,15 @@ impl<
                     .defaults
                     .publish_subscribe
                     .unable_to_deliver_strategy,
+                memory_lock_policy: factory
+                    .service
+                    .shared_node
+                    .config()
+                    .defaults
+                    .publish_subscribe
+                    .publisher_memory_lock_policy,
+                numa_placement: NumaPlacement::Disabled,
+                priority: 0,
             },
             factory,
         }
@@ -174,6 +190,38 @@ impl<
         self
     }
 
+    /// Defines if the data segment of the [`Publisher`] is locked into RAM and pre-faulted so
+    /// that the first access of a [`crate::sample_mut::SampleMut`] does not cause a page fault.
+    /// It applies to every data segment that is allocated, including the ones that are
+    /// allocated when the [`AllocationStrategy`] requires a resize.
+    /// If the memory cannot be locked, [`MemoryLockPolicy::Required`] lets
+    /// [`PortFactoryPublisher::create()`] fail with
+    /// [`PublisherCreateError::UnableToLockDataSegment`] while
+    /// [`MemoryLockPolicy::BestEffort`] only emits a warning.
+    pub fn memory_lock_policy(mut self, value: MemoryLockPolicy) -> Self {
+        self.config.memory_lock_policy = value;
+        self
+    }
+
+    /// Binds the data segment of the [`Publisher`] to a NUMA node so that
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s on that node can access the
+    /// samples without cross-node latency. It applies to every data segment that is allocated,
+    /// including the ones that are allocated when the [`AllocationStrategy`] requires a resize.
+    /// On systems with only one NUMA node the placement is ignored and a warning is emitted.
+    pub fn numa_placement(mut self, value: NumaPlacement) -> Self {
+        self.config.numa_placement = value;
+        self
+    }
+
+    /// Defines the priority of the [`Publisher`]. [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// with [`ReceiveOrder::Priority`](crate::port::receive_order::ReceiveOrder::Priority)
+    /// receive the [`crate::sample::Sample`]s of [`Publisher`]s with a higher priority first.
+    /// The default priority is `0`.
+    pub fn priority(mut self, value: u8) -> Self {
+        self.config.priority = value;
+        self
+    }
+
     /// Sets the [`DegradationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegradationAction`] measures will be taken.
@@ -195,7 +243,7 @@ impl<
     pub fn create(self) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
         let origin = format!("{self:?}");
         Ok(
-            fail!(from origin, when Publisher::new(self.factory.service.clone(), self.factory.service.static_config.publish_subscribe(), self.config),
+            fail!(from origin, when Publisher::new(self.factory.service.clone(), self.factory.service.static_config.publish_subscribe(), self.factory.deadline_event_service.as_ref(), self.config),
                 "Failed to create new Publisher port."),
         )
     }
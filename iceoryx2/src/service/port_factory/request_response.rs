@@ -48,11 +48,12 @@ use crate::{
     node::NodeListFailure,
     prelude::AttributeSet,
     service::{
-        self, dynamic_config, service_id::ServiceId, service_name::ServiceName, static_config,
-        NoResource, ServiceState,
+        self, access_policy::AccessPolicy, dynamic_config, service_id::ServiceId,
+        service_name::ServiceName, static_config, NoResource, ServiceState,
     },
 };
 
+use super::event;
 use super::{client::PortFactoryClient, nodes, server::PortFactoryServer};
 
 extern crate alloc;
@@ -72,6 +73,7 @@ pub struct PortFactory<
     ResponseHeader: Debug + ZeroCopySend,
 > {
     pub(crate) service: Arc<ServiceState<Service, NoResource>>,
+    pub(crate) cancellation_event_service: Option<Arc<event::PortFactory<Service>>>,
     _request_payload: PhantomData<RequestPayload>,
     _request_header: PhantomData<RequestHeader>,
     _response_payload: PhantomData<ResponsePayload>,
@@ -112,6 +114,7 @@ impl<
     fn clone(&self) -> Self {
         Self {
             service: self.service.clone(),
+            cancellation_event_service: self.cancellation_event_service.clone(),
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -145,6 +148,10 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn access_policy(&self) -> &AccessPolicy {
+        self.service.static_config.access_policy()
+    }
+
     fn static_config(&self) -> &Self::StaticConfig {
         self.service.static_config.request_response()
     }
@@ -173,9 +180,13 @@ impl<
         ResponseHeader: Debug + ZeroCopySend,
     > PortFactory<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
 {
-    pub(crate) fn new(service: ServiceState<Service, NoResource>) -> Self {
+    pub(crate) fn new(
+        service: ServiceState<Service, NoResource>,
+        cancellation_event_service: Option<event::PortFactory<Service>>,
+    ) -> Self {
         Self {
             service: Arc::new(service),
+            cancellation_event_service: cancellation_event_service.map(Arc::new),
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
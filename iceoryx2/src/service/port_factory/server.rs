@@ -39,6 +39,7 @@ use alloc::format;
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::shared_memory::{MemoryLockPolicy, NumaPlacement};
 use iceoryx2_cal::shm_allocator::AllocationStrategy;
 
 use crate::{
@@ -54,6 +55,8 @@ pub(crate) struct LocalServerConfig {
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) memory_lock_policy: MemoryLockPolicy,
+    pub(crate) numa_placement: NumaPlacement,
 }
 
 /// Defines a failure that can occur when a [`Server`] is created with
@@ -65,10 +68,15 @@ pub enum ServerCreateError {
     ExceedsMaxSupportedServers,
     /// The datasegment in which the payload of the [`Server`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The datasegment in which the payload of the [`Server`] is stored, could not be locked
+    /// into RAM and the [`MemoryLockPolicy`] is [`MemoryLockPolicy::Required`].
+    UnableToLockDataSegment,
     /// Caused by a failure when instantiating a
     /// [`ArcSyncPolicy`](iceoryx2_cal::arc_sync_policy::ArcSyncPolicy) defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
 }
 
 impl core::fmt::Display for ServerCreateError {
@@ -151,6 +159,8 @@ impl<
                 unable_to_deliver_strategy: self.config.unable_to_deliver_strategy,
                 initial_max_slice_len: self.config.initial_max_slice_len,
                 allocation_strategy: self.config.allocation_strategy,
+                memory_lock_policy: self.config.memory_lock_policy,
+                numa_placement: self.config.numa_placement,
             },
             max_loaned_responses_per_request: self.max_loaned_responses_per_request,
             request_degradation_callback: None,
@@ -181,6 +191,8 @@ impl<
                 unable_to_deliver_strategy: defs.server_unable_to_deliver_strategy,
                 initial_max_slice_len: 1,
                 allocation_strategy: AllocationStrategy::Static,
+                memory_lock_policy: defs.server_memory_lock_policy,
+                numa_placement: NumaPlacement::Disabled,
             },
             request_degradation_callback: None,
             response_degradation_callback: None,
@@ -208,6 +220,29 @@ impl<
         self
     }
 
+    /// Defines if the data segment of the [`Server`] is locked into RAM and pre-faulted so
+    /// that the first access of a [`ResponseMut`](crate::response_mut::ResponseMut) does not
+    /// cause a page fault. It applies to every data segment that is allocated, including the
+    /// ones that are allocated when the [`AllocationStrategy`] requires a resize.
+    /// If the memory cannot be locked, [`MemoryLockPolicy::Required`] lets
+    /// [`PortFactoryServer::create()`] fail with [`ServerCreateError::UnableToLockDataSegment`]
+    /// while [`MemoryLockPolicy::BestEffort`] only emits a warning.
+    pub fn memory_lock_policy(mut self, value: MemoryLockPolicy) -> Self {
+        self.config.memory_lock_policy = value;
+        self
+    }
+
+    /// Binds the data segment of the [`Server`] to a NUMA node so that
+    /// [`Client`](crate::port::client::Client)s on that node can access the
+    /// [`Response`](crate::response::Response)s without cross-node latency. It applies to every
+    /// data segment that is allocated, including the ones that are allocated when the
+    /// [`AllocationStrategy`] requires a resize.
+    /// On systems with only one NUMA node the placement is ignored and a warning is emitted.
+    pub fn numa_placement(mut self, value: NumaPlacement) -> Self {
+        self.config.numa_placement = value;
+        self
+    }
+
     /// Sets the [`DegradationCallback`] for receiving [`ActiveRequest`](crate::active_request::ActiveRequest)s
     /// from a [`Client`](crate::port::client::Client). Whenever a connection to a
     /// [`Client`](crate::port::client::Client) is corrupted or it seems to be dead, this callback
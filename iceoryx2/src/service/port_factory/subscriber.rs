@@ -37,10 +37,12 @@ use iceoryx2_bb_log::fail;
 
 use crate::{
     port::{
-        subscriber::{Subscriber, SubscriberCreateError},
+        details::chunk::Chunk,
+        receive_order::ReceiveOrder,
+        subscriber::{SampleFilter, Subscriber, SubscriberCreateError},
         DegradationAction, DegradationCallback,
     },
-    service,
+    service::{self, header::publish_subscribe::Header},
 };
 
 use super::publish_subscribe::PortFactory;
@@ -49,6 +51,8 @@ use super::publish_subscribe::PortFactory;
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+    pub(crate) sample_filter: Option<SampleFilter<'static>>,
+    pub(crate) receive_order: ReceiveOrder,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -84,11 +88,14 @@ impl<
     /// # Safety
     ///
     ///   * does not clone the degradation callback
+    ///   * does not clone the sample filter
     pub unsafe fn __internal_partial_clone(&self) -> Self {
         Self {
             config: SubscriberConfig {
                 buffer_size: self.config.buffer_size,
                 degradation_callback: None,
+                sample_filter: None,
+                receive_order: self.config.receive_order,
             },
             factory: self.factory,
         }
@@ -99,6 +106,8 @@ impl<
             config: SubscriberConfig {
                 buffer_size: None,
                 degradation_callback: None,
+                sample_filter: None,
+                receive_order: ReceiveOrder::default(),
             },
             factory,
         }
@@ -110,6 +119,15 @@ impl<
         self
     }
 
+    /// Defines in which order the [`Subscriber`] receives the
+    /// [`Sample`](crate::sample::Sample)s of multiple
+    /// [`Publisher`](crate::port::publisher::Publisher)s. By default, it is
+    /// [`ReceiveOrder::Priority`].
+    pub fn receive_order(mut self, value: ReceiveOrder) -> Self {
+        self.config.receive_order = value;
+        self
+    }
+
     /// Sets the [`DegradationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegradationAction`] measures will be taken.
@@ -127,14 +145,80 @@ impl<
         self
     }
 
+    /// Sets a filter that is evaluated on the user header of every received
+    /// [`Sample`](crate::sample::Sample). When it returns `false` the
+    /// [`Sample`](crate::sample::Sample) is released immediately, is never handed to the user
+    /// and does not occupy a borrow slot. It replaces a previously set filter.
+    pub fn filter_by_user_header<F: Fn(&UserHeader) -> bool + Send + 'static>(
+        mut self,
+        predicate: F,
+    ) -> Self {
+        self.config.sample_filter = Some(SampleFilter::new(move |chunk: &Chunk| {
+            predicate(unsafe { &*chunk.user_header.cast::<UserHeader>() })
+        }));
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
     pub fn create(
         self,
     ) -> Result<Subscriber<Service, PayloadType, UserHeader>, SubscriberCreateError> {
         let origin = format!("{self:?}");
         Ok(
-            fail!(from origin, when Subscriber::new(self.factory.service.clone(), self.factory.service.static_config.publish_subscribe(), self.config),
+            fail!(from origin, when Subscriber::new(self.factory.service.clone(), self.factory.service.static_config.publish_subscribe(), self.factory.deadline_event_service.as_ref(), self.config),
                 "Failed to create new Subscriber port."),
         )
     }
 }
+
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > PortFactorySubscriber<'_, Service, Payload, UserHeader>
+{
+    /// Sets a filter that is evaluated on the user header and the payload of every received
+    /// [`Sample`](crate::sample::Sample). When it returns `false` the
+    /// [`Sample`](crate::sample::Sample) is released immediately, is never handed to the user
+    /// and does not occupy a borrow slot. It replaces a previously set filter.
+    pub fn filter<F: Fn(&UserHeader, &Payload) -> bool + Send + 'static>(
+        mut self,
+        predicate: F,
+    ) -> Self {
+        self.config.sample_filter = Some(SampleFilter::new(move |chunk: &Chunk| {
+            predicate(
+                unsafe { &*chunk.user_header.cast::<UserHeader>() },
+                unsafe { &*chunk.payload.cast::<Payload>() },
+            )
+        }));
+        self
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > PortFactorySubscriber<'_, Service, [Payload], UserHeader>
+{
+    /// Sets a filter that is evaluated on the user header and the payload of every received
+    /// [`Sample`](crate::sample::Sample). When it returns `false` the
+    /// [`Sample`](crate::sample::Sample) is released immediately, is never handed to the user
+    /// and does not occupy a borrow slot. It replaces a previously set filter.
+    pub fn filter<F: Fn(&UserHeader, &[Payload]) -> bool + Send + 'static>(
+        mut self,
+        predicate: F,
+    ) -> Self {
+        self.config.sample_filter = Some(SampleFilter::new(move |chunk: &Chunk| {
+            let number_of_elements =
+                unsafe { (*chunk.header.cast::<Header>()).number_of_elements() };
+            predicate(
+                unsafe { &*chunk.user_header.cast::<UserHeader>() },
+                unsafe {
+                    core::slice::from_raw_parts(chunk.payload.cast(), number_of_elements as _)
+                },
+            )
+        }));
+        self
+    }
+}
@@ -25,8 +25,9 @@ use crate::service;
 use crate::service::config_scheme::data_segment_config;
 use crate::service::naming_scheme::data_segment_name;
 
-use super::config_scheme::connection_config;
+use super::config_scheme::{connection_config, event_config};
 use super::naming_scheme::extract_receiver_port_id_from_connection;
+use super::naming_scheme::extract_receiver_port_id_from_wake_up_event;
 use super::naming_scheme::extract_sender_port_id_from_connection;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -109,6 +110,29 @@ fn handle_port_remove_error(
     }
 }
 
+fn handle_event_remove_error(
+    result: Result<bool, NamedConceptRemoveError>,
+    origin: &str,
+    msg: &str,
+    event: &FileName,
+) -> Result<(), RemovePortFromAllConnectionsError> {
+    match result {
+        Ok(_) => Ok(()),
+        Err(NamedConceptRemoveError::InsufficientPermissions) => {
+            fail!(from origin,
+                with RemovePortFromAllConnectionsError::InsufficientPermissions,
+                "{} due to insufficient permissions to remove the event ({:?}).",
+                msg, event);
+        }
+        Err(NamedConceptRemoveError::InternalError) => {
+            fail!(from origin,
+                with RemovePortFromAllConnectionsError::InternalError,
+                "{} due to an internal error while removing the event ({:?}).",
+                msg, event);
+        }
+    }
+}
+
 pub(crate) unsafe fn remove_sender_port_from_all_connections<Service: service::Service>(
     port_id: u128,
     config: &config::Config,
@@ -178,3 +202,48 @@ pub(crate) unsafe fn remove_receiver_port_from_all_connections<Service: service:
 
     ret_val
 }
+
+pub(crate) unsafe fn remove_wake_up_events_of_receiver_port<Service: service::Service>(
+    port_id: u128,
+    config: &config::Config,
+) -> Result<(), RemovePortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_wake_up_events_of_receiver_port::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+    let msg = "Unable to remove the wake up events of the receiver port";
+
+    let event_config = event_config::<Service>(config);
+    let event_list = match <Service::Event as NamedConceptMgmt>::list_cfg(&event_config) {
+        Ok(list) => list,
+        Err(NamedConceptListError::InsufficientPermissions) => {
+            fail!(from origin, with RemovePortFromAllConnectionsError::InsufficientPermissions,
+                    "{} due to insufficient permissions to list all events.", msg);
+        }
+        Err(NamedConceptListError::InternalError) => {
+            fail!(from origin, with RemovePortFromAllConnectionsError::InternalError,
+                "{} due to an internal error while listing all events.", msg);
+        }
+    };
+
+    let mut ret_val = Ok(());
+    for event in event_list {
+        if extract_receiver_port_id_from_wake_up_event(&event) != Some(port_id) {
+            continue;
+        }
+
+        let result = handle_event_remove_error(
+            <Service::Event as NamedConceptMgmt>::remove_cfg(&event, &event_config),
+            &origin,
+            msg,
+            &event,
+        );
+
+        if ret_val.is_ok() {
+            ret_val = result;
+        }
+    }
+
+    ret_val
+}
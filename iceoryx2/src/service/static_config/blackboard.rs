@@ -47,6 +47,9 @@ pub struct StaticConfig {
     pub(crate) max_writers: usize,
     pub(crate) max_nodes: usize,
     pub(crate) type_details: TypeDetail,
+    pub(crate) has_event_service: bool,
+    pub(crate) number_of_reserved_keys: usize,
+    pub(crate) max_reserved_value_size: usize,
 }
 
 impl StaticConfig {
@@ -56,6 +59,9 @@ impl StaticConfig {
             max_writers: 1,
             max_nodes: config.defaults.blackboard.max_nodes,
             type_details: TypeDetail::default(),
+            has_event_service: false,
+            number_of_reserved_keys: 0,
+            max_reserved_value_size: 0,
         }
     }
 
@@ -70,8 +76,31 @@ impl StaticConfig {
         self.max_readers
     }
 
+    /// Returns the maximum supported amount of [`crate::port::writer::Writer`] ports
+    pub fn max_writers(&self) -> usize {
+        self.max_writers
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn type_details(&self) -> &TypeDetail {
         &self.type_details
     }
+
+    /// Returns true when the [`crate::service::Service`] owns an event service that notifies
+    /// about every update of an entry.
+    pub fn has_event_service(&self) -> bool {
+        self.has_event_service
+    }
+
+    /// Returns the number of key-value pairs that were reserved to be added after the
+    /// [`crate::service::Service`] was created.
+    pub fn number_of_reserved_keys(&self) -> usize {
+        self.number_of_reserved_keys
+    }
+
+    /// Returns the maximum size of the value of a key-value pair that is added after the
+    /// [`crate::service::Service`] was created.
+    pub fn max_reserved_value_size(&self) -> usize {
+        self.max_reserved_value_size
+    }
 }
@@ -28,6 +28,7 @@
 //! println!("notifier created event:       {:?}", event.static_config().notifier_created_event());
 //! println!("notifier dropped event:       {:?}", event.static_config().notifier_dropped_event());
 //! println!("notifier dead event:          {:?}", event.static_config().notifier_dead_event());
+//! println!("max notification payload:     {:?}", event.static_config().max_notification_payload_size());
 //!
 //! # Ok(())
 //! # }
@@ -61,6 +62,7 @@ pub struct StaticConfig {
     pub(crate) notifier_created_event: Option<usize>,
     pub(crate) notifier_dropped_event: Option<usize>,
     pub(crate) notifier_dead_event: Option<usize>,
+    pub(crate) max_notification_payload_size: usize,
 }
 
 impl StaticConfig {
@@ -77,6 +79,7 @@ impl StaticConfig {
             notifier_created_event: config.defaults.event.notifier_created_event,
             notifier_dropped_event: config.defaults.event.notifier_dropped_event,
             notifier_dead_event: config.defaults.event.notifier_dead_event,
+            max_notification_payload_size: 0,
         }
     }
 
@@ -124,4 +127,11 @@ impl StaticConfig {
     pub fn notifier_dead_event(&self) -> Option<EventId> {
         self.notifier_dead_event.map(EventId::new)
     }
+
+    /// Returns the maximum size in bytes of the
+    /// [`NotificationPayload`](crate::port::notification_payload::NotificationPayload) that
+    /// can be attached to a notification. If it is zero, notifications do not carry a payload.
+    pub fn max_notification_payload_size(&self) -> usize {
+        self.max_notification_payload_size
+    }
 }
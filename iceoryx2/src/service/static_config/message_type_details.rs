@@ -21,6 +21,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::constants::MAX_TYPE_NAME_LENGTH;
 
+use super::type_schema::TypeSchema;
+
 /// Defines if the type is a slice with a runtime-size ([`TypeVariant::Dynamic`])
 /// or if its a type that satisfies [`Sized`] ([`TypeVariant::FixedSize`]).
 #[repr(C)]
@@ -61,6 +63,7 @@ pub struct TypeDetail {
     pub(crate) type_name: TypeName,
     pub(crate) size: usize,
     pub(crate) alignment: usize,
+    pub(crate) schema: TypeSchema,
 }
 
 impl TypeDetail {
@@ -78,6 +81,7 @@ impl TypeDetail {
             },
             size: core::mem::size_of::<T>(),
             alignment: core::mem::align_of::<T>(),
+            schema: TypeSchema::new::<T>(),
         }
     }
 
@@ -101,6 +105,12 @@ impl TypeDetail {
     pub fn alignment(&self) -> usize {
         self.alignment
     }
+
+    /// The field-level layout of the underlying type. It is empty when the type does not
+    /// provide a [`TypeSchema`].
+    pub fn schema(&self) -> &TypeSchema {
+        &self.schema
+    }
 }
 
 /// Contains all type information to the header and payload type.
@@ -114,6 +124,9 @@ pub struct MessageTypeDetails {
     pub user_header: TypeDetail,
     /// The [`TypeDetail`] of the payload of the message, the last part.
     pub payload: TypeDetail,
+    /// The name of the [`Serialize`](iceoryx2_cal::serialize::Serialize) implementation that
+    /// serializes the payload. It is empty when the payload is not serialized.
+    pub serializer: TypeName,
 }
 
 impl MessageTypeDetails {
@@ -124,9 +137,25 @@ impl MessageTypeDetails {
             header: TypeDetail::new::<Header>(TypeVariant::FixedSize),
             user_header: TypeDetail::new::<UserHeader>(TypeVariant::FixedSize),
             payload: TypeDetail::new::<Payload>(payload_variant),
+            serializer: TypeName::new(),
         }
     }
 
+    /// Returns true when the [`TypeSchema`] of any part of the message exceeds its limits,
+    /// see [`TypeSchema::exceeds_limits()`].
+    pub(crate) fn exceeds_type_schema_limits(&self) -> bool {
+        self.header.schema.exceeds_limits()
+            || self.user_header.schema.exceeds_limits()
+            || self.payload.schema.exceeds_limits()
+    }
+
+    /// Returns true when the payload is serialized with the
+    /// [`Serialize`](iceoryx2_cal::serialize::Serialize) implementation stored in
+    /// [`MessageTypeDetails::serializer`].
+    pub fn is_serialized(&self) -> bool {
+        !self.serializer.is_empty()
+    }
+
     pub(crate) fn payload_ptr_from_header(&self, header: *const u8) -> *const u8 {
         let user_header = self.user_header_ptr_from_header(header) as usize;
         let payload_start = align(user_header + self.user_header.size, self.payload.alignment);
@@ -161,16 +190,23 @@ impl MessageTypeDetails {
             && self.user_header.variant == rhs.user_header.variant
             && self.user_header.size == rhs.user_header.size
             && self.user_header.alignment <= rhs.user_header.alignment
+            && self
+                .user_header
+                .schema
+                .is_compatible_to(&rhs.user_header.schema)
             && self.payload.type_name == rhs.payload.type_name
             && self.payload.variant == rhs.payload.variant
             && self.payload.size == rhs.payload.size
             && self.payload.alignment <= rhs.payload.alignment
+            && self.payload.schema.is_compatible_to(&rhs.payload.schema)
+            && self.serializer == rhs.serializer
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::service::static_config::type_schema::TypeSchema;
     use iceoryx2_bb_derive_macros::ZeroCopySend;
     use iceoryx2_bb_testing::assert_that;
 
@@ -196,19 +232,23 @@ mod tests {
                 type_name: "i32".try_into().unwrap(),
                 size: 4,
                 alignment: 4, // i32 uses 4 bytes, so its aliment is always 4 no matter x32 or x64.
+                schema: TypeSchema::new::<i32>(),
             },
             user_header: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: ALIGNMENT,
+                schema: TypeSchema::new::<i64>(),
             },
             payload: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "iceoryx2::service::static_config::message_type_details::tests::test_from::MyPayload".try_into().unwrap(),
                 size: 16,
                 alignment: ALIGNMENT,
+                schema: TypeSchema::default(),
             },
+            serializer: TypeName::new(),
         };
         assert_that!(sut, eq expected);
 
@@ -219,19 +259,23 @@ mod tests {
                 type_name: "i32".try_into().unwrap(),
                 size: 4,
                 alignment: 4,
+                schema: TypeSchema::new::<i32>(),
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "bool".try_into().unwrap(),
                 size: 1,
                 alignment: 1,
+                schema: TypeSchema::new::<bool>(),
             },
             payload: TypeDetail {
                 variant: TypeVariant::Dynamic,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: ALIGNMENT,
+                schema: TypeSchema::new::<i64>(),
             },
+            serializer: TypeName::new(),
         };
         assert_that!(sut, eq expected);
     }
@@ -365,6 +409,33 @@ mod tests {
         assert_that!(sut, eq false);
     }
 
+    #[test]
+    fn test_is_compatible_to_failed_when_serializers_differ() {
+        let left = MessageTypeDetails::from::<i64, i64, u8>(TypeVariant::Dynamic);
+        let mut right = left.clone();
+        right.serializer = "SomeSerializer".try_into().unwrap();
+
+        assert_that!(left.is_serialized(), eq false);
+        assert_that!(right.is_serialized(), eq true);
+        assert_that!(left.is_compatible_to(&right), eq false);
+        assert_that!(right.is_compatible_to(&left), eq false);
+        assert_that!(right.is_compatible_to(&right), eq true);
+    }
+
+    #[test]
+    fn test_is_compatible_to_verifies_schema_only_when_both_are_available() {
+        let left = MessageTypeDetails::from::<i64, i64, u64>(TypeVariant::FixedSize);
+        let mut right = left.clone();
+        right.payload.schema = TypeSchema::default();
+
+        assert_that!(left.is_compatible_to(&right), eq true);
+        assert_that!(right.is_compatible_to(&left), eq true);
+
+        right.payload.schema = TypeSchema::new::<[u32; 2]>();
+        assert_that!(left.is_compatible_to(&right), eq false);
+        assert_that!(right.is_compatible_to(&left), eq false);
+    }
+
     #[test]
     fn test_is_compatible_to_succeed_when_rhs_aligment_is_bigger() {
         let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
@@ -377,19 +448,23 @@ mod tests {
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: ALIGNMENT,
+                schema: TypeSchema::new::<i64>(),
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                schema: TypeSchema::new::<i64>(),
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                schema: TypeSchema::new::<i64>(),
             },
+            serializer: TypeName::new(),
         };
         // smaller to bigger is allowed.
         let sut = left.is_compatible_to(&right);
@@ -412,19 +487,23 @@ mod tests {
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: ALIGNMENT,
+                schema: TypeSchema::new::<i64>(),
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                schema: TypeSchema::new::<i64>(),
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                schema: TypeSchema::new::<i64>(),
             },
+            serializer: TypeName::new(),
         };
         // bigger to smaller is invalid.
         let sut = right.is_compatible_to(&left);
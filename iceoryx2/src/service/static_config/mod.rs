@@ -24,6 +24,10 @@ pub mod publish_subscribe;
 /// and the type variant
 pub mod message_type_details;
 
+/// Contains the field-level layout of a type that allows tools to decode samples
+/// without knowing the type at compile time
+pub mod type_schema;
+
 pub mod request_response;
 
 pub mod messaging_pattern;
@@ -43,7 +47,10 @@ use crate::config;
 
 use self::messaging_pattern::MessagingPattern;
 
-use super::{attribute::AttributeSet, service_id::ServiceId, service_name::ServiceName};
+use super::{
+    access_policy::AccessPolicy, attribute::AttributeSet, service_id::ServiceId,
+    service_name::ServiceName,
+};
 
 /// Defines a common set of static service configuration details every service shares.
 #[derive(Debug, Eq, PartialEq, Clone, ZeroCopySend, Serialize, Deserialize)]
@@ -52,6 +59,7 @@ pub struct StaticConfig {
     service_id: ServiceId,
     service_name: ServiceName,
     pub(crate) attributes: AttributeSet,
+    pub(crate) access_policy: AccessPolicy,
     pub(crate) messaging_pattern: MessagingPattern,
 }
 
@@ -70,6 +78,7 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            access_policy: AccessPolicy::default(),
         }
     }
 
@@ -86,6 +95,7 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            access_policy: AccessPolicy::default(),
         }
     }
 
@@ -103,6 +113,7 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            access_policy: AccessPolicy::default(),
         }
     }
 
@@ -119,6 +130,7 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            access_policy: AccessPolicy::default(),
         }
     }
 
@@ -127,6 +139,11 @@ impl StaticConfig {
         &self.attributes
     }
 
+    /// Returns the [`AccessPolicy`] of the [`crate::service::Service`]
+    pub fn access_policy(&self) -> &AccessPolicy {
+        &self.access_policy
+    }
+
     /// Returns the uuid of the [`crate::service::Service`]
     pub fn service_id(&self) -> &ServiceId {
         &self.service_id
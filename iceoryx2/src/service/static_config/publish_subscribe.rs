@@ -28,11 +28,15 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("lifespan:                         {:?}", pubsub.static_config().lifespan());
+//! println!("subscriber deadline:              {:?}", pubsub.static_config().subscriber_deadline());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use super::message_type_details::MessageTypeDetails;
 use crate::config;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
@@ -53,6 +57,8 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) lifespan: Option<Duration>,
+    pub(crate) subscriber_deadline: Option<Duration>,
     pub(crate) message_type_details: MessageTypeDetails,
 }
 
@@ -72,6 +78,8 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            lifespan: None,
+            subscriber_deadline: None,
             message_type_details: MessageTypeDetails::default(),
         }
     }
@@ -126,6 +134,23 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns the lifespan of a [`crate::sample::Sample`]. A [`crate::sample::Sample`] that was
+    /// not received by a [`crate::port::subscriber::Subscriber`] within its lifespan, measured
+    /// from the point in time when it was sent, is dropped instead of delivered.
+    pub fn lifespan(&self) -> Option<Duration> {
+        self.lifespan
+    }
+
+    /// Returns the deadline of the [`crate::port::subscriber::Subscriber`]s. If no new
+    /// [`crate::sample::Sample`] arrives within the deadline, the
+    /// [`crate::port::subscriber::Subscriber::deadline_listener()`] that is attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet) with
+    /// [`WaitSet::attach_deadline()`](crate::waitset::WaitSet::attach_deadline()) reports the
+    /// missed deadline.
+    pub fn subscriber_deadline(&self) -> Option<Duration> {
+        self.subscriber_deadline
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
@@ -33,6 +33,8 @@
 //! println!("response safe overflow: {:?}", req_res.static_config().has_safe_overflow_for_responses());
 //! println!("max borrowed responses per pending response: {:?}", req_res.static_config().max_borrowed_responses_per_pending_response());
 //! println!("does support fire and forget requests: {:?}", req_res.static_config().does_support_fire_and_forget_requests());
+//! println!("has request cancellation: {:?}", req_res.static_config().has_request_cancellation());
+//! println!("dispatch policy: {:?}", req_res.static_config().dispatch_policy());
 //!
 //! # Ok(())
 //! # }
@@ -43,6 +45,7 @@ use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use serde::{Deserialize, Serialize};
 
 use crate::config;
+use crate::service::dispatch_policy::DispatchPolicy;
 
 use super::message_type_details::MessageTypeDetails;
 
@@ -56,6 +59,8 @@ pub struct StaticConfig {
     pub(crate) enable_safe_overflow_for_requests: bool,
     pub(crate) enable_safe_overflow_for_responses: bool,
     pub(crate) enable_fire_and_forget_requests: bool,
+    pub(crate) enable_request_cancellation: bool,
+    pub(crate) dispatch_policy: DispatchPolicy,
     pub(crate) max_active_requests_per_client: usize,
     pub(crate) max_loaned_requests: usize,
     pub(crate) max_response_buffer_size: usize,
@@ -95,6 +100,8 @@ impl StaticConfig {
                 .defaults
                 .request_response
                 .enable_fire_and_forget_requests,
+            enable_request_cancellation: false,
+            dispatch_policy: DispatchPolicy::default(),
             request_message_type_details: MessageTypeDetails::default(),
             response_message_type_details: MessageTypeDetails::default(),
         }
@@ -160,6 +167,21 @@ impl StaticConfig {
         self.enable_fire_and_forget_requests
     }
 
+    /// Returns true if the [`Client`](crate::port::client::Client) signals the
+    /// [`Server`](crate::port::server::Server) when a
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) is cancelled, otherwise
+    /// false.
+    pub fn has_request_cancellation(&self) -> bool {
+        self.enable_request_cancellation
+    }
+
+    /// Returns the [`DispatchPolicy`] that defines to which
+    /// [`Server`](crate::port::server::Server)s a [`Client`](crate::port::client::Client)
+    /// delivers a [`RequestMut`](crate::request_mut::RequestMut).
+    pub fn dispatch_policy(&self) -> DispatchPolicy {
+        self.dispatch_policy
+    }
+
     /// Returns the maximum number of borrowed [`Response`](crate::response::Response)s a
     /// [`Client`](`crate::port::client::Client`) can hold in
     /// parallel per [`PendingResponse`](crate::pending_response::PendingResponse)
@@ -0,0 +1,499 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema) describes the
+//! memory layout of a type field by field. It is stored in the
+//! [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail) of the
+//! user header and the payload so that generic tools can decode samples without knowing the
+//! types at compile time.
+//!
+//! The schema is provided by
+//! [`ZeroCopySend::describe_type_schema()`](iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend::describe_type_schema()).
+//! Primitives, arrays and the fixed-size containers of `iceoryx2-bb-container` describe
+//! themselves, structs describe their fields when they derive
+//! [`ZeroCopySend`](iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend) and are
+//! annotated with `#[type_schema]`. All other types are opaque and the
+//! [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema) is empty.
+//!
+//! A [`TypeSchema`](crate::service::static_config::type_schema::TypeSchema) that exceeds
+//! [`MAX_TYPE_SCHEMA_FIELDS`](crate::constants::MAX_TYPE_SCHEMA_FIELDS),
+//! [`MAX_FIELD_NAME_LENGTH`](crate::constants::MAX_FIELD_NAME_LENGTH) or
+//! [`MAX_TYPE_SCHEMA_NAMES_LENGTH`](crate::constants::MAX_TYPE_SCHEMA_NAMES_LENGTH) cannot be
+//! stored, the creation of a [`Service`](crate::service::Service) with such a type fails.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! #[repr(C)]
+//! #[derive(Debug, ZeroCopySend)]
+//! #[type_schema]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<Position>()
+//!     .open_or_create()?;
+//!
+//! let schema = service.static_config().message_type_details().payload.schema();
+//! for field in schema.fields() {
+//!     println!("{} {:?} at offset {}", schema.field_name(field), field.kind(), field.offset());
+//! }
+//!
+//! // decode the raw bytes of a sample with any serde serializer
+//! let position = Position { x: 1.5, y: 2.5 };
+//! let bytes = unsafe {
+//!     core::slice::from_raw_parts(
+//!         (&position as *const Position).cast::<u8>(),
+//!         core::mem::size_of::<Position>(),
+//!     )
+//! };
+//! println!("{}", toml::to_string(&schema.decode(bytes))?);
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::string::*;
+use iceoryx2_bb_container::vector::{StaticVec, Vector};
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::type_schema::{FieldDescription, TypeSchemaVisitor};
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::warn;
+use serde::ser::{Error, SerializeMap, SerializeSeq};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::constants::{
+    MAX_FIELD_NAME_LENGTH, MAX_TYPE_SCHEMA_FIELDS, MAX_TYPE_SCHEMA_NAMES_LENGTH,
+};
+
+pub use iceoryx2_bb_elementary_traits::type_schema::TypeSchemaKind;
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "TypeSchemaKind")]
+enum TypeSchemaKindDef {
+    Opaque,
+    Bool,
+    Char,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    F32,
+    F64,
+    Struct,
+    Array,
+    String,
+    Vec,
+}
+
+/// A single field of a [`TypeSchema`]. The fields are stored in depth-first order, the members of
+/// a field directly follow the field. The name of the field is stored in the [`TypeSchema`], see
+/// [`TypeSchema::field_name()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+#[repr(C)]
+pub struct SchemaField {
+    offset: u32,
+    size: u32,
+    capacity: u32,
+    #[serde(with = "TypeSchemaKindDef")]
+    kind: TypeSchemaKind,
+    name_start: u16,
+    name_length: u16,
+    number_of_members: u16,
+}
+
+impl SchemaField {
+    /// Defines how the bytes of the field are interpreted.
+    pub fn kind(&self) -> TypeSchemaKind {
+        self.kind
+    }
+
+    /// The offset in bytes relative to the start of the enclosing field.
+    pub fn offset(&self) -> usize {
+        self.offset as usize
+    }
+
+    /// The size of the field in bytes.
+    pub fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    /// The number of elements of an array or the capacity of a string or vector.
+    pub fn capacity(&self) -> usize {
+        self.capacity as usize
+    }
+
+    /// The number of direct members of the field.
+    pub fn number_of_members(&self) -> usize {
+        self.number_of_members as usize
+    }
+}
+
+/// The field-level layout of a type. See the [module documentation](crate::service::static_config::type_schema)
+/// for details.
+#[derive(Default, Debug, Clone, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+#[repr(C)]
+pub struct TypeSchema {
+    fields: StaticVec<SchemaField, MAX_TYPE_SCHEMA_FIELDS>,
+    names: StaticString<MAX_TYPE_SCHEMA_NAMES_LENGTH>,
+    exceeds_limits: bool,
+}
+
+impl TypeSchema {
+    /// Creates the [`TypeSchema`] of `T`. If `T` is opaque the [`TypeSchema`] is empty. If `T`
+    /// is larger than [`u32::MAX`] bytes or its layout exceeds [`MAX_TYPE_SCHEMA_FIELDS`],
+    /// [`MAX_FIELD_NAME_LENGTH`] or [`MAX_TYPE_SCHEMA_NAMES_LENGTH`] the [`TypeSchema`] is empty
+    /// and [`TypeSchema::exceeds_limits()`] returns true.
+    pub fn new<T: ZeroCopySend>() -> Self {
+        let mut builder = TypeSchemaBuilder::default();
+        T::describe_type_schema(&mut builder, "", 0);
+
+        if builder.is_truncated {
+            warn!(from "TypeSchema::new()",
+                "The schema of \"{}\" exceeds the maximum number of fields ({}), the maximum field name length ({}), the maximum length of all field names ({}) or the maximum size. Services cannot be created with this type.",
+                core::any::type_name::<T>(), MAX_TYPE_SCHEMA_FIELDS, MAX_FIELD_NAME_LENGTH, MAX_TYPE_SCHEMA_NAMES_LENGTH);
+            return Self {
+                exceeds_limits: true,
+                ..Self::default()
+            };
+        }
+
+        match builder.schema.fields.first() {
+            Some(field) if field.kind != TypeSchemaKind::Opaque => builder.schema,
+            _ => Self::default(),
+        }
+    }
+
+    /// Returns true when no schema is available.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns true when the layout of the type exceeds [`MAX_TYPE_SCHEMA_FIELDS`],
+    /// [`MAX_FIELD_NAME_LENGTH`] or [`MAX_TYPE_SCHEMA_NAMES_LENGTH`]. A
+    /// [`Service`](crate::service::Service) cannot be created with such a type.
+    pub fn exceeds_limits(&self) -> bool {
+        self.exceeds_limits
+    }
+
+    /// Returns all fields in depth-first order. The first field describes the type itself.
+    pub fn fields(&self) -> &[SchemaField] {
+        self.fields.as_slice()
+    }
+
+    /// Returns the name of a field of the [`TypeSchema`]. The outermost field and the elements
+    /// of an array have an empty name, the fields of tuple structs are named by their index.
+    pub fn field_name(&self, field: &SchemaField) -> &str {
+        let start = field.name_start as usize;
+        self.names
+            .as_str()
+            .get(start..start + field.name_length as usize)
+            .unwrap_or("")
+    }
+
+    /// Returns a view that decodes the bytes of a single instance of the type with any
+    /// [`Serializer`], like JSON, YAML or RON.
+    pub fn decode<'a>(&'a self, bytes: &'a [u8]) -> DecodedValue<'a> {
+        DecodedValue {
+            schema: self,
+            index: 0,
+            bytes,
+        }
+    }
+
+    /// Two schemas are compatible when they are equal or when one of them is not available
+    /// because the type is opaque. A schema that exceeds the limits is never compatible.
+    pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
+        !self.exceeds_limits
+            && !rhs.exceeds_limits
+            && (self.is_empty() || rhs.is_empty() || self == rhs)
+    }
+
+    fn end_of_field(&self, index: usize) -> usize {
+        let mut next = index + 1;
+        if let Some(field) = self.fields.get(index) {
+            for _ in 0..field.number_of_members {
+                if next >= self.fields.len() {
+                    break;
+                }
+                next = self.end_of_field(next);
+            }
+        }
+        next
+    }
+
+    fn members(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let number_of_members = self.fields.get(index).map_or(0, |f| f.number_of_members);
+        let mut next = index + 1;
+        (0..number_of_members).map_while(move |_| {
+            if next >= self.fields.len() {
+                return None;
+            }
+            let current = next;
+            next = self.end_of_field(current);
+            Some(current)
+        })
+    }
+
+    fn member_by_name(&self, index: usize, name: &str) -> Option<usize> {
+        self.members(index)
+            .find(|member| self.field_name(&self.fields[*member]) == name)
+    }
+}
+
+#[derive(Default)]
+struct TypeSchemaBuilder {
+    schema: TypeSchema,
+    parents: Vec<Option<usize>>,
+    is_truncated: bool,
+}
+
+impl TypeSchemaVisitor for TypeSchemaBuilder {
+    fn enter(&mut self, field: &FieldDescription<'_>) {
+        let name_start = self.schema.names.len();
+        if self.is_truncated
+            || self.schema.fields.is_full()
+            || field.name.len() > MAX_FIELD_NAME_LENGTH
+            || u32::try_from(field.size).is_err()
+            || u32::try_from(field.capacity).is_err()
+            || self.schema.names.push_bytes(field.name.as_bytes()).is_err()
+        {
+            self.is_truncated = true;
+            self.parents.push(None);
+            return;
+        }
+
+        if let Some(Some(parent)) = self.parents.last() {
+            self.schema.fields[*parent].number_of_members += 1;
+        }
+
+        self.parents.push(Some(self.schema.fields.len()));
+        // cannot fail, the capacity was checked above
+        let _ = self.schema.fields.push(SchemaField {
+            offset: field.offset as u32,
+            size: field.size as u32,
+            capacity: field.capacity as u32,
+            kind: field.kind,
+            // cannot overflow, the names are limited by MAX_TYPE_SCHEMA_NAMES_LENGTH
+            name_start: name_start as u16,
+            name_length: field.name.len() as u16,
+            number_of_members: 0,
+        });
+    }
+
+    fn leave(&mut self) {
+        self.parents.pop();
+    }
+}
+
+/// Decodes the bytes of a type with its [`TypeSchema`] when it is serialized with any
+/// [`Serializer`]. Structs are serialized as maps, arrays and vectors as sequences, strings as
+/// strings and opaque fields as bytes. Created with [`TypeSchema::decode()`].
+#[derive(Debug, Clone, Copy)]
+pub struct DecodedValue<'a> {
+    schema: &'a TypeSchema,
+    index: usize,
+    bytes: &'a [u8],
+}
+
+fn to_array<const N: usize, E: Error>(bytes: &[u8]) -> Result<[u8; N], E> {
+    bytes
+        .get(..N)
+        .and_then(|v| v.try_into().ok())
+        .ok_or_else(|| {
+            E::custom(format!(
+                "a field requires {N} bytes but only {} are provided",
+                bytes.len()
+            ))
+        })
+}
+
+fn to_u64<E: Error>(bytes: &[u8], size: usize) -> Result<u64, E> {
+    match size {
+        4 => Ok(u32::from_ne_bytes(to_array(bytes)?) as u64),
+        8 => Ok(u64::from_ne_bytes(to_array(bytes)?)),
+        _ => Err(E::custom(format!(
+            "unsupported size {size} of an unsigned integer"
+        ))),
+    }
+}
+
+fn to_i64<E: Error>(bytes: &[u8], size: usize) -> Result<i64, E> {
+    match size {
+        4 => Ok(i32::from_ne_bytes(to_array(bytes)?) as i64),
+        8 => Ok(i64::from_ne_bytes(to_array(bytes)?)),
+        _ => Err(E::custom(format!(
+            "unsupported size {size} of a signed integer"
+        ))),
+    }
+}
+
+impl<'a> DecodedValue<'a> {
+    fn member(&self, index: usize, offset: usize) -> Self {
+        Self {
+            schema: self.schema,
+            index,
+            bytes: self.bytes.get(offset..).unwrap_or(&[]),
+        }
+    }
+
+    fn serialize_elements<S: Serializer>(
+        &self,
+        serializer: S,
+        element: usize,
+        offset: usize,
+        len: usize,
+    ) -> Result<S::Ok, S::Error> {
+        let element_size = self.schema.fields.get(element).map_or(0, |f| f.size());
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for n in 0..len {
+            seq.serialize_element(&self.member(element, offset + n * element_size))?;
+        }
+        seq.end()
+    }
+
+    fn container_len<E: Error>(&self, field: &SchemaField) -> Result<(usize, usize), E> {
+        let (data, len) = match (
+            self.schema.member_by_name(self.index, "data"),
+            self.schema.member_by_name(self.index, "len"),
+        ) {
+            (Some(data), Some(len)) => (data, len),
+            _ => {
+                return Err(E::custom(format!(
+                    "the container \"{}\" has no \"data\" or \"len\" member",
+                    self.schema.field_name(field)
+                )))
+            }
+        };
+
+        let len_field = &self.schema.fields[len];
+        let number_of_elements = to_u64::<E>(
+            self.bytes.get(len_field.offset()..).unwrap_or(&[]),
+            len_field.size(),
+        )? as usize;
+        if number_of_elements > field.capacity() {
+            return Err(E::custom(format!(
+                "the container \"{}\" has a length of {} which exceeds its capacity of {}",
+                self.schema.field_name(field),
+                number_of_elements,
+                field.capacity()
+            )));
+        }
+
+        Ok((data, number_of_elements))
+    }
+}
+
+impl Serialize for DecodedValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let field = match self.schema.fields.get(self.index) {
+            Some(field) => field,
+            None => return Err(S::Error::custom("the type schema is empty or corrupted")),
+        };
+
+        let bytes = match self.bytes.get(..field.size()) {
+            Some(bytes) => bytes,
+            None => {
+                return Err(S::Error::custom(format!(
+                    "the field \"{}\" requires {} bytes but only {} are provided",
+                    self.schema.field_name(field),
+                    field.size(),
+                    self.bytes.len()
+                )))
+            }
+        };
+
+        match field.kind {
+            TypeSchemaKind::Opaque => serializer.serialize_bytes(bytes),
+            TypeSchemaKind::Bool => serializer.serialize_bool(to_array::<1, _>(bytes)?[0] != 0),
+            TypeSchemaKind::Char => match char::from_u32(u32::from_ne_bytes(to_array(bytes)?)) {
+                Some(c) => serializer.serialize_char(c),
+                None => Err(S::Error::custom(format!(
+                    "the field \"{}\" contains an invalid char",
+                    self.schema.field_name(field)
+                ))),
+            },
+            TypeSchemaKind::U8 => serializer.serialize_u8(u8::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::U16 => serializer.serialize_u16(u16::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::U32 => serializer.serialize_u32(u32::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::U64 => serializer.serialize_u64(u64::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::U128 => {
+                serializer.serialize_u128(u128::from_ne_bytes(to_array(bytes)?))
+            }
+            TypeSchemaKind::Usize => serializer.serialize_u64(to_u64(bytes, field.size())?),
+            TypeSchemaKind::I8 => serializer.serialize_i8(i8::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::I16 => serializer.serialize_i16(i16::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::I32 => serializer.serialize_i32(i32::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::I64 => serializer.serialize_i64(i64::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::I128 => {
+                serializer.serialize_i128(i128::from_ne_bytes(to_array(bytes)?))
+            }
+            TypeSchemaKind::Isize => serializer.serialize_i64(to_i64(bytes, field.size())?),
+            TypeSchemaKind::F32 => serializer.serialize_f32(f32::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::F64 => serializer.serialize_f64(f64::from_ne_bytes(to_array(bytes)?)),
+            TypeSchemaKind::Struct => {
+                let mut map = serializer.serialize_map(Some(field.number_of_members()))?;
+                for member in self.schema.members(self.index) {
+                    let member_field = &self.schema.fields[member];
+                    map.serialize_entry(
+                        self.schema.field_name(member_field),
+                        &self.member(member, member_field.offset()),
+                    )?;
+                }
+                map.end()
+            }
+            TypeSchemaKind::Array => {
+                self.serialize_elements(serializer, self.index + 1, 0, field.capacity())
+            }
+            TypeSchemaKind::String => {
+                let (data, len) = self.container_len(field)?;
+                let data_offset = self.schema.fields[data].offset();
+                match bytes
+                    .get(data_offset..data_offset + len)
+                    .map(core::str::from_utf8)
+                {
+                    Some(Ok(value)) => serializer.serialize_str(value),
+                    _ => Err(S::Error::custom(format!(
+                        "the string \"{}\" contains invalid UTF-8",
+                        self.schema.field_name(field)
+                    ))),
+                }
+            }
+            TypeSchemaKind::Vec => {
+                let (data, len) = self.container_len(field)?;
+                self.serialize_elements(
+                    serializer,
+                    data + 1,
+                    self.schema.fields[data].offset(),
+                    len,
+                )
+            }
+        }
+    }
+}
@@ -21,6 +21,7 @@ use crate::{
     config::Config,
     prelude::{NodeName, ServiceName},
     service::static_config::message_type_details::{TypeDetail, TypeName, TypeVariant},
+    service::static_config::type_schema::TypeSchema,
 };
 
 pub fn generate_service_name() -> ServiceName {
@@ -64,6 +65,7 @@ pub fn create_custom_type_detail(
         type_name,
         size,
         alignment,
+        schema: TypeSchema::default(),
     }
 }
 
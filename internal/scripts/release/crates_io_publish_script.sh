@@ -55,6 +55,7 @@ CRATES_TO_PUBLISH=(
     iceoryx2-tunnel
     iceoryx2-tunnel-conformance-tests
     iceoryx2-tunnel-zenoh
+    iceoryx2-tunnel-udp
     iceoryx2-cli
 )
 CRATES_TO_IGNORE=(